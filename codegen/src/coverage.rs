@@ -0,0 +1,114 @@
+//! Reports which services, actions and parameters lack entries
+//! in the service documentation, so that its completeness can be
+//! tracked as device descriptions are added.
+
+use crate::{Documentation, VersionedService};
+use std::collections::BTreeMap;
+
+#[derive(clap::Args, Debug)]
+pub struct CoverageArgs {
+    /// Exit with a failure status if less than this percentage
+    /// of the items are documented
+    #[arg(long)]
+    fail_under: Option<f64>,
+
+    /// Only print the per-service totals, rather than listing
+    /// each undocumented item
+    #[arg(long)]
+    summary: bool,
+}
+
+/// Counts of documented items
+#[derive(Default, Debug, Clone, Copy)]
+struct Tally {
+    documented: usize,
+    total: usize,
+}
+
+impl Tally {
+    fn add(&mut self, documented: bool) {
+        self.total += 1;
+        if documented {
+            self.documented += 1;
+        }
+    }
+
+    fn percentage(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.documented as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+impl std::fmt::Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{} ({:.1}%)",
+            self.documented,
+            self.total,
+            self.percentage()
+        )
+    }
+}
+
+pub fn report(
+    args: &CoverageArgs,
+    services: &BTreeMap<String, VersionedService>,
+    docs: &Documentation,
+) {
+    let mut overall = Tally::default();
+
+    for (service_name, service) in services {
+        let mut tally = Tally::default();
+        let mut missing = vec![];
+        let service_docs = docs.services.get(&format!("{service_name}Service"));
+
+        tally.add(service_docs.is_some());
+        if service_docs.is_none() {
+            missing.push("service description".to_string());
+        }
+
+        for (action_name, action) in &service.actions {
+            let action_docs = service_docs.and_then(|s| s.actions.get(action_name));
+            tally.add(action_docs.is_some());
+            if action_docs.is_none() {
+                missing.push(format!("action {action_name}"));
+            }
+
+            for p in action.inputs.iter().chain(action.outputs.iter()) {
+                let documented = action_docs
+                    .map(|a| a.params.contains_key(&p.param.name))
+                    .unwrap_or(false);
+                tally.add(documented);
+                if !documented {
+                    missing.push(format!("param {action_name}.{}", p.param.name));
+                }
+            }
+        }
+
+        println!("{service_name}: {tally}");
+        if !args.summary {
+            for item in &missing {
+                println!("  missing {item}");
+            }
+        }
+
+        overall.documented += tally.documented;
+        overall.total += tally.total;
+    }
+
+    println!("Total: {overall}");
+
+    if let Some(threshold) = args.fail_under {
+        if overall.percentage() < threshold {
+            eprintln!(
+                "documentation coverage {:.1}% is below {threshold}%",
+                overall.percentage()
+            );
+            std::process::exit(1);
+        }
+    }
+}
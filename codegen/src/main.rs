@@ -1,23 +1,124 @@
-use crate::schema::{ModelInfo, Parameter, ServiceInfo, StateVariable};
+use crate::schema::{AllowedValueRange, ModelInfo, Parameter, ServiceInfo, StateVariable};
+use clap::{Parser, Subcommand};
 use inflector::Inflector;
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
+mod coverage;
 mod schema;
+mod scrape;
 
 #[derive(Debug)]
 pub struct VersionedService {
     pub info: ServiceInfo,
     pub state_variables: BTreeMap<String, StateVariable>,
     pub actions: BTreeMap<String, VersionedAction>,
+    pub type_overrides: Vec<TypeOverride>,
+}
+
+/// Replaces the type of a field with a more meaningful one,
+/// such as a decoded xml document or a duration
+#[derive(Deserialize, Debug, Clone)]
+pub struct TypeOverride {
+    /// The state variable or parameter name
+    name: String,
+    /// When set, only the field in this struct is replaced
+    #[serde(rename = "struct")]
+    containing_struct_name: Option<String>,
+    /// The Rust type to use for the field
+    #[serde(rename = "type")]
+    type_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct TypeOverrides {
+    overrides: Vec<TypeOverride>,
 }
 
 fn refine_name(name: &str) -> String {
     name.replace("A_ARG_TYPE_", "")
 }
 
+/// Ranges for state variables whose range is documented but that
+/// is not present in the device descriptions that we have captured.
+/// Each entry is (service, state variable, minimum, maximum).
+const KNOWN_RANGES: &[(&str, &str, i64, i64)] = &[
+    ("GroupRenderingControl", "GroupVolume", 0, 100),
+    ("RenderingControl", "Bass", -10, 10),
+    ("RenderingControl", "Treble", -10, 10),
+    ("RenderingControl", "Volume", 0, 100),
+];
+
+/// Returns the Rust type used for an integer UPnP data type, if
+/// a bounded newtype can be generated for it. The range must fit
+/// in the `i32` reported by `Error::OutOfRange`.
+fn bounded_base_type(data_type: &str) -> Option<&'static str> {
+    match data_type {
+        "ui1" => Some("u8"),
+        "ui2" => Some("u16"),
+        "i1" => Some("i8"),
+        "i2" => Some("i16"),
+        "i4" => Some("i32"),
+        _ => None,
+    }
+}
+
+/// Returns the Rust type that represents values of the UPnP
+/// `data_type`, or None if it is not one that we know about
+fn rust_type(data_type: &str) -> Option<&'static str> {
+    Some(match data_type {
+        "string" => "String",
+        "ui4" => "u32",
+        "ui2" => "u16",
+        "ui1" => "u8",
+        "i4" | "int" => "i32",
+        "i2" => "i16",
+        "i1" => "i8",
+        "char" => "char",
+        "r4" => "f32",
+        "float" | "r8" | "number" => "f64",
+        "boolean" => "bool",
+        // These are passed through in their ISO 8601 and
+        // base64 encoded forms respectively
+        "date" | "dateTime" | "dateTime.tz" | "time" | "time.tz" => "String",
+        "bin.base64" | "base64" => "String",
+        _ => return None,
+    })
+}
+
+/// Returns a description of each state variable whose data type
+/// has no corresponding Rust type
+fn check_data_types(services: &BTreeMap<String, VersionedService>) -> Vec<String> {
+    let mut unknown = vec![];
+    for (service_name, service) in services {
+        for (name, sv) in &service.state_variables {
+            if rust_type(&sv.data_type).is_none() {
+                unknown.push(format!("{service_name}: {name} has type {}", sv.data_type));
+            }
+        }
+    }
+    unknown
+}
+
+/// Returns the allowed range of `sv`, if a bounded newtype should
+/// be generated for it
+fn value_range(service_name: &str, sv: &StateVariable) -> Option<AllowedValueRange> {
+    bounded_base_type(&sv.data_type)?;
+    sv.allowed_value_range.or_else(|| {
+        KNOWN_RANGES
+            .iter()
+            .find(|(service, name, _, _)| *service == service_name && *name == sv.name)
+            .map(|(_, _, minimum, maximum)| AllowedValueRange {
+                minimum: *minimum,
+                maximum: *maximum,
+                step: None,
+            })
+    })
+}
+
 impl VersionedService {
     fn resolve_type_for_sv(
         &self,
@@ -32,26 +133,18 @@ impl VersionedService {
         let target = if let Some(Value::Array(_)) = &sv.allowed_values {
             // Use an enum
             format!("super::{refined_name}")
+        } else if value_range(&self.info.name, sv).is_some() {
+            // Use a bounded newtype
+            format!("super::{refined_name}")
+        } else if let Some(target) = self
+            .override_type(&refined_name, containing_struct_name)
+            .or_else(|| self.override_type(field_name, containing_struct_name))
+        {
+            target
         } else {
-            if sv.data_type == "string" {
-                let target = self.maybe_decode_xml(&refined_name, containing_struct_name);
-                if target == "String" {
-                    self.maybe_decode_xml(field_name, containing_struct_name)
-                } else {
-                    target
-                }
-            } else {
-                match sv.data_type.as_str() {
-                    "string" => "String",
-                    "ui4" => "u32",
-                    "ui2" => "u16",
-                    "i4" => "i32",
-                    "i2" => "i16",
-                    "boolean" => "bool",
-                    dt => unimplemented!("unhandled type {dt}"),
-                }
+            rust_type(&sv.data_type)
+                .expect("data types are checked by check_data_types")
                 .to_string()
-            }
         };
         if always_optional {
             format!("Option<{target}>")
@@ -60,77 +153,17 @@ impl VersionedService {
         }
     }
 
-    fn maybe_decode_xml(&self, name: &str, containing_struct_name: &str) -> String {
-        enum Entry {
-            Name(&'static str),
-            Alias {
-                name: &'static str,
-                type_name: &'static str,
-            },
-            StructField {
-                containing_struct_name: &'static str,
-                name: &'static str,
-                type_name: &'static str,
-            },
-        }
-
-        impl Entry {
-            fn matches(&self, field_name: &str, containing_struct: &str) -> bool {
-                match self {
-                    Self::Name(name) => field_name == *name,
-                    Self::Alias { name, .. } => field_name == *name,
-                    Self::StructField {
-                        containing_struct_name,
-                        name,
-                        ..
-                    } => containing_struct == *containing_struct_name && field_name == *name,
-                }
-            }
-
-            fn type_name(&self) -> &'static str {
-                match self {
-                    Self::Name(type_name)
-                    | Self::Alias { type_name, .. }
-                    | Self::StructField { type_name, .. } => type_name,
-                }
-            }
-        }
-
-        const KNOWN_TYPES: &[Entry] = &[
-            Entry::Name("ZoneGroupState"),
-            Entry::Name("TrackMetaData"),
-            Entry::Alias {
-                name: "EnqueuedTransportURIMetaData",
-                type_name: "TrackMetaData",
-            },
-            Entry::Alias {
-                name: "AVTransportURIMetaData",
-                type_name: "TrackMetaData",
-            },
-            Entry::Alias {
-                name: "EnqueuedURIMetaData",
-                type_name: "TrackMetaData",
-            },
-            Entry::Alias {
-                name: "CurrentTrackMetaData",
-                type_name: "TrackMetaData",
-            },
-            Entry::StructField {
-                containing_struct_name: "BrowseResponse",
-                name: "Result",
-                type_name: "TrackMetaDataList",
-            },
-        ];
-
-        if let Some(entry) = KNOWN_TYPES
+    /// Returns the overridden type of the field named `name`, if any
+    fn override_type(&self, name: &str, containing_struct_name: &str) -> Option<String> {
+        self.type_overrides
             .iter()
-            .find(|e| e.matches(name, containing_struct_name))
-        {
-            // Use a wrapped version of this type
-            format!("DecodeXmlString<crate::{}>", entry.type_name())
-        } else {
-            "String".to_string()
-        }
+            .find(|o| {
+                o.name == name
+                    && o.containing_struct_name
+                        .as_deref()
+                        .is_none_or(|s| s == containing_struct_name)
+            })
+            .map(|o| o.type_name.clone())
     }
 
     fn resolve_type_for_param(
@@ -150,7 +183,9 @@ impl VersionedService {
                 false,
                 containing_struct_name,
             ),
-            None => self.maybe_decode_xml(&param.param.name, containing_struct_name),
+            None => self
+                .override_type(&param.param.name, containing_struct_name)
+                .unwrap_or_else(|| "String".to_string()),
         };
 
         if param.optional || always_optional {
@@ -159,13 +194,206 @@ impl VersionedService {
             target
         }
     }
+
+    /// Returns a representative value for a parameter of type
+    /// `field_type`, for use in the generated tests.
+    /// Returns None if there is no sensible value, such as for
+    /// embedded xml documents that we can only decode
+    fn sample_value(&self, param: &VersionedParameter, field_type: &str) -> Option<Sample> {
+        let (inner, optional) = match field_type
+            .strip_prefix("Option<")
+            .and_then(|t| t.strip_suffix('>'))
+        {
+            Some(inner) => (inner, true),
+            None => (field_type, false),
+        };
+
+        let sample = if let Some(type_name) = inner.strip_prefix("super::") {
+            let sv = self
+                .state_variables
+                .get(&param.param.related_state_variable_name)?;
+            if let Some(Value::Array(allowed)) = &sv.allowed_values {
+                // Prefer the last value, so that a value that failed
+                // to decode can't be mistaken for the default
+                let item = allowed.last()?;
+                Sample {
+                    expr: format!("{type_name}::{}", item.to_string().to_pascal_case()),
+                    xml: Some(xml_escape(
+                        &item
+                            .as_str()
+                            .map(str::to_string)
+                            .unwrap_or_else(|| item.to_string()),
+                    )),
+                }
+            } else {
+                let range = value_range(&self.info.name, sv)?;
+                let value = 1.clamp(range.minimum, range.maximum);
+                Sample {
+                    expr: format!("{type_name}::new({value}).unwrap()"),
+                    xml: Some(value.to_string()),
+                }
+            }
+        } else {
+            let (expr, xml) = match inner {
+                "String" => (
+                    format!("{:?}.to_string()", param.param.name),
+                    xml_escape(&param.param.name),
+                ),
+                "bool" => ("true".to_string(), "1".to_string()),
+                "char" => ("'a'".to_string(), "a".to_string()),
+                "f32" | "f64" => ("1.5".to_string(), "1.5".to_string()),
+                "u8" | "u16" | "u32" | "i8" | "i16" | "i32" => ("1".to_string(), "1".to_string()),
+                "crate::HmsDuration" => (
+                    "crate::HmsDuration(std::time::Duration::from_secs(72))".to_string(),
+                    "0:01:12".to_string(),
+                ),
+                "crate::NumericBool" => ("crate::NumericBool(true)".to_string(), "1".to_string()),
+                "crate::InstanceId" => ("crate::InstanceId(1)".to_string(), "1".to_string()),
+                // DIDL-Lite metadata can be encoded, so it can be used
+                // in requests, but we don't attempt to produce the
+                // escaped form for responses
+                "DecodeXmlString<crate::TrackMetaData>" => {
+                    return Some(Sample {
+                        expr: wrap_optional(SAMPLE_TRACK_META_DATA, optional),
+                        xml: None,
+                    });
+                }
+                _ => return None,
+            };
+            Sample {
+                expr,
+                xml: Some(xml),
+            }
+        };
+
+        Some(Sample {
+            expr: wrap_optional(&sample.expr, optional),
+            xml: sample.xml,
+        })
+    }
+}
+
+impl VersionedService {
+    /// Returns the value that a field of type `field_type` should
+    /// decode to from `text`, as captured from a player
+    fn fixture_value(
+        &self,
+        param: &VersionedParameter,
+        field_type: &str,
+        text: &str,
+    ) -> Option<FixtureValue> {
+        let inner = field_type
+            .strip_prefix("Option<")
+            .and_then(|t| t.strip_suffix('>'))
+            .unwrap_or(field_type);
+
+        if let Some(type_name) = inner.strip_prefix("super::") {
+            let sv = self
+                .state_variables
+                .get(&param.param.related_state_variable_name)?;
+            if let Some(Value::Array(allowed)) = &sv.allowed_values {
+                allowed.iter().find(|item| item.as_str() == Some(text))?;
+                return Some(FixtureValue::Expr(format!(
+                    "{type_name}::{}",
+                    text.to_pascal_case()
+                )));
+            }
+            let value: i64 = text.parse().ok()?;
+            return Some(FixtureValue::Expr(format!(
+                "{type_name}::new({value}).unwrap()"
+            )));
+        }
+
+        let expr = match inner {
+            "String" => format!("{text:?}.to_string()"),
+            "bool" | "crate::NumericBool" => {
+                let value = match text {
+                    "1" | "true" => "true",
+                    "0" | "false" => "false",
+                    _ => return None,
+                };
+                if inner == "bool" {
+                    value.to_string()
+                } else {
+                    format!("crate::NumericBool({value})")
+                }
+            }
+            "u8" | "u16" | "u32" | "i8" | "i16" | "i32" => text.parse::<i64>().ok()?.to_string(),
+            "f32" | "f64" => format!("{:?}", text.parse::<f64>().ok()?),
+            "crate::InstanceId" => format!("crate::InstanceId({})", text.parse::<u32>().ok()?),
+            "crate::HmsDuration" => {
+                let mut seconds = 0;
+                for part in text.split(':') {
+                    seconds = seconds * 60 + part.parse::<u64>().ok()?;
+                }
+                format!("crate::HmsDuration(std::time::Duration::from_secs({seconds}))")
+            }
+            "DecodeXmlString<crate::TrackMetaData>" => {
+                let didl = roxmltree::Document::parse(text).ok()?;
+                let title = didl
+                    .descendants()
+                    .find(|n| n.tag_name().name() == "title")?
+                    .text()?;
+                return Some(FixtureValue::Title(title.to_string()));
+            }
+            _ => return None,
+        };
+        Some(FixtureValue::Expr(expr))
+    }
+}
+
+/// The value that a field decoded from a captured response is
+/// expected to have
+enum FixtureValue {
+    /// A Rust expression of the type of the field
+    Expr(String),
+    /// The title of DIDL-Lite track metadata
+    Title(String),
+}
+
+/// A value used to populate a field in a generated test
+struct Sample {
+    /// The Rust expression that produces the value
+    expr: String,
+    /// The text of the value as it appears in a SOAP response,
+    /// if it can be represented there
+    xml: Option<String>,
+}
+
+fn wrap_optional(expr: &str, optional: bool) -> String {
+    if optional {
+        format!("Some({expr})")
+    } else {
+        expr.to_string()
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
+/// DIDL-Lite metadata that survives being encoded and decoded
+/// again unchanged
+const SAMPLE_TRACK_META_DATA: &str = "DecodeXmlString(Some(crate::TrackMetaData {
+    id: Some(\"-1\".to_string()),
+    title: \"Title\".to_string(),
+    url: \"http://example.com/track.mp3\".to_string(),
+    mime_type: Some(\"audio/mpeg\".to_string()),
+    ..Default::default()
+}))";
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct VersionedAction {
     pub name: String,
     pub inputs: Vec<VersionedParameter>,
     pub outputs: Vec<VersionedParameter>,
+    pub supported_by: BTreeSet<String>,
+    /// Set when none of the current-generation models support
+    /// this action; see `CURRENT_GENERATION`
+    pub legacy: bool,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -175,22 +403,87 @@ pub struct VersionedParameter {
     pub optional: bool,
 }
 
+/// The lowest `software_generation` of the models that are still
+/// sold and updated; models below it only run the S1 software
+const CURRENT_GENERATION: u32 = 2;
+
+/// Marks the actions that are only supported by models from before
+/// `CURRENT_GENERATION`. Nothing is marked when the current models
+/// have been excluded by `--model`, as there is nothing to compare.
+fn mark_legacy_actions(
+    services: &mut BTreeMap<String, VersionedService>,
+    models: &BTreeMap<String, ModelInfo>,
+) {
+    let current: BTreeSet<String> = models
+        .values()
+        .filter(|info| info.software_generation >= CURRENT_GENERATION)
+        .map(|info| info.model.to_string())
+        .collect();
+    if current.is_empty() {
+        return;
+    }
+    for service in services.values_mut() {
+        for action in service.actions.values_mut() {
+            action.legacy = action.supported_by.is_disjoint(&current);
+        }
+    }
+}
+
+/// Returns the `#[deprecated]` attribute for a legacy action
+fn legacy_attribute(action: &VersionedAction) -> String {
+    format!(
+        "#[deprecated(note = \"only supported on: {}\")]",
+        action
+            .supported_by
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
 fn make_supported_set(model: &str) -> BTreeSet<String> {
     let mut set = BTreeSet::new();
     set.insert(model.to_string());
     set
 }
 
-fn apply_parameter(target: &mut Vec<VersionedParameter>, source: &[Parameter], model: &str) {
+/// Merges the parameters that `model` declares for an action into
+/// `target`. Parameters are matched by name so that models which add,
+/// remove or reorder parameters can be combined; parameters that are
+/// not declared by every model are marked optional.
+/// Disagreements that can't be resolved exactly are appended to
+/// `conflicts` so that they can be reviewed.
+fn apply_parameter(
+    target: &mut Vec<VersionedParameter>,
+    source: &[Parameter],
+    model: &str,
+    action: &str,
+    state_variables: &BTreeMap<String, StateVariable>,
+    conflicts: &mut Vec<String>,
+) {
     let was_empty = target.is_empty();
 
     for (idx, source_param) in source.iter().enumerate() {
-        match target.get_mut(idx) {
-            Some(target_param) => {
-                assert_eq!(
-                    target_param.param, *source_param,
-                    "index {idx} has conflicting parameters {target_param:?} vs {source:?}"
-                );
+        match target
+            .iter()
+            .position(|p| p.param.name == source_param.name)
+        {
+            Some(pos) => {
+                if pos != idx {
+                    conflicts.push(format!(
+                        "{action}: {model} declares {} at index {idx} rather than {pos}",
+                        source_param.name
+                    ));
+                }
+                let target_param = &mut target[pos];
+                if target_param.param != *source_param {
+                    reconcile_parameter(&mut target_param.param, source_param, state_variables);
+                    conflicts.push(format!(
+                        "{action}: {model} declares {source_param:?}, using {:?}",
+                        target_param.param
+                    ));
+                }
                 target_param.supported_by.insert(model.to_string());
             }
             None => {
@@ -202,23 +495,103 @@ fn apply_parameter(target: &mut Vec<VersionedParameter>, source: &[Parameter], m
             }
         }
     }
-}
 
-fn merge_allowed_values(target: &mut Option<Value>, source: &Option<Value>) {
-    match (target, source) {
-        (Some(Value::Array(target)), Some(Value::Array(source))) => {
-            for item in source.iter() {
-                if target.iter().find(|i| *i == item).is_none() {
-                    target.push(item.clone());
-                }
+    if !was_empty {
+        for target_param in target.iter_mut() {
+            if !source.iter().any(|p| p.name == target_param.param.name) {
+                target_param.optional = true;
             }
         }
-        (Some(source), Some(target)) if source == target => {}
-        (None, None) => {}
-        stuff => unimplemented!("handle {stuff:?} case"),
     }
 }
 
+/// Resolves a parameter that two models define differently by
+/// choosing whichever related state variable has the wider type
+fn reconcile_parameter(
+    target: &mut Parameter,
+    source: &Parameter,
+    state_variables: &BTreeMap<String, StateVariable>,
+) {
+    let data_type = |param: &Parameter| {
+        state_variables
+            .get(&param.related_state_variable_name)
+            .map(|sv| sv.data_type.as_str())
+    };
+    if let (Some(target_type), Some(source_type)) = (data_type(target), data_type(source)) {
+        if widen_data_type(target_type, source_type) != target_type {
+            target.related_state_variable_name = source.related_state_variable_name.clone();
+        }
+    }
+}
+
+/// Returns the narrowest UPnP data type that can represent values
+/// of both `a` and `b`, falling back to `string` when the types
+/// are unrelated
+fn widen_data_type<'a>(a: &'a str, b: &'a str) -> &'a str {
+    const UNSIGNED: &[&str] = &["ui1", "ui2", "ui4"];
+    const SIGNED: &[&str] = &["i1", "i2", "i4"];
+
+    if a == b {
+        return a;
+    }
+    let rank = |ladder: &[&str], dt: &str| ladder.iter().position(|t| *t == dt);
+    for ladder in [UNSIGNED, SIGNED] {
+        if let (Some(ra), Some(rb)) = (rank(ladder, a), rank(ladder, b)) {
+            return if ra >= rb { a } else { b };
+        }
+    }
+    // An unsigned value fits in a signed type of twice the width
+    if let (Some(ru), Some(rs)) = (rank(UNSIGNED, a), rank(SIGNED, b)) {
+        if rs > ru {
+            return b;
+        }
+    }
+    if let (Some(ru), Some(rs)) = (rank(UNSIGNED, b), rank(SIGNED, a)) {
+        if rs > ru {
+            return a;
+        }
+    }
+    "string"
+}
+
+/// Merges the allowed values that a model declares for a state
+/// variable into `target`, producing the union of the two lists.
+/// Models don't always agree on the shape: a missing value is
+/// treated as an empty list and a scalar as a single element list,
+/// and the disagreement is appended to `conflicts`.
+fn merge_allowed_values(
+    target: &mut Option<Value>,
+    source: &Option<Value>,
+    context: &str,
+    conflicts: &mut Vec<String>,
+) {
+    fn as_list(value: &Option<Value>) -> Vec<Value> {
+        match value {
+            None => vec![],
+            Some(Value::Array(list)) => list.clone(),
+            Some(scalar) => vec![scalar.clone()],
+        }
+    }
+
+    let is_list = |value: &Option<Value>| matches!(value, Some(Value::Array(_)));
+    if *target == *source {
+        return;
+    }
+    if !(is_list(target) && is_list(source)) {
+        conflicts.push(format!(
+            "{context}: allowed values {source:?} don't match {target:?}, using the union"
+        ));
+    }
+
+    let mut merged = as_list(target);
+    for item in as_list(source) {
+        if !merged.contains(&item) {
+            merged.push(item);
+        }
+    }
+    *target = Some(Value::Array(merged));
+}
+
 #[derive(Deserialize, Debug)]
 struct Documentation {
     services: BTreeMap<String, ServiceDocs>,
@@ -229,6 +602,14 @@ struct ServiceDocs {
     description: String,
     #[serde(default)]
     actions: BTreeMap<String, ActionDocs>,
+    #[serde(default)]
+    errors: Vec<ErrorDocs>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ErrorDocs {
+    code: u32,
+    description: String,
 }
 
 #[derive(Deserialize, Debug)]
@@ -285,25 +666,111 @@ const LAST_NS: &[LastMeta] = &[
     },
 ];
 
+/// Generates the service bindings of the sonos crate from the
+/// device descriptions captured from players
+#[derive(Parser, Debug)]
+struct Args {
+    /// The directory holding the `<model>.json` device descriptions
+    #[arg(long, default_value = "data/devices")]
+    devices: PathBuf,
+
+    /// The service documentation
+    #[arg(long, default_value = "data/documentation.json")]
+    docs: PathBuf,
+
+    /// The type overrides for fields
+    #[arg(long, default_value = "data/type_overrides.json")]
+    types: PathBuf,
+
+    /// The models that have features that can't be discovered from
+    /// their device descriptions, such as a battery
+    #[arg(long, default_value = "data/model_features.json")]
+    model_features: PathBuf,
+
+    /// The directory holding responses captured from players, as
+    /// `<service>/<action>.xml`. A decode test is generated for each.
+    #[arg(long, default_value = "data/responses")]
+    responses: PathBuf,
+
+    /// The directory to which the generated modules are written.
+    /// Any existing content is replaced.
+    #[arg(long, default_value = "../src/generated")]
+    output: PathBuf,
+
+    /// The manifest whose service features are updated
+    #[arg(long, default_value = "../Cargo.toml")]
+    manifest: PathBuf,
+
+    /// Don't update the service features in the manifest
+    #[arg(long)]
+    no_manifest: bool,
+
+    /// Only generate these services, eg: `--service AVTransport`.
+    /// May be repeated. All services are generated by default.
+    #[arg(long = "service")]
+    services: Vec<String>,
+
+    /// Only consider the device descriptions of these models,
+    /// eg: `--model S14`. May be repeated. All models are used
+    /// by default.
+    #[arg(long = "model")]
+    models: Vec<String>,
+
+    /// Also generate traits for this model, eg: `--model-trait S14`,
+    /// that only have the actions that it supports. May be repeated.
+    #[arg(long = "model-trait")]
+    model_traits: Vec<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Discovers a player and captures its device and service
+    /// descriptions into the devices directory, rather than
+    /// generating code
+    Scrape(scrape::ScrapeArgs),
+    /// Reports which services, actions and parameters are not
+    /// described by the documentation, rather than generating code
+    Coverage(coverage::CoverageArgs),
+}
+
 fn main() {
+    let args = Args::parse();
+
+    if let Some(Command::Scrape(scrape_args)) = &args.command {
+        scrape::scrape(scrape_args);
+        return;
+    }
+
     let mut models = BTreeMap::new();
-    let docs: Documentation =
-        serde_json::from_slice(&std::fs::read("data/documentation.json").unwrap()).unwrap();
+    let docs: Documentation = serde_json::from_slice(&std::fs::read(&args.docs).unwrap()).unwrap();
+    let type_overrides: TypeOverrides =
+        serde_json::from_slice(&std::fs::read(&args.types).unwrap()).unwrap();
+    let model_features: ModelFeatures =
+        serde_json::from_slice(&std::fs::read(&args.model_features).unwrap()).unwrap();
 
-    for entry in std::fs::read_dir("data/devices").unwrap() {
+    for entry in std::fs::read_dir(&args.devices).unwrap() {
         let entry = entry.unwrap();
         let meta = entry.metadata().unwrap();
         if meta.is_file() {
             let text = std::fs::read(entry.path()).unwrap();
             let info: ModelInfo = serde_json::from_slice(&text).unwrap();
-            models.insert(info.model.to_string(), info);
+            if args.models.is_empty() || args.models.contains(&info.model) {
+                models.insert(info.model.to_string(), info);
+            }
         }
     }
 
     let mut services = BTreeMap::new();
+    let mut conflicts = vec![];
 
     for info in models.values() {
         for service in &info.services {
+            if !args.services.is_empty() && !args.services.contains(&service.name) {
+                continue;
+            }
             let entry = services.entry(service.name.clone()).or_insert_with(|| {
                 let mut info = service.clone();
                 info.state_variables.clear();
@@ -312,6 +779,7 @@ fn main() {
                     info,
                     state_variables: BTreeMap::new(),
                     actions: BTreeMap::new(),
+                    type_overrides: type_overrides.overrides.clone(),
                 }
             });
 
@@ -325,7 +793,20 @@ fn main() {
                 // so let's assume that we can try it if any models do;
                 // it will be a runtime error if the model doesn't support it.
                 var_entry.send_events = var_entry.send_events || var.send_events;
-                merge_allowed_values(&mut var_entry.allowed_values, &var.allowed_values);
+                if var_entry.data_type != var.data_type {
+                    let widened = widen_data_type(&var_entry.data_type, &var.data_type);
+                    conflicts.push(format!(
+                        "{}: {} declares {} as {}, using {widened}",
+                        service.name, info.model, var.name, var.data_type
+                    ));
+                    var_entry.data_type = widened.to_string();
+                }
+                merge_allowed_values(
+                    &mut var_entry.allowed_values,
+                    &var.allowed_values,
+                    &format!("{}: {} {}", service.name, info.model, var.name),
+                    &mut conflicts,
+                );
             }
 
             for action in &service.actions {
@@ -337,23 +818,75 @@ fn main() {
                             name: action.name.clone(),
                             inputs: vec![],
                             outputs: vec![],
+                            supported_by: BTreeSet::new(),
+                            legacy: false,
                         });
-                apply_parameter(&mut action_entry.inputs, &action.inputs, &info.model);
-                apply_parameter(&mut action_entry.outputs, &action.outputs, &info.model);
+                action_entry.supported_by.insert(info.model.to_string());
+                apply_parameter(
+                    &mut action_entry.inputs,
+                    &action.inputs,
+                    &info.model,
+                    &action.name,
+                    &entry.state_variables,
+                    &mut conflicts,
+                );
+                apply_parameter(
+                    &mut action_entry.outputs,
+                    &action.outputs,
+                    &info.model,
+                    &action.name,
+                    &entry.state_variables,
+                    &mut conflicts,
+                );
             }
         }
     }
 
-    let mut traits = String::new();
-    let mut types = String::new();
-    let mut impls = String::new();
+    for conflict in &conflicts {
+        eprintln!("warning: conflicting definitions: {conflict}");
+    }
+
+    mark_legacy_actions(&mut services, &models);
+
+    let unknown = check_data_types(&services);
+    if !unknown.is_empty() {
+        for unknown in &unknown {
+            eprintln!("error: unhandled data type: {unknown}");
+        }
+        std::process::exit(1);
+    }
+
+    if let Some(Command::Coverage(coverage_args)) = &args.command {
+        coverage::report(coverage_args, &services, &docs);
+        return;
+    }
+
+    let mut modules = String::new();
     let mut prelude = String::new();
+    let mut prelude_modules = String::new();
+    let mut features = vec![];
+    let mut used_fixtures = BTreeSet::new();
+
+    // Start from a clean slate, so that the files of services that
+    // no longer exist are removed
+    remove_generated_files(&args.output);
+    std::fs::create_dir_all(&args.output).unwrap();
 
     for (service_name, service) in &services {
+        let mut traits = String::new();
+        let mut types = String::new();
+        let mut impls = String::new();
+        let mut dyn_traits = String::new();
+        let mut dyn_impls = String::new();
+        let mut tests = String::new();
+
         let service_module = to_snake_case(service_name);
         println!("Service {service_name}");
 
         let service_type = &service.info.service_type;
+        let feature = service_feature(&service_module);
+        let cfg = format!("#[cfg(feature = \"{feature}\")]");
+        features.push(feature.clone());
 
         writeln!(&mut traits, "#[allow(async_fn_in_trait)]").ok();
 
@@ -365,17 +898,18 @@ fn main() {
             writeln!(&mut traits, "/// {doc}").ok();
         }
         writeln!(&mut traits, "pub trait {service_name} {{").ok();
-        writeln!(&mut prelude, "pub use super::{service_name};").ok();
-        writeln!(&mut impls, "impl {service_name} for SonosDevice {{").ok();
-
+        writeln!(&mut prelude, "{cfg}\npub use super::{service_name};").ok();
         writeln!(
-            &mut types,
-            "/// Request and Response types for the `{service_name}` service.
+            &mut prelude_modules,
+            "/// Brings only the `{service_name}` trait into scope:
+            /// `use sonos::prelude::services::{service_module}::*;`
+            {cfg}
             pub mod {service_module} {{
-use super::*;
-"
+                pub use crate::{service_name};
+            }}"
         )
         .ok();
+        writeln!(&mut impls, "impl {service_name} for SonosDevice {{").ok();
 
         writeln!(
             &mut types,
@@ -402,22 +936,25 @@ use super::*;
                 if !action.inputs.is_empty() {
                     writeln!(
                         &mut types,
-                        "#[derive(ToXml, Debug, Clone, PartialEq, Default)]
+                        "#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+                        {SERDE_DERIVE}
                         #[xml(rename=\"{action_name}\", ns(SERVICE_TYPE))]",
                     )
                     .ok();
                     writeln!(&mut types, "pub struct {request_type_name} {{").ok();
+                    let mut builder_fields = vec![];
+                    let mut samples = vec![];
                     for p in &action.inputs {
                         let field_name = to_snake_case(&p.param.name);
                         let field_type =
                             service.resolve_type_for_param(&p, false, &request_type_name);
 
-                        if let Some(doc) = docs
+                        let doc = docs
                             .services
                             .get(&format!("{service_name}Service"))
                             .and_then(|s| s.actions.get(action_name))
-                            .and_then(|a| a.params.get(&p.param.name))
-                        {
+                            .and_then(|a| a.params.get(&p.param.name));
+                        if let Some(doc) = doc {
                             writeln!(&mut types, "/// {doc}").ok();
                         }
 
@@ -428,8 +965,19 @@ use super::*;
                         )
                         .ok();
                         writeln!(&mut types, "  pub {field_name}: {field_type},").ok();
+                        let expr = service
+                            .sample_value(p, &field_type)
+                            .map(|sample| sample.expr)
+                            .unwrap_or_else(|| "Default::default()".to_string());
+                        samples.push((field_name.clone(), expr));
+                        builder_fields.push((field_name, field_type, doc));
                     }
                     writeln!(&mut types, "}}\n").ok();
+                    emit_request_test(&mut tests, &request_type_name, &samples);
+
+                    if builder_fields.len() >= BUILDER_MIN_INPUTS {
+                        emit_request_builder(&mut types, &request_type_name, &builder_fields);
+                    }
                 }
                 format!("{service_module}::{request_type_name}")
             };
@@ -439,15 +987,22 @@ use super::*;
             } else {
                 let response_type_name = format!("{method_name}_response").to_pascal_case();
                 writeln!(&mut types, "#[derive(FromXml, Debug, Clone, PartialEq)]").ok();
+                writeln!(&mut types, "{SERDE_DERIVE}").ok();
                 writeln!(
                     &mut types,
                     "#[xml(rename=\"{action_name}Response\", ns(SERVICE_TYPE))]",
                 )
                 .ok();
                 writeln!(&mut types, "pub struct {response_type_name} {{").ok();
+                let mut samples = vec![];
                 for p in &action.outputs {
                     let field_name = to_snake_case(&p.param.name);
                     let field_type = service.resolve_type_for_param(&p, true, &response_type_name);
+                    samples.push((
+                        field_name.clone(),
+                        p.param.name.clone(),
+                        service.sample_value(p, &field_type),
+                    ));
                     writeln!(
                         &mut types,
                         "  #[xml(rename=\"{}\", ns(\"\"))]",
@@ -457,6 +1012,29 @@ use super::*;
                     writeln!(&mut types, "  pub {field_name}: {field_type},").ok();
                 }
                 writeln!(&mut types, "}}\n").ok();
+                emit_response_test(
+                    &mut tests,
+                    service_type,
+                    action_name,
+                    &response_type_name,
+                    &samples,
+                );
+                let fixture = args
+                    .responses
+                    .join(service_name)
+                    .join(format!("{action_name}.xml"));
+                if let Ok(xml) = std::fs::read_to_string(&fixture) {
+                    emit_fixture_test(
+                        &mut tests,
+                        &fixture,
+                        xml.trim(),
+                        action_name,
+                        &response_type_name,
+                        service,
+                        &action.outputs,
+                    );
+                    used_fixtures.insert(fixture);
+                }
                 writeln!(
                     &mut types,
                     "
@@ -491,10 +1069,32 @@ impl crate::DecodeSoapResponse for {response_type_name} {{
                 .map(|a| &a.description)
             {
                 writeln!(&mut traits, "/// {doc}").ok();
+                writeln!(&mut traits, "///").ok();
             }
             writeln!(
                 &mut traits,
-                "async fn {method_name}(&self{params}) -> Result<{response_type_name}>;"
+                "/// Supported by: {}",
+                action
+                    .supported_by
+                    .iter()
+                    .map(|model| format!("`{model}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .ok();
+            let (deprecated, allow_deprecated) = if action.legacy {
+                writeln!(
+                    &mut traits,
+                    "///\n/// **Warning:** none of the current-generation models support this action"
+                )
+                .ok();
+                (legacy_attribute(action), "#[allow(deprecated)]")
+            } else {
+                (String::new(), "")
+            };
+            writeln!(
+                &mut traits,
+                "{deprecated}\nasync fn {method_name}(&self{params}) -> Result<{response_type_name}>;"
             )
             .ok();
             writeln!(
@@ -505,6 +1105,27 @@ impl crate::DecodeSoapResponse for {response_type_name} {{
             writeln!(&mut impls, "  self.action(&{service_module}::SERVICE_TYPE, \"{action_name}\", {encode_payload}).await").ok();
             writeln!(&mut impls, "}}\n").ok();
             writeln!(&mut impls).ok();
+
+            let args = if action.inputs.is_empty() {
+                ""
+            } else {
+                ", request"
+            };
+            writeln!(
+                &mut dyn_traits,
+                "/// See `{service_name}::{method_name}`
+{deprecated}
+fn {method_name}(&self{params}) -> ActionFuture<'_, {response_type_name}>;"
+            )
+            .ok();
+            writeln!(
+                &mut dyn_impls,
+                "{allow_deprecated}
+fn {method_name}(&self{params}) -> ActionFuture<'_, {response_type_name}> {{
+    Box::pin(<Self as {service_name}>::{method_name}(self{args}))
+}}"
+            )
+            .ok();
         }
 
         writeln!(&mut traits, "}}\n").ok();
@@ -518,6 +1139,7 @@ impl crate::DecodeSoapResponse for {response_type_name} {{
 /// Use `SonosDevice::subscribe_{service_module}()` to obtain an event
 /// stream that produces these.
 #[derive(Debug, Clone, PartialEq, Default)]
+{SERDE_DERIVE}
 pub struct {service_name}Event {{"
             )
             .ok();
@@ -615,6 +1237,7 @@ impl DecodeXml for {service_name}Event {{
 
 impl crate::SonosDevice {{
     /// Subscribe to events from the `{service_name}` service on this device
+    #[cfg(feature = "events")]
     pub async fn subscribe_{service_module}(&self) -> crate::Result<crate::upnp::EventStream<{service_name}Event>> {{
         self.subscribe_helper(&SERVICE_TYPE).await
     }}
@@ -626,6 +1249,7 @@ impl crate::SonosDevice {{
                     &mut types,
                     r#"
     #[derive(Debug, Clone, PartialEq, Default)]
+    {SERDE_DERIVE}
     pub struct {service_name}LastChange {{
     "#
                 )
@@ -718,6 +1342,7 @@ result.{field_name} = item.{field_name}.and_then(|v| v.val);
 {instance_wrapper}
 
 #[derive(Debug, Clone, PartialEq, Default)]
+{SERDE_DERIVE}
 pub struct {service_name}LastChangeMap {{
     pub map: std::collections::BTreeMap<u32, {service_name}LastChange>,
 }}
@@ -750,12 +1375,23 @@ impl DecodeXml for {service_name}LastChangeMap {{
             } // if has_last_change
         }
 
-        // close `mod service_module`
-        writeln!(&mut types, "}}\n").ok();
+        if let Some(errors) = docs
+            .services
+            .get(&format!("{service_name}Service"))
+            .map(|s| &s.errors)
+            .filter(|errors| !errors.is_empty())
+        {
+            emit_service_errors(&mut types, service_name, errors);
+        }
+
+        // The service trait, and the enums and bounded newtypes for
+        // the state variables, are re-exported from the crate
+        let mut exported = vec![service_name.to_string()];
 
         for (name, sv) in &service.state_variables {
             if let Some(Value::Array(allowed)) = &sv.allowed_values {
                 let enum_name = refine_name(name);
+                exported.push(enum_name.clone());
 
                 writeln!(
                     &mut types,
@@ -781,25 +1417,22 @@ impl DecodeXml for {service_name}LastChangeMap {{
                 writeln!(&mut types, "  Unspecified(String),").ok();
                 writeln!(&mut types, "}}\n").ok();
 
-                writeln!(&mut types, "impl ToString for {enum_name} {{").ok();
-                writeln!(&mut types, "fn to_string(&self) -> String {{").ok();
-                writeln!(&mut types, "match self {{").ok();
+                writeln!(&mut types, "impl std::fmt::Display for {enum_name} {{").ok();
+                writeln!(
+                    &mut types,
+                    "fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+                )
+                .ok();
+                writeln!(&mut types, "let s = match self {{").ok();
 
                 for item in allowed {
                     let variant = item.to_string().to_pascal_case();
-                    writeln!(
-                        &mut types,
-                        "  {enum_name}::{variant} => {item}.to_string(),"
-                    )
-                    .ok();
+                    writeln!(&mut types, "  {enum_name}::{variant} => {item},").ok();
                 }
 
-                writeln!(
-                    &mut types,
-                    "  {enum_name}::Unspecified(s) => s.to_string(),"
-                )
-                .ok();
-                writeln!(&mut types, "}}").ok();
+                writeln!(&mut types, "  {enum_name}::Unspecified(s) => s.as_str(),").ok();
+                writeln!(&mut types, "}};").ok();
+                writeln!(&mut types, "f.write_str(s)").ok();
                 writeln!(&mut types, "}}\n").ok();
                 writeln!(&mut types, "}}\n").ok();
 
@@ -874,18 +1507,138 @@ impl<'xml> instant_xml::FromXml<'xml> for {enum_name} {{
     const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
 }}
 
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = \"serde\")]
+impl serde::Serialize for {enum_name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {{
+        serializer.collect_str(self)
+    }}
+}}
 
+#[cfg(feature = \"serde\")]
+impl<'de> serde::Deserialize<'de> for {enum_name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {{
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }}
+}}
 "
                 )
                 .ok();
             }
         }
+
+        for (name, sv) in &service.state_variables {
+            if let Some(range) = value_range(service_name, sv) {
+                let type_name = refine_name(name);
+                emit_bounded_newtype(&mut types, &type_name, &sv.data_type, range);
+                exported.push(type_name);
+            }
+        }
+
+        write_formatted(
+            &args.output.join(format!("{service_module}.rs")),
+            GENERATED_HEADER,
+            &format!(
+                "//! Request and Response types for the `{service_name}` service.
+
+use super::*;
+
+{types}
+{traits}
+{impls}
+
+/// A variant of `{service_name}` that can be used as a trait object,
+/// eg: `Box<dyn {service_name}Dyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = \"dyn-traits\")]
+pub trait {service_name}Dyn: Send + Sync {{
+{dyn_traits}
+}}
+
+#[cfg(feature = \"dyn-traits\")]
+impl {service_name}Dyn for SonosDevice {{
+{dyn_impls}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    {tests}
+}}
+"
+            ),
+        );
+
+        writeln!(
+            &mut modules,
+            "{cfg}\npub mod {service_module};\n{cfg}\npub use {service_module}::{{{}}};\n\
+            #[cfg(all(feature = \"{feature}\", feature = \"dyn-traits\"))]\n\
+            pub use {service_module}::{service_name}Dyn;\n",
+            exported.join(", ")
+        )
+        .ok();
+    }
+
+    if args.services.is_empty() {
+        warn_unused_fixtures(&args.responses, &used_fixtures);
+    }
+
+    write_formatted(
+        &args.output.join("capabilities.rs"),
+        GENERATED_HEADER,
+        &emit_capabilities(&models, &services, &model_features),
+    );
+    writeln!(&mut modules, "pub mod capabilities;").ok();
+
+    if !args.model_traits.is_empty() {
+        let models_dir = args.output.join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        let mut model_modules = String::new();
+        for model in &args.model_traits {
+            let info = models
+                .get(model)
+                .unwrap_or_else(|| panic!("--model-trait {model}: no such model"));
+            let model_module = to_snake_case(model);
+            write_formatted(
+                &models_dir.join(format!("{model_module}.rs")),
+                GENERATED_HEADER,
+                &emit_model_traits(info, &services),
+            );
+            writeln!(&mut model_modules, "pub mod {model_module};").ok();
+        }
+        write_formatted(
+            &models_dir.join("mod.rs"),
+            GENERATED_HEADER,
+            &format!(
+                "//! Service traits that are restricted to the actions that a
+//! particular model supports.
+
+{model_modules}"
+            ),
+        );
+        writeln!(&mut modules, "pub mod models;").ok();
+    }
+
+    if args.no_manifest {
+        // Leave the manifest alone
+    } else if !args.services.is_empty() || !args.models.is_empty() {
+        // The features of the services that were filtered out would
+        // be dropped from the manifest
+        eprintln!("warning: not updating the manifest, as --service or --model was used");
+    } else {
+        update_cargo_features(&args.manifest, &features);
     }
 
-    std::fs::write(
-        "../src/generated.rs",
-        format!(
-            "// This file was auto-generated by codegen! Do not edit!
+    write_formatted(
+        &args.output.join("mod.rs"),
+        &format!(
+            "{GENERATED_HEADER}// Which of the imports are used depends on the enabled services\n"
+        ),
+        &format!(
+            "#![cfg_attr(not(feature = \"all\"), allow(unused_imports))]
 
 use std::str::FromStr;
 use crate::SonosDevice;
@@ -894,32 +1647,1071 @@ use instant_xml::{{FromXml, ToXml}};
 use crate::upnp::DecodeXml;
 use crate::xmlutil::DecodeXmlString;
 
-{types}
-{traits}
-{impls}
+{INSTANCE_ID}
 
+/// The future returned by the methods of the `dyn`-compatible
+/// service traits
+#[cfg(feature = \"dyn-traits\")]
+pub type ActionFuture<'a, T> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<T>> + Send + 'a>>;
+
+{modules}
 /// The prelude makes it convenient to use the methods of `SonosDevice`.
 /// Intended usage is `use sonos::prelude::*;` and then you don't have
 /// to worry about importing the individual service traits.
+/// Applications that use only a few services can instead import
+/// from the per-service modules, such as `prelude::services::av_transport`.
 pub mod prelude {{
 {prelude}
+/// A module per service, so that applications that use only a few
+/// services can import their traits without importing the rest.
+/// These are kept out of the prelude itself so that `use sonos::prelude::*`
+/// doesn't bring names such as `av_transport` into scope, where they
+/// would be ambiguous with the request and response modules of the
+/// same name that `use sonos::*` brings into scope.
+pub mod services {{
+{prelude_modules}
+}}
 }}
 "
         ),
-    )
-    .unwrap();
+    );
 }
 
-fn to_snake_case(s: &str) -> String {
-    // Fixup some special cases
-    let s = s
-        .replace("URIs", "Uris")
-        .replace("UUIDs", "Uuids")
-        .replace("IDs", "Ids");
-    let result = s.to_snake_case();
-    if result == "type" {
-        "type_".to_string()
-    } else {
-        result
+/// Returns a module with a variant of each service trait that only
+/// has the actions that the model described by `info` supports.
+/// The methods delegate to the full service traits.
+fn emit_model_traits(info: &ModelInfo, services: &BTreeMap<String, VersionedService>) -> String {
+    let model = &info.model;
+    let mut traits = String::new();
+
+    for (service_name, service) in services {
+        let actions: Vec<_> = service
+            .actions
+            .values()
+            .filter(|action| action.supported_by.contains(model))
+            .collect();
+        if actions.is_empty() {
+            continue;
+        }
+
+        let service_module = to_snake_case(service_name);
+        let cfg = format!("#[cfg(feature = \"{}\")]", service_feature(&service_module));
+        let mut methods = String::new();
+        let mut impls = String::new();
+
+        for action in actions {
+            let method_name = to_snake_case(&action.name);
+            let (params, args) = if action.inputs.is_empty() {
+                (String::new(), String::new())
+            } else {
+                let request_type_name = format!("{method_name}_request").to_pascal_case();
+                (
+                    format!(", request: crate::{service_module}::{request_type_name}"),
+                    ", request".to_string(),
+                )
+            };
+            let response_type_name = if action.outputs.is_empty() {
+                "()".to_string()
+            } else {
+                let response_type_name = format!("{method_name}_response").to_pascal_case();
+                format!("crate::{service_module}::{response_type_name}")
+            };
+
+            writeln!(
+                &mut methods,
+                "/// See `crate::{service_name}::{method_name}`"
+            )
+            .ok();
+            let unsupported: Vec<_> = action
+                .inputs
+                .iter()
+                .filter(|p| !p.supported_by.contains(model))
+                .map(|p| format!("`{}`", to_snake_case(&p.param.name)))
+                .collect();
+            if !unsupported.is_empty() {
+                writeln!(
+                    &mut methods,
+                    "///\n/// The `{model}` doesn't accept {}, which should be left as `None`",
+                    unsupported.join(", ")
+                )
+                .ok();
+            }
+            let (deprecated, allow_deprecated) = if action.legacy {
+                (legacy_attribute(action), "#[allow(deprecated)]")
+            } else {
+                (String::new(), "")
+            };
+            writeln!(
+                &mut methods,
+                "{deprecated}\nasync fn {method_name}(&self{params}) -> Result<{response_type_name}>;"
+            )
+            .ok();
+            writeln!(
+                &mut impls,
+                "{allow_deprecated}\nasync fn {method_name}(&self{params}) -> Result<{response_type_name}> {{
+    <Self as crate::{service_name}>::{method_name}(self{args}).await
+}}"
+            )
+            .ok();
+        }
+
+        writeln!(
+            &mut traits,
+            "/// The actions of `crate::{service_name}` that the `{model}` supports
+{cfg}
+#[allow(async_fn_in_trait)]
+pub trait {service_name} {{
+{methods}
+}}
+
+{cfg}
+impl {service_name} for SonosDevice {{
+{impls}
+}}
+"
+        )
+        .ok();
+    }
+
+    format!(
+        "//! Service traits for the `{model}` ({}), which only have the
+//! actions that it supports, so that using an action that it doesn't
+//! support is a compile error rather than a fault reported by the
+//! device. Import these in place of the traits in the prelude.
+
+#![cfg_attr(not(feature = \"all\"), allow(unused_imports))]
+
+use crate::{{Result, SonosDevice}};
+
+{traits}",
+        info.model_description
+    )
+}
+
+/// The models that have each of the features that can't be discovered
+/// from device descriptions, mapping model number to product name
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ModelFeatures {
+    battery: BTreeMap<String, String>,
+    fixed_volume: BTreeMap<String, String>,
+    non_airplay: BTreeMap<String, String>,
+}
+
+impl ModelFeatures {
+    fn emit(&self) -> String {
+        let mut result = String::new();
+        for (name, doc, models) in [
+            (
+                "BATTERY_MODELS",
+                "Model numbers of players with a built-in battery",
+                &self.battery,
+            ),
+            (
+                "FIXED_VOLUME_MODELS",
+                "Model numbers of players with a line-out whose volume can be fixed",
+                &self.fixed_volume,
+            ),
+            (
+                "NON_AIRPLAY_MODELS",
+                "Model numbers of players that cannot be the target of an AirPlay stream",
+                &self.non_airplay,
+            ),
+        ] {
+            let products = models
+                .iter()
+                .map(|(model, product)| format!("/// - {model}: {product}\n"))
+                .collect::<String>();
+            let models = models
+                .keys()
+                .map(|model| format!("{model:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                &mut result,
+                "/// {doc}:\n{products}pub const {name}: &[&str] = &[{models}];\n"
+            )
+            .ok();
+        }
+        result
+    }
+}
+
+/// Returns the `capabilities` module, which records which of the
+/// models that we have device descriptions for support each action,
+/// along with the models that have features that aren't described
+fn emit_capabilities(
+    models: &BTreeMap<String, ModelInfo>,
+    services: &BTreeMap<String, VersionedService>,
+    features: &ModelFeatures,
+) -> String {
+    let mut table = String::new();
+    for (service_name, service) in services {
+        for (action_name, action) in &service.actions {
+            let models = action
+                .supported_by
+                .iter()
+                .map(|model| format!("{model:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                &mut table,
+                "({service_name:?}, {action_name:?}, &[{models}]),"
+            )
+            .ok();
+        }
+    }
+    let known_models = models
+        .keys()
+        .map(|model| format!("{model:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let features = features.emit();
+
+    format!(
+        "//! Which models support each action, according to the device
+//! descriptions from which this crate was generated, and which
+//! models have features that their device descriptions don't reveal.
+
+/// The model numbers, eg: `S14`, whose device descriptions are known
+pub const MODELS: &[&str] = &[{known_models}];
+
+{features}
+/// (service, action, models that support it), sorted by service
+/// and then action
+const ACTIONS: &[(&str, &str, &[&str])] = &[
+{table}
+];
+
+/// Returns whether the model identified by `model` (eg: `S14`)
+/// supports `action` of `service`, where `service` is the name
+/// of the service trait, eg: `AVTransport`.
+/// Returns `None` if the model, or the action, is not known, in
+/// which case the only way to find out is to try it.
+pub fn is_supported(model: &str, service: &str, action: &str) -> Option<bool> {{
+    if !MODELS.contains(&model) {{
+        return None;
+    }}
+    let idx = ACTIONS
+        .binary_search_by(|(s, a, _)| (*s, *a).cmp(&(service, action)))
+        .ok()?;
+    Some(ACTIONS[idx].2.contains(&model))
+}}
+
+/// Returns the models that support `action` of `service`
+pub fn supported_by(service: &str, action: &str) -> &'static [&'static str] {{
+    ACTIONS
+        .binary_search_by(|(s, a, _)| (*s, *a).cmp(&(service, action)))
+        .map(|idx| ACTIONS[idx].2)
+        .unwrap_or(&[])
+}}
+"
+    )
+}
+
+const GENERATED_HEADER: &str = "// This file was auto-generated by codegen! Do not edit!\n";
+
+/// Removes the files that a previous run generated in `dir` and its
+/// `models` directory, which are recognized by `GENERATED_HEADER`.
+/// Anything else, such as a mistyped `--output` pointing at a
+/// directory of hand-written code, is left alone.
+fn remove_generated_files(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path.file_name() == Some("models".as_ref()) {
+                remove_generated_files(&path);
+                // Only succeeds if nothing else is in there
+                std::fs::remove_dir(&path).ok();
+            }
+            continue;
+        }
+        let generated = std::fs::read_to_string(&path)
+            .map(|text| text.starts_with(GENERATED_HEADER))
+            .unwrap_or(false);
+        if generated {
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+}
+
+/// Parses `text` as a rust source file, and writes it to `path`
+/// formatted by prettyplease. A codegen bug that produces invalid
+/// syntax is reported here, rather than as a compile error in the
+/// crate. `header` is written verbatim ahead of the code, as regular
+/// comments are discarded by the parser.
+fn write_formatted(path: &Path, header: &str, text: &str) {
+    let file = syn::parse_file(text).unwrap_or_else(|err| {
+        let start = err.span().start();
+        let line = text.lines().nth(start.line.saturating_sub(1)).unwrap_or("");
+        panic!(
+            "{}:{}:{}: generated code is invalid: {err}\n{line}",
+            path.display(),
+            start.line,
+            start.column + 1
+        )
+    });
+    std::fs::write(path, format!("{header}\n{}", prettyplease::unparse(&file))).unwrap();
+}
+
+/// The type of the `InstanceID` parameter that is common to many
+/// actions. It is shared by all of the services.
+const INSTANCE_ID: &str = "
+/// Identifies the virtual instance of a service that a request
+/// applies to. Players only have a single instance of each service,
+/// which is `InstanceId::DEFAULT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct InstanceId(pub u32);
+
+impl InstanceId {
+    pub const DEFAULT: InstanceId = InstanceId(0);
+}
+
+impl From<u32> for InstanceId {
+    fn from(value: u32) -> InstanceId {
+        InstanceId(value)
+    }
+}
+
+impl From<InstanceId> for u32 {
+    fn from(value: InstanceId) -> u32 {
+        value.0
+    }
+}
+
+impl std::fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl instant_xml::ToXml for InstanceId {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+
+    fn present(&self) -> bool {
+        true
+    }
+}
+
+impl<'xml> instant_xml::FromXml<'xml> for InstanceId {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: u32 = value.parse().map_err(|err| {
+                    instant_xml::Error::Other(format!(
+                        \"invalid value for field {field}: {value}: {err:#}\"
+                    ))
+                })?;
+                *into = Some(InstanceId(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+
+    type Accumulator = Option<InstanceId>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+";
+
+/// Applied to the generated data types, so that they can be
+/// persisted or bridged as JSON when the `serde` feature is enabled
+const SERDE_DERIVE: &str =
+    "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]";
+
+/// Emits a test that encodes a populated request and decodes
+/// it again, to verify that the xml attributes are consistent
+fn emit_request_test(tests: &mut String, type_name: &str, samples: &[(String, String)]) {
+    let fields: String = samples
+        .iter()
+        .map(|(field_name, expr)| format!("{field_name}: {expr},\n"))
+        .collect();
+    let test_name = to_snake_case(type_name);
+    writeln!(
+        tests,
+        "#[test]
+fn test_{test_name}() {{
+    let request = {type_name} {{
+        {fields}
+    }};
+    let xml = instant_xml::to_string(&request).unwrap();
+    let decoded: {type_name} = instant_xml::from_str(&xml).unwrap();
+    assert_eq!(decoded, request);
+}}
+"
+    )
+    .ok();
+}
+
+/// Emits a test that decodes a response envelope of the form
+/// produced by a device. Fields without a sample value are left
+/// out of the envelope, and so are expected to be `None`.
+fn emit_response_test(
+    tests: &mut String,
+    service_type: &str,
+    action_name: &str,
+    type_name: &str,
+    samples: &[(String, String, Option<Sample>)],
+) {
+    let mut fields = String::new();
+    let mut elements = String::new();
+    for (field_name, param_name, sample) in samples {
+        match sample {
+            Some(Sample {
+                expr,
+                xml: Some(xml),
+            }) => {
+                writeln!(&mut fields, "{field_name}: {expr},").ok();
+                write!(&mut elements, "<{param_name}>{xml}</{param_name}>").ok();
+            }
+            _ => {
+                writeln!(&mut fields, "{field_name}: None,").ok();
+            }
+        }
+    }
+    let test_name = to_snake_case(type_name);
+    writeln!(
+        tests,
+        "#[test]
+fn test_{test_name}() {{
+    let xml = r#\"<s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\"><s:Body><u:{action_name}Response xmlns:u=\"{service_type}\">{elements}</u:{action_name}Response></s:Body></s:Envelope>\"#;
+    let expected = {type_name} {{
+        {fields}
+    }};
+    let decoded = <{type_name} as crate::DecodeSoapResponse>::decode_soap_xml(xml).unwrap();
+    assert_eq!(decoded, expected);
+}}
+"
+    )
+    .ok();
+}
+
+/// Emits a test that decodes a response that was captured from
+/// a player, and verifies that each value that it holds was decoded
+/// to the value in the response. Values that can't be expressed as
+/// a Rust literal, such as embedded xml documents other than DIDL-Lite
+/// metadata, are only checked to be present.
+#[allow(clippy::too_many_arguments)]
+fn emit_fixture_test(
+    tests: &mut String,
+    fixture: &Path,
+    xml: &str,
+    action_name: &str,
+    type_name: &str,
+    service: &VersionedService,
+    outputs: &[VersionedParameter],
+) {
+    let doc = roxmltree::Document::parse(xml)
+        .unwrap_or_else(|err| panic!("{}: {err:#}", fixture.display()));
+    let response = doc
+        .descendants()
+        .find(|n| n.has_tag_name(format!("{action_name}Response").as_str()))
+        .unwrap_or_else(|| panic!("{}: no {action_name}Response", fixture.display()));
+
+    let mut checks = String::new();
+    for element in response.children().filter(|n| n.is_element()) {
+        let name = element.tag_name().name();
+        let text = element.text().unwrap_or("").trim();
+        if text.is_empty() || text == "NOT_IMPLEMENTED" {
+            continue;
+        }
+        let param = outputs
+            .iter()
+            .find(|p| p.param.name == name)
+            .unwrap_or_else(|| panic!("{}: unknown element {name}", fixture.display()));
+        let field_name = to_snake_case(name);
+        let field_type = service.resolve_type_for_param(param, true, type_name);
+        let check = match service.fixture_value(param, &field_type, text) {
+            Some(FixtureValue::Expr(expected)) => {
+                format!("assert_eq!(decoded.{field_name}, Some({expected}), \"{name}\");")
+            }
+            Some(FixtureValue::Title(title)) => format!(
+                "assert_eq!(decoded.{field_name}.and_then(|m| m.into_inner()).map(|m| m.title), \
+                 Some({title:?}.to_string()), \"{name}\");"
+            ),
+            None => {
+                format!("assert!(decoded.{field_name}.is_some(), \"{name} was not decoded\");")
+            }
+        };
+        writeln!(&mut checks, "{check}").ok();
+    }
+
+    // Choose a raw string delimiter that can't appear in the xml
+    let mut hashes = "#".to_string();
+    while xml.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+
+    let test_name = to_snake_case(type_name);
+    writeln!(
+        tests,
+        "/// Decodes `{}`
+#[test]
+fn test_{test_name}_fixture() {{
+    let xml = r{hashes}\"{xml}\"{hashes};
+    let decoded = <{type_name} as crate::DecodeSoapResponse>::decode_soap_xml(xml).unwrap();
+    {checks}
+}}
+",
+        fixture.display()
+    )
+    .ok();
+}
+
+/// Reports fixtures that don't correspond to an action, which are
+/// likely to be misnamed
+fn warn_unused_fixtures(responses: &Path, used: &BTreeSet<PathBuf>) {
+    let Ok(services) = std::fs::read_dir(responses) else {
+        return;
+    };
+    for service in services.flatten() {
+        let Ok(fixtures) = std::fs::read_dir(service.path()) else {
+            continue;
+        };
+        for fixture in fixtures.flatten() {
+            if !used.contains(&fixture.path()) {
+                eprintln!(
+                    "warning: {} doesn't match a response",
+                    fixture.path().display()
+                );
+            }
+        }
+    }
+}
+
+/// Requests with at least this many fields get a builder type
+const BUILDER_MIN_INPUTS: usize = 4;
+
+/// Emits a builder for a request type. The builder starts from the
+/// `Default` of the request, so fields that are not set are `0`,
+/// empty, or the first allowed value of an enum
+fn emit_request_builder(
+    types: &mut String,
+    request_type_name: &str,
+    fields: &[(String, String, Option<&String>)],
+) {
+    let builder_name = format!("{request_type_name}Builder");
+
+    writeln!(
+        types,
+        "impl {request_type_name} {{
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> {builder_name} {{
+        {builder_name}::default()
+    }}
+}}
+
+/// Builds a `{request_type_name}`; see `{request_type_name}::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct {builder_name} {{
+    request: {request_type_name},
+}}
+
+impl {builder_name} {{"
+    )
+    .ok();
+
+    for (field_name, field_type, doc) in fields {
+        let (param_type, value) = match field_type.strip_prefix("Option<") {
+            Some(inner) => {
+                let inner = inner.strip_suffix('>').expect("balanced Option<>");
+                if inner == "String" {
+                    (
+                        "impl Into<String>".to_string(),
+                        format!("Some({field_name}.into())"),
+                    )
+                } else {
+                    (inner.to_string(), format!("Some({field_name})"))
+                }
+            }
+            None if field_type == "String" => (
+                "impl Into<String>".to_string(),
+                format!("{field_name}.into()"),
+            ),
+            None => (field_type.to_string(), field_name.to_string()),
+        };
+        if let Some(doc) = doc {
+            writeln!(types, "/// {doc}").ok();
+        }
+        writeln!(
+            types,
+            "pub fn {field_name}(mut self, {field_name}: {param_type}) -> Self {{
+        self.request.{field_name} = {value};
+        self
+    }}
+"
+        )
+        .ok();
+    }
+
+    writeln!(
+        types,
+        "pub fn build(self) -> {request_type_name} {{
+        self.request
+    }}
+}}
+"
+    )
+    .ok();
+}
+
+/// Returns the name of the cargo feature that enables the service
+/// whose module is named `service_module`, eg: `svc-av-transport`
+fn service_feature(service_module: &str) -> String {
+    format!("svc-{}", service_module.replace('_', "-"))
+}
+
+const FEATURES_BEGIN: &str = "# BEGIN service features";
+const FEATURES_END: &str = "# END service features";
+
+/// Rewrites the service features between the `FEATURES_BEGIN` and
+/// `FEATURES_END` markers in the manifest of the crate, so that there
+/// is a feature for each generated service, and an `all` feature that
+/// enables every one of them
+fn update_cargo_features(manifest: &Path, features: &[String]) {
+    let text = std::fs::read_to_string(manifest).unwrap();
+    let (head, rest) = text
+        .split_once(FEATURES_BEGIN)
+        .unwrap_or_else(|| panic!("{} has no {FEATURES_BEGIN:?} marker", manifest.display()));
+    let (_, tail) = rest
+        .split_once(FEATURES_END)
+        .unwrap_or_else(|| panic!("{} has no {FEATURES_END:?} marker", manifest.display()));
+
+    let mut block = String::new();
+    let all = features
+        .iter()
+        .map(|f| format!("\"{f}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(&mut block, "{FEATURES_BEGIN}").ok();
+    writeln!(&mut block, "all = [{all}]").ok();
+    for feature in features {
+        writeln!(&mut block, "{feature} = []").ok();
+    }
+    write!(&mut block, "{FEATURES_END}").ok();
+
+    std::fs::write(manifest, format!("{head}{block}{tail}")).unwrap();
+}
+
+/// Emits a newtype for an integer state variable with an allowed
+/// range, which validates values on construction
+fn emit_bounded_newtype(
+    types: &mut String,
+    type_name: &str,
+    data_type: &str,
+    range: AllowedValueRange,
+) {
+    let base = bounded_base_type(data_type).expect("checked by value_range");
+    let AllowedValueRange {
+        minimum, maximum, ..
+    } = range;
+    // Derive `Default` when it is in range, as clippy prefers
+    let (derive_default, default_impl) = match 0.clamp(minimum, maximum) {
+        0 => (", Default", String::new()),
+        default => (
+            "",
+            format!(
+                "impl Default for {type_name} {{
+    fn default() -> Self {{
+        Self({default})
+    }}
+}}
+"
+            ),
+        ),
+    };
+
+    writeln!(
+        types,
+        "/// A `{type_name}` value, in the range `{minimum}..={maximum}`.
+/// Values are checked when they are constructed via `new` or
+/// `try_from`, so that an out of range value is reported before
+/// any request is made. Values reported by the device, or read
+/// back via serde, are accepted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash{derive_default})]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct {type_name}({base});
+
+impl {type_name} {{
+    pub const MIN: {base} = {minimum};
+    pub const MAX: {base} = {maximum};
+
+    /// Returns `Error::OutOfRange` if `value` is outside
+    /// of the range `MIN..=MAX`
+    pub fn new(value: {base}) -> Result<Self> {{
+        if (Self::MIN..=Self::MAX).contains(&value) {{
+            Ok(Self(value))
+        }} else {{
+            Err(crate::Error::OutOfRange {{
+                name: \"{type_name}\",
+                value: value.into(),
+                min: Self::MIN.into(),
+                max: Self::MAX.into(),
+            }})
+        }}
+    }}
+
+    /// Returns the nearest value that is in range
+    pub fn saturating(value: {base}) -> Self {{
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }}
+
+    pub fn get(self) -> {base} {{
+        self.0
+    }}
+}}
+
+{default_impl}
+impl TryFrom<{base}> for {type_name} {{
+    type Error = crate::Error;
+    fn try_from(value: {base}) -> Result<Self> {{
+        Self::new(value)
+    }}
+}}
+
+impl From<{type_name}> for {base} {{
+    fn from(value: {type_name}) -> {base} {{
+        value.0
+    }}
+}}
+
+impl std::fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+        self.0.fmt(f)
+    }}
+}}
+
+impl FromStr for {type_name} {{
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<{type_name}> {{
+        let value: {base} = s.parse().map_err(|_| crate::Error::InvalidNumber {{
+            name: \"{type_name}\",
+            value: s.to_string(),
+        }})?;
+        Self::new(value)
+    }}
+}}
+
+impl instant_xml::ToXml for {type_name} {{
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {{
+        self.0.serialize(field, serializer)
+    }}
+
+    fn present(&self) -> bool {{
+        true
+    }}
+}}
+
+impl<'xml> instant_xml::FromXml<'xml> for {type_name} {{
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {{
+        match field {{
+            Some(field) => id == field,
+            None => false,
+        }}
+    }}
+
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {{
+        if into.is_some() {{
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }}
+
+        match deserializer.take_str()? {{
+            Some(value) => {{
+                let parsed: {base} = value.parse().map_err(|err| {{
+                    instant_xml::Error::Other(format!(
+                        \"invalid value for field {{field}}: {{value}}: {{err:#}}\"
+                    ))
+                }})?;
+                *into = Some({type_name}(parsed));
+                Ok(())
+            }}
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }}
+    }}
+
+    type Accumulator = Option<{type_name}>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}}
+"
+    )
+    .ok();
+}
+
+/// Emits an `Error` enum for the documented fault codes of a service
+fn emit_service_errors(types: &mut String, service_name: &str, errors: &[ErrorDocs]) {
+    let variants: Vec<(String, &ErrorDocs)> = errors
+        .iter()
+        .map(|e| (e.description.replace('\'', "").to_pascal_case(), e))
+        .collect();
+
+    writeln!(
+        types,
+        "/// Errors that are documented for the `{service_name}` service.
+        /// Use `Error::from_error` to obtain one from the error returned
+        /// by a method of this service.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Error {{"
+    )
+    .ok();
+    for (variant, e) in &variants {
+        writeln!(types, "/// {}: {}", e.code, e.description).ok();
+        writeln!(types, "{variant},").ok();
+    }
+    writeln!(
+        types,
+        "/// An error code that is not documented for this service
+        Other(u32),
+        }}\n"
+    )
+    .ok();
+
+    writeln!(types, "impl From<u32> for Error {{").ok();
+    writeln!(types, "fn from(code: u32) -> Self {{").ok();
+    writeln!(types, "match code {{").ok();
+    for (variant, e) in &variants {
+        writeln!(types, "{} => Self::{variant},", e.code).ok();
+    }
+    writeln!(types, "code => Self::Other(code),").ok();
+    writeln!(types, "}}\n}}\n}}\n").ok();
+
+    writeln!(types, "impl Error {{").ok();
+    writeln!(types, "pub fn code(&self) -> u32 {{").ok();
+    writeln!(types, "match self {{").ok();
+    for (variant, e) in &variants {
+        writeln!(types, "Self::{variant} => {},", e.code).ok();
+    }
+    writeln!(types, "Self::Other(code) => *code,").ok();
+    writeln!(types, "}}\n}}\n").ok();
+
+    writeln!(
+        types,
+        "pub fn description(&self) -> Option<&'static str> {{"
+    )
+    .ok();
+    writeln!(types, "match self {{").ok();
+    for (variant, e) in &variants {
+        writeln!(types, "Self::{variant} => Some({:?}),", e.description).ok();
+    }
+    writeln!(types, "Self::Other(_) => None,").ok();
+    writeln!(types, "}}\n}}\n").ok();
+
+    writeln!(
+        types,
+        "/// Returns the documented error if `err` is a fault
+        /// reported by this service
+        pub fn from_error(err: &crate::Error) -> Option<Self> {{
+            match err {{
+                crate::Error::Upnp(e) if e.service == SERVICE_TYPE => Some(e.code.into()),
+                _ => None,
+            }}
+        }}
+        }}\n"
+    )
+    .ok();
+}
+
+fn to_snake_case(s: &str) -> String {
+    // Fixup some special cases
+    let s = s
+        .replace("URIs", "Uris")
+        .replace("UUIDs", "Uuids")
+        .replace("IDs", "Ids");
+    let result = s.to_snake_case();
+    if result == "type" {
+        "type_".to_string()
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_remove_generated_files() {
+        let dir = std::env::temp_dir().join(format!("codegen-clean-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("models")).unwrap();
+        let generated = format!("{GENERATED_HEADER}pub struct A;\n");
+        std::fs::write(dir.join("queue.rs"), &generated).unwrap();
+        std::fs::write(dir.join("models").join("s14.rs"), &generated).unwrap();
+        std::fs::write(dir.join("lib.rs"), "pub struct Mine;\n").unwrap();
+
+        remove_generated_files(&dir);
+        assert!(!dir.join("queue.rs").exists());
+        assert!(!dir.join("models").exists());
+        assert!(dir.join("lib.rs").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_emit_model_traits() {
+        fn param(name: &str, models: &[&str]) -> VersionedParameter {
+            VersionedParameter {
+                param: Parameter {
+                    name: name.to_string(),
+                    direction: "in".to_string(),
+                    related_state_variable_name: name.to_string(),
+                },
+                supported_by: models.iter().map(|m| m.to_string()).collect(),
+                optional: models.len() < 2,
+            }
+        }
+        fn action(
+            name: &str,
+            inputs: Vec<VersionedParameter>,
+            outputs: Vec<VersionedParameter>,
+            models: &[&str],
+        ) -> (String, VersionedAction) {
+            (
+                name.to_string(),
+                VersionedAction {
+                    name: name.to_string(),
+                    inputs,
+                    outputs,
+                    supported_by: models.iter().map(|m| m.to_string()).collect(),
+                    legacy: false,
+                },
+            )
+        }
+        fn service(
+            name: &str,
+            actions: Vec<(String, VersionedAction)>,
+        ) -> (String, VersionedService) {
+            (
+                name.to_string(),
+                VersionedService {
+                    info: ServiceInfo {
+                        name: name.to_string(),
+                        service_name: name.to_string(),
+                        discovery_uri: String::new(),
+                        service_id: format!("urn:upnp-org:serviceId:{name}"),
+                        service_type: format!("urn:schemas-upnp-org:service:{name}:1"),
+                        control_url: format!("/{name}/Control"),
+                        event_sub_url: format!("/{name}/Event"),
+                        state_variables: vec![],
+                        actions: vec![],
+                    },
+                    state_variables: BTreeMap::new(),
+                    actions: actions.into_iter().collect(),
+                    type_overrides: vec![],
+                },
+            )
+        }
+
+        let services = [
+            service(
+                "RenderingControl",
+                vec![
+                    action(
+                        "GetVolume",
+                        vec![
+                            param("InstanceID", &["S1", "S14"]),
+                            param("Channel", &["S14"]),
+                        ],
+                        vec![param("CurrentVolume", &["S1", "S14"])],
+                        &["S1", "S14"],
+                    ),
+                    action("SetOutputFixed", vec![], vec![], &["S23"]),
+                ],
+            ),
+            // Nothing that the model supports, so no trait is emitted
+            service(
+                "HTControl",
+                vec![action("IsRemoteConfigured", vec![], vec![], &["S14"])],
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let info = ModelInfo {
+            model: "S1".to_string(),
+            model_description: "Play:1".to_string(),
+            software_generation: 2,
+            software_version: "79.1-56030".to_string(),
+            discovery_date: Default::default(),
+            services: vec![],
+        };
+
+        assert_eq!(
+            emit_model_traits(&info, &services),
+            r##"//! Service traits for the `S1` (Play:1), which only have the
+//! actions that it supports, so that using an action that it doesn't
+//! support is a compile error rather than a fault reported by the
+//! device. Import these in place of the traits in the prelude.
+
+#![cfg_attr(not(feature = "all"), allow(unused_imports))]
+
+use crate::{Result, SonosDevice};
+
+/// The actions of `crate::RenderingControl` that the `S1` supports
+#[cfg(feature = "svc-rendering-control")]
+#[allow(async_fn_in_trait)]
+pub trait RenderingControl {
+/// See `crate::RenderingControl::get_volume`
+///
+/// The `S1` doesn't accept `channel`, which should be left as `None`
+
+async fn get_volume(&self, request: crate::rendering_control::GetVolumeRequest) -> Result<crate::rendering_control::GetVolumeResponse>;
+
+}
+
+#[cfg(feature = "svc-rendering-control")]
+impl RenderingControl for SonosDevice {
+
+async fn get_volume(&self, request: crate::rendering_control::GetVolumeRequest) -> Result<crate::rendering_control::GetVolumeResponse> {
+    <Self as crate::RenderingControl>::get_volume(self, request).await
+}
+
+}
+
+"##
+        );
+    }
+
+    #[test]
+    fn test_rust_type() {
+        assert_eq!(rust_type("ui2"), Some("u16"));
+        assert_eq!(rust_type("r4"), Some("f32"));
+        assert_eq!(rust_type("float"), Some("f64"));
+        assert_eq!(rust_type("uri"), None);
     }
 }
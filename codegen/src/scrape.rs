@@ -0,0 +1,240 @@
+//! Captures the device description and service descriptions (SCPD)
+//! from a player on the network, and normalizes them into the
+//! `ModelInfo` form that is read from `data/devices`.
+
+use crate::schema::{Action, AllowedValueRange, ModelInfo, Parameter, ServiceInfo, StateVariable};
+use roxmltree::{Document, Node};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const SSDP_ADDR: &str = "239.255.255.250:1900";
+const ZONE_PLAYER: &str = "urn:schemas-upnp-org:device:ZonePlayer:1";
+const DEVICE_PORT: u16 = 1400;
+const DESCRIPTION_PATH: &str = "/xml/device_description.xml";
+
+#[derive(clap::Args, Debug)]
+pub struct ScrapeArgs {
+    /// The address of the player to scrape. When omitted, the first
+    /// player that responds to SSDP discovery is used.
+    #[arg(long)]
+    ip: Option<Ipv4Addr>,
+
+    /// How long to wait for a player to respond to discovery
+    #[arg(long, default_value = "5")]
+    timeout_secs: u64,
+
+    /// The directory to which `sonos-<model>-<generation>.json`
+    /// is written
+    #[arg(long, default_value = "data/devices")]
+    output: PathBuf,
+}
+
+pub fn scrape(args: &ScrapeArgs) {
+    let ip = match args.ip {
+        Some(ip) => ip,
+        None => discover(Duration::from_secs(args.timeout_secs))
+            .expect("no player responded to discovery; try passing --ip"),
+    };
+    println!("Scraping {ip}");
+
+    let info = fetch_model_info(ip);
+    let path = args.output.join(format!(
+        "sonos-{}-{}.json",
+        info.model, info.software_generation
+    ));
+    write_model_info(&path, &info);
+    println!(
+        "Wrote {} services of {} ({}) to {}",
+        info.services.len(),
+        info.model,
+        info.model_description,
+        path.display()
+    );
+}
+
+/// Sends an SSDP M-SEARCH for players, and returns the address of
+/// the first one to respond
+fn discover(timeout: Duration) -> Option<Ipv4Addr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+    let request = format!(
+        "M-SEARCH * HTTP/1.1\r\n\
+         HOST: {SSDP_ADDR}\r\n\
+         MAN: \"ssdp:discover\"\r\n\
+         MX: 1\r\n\
+         ST: {ZONE_PLAYER}\r\n\r\n"
+    );
+    socket.send_to(request.as_bytes(), SSDP_ADDR).unwrap();
+
+    let deadline = Instant::now() + timeout;
+    let mut buf = [0u8; 2048];
+    loop {
+        let remaining = deadline.checked_duration_since(Instant::now())?;
+        socket.set_read_timeout(Some(remaining)).unwrap();
+        let (len, from) = socket.recv_from(&mut buf).ok()?;
+        let response = String::from_utf8_lossy(&buf[..len]);
+        if response.contains(ZONE_PLAYER) {
+            if let SocketAddr::V4(from) = from {
+                return Some(*from.ip());
+            }
+        }
+    }
+}
+
+/// Performs an HTTP GET of `path` from the player at `ip`.
+/// HTTP/1.0 is used so that the response is never chunked.
+fn http_get(ip: Ipv4Addr, path: &str) -> String {
+    let mut stream = TcpStream::connect((ip, DEVICE_PORT)).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    write!(
+        stream,
+        "GET {path} HTTP/1.0\r\nHost: {ip}:{DEVICE_PORT}\r\n\r\n"
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .unwrap_or_else(|| panic!("malformed response to GET {path}"));
+    let status = head.lines().next().unwrap_or("");
+    assert!(
+        status.split_whitespace().nth(1) == Some("200"),
+        "GET {path}: {status}"
+    );
+    body.to_string()
+}
+
+fn fetch_model_info(ip: Ipv4Addr) -> ModelInfo {
+    let xml = http_get(ip, DESCRIPTION_PATH);
+    let doc = Document::parse(&xml).unwrap();
+    let root_device = doc
+        .descendants()
+        .find(|n| n.has_tag_name("device"))
+        .expect("device description has no device");
+
+    // The services of the root device and of the embedded media
+    // server and media renderer devices are listed together. Where
+    // a service appears in more than one device, the last is used,
+    // which is consistent with the captured data.
+    let mut services = BTreeMap::new();
+    for service in doc.descendants().filter(|n| n.has_tag_name("service")) {
+        let service = parse_service(ip, service);
+        services.insert(service.name.to_lowercase(), service);
+    }
+
+    ModelInfo {
+        model: child_text(root_device, "modelNumber"),
+        model_description: child_text(root_device, "modelName"),
+        software_generation: child_text(root_device, "swGen").parse().unwrap_or(1),
+        software_version: child_text(root_device, "softwareVersion"),
+        discovery_date: chrono::Utc::now(),
+        services: services.into_values().collect(),
+    }
+}
+
+fn parse_service(ip: Ipv4Addr, service: Node) -> ServiceInfo {
+    let service_id = child_text(service, "serviceId");
+    let name = service_id
+        .rsplit(':')
+        .next()
+        .unwrap_or(&service_id)
+        .to_string();
+    let discovery_uri = child_text(service, "SCPDURL");
+    println!("Service {name}");
+
+    let scpd = http_get(ip, &discovery_uri);
+    let scpd = Document::parse(&scpd).unwrap();
+
+    let state_variables = scpd
+        .descendants()
+        .filter(|n| n.has_tag_name("stateVariable"))
+        .map(parse_state_variable)
+        .collect();
+    let actions = scpd
+        .descendants()
+        .filter(|n| n.has_tag_name("action"))
+        .map(parse_action)
+        .collect();
+
+    ServiceInfo {
+        service_name: format!("{name}Service"),
+        name,
+        discovery_uri,
+        service_id,
+        service_type: child_text(service, "serviceType"),
+        control_url: child_text(service, "controlURL"),
+        event_sub_url: child_text(service, "eventSubURL"),
+        state_variables,
+        actions,
+    }
+}
+
+fn parse_state_variable(node: Node) -> StateVariable {
+    let allowed_values: Vec<Value> = node
+        .descendants()
+        .filter(|n| n.has_tag_name("allowedValue"))
+        .filter_map(|n| n.text())
+        .map(|v| Value::String(v.to_string()))
+        .collect();
+    let allowed_value_range = node
+        .children()
+        .find(|n| n.has_tag_name("allowedValueRange"))
+        .map(|range| AllowedValueRange {
+            minimum: child_text(range, "minimum").parse().unwrap(),
+            maximum: child_text(range, "maximum").parse().unwrap(),
+            step: child_text(range, "step").parse().ok(),
+        });
+
+    StateVariable {
+        name: child_text(node, "name"),
+        data_type: child_text(node, "dataType"),
+        send_events: node.attribute("sendEvents") == Some("yes"),
+        allowed_values: (!allowed_values.is_empty()).then_some(Value::Array(allowed_values)),
+        allowed_value_range,
+    }
+}
+
+fn parse_action(node: Node) -> Action {
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+    for arg in node.descendants().filter(|n| n.has_tag_name("argument")) {
+        let param = Parameter {
+            name: child_text(arg, "name"),
+            direction: child_text(arg, "direction"),
+            related_state_variable_name: child_text(arg, "relatedStateVariable"),
+        };
+        if param.direction == "in" {
+            inputs.push(param);
+        } else {
+            outputs.push(param);
+        }
+    }
+    Action {
+        name: child_text(node, "name"),
+        inputs,
+        outputs,
+    }
+}
+
+/// Returns the trimmed text of the first child element of `node`
+/// named `name`, or an empty string if there is no such child
+fn child_text(node: Node, name: &str) -> String {
+    node.children()
+        .find(|n| n.has_tag_name(name))
+        .and_then(|n| n.text())
+        .unwrap_or("")
+        .trim()
+        .to_string()
+}
+
+fn write_model_info(path: &Path, info: &ModelInfo) {
+    let mut json = serde_json::to_string_pretty(info).unwrap();
+    json.push('\n');
+    std::fs::write(path, json).unwrap();
+}
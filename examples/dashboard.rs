@@ -0,0 +1,338 @@
+//! A terminal dashboard that shows each room, what it is playing,
+//! its volume and the group that it belongs to.
+//!
+//! The display is updated only from UPnP events: the initial state
+//! comes from the first event of each subscription, and the keys
+//! below perform actions whose effect is shown once the players
+//! report it. Running it against a busy household is a good way to
+//! exercise the event subscriptions of every player at once.
+//!
+//! Keys: up/down select a room, `+`/`-` adjust its volume,
+//! space toggles between play and pause, and `q` quits.
+//!
+//! Run it with `cargo run --example dashboard --features dashboard`.
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Gauge, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use sonos::av_transport::AVTransportEvent;
+use sonos::rendering_control::RenderingControlEvent;
+use sonos::zone_group_topology::ZoneGroupTopologyEvent;
+use sonos::{SonosDevice, SonosSystem, TrackMetaData, TransportState, Volume, ZoneGroup};
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+enum Update {
+    Transport(usize, AVTransportEvent),
+    Rendering(usize, RenderingControlEvent),
+    Topology(Box<ZoneGroupTopologyEvent>),
+    Key(KeyCode),
+    Error(String),
+}
+
+struct Room {
+    device: SonosDevice,
+    name: String,
+    state: Option<TransportState>,
+    track: Option<TrackMetaData>,
+    volume: Option<Volume>,
+    muted: bool,
+}
+
+struct Dashboard {
+    rooms: Vec<Room>,
+    groups: Vec<ZoneGroup>,
+    selected: usize,
+    status: String,
+    tx: UnboundedSender<Update>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let system = SonosSystem::discover(Duration::from_secs(5)).await?;
+    let (tx, rx) = unbounded_channel();
+
+    let mut rooms = vec![];
+    for device in system.devices() {
+        rooms.push(Room {
+            device: device.clone(),
+            name: device.name().await?,
+            state: None,
+            track: None,
+            volume: None,
+            muted: false,
+        });
+    }
+    rooms.sort_by(|a, b| a.name.cmp(&b.name));
+    for (idx, room) in rooms.iter().enumerate() {
+        subscribe(idx, &room.device, &tx).await?;
+    }
+
+    if let Some(device) = system.devices().first() {
+        let mut events = device.subscribe_zone_group_topology().await?;
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                if tx.send(Update::Topology(Box::new(event))).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    let keys = tx.clone();
+    std::thread::spawn(move || loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                if keys.send(Update::Key(key.code)).is_err() {
+                    break;
+                }
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    });
+
+    let mut dashboard = Dashboard {
+        rooms,
+        groups: vec![],
+        selected: 0,
+        status: String::new(),
+        tx,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = dashboard.run(&mut terminal, rx).await;
+    ratatui::restore();
+    result
+}
+
+/// Forwards the transport and rendering events of `device` to `tx`,
+/// tagged with `idx`, the index of its room
+async fn subscribe(
+    idx: usize,
+    device: &SonosDevice,
+    tx: &UnboundedSender<Update>,
+) -> sonos::Result<()> {
+    let mut transport = device.subscribe_av_transport().await?;
+    let mut rendering = device.subscribe_rendering_control().await?;
+
+    let tx_transport = tx.clone();
+    tokio::spawn(async move {
+        while let Some(event) = transport.recv().await {
+            if tx_transport.send(Update::Transport(idx, event)).is_err() {
+                break;
+            }
+        }
+    });
+
+    let tx_rendering = tx.clone();
+    tokio::spawn(async move {
+        while let Some(event) = rendering.recv().await {
+            if tx_rendering.send(Update::Rendering(idx, event)).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+impl Dashboard {
+    async fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        mut rx: UnboundedReceiver<Update>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        terminal.draw(|frame| self.draw(frame))?;
+        while let Some(update) = rx.recv().await {
+            match update {
+                Update::Key(KeyCode::Char('q') | KeyCode::Esc) => break,
+                Update::Key(key) => self.key(key),
+                Update::Transport(idx, event) => self.transport(idx, event),
+                Update::Rendering(idx, event) => self.rendering(idx, event),
+                Update::Topology(event) => {
+                    if let Some(state) = event.zone_group_state.and_then(|s| s.into_inner()) {
+                        self.groups = state.groups;
+                    }
+                }
+                Update::Error(error) => self.status = error,
+            }
+            terminal.draw(|frame| self.draw(frame))?;
+        }
+        Ok(())
+    }
+
+    fn transport(&mut self, idx: usize, event: AVTransportEvent) {
+        let Some(change) = event.last_change.and_then(|c| c.into_inner()) else {
+            return;
+        };
+        let Some(room) = self.rooms.get_mut(idx) else {
+            return;
+        };
+        for change in change.map.into_values() {
+            if let Some(state) = change.transport_state {
+                room.state.replace(state);
+            }
+            if let Some(track) = change.current_track_meta_data {
+                room.track = track.into_inner();
+            }
+        }
+    }
+
+    fn rendering(&mut self, idx: usize, event: RenderingControlEvent) {
+        let Some(change) = event.last_change.and_then(|c| c.into_inner()) else {
+            return;
+        };
+        let Some(room) = self.rooms.get_mut(idx) else {
+            return;
+        };
+        for change in change.map.into_values() {
+            if let Some(volume) = change.volume {
+                room.volume.replace(volume);
+            }
+            if let Some(muted) = change.mute {
+                room.muted = muted;
+            }
+        }
+    }
+
+    fn key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.rooms.len().saturating_sub(1))
+            }
+            KeyCode::Char('+') => {
+                self.perform(|device| async move { device.adjust_volume(5).await.map(|_| ()) })
+            }
+            KeyCode::Char('-') => {
+                self.perform(|device| async move { device.adjust_volume(-5).await.map(|_| ()) })
+            }
+            KeyCode::Char(' ') => {
+                let playing = self
+                    .rooms
+                    .get(self.selected)
+                    .is_some_and(|room| room.state == Some(TransportState::Playing));
+                self.perform(move |device| async move {
+                    if playing {
+                        device.pause().await
+                    } else {
+                        device.play().await
+                    }
+                })
+            }
+            _ => {}
+        }
+    }
+
+    /// Runs `action` against the selected room in the background.
+    /// Its effect is picked up from the resulting events, so only
+    /// failures are reported back.
+    fn perform<F, Fut>(&self, action: F)
+    where
+        F: FnOnce(SonosDevice) -> Fut,
+        Fut: std::future::Future<Output = sonos::Result<()>> + Send + 'static,
+    {
+        let Some(room) = self.rooms.get(self.selected) else {
+            return;
+        };
+        let future = action(room.device.clone());
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            if let Err(err) = future.await {
+                tx.send(Update::Error(format!("{err:#}"))).ok();
+            }
+        });
+    }
+
+    /// Describes the group that `room` belongs to, if it is
+    /// grouped with any other room
+    fn group_label(&self, room: &Room) -> Option<String> {
+        let group = self
+            .groups
+            .iter()
+            .find(|group| group.members.iter().any(|m| m.zone_name == room.name))?;
+        if group.members.len() < 2 {
+            return None;
+        }
+        let coordinator = group
+            .members
+            .iter()
+            .find(|m| m.uuid == group.coordinator)
+            .map(|m| m.zone_name.as_str())
+            .unwrap_or("?");
+        if coordinator == room.name {
+            Some(format!("leads {} rooms", group.members.len()))
+        } else {
+            Some(format!("grouped with {coordinator}"))
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [rooms_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let areas =
+            Layout::vertical(self.rooms.iter().map(|_| Constraint::Length(4))).split(rooms_area);
+        for (idx, (room, area)) in self.rooms.iter().zip(areas.iter()).enumerate() {
+            self.draw_room(frame, room, *area, idx == self.selected);
+        }
+
+        let status = if self.status.is_empty() {
+            "↑/↓ select  +/- volume  space play/pause  q quit"
+        } else {
+            &self.status
+        };
+        frame.render_widget(Paragraph::new(status), status_area);
+    }
+
+    fn draw_room(&self, frame: &mut Frame, room: &Room, area: Rect, selected: bool) {
+        let mut title = room.name.clone();
+        if let Some(group) = self.group_label(room) {
+            title.push_str(&format!(" ({group})"));
+        }
+        let border = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        let block = Block::bordered().title(title).border_style(border);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [playing_area, volume_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(inner);
+
+        let state = room
+            .state
+            .as_ref()
+            .map(|state| state.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let track = match &room.track {
+            Some(track) => match &track.creator {
+                Some(creator) => format!("{} - {creator}", track.title),
+                None => track.title.clone(),
+            },
+            None => String::new(),
+        };
+        frame.render_widget(Paragraph::new(format!("{state} {track}")), playing_area);
+
+        let volume = room.volume.map(u16::from).unwrap_or(0);
+        let label = if room.muted {
+            format!("{volume} (muted)")
+        } else {
+            volume.to_string()
+        };
+        let gauge = Gauge::default()
+            .percent(volume.min(100))
+            .label(label)
+            .gauge_style(Style::default().fg(if room.muted {
+                Color::DarkGray
+            } else {
+                Color::Green
+            }));
+        frame.render_widget(gauge, volume_area);
+    }
+}
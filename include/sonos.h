@@ -0,0 +1,163 @@
+#ifndef SONOS_H
+#define SONOS_H
+
+/* This file is generated by cbindgen from src/ffi.rs. Do not edit it by hand; run `make header` instead. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// The outcome of a call
+typedef enum SonosStatus {
+  SONOS_STATUS_OK = 0,
+  // A pointer was null, or a string was not valid
+  SONOS_STATUS_INVALID_ARGUMENT = 1,
+  // A value was outside of the range accepted by the device
+  SONOS_STATUS_OUT_OF_RANGE = 2,
+  // The device could not be reached
+  SONOS_STATUS_NETWORK = 3,
+  // The device rejected the request
+  SONOS_STATUS_DEVICE = 4,
+  // The library panicked; this is a bug
+  SONOS_STATUS_PANIC = 5,
+  SONOS_STATUS_FAILED = 6,
+} SonosStatus;
+
+// A handle to a player
+typedef struct SonosDevice SonosDevice;
+
+// The players found by `sonos_discover`
+typedef struct SonosDeviceList SonosDeviceList;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns a description of the last failure on the calling thread,
+// or NULL if there hasn't been one. The string remains valid until
+// the next failing call on the same thread, and must not be freed.
+const char *sonos_last_error_message(void);
+
+// Releases a string returned by the library
+//
+// # Safety
+// `s` must be null or a string returned by the library that has
+// not already been freed
+void sonos_string_free(char *s);
+
+// Discovers the players on the network, waiting up to
+// `timeout_ms` milliseconds for them to respond
+//
+// # Safety
+// `out` must be valid for writes
+SonosStatus sonos_discover(uint32_t timeout_ms, SonosDeviceList **out);
+
+// Returns the number of players in `list`, or 0 if it is null
+//
+// # Safety
+// `list` must be null or a live list returned by `sonos_discover`
+size_t sonos_device_list_len(const SonosDeviceList *list);
+
+// Returns a new handle to the player at `index` in `list`, which
+// must be released with `sonos_device_free`, or NULL if `index`
+// is out of range
+//
+// # Safety
+// `list` must be null or a live list returned by `sonos_discover`
+SonosDevice *sonos_device_list_get(const SonosDeviceList *list, size_t index);
+
+// Releases `list`. Handles obtained from it remain valid.
+//
+// # Safety
+// `list` must be null or a list returned by `sonos_discover` that
+// has not already been freed
+void sonos_device_list_free(SonosDeviceList *list);
+
+// Connects to the player at `ip`, a dotted IPv4 address
+//
+// # Safety
+// `ip` must be a NUL terminated string and `out` must be valid
+// for writes
+SonosStatus sonos_device_from_ip(const char *ip, SonosDevice **out);
+
+// Releases `device`
+//
+// # Safety
+// `device` must be null or a handle returned by the library that
+// has not already been freed
+void sonos_device_free(SonosDevice *device);
+
+// Returns the room name of `device` through `out`, which must be
+// released with `sonos_string_free`
+//
+// # Safety
+// `device` must be a live handle and `out` must be valid for writes
+SonosStatus sonos_device_name(const SonosDevice *device, char **out);
+
+// Begins playback
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_play(const SonosDevice *device);
+
+// Pauses playback
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_pause(const SonosDevice *device);
+
+// Stops playback
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_stop(const SonosDevice *device);
+
+// Skips to the next track
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_next(const SonosDevice *device);
+
+// Skips to the previous track
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_previous(const SonosDevice *device);
+
+// Returns the volume of `device`, in the range 0-100, through `out`
+//
+// # Safety
+// `device` must be a live handle and `out` must be valid for writes
+SonosStatus sonos_device_get_volume(const SonosDevice *device, uint16_t *out);
+
+// Sets the volume of `device`, in the range 0-100
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_set_volume(const SonosDevice *device, uint16_t volume);
+
+// Adjusts the volume of `device` by `delta`. The new volume is
+// written through `out`, unless it is NULL.
+//
+// # Safety
+// `device` must be a live handle and `out` must be null or valid
+// for writes
+SonosStatus sonos_device_adjust_volume(const SonosDevice *device, int16_t delta, uint16_t *out);
+
+// Returns the mute state of `device` through `out`
+//
+// # Safety
+// `device` must be a live handle and `out` must be valid for writes
+SonosStatus sonos_device_get_mute(const SonosDevice *device, bool *out);
+
+// Sets the mute state of `device`
+//
+// # Safety
+// `device` must be a live handle
+SonosStatus sonos_device_set_mute(const SonosDevice *device, bool mute);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SONOS_H */
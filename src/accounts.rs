@@ -0,0 +1,231 @@
+use crate::redact::{Masked, Redacted};
+use crate::{system_properties, Error, HttpRequest, Result, SonosDevice, SystemProperties};
+use instant_xml::FromXml;
+
+/// A music service account that has been configured in the household.
+/// The username is masked in the `Debug` representation.
+#[derive(Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicServiceAccount {
+    /// The service type; see `MusicService::service_type`
+    pub service_type: u32,
+    /// The serial number of the account, which is the `sn=` value
+    /// used in URIs that refer to content from this account
+    pub serial_number: u32,
+    pub username: Option<String>,
+    pub nickname: Option<String>,
+    /// The SMAPI token and key of a linked account; see
+    /// `SonosDevice::service_token`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) oa_device_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) key: Option<String>,
+}
+
+impl std::fmt::Debug for MusicServiceAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("MusicServiceAccount")
+            .field("service_type", &self.service_type)
+            .field("serial_number", &self.serial_number)
+            .field("username", &self.username.as_deref().map(Masked))
+            .field("nickname", &self.nickname)
+            .field(
+                "oa_device_id",
+                &self.oa_device_id.as_ref().map(|_| Redacted),
+            )
+            .field("key", &self.key.as_ref().map(|_| Redacted))
+            .finish()
+    }
+}
+
+impl MusicServiceAccount {
+    /// The id of the music service that this account belongs to
+    pub fn service_id(&self) -> u32 {
+        self.service_type.saturating_sub(7) / 256
+    }
+}
+
+impl SonosDevice {
+    /// Returns the music service accounts that are configured in the
+    /// household. Accounts that have been deleted are excluded.
+    pub async fn accounts(&self) -> Result<Vec<MusicServiceAccount>> {
+        self.identify_errors(async {
+            let url = self.shared.url.join("/status/accounts")?;
+            let response = self
+                .shared
+                .client
+                .send(HttpRequest::get(url))
+                .await?
+                .check()?;
+            parse_accounts(&response.body)
+        })
+        .await
+    }
+
+    /// Returns the first account configured for the music service
+    /// identified by `service_id`, if any
+    pub async fn account_for_service(
+        &self,
+        service_id: u32,
+    ) -> Result<Option<MusicServiceAccount>> {
+        Ok(self
+            .accounts()
+            .await?
+            .into_iter()
+            .find(|account| account.service_id() == service_id))
+    }
+
+    /// Reads a SystemProperties string variable, returning `Ok(None)`
+    /// if it is not set
+    pub async fn system_string(&self, variable_name: &str) -> Result<Option<String>> {
+        self.identify_errors(async {
+            let result = <Self as SystemProperties>::get_string(
+                self,
+                system_properties::GetStringRequest {
+                    variable_name: variable_name.to_string(),
+                },
+            )
+            .await;
+            match result {
+                Ok(response) => Ok(response.string_value),
+                // The device reports a SOAP fault for unknown variables
+                Err(err) if err.is_soap_fault() => Ok(None),
+                Err(err) => Err(err),
+            }
+        })
+        .await
+    }
+
+    /// Sets a SystemProperties string variable
+    pub async fn set_system_string(&self, variable_name: &str, value: &str) -> Result<()> {
+        <Self as SystemProperties>::set_string(
+            self,
+            system_properties::SetStringRequest {
+                variable_name: variable_name.to_string(),
+                string_value: value.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Removes a SystemProperties string variable
+    pub async fn remove_system_string(&self, variable_name: &str) -> Result<()> {
+        <Self as SystemProperties>::remove(
+            self,
+            system_properties::RemoveRequest {
+                variable_name: variable_name.to_string(),
+            },
+        )
+        .await
+    }
+}
+
+fn parse_accounts(xml: &str) -> Result<Vec<MusicServiceAccount>> {
+    let info: ZpSupportInfo = instant_xml::from_str(xml).map_err(|error| Error::XmlParse {
+        error,
+        text: xml.to_string(),
+    })?;
+    Ok(info
+        .accounts
+        .map(|a| a.accounts)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|account| account.deleted.as_deref() != Some("1"))
+        .map(|account| MusicServiceAccount {
+            service_type: account.service_type,
+            serial_number: account.serial_number,
+            username: account.username.map(|u| u.value),
+            nickname: account.nickname.map(|n| n.value),
+            oa_device_id: account.oa_device_id.map(|v| v.value),
+            key: account.key.map(|k| k.value),
+        })
+        .collect())
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "ZPSupportInfo")]
+struct ZpSupportInfo {
+    accounts: Option<Accounts>,
+}
+
+#[derive(Debug, FromXml)]
+struct Accounts {
+    accounts: Vec<Account>,
+}
+
+#[derive(Debug, FromXml)]
+struct Account {
+    #[xml(rename = "Type", attribute)]
+    service_type: u32,
+    #[xml(rename = "SerialNum", attribute)]
+    serial_number: u32,
+    #[xml(rename = "Deleted", attribute)]
+    deleted: Option<String>,
+    username: Option<UserName>,
+    nickname: Option<NickName>,
+    oa_device_id: Option<OaDevId>,
+    key: Option<Key>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "UN")]
+struct UserName {
+    #[xml(direct)]
+    value: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "NN")]
+struct NickName {
+    #[xml(direct)]
+    value: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "OADevID")]
+struct OaDevId {
+    #[xml(direct)]
+    value: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "Key")]
+struct Key {
+    #[xml(direct)]
+    value: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_accounts() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><ZPSupportInfo type="User"><Accounts LastUpdateDevice="RINCON_000E58000000001400" Version="8" NextSerialNum="5"><Account Type="2311" SerialNum="1"><UN>12345678</UN><MD>1</MD><NN>Spotify</NN><OADevID></OADevID><Key></Key></Account><Account Type="519" SerialNum="2" Deleted="1"><UN></UN><MD>1</MD><NN></NN><OADevID></OADevID><Key></Key></Account><Account Type="65031" SerialNum="0"><UN></UN><MD>1</MD><NN></NN><OADevID></OADevID><Key></Key></Account></Accounts></ZPSupportInfo>"#;
+
+        let accounts = parse_accounts(xml).unwrap();
+        assert_eq!(
+            accounts,
+            vec![
+                MusicServiceAccount {
+                    service_type: 2311,
+                    serial_number: 1,
+                    username: Some("12345678".to_string()),
+                    nickname: Some("Spotify".to_string()),
+                    oa_device_id: Some(String::new()),
+                    key: Some(String::new()),
+                },
+                MusicServiceAccount {
+                    service_type: 65031,
+                    serial_number: 0,
+                    username: Some(String::new()),
+                    nickname: Some(String::new()),
+                    oa_device_id: Some(String::new()),
+                    key: Some(String::new()),
+                },
+            ]
+        );
+        assert_eq!(accounts[0].service_id(), 9);
+        assert_eq!(accounts[1].service_id(), 254);
+    }
+}
@@ -0,0 +1,594 @@
+use crate::{
+    alarm_clock, av_transport, duration_to_hms, hms_to_duration, AVTransport, AlarmClock,
+    AlarmPlayMode, Error, InstanceId, Recurrence, Result, SonosDevice, Volume,
+};
+use instant_xml::FromXml;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The program URI that plays the built-in alarm chime
+pub const ALARM_BUZZER_URI: &str = "x-rincon-buzzer:0";
+
+/// A day of the week, numbered as in the `ON_` recurrence strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Weekday {
+    Sunday = 0,
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+}
+
+impl Weekday {
+    pub(crate) const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    fn from_digit(c: char) -> Option<Self> {
+        let n = c.to_digit(10)? as usize;
+        Self::ALL.get(n).copied()
+    }
+}
+
+/// When an alarm repeats
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AlarmRecurrence {
+    /// The alarm sounds once and is then disabled
+    Once,
+    #[default]
+    Daily,
+    /// Monday through Friday
+    Weekdays,
+    /// Saturday and Sunday
+    Weekends,
+    /// The specified days of the week
+    On(Vec<Weekday>),
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+impl AlarmRecurrence {
+    /// Returns true if the recurrence includes `day`.
+    /// `Once` is considered to include every day, and an
+    /// `Unspecified` recurrence to include none.
+    pub fn includes(&self, day: Weekday) -> bool {
+        match self {
+            Self::Once | Self::Daily => true,
+            Self::Weekdays => !matches!(day, Weekday::Saturday | Weekday::Sunday),
+            Self::Weekends => matches!(day, Weekday::Saturday | Weekday::Sunday),
+            Self::On(days) => days.contains(&day),
+            Self::Unspecified(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for AlarmRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Once => write!(f, "ONCE"),
+            Self::Daily => write!(f, "DAILY"),
+            Self::Weekdays => write!(f, "WEEKDAYS"),
+            Self::Weekends => write!(f, "WEEKENDS"),
+            Self::On(days) => {
+                let mut days = days.clone();
+                days.sort();
+                days.dedup();
+                write!(f, "ON_")?;
+                for day in days {
+                    write!(f, "{}", day as u8)?;
+                }
+                Ok(())
+            }
+            Self::Unspecified(s) => f.write_str(s),
+        }
+    }
+}
+
+impl FromStr for AlarmRecurrence {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ONCE" => Ok(Self::Once),
+            "DAILY" => Ok(Self::Daily),
+            "WEEKDAYS" => Ok(Self::Weekdays),
+            "WEEKENDS" => Ok(Self::Weekends),
+            _ => {
+                let days = s.strip_prefix("ON_").and_then(|digits| {
+                    digits
+                        .chars()
+                        .map(Weekday::from_digit)
+                        .collect::<Option<Vec<_>>>()
+                });
+                Ok(match days {
+                    Some(days) => Self::On(days),
+                    None => Self::Unspecified(s.to_string()),
+                })
+            }
+        }
+    }
+}
+
+impl From<&AlarmRecurrence> for Recurrence {
+    fn from(recurrence: &AlarmRecurrence) -> Recurrence {
+        match recurrence {
+            AlarmRecurrence::Once => Recurrence::Once,
+            AlarmRecurrence::Daily => Recurrence::Daily,
+            AlarmRecurrence::Weekdays => Recurrence::Weekdays,
+            AlarmRecurrence::Weekends => Recurrence::Weekends,
+            AlarmRecurrence::On(_) | AlarmRecurrence::Unspecified(_) => {
+                Recurrence::Unspecified(recurrence.to_string())
+            }
+        }
+    }
+}
+
+/// A time of day, in the local time of the household
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LocalTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl LocalTime {
+    /// Returns `Error::InvalidTime` if the time is not valid
+    pub fn new(hour: u8, minute: u8, second: u8) -> Result<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(Error::InvalidTime(format!(
+                "{hour:02}:{minute:02}:{second:02}"
+            )));
+        }
+        Ok(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+}
+
+impl fmt::Display for LocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for LocalTime {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut fields = s.split(':').map(|field| field.parse::<u8>().ok());
+        match (fields.next(), fields.next(), fields.next(), fields.next()) {
+            (Some(Some(hour)), Some(Some(minute)), second, None) => {
+                let second = match second {
+                    None => 0,
+                    Some(Some(second)) => second,
+                    Some(None) => return Err(Error::InvalidTime(s.to_string())),
+                };
+                Self::new(hour, minute, second)
+            }
+            _ => Err(Error::InvalidTime(s.to_string())),
+        }
+    }
+}
+
+/// An alarm, as stored on the device.
+/// Alarms are shared by all players in the household.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alarm {
+    /// The id assigned by the device when the alarm was created
+    pub id: u32,
+    /// The local time at which the alarm starts
+    pub start_time: LocalTime,
+    /// How long the alarm plays before stopping
+    #[cfg_attr(feature = "serde", serde(with = "crate::alarm_json::hms"))]
+    pub duration: Duration,
+    pub recurrence: AlarmRecurrence,
+    pub enabled: bool,
+    /// The UUID of the room in which the alarm sounds
+    pub room_uuid: String,
+    /// The URI to play; `ALARM_BUZZER_URI` plays the alarm chime
+    pub program_uri: String,
+    /// The DIDL-Lite metadata that accompanies `program_uri`
+    pub program_metadata: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::alarm_json::play_mode"))]
+    pub play_mode: AlarmPlayMode,
+    pub volume: Volume,
+    /// Whether the rooms that are grouped with `room_uuid` when
+    /// the alarm starts should also play it
+    pub include_linked_zones: bool,
+}
+
+impl Default for Alarm {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            start_time: LocalTime {
+                hour: 7,
+                minute: 0,
+                second: 0,
+            },
+            duration: Duration::from_secs(3600),
+            recurrence: AlarmRecurrence::Daily,
+            enabled: true,
+            room_uuid: String::new(),
+            program_uri: ALARM_BUZZER_URI.to_string(),
+            program_metadata: String::new(),
+            play_mode: AlarmPlayMode::ShuffleNorepeat,
+            volume: Volume::saturating(20),
+            include_linked_zones: false,
+        }
+    }
+}
+
+/// A change to the alarms of a household, as computed by
+/// `plan_alarm_changes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlarmChange {
+    Create(Alarm),
+    Update(Alarm),
+    Destroy(u32),
+}
+
+/// Computes the changes needed to make `existing` match `desired`.
+/// An alarm in `desired` matches an existing alarm with the same id,
+/// or failing that, one with identical settings, so that applying
+/// the same set of alarms more than once makes no further changes,
+/// even when it is applied to a different household.
+pub fn plan_alarm_changes(existing: &[Alarm], desired: &[Alarm]) -> Vec<AlarmChange> {
+    let mut unmatched: Vec<&Alarm> = existing.iter().collect();
+    let mut changes = vec![];
+
+    for alarm in desired {
+        if let Some(idx) = unmatched.iter().position(|e| e.id == alarm.id) {
+            if unmatched.remove(idx) != alarm {
+                changes.push(AlarmChange::Update(alarm.clone()));
+            }
+        } else if let Some(idx) = unmatched.iter().position(|e| {
+            let mut e = (*e).clone();
+            e.id = alarm.id;
+            e == *alarm
+        }) {
+            unmatched.remove(idx);
+        } else {
+            changes.push(AlarmChange::Create(alarm.clone()));
+        }
+    }
+
+    changes.extend(unmatched.into_iter().map(|e| AlarmChange::Destroy(e.id)));
+    changes
+}
+
+/// Describes an alarm that is currently sounding
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunningAlarm {
+    pub alarm_id: u32,
+    /// The id of the group that is playing the alarm
+    pub group_id: Option<String>,
+    /// The time at which the alarm started
+    pub logged_start_time: Option<String>,
+}
+
+impl SonosDevice {
+    /// Returns the alarm that is currently sounding on the group
+    /// that this device belongs to, if any
+    pub async fn running_alarm(&self) -> Result<Option<RunningAlarm>> {
+        let response = <Self as AVTransport>::get_running_alarm_properties(
+            self,
+            av_transport::GetRunningAlarmPropertiesRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?;
+        Ok(response
+            .alarm_id
+            .filter(|&id| id != 0)
+            .map(|alarm_id| RunningAlarm {
+                alarm_id,
+                group_id: response.group_id.filter(|id| !id.is_empty()),
+                logged_start_time: response.logged_start_time.filter(|t| !t.is_empty()),
+            }))
+    }
+
+    /// Returns true if an alarm is currently sounding
+    pub async fn is_alarm_running(&self) -> Result<bool> {
+        Ok(self.running_alarm().await?.is_some())
+    }
+
+    /// Snoozes the alarm that is currently sounding, silencing it
+    /// for `duration`, eg: `Duration::from_secs(600)` for 10 minutes.
+    /// This must be sent to the coordinator of the group.
+    pub async fn snooze_alarm(&self, duration: Duration) -> Result<()> {
+        <Self as AVTransport>::snooze_alarm(
+            self,
+            av_transport::SnoozeAlarmRequest {
+                instance_id: InstanceId::DEFAULT,
+                duration: duration_to_hms(duration),
+            },
+        )
+        .await
+    }
+
+    /// Returns the alarms that are configured in the household
+    pub async fn alarms(&self) -> Result<Vec<Alarm>> {
+        let response = <Self as AlarmClock>::list_alarms(self).await?;
+        match response.current_alarm_list {
+            Some(xml) => parse_alarms(&xml),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Creates a new alarm, returning the id that was assigned to it.
+    /// The `id` field of `alarm` is ignored.
+    pub async fn create_alarm(&self, alarm: &Alarm) -> Result<u32> {
+        <Self as AlarmClock>::create_alarm(
+            self,
+            alarm_clock::CreateAlarmRequest {
+                start_local_time: alarm.start_time.to_string(),
+                duration: duration_to_hms(alarm.duration),
+                recurrence: (&alarm.recurrence).into(),
+                enabled: alarm.enabled,
+                room_uuid: alarm.room_uuid.to_string(),
+                program_uri: alarm.program_uri.to_string(),
+                program_meta_data: alarm.program_metadata.to_string(),
+                play_mode: alarm.play_mode.clone(),
+                volume: alarm.volume.get(),
+                include_linked_zones: alarm.include_linked_zones,
+            },
+        )
+        .await?
+        .assigned_id
+        .ok_or(Error::MissingValue("AssignedID"))
+    }
+
+    /// Replaces the alarm whose id matches `alarm.id`
+    pub async fn update_alarm(&self, alarm: &Alarm) -> Result<()> {
+        <Self as AlarmClock>::update_alarm(
+            self,
+            alarm_clock::UpdateAlarmRequest {
+                id: alarm.id,
+                start_local_time: alarm.start_time.to_string(),
+                duration: duration_to_hms(alarm.duration),
+                recurrence: (&alarm.recurrence).into(),
+                enabled: alarm.enabled,
+                room_uuid: alarm.room_uuid.to_string(),
+                program_uri: alarm.program_uri.to_string(),
+                program_meta_data: alarm.program_metadata.to_string(),
+                play_mode: alarm.play_mode.clone(),
+                volume: alarm.volume.get(),
+                include_linked_zones: alarm.include_linked_zones,
+            },
+        )
+        .await
+    }
+
+    pub async fn destroy_alarm(&self, id: u32) -> Result<()> {
+        <Self as AlarmClock>::destroy_alarm(self, alarm_clock::DestroyAlarmRequest { id }).await
+    }
+
+    /// Creates, updates and destroys alarms so that the alarms of the
+    /// household match `desired`, returning the changes that were made.
+    /// See `plan_alarm_changes` for how alarms are matched.
+    /// The changes are made one at a time, so if the future is dropped
+    /// part way through, only some of them will have been made.
+    pub async fn apply_alarms(&self, desired: &[Alarm]) -> Result<Vec<AlarmChange>> {
+        let changes = plan_alarm_changes(&self.alarms().await?, desired);
+        for change in &changes {
+            match change {
+                AlarmChange::Create(alarm) => {
+                    self.create_alarm(alarm).await?;
+                }
+                AlarmChange::Update(alarm) => self.update_alarm(alarm).await?,
+                AlarmChange::Destroy(id) => self.destroy_alarm(*id).await?,
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Enables or disables the alarm identified by `id`, leaving
+    /// its other settings unchanged
+    pub async fn set_alarm_enabled(&self, id: u32, enabled: bool) -> Result<()> {
+        let mut alarm = self
+            .alarms()
+            .await?
+            .into_iter()
+            .find(|alarm| alarm.id == id)
+            .ok_or(Error::AlarmNotFound(id))?;
+        alarm.enabled = enabled;
+        self.update_alarm(&alarm).await
+    }
+}
+
+fn parse_alarms(xml: &str) -> Result<Vec<Alarm>> {
+    let list: AlarmList = instant_xml::from_str(xml).map_err(|error| Error::XmlParse {
+        error,
+        text: xml.to_string(),
+    })?;
+    list.alarms
+        .into_iter()
+        .map(|alarm| {
+            Ok(Alarm {
+                id: alarm.id,
+                start_time: alarm.start_time.parse()?,
+                duration: hms_to_duration(&alarm.duration),
+                recurrence: alarm.recurrence.parse()?,
+                enabled: alarm.enabled,
+                room_uuid: alarm.room_uuid,
+                program_uri: alarm.program_uri,
+                program_metadata: alarm.program_metadata.unwrap_or_default(),
+                play_mode: alarm.play_mode,
+                volume: Volume::saturating(alarm.volume),
+                include_linked_zones: alarm.include_linked_zones,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "Alarms")]
+struct AlarmList {
+    alarms: Vec<AlarmEntry>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "Alarm")]
+struct AlarmEntry {
+    #[xml(rename = "ID", attribute)]
+    id: u32,
+    #[xml(rename = "StartTime", attribute)]
+    start_time: String,
+    #[xml(rename = "Duration", attribute)]
+    duration: String,
+    #[xml(rename = "Recurrence", attribute)]
+    recurrence: String,
+    #[xml(rename = "Enabled", attribute)]
+    enabled: bool,
+    #[xml(rename = "RoomUUID", attribute)]
+    room_uuid: String,
+    #[xml(rename = "ProgramURI", attribute)]
+    program_uri: String,
+    #[xml(rename = "ProgramMetaData", attribute)]
+    program_metadata: Option<String>,
+    #[xml(rename = "PlayMode", attribute)]
+    play_mode: AlarmPlayMode,
+    #[xml(rename = "Volume", attribute)]
+    volume: u16,
+    #[xml(rename = "IncludeLinkedZones", attribute)]
+    include_linked_zones: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_alarms() {
+        let xml = r#"<Alarms><Alarm ID="14" StartTime="07:00:00" Duration="02:00:00" Recurrence="WEEKDAYS" Enabled="1" RoomUUID="RINCON_000E58000000001400" ProgramURI="x-rincon-buzzer:0" ProgramMetaData="" PlayMode="SHUFFLE_NOREPEAT" Volume="25" IncludeLinkedZones="0"/><Alarm ID="15" StartTime="21:30:00" Duration="00:30:00" Recurrence="ON_06" Enabled="0" RoomUUID="RINCON_000E58000000001400" ProgramURI="x-rincon-buzzer:0" ProgramMetaData="" PlayMode="NORMAL" Volume="10" IncludeLinkedZones="1"/></Alarms>"#;
+        let alarms = parse_alarms(xml).unwrap();
+        assert_eq!(
+            alarms,
+            vec![
+                Alarm {
+                    id: 14,
+                    start_time: LocalTime::new(7, 0, 0).unwrap(),
+                    duration: Duration::from_secs(7200),
+                    recurrence: AlarmRecurrence::Weekdays,
+                    enabled: true,
+                    room_uuid: "RINCON_000E58000000001400".to_string(),
+                    program_uri: ALARM_BUZZER_URI.to_string(),
+                    program_metadata: String::new(),
+                    play_mode: AlarmPlayMode::ShuffleNorepeat,
+                    volume: Volume::saturating(25),
+                    include_linked_zones: false,
+                },
+                Alarm {
+                    id: 15,
+                    start_time: LocalTime::new(21, 30, 0).unwrap(),
+                    duration: Duration::from_secs(1800),
+                    recurrence: AlarmRecurrence::On(vec![Weekday::Sunday, Weekday::Saturday]),
+                    enabled: false,
+                    room_uuid: "RINCON_000E58000000001400".to_string(),
+                    program_uri: ALARM_BUZZER_URI.to_string(),
+                    program_metadata: String::new(),
+                    play_mode: AlarmPlayMode::Normal,
+                    volume: Volume::saturating(10),
+                    include_linked_zones: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recurrence() {
+        let on: AlarmRecurrence = "ON_135".parse().unwrap();
+        assert_eq!(
+            on,
+            AlarmRecurrence::On(vec![Weekday::Monday, Weekday::Wednesday, Weekday::Friday])
+        );
+        assert_eq!(
+            AlarmRecurrence::On(vec![Weekday::Saturday, Weekday::Monday, Weekday::Saturday])
+                .to_string(),
+            "ON_16"
+        );
+        assert_eq!(
+            Recurrence::from(&AlarmRecurrence::Weekends),
+            Recurrence::Weekends
+        );
+        assert_eq!(
+            "ON_7".parse::<AlarmRecurrence>().unwrap(),
+            AlarmRecurrence::Unspecified("ON_7".to_string())
+        );
+        let biweekly: AlarmRecurrence = "BIWEEKLY_1".parse().unwrap();
+        assert_eq!(biweekly.to_string(), "BIWEEKLY_1");
+        assert!(!biweekly.includes(Weekday::Monday));
+        assert_eq!(
+            Recurrence::from(&biweekly),
+            Recurrence::Unspecified("BIWEEKLY_1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_local_time() {
+        let time: LocalTime = "06:45".parse().unwrap();
+        assert_eq!(time.to_string(), "06:45:00");
+        assert!("24:00:00".parse::<LocalTime>().is_err());
+    }
+
+    #[test]
+    fn test_plan_alarm_changes() {
+        let kitchen = Alarm {
+            id: 1,
+            room_uuid: "RINCON_KITCHEN".to_string(),
+            ..Default::default()
+        };
+        let bedroom = Alarm {
+            id: 2,
+            room_uuid: "RINCON_BEDROOM".to_string(),
+            ..Default::default()
+        };
+        let office = Alarm {
+            id: 3,
+            room_uuid: "RINCON_OFFICE".to_string(),
+            ..Default::default()
+        };
+        let existing = vec![kitchen.clone(), bedroom.clone(), office.clone()];
+
+        assert_eq!(plan_alarm_changes(&existing, &existing), vec![]);
+
+        let louder = Alarm {
+            volume: Volume::saturating(40),
+            ..bedroom.clone()
+        };
+        // The same settings as the office alarm, but with the id
+        // assigned by another household
+        let imported = Alarm {
+            id: 17,
+            ..office.clone()
+        };
+        let new = Alarm {
+            id: 18,
+            room_uuid: "RINCON_STUDY".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            plan_alarm_changes(&existing, &[louder.clone(), imported, new.clone()]),
+            vec![
+                AlarmChange::Update(louder),
+                AlarmChange::Create(new),
+                AlarmChange::Destroy(1),
+            ]
+        );
+    }
+}
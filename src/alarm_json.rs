@@ -0,0 +1,142 @@
+use crate::{AlarmChange, AlarmRecurrence, LocalTime, Result, SonosDevice};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for LocalTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocalTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for AlarmRecurrence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AlarmRecurrence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Represents a `Duration` as `HH:MM:SS`
+pub(crate) mod hms {
+    use crate::duration_to_hms;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&duration_to_hms(*d))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse(&s)
+            .ok_or_else(|| de::Error::custom(format!("invalid duration {s:?}, expected HH:MM:SS")))
+    }
+
+    /// Parses `HH:MM:SS`, optionally preceded by a number of days as
+    /// produced by `duration_to_hms`. Unlike `hms_to_duration`, which
+    /// is lenient about what the device reports, out of range fields
+    /// are rejected rather than carried into the next field.
+    pub(crate) fn parse(s: &str) -> Option<Duration> {
+        let fields = s
+            .split(':')
+            .map(|field| field.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (days, hours, minutes, seconds) = match fields[..] {
+            [hours, minutes, seconds] => (0, hours, minutes, seconds),
+            [days, hours, minutes, seconds] if hours < 24 => (days, hours, minutes, seconds),
+            _ => return None,
+        };
+        if minutes >= 60 || seconds >= 60 {
+            return None;
+        }
+        Some(Duration::from_secs(
+            ((days * 24 + hours) * 60 + minutes) * 60 + seconds,
+        ))
+    }
+}
+
+/// Represents an `AlarmPlayMode` using the string that the
+/// device uses for it
+pub(crate) mod play_mode {
+    use crate::AlarmPlayMode;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        mode: &AlarmPlayMode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&mode.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<AlarmPlayMode, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl SonosDevice {
+    /// Returns the alarms of the household as a JSON array, suitable
+    /// for backing up and passing to `import_alarms_json`
+    pub async fn export_alarms_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.alarms().await?)?)
+    }
+
+    /// Makes the alarms of the household match those in `json`, as
+    /// produced by `export_alarms_json`, returning the changes that
+    /// were made. See `apply_alarms` for details.
+    pub async fn import_alarms_json(&self, json: &str) -> Result<Vec<AlarmChange>> {
+        let desired: Vec<crate::Alarm> = serde_json::from_str(json)?;
+        self.apply_alarms(&desired).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Alarm, AlarmRecurrence, Weekday};
+
+    #[test]
+    fn test_round_trip() {
+        let alarm = Alarm {
+            id: 3,
+            recurrence: AlarmRecurrence::On(vec![Weekday::Tuesday, Weekday::Thursday]),
+            room_uuid: "RINCON_000E58000000001400".to_string(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&alarm).unwrap();
+        assert_eq!(
+            json,
+            r#"{"id":3,"start_time":"07:00:00","duration":"01:00:00","recurrence":"ON_24","enabled":true,"room_uuid":"RINCON_000E58000000001400","program_uri":"x-rincon-buzzer:0","program_metadata":"","play_mode":"SHUFFLE_NOREPEAT","volume":20,"include_linked_zones":false}"#
+        );
+        let parsed: Alarm = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, alarm);
+
+        let invalid = json.replace("01:00:00", "00:90:00");
+        assert!(serde_json::from_str::<Alarm>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_parse_hms() {
+        use std::time::Duration;
+        assert_eq!(hms::parse("01:30:15"), Some(Duration::from_secs(5415)));
+        assert_eq!(hms::parse("1:01:00:00"), Some(Duration::from_secs(90000)));
+        assert_eq!(hms::parse("00:60:00"), None);
+        assert_eq!(hms::parse("00:00:60"), None);
+        assert_eq!(hms::parse("1:24:00:00"), None);
+        assert_eq!(hms::parse("01:00"), None);
+        assert_eq!(hms::parse("soon"), None);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    rendering_control, Channel, Error, InstanceId, RenderingControl, Result, SonosDevice, Volume,
+};
+
+/// Computes the (left, right) channel volumes for `balance`,
+/// where -100 is fully left and 100 is fully right.
+/// Balance is expressed by reducing the volume of one channel
+/// relative to `Volume::MAX`, the full-scale volume of a channel.
+fn balance_to_channels(balance: i8) -> (Volume, Volume) {
+    let reduction = i16::from(balance).unsigned_abs().min(Volume::MAX);
+    let reduced = Volume::saturating(Volume::MAX - reduction);
+    let full = Volume::saturating(Volume::MAX);
+    if balance < 0 {
+        (full, reduced)
+    } else {
+        (reduced, full)
+    }
+}
+
+fn channels_to_balance(left: Volume, right: Volume) -> i8 {
+    let balance = i32::from(right.get()) - i32::from(left.get());
+    balance.clamp(-100, 100) as i8
+}
+
+impl SonosDevice {
+    /// Sets the stereo balance, in the range -100 (fully left) to
+    /// 100 (fully right). Values outside of this range are clamped.
+    /// As in the Sonos app, balance is applied by reducing the volume
+    /// of the opposite channel, leaving the master volume unchanged.
+    pub async fn set_balance(&self, balance: i8) -> Result<()> {
+        let (left, right) = balance_to_channels(balance);
+        for (channel, volume) in [(Channel::Lf, left), (Channel::Rf, right)] {
+            <Self as RenderingControl>::set_volume(
+                self,
+                rendering_control::SetVolumeRequest {
+                    instance_id: InstanceId::DEFAULT,
+                    channel,
+                    desired_volume: volume,
+                },
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the stereo balance, in the range -100 (fully left)
+    /// to 100 (fully right)
+    pub async fn balance(&self) -> Result<i8> {
+        let left = self.channel_volume(Channel::Lf).await?;
+        let right = self.channel_volume(Channel::Rf).await?;
+        Ok(channels_to_balance(left, right))
+    }
+
+    async fn channel_volume(&self, channel: Channel) -> Result<Volume> {
+        <Self as RenderingControl>::get_volume(
+            self,
+            rendering_control::GetVolumeRequest {
+                instance_id: InstanceId::DEFAULT,
+                channel,
+            },
+        )
+        .await?
+        .current_volume
+        .ok_or(Error::VolumeNone)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_balance() {
+        for balance in [-100, -40, 0, 25, 100] {
+            let (left, right) = balance_to_channels(balance);
+            assert_eq!(channels_to_balance(left, right), balance);
+        }
+        let pair = |left, right| (Volume::saturating(left), Volume::saturating(right));
+        assert_eq!(balance_to_channels(-40), pair(100, 60));
+        assert_eq!(balance_to_channels(127), pair(0, 100));
+        assert_eq!(balance_to_channels(-128), pair(100, 0));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Error, HttpRequest, Result, SonosDevice};
+use http::StatusCode;
+use instant_xml::FromXml;
+use std::str::FromStr;
+
+/// Where a portable player is drawing its power from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerSource {
+    #[default]
+    Battery,
+    /// The wireless charging base of a Move, or the Roam charger
+    ChargingRing,
+    UsbPower,
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+impl FromStr for PowerSource {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<PowerSource> {
+        match s {
+            "BATTERY" => Ok(PowerSource::Battery),
+            "SONOS_CHARGING_RING" => Ok(PowerSource::ChargingRing),
+            "USB_POWER" => Ok(PowerSource::UsbPower),
+            s => Ok(PowerSource::Unspecified(s.to_string())),
+        }
+    }
+}
+
+/// The battery status of a portable player, such as a Move or Roam
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryStatus {
+    /// The charge level, as a percentage
+    pub level: u8,
+    /// true if the player is on external power and not yet
+    /// fully charged
+    pub charging: bool,
+    pub power_source: PowerSource,
+    /// eg: `GREEN`
+    pub health: Option<String>,
+    /// eg: `NORMAL`
+    pub temperature: Option<String>,
+}
+
+impl SonosDevice {
+    /// Returns the battery status of a portable player.
+    /// Returns `Error::NotSupported` for players that have no battery.
+    pub async fn battery_status(&self) -> Result<BatteryStatus> {
+        self.identify_errors(async {
+            let url = self.shared.url.join("/status/batterystatus")?;
+            let response = self.shared.client.send(HttpRequest::get(url)).await?;
+            if response.status == StatusCode::NOT_FOUND {
+                return Err(Error::NotSupported("Battery status"));
+            }
+            let response = response.check()?;
+            parse_battery_status(&response.body)?.ok_or(Error::NotSupported("Battery status"))
+        })
+        .await
+    }
+}
+
+fn parse_battery_status(xml: &str) -> Result<Option<BatteryStatus>> {
+    let info: ZpSupportInfo = instant_xml::from_str(xml).map_err(|error| Error::XmlParse {
+        error,
+        text: xml.to_string(),
+    })?;
+    let Some(battery) = info.battery else {
+        return Ok(None);
+    };
+
+    let mut status = BatteryStatus::default();
+    let mut have_level = false;
+    for data in battery.data {
+        match data.name.as_str() {
+            "Level" => {
+                have_level = true;
+                status.level = data.value.trim().parse().unwrap_or(0);
+            }
+            "PowerSource" => status.power_source = data.value.parse()?,
+            "Health" => status.health = Some(data.value),
+            "Temperature" => status.temperature = Some(data.value),
+            _ => {}
+        }
+    }
+    if !have_level {
+        return Ok(None);
+    }
+    status.charging = status.power_source != PowerSource::Battery && status.level < 100;
+    Ok(Some(status))
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "ZPSupportInfo")]
+struct ZpSupportInfo {
+    battery: Option<LocalBatteryStatus>,
+}
+
+#[derive(Debug, FromXml)]
+struct LocalBatteryStatus {
+    data: Vec<Data>,
+}
+
+#[derive(Debug, FromXml)]
+struct Data {
+    #[xml(attribute)]
+    name: String,
+    #[xml(direct)]
+    value: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_battery_status() {
+        let xml = r#"<?xml version="1.0" ?><ZPSupportInfo><LocalBatteryStatus><Data name="Health">GREEN</Data><Data name="Level">87</Data><Data name="Temperature">NORMAL</Data><Data name="PowerSource">SONOS_CHARGING_RING</Data></LocalBatteryStatus></ZPSupportInfo>"#;
+        assert_eq!(
+            parse_battery_status(xml).unwrap(),
+            Some(BatteryStatus {
+                level: 87,
+                charging: true,
+                power_source: PowerSource::ChargingRing,
+                health: Some("GREEN".to_string()),
+                temperature: Some("NORMAL".to_string()),
+            })
+        );
+
+        assert_eq!(
+            parse_battery_status("<ZPSupportInfo></ZPSupportInfo>").unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! A command line tool for controlling the players on the network.
+//! Besides being useful in its own right, it exercises the high
+//! level API in the way that applications are expected to use it.
+//!
+//! Build it with `cargo install wez-sonos --features cli`.
+use clap::{Parser, Subcommand};
+use sonos::prelude::*;
+use sonos::{
+    av_transport, Error, Result, SonosDevice, SonosSystem, SonosUri, TrackMetaData, VolumeChange,
+};
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(name = "sonos", about = "Control Sonos players")]
+struct Args {
+    /// The room to control. When omitted, the first player to
+    /// respond to discovery is used.
+    #[arg(long, short, global = true)]
+    room: Option<String>,
+
+    /// The address of the player to control, which avoids the
+    /// delay of discovering players
+    #[arg(long, global = true, conflicts_with = "room")]
+    ip: Option<Ipv4Addr>,
+
+    /// How long to wait for players to respond to discovery
+    #[arg(long, default_value = "5", global = true)]
+    timeout_secs: u64,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Lists the players on the network
+    Discover,
+    /// Shows what is playing, and the volume
+    Status,
+    Play,
+    Pause,
+    Stop,
+    Next,
+    Previous,
+    /// Shows the volume, or sets it when given a value. A value
+    /// prefixed with `+` or `-` adjusts the current volume.
+    Volume {
+        #[arg(allow_hyphen_values = true)]
+        value: Option<String>,
+    },
+    #[command(subcommand)]
+    Group(GroupCommand),
+    #[command(subcommand)]
+    Queue(QueueCommand),
+    #[command(subcommand)]
+    Favorites(FavoritesCommand),
+    /// Speaks `text` by playing the audio produced by a text to
+    /// speech service
+    Say {
+        text: String,
+        /// The URL of the text to speech service, in which `{text}`
+        /// is replaced by the percent-encoded text
+        #[arg(long, env = "SONOS_TTS_URL")]
+        tts_url: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum GroupCommand {
+    /// Lists the groups and their members
+    List,
+    /// Adds the room to the group that `coordinator` belongs to
+    Join { coordinator: String },
+    /// Removes the room from its group
+    Leave,
+}
+
+#[derive(Subcommand, Debug)]
+enum QueueCommand {
+    /// Lists the tracks in the queue
+    List,
+    /// Appends `uri` to the queue
+    Add { uri: String },
+    /// Removes all tracks from the queue
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+enum FavoritesCommand {
+    /// Lists the favorites of the household
+    List,
+    /// Plays the favorite whose title is `name`
+    Play { name: String },
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+    let args = Args::parse();
+    if let Err(err) = run(args).await {
+        eprintln!("{err:#}");
+        std::process::exit(1);
+    }
+}
+
+async fn run(args: Args) -> Result<()> {
+    let timeout = Duration::from_secs(args.timeout_secs);
+    if let Command::Discover = args.command {
+        return discover(timeout).await;
+    }
+
+    let device = select_device(&args, timeout).await?;
+    match args.command {
+        Command::Discover => unreachable!(),
+        Command::Status => status(&device).await,
+        Command::Play => device.play().await,
+        Command::Pause => device.pause().await,
+        Command::Stop => device.stop().await,
+        Command::Next => device.next().await,
+        Command::Previous => device.previous().await,
+        Command::Volume { value } => volume(&device, value.as_deref()).await,
+        Command::Group(command) => group(&device, command).await,
+        Command::Queue(command) => queue(&device, command).await,
+        Command::Favorites(command) => favorites(&device, command).await,
+        Command::Say { text, tts_url } => say(&device, &text, &tts_url).await,
+    }
+}
+
+async fn select_device(args: &Args, timeout: Duration) -> Result<SonosDevice> {
+    if let Some(ip) = args.ip {
+        return SonosDevice::from_ip(ip).await;
+    }
+    let system = SonosSystem::discover(timeout).await?;
+    match &args.room {
+        Some(room) => system.device_for_room(room).await.cloned(),
+        None => system.devices().first().cloned().ok_or(Error::NoDevices),
+    }
+}
+
+async fn discover(timeout: Duration) -> Result<()> {
+    let system = SonosSystem::discover(timeout).await?;
+    for device in system.devices() {
+        let identity = device.identity();
+        let name = device
+            .name()
+            .await
+            .unwrap_or_else(|err| format!("? ({err})"));
+        println!(
+            "{name}\t{}\t{}",
+            identity.model.unwrap_or_default(),
+            device.url().host_str().unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+async fn status(device: &SonosDevice) -> Result<()> {
+    let transport = device.get_transport_info(Default::default()).await?;
+    let position = device.get_position_info(Default::default()).await?;
+    println!("Room:   {}", device.name().await?);
+    if let Some(state) = transport.current_transport_state {
+        println!("State:  {state}");
+    }
+    if let Some(track) = position.track_meta_data.and_then(|m| m.into_inner()) {
+        println!("Track:  {}", describe_track(&track));
+    }
+    println!("Volume: {}", device.get_volume().await?);
+    Ok(())
+}
+
+async fn volume(device: &SonosDevice, value: Option<&str>) -> Result<()> {
+    let volume = match value {
+        None => device.get_volume().await?,
+        Some(value) => match value.parse()? {
+            VolumeChange::Adjust(delta) => device.adjust_volume(delta).await?,
+            VolumeChange::Set(volume) => {
+                device.set_volume(volume).await?;
+                volume
+            }
+        },
+    };
+    println!("{volume}");
+    Ok(())
+}
+
+async fn group(device: &SonosDevice, command: GroupCommand) -> Result<()> {
+    match command {
+        GroupCommand::List => {
+            for group in device.get_zone_group_state().await? {
+                let mut names = vec![];
+                for member in &group.members {
+                    if member.uuid == group.coordinator {
+                        names.insert(0, format!("{} (coordinator)", member.zone_name));
+                    } else {
+                        names.push(member.zone_name.clone());
+                    }
+                }
+                println!("{}: {}", group.id, names.join(", "));
+            }
+            Ok(())
+        }
+        GroupCommand::Join { coordinator } => {
+            let uuid = device
+                .get_zone_group_state()
+                .await?
+                .into_iter()
+                .find(|group| group.members.iter().any(|m| m.zone_name == coordinator))
+                .map(|group| group.coordinator)
+                .ok_or(Error::RoomNotFound(coordinator))?;
+            device
+                .set_av_transport_uri(&SonosUri::Group { uuid }.to_string(), None)
+                .await
+        }
+        GroupCommand::Leave => {
+            device
+                .become_coordinator_of_standalone_group(
+                    av_transport::BecomeCoordinatorOfStandaloneGroupRequest::default(),
+                )
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+async fn queue(device: &SonosDevice, command: QueueCommand) -> Result<()> {
+    match command {
+        QueueCommand::List => {
+            for (idx, track) in device.queue_browse(0, 0).await?.iter().enumerate() {
+                println!("{:>4}. {}", idx + 1, describe_track(track));
+            }
+            Ok(())
+        }
+        QueueCommand::Add { uri } => {
+            let response = device.queue_append(&uri, None).await?;
+            if let Some(track) = response.first_track_number_enqueued {
+                println!("Added as track {track}");
+            }
+            Ok(())
+        }
+        QueueCommand::Clear => device.queue_clear().await,
+    }
+}
+
+async fn favorites(device: &SonosDevice, command: FavoritesCommand) -> Result<()> {
+    let favorites = device.content_browse("FV:2", 0, 0).await?;
+    match command {
+        FavoritesCommand::List => {
+            for favorite in favorites {
+                println!("{}", favorite.title);
+            }
+            Ok(())
+        }
+        FavoritesCommand::Play { name } => {
+            let item = favorites
+                .into_iter()
+                .find(|item| item.title.eq_ignore_ascii_case(&name))
+                .ok_or(Error::FavoriteNotFound(name))?;
+            let uri = item.url.clone();
+            device.set_av_transport_uri(&uri, Some(item)).await?;
+            device.play().await
+        }
+    }
+}
+
+async fn say(device: &SonosDevice, text: &str, tts_url: &str) -> Result<()> {
+    let text: String = url::form_urlencoded::byte_serialize(text.as_bytes()).collect();
+    let uri = tts_url.replace("{text}", &text);
+    device.set_av_transport_uri(&uri, None).await?;
+    device.play().await
+}
+
+fn describe_track(track: &TrackMetaData) -> String {
+    match (&track.creator, &track.album) {
+        (Some(creator), Some(album)) => format!("{} - {creator} ({album})", track.title),
+        (Some(creator), None) => format!("{} - {creator}", track.title),
+        _ => track.title.clone(),
+    }
+}
@@ -0,0 +1,256 @@
+//! Bindings for Kotlin, Swift and Python, generated by UniFFI.
+//!
+//! The async methods are exported as coroutines, `async` functions
+//! and awaitables respectively, and run on a tokio runtime that is
+//! started on first use. Events are delivered to a foreign
+//! `EventListener` from that runtime, so listeners must hand work
+//! off to their UI thread themselves.
+//!
+//! `make bindings` generates all three; to build the library and
+//! generate the bindings for a single language:
+//!
+//! ```console
+//! $ cargo rustc --lib --release --features uniffi --crate-type cdylib
+//! $ cargo run --features uniffi-bindgen --bin uniffi-bindgen -- \
+//!     generate --library target/release/libsonos.so --language kotlin --out-dir out
+//! ```
+use crate::bridge::{BridgeCommand, Household};
+use crate::schema::{BridgeEvent, RoomState};
+use crate::{SonosDevice, SonosSystem, Volume};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// The errors reported to foreign code. The message holds the
+/// description of the underlying `sonos::Error`.
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum SonosError {
+    #[error("{0}")]
+    RoomNotFound(String),
+    #[error("{0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    Failed(String),
+}
+
+impl From<crate::Error> for SonosError {
+    fn from(error: crate::Error) -> Self {
+        let message = format!("{error:#}");
+        match error.inner() {
+            crate::Error::RoomNotFound(_) => Self::RoomNotFound(message),
+            crate::Error::OutOfRange { .. }
+            | crate::Error::InvalidBool(_)
+            | crate::Error::InvalidNumber { .. }
+            | crate::Error::InvalidArgument(_) => Self::InvalidArgument(message),
+            _ => Self::Failed(message),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, SonosError>;
+
+/// Discovers the players on the network, waiting up to
+/// `timeout_ms` milliseconds for them to respond
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn discover(timeout_ms: u64) -> Result<Arc<SonosHousehold>> {
+    let system = SonosSystem::discover(Duration::from_millis(timeout_ms)).await?;
+    Ok(Arc::new(SonosHousehold {
+        system: Arc::new(system),
+    }))
+}
+
+/// Connects to the player at `ip`, an IPv4 address
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn player_from_ip(ip: String) -> Result<Arc<Player>> {
+    let addr = ip
+        .parse()
+        .map_err(|_| SonosError::InvalidArgument(format!("invalid IPv4 address {ip}")))?;
+    let device = SonosDevice::from_ip(addr).await?;
+    Ok(Arc::new(Player { device }))
+}
+
+/// Receives the events of a household; see
+/// `SonosHousehold::subscribe`
+#[uniffi::export(with_foreign)]
+pub trait EventListener: Send + Sync {
+    /// Called with the full state of a room whenever it changes
+    fn on_room_changed(&self, room: RoomState);
+    /// Called for each track, volume and topology change
+    fn on_event(&self, event: BridgeEvent);
+}
+
+/// The players found by `discover`
+#[derive(uniffi::Object)]
+pub struct SonosHousehold {
+    system: Arc<SonosSystem>,
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl SonosHousehold {
+    pub fn players(&self) -> Vec<Arc<Player>> {
+        self.system
+            .devices()
+            .iter()
+            .map(|device| {
+                Arc::new(Player {
+                    device: device.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the player in the room named `room`
+    pub async fn player(&self, room: String) -> Result<Arc<Player>> {
+        let device = self.system.device_for_room(&room).await?;
+        Ok(Arc::new(Player {
+            device: device.clone(),
+        }))
+    }
+
+    /// Subscribes to the events of each player and delivers them to
+    /// `listener` until the returned subscription is cancelled or
+    /// dropped
+    pub async fn subscribe(&self, listener: Arc<dyn EventListener>) -> Result<Arc<Subscription>> {
+        let (mut household, mut rx) = Household::subscribe(&self.system).await?;
+        let task = tokio::spawn(async move {
+            while let Some(update) = rx.recv().await {
+                let (changed, events) = household.apply_events(update);
+                for idx in changed {
+                    listener.on_room_changed(household.rooms[idx].clone());
+                }
+                for event in events {
+                    listener.on_event(event);
+                }
+            }
+        });
+        Ok(Arc::new(Subscription {
+            task: Mutex::new(Some(task)),
+        }))
+    }
+}
+
+/// The handle returned by `SonosHousehold::subscribe`
+#[derive(uniffi::Object)]
+pub struct Subscription {
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+#[uniffi::export]
+impl Subscription {
+    /// Stops delivering events
+    pub fn cancel(&self) {
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// A single player
+#[derive(uniffi::Object)]
+pub struct Player {
+    device: SonosDevice,
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl Player {
+    /// The URL of the device description of the player
+    pub fn url(&self) -> String {
+        self.device.url().to_string()
+    }
+
+    pub async fn name(&self) -> Result<String> {
+        Ok(self.device.name().await?)
+    }
+
+    pub async fn play(&self) -> Result<()> {
+        Ok(self.device.play().await?)
+    }
+
+    pub async fn pause(&self) -> Result<()> {
+        Ok(self.device.pause().await?)
+    }
+
+    pub async fn stop(&self) -> Result<()> {
+        Ok(self.device.stop().await?)
+    }
+
+    pub async fn next(&self) -> Result<()> {
+        Ok(self.device.next().await?)
+    }
+
+    pub async fn previous(&self) -> Result<()> {
+        Ok(self.device.previous().await?)
+    }
+
+    pub async fn volume(&self) -> Result<u16> {
+        Ok(self.device.get_volume().await?.get())
+    }
+
+    /// Sets the volume, in the range 0-100
+    pub async fn set_volume(&self, volume: u16) -> Result<()> {
+        Ok(self.device.set_volume(Volume::new(volume)?).await?)
+    }
+
+    /// Adjusts the volume by `delta`, returning the new volume
+    pub async fn adjust_volume(&self, delta: i16) -> Result<u16> {
+        Ok(self.device.adjust_volume(delta).await?.get())
+    }
+
+    pub async fn muted(&self) -> Result<bool> {
+        Ok(self.device.get_mute().await?)
+    }
+
+    pub async fn set_mute(&self, mute: bool) -> Result<()> {
+        Ok(self.device.set_mute(mute).await?)
+    }
+
+    /// Adds this player to the group of the room named `room`
+    pub async fn join(&self, room: String) -> Result<()> {
+        let groups = self.device.get_zone_group_state().await?;
+        Ok(BridgeCommand::Join(room)
+            .perform(&self.device, &groups, None)
+            .await?)
+    }
+
+    /// Removes this player from its group
+    pub async fn leave(&self) -> Result<()> {
+        Ok(BridgeCommand::Leave
+            .perform(&self.device, &[], None)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_mapping() {
+        assert!(matches!(
+            SonosError::from(crate::Error::RoomNotFound("Attic".to_string())),
+            SonosError::RoomNotFound(_)
+        ));
+        assert!(matches!(
+            SonosError::from(crate::Error::InvalidNumber {
+                name: "volume",
+                value: "loud".to_string()
+            }),
+            SonosError::InvalidArgument(_)
+        ));
+        assert!(matches!(
+            SonosError::from(crate::Error::InvalidArgument("position".to_string())),
+            SonosError::InvalidArgument(_)
+        ));
+        assert!(matches!(
+            SonosError::from(crate::Error::VolumeNone),
+            SonosError::Failed(_)
+        ));
+    }
+}
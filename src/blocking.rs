@@ -0,0 +1,219 @@
+//! A blocking wrapper around the async API, for programs such as
+//! scripts and command line tools that don't otherwise use async.
+//! Each handle runs the async methods to completion on a small
+//! runtime that is bundled with it.
+//!
+//! Only discovery and the common playback, volume and mute methods are
+//! wrapped directly; use `SonosDevice::run` for everything else.
+//!
+//! The blocking API must not be used from within an async runtime,
+//! such as from a `#[tokio::main]` function, as blocking there would
+//! stall the runtime. The wrapped methods return
+//! `Error::BlockingInAsyncContext` when called that way.
+//!
+//! ```no_run
+//! # fn example() -> sonos::Result<()> {
+//! let device = sonos::blocking::SonosDevice::from_ip("192.168.1.10".parse().unwrap())?;
+//! device.set_volume(sonos::Volume::new(20)?)?;
+//! device.play()?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{Error, Result, SonosDeviceBuilder};
+use std::future::Future;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use url::Url;
+
+fn new_runtime() -> Result<Arc<Runtime>> {
+    check_context()?;
+    Ok(Arc::new(
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?,
+    ))
+}
+
+/// Returns an error if called from within an async runtime, where
+/// `Runtime::block_on` would panic
+fn check_context() -> Result<()> {
+    match tokio::runtime::Handle::try_current() {
+        Ok(_) => Err(Error::BlockingInAsyncContext),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Generates blocking methods that call the async method of the
+/// same name on the wrapped device
+#[cfg(any(
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-rendering-control"
+))]
+macro_rules! blocking_methods {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> Result<$ret> {
+                check_context()?;
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// A blocking handle to a device; see `crate::SonosDevice`.
+/// Clones share the same runtime.
+#[derive(Debug, Clone)]
+pub struct SonosDevice {
+    inner: crate::SonosDevice,
+    runtime: Arc<Runtime>,
+}
+
+impl SonosDevice {
+    /// Constructs a SonosDevice from the supplied IP Address.
+    /// Validates that the device is actually a Sonos device
+    /// before returning successfully.
+    pub fn from_ip(addr: Ipv4Addr) -> Result<Self> {
+        Self::build(crate::SonosDevice::builder().address(addr))
+    }
+
+    /// Constructs a SonosDevice from the supplied URL, which must
+    /// be the device_description.xml URL for that device.
+    pub fn from_url(url: Url) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(crate::SonosDevice::from_url(url))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Constructs a SonosDevice from `builder`, which must have
+    /// had its address set via `SonosDeviceBuilder::address`
+    pub fn build(builder: SonosDeviceBuilder) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(builder.build())?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Returns the async device that this handle wraps
+    pub fn inner(&self) -> &crate::SonosDevice {
+        &self.inner
+    }
+
+    /// Returns the async device that this handle wraps, for use
+    /// once the program has its own async runtime. The runtime of
+    /// this handle is shut down when its last clone is dropped.
+    pub fn into_inner(self) -> crate::SonosDevice {
+        self.inner
+    }
+
+    /// Runs `f` to completion on the runtime of this handle.
+    /// This provides blocking access to the methods that are not
+    /// wrapped here, such as those of the service traits:
+    ///
+    /// ```no_run
+    /// # fn example(device: sonos::blocking::SonosDevice) -> sonos::Result<()> {
+    /// use sonos::prelude::*;
+    /// let info = device.run(|d| d.get_transport_info(Default::default()))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an async runtime.
+    pub fn run<'a, F, Fut, T>(&'a self, f: F) -> T
+    where
+        F: FnOnce(&'a crate::SonosDevice) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    #[cfg(feature = "svc-device-properties")]
+    blocking_methods! {
+        /// Returns the room/zone name of the device
+        fn name(&self) -> String;
+    }
+
+    #[cfg(feature = "svc-av-transport")]
+    blocking_methods! {
+        /// Stops playback
+        fn stop(&self) -> ();
+        /// Begin playback
+        fn play(&self) -> ();
+        /// Pause playback
+        fn pause(&self) -> ();
+        /// Skip to the next track
+        fn next(&self) -> ();
+        /// Skip to the previous track
+        fn previous(&self) -> ();
+        /// Clears the queue
+        fn queue_clear(&self) -> ();
+        fn set_av_transport_uri(
+            &self,
+            uri: &str,
+            metadata: Option<crate::TrackMetaData>
+        ) -> ();
+    }
+
+    #[cfg(feature = "svc-rendering-control")]
+    blocking_methods! {
+        /// Sets the mute state for the master sound channel
+        fn set_mute(&self, mute: bool) -> ();
+        /// Returns the mute state for the master sound channel
+        fn get_mute(&self) -> bool;
+        /// Sets the volume of the master sound channel
+        fn set_volume(&self, volume: crate::Volume) -> ();
+        /// Gets the volume of the master sound channel
+        fn get_volume(&self) -> crate::Volume;
+        /// Adjusts the volume of the master sound channel by
+        /// `delta`, returning the new volume
+        fn adjust_volume(&self, delta: i16) -> crate::Volume;
+    }
+}
+
+/// Discovers the players on the network, waiting up to `timeout`
+/// for them to respond. Unlike `crate::discover`, duplicates are
+/// removed. The returned devices share a runtime.
+pub fn discover(timeout: Duration) -> Result<Vec<SonosDevice>> {
+    let runtime = new_runtime()?;
+    let devices = runtime.block_on(async {
+        let mut rx = crate::discover(timeout).await?;
+        let mut devices: Vec<crate::SonosDevice> = vec![];
+        while let Some(device) = rx.recv().await {
+            if !devices.iter().any(|d| d.url() == device.url()) {
+                devices.push(device);
+            }
+        }
+        Result::Ok(devices)
+    })?;
+    Ok(devices
+        .into_iter()
+        .map(|inner| SonosDevice {
+            inner,
+            runtime: runtime.clone(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_context() {
+        assert!(check_context().is_ok());
+        let runtime = new_runtime().unwrap();
+        runtime.block_on(async {
+            assert!(matches!(
+                discover(Duration::from_millis(1)),
+                Err(Error::BlockingInAsyncContext)
+            ));
+        });
+    }
+}
@@ -0,0 +1,396 @@
+//! Bridges that expose the players of a `SonosSystem` to other
+//! home automation systems, and to clients written in other languages.
+//! The bridges share the same view of each room, `RoomState`, and the
+//! same set of commands, `BridgeCommand`. Everything they emit is one
+//! of the versioned JSON documents defined in `sonos::schema`.
+use crate::av_transport::{AVTransportEvent, BecomeCoordinatorOfStandaloneGroupRequest};
+use crate::rendering_control::RenderingControlEvent;
+use crate::schema::{BridgeEvent, Topology, TrackInfo};
+use crate::sonos_bool::parse_sonos_bool;
+use crate::zone_group_topology::ZoneGroupTopologyEvent;
+use crate::{
+    AVTransport, Error, Result, SonosDevice, SonosSystem, SonosUri, TransportState, Volume,
+    VolumeChange, ZoneGroup,
+};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "rest")]
+pub mod rest;
+#[cfg(feature = "webhooks")]
+pub mod webhook;
+
+pub use crate::schema::RoomState;
+
+/// A command for a room, as received by a bridge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeCommand {
+    Play,
+    Pause,
+    Stop,
+    Next,
+    Previous,
+    SetVolume(Volume),
+    AdjustVolume(i16),
+    Mute(bool),
+    /// Adds the room to the group of the named room
+    Join(String),
+    /// Removes the room from its group
+    Leave,
+    /// Speaks the text using the text to speech service
+    /// configured on the bridge
+    Say(String),
+}
+
+impl BridgeCommand {
+    /// Parses the name of a command together with its payload:
+    ///
+    /// * `play`, `pause`, `stop`, `next`, `previous` and `leave`
+    ///   ignore the payload.
+    /// * `volume` accepts `0`-`100`, or `+N`/`-N` to adjust the
+    ///   current volume.
+    /// * `mute` accepts any boolean accepted by `parse_sonos_bool`.
+    /// * `join` accepts the name of a room in the group to join.
+    /// * `say` accepts the text to speak.
+    pub fn parse(command: &str, payload: &str) -> Result<Self> {
+        let payload = payload.trim();
+        Ok(match command {
+            "play" => Self::Play,
+            "pause" => Self::Pause,
+            "stop" => Self::Stop,
+            "next" => Self::Next,
+            "previous" => Self::Previous,
+            "volume" => match payload.parse()? {
+                VolumeChange::Set(volume) => Self::SetVolume(volume),
+                VolumeChange::Adjust(delta) => Self::AdjustVolume(delta),
+            },
+            "mute" => Self::Mute(
+                parse_sonos_bool(payload).ok_or_else(|| Error::InvalidBool(payload.to_string()))?,
+            ),
+            "join" => Self::Join(payload.to_string()),
+            "leave" => Self::Leave,
+            "say" => Self::Say(payload.to_string()),
+            _ => return Err(Error::UnknownBridgeCommand(command.to_string())),
+        })
+    }
+
+    /// Performs the command on `device`. `groups` is used to find the
+    /// group to join, and `tts_url` is the template used by `Say`, in
+    /// which `{text}` is replaced by the percent-encoded text.
+    #[cfg_attr(
+        not(any(feature = "mqtt", feature = "rest", feature = "uniffi")),
+        allow(dead_code)
+    )]
+    pub(crate) async fn perform(
+        self,
+        device: &SonosDevice,
+        groups: &[ZoneGroup],
+        tts_url: Option<&str>,
+    ) -> Result<()> {
+        match self {
+            Self::Play => device.play().await,
+            Self::Pause => device.pause().await,
+            Self::Stop => device.stop().await,
+            Self::Next => device.next().await,
+            Self::Previous => device.previous().await,
+            Self::SetVolume(volume) => device.set_volume(volume).await,
+            Self::AdjustVolume(delta) => device.adjust_volume(delta).await.map(|_| ()),
+            Self::Mute(mute) => device.set_mute(mute).await,
+            Self::Join(room) => {
+                // Members join the group via its coordinator, which may
+                // not be the named room
+                let uuid = groups
+                    .iter()
+                    .find(|group| group.members.iter().any(|m| m.zone_name == room))
+                    .map(|group| group.coordinator.clone())
+                    .ok_or(Error::RoomNotFound(room))?;
+                device
+                    .set_av_transport_uri(&SonosUri::Group { uuid }.to_string(), None)
+                    .await
+            }
+            Self::Leave => {
+                <SonosDevice as AVTransport>::become_coordinator_of_standalone_group(
+                    device,
+                    BecomeCoordinatorOfStandaloneGroupRequest::default(),
+                )
+                .await?;
+                Ok(())
+            }
+            Self::Say(text) => {
+                let template = tts_url.ok_or(Error::NotSupported("say without a tts_url"))?;
+                let text: String = url::form_urlencoded::byte_serialize(text.as_bytes()).collect();
+                let uri = template.replace("{text}", &text);
+                device.set_av_transport_uri(&uri, None).await?;
+                device.play().await
+            }
+        }
+    }
+}
+
+/// Returns the form of `room_name` used in topics and paths:
+/// lowercase, with each run of characters other than letters and
+/// digits replaced by `_`, eg: `Living Room` becomes `living_room`
+pub fn room_slug(room_name: &str) -> String {
+    let mut slug = String::new();
+    for c in room_name.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    slug.trim_end_matches('_').to_string()
+}
+
+pub(crate) enum Update {
+    Transport(usize, AVTransportEvent),
+    Rendering(usize, RenderingControlEvent),
+    Topology(Box<ZoneGroupTopologyEvent>),
+}
+
+/// The state of each room of a `SonosSystem`, kept up to date from
+/// events. `rooms` is in the same order as `SonosSystem::devices`.
+pub(crate) struct Household {
+    pub rooms: Vec<RoomState>,
+    pub groups: Vec<ZoneGroup>,
+}
+
+impl Household {
+    /// Subscribes to the events of each player in `system`. The
+    /// returned receiver produces the updates to pass to `apply`.
+    pub async fn subscribe(system: &SonosSystem) -> Result<(Self, UnboundedReceiver<Update>)> {
+        let (tx, rx) = unbounded_channel();
+        let mut rooms = vec![];
+        for (idx, device) in system.devices().iter().enumerate() {
+            rooms.push(RoomState {
+                room: device.name().await?,
+                ..RoomState::default()
+            });
+            device
+                .subscribe_av_transport()
+                .await?
+                .forward(&tx, move |event| Update::Transport(idx, event));
+            device
+                .subscribe_rendering_control()
+                .await?
+                .forward(&tx, move |event| Update::Rendering(idx, event));
+        }
+        if let Some(device) = system.devices().first() {
+            device
+                .subscribe_zone_group_topology()
+                .await?
+                .forward(&tx, |event| Update::Topology(Box::new(event)));
+        }
+        let household = Self {
+            rooms,
+            groups: vec![],
+        };
+        Ok((household, rx))
+    }
+
+    /// Applies `update`, returning the indices of the rooms
+    /// whose state changed
+    pub fn apply(&mut self, update: Update) -> Vec<usize> {
+        match update {
+            Update::Transport(idx, event) => {
+                apply_transport(&mut self.rooms[idx], event);
+                vec![idx]
+            }
+            Update::Rendering(idx, event) => {
+                apply_rendering(&mut self.rooms[idx], event);
+                vec![idx]
+            }
+            Update::Topology(event) => {
+                let Some(state) = event.zone_group_state.and_then(|s| s.into_inner()) else {
+                    return vec![];
+                };
+                self.groups = state.groups;
+                for room in &mut self.rooms {
+                    apply_groups(room, &self.groups);
+                }
+                (0..self.rooms.len()).collect()
+            }
+        }
+    }
+
+    /// Like `apply`, but also returns the events that describe what
+    /// changed. The initial state reported by each subscription
+    /// doesn't produce events.
+    #[cfg_attr(not(feature = "webhooks"), allow(dead_code))]
+    pub fn apply_events(&mut self, update: Update) -> (Vec<usize>, Vec<BridgeEvent>) {
+        let before = self.rooms.clone();
+        let changed = self.apply(update);
+        let mut events = vec![];
+        for &idx in &changed {
+            let (old, new) = (&before[idx], &self.rooms[idx]);
+            if old.state.is_some() && old.track != new.track {
+                events.push(BridgeEvent::TrackChange {
+                    room: new.room.clone(),
+                    track: new.track.clone(),
+                });
+            }
+            if old.volume.is_some() && (old.volume != new.volume || old.muted != new.muted) {
+                events.push(BridgeEvent::VolumeChange {
+                    room: new.room.clone(),
+                    volume: new.volume,
+                    muted: new.muted,
+                });
+            }
+        }
+        let (old, new) = (
+            Topology::from_rooms(&before),
+            Topology::from_rooms(&self.rooms),
+        );
+        if !old.groups.is_empty() && old != new {
+            events.push(BridgeEvent::TopologyChange { groups: new.groups });
+        }
+        (changed, events)
+    }
+
+    /// Returns the index of the room whose name or slug is `room`
+    #[cfg_attr(not(any(feature = "mqtt", feature = "rest")), allow(dead_code))]
+    pub fn find(&self, room: &str) -> Result<usize> {
+        self.rooms
+            .iter()
+            .position(|state| state.room == room || room_slug(&state.room) == room)
+            .ok_or_else(|| Error::RoomNotFound(room.to_string()))
+    }
+}
+
+fn apply_transport(room: &mut RoomState, event: AVTransportEvent) {
+    let Some(change) = event.last_change.and_then(|c| c.into_inner()) else {
+        return;
+    };
+    for change in change.map.into_values() {
+        if let Some(state) = change.transport_state {
+            room.state.replace(state.to_string());
+            if state == TransportState::Stopped {
+                room.track = None;
+            }
+        }
+        if let Some(track) = change.current_track_meta_data {
+            room.track = track.into_inner().as_ref().map(TrackInfo::from);
+        }
+    }
+}
+
+fn apply_rendering(room: &mut RoomState, event: RenderingControlEvent) {
+    let Some(change) = event.last_change.and_then(|c| c.into_inner()) else {
+        return;
+    };
+    for change in change.map.into_values() {
+        if let Some(volume) = change.volume {
+            room.volume.replace(volume.into());
+        }
+        if let Some(muted) = change.mute {
+            room.muted.replace(muted);
+        }
+    }
+}
+
+fn apply_groups(room: &mut RoomState, groups: &[ZoneGroup]) {
+    room.coordinator = None;
+    room.group.clear();
+    let Some(group) = groups
+        .iter()
+        .find(|group| group.members.iter().any(|m| m.zone_name == room.room))
+    else {
+        return;
+    };
+    for member in &group.members {
+        if member.uuid == group.coordinator {
+            room.coordinator.replace(member.zone_name.clone());
+            room.group.insert(0, member.zone_name.clone());
+        } else {
+            room.group.push(member.zone_name.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_join_coordinator() {
+        use crate::av_transport::SetAvTransportUriRequest;
+        use crate::vcr::{replay_device, Exchange};
+        use crate::{DecodeXml, InstanceId, ZoneGroupState};
+
+        let mut groups =
+            ZoneGroupState::decode_xml(include_str!("../../data/zone_group_state.xml"))
+                .unwrap()
+                .groups;
+        let coordinator: crate::PlayerUuid = "RINCON_000E58000000001400".parse().unwrap();
+        groups[0].coordinator = coordinator.clone();
+        groups[0].members[0].uuid = coordinator.clone();
+        let mut member = groups[1].members[0].clone();
+        member.uuid = "RINCON_000E58000000001401".parse().unwrap();
+        member.zone_name = "Office".to_string();
+        groups[0].members.push(member);
+
+        let (device, client) = replay_device(vec![Exchange::upnp(
+            "/MediaRenderer/AVTransport/Control",
+            "AVTransport",
+            "SetAVTransportURI",
+            "",
+        )
+        .request(SetAvTransportUriRequest {
+            instance_id: InstanceId::DEFAULT,
+            current_uri: "x-rincon:RINCON_000E58000000001400".to_string(),
+            current_uri_meta_data: None.into(),
+        })])
+        .await;
+
+        BridgeCommand::Join("Office".to_string())
+            .perform(&device, &groups, None)
+            .await
+            .unwrap();
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[test]
+    fn test_room_slug() {
+        assert_eq!(room_slug("Living Room"), "living_room");
+        assert_eq!(room_slug(" Kid's Room (Upstairs) "), "kid_s_room_upstairs");
+        assert_eq!(room_slug("Büro"), "büro");
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            BridgeCommand::parse("play", "").unwrap(),
+            BridgeCommand::Play
+        );
+        assert_eq!(
+            BridgeCommand::parse("volume", "25").unwrap(),
+            BridgeCommand::SetVolume(Volume::new(25).unwrap())
+        );
+        assert_eq!(
+            BridgeCommand::parse("volume", "-5").unwrap(),
+            BridgeCommand::AdjustVolume(-5)
+        );
+        assert_eq!(
+            BridgeCommand::parse("mute", "ON").unwrap(),
+            BridgeCommand::Mute(true)
+        );
+        assert_eq!(
+            BridgeCommand::parse("join", "Kitchen\n").unwrap(),
+            BridgeCommand::Join("Kitchen".to_string())
+        );
+        assert!(matches!(
+            BridgeCommand::parse("volume", "150"),
+            Err(Error::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            BridgeCommand::parse("volume", "+x"),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            BridgeCommand::parse("dance", ""),
+            Err(Error::UnknownBridgeCommand(command)) if command == "dance"
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+//! Publishes the state of each room to an MQTT broker and performs
+//! the commands that are published to its command topics, which is
+//! enough to integrate with Home Assistant or Node-RED.
+//!
+//! With the default prefix of `sonos`, the topics are:
+//!
+//! * `sonos/bridge/status`: `online`, retained, while the bridge is
+//!   running. Configure `MqttBridge::last_will` on the connection so
+//!   that the broker replaces it with `offline`.
+//! * `sonos/<room>/state`: a retained, versioned JSON `RoomState`,
+//!   republished whenever the players report a change.
+//! * `sonos/<room>/set/<command>`: performs a `BridgeCommand`; see
+//!   `BridgeCommand::parse` for the commands and their payloads.
+//!
+//! `<room>` is the room name converted by `room_slug`,
+//! eg: `living_room`.
+//!
+//! ```no_run
+//! # async fn example() -> sonos::Result<()> {
+//! use rumqttc::{AsyncClient, MqttOptions};
+//! use sonos::bridge::mqtt::MqttBridge;
+//! use sonos::SonosSystem;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let system = Arc::new(SonosSystem::discover(Duration::from_secs(5)).await?);
+//! let bridge = MqttBridge::new(system).tts_url("http://tts.local/say?text={text}");
+//! let mut options = MqttOptions::new("sonos-bridge", "broker.local", 1883);
+//! options.set_last_will(bridge.last_will());
+//! let (client, eventloop) = AsyncClient::new(options, 64);
+//! bridge.run(client, eventloop).await
+//! # }
+//! ```
+use super::Household;
+pub use super::{room_slug, BridgeCommand, RoomState};
+use crate::schema::Versioned;
+use crate::{Error, Result, SonosSystem};
+use rumqttc::{AsyncClient, Event, EventLoop, LastWill, Packet, QoS};
+use std::sync::Arc;
+
+const DEFAULT_TOPIC_PREFIX: &str = "sonos";
+
+/// Connects the players of a `SonosSystem` to an MQTT broker
+pub struct MqttBridge {
+    system: Arc<SonosSystem>,
+    topic_prefix: String,
+    tts_url: Option<String>,
+}
+
+impl MqttBridge {
+    pub fn new(system: Arc<SonosSystem>) -> Self {
+        Self {
+            system,
+            topic_prefix: DEFAULT_TOPIC_PREFIX.to_string(),
+            tts_url: None,
+        }
+    }
+
+    /// Sets the prefix of all of the topics used by the bridge.
+    /// The default is `sonos`.
+    pub fn topic_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.topic_prefix = prefix.into();
+        self
+    }
+
+    /// Enables the `say` command, which plays the URL produced by
+    /// replacing `{text}` in `template` with the percent-encoded text
+    pub fn tts_url(mut self, template: impl Into<String>) -> Self {
+        self.tts_url.replace(template.into());
+        self
+    }
+
+    fn status_topic(&self) -> String {
+        format!("{}/bridge/status", self.topic_prefix)
+    }
+
+    /// Returns the will that the broker should publish if the bridge
+    /// disconnects unexpectedly; pass it to `MqttOptions::set_last_will`
+    pub fn last_will(&self) -> LastWill {
+        LastWill::new(self.status_topic(), "offline", QoS::AtLeastOnce, true)
+    }
+
+    /// Subscribes to the events of each player and runs the bridge
+    /// until the connection to the broker fails. `eventloop` is polled
+    /// by this method, so it must not be polled elsewhere.
+    /// Commands are performed one at a time; errors performing them
+    /// are logged and do not stop the bridge.
+    pub async fn run(&self, client: AsyncClient, mut eventloop: EventLoop) -> Result<()> {
+        let (mut household, mut rx) = Household::subscribe(&self.system).await?;
+
+        client
+            .subscribe(format!("{}/+/set/+", self.topic_prefix), QoS::AtLeastOnce)
+            .await
+            .map_err(|err| Error::Mqtt(err.into()))?;
+        client
+            .publish(self.status_topic(), QoS::AtLeastOnce, true, "online")
+            .await
+            .map_err(|err| Error::Mqtt(err.into()))?;
+
+        loop {
+            tokio::select! {
+                event = eventloop.poll() => {
+                    let event = event.map_err(|err| Error::Mqtt(err.into()))?;
+                    if let Event::Incoming(Packet::Publish(publish)) = event {
+                        let payload = String::from_utf8_lossy(&publish.payload);
+                        if let Err(err) = self
+                            .handle_command(&publish.topic, &payload, &household)
+                            .await
+                        {
+                            log::error!("MQTT command {} failed: {err:#}", publish.topic);
+                        }
+                    }
+                }
+                Some(update) = rx.recv() => {
+                    for idx in household.apply(update) {
+                        self.publish_state(&client, &household.rooms[idx]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Like `run`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn run_until_cancelled(
+        &self,
+        client: AsyncClient,
+        eventloop: EventLoop,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.run(client, eventloop), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+
+    /// Queues the state of `room` for publishing. This doesn't wait
+    /// for space in the client's queue, because the queue is only
+    /// drained while `run` is polling the event loop.
+    fn publish_state(&self, client: &AsyncClient, room: &RoomState) {
+        let topic = format!("{}/{}/state", self.topic_prefix, room_slug(&room.room));
+        let result = serde_json::to_vec(&Versioned::new(room))
+            .map_err(Error::from)
+            .and_then(|payload| {
+                client
+                    .try_publish(&topic, QoS::AtLeastOnce, true, payload)
+                    .map_err(|err| Error::Mqtt(err.into()))
+            });
+        if let Err(err) = result {
+            log::error!("publishing {topic} failed: {err:#}");
+        }
+    }
+
+    async fn handle_command(
+        &self,
+        topic: &str,
+        payload: &str,
+        household: &Household,
+    ) -> Result<()> {
+        let Some(rest) = topic
+            .strip_prefix(&self.topic_prefix)
+            .and_then(|rest| rest.strip_prefix('/'))
+        else {
+            return Ok(());
+        };
+        let Some((slug, command)) = rest.split_once("/set/") else {
+            return Ok(());
+        };
+        let idx = household.find(slug)?;
+
+        log::debug!("MQTT command {command} for {}", household.rooms[idx].room);
+        BridgeCommand::parse(command, payload)?
+            .perform(
+                &self.system.devices()[idx],
+                &household.groups,
+                self.tts_url.as_deref(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,240 @@
+//! Serves the household over HTTP, so that clients written in any
+//! language can control it with this crate running as a daemon.
+//!
+//! * `GET /rooms`: the `RoomState` of every room, as a `RoomList`.
+//! * `GET /rooms/<room>`: the `RoomState` of a room.
+//! * `POST /rooms/<room>/<command>`: performs a `BridgeCommand`,
+//!   whose payload is the request body, eg: `POST /rooms/kitchen/volume`
+//!   with a body of `+5`. Responds with `204 No Content`.
+//! * `GET /events`: a server-sent event stream, which produces a
+//!   `room` event holding the `RoomState` of a room whenever
+//!   it changes. Only changes are sent, so clients should fetch
+//!   `/rooms` after connecting.
+//!
+//! Each document is one of the versioned documents defined in
+//! `sonos::schema`. `<room>` is either the room name or its `room_slug`.
+//! Errors are reported as `{"error": "<message>"}`, with a status of
+//! 404 for unknown rooms, 400 for invalid commands, and 502 when the
+//! player fails to perform a command.
+//!
+//! ```no_run
+//! # async fn example() -> sonos::Result<()> {
+//! use sonos::bridge::rest::RestBridge;
+//! use sonos::SonosSystem;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let system = Arc::new(SonosSystem::discover(Duration::from_secs(5)).await?);
+//! RestBridge::new(system)
+//!     .run("0.0.0.0:5005".parse().unwrap())
+//!     .await
+//! # }
+//! ```
+use super::{BridgeCommand, Household, RoomState};
+use crate::schema::{RoomList, Versioned};
+use crate::{Error, Result, SonosSystem};
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+
+/// How many changes are buffered for each event stream client;
+/// a client that falls further behind misses the older changes
+const EVENT_CAPACITY: usize = 64;
+
+/// Serves the players of a `SonosSystem` over a REST and
+/// server-sent events API
+pub struct RestBridge {
+    system: Arc<SonosSystem>,
+    tts_url: Option<String>,
+}
+
+struct Shared {
+    system: Arc<SonosSystem>,
+    tts_url: Option<String>,
+    household: Mutex<Household>,
+    events: broadcast::Sender<RoomState>,
+}
+
+impl RestBridge {
+    pub fn new(system: Arc<SonosSystem>) -> Self {
+        Self {
+            system,
+            tts_url: None,
+        }
+    }
+
+    /// Enables the `say` command, which plays the URL produced by
+    /// replacing `{text}` in `template` with the percent-encoded text
+    pub fn tts_url(mut self, template: impl Into<String>) -> Self {
+        self.tts_url.replace(template.into());
+        self
+    }
+
+    /// Subscribes to the events of each player and serves the API on
+    /// `addr` until accepting connections fails
+    pub async fn run(&self, addr: SocketAddr) -> Result<()> {
+        let (household, mut rx) = Household::subscribe(&self.system).await?;
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        let shared = Arc::new(Shared {
+            system: self.system.clone(),
+            tts_url: self.tts_url.clone(),
+            household: Mutex::new(household),
+            events,
+        });
+
+        let router = Router::new()
+            .route("/rooms", get(list_rooms))
+            .route("/rooms/:room", get(get_room))
+            .route("/rooms/:room/:command", post(perform_command))
+            .route("/events", get(event_stream))
+            .with_state(shared.clone());
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+
+        let watch = async {
+            while let Some(update) = rx.recv().await {
+                let changed: Vec<RoomState> = {
+                    let mut household = shared.household.lock().unwrap();
+                    household
+                        .apply(update)
+                        .into_iter()
+                        .map(|idx| household.rooms[idx].clone())
+                        .collect()
+                };
+                for room in changed {
+                    // Fails only when no clients are listening
+                    shared.events.send(room).ok();
+                }
+            }
+            Ok::<_, Error>(())
+        };
+        let serve = async { axum::serve(listener, router).await.map_err(Error::from) };
+        tokio::try_join!(watch, serve)?;
+        Ok(())
+    }
+
+    /// Like `run`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn run_until_cancelled(
+        &self,
+        addr: SocketAddr,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.run(addr), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+}
+
+async fn list_rooms(State(shared): State<Arc<Shared>>) -> Json<Versioned<RoomList>> {
+    let rooms = shared.household.lock().unwrap().rooms.clone();
+    Json(Versioned::new(RoomList { rooms }))
+}
+
+async fn get_room(
+    State(shared): State<Arc<Shared>>,
+    Path(room): Path<String>,
+) -> std::result::Result<Json<Versioned<RoomState>>, ApiError> {
+    let household = shared.household.lock().unwrap();
+    let idx = household.find(&room)?;
+    Ok(Json(Versioned::new(household.rooms[idx].clone())))
+}
+
+async fn perform_command(
+    State(shared): State<Arc<Shared>>,
+    Path((room, command)): Path<(String, String)>,
+    payload: String,
+) -> std::result::Result<StatusCode, ApiError> {
+    let command = BridgeCommand::parse(&command, &payload)?;
+    let (idx, groups) = {
+        let household = shared.household.lock().unwrap();
+        (household.find(&room)?, household.groups.clone())
+    };
+    command
+        .perform(
+            &shared.system.devices()[idx],
+            &groups,
+            shared.tts_url.as_deref(),
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn event_stream(
+    State(shared): State<Arc<Shared>>,
+) -> Sse<impl Stream<Item = std::result::Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(shared.events.subscribe()).filter_map(|room| {
+        // A client that lagged behind skips the changes it missed
+        let room = room.ok()?;
+        Event::default()
+            .event("room")
+            .json_data(Versioned::new(room))
+            .ok()
+            .map(Ok)
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Reports an `Error` as a JSON response
+struct ApiError(Error);
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self.0.inner() {
+            Error::RoomNotFound(_) => StatusCode::NOT_FOUND,
+            Error::UnknownBridgeCommand(_)
+            | Error::OutOfRange { .. }
+            | Error::InvalidBool(_)
+            | Error::InvalidNumber { .. }
+            | Error::InvalidArgument(_)
+            | Error::NotSupported(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::BAD_GATEWAY,
+        };
+        let body = serde_json::json!({ "error": format!("{:#}", self.0) });
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_status() {
+        let status = |error| ApiError(error).into_response().status();
+        assert_eq!(
+            status(Error::RoomNotFound("attic".to_string())),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(Error::UnknownBridgeCommand("dance".to_string())),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(Error::InvalidNumber {
+                name: "volume",
+                value: "loud".to_string()
+            }),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(Error::InvalidArgument("position".to_string())),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(status(Error::VolumeNone), StatusCode::BAD_GATEWAY);
+    }
+}
@@ -0,0 +1,313 @@
+//! POSTs selected events to webhook URLs, for consumers such as
+//! serverless functions and home automation systems that can't hold
+//! a subscription open themselves.
+//!
+//! Each event is sent as a versioned JSON `BridgeEvent`, as defined
+//! in `sonos::schema`, with an `X-Sonos-Event` header holding its
+//! `BridgeEventKind`. When the
+//! webhook has a secret, the `X-Sonos-Timestamp` header holds the
+//! time the event was sent, in seconds since the Unix epoch, and the
+//! `X-Sonos-Signature` header holds `sha256=<hex>`, the HMAC-SHA256
+//! of `<timestamp>.<body>` keyed by the secret. The receiver should
+//! compute and compare the signature, and reject timestamps that are
+//! more than a few minutes old so that captured requests can't be
+//! replayed, before trusting the event.
+//!
+//! The state reported when first subscribing is not sent, so only
+//! the changes that happen while the dispatcher is running produce
+//! events.
+//!
+//! ```no_run
+//! # async fn example() -> sonos::Result<()> {
+//! use sonos::bridge::webhook::{Webhook, WebhookDispatcher};
+//! use sonos::schema::BridgeEventKind;
+//! use sonos::SonosSystem;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let system = Arc::new(SonosSystem::discover(Duration::from_secs(5)).await?);
+//! WebhookDispatcher::new(system)
+//!     .webhook(
+//!         Webhook::new("https://example.com/hooks/sonos".parse().unwrap())
+//!             .events(&[BridgeEventKind::TrackChange])
+//!             .secret("s3cret"),
+//!     )
+//!     .run()
+//!     .await
+//! # }
+//! ```
+use super::Household;
+use crate::redact::Redacted;
+use crate::schema::{BridgeEvent, BridgeEventKind, Versioned};
+use crate::{HttpClient, HttpRequest, Result, RetryPolicy, SonosSystem};
+use hmac::{Hmac, Mac};
+use http::StatusCode;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::mpsc::{channel, Receiver};
+use url::Url;
+
+/// The number of events that can be waiting for delivery to a
+/// webhook before further events for it are dropped
+const QUEUE_CAPACITY: usize = 64;
+
+/// A URL to which events are POSTed.
+/// The secret is redacted from the `Debug` representation.
+#[derive(Clone)]
+pub struct Webhook {
+    url: Url,
+    events: Vec<BridgeEventKind>,
+    secret: Option<String>,
+}
+
+impl Webhook {
+    /// Creates a webhook that receives every kind of event
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            events: vec![],
+            secret: None,
+        }
+    }
+
+    /// Limits the webhook to the listed kinds of event
+    pub fn events(mut self, kinds: &[BridgeEventKind]) -> Self {
+        self.events = kinds.to_vec();
+        self
+    }
+
+    /// Signs each request with `secret`
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret.replace(secret.into());
+        self
+    }
+
+    fn wants(&self, kind: BridgeEventKind) -> bool {
+        self.events.is_empty() || self.events.contains(&kind)
+    }
+
+    /// Builds the request that delivers `body`, sent at `timestamp`
+    /// seconds since the Unix epoch
+    fn request(&self, kind: BridgeEventKind, body: String, timestamp: u64) -> Result<HttpRequest> {
+        let mut request = HttpRequest::post(self.url.clone())
+            .header("content-type", "application/json")?
+            .header("x-sonos-event", kind.as_str())?;
+        if let Some(secret) = &self.secret {
+            request = request
+                .header("x-sonos-timestamp", &timestamp.to_string())?
+                .header("x-sonos-signature", &signature(secret, timestamp, &body))?;
+        }
+        Ok(request.body(body))
+    }
+}
+
+impl std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Webhook")
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("secret", &self.secret.as_ref().map(|_| Redacted))
+            .finish()
+    }
+}
+
+/// Sends the events of a `SonosSystem` to webhooks
+pub struct WebhookDispatcher {
+    system: Arc<SonosSystem>,
+    webhooks: Vec<Webhook>,
+    client: Arc<dyn HttpClient>,
+    retry_policy: RetryPolicy,
+    timeout: Duration,
+}
+
+impl WebhookDispatcher {
+    pub fn new(system: Arc<SonosSystem>) -> Self {
+        Self {
+            system,
+            webhooks: vec![],
+            client: Arc::new(reqwest::Client::new()),
+            retry_policy: RetryPolicy::default().retry_statuses(vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ]),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn webhook(mut self, webhook: Webhook) -> Self {
+        self.webhooks.push(webhook);
+        self
+    }
+
+    /// Sets the `HttpClient` used to deliver events.
+    /// The default is a `reqwest::Client`.
+    pub fn http_client(mut self, client: Arc<dyn HttpClient>) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sets how failed deliveries are retried. The default retries
+    /// twice when the webhook can't be reached or responds with
+    /// 429, 502, 503 or 504.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets how long each delivery attempt may take before it is
+    /// abandoned. The default is 10 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Subscribes to the events of each player and sends them to the
+    /// webhooks until the subscriptions end. Each webhook receives its
+    /// events in order, without waiting for the other webhooks.
+    /// Deliveries that still fail after retrying are logged and
+    /// dropped, as are events that arrive while 64 events are
+    /// already waiting for a slow webhook.
+    pub async fn run(&self) -> Result<()> {
+        let (mut household, mut rx) = Household::subscribe(&self.system).await?;
+
+        let mut queues = vec![];
+        for webhook in &self.webhooks {
+            let (tx, rx) = channel(QUEUE_CAPACITY);
+            tokio::spawn(deliver(
+                webhook.clone(),
+                self.client.clone(),
+                self.retry_policy.clone(),
+                self.timeout,
+                rx,
+            ));
+            queues.push(tx);
+        }
+
+        while let Some(update) = rx.recv().await {
+            for event in household.apply_events(update).1 {
+                for (webhook, queue) in self.webhooks.iter().zip(&queues) {
+                    if !webhook.wants(event.kind()) {
+                        continue;
+                    }
+                    if let Err(TrySendError::Full(event)) = queue.try_send(event.clone()) {
+                        log::warn!(
+                            "dropping {} for {}: too many deliveries are pending",
+                            event.kind(),
+                            webhook.url
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `run`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn run_until_cancelled(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.run(), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+}
+
+/// Delivers the events received from `rx` to `webhook`, one at a time
+async fn deliver(
+    webhook: Webhook,
+    client: Arc<dyn HttpClient>,
+    policy: RetryPolicy,
+    timeout: Duration,
+    mut rx: Receiver<BridgeEvent>,
+) {
+    while let Some(event) = rx.recv().await {
+        if let Err(err) = send_event(&webhook, &*client, &policy, timeout, &event).await {
+            log::error!(
+                "delivering {} to {} failed: {err:#}",
+                event.kind(),
+                webhook.url
+            );
+        }
+    }
+}
+
+async fn send_event(
+    webhook: &Webhook,
+    client: &dyn HttpClient,
+    policy: &RetryPolicy,
+    timeout: Duration,
+    event: &BridgeEvent,
+) -> Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let mut request = webhook.request(
+        event.kind(),
+        serde_json::to_string(&Versioned::new(event))?,
+        timestamp,
+    )?;
+    request.timeout = Some(timeout);
+    let mut attempt = 0;
+    loop {
+        match client.send(request.clone()).await.and_then(|r| r.check()) {
+            Ok(_) => return Ok(()),
+            Err(err) if attempt + 1 < policy.max_attempts && policy.is_retryable(&err) => {
+                attempt += 1;
+                let backoff = policy.backoff(attempt);
+                log::debug!(
+                    "webhook {} failed: {err:#}, retrying in {backoff:?}",
+                    webhook.url
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Returns the value of the `X-Sonos-Signature` header for `body`,
+/// sent at `timestamp`
+fn signature(secret: &str, timestamp: u64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(body.as_bytes());
+    let mut result = "sha256=".to_string();
+    for b in mac.finalize().into_bytes() {
+        result.push_str(&format!("{b:02x}"));
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        let body = r#"{"kind":"volume"}"#;
+        let expected = "sha256=8b604158380c3c7e101808da3210a4b188ecec7b71a7aaf6b82eddb6e20ec0ce";
+        assert_eq!(signature("s3cret", 1700000000, body), expected);
+
+        let request = Webhook::new("http://127.0.0.1/hook".parse().unwrap())
+            .secret("s3cret")
+            .request(BridgeEventKind::VolumeChange, body.to_string(), 1700000000)
+            .unwrap();
+        assert_eq!(request.headers["x-sonos-timestamp"], "1700000000");
+        assert_eq!(request.headers["x-sonos-signature"], expected);
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let webhook = Webhook::new("http://127.0.0.1/hook".parse().unwrap()).secret("s3cret");
+        let debug = format!("{webhook:?}");
+        assert!(!debug.contains("s3cret"), "{debug}");
+        assert!(debug.contains("<redacted>"), "{debug}");
+    }
+}
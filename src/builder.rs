@@ -0,0 +1,437 @@
+use crate::http_client::SharedHttpClient;
+use crate::metrics::Metrics;
+use crate::runtime::SharedRuntime;
+use crate::{
+    DeviceSpec, Error, HandleOptions, HttpClient, HttpRequest, MetricsSink, Result, RetryPolicy,
+    Runtime, SharedDevice, SonosDevice,
+};
+#[cfg(feature = "reqwest")]
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+/// The default limit on the number of concurrent SOAP actions
+/// sent to a device
+const DEFAULT_MAX_CONCURRENT_ACTIONS: usize = 4;
+
+/// Configures how a `SonosDevice` communicates with the device.
+/// Use `SonosDevice::builder()` to create one.
+///
+/// Each device handle keeps a pool of persistent connections to the
+/// device, which is shared by its clones. To share a single pool
+/// across several devices, pass the same client to `client()`.
+#[derive(Debug, Clone, Default)]
+pub struct SonosDeviceBuilder {
+    address: Option<Ipv4Addr>,
+    room_name: Option<String>,
+    #[cfg(feature = "reqwest")]
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    #[cfg(feature = "reqwest")]
+    pool_max_idle_per_host: Option<usize>,
+    #[cfg(feature = "reqwest")]
+    pool_idle_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    user_agent: Option<String>,
+    #[cfg(feature = "reqwest")]
+    headers: HeaderMap,
+    #[cfg(feature = "reqwest")]
+    proxy: Option<reqwest::Proxy>,
+    client: Option<SharedHttpClient>,
+    runtime: Option<SharedRuntime>,
+    metrics: Metrics,
+    max_concurrent_actions: Option<usize>,
+    volume_coalescing_window: Option<Duration>,
+    #[cfg(feature = "https")]
+    secure: bool,
+    #[cfg(feature = "https")]
+    pinned_certificates: Vec<crate::CertificateFingerprint>,
+}
+
+impl SonosDeviceBuilder {
+    /// Sets the address of the device that `build()` connects to
+    pub fn address(mut self, addr: Ipv4Addr) -> Self {
+        self.address.replace(addr);
+        self
+    }
+
+    /// Sets the room name of the device, so that `SonosDevice::name`
+    /// doesn't need to ask the device for it. This is useful when the
+    /// name is already known, such as from the zone group topology.
+    /// The name is not checked against the device.
+    pub fn room_name(mut self, room_name: &str) -> Self {
+        self.room_name.replace(room_name.to_string());
+        self
+    }
+
+    /// Sets the time allowed to establish a connection to the device
+    #[cfg(feature = "reqwest")]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout.replace(timeout);
+        self
+    }
+
+    /// Sets the time allowed for a request to complete, from
+    /// connecting through to reading the response.
+    /// This can be overridden for individual calls via
+    /// `SonosDevice::with_timeout`.
+    #[cfg(feature = "reqwest")]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout.replace(timeout);
+        self
+    }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy.replace(policy);
+        self
+    }
+
+    /// Sets the maximum number of idle connections that are kept
+    /// open to the device
+    #[cfg(feature = "reqwest")]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host.replace(max);
+        self
+    }
+
+    /// Sets how long an idle connection is kept open before
+    /// it is closed
+    #[cfg(feature = "reqwest")]
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout.replace(timeout);
+        self
+    }
+
+    /// Sets the User-Agent that is sent with SOAP and event
+    /// subscription requests
+    #[cfg(feature = "reqwest")]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent.replace(user_agent.to_string());
+        self
+    }
+
+    /// Adds a header that is sent with SOAP and event
+    /// subscription requests
+    #[cfg(feature = "reqwest")]
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sends SOAP and event subscription requests via `proxy`, eg:
+    /// `reqwest::Proxy::all("http://127.0.0.1:8080")?` to inspect
+    /// the traffic with mitmproxy.
+    /// Events are delivered by the device directly to the callback
+    /// server and so do not pass through the proxy.
+    #[cfg(feature = "reqwest")]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy.replace(proxy);
+        self
+    }
+
+    /// Uses `client` for all requests, rather than building one
+    /// from the options of this builder. The timeout, pool, user agent,
+    /// header and proxy options of the builder are ignored when a client
+    /// is supplied.
+    #[cfg(feature = "reqwest")]
+    pub fn client(self, client: reqwest::Client) -> Self {
+        self.http_client(Arc::new(client))
+    }
+
+    /// Uses `client` for all requests, allowing an alternative HTTP
+    /// stack to be used. As with `client()`, the timeout, pool,
+    /// user agent, header and proxy options of the builder are ignored.
+    /// Without the `reqwest` feature, a client must be supplied.
+    pub fn http_client(mut self, client: Arc<dyn HttpClient>) -> Self {
+        self.client.replace(SharedHttpClient::new(client));
+        self
+    }
+
+    /// Uses `runtime` to spawn tasks, sleep and open sockets, rather
+    /// than `TokioRuntime`. This is required when the `tokio-runtime`
+    /// feature is disabled.
+    pub fn runtime(mut self, runtime: Arc<dyn Runtime>) -> Self {
+        self.runtime.replace(SharedRuntime::new(runtime));
+        self
+    }
+
+    #[cfg(feature = "discovery")]
+    pub(crate) fn with_shared_runtime(mut self, runtime: SharedRuntime) -> Self {
+        self.runtime.replace(runtime);
+        self
+    }
+
+    /// Sets the maximum number of SOAP actions that may be in flight
+    /// to the device at once; further actions wait their turn.
+    /// The limit is shared by the clones of the resulting device.
+    /// Defaults to 4.
+    pub fn max_concurrent_actions(mut self, max: usize) -> Self {
+        self.max_concurrent_actions.replace(max.max(1));
+        self
+    }
+
+    /// Coalesces rapid successive `set_volume` calls: each call waits
+    /// for `window` and is dropped if another call was made in the
+    /// meantime, so that only the final value is sent to the device.
+    /// This prevents a UI slider from queueing many requests.
+    pub fn coalesce_volume(mut self, window: Duration) -> Self {
+        self.volume_coalescing_window.replace(window);
+        self
+    }
+
+    /// Reports the latency and outcome of each SOAP action to `sink`
+    pub fn metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Metrics::new(sink);
+        self
+    }
+
+    /// Talks to the device over HTTPS on port 1443, which is
+    /// supported by newer firmware, falling back to plain HTTP on
+    /// port 1400 if nothing accepts the connection on port 1443.
+    /// A TLS or certificate failure is returned as an error rather
+    /// than downgrading, as is any failure once certificates have
+    /// been pinned.
+    /// The device presents a self-signed certificate, which is
+    /// accepted unless certificates have been pinned via
+    /// `pin_certificate`.
+    #[cfg(feature = "https")]
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Only accepts a device certificate whose SHA-256 fingerprint
+    /// matches `fingerprint`, or one of the other pinned fingerprints.
+    /// Implies `secure(true)`.
+    /// Pinning requires the `rustls` feature; with only `native-tls`,
+    /// building the device fails with `Error::NotSupported`.
+    #[cfg(feature = "https")]
+    pub fn pin_certificate(mut self, fingerprint: crate::CertificateFingerprint) -> Self {
+        self.secure = true;
+        self.pinned_certificates.push(fingerprint);
+        self
+    }
+
+    pub(crate) fn build_client(&self) -> Result<SharedHttpClient> {
+        match &self.client {
+            Some(client) => Ok(client.clone()),
+            #[cfg(feature = "reqwest")]
+            None => self.build_reqwest_client(),
+            #[cfg(not(feature = "reqwest"))]
+            None => Err(Error::NoHttpClient),
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn build_reqwest_client(&self) -> Result<SharedHttpClient> {
+        let mut builder = reqwest::Client::builder().default_headers(self.headers.clone());
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        #[cfg(feature = "https")]
+        if self.secure {
+            builder = crate::tls::configure(builder, &self.pinned_certificates)?;
+        }
+        Ok(SharedHttpClient::new(Arc::new(builder.build()?)))
+    }
+
+    /// Constructs a SonosDevice from the address set via `address()`.
+    /// Validates that the device is actually a Sonos device
+    /// before returning successfully.
+    pub async fn build(self) -> Result<SonosDevice> {
+        let addr = self.address.ok_or(Error::NoAddress)?;
+        self.from_ip(addr).await
+    }
+
+    /// Constructs a SonosDevice from the supplied IP Address.
+    /// Validates that the device is actually a Sonos device
+    /// before returning successfully.
+    pub async fn from_ip(self, addr: Ipv4Addr) -> Result<SonosDevice> {
+        self.from_url(format!("http://{addr}:1400/xml/device_description.xml").parse()?)
+            .await
+    }
+
+    /// Constructs a SonosDevice from the supplied URL, which must
+    /// be the device_description.xml URL for that device.
+    /// Validates that the device is actually a Sonos device
+    /// before returning successfully.
+    pub async fn from_url(self, url: Url) -> Result<SonosDevice> {
+        let client = self.build_client()?;
+        let runtime = match &self.runtime {
+            Some(runtime) => runtime.clone(),
+            None => SharedRuntime::default_runtime()?,
+        };
+
+        #[cfg(feature = "https")]
+        if self.secure && url.scheme() == "http" {
+            let mut secure_url = url.clone();
+            secure_url.set_scheme("https").ok();
+            secure_url.set_port(Some(crate::tls::SECURE_PORT)).ok();
+            match fetch_device_spec(&client, &secure_url).await {
+                Ok(device) => {
+                    return Ok(self.into_device(secure_url, device, client, runtime));
+                }
+                Err(err) if self.pinned_certificates.is_empty() && is_unreachable(&err) => {
+                    log::debug!("{secure_url} failed: {err:#}, falling back to {url}");
+                }
+                // A TLS or certificate failure, or any failure once
+                // certificates are pinned, must not silently downgrade
+                // the connection to plain HTTP
+                Err(err) => return Err(err),
+            }
+        }
+
+        let device = fetch_device_spec(&client, &url).await?;
+        Ok(self.into_device(url, device, client, runtime))
+    }
+
+    fn into_device(
+        self,
+        url: Url,
+        device: DeviceSpec,
+        client: SharedHttpClient,
+        runtime: SharedRuntime,
+    ) -> SonosDevice {
+        SonosDevice {
+            shared: Arc::new(SharedDevice {
+                url,
+                device,
+                room_name: Mutex::new(self.room_name),
+                client,
+                runtime: runtime.clone(),
+                action_permits: tokio::sync::Semaphore::new(
+                    self.max_concurrent_actions
+                        .unwrap_or(DEFAULT_MAX_CONCURRENT_ACTIONS),
+                ),
+                volume_coalescer: self
+                    .volume_coalescing_window
+                    .map(|window| crate::coalesce::Coalescer::new(window, runtime)),
+                #[cfg(feature = "svc-music-services")]
+                services: Default::default(),
+            }),
+            options: Arc::new(HandleOptions {
+                retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+                timeout: None,
+                interceptors: Default::default(),
+                metrics: self.metrics,
+            }),
+        }
+    }
+}
+
+/// Returns true if `err` means that nothing accepted the connection
+/// at all, as opposed to a failure during or after the TLS handshake
+#[cfg(feature = "https")]
+fn is_unreachable(err: &Error) -> bool {
+    let Error::Reqwest(err) = err else {
+        return false;
+    };
+    err.is_connect() && io_error_is_unreachable(err)
+}
+
+#[cfg(feature = "https")]
+fn io_error_is_unreachable(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io.kind(),
+                std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::HostUnreachable
+                    | std::io::ErrorKind::NetworkUnreachable
+            );
+        }
+        source = err.source();
+    }
+    false
+}
+
+async fn fetch_device_spec(client: &SharedHttpClient, url: &Url) -> Result<DeviceSpec> {
+    let response = client.send(HttpRequest::get(url.clone())).await?.check()?;
+    DeviceSpec::parse_xml(&response.body)
+}
+
+impl SonosDevice {
+    pub fn builder() -> SonosDeviceBuilder {
+        SonosDeviceBuilder::default()
+    }
+
+    /// Returns a copy of this handle whose requests are allowed
+    /// `timeout` to complete, overriding the timeout configured via
+    /// the builder. This is useful for actions that take much longer
+    /// than most, such as `refresh_share_index`:
+    ///
+    /// ```no_run
+    /// # async fn example(device: sonos::SonosDevice) -> sonos::Result<()> {
+    /// use sonos::prelude::*;
+    /// use std::time::Duration;
+    /// device
+    ///     .with_timeout(Duration::from_secs(300))
+    ///     .refresh_share_index(sonos::content_directory::RefreshShareIndexRequest {
+    ///         album_artist_display_option: "NONE".to_string(),
+    ///     })
+    ///     .await
+    /// # }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        let mut device = self.clone();
+        Arc::make_mut(&mut device.options).timeout.replace(timeout);
+        device
+    }
+
+    /// Establishes a connection to the device ahead of time, so that
+    /// the first action that is performed doesn't have to wait
+    /// for it to be set up
+    pub async fn preconnect(&self) -> Result<()> {
+        self.identify_errors(async {
+            self.shared
+                .client
+                .send(HttpRequest::get(self.shared.url.clone()))
+                .await?
+                .check()?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "https"))]
+mod test {
+    use super::*;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("tls handshake failed")]
+    struct Handshake(#[source] std::io::Error);
+
+    #[test]
+    fn test_io_error_is_unreachable() {
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        assert!(io_error_is_unreachable(&refused));
+
+        let invalid = std::io::Error::new(std::io::ErrorKind::InvalidData, "bad certificate");
+        assert!(!io_error_is_unreachable(&invalid));
+        assert!(!io_error_is_unreachable(&Handshake(invalid)));
+
+        assert!(!is_unreachable(&Error::NoAddress));
+    }
+}
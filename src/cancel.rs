@@ -0,0 +1,37 @@
+use std::future::Future;
+use std::task::Poll;
+
+/// Runs `fut` until it completes, returning its output, or until
+/// `cancel` resolves, in which case `fut` is dropped and `None`
+/// is returned
+pub(crate) async fn until_cancelled<F: Future>(
+    fut: F,
+    cancel: impl Future<Output = ()>,
+) -> Option<F::Output> {
+    let mut fut = std::pin::pin!(fut);
+    let mut cancel = std::pin::pin!(cancel);
+    std::future::poll_fn(|cx| {
+        if cancel.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        fut.as_mut().poll(cx).map(Some)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_until_cancelled() {
+        assert_eq!(
+            until_cancelled(async { 42 }, std::future::pending()).await,
+            Some(42)
+        );
+        assert_eq!(
+            until_cancelled(std::future::pending::<u32>(), async {}).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::capabilities::{BATTERY_MODELS, FIXED_VOLUME_MODELS, NON_AIRPLAY_MODELS};
+use crate::SonosDevice;
+
+/// The service types whose presence indicates a capability. These are
+/// spelled out rather than taken from the generated service modules so
+/// that capabilities can be checked even when those services are not
+/// compiled in.
+const HT_CONTROL_SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:HTControl:1";
+const AUDIO_IN_SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AudioIn:1";
+
+/// Optional features that are not present on every player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Capability {
+    /// The player is a soundbar or home theater amp that can
+    /// play TV audio, and has the HTControl service
+    HtPlayback,
+    /// The player has an analog line-in, and has the AudioIn service
+    LineIn,
+    /// The player is portable and has a battery
+    Battery,
+    /// The player can be the target of an AirPlay 2 stream.
+    /// S2 players support this unless they are listed in
+    /// `capabilities::NON_AIRPLAY_MODELS`.
+    AirPlay,
+    /// The player has a line-out that can be fixed at full volume
+    FixedVolume,
+}
+
+impl SonosDevice {
+    /// Returns the model number reported in the device description,
+    /// eg: `S14`
+    pub fn model_number(&self) -> Option<&str> {
+        self.device_spec().model_number.as_deref()
+    }
+
+    /// Returns true if the device supports `capability`.
+    /// This is derived from the device description and does not
+    /// communicate with the device, so it can be used to decide which
+    /// controls to present without first attempting the operation.
+    pub fn supports(&self, capability: Capability) -> bool {
+        let model_is = |models: &[&str]| {
+            self.model_number()
+                .map(|model| models.contains(&model))
+                .unwrap_or(false)
+        };
+        match capability {
+            Capability::HtPlayback => self
+                .device_spec()
+                .get_service(HT_CONTROL_SERVICE_TYPE)
+                .is_some(),
+            Capability::LineIn => self
+                .device_spec()
+                .get_service(AUDIO_IN_SERVICE_TYPE)
+                .is_some(),
+            Capability::Battery => model_is(BATTERY_MODELS),
+            Capability::FixedVolume => model_is(FIXED_VOLUME_MODELS),
+            Capability::AirPlay => self.model_number().is_some() && !model_is(NON_AIRPLAY_MODELS),
+        }
+    }
+
+    /// Returns the set of capabilities supported by the device
+    pub fn capabilities(&self) -> Vec<Capability> {
+        [
+            Capability::HtPlayback,
+            Capability::LineIn,
+            Capability::Battery,
+            Capability::AirPlay,
+            Capability::FixedVolume,
+        ]
+        .into_iter()
+        .filter(|&capability| self.supports(capability))
+        .collect()
+    }
+
+    /// Returns whether this model supports `action` of `service`,
+    /// eg: `supports_action("HTControl", "SetIRRepeaterState")`,
+    /// according to `capabilities::is_supported`.
+    /// Returns `None` if the model or action is not known.
+    pub fn supports_action(&self, service: &str, action: &str) -> Option<bool> {
+        crate::capabilities::is_supported(self.model_number()?, service, action)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::capabilities::{is_supported, supported_by};
+
+    #[test]
+    fn test_action_support() {
+        assert_eq!(
+            is_supported("S14", "HTControl", "SetIRRepeaterState"),
+            Some(true)
+        );
+        assert_eq!(
+            is_supported("S1", "HTControl", "SetIRRepeaterState"),
+            Some(false)
+        );
+        assert_eq!(is_supported("S14", "HTControl", "NoSuchAction"), None);
+        assert_eq!(is_supported("S999", "AVTransport", "Play"), None);
+        assert!(supported_by("AVTransport", "Play").contains(&"S1"));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_supports() {
+        use crate::{Capability, Exchange, ReplayClient, SonosDevice};
+        use std::sync::Arc;
+
+        async fn device_with_model(model: &str) -> SonosDevice {
+            let client = ReplayClient::new(vec![Exchange {
+                method: "GET".to_string(),
+                path: "/xml/device_description.xml".to_string(),
+                status: 200,
+                response_body: include_str!("../data/device_spec.xml").replacen(
+                    "<modelNumber>S23</modelNumber>",
+                    &format!("<modelNumber>{model}</modelNumber>"),
+                    1,
+                ),
+                ..Default::default()
+            }]);
+            SonosDevice::builder()
+                .http_client(Arc::new(client))
+                .from_url(
+                    "http://192.168.1.2:1400/xml/device_description.xml"
+                        .parse()
+                        .unwrap(),
+                )
+                .await
+                .unwrap()
+        }
+
+        // A Port has a line-in, and a line-out that can be fixed
+        let port = device_with_model("S23").await;
+        assert_eq!(
+            port.capabilities(),
+            vec![
+                Capability::LineIn,
+                Capability::AirPlay,
+                Capability::FixedVolume
+            ]
+        );
+
+        let roam = device_with_model("S27").await;
+        assert!(roam.supports(Capability::Battery));
+        assert!(roam.supports(Capability::AirPlay));
+        assert!(!roam.supports(Capability::FixedVolume));
+
+        let play1 = device_with_model("S1").await;
+        assert!(!play1.supports(Capability::AirPlay));
+        assert!(!play1.supports(Capability::HtPlayback));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::runtime::SharedRuntime;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Collapses a burst of writes into the last of them.
+/// Each write waits for the window to elapse; only the write that
+/// was not superseded by another during that time goes ahead.
+#[derive(Debug)]
+pub(crate) struct Coalescer {
+    window: Duration,
+    generation: AtomicU64,
+    runtime: SharedRuntime,
+}
+
+impl Coalescer {
+    pub fn new(window: Duration, runtime: SharedRuntime) -> Self {
+        Self {
+            window,
+            generation: AtomicU64::new(0),
+            runtime,
+        }
+    }
+
+    /// Waits for the window to elapse, returning true if no other
+    /// write was started in the meantime
+    pub async fn is_latest(&self) -> bool {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.runtime.sleep(self.window).await;
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_coalesce() {
+        let coalescer = Coalescer::new(
+            Duration::from_millis(20),
+            SharedRuntime::default_runtime().unwrap(),
+        );
+        let (first, second) = tokio::join!(coalescer.is_latest(), async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            coalescer.is_latest().await
+        });
+        assert!(!first);
+        assert!(second);
+        assert!(coalescer.is_latest().await);
+    }
+}
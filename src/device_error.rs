@@ -0,0 +1,154 @@
+use crate::{Error, Result, SonosDevice};
+use std::fmt;
+use std::future::Future;
+use std::net::IpAddr;
+
+/// Identifies the device that an error came from; see `Error::Device`.
+/// This is captured when the error occurs, so the room name is only
+/// present if it had already been cached by `SonosDevice::name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceIdentity {
+    pub room_name: Option<String>,
+    /// The model name, eg: `Sonos One`, falling back to the model
+    /// number if the device didn't report a name
+    pub model: Option<String>,
+    pub ip: Option<IpAddr>,
+}
+
+impl fmt::Display for DeviceIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.room_name.as_deref().unwrap_or("<unnamed>"))?;
+        if let Some(model) = &self.model {
+            write!(f, " ({model})")?;
+        }
+        if let Some(ip) = &self.ip {
+            write!(f, " at {ip}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SonosDevice {
+    /// Returns the identity of this device, as attached to the
+    /// errors that it returns
+    pub fn identity(&self) -> DeviceIdentity {
+        DeviceIdentity {
+            room_name: self.shared.room_name.lock().unwrap().clone(),
+            model: self
+                .shared
+                .device
+                .model_name
+                .clone()
+                .or_else(|| self.shared.device.model_number.clone()),
+            ip: self
+                .shared
+                .url
+                .host_str()
+                .and_then(|host| host.parse().ok()),
+        }
+    }
+
+    /// Awaits `fut`, wrapping any error that it returns in
+    /// `Error::Device` to identify this device
+    pub(crate) async fn identify_errors<T>(
+        &self,
+        fut: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        fut.await.map_err(|err| err.with_device(self))
+    }
+}
+
+impl Error {
+    /// Wraps this error in `Error::Device`, unless it already
+    /// identifies a device
+    pub(crate) fn with_device(self, device: &SonosDevice) -> Error {
+        match self {
+            Error::Device { .. } => self,
+            error => Error::Device {
+                device: Box::new(device.identity()),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Returns the identity of the device that this error came from,
+    /// if it is known
+    pub fn device(&self) -> Option<&DeviceIdentity> {
+        match self {
+            Error::Device { device, .. } => Some(device),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error, without the device context
+    /// added by `Error::Device`
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Device { error, .. } => error,
+            _ => self,
+        }
+    }
+
+    /// Like `inner`, but takes ownership of the error
+    pub fn into_inner(self) -> Error {
+        match self {
+            Error::Device { error, .. } => *error,
+            _ => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_device_error() {
+        let error = Error::Device {
+            device: Box::new(DeviceIdentity {
+                room_name: Some("Kitchen".to_string()),
+                model: Some("Sonos One".to_string()),
+                ip: Some("192.168.1.10".parse().unwrap()),
+            }),
+            error: Box::new(Error::VolumeNone),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Kitchen (Sonos One) at 192.168.1.10: Device reports None for volume"
+        );
+        assert_eq!(
+            error.device().and_then(|d| d.room_name.as_deref()),
+            Some("Kitchen")
+        );
+        assert!(matches!(error.inner(), Error::VolumeNone));
+        assert!(matches!(error.into_inner(), Error::VolumeNone));
+
+        let identity = DeviceIdentity {
+            room_name: None,
+            model: None,
+            ip: None,
+        };
+        assert_eq!(identity.to_string(), "<unnamed>");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_http_error_identifies_device() {
+        use crate::vcr::{replay_device, Exchange};
+
+        let (device, _client) = replay_device(vec![Exchange {
+            method: "GET".to_string(),
+            path: "/status/batterystatus".to_string(),
+            status: 404,
+            ..Default::default()
+        }])
+        .await;
+
+        let error = device.battery_status().await.unwrap_err();
+        assert_eq!(
+            error.device().and_then(|d| d.ip),
+            Some("192.168.1.2".parse().unwrap())
+        );
+        assert!(matches!(error.inner(), Error::NotSupported(_)));
+    }
+}
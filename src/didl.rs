@@ -1,4 +1,4 @@
-use crate::{DecodeXml, EncodeXml, Error, Result};
+use crate::{DecodeXml, EncodeXml, Error, QueueObjectId, Result};
 use instant_xml::{FromXml, ToXml};
 use std::time::Duration;
 
@@ -11,7 +11,11 @@ const XMLNS_RINCONN: &str = "urn:schemas-rinconnetworks-com:metadata-1-0/";
 /// This type can be converted to/from the corresponding DIDL-Lite
 /// xml form.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackMetaData {
+    /// The DIDL-Lite item id, eg: `Q:0/1` for an item in the queue;
+    /// see `queue_item_id`
+    pub id: Option<String>,
     pub title: String,
     pub creator: Option<String>,
     pub album: Option<String>,
@@ -20,6 +24,9 @@ pub struct TrackMetaData {
     pub mime_type: Option<String>,
     pub art_url: Option<String>,
     pub class: ObjectClass,
+    /// The Sonos-specific `desc` value, which identifies the music
+    /// service account that provides the item, eg: `SA_RINCON65031_`
+    pub desc: Option<String>,
 }
 
 impl DecodeXml for TrackMetaData {
@@ -42,6 +49,7 @@ impl EncodeXml for TrackMetaData {
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackMetaDataList {
     pub tracks: Vec<TrackMetaData>,
 }
@@ -104,7 +112,21 @@ pub fn hms_to_duration(hms: &str) -> Duration {
 }
 
 impl TrackMetaData {
+    /// Returns the id of this item in the queue, for items
+    /// returned by `SonosDevice::queue_browse`
+    pub fn queue_item_id(&self) -> Option<QueueObjectId> {
+        self.id.as_deref()?.parse().ok()
+    }
+
+    /// Encodes the metadata as DIDL-Lite.
+    /// Items are described as music tracks, regardless of `class`,
+    /// unless they carry the `desc` of a music service, in which case
+    /// the player needs the real class to accept the item.
     pub fn to_didl_string(&self) -> String {
+        let class = match &self.desc {
+            Some(_) => self.class.clone(),
+            None => ObjectClass::MusicTrack,
+        };
         let didl = DidlLite {
             item: vec![UpnpItem {
                 queue_item_id: None,
@@ -113,7 +135,7 @@ impl TrackMetaData {
                     .clone()
                     .map(|mime_type| MimeType { mime_type }),
                 duration: None,
-                id: "-1".to_string(),
+                id: self.id.clone().unwrap_or_else(|| "-1".to_string()),
                 parent_id: "-1".to_string(),
                 restricted: Some(true),
                 res: Some(Res {
@@ -135,7 +157,12 @@ impl TrackMetaData {
                     .map(|album_title| AlbumTitle { album_title }),
                 creator: self.creator.clone().map(|artist| Creator { artist }),
                 artist: self.creator.clone().map(|artist| Artist { artist }),
-                class: Some(ObjectClass::MusicTrack),
+                class: Some(class),
+                desc: self.desc.clone().map(|value| Desc {
+                    id: "cdudn".to_string(),
+                    namespace: XMLNS_RINCONN.to_string(),
+                    value,
+                }),
             }],
         };
         instant_xml::to_string(&didl).expect("infallible xml encode!?")
@@ -146,6 +173,7 @@ impl TrackMetaData {
         let mut result = vec![];
         for item in didl.item {
             result.push(Self {
+                id: Some(item.id),
                 class: item.class.unwrap_or_default(),
                 album: item.album_title.map(|a| a.album_title),
                 creator: item.creator.map(|a| a.artist),
@@ -167,6 +195,7 @@ impl TrackMetaData {
                     let fields: Vec<&str> = r.protocol_info.as_ref()?.split(':').collect();
                     fields.get(2).map(|mime_type| mime_type.to_string())
                 }),
+                desc: item.desc.map(|d| d.value).filter(|v| !v.is_empty()),
             });
         }
         Ok(result)
@@ -199,6 +228,7 @@ pub struct UpnpItem {
     pub class: Option<ObjectClass>,
     pub mime_type: Option<MimeType>,
     pub queue_item_id: Option<QueueItemId>,
+    pub desc: Option<Desc>,
 }
 
 #[derive(Debug, FromXml, ToXml)]
@@ -268,7 +298,19 @@ pub struct QueueItemId {
     pub id: String,
 }
 
+#[derive(Debug, FromXml, ToXml)]
+#[xml(rename = "desc", ns(XMLNS_DIDL_LITE))]
+pub struct Desc {
+    #[xml(attribute)]
+    pub id: String,
+    #[xml(attribute, rename = "nameSpace")]
+    pub namespace: String,
+    #[xml(direct)]
+    pub value: String,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, FromXml, ToXml)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(rename="class", scalar, ns(XMLNS_UPNP, upnp=XMLNS_UPNP))]
 pub enum ObjectClass {
     #[xml(rename = "object.item.audioItem.musicTrack")]
@@ -280,6 +322,8 @@ pub enum ObjectClass {
     LineIn,
     #[xml(rename = "object.container.playlistContainer")]
     PlayList,
+    #[xml(rename = "object.container.album.musicAlbum")]
+    MusicAlbum,
     #[xml(rename = "object.container")]
     Container,
     #[xml(rename = "object.item")]
@@ -319,6 +363,7 @@ mod test {
                 title: Some(Title {
                     title: "Track Title".to_string(),
                 }),
+                desc: None,
             }],
         };
         k9::snapshot!(
@@ -396,6 +441,7 @@ DidlLite {
                     id: "http://192.168.1.214:8097/single/RINCON_XXX/51f8b02b9d3b4a88b97dd385ba2b572b.flac?ts=1716507641",
                 },
             ),
+            desc: None,
         },
     ],
 }
@@ -439,6 +485,13 @@ DidlLite {
             ),
             mime_type: None,
             queue_item_id: None,
+            desc: Some(
+                Desc {
+                    id: "cdudn",
+                    namespace: "urn:schemas-rinconnetworks-com:metadata-1-0/",
+                    value: "",
+                },
+            ),
         },
     ],
 }
@@ -446,6 +499,20 @@ DidlLite {
         );
     }
 
+    #[test]
+    fn test_to_didl_string_class() {
+        let line_in = TrackMetaData {
+            title: "Line-In".to_string(),
+            url: "x-rincon-stream:RINCON_XXX".to_string(),
+            class: ObjectClass::LineIn,
+            ..Default::default()
+        };
+        k9::snapshot!(
+            line_in.to_didl_string(),
+            r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/"><item id="-1" parentID="-1" restricted="true"><res protocolInfo="http-get:*:audio/mpeg">x-rincon-stream:RINCON_XXX</res><dc:title>Line-In</dc:title><upnp:class>object.item.audioItem.musicTrack</upnp:class></item></DIDL-Lite>"#
+        );
+    }
+
     #[test]
     fn test_hms() {
         fn r(hms: &str, s: u64) {
@@ -1,7 +1,11 @@
-use crate::{Result, SonosDevice};
+use crate::cancel::until_cancelled;
+use crate::runtime::SharedRuntime;
+use crate::{Result, Runtime, SonosDevice};
 use std::collections::BTreeMap;
-use std::time::Duration;
-use tokio::net::UdpSocket;
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::{channel, Receiver};
 
 /// URN identifying Sonos ZonePlayer compatible products.
@@ -10,12 +14,53 @@ use tokio::sync::mpsc::{channel, Receiver};
 /// own custom discovery functionality.
 pub const SONOS_URN: &str = "urn:schemas-upnp-org:device:ZonePlayer:1";
 
+/// The SSDP multicast address
+const SSDP_ADDR: SocketAddr =
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(239, 255, 255, 250), 1900));
+
 /// Discover SonosDevices on the network, stopping once the specified
 /// timeout is reached.
 /// Returns a channel that will yield `SonosDevice` instances as responses
 /// to discovery requests are detected.
 /// Note that it is possible (likely) for duplicates to be returned.
 pub async fn discover(timeout: Duration) -> Result<Receiver<SonosDevice>> {
+    discover_until(
+        SharedRuntime::default_runtime()?,
+        timeout,
+        std::future::pending(),
+    )
+    .await
+}
+
+/// Like `discover`, but uses `runtime` rather than `TokioRuntime`,
+/// both for discovery and for the devices that are found
+pub async fn discover_with_runtime(
+    runtime: Arc<dyn Runtime>,
+    timeout: Duration,
+) -> Result<Receiver<SonosDevice>> {
+    discover_until(SharedRuntime::new(runtime), timeout, std::future::pending()).await
+}
+
+/// Like `discover`, but also stops once `token` is cancelled,
+/// closing the channel
+#[cfg(feature = "cancellation")]
+pub async fn discover_cancellable(
+    timeout: Duration,
+    token: tokio_util::sync::CancellationToken,
+) -> Result<Receiver<SonosDevice>> {
+    discover_until(
+        SharedRuntime::default_runtime()?,
+        timeout,
+        token.cancelled_owned(),
+    )
+    .await
+}
+
+async fn discover_until(
+    runtime: SharedRuntime,
+    timeout: Duration,
+    cancel: impl Future<Output = ()> + Send + 'static,
+) -> Result<Receiver<SonosDevice>> {
     const MX: usize = 3;
 
     let timeout = if timeout.as_secs() as usize <= MX {
@@ -33,56 +78,65 @@ pub async fn discover(timeout: Duration) -> Result<Receiver<SonosDevice>> {
     );
     const DEFAULT_SEARCH_TTL: u32 = 2;
 
-    let socket = UdpSocket::bind("0.0.0.0:0").await?;
-    socket.set_multicast_ttl_v4(DEFAULT_SEARCH_TTL).ok();
-    socket
-        .send_to(disco_packet.as_bytes(), "239.255.255.250:1900")
+    let socket = runtime
+        .bind_udp(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
         .await?;
+    socket.set_multicast_ttl_v4(DEFAULT_SEARCH_TTL).ok();
+    socket.send_to(disco_packet.as_bytes(), SSDP_ADDR).await?;
 
-    let deadline = tokio::time::Instant::now() + timeout;
+    let deadline = Instant::now() + timeout;
 
     let (tx, rx) = channel(8);
 
-    tokio::spawn(async move {
-        let mut buf = [0u8; 2048];
-
-        loop {
-            match tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
-                Ok(Ok((n_read, peer))) => {
-                    let buf = &buf[0..n_read];
-                    let buf = String::from_utf8_lossy(&buf);
-                    log::trace!("DISCO: ({peer:?}) {buf}");
-                    let mut headers: BTreeMap<String, String> = BTreeMap::new();
-                    for line in buf.lines() {
-                        let Some((name, value)) = line.split_once(':') else {
-                            continue;
-                        };
-
-                        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
-                    }
-                    log::trace!("Headers: {headers:?}");
-
-                    match (headers.get("st"), headers.get("location")) {
-                        (Some(st), Some(url)) if st == SONOS_URN => {
-                            if let Ok(url) = url.parse() {
-                                if let Ok(device) = SonosDevice::from_url(url).await {
-                                    if tx.send(device).await.is_err() {
-                                        break;
+    runtime.clone().spawn(Box::pin(async move {
+        let discovery = async {
+            let mut buf = [0u8; 2048];
+
+            loop {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                match runtime.timeout(remaining, socket.recv_from(&mut buf)).await {
+                    Some(Ok((n_read, peer))) => {
+                        let buf = &buf[0..n_read];
+                        let buf = String::from_utf8_lossy(&buf);
+                        log::trace!("DISCO: ({peer:?}) {buf}");
+                        let mut headers: BTreeMap<String, String> = BTreeMap::new();
+                        for line in buf.lines() {
+                            let Some((name, value)) = line.split_once(':') else {
+                                continue;
+                            };
+
+                            headers
+                                .insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+                        }
+                        log::trace!("Headers: {headers:?}");
+
+                        match (headers.get("st"), headers.get("location")) {
+                            (Some(st), Some(url)) if st == SONOS_URN => {
+                                if let Ok(url) = url.parse() {
+                                    let device = SonosDevice::builder()
+                                        .with_shared_runtime(runtime.clone())
+                                        .from_url(url)
+                                        .await;
+                                    if let Ok(device) = device {
+                                        if tx.send(device).await.is_err() {
+                                            break;
+                                        }
                                     }
                                 }
                             }
+                            _ => {}
                         }
-                        _ => {}
                     }
+                    Some(Err(err)) => {
+                        log::error!("{err:#}");
+                        break;
+                    }
+                    None => break,
                 }
-                Ok(Err(err)) => {
-                    log::error!("{err:#}");
-                    break;
-                }
-                Err(_) => break,
             }
-        }
-    });
+        };
+        until_cancelled(discovery, cancel).await;
+    }));
 
     Ok(rx)
 }
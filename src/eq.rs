@@ -0,0 +1,180 @@
+use crate::{
+    rendering_control, Bass, Channel, Error, InstanceId, RenderingControl, Result, SonosDevice,
+    Treble,
+};
+use std::ops::RangeInclusive;
+
+/// The range of the bass and treble settings
+pub const EQ_RANGE: RangeInclusive<i16> = -10..=10;
+
+/// The basic equalizer settings of a player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqSettings {
+    /// In the range -10..=10
+    pub bass: i16,
+    /// In the range -10..=10
+    pub treble: i16,
+    pub loudness: bool,
+}
+
+impl EqSettings {
+    /// Returns an error if any of the settings are out of range
+    pub fn validate(&self) -> Result<()> {
+        check_range("Bass", self.bass, EQ_RANGE)?;
+        check_range("Treble", self.treble, EQ_RANGE)
+    }
+}
+
+pub(crate) fn check_range(
+    name: &'static str,
+    value: i16,
+    range: RangeInclusive<i16>,
+) -> Result<()> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange {
+            name,
+            value: value.into(),
+            min: (*range.start()).into(),
+            max: (*range.end()).into(),
+        })
+    }
+}
+
+impl SonosDevice {
+    /// Returns the bass, treble and loudness settings
+    pub async fn eq(&self) -> Result<EqSettings> {
+        let bass = <Self as RenderingControl>::get_bass(
+            self,
+            rendering_control::GetBassRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?
+        .current_bass
+        .map(Bass::get)
+        .ok_or(Error::MissingValue("CurrentBass"))?;
+
+        let treble = <Self as RenderingControl>::get_treble(
+            self,
+            rendering_control::GetTrebleRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?
+        .current_treble
+        .map(Treble::get)
+        .ok_or(Error::MissingValue("CurrentTreble"))?;
+
+        let loudness = <Self as RenderingControl>::get_loudness(
+            self,
+            rendering_control::GetLoudnessRequest {
+                instance_id: InstanceId::DEFAULT,
+                channel: Channel::Master,
+            },
+        )
+        .await?
+        .current_loudness
+        .ok_or(Error::MissingValue("CurrentLoudness"))?;
+
+        Ok(EqSettings {
+            bass,
+            treble,
+            loudness,
+        })
+    }
+
+    /// Applies the bass, treble and loudness settings.
+    /// The settings are validated before any of them are applied.
+    pub async fn set_eq(&self, settings: EqSettings) -> Result<()> {
+        settings.validate()?;
+
+        <Self as RenderingControl>::set_bass(
+            self,
+            rendering_control::SetBassRequest {
+                instance_id: InstanceId::DEFAULT,
+                desired_bass: Bass::new(settings.bass)?,
+            },
+        )
+        .await?;
+
+        <Self as RenderingControl>::set_treble(
+            self,
+            rendering_control::SetTrebleRequest {
+                instance_id: InstanceId::DEFAULT,
+                desired_treble: Treble::new(settings.treble)?,
+            },
+        )
+        .await?;
+
+        <Self as RenderingControl>::set_loudness(
+            self,
+            rendering_control::SetLoudnessRequest {
+                instance_id: InstanceId::DEFAULT,
+                channel: Channel::Master,
+                desired_loudness: settings.loudness,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(EqSettings {
+            bass: -10,
+            treble: 10,
+            loudness: true
+        }
+        .validate()
+        .is_ok());
+
+        match (EqSettings {
+            bass: 0,
+            treble: 11,
+            loudness: false,
+        })
+        .validate()
+        {
+            Err(Error::OutOfRange { name, value, .. }) => {
+                assert_eq!(name, "Treble");
+                assert_eq!(value, 11);
+            }
+            result => panic!("unexpected {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_bounded_newtype() {
+        assert_eq!(Bass::new(-10).unwrap().get(), -10);
+        assert!(Bass::new(-11).is_err());
+        assert_eq!(Treble::saturating(42).get(), 10);
+        assert_eq!("7".parse::<Treble>().unwrap(), Treble::new(7).unwrap());
+        assert!(matches!(
+            "11".parse::<Treble>(),
+            Err(Error::OutOfRange { value: 11, .. })
+        ));
+        match "loud".parse::<Treble>() {
+            Err(Error::InvalidNumber { name, value }) => {
+                assert_eq!(name, "Treble");
+                assert_eq!(value, "loud");
+            }
+            result => panic!("unexpected {result:?}"),
+        }
+        let xml = instant_xml::to_string(&rendering_control::SetBassRequest {
+            instance_id: InstanceId::DEFAULT,
+            desired_bass: Bass::new(-3).unwrap(),
+        })
+        .unwrap();
+        assert!(
+            xml.contains(r#"<DesiredBass xmlns="">-3</DesiredBass>"#),
+            "{xml}"
+        );
+    }
+}
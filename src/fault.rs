@@ -0,0 +1,316 @@
+use crate::redact::{redact_xml, SENSITIVE_ARGUMENTS};
+use crate::{Error, SOAP_ENVELOPE};
+use http::StatusCode;
+use instant_xml::FromXml;
+use std::fmt;
+
+const UPNP_CONTROL_NS: &str = "urn:schemas-upnp-org:control-1-0";
+
+/// The maximum number of bytes of each SOAP body that is retained
+/// by `RawSoap`
+const RAW_XML_CAP: usize = 16 * 1024;
+
+/// The SOAP request and response bodies of a failed action,
+/// retained to help debug interop problems.
+/// Each body is truncated to 16KiB, and credentials such as
+/// account passwords and tokens are replaced with `REDACTED`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RawSoap {
+    /// The request as it was sent, after any interceptors rewrote it
+    pub request: String,
+    pub response: String,
+}
+
+impl RawSoap {
+    pub(crate) fn new(request: &str, response: &str) -> Self {
+        Self {
+            request: truncate(&redact_xml(request, SENSITIVE_ARGUMENTS)),
+            response: truncate(&redact_xml(response, SENSITIVE_ARGUMENTS)),
+        }
+    }
+}
+
+fn truncate(s: &str) -> String {
+    if s.len() <= RAW_XML_CAP {
+        return s.to_string();
+    }
+    let mut end = RAW_XML_CAP;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &s[..end])
+}
+
+/// Well-known UPnP error codes.
+/// The meaning of the codes in the 7xx range depends on the service;
+/// the variants here are named for their meaning in AVTransport,
+/// which is the service most likely to report them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpnpErrorCode {
+    /// 401
+    InvalidAction,
+    /// 402
+    InvalidArgs,
+    /// 501
+    ActionFailed,
+    /// 600
+    ArgumentValueInvalid,
+    /// 601
+    ArgumentValueOutOfRange,
+    /// 602
+    OptionalActionNotImplemented,
+    /// 701; ContentDirectory uses this code for "no such object",
+    /// and the group services use it when the player is not the
+    /// coordinator of its group
+    TransitionNotAvailable,
+    /// 702
+    NoContents,
+    /// 703
+    ReadError,
+    /// 704
+    PlaybackFormatNotSupported,
+    /// 705
+    TransportLocked,
+    /// 706
+    WriteError,
+    /// 707
+    MediaProtected,
+    /// 708
+    RecordFormatNotSupported,
+    /// 709
+    MediaFull,
+    /// 710
+    SeekModeNotSupported,
+    /// 711
+    IllegalSeekTarget,
+    /// 712
+    PlayModeNotSupported,
+    /// 713
+    RecordQualityNotSupported,
+    /// 714; reported when a URI is passed without the metadata
+    /// that the player needs in order to play it
+    IllegalMimeType,
+    /// 715
+    ContentBusy,
+    /// 716
+    ResourceNotFound,
+    /// 717
+    PlaySpeedNotSupported,
+    /// 718
+    InvalidInstanceId,
+    /// 800; reported when the command is not supported, or when it
+    /// must be sent to the coordinator of the group
+    GroupOperationFailed,
+    /// A code that is not described above
+    Other(u32),
+}
+
+impl From<u32> for UpnpErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            401 => Self::InvalidAction,
+            402 => Self::InvalidArgs,
+            501 => Self::ActionFailed,
+            600 => Self::ArgumentValueInvalid,
+            601 => Self::ArgumentValueOutOfRange,
+            602 => Self::OptionalActionNotImplemented,
+            701 => Self::TransitionNotAvailable,
+            702 => Self::NoContents,
+            703 => Self::ReadError,
+            704 => Self::PlaybackFormatNotSupported,
+            705 => Self::TransportLocked,
+            706 => Self::WriteError,
+            707 => Self::MediaProtected,
+            708 => Self::RecordFormatNotSupported,
+            709 => Self::MediaFull,
+            710 => Self::SeekModeNotSupported,
+            711 => Self::IllegalSeekTarget,
+            712 => Self::PlayModeNotSupported,
+            713 => Self::RecordQualityNotSupported,
+            714 => Self::IllegalMimeType,
+            715 => Self::ContentBusy,
+            716 => Self::ResourceNotFound,
+            717 => Self::PlaySpeedNotSupported,
+            718 => Self::InvalidInstanceId,
+            800 => Self::GroupOperationFailed,
+            code => Self::Other(code),
+        }
+    }
+}
+
+/// A SOAP fault reported by the device in response to an action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpnpError {
+    pub code: u32,
+    pub description: Option<String>,
+    /// The service type, eg: `urn:schemas-upnp-org:service:AVTransport:1`
+    pub service: String,
+    /// The name of the action, eg: `Play`
+    pub action: String,
+    /// The SOAP request and response
+    pub raw: Option<Box<RawSoap>>,
+}
+
+impl UpnpError {
+    pub fn kind(&self) -> UpnpErrorCode {
+        self.code.into()
+    }
+
+    /// Parses a SOAP fault envelope, returning `None` if `xml`
+    /// is not a UPnP fault
+    pub fn parse_fault(xml: &str, service: &str, action: &str) -> Option<Self> {
+        let envelope: Envelope = instant_xml::from_str(xml).ok()?;
+        let error = envelope.body.fault.detail?.error;
+        Some(Self {
+            code: error.code,
+            description: error.description.filter(|d| !d.is_empty()),
+            service: service.to_string(),
+            action: action.to_string(),
+            raw: None,
+        })
+    }
+}
+
+impl fmt::Display for UpnpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} failed with error {}",
+            self.service, self.action, self.code
+        )?;
+        if let Some(description) = &self.description {
+            write!(f, ": {description}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error {
+    /// Converts a `FailedRequest` whose body is a UPnP fault
+    /// into an `Error::Upnp`, retaining the request body
+    pub(crate) fn with_upnp_fault(self, service: &str, action: &str, request: &str) -> Error {
+        match &self {
+            Error::FailedRequest { status, body, .. }
+                if *status == StatusCode::INTERNAL_SERVER_ERROR =>
+            {
+                match UpnpError::parse_fault(body, service, action) {
+                    Some(mut error) => {
+                        error.raw.replace(Box::new(RawSoap::new(request, body)));
+                        Error::Upnp(error)
+                    }
+                    None => self,
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// Returns the SOAP request and response bodies of an action
+    /// that faulted or whose response could not be decoded
+    pub fn raw(&self) -> Option<&RawSoap> {
+        match self.inner() {
+            Error::Upnp(error) => error.raw.as_deref(),
+            Error::DecodeResponse { raw, .. } => Some(raw),
+            _ => None,
+        }
+    }
+
+    /// Returns the UPnP error code reported by the device, if any
+    pub fn upnp_error_code(&self) -> Option<UpnpErrorCode> {
+        match self.inner() {
+            Error::Upnp(error) => Some(error.kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the device reported a SOAP fault, which is
+    /// typically how it indicates that an action is not applicable
+    pub fn is_soap_fault(&self) -> bool {
+        match self.inner() {
+            Error::Upnp(_) => true,
+            Error::FailedRequest { status, .. } => *status == StatusCode::INTERNAL_SERVER_ERROR,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Envelope {
+    body: Body,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Body {
+    fault: Fault,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Fault {
+    detail: Option<FaultDetail>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "detail", ns(""))]
+struct FaultDetail {
+    error: UpnpFault,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "UPnPError", ns(UPNP_CONTROL_NS))]
+struct UpnpFault {
+    #[xml(rename = "errorCode")]
+    code: u32,
+    #[xml(rename = "errorDescription")]
+    description: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_fault() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><s:Fault><faultcode>s:Client</faultcode><faultstring>UPnPError</faultstring><detail><UPnPError xmlns="urn:schemas-upnp-org:control-1-0"><errorCode>714</errorCode></UPnPError></detail></s:Fault></s:Body></s:Envelope>"#;
+        let error = UpnpError::parse_fault(
+            xml,
+            "urn:schemas-upnp-org:service:AVTransport:1",
+            "SetAVTransportURI",
+        )
+        .unwrap();
+        assert_eq!(error.code, 714);
+        assert_eq!(error.description, None);
+        assert_eq!(error.kind(), UpnpErrorCode::IllegalMimeType);
+        assert_eq!(
+            error.to_string(),
+            "urn:schemas-upnp-org:service:AVTransport:1 SetAVTransportURI failed with error 714"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let long = "é".repeat(RAW_XML_CAP);
+        let raw = RawSoap::new("<a/>", &long);
+        assert_eq!(raw.request, "<a/>");
+        assert!(raw.response.len() <= RAW_XML_CAP + 3);
+        assert!(raw.response.ends_with("..."));
+    }
+
+    #[test]
+    fn test_redacts_credentials() {
+        let raw = RawSoap::new(
+            "<u:SetString><VariableName>R_Token</VariableName><StringValue>secret</StringValue></u:SetString>",
+            "<u:AddAccountXResponse><AccountPassword>hunter2</AccountPassword></u:AddAccountXResponse>",
+        );
+        assert_eq!(
+            raw.request,
+            "<u:SetString><VariableName>R_Token</VariableName><StringValue>REDACTED</StringValue></u:SetString>"
+        );
+        assert_eq!(
+            raw.response,
+            "<u:AddAccountXResponse><AccountPassword>REDACTED</AccountPassword></u:AddAccountXResponse>"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! A C ABI for embedding in C and C++ programs, such as home
+//! automation firmware. `include/sonos.h` declares these functions;
+//! run `make header` to regenerate it after changing this module.
+//!
+//! Devices are opaque handles that must be released with
+//! `sonos_device_free`. Every call blocks until it completes, and
+//! returns a `SonosStatus`; when that isn't `SONOS_STATUS_OK`,
+//! `sonos_last_error_message` describes the failure. Values are
+//! returned through out parameters, which are only written on success.
+//! Strings returned by the library must be released with
+//! `sonos_string_free`.
+//!
+//! Build the library with:
+//!
+//! ```console
+//! $ cargo rustc --lib --release --features ffi --crate-type staticlib
+//! ```
+use crate::{blocking, Error, Result, Volume};
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+/// The outcome of a call
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SonosStatus {
+    Ok = 0,
+    /// A pointer was null, or a string was not valid
+    InvalidArgument = 1,
+    /// A value was outside of the range accepted by the device
+    OutOfRange = 2,
+    /// The device could not be reached
+    Network = 3,
+    /// The device rejected the request
+    Device = 4,
+    /// The library panicked; this is a bug
+    Panic = 5,
+    Failed = 6,
+}
+
+impl From<&Error> for SonosStatus {
+    fn from(error: &Error) -> Self {
+        match error.inner() {
+            Error::InvalidArgument(_) => Self::InvalidArgument,
+            Error::OutOfRange { .. } => Self::OutOfRange,
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(_) => Self::Network,
+            Error::Http(_) | Error::Io(_) | Error::Timeout => Self::Network,
+            Error::Upnp(_) | Error::FailedRequest { .. } => Self::Device,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// A handle to a player
+pub struct SonosDevice(blocking::SonosDevice);
+
+/// The players found by `sonos_discover`
+pub struct SonosDeviceList(Vec<blocking::SonosDevice>);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    // Interior NULs can't be represented, so truncate at the first
+    let message = message.split('\0').next().unwrap_or_default();
+    let message = CString::new(message).expect("NULs were removed");
+    LAST_ERROR.with(|last| last.replace(Some(message)));
+}
+
+/// Runs `f`, recording any error for `sonos_last_error_message`
+fn call(f: impl FnOnce() -> Result<()>) -> SonosStatus {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => SonosStatus::Ok,
+        Ok(Err(error)) => {
+            let status = SonosStatus::from(&error);
+            set_last_error(format!("{error:#}"));
+            status
+        }
+        Err(_) => {
+            set_last_error("sonos panicked".to_string());
+            SonosStatus::Panic
+        }
+    }
+}
+
+/// # Safety
+/// `ptr` must be null or point to a live value of type `T`
+unsafe fn get<'a, T>(ptr: *const T, name: &str) -> Result<&'a T> {
+    ptr.as_ref()
+        .ok_or_else(|| Error::InvalidArgument(format!("{name} is null")))
+}
+
+/// Writes the result of `value` through the out parameter `out`.
+/// `value` is only called once `out` is known to be non-null, so
+/// that nothing is allocated or sent when the call must fail.
+///
+/// # Safety
+/// `out` must be null or valid for writes
+unsafe fn put<T>(out: *mut T, name: &str, value: impl FnOnce() -> Result<T>) -> Result<()> {
+    if out.is_null() {
+        return Err(Error::InvalidArgument(format!("{name} is null")));
+    }
+    out.write(value()?);
+    Ok(())
+}
+
+/// # Safety
+/// `ptr` must be null or point to a NUL terminated string
+unsafe fn get_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str> {
+    if ptr.is_null() {
+        return Err(Error::InvalidArgument(format!("{name} is null")));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| Error::InvalidArgument(format!("{name} is not valid UTF-8")))
+}
+
+/// Returns a description of the last failure on the calling thread,
+/// or NULL if there hasn't been one. The string remains valid until
+/// the next failing call on the same thread, and must not be freed.
+#[no_mangle]
+pub extern "C" fn sonos_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Releases a string returned by the library
+///
+/// # Safety
+/// `s` must be null or a string returned by the library that has
+/// not already been freed
+#[no_mangle]
+pub unsafe extern "C" fn sonos_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Discovers the players on the network, waiting up to
+/// `timeout_ms` milliseconds for them to respond
+///
+/// # Safety
+/// `out` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_discover(
+    timeout_ms: u32,
+    out: *mut *mut SonosDeviceList,
+) -> SonosStatus {
+    call(|| {
+        put(out, "out", || {
+            let devices = blocking::discover(Duration::from_millis(timeout_ms.into()))?;
+            Ok(Box::into_raw(Box::new(SonosDeviceList(devices))))
+        })
+    })
+}
+
+/// Returns the number of players in `list`, or 0 if it is null
+///
+/// # Safety
+/// `list` must be null or a live list returned by `sonos_discover`
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_list_len(list: *const SonosDeviceList) -> usize {
+    list.as_ref().map_or(0, |list| list.0.len())
+}
+
+/// Returns a new handle to the player at `index` in `list`, which
+/// must be released with `sonos_device_free`, or NULL if `index`
+/// is out of range
+///
+/// # Safety
+/// `list` must be null or a live list returned by `sonos_discover`
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_list_get(
+    list: *const SonosDeviceList,
+    index: usize,
+) -> *mut SonosDevice {
+    match list.as_ref().and_then(|list| list.0.get(index)) {
+        Some(device) => Box::into_raw(Box::new(SonosDevice(device.clone()))),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Releases `list`. Handles obtained from it remain valid.
+///
+/// # Safety
+/// `list` must be null or a list returned by `sonos_discover` that
+/// has not already been freed
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_list_free(list: *mut SonosDeviceList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// Connects to the player at `ip`, a dotted IPv4 address
+///
+/// # Safety
+/// `ip` must be a NUL terminated string and `out` must be valid
+/// for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_from_ip(
+    ip: *const c_char,
+    out: *mut *mut SonosDevice,
+) -> SonosStatus {
+    call(|| {
+        let ip = get_str(ip, "ip")?;
+        let addr = ip
+            .parse()
+            .map_err(|_| Error::InvalidArgument(format!("invalid IPv4 address {ip}")))?;
+        put(out, "out", || {
+            let device = blocking::SonosDevice::from_ip(addr)?;
+            Ok(Box::into_raw(Box::new(SonosDevice(device))))
+        })
+    })
+}
+
+/// Releases `device`
+///
+/// # Safety
+/// `device` must be null or a handle returned by the library that
+/// has not already been freed
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_free(device: *mut SonosDevice) {
+    if !device.is_null() {
+        drop(Box::from_raw(device));
+    }
+}
+
+/// Returns the room name of `device` through `out`, which must be
+/// released with `sonos_string_free`
+///
+/// # Safety
+/// `device` must be a live handle and `out` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_name(
+    device: *const SonosDevice,
+    out: *mut *mut c_char,
+) -> SonosStatus {
+    call(|| {
+        let device = get(device, "device")?;
+        put(out, "out", || {
+            let name = CString::new(device.0.name()?)
+                .map_err(|_| Error::InvalidArgument("room name contains NUL".to_string()))?;
+            Ok(name.into_raw())
+        })
+    })
+}
+
+/// Generates functions that perform an action that takes no
+/// arguments on a device
+macro_rules! device_actions {
+    ($($(#[$meta:meta])* fn $name:ident => $method:ident;)*) => {
+        $(
+            $(#[$meta])*
+            ///
+            /// # Safety
+            /// `device` must be a live handle
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(device: *const SonosDevice) -> SonosStatus {
+                call(|| get(device, "device")?.0.$method())
+            }
+        )*
+    };
+}
+
+device_actions! {
+    /// Begins playback
+    fn sonos_device_play => play;
+    /// Pauses playback
+    fn sonos_device_pause => pause;
+    /// Stops playback
+    fn sonos_device_stop => stop;
+    /// Skips to the next track
+    fn sonos_device_next => next;
+    /// Skips to the previous track
+    fn sonos_device_previous => previous;
+}
+
+/// Returns the volume of `device`, in the range 0-100, through `out`
+///
+/// # Safety
+/// `device` must be a live handle and `out` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_get_volume(
+    device: *const SonosDevice,
+    out: *mut u16,
+) -> SonosStatus {
+    call(|| {
+        let device = get(device, "device")?;
+        put(out, "out", || Ok(device.0.get_volume()?.get()))
+    })
+}
+
+/// Sets the volume of `device`, in the range 0-100
+///
+/// # Safety
+/// `device` must be a live handle
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_set_volume(
+    device: *const SonosDevice,
+    volume: u16,
+) -> SonosStatus {
+    call(|| get(device, "device")?.0.set_volume(Volume::new(volume)?))
+}
+
+/// Adjusts the volume of `device` by `delta`. The new volume is
+/// written through `out`, unless it is NULL.
+///
+/// # Safety
+/// `device` must be a live handle and `out` must be null or valid
+/// for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_adjust_volume(
+    device: *const SonosDevice,
+    delta: i16,
+    out: *mut u16,
+) -> SonosStatus {
+    call(|| {
+        let volume = get(device, "device")?.0.adjust_volume(delta)?;
+        if !out.is_null() {
+            out.write(volume.get());
+        }
+        Ok(())
+    })
+}
+
+/// Returns the mute state of `device` through `out`
+///
+/// # Safety
+/// `device` must be a live handle and `out` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_get_mute(
+    device: *const SonosDevice,
+    out: *mut bool,
+) -> SonosStatus {
+    call(|| {
+        let device = get(device, "device")?;
+        put(out, "out", || device.0.get_mute())
+    })
+}
+
+/// Sets the mute state of `device`
+///
+/// # Safety
+/// `device` must be a live handle
+#[no_mangle]
+pub unsafe extern "C" fn sonos_device_set_mute(
+    device: *const SonosDevice,
+    mute: bool,
+) -> SonosStatus {
+    call(|| get(device, "device")?.0.set_mute(mute))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            assert_eq!(
+                sonos_device_play(std::ptr::null()),
+                SonosStatus::InvalidArgument
+            );
+            let message = CStr::from_ptr(sonos_last_error_message());
+            assert_eq!(
+                message.to_str().unwrap(),
+                "Invalid argument: device is null"
+            );
+
+            let mut device = std::ptr::null_mut();
+            assert_eq!(
+                sonos_device_from_ip(c"not an ip".as_ptr(), &mut device),
+                SonosStatus::InvalidArgument
+            );
+            assert!(device.is_null());
+        }
+    }
+
+    #[test]
+    fn test_device_list() {
+        unsafe {
+            assert_eq!(sonos_device_list_len(std::ptr::null()), 0);
+            let list = Box::into_raw(Box::new(SonosDeviceList(vec![])));
+            assert!(sonos_device_list_get(list, 0).is_null());
+            sonos_device_list_free(list);
+        }
+    }
+}
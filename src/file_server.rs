@@ -0,0 +1,483 @@
+//! Serves files from the local disk over HTTP, so that clips such as
+//! notifications and text-to-speech output can be played by the
+//! players without standing up a separate web server.
+use crate::http_server::read_request;
+use crate::Result;
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// How long a player has to send its request; the response itself
+/// is not limited, as the player may read a long file slowly
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The server used by `serve_file`
+static SHARED: tokio::sync::Mutex<Option<FileServer>> = tokio::sync::Mutex::const_new(None);
+
+/// Returns a URL at which the players can fetch `path`, eg:
+///
+/// ```no_run
+/// # async fn example(device: sonos::SonosDevice) -> sonos::Result<()> {
+/// let url = sonos::serve_file("/tmp/doorbell.mp3").await?;
+/// device.set_av_transport_uri(url.as_str(), None).await?;
+/// device.play().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The file is served by a `FileServer` that is shared by the process
+/// and listens on the address of this host on the local network.
+/// It is started on the tokio runtime of the first caller, and runs
+/// until that runtime shuts down, after which the next caller starts
+/// it again on its own runtime. The restarted server listens on a new
+/// port, so URLs returned before the restart no longer work.
+pub async fn serve_file(path: impl AsRef<Path>) -> Result<Url> {
+    let mut shared = SHARED.lock().await;
+    let server = ensure_running(&mut shared, || Ok(SocketAddr::new(lan_address()?, 0))).await?;
+    server.serve(path).await
+}
+
+/// Stops serving `path` via the server used by `serve_file`
+pub async fn unserve_file(path: impl AsRef<Path>) -> Result<()> {
+    if let Some(server) = SHARED.lock().await.as_ref() {
+        server.unserve(path).await?;
+    }
+    Ok(())
+}
+
+/// Returns the server in `slot`, first starting one listening on
+/// `addr` if there is none or if its task has finished, which happens
+/// when the runtime it was spawned on shuts down.
+/// The registered files carry over to the new server.
+async fn ensure_running(
+    slot: &mut Option<FileServer>,
+    addr: impl FnOnce() -> std::io::Result<SocketAddr>,
+) -> Result<&FileServer> {
+    if slot
+        .as_ref()
+        .is_some_and(|server| !server.task.is_finished())
+    {
+        return Ok(slot.as_ref().expect("checked above"));
+    }
+    let files = slot
+        .take()
+        .map(|server| server.files.clone())
+        .unwrap_or_default();
+    let server = FileServer::listen(addr()?, files).await?;
+    Ok(slot.insert(server))
+}
+
+/// The files registered with a `FileServer`, identified in the URL
+/// by a number so that the paths are not exposed
+#[derive(Default)]
+struct Files {
+    ids: HashMap<PathBuf, u64>,
+    paths: HashMap<u64, PathBuf>,
+    next_id: u64,
+}
+
+impl Files {
+    fn register(&mut self, path: PathBuf) -> u64 {
+        if let Some(id) = self.ids.get(&path) {
+            return *id;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.ids.insert(path.clone(), id);
+        self.paths.insert(id, path);
+        id
+    }
+
+    fn unregister(&mut self, path: &Path) {
+        if let Some(id) = self.ids.remove(path) {
+            self.paths.remove(&id);
+        }
+    }
+}
+
+/// A minimal HTTP server that makes the files registered via `serve`
+/// available to the players. Requests for any other path are refused.
+/// Range requests are supported, so that players can seek and resume.
+pub struct FileServer {
+    addr: SocketAddr,
+    files: Arc<Mutex<Files>>,
+    task: JoinHandle<()>,
+}
+
+impl FileServer {
+    /// Listens on `addr`, which must be reachable by the players.
+    /// Use port 0 to pick a free port.
+    pub async fn bind(addr: SocketAddr) -> Result<Self> {
+        Self::listen(addr, Default::default()).await
+    }
+
+    async fn listen(addr: SocketAddr, files: Arc<Mutex<Files>>) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let task = tokio::spawn(accept(listener, files.clone()));
+        Ok(Self { addr, files, task })
+    }
+
+    /// Listens on a free port of the address of this host on the
+    /// local network
+    pub async fn bind_lan() -> Result<Self> {
+        Self::bind(SocketAddr::new(lan_address()?, 0)).await
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the URL at which `path` is served. The file is read
+    /// afresh for each request, so it may be rewritten in place, eg:
+    /// with the next text-to-speech clip.
+    pub async fn serve(&self, path: impl AsRef<Path>) -> Result<Url> {
+        let path = tokio::fs::canonicalize(path).await?;
+        if !tokio::fs::metadata(&path).await?.is_file() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{} is not a file", path.display()),
+            )
+            .into());
+        }
+
+        let id = self.files.lock().unwrap().register(path.clone());
+
+        // The name is only there to make the URL recognizable, and to
+        // give players that look at the extension something to go on
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut url: Url = format!("http://{}/", self.addr).parse()?;
+        url.path_segments_mut()
+            .expect("http URLs have a path")
+            .pop_if_empty()
+            .push(&id.to_string())
+            .push(&name);
+        Ok(url)
+    }
+
+    /// Stops serving `path`; requests for its URL are refused
+    /// from now on
+    pub async fn unserve(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = tokio::fs::canonicalize(path).await?;
+        self.files.lock().unwrap().unregister(&path);
+        Ok(())
+    }
+}
+
+impl Drop for FileServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn accept(listener: TcpListener, files: Arc<Mutex<Files>>) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                let files = files.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = respond(stream, &files).await {
+                        log::debug!("file request from {peer} failed: {err:#}");
+                    }
+                });
+            }
+            Err(err) => {
+                log::error!("accept failed: {err:#}");
+                return;
+            }
+        }
+    }
+}
+
+async fn respond(mut stream: TcpStream, files: &Mutex<Files>) -> Result<()> {
+    let Ok(request) = tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream)).await else {
+        return Err(std::io::Error::from(std::io::ErrorKind::TimedOut).into());
+    };
+    let Some(request) = request? else {
+        return Ok(());
+    };
+    let method = request.method.as_str();
+    let range = request.header("Range");
+
+    if method != "GET" && method != "HEAD" {
+        return write_head(
+            &mut stream,
+            "405 Method Not Allowed",
+            "Content-Length: 0\r\n",
+        )
+        .await;
+    }
+    let Some(path) = lookup(files, &request.path) else {
+        return write_head(&mut stream, "404 Not Found", "Content-Length: 0\r\n").await;
+    };
+    let mut file = match File::open(&path).await {
+        Ok(file) => file,
+        Err(err) => {
+            log::error!("opening {} failed: {err:#}", path.display());
+            return write_head(&mut stream, "404 Not Found", "Content-Length: 0\r\n").await;
+        }
+    };
+    let len = file.metadata().await?.len();
+    let content_type = content_type(&path);
+
+    let (status, start, count, content_range) = match parse_range(range, len) {
+        ByteRange::Full => ("200 OK", 0, len, String::new()),
+        ByteRange::Partial { start, end } => (
+            "206 Partial Content",
+            start,
+            end - start + 1,
+            format!("Content-Range: bytes {start}-{end}/{len}\r\n"),
+        ),
+        ByteRange::Unsatisfiable => {
+            let headers = format!("Content-Range: bytes */{len}\r\nContent-Length: 0\r\n");
+            return write_head(&mut stream, "416 Range Not Satisfiable", &headers).await;
+        }
+    };
+
+    let headers = format!(
+        "Content-Type: {content_type}\r\n\
+         Content-Length: {count}\r\n\
+         Accept-Ranges: bytes\r\n\
+         {content_range}"
+    );
+    write_head(&mut stream, status, &headers).await?;
+    if method == "GET" {
+        file.seek(SeekFrom::Start(start)).await?;
+        tokio::io::copy(&mut file.take(count), &mut stream).await?;
+    }
+    Ok(())
+}
+
+/// Writes the status line and `headers`, each of which must be
+/// terminated by CRLF, closing the connection after the response
+async fn write_head(stream: &mut TcpStream, status: &str, headers: &str) -> Result<()> {
+    let head = format!("HTTP/1.1 {status}\r\n{headers}Connection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await?;
+    Ok(())
+}
+
+/// Returns the registered file identified by the first segment
+/// of the request `path`
+fn lookup(files: &Mutex<Files>, path: &str) -> Option<PathBuf> {
+    let id: u64 = path.strip_prefix('/')?.split('/').next()?.parse().ok()?;
+    files.lock().unwrap().paths.get(&id).cloned()
+}
+
+/// Returns the address of this host on the local network, which is
+/// the one used to reach the SSDP multicast group that the players
+/// belong to. No packets are sent.
+fn lan_address() -> std::io::Result<IpAddr> {
+    let socket = std::net::UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    socket.connect((Ipv4Addr::new(239, 255, 255, 250), 1900))?;
+    Ok(socket.local_addr()?.ip())
+}
+
+/// Guesses the content type of an audio file from its extension
+fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "mp3" => "audio/mpeg",
+        "aac" => "audio/aac",
+        "m4a" | "mp4" => "audio/mp4",
+        "flac" => "audio/flac",
+        "ogg" | "oga" | "opus" => "audio/ogg",
+        "wav" => "audio/wav",
+        "aif" | "aiff" => "audio/aiff",
+        "wma" => "audio/x-ms-wma",
+        _ => "application/octet-stream",
+    }
+}
+
+/// The part of a file requested via the `Range` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ByteRange {
+    Full,
+    /// `end` is inclusive
+    Partial {
+        start: u64,
+        end: u64,
+    },
+    Unsatisfiable,
+}
+
+/// Interprets the `Range` header of a request for a file of `len`
+/// bytes. Headers that can't be parsed, or that request multiple
+/// ranges, are ignored, as RFC 9110 allows, and the whole file is sent.
+fn parse_range(range: Option<&str>, len: u64) -> ByteRange {
+    let Some(spec) = range.and_then(|range| range.trim().strip_prefix("bytes=")) else {
+        return ByteRange::Full;
+    };
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+    let Some((start, end)) = spec.split_once('-') else {
+        return ByteRange::Full;
+    };
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        // A suffix: the last `end` bytes of the file
+        return match end.parse::<u64>() {
+            Ok(0) => ByteRange::Unsatisfiable,
+            Ok(_) if len == 0 => ByteRange::Unsatisfiable,
+            Ok(suffix) => ByteRange::Partial {
+                start: len.saturating_sub(suffix),
+                end: len - 1,
+            },
+            Err(_) => ByteRange::Full,
+        };
+    }
+
+    let Ok(start) = start.parse::<u64>() else {
+        return ByteRange::Full;
+    };
+    let end = if end.is_empty() {
+        u64::MAX
+    } else {
+        match end.parse::<u64>() {
+            Ok(end) if end >= start => end,
+            _ => return ByteRange::Full,
+        }
+    };
+    if start >= len {
+        return ByteRange::Unsatisfiable;
+    }
+    ByteRange::Partial {
+        start,
+        end: end.min(len - 1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range(None, 10), ByteRange::Full);
+        assert_eq!(
+            parse_range(Some("bytes=2-4"), 10),
+            ByteRange::Partial { start: 2, end: 4 }
+        );
+        assert_eq!(
+            parse_range(Some("bytes=2-"), 10),
+            ByteRange::Partial { start: 2, end: 9 }
+        );
+        assert_eq!(
+            parse_range(Some("bytes=5-100"), 10),
+            ByteRange::Partial { start: 5, end: 9 }
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-3"), 10),
+            ByteRange::Partial { start: 7, end: 9 }
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-30"), 10),
+            ByteRange::Partial { start: 0, end: 9 }
+        );
+        assert_eq!(parse_range(Some("bytes=10-"), 10), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=-0"), 10), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=4-2"), 10), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=0-1,4-5"), 10), ByteRange::Full);
+        assert_eq!(parse_range(Some("items=0-1"), 10), ByteRange::Full);
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(content_type(Path::new("/tmp/chime.MP3")), "audio/mpeg");
+        assert_eq!(content_type(Path::new("speech.wav")), "audio/wav");
+        assert_eq!(
+            content_type(Path::new("README")),
+            "application/octet-stream"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve() {
+        let path =
+            std::env::temp_dir().join(format!("sonos-file-server-{}.mp3", std::process::id()));
+        std::fs::write(&path, b"0123456789").unwrap();
+
+        let server = FileServer::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+            .await
+            .unwrap();
+        let url = server.serve(&path).await.unwrap();
+        assert_eq!(server.serve(&path).await.unwrap(), url);
+
+        let addr = server.local_addr();
+        let get = |request: String| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        let response = get(format!(
+            "GET {} HTTP/1.1\r\nRange: bytes=2-4\r\n\r\n",
+            url.path()
+        ))
+        .await;
+        assert!(response.starts_with("HTTP/1.1 206 Partial Content\r\n"));
+        assert!(response.contains("Content-Type: audio/mpeg\r\n"));
+        assert!(response.contains("Content-Range: bytes 2-4/10\r\n"));
+        assert!(response.ends_with("\r\n\r\n234"));
+
+        let response = get("GET /1/other.mp3 HTTP/1.1\r\n\r\n".to_string()).await;
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        server.unserve(&path).await.unwrap();
+        let response = get(format!("GET {} HTTP/1.1\r\n\r\n", url.path())).await;
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_restart() {
+        let path =
+            std::env::temp_dir().join(format!("sonos-file-server-{}.wav", std::process::id()));
+        std::fs::write(&path, b"RIFF").unwrap();
+        let localhost = || Ok(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)));
+        let mut slot = None;
+
+        let first = tokio::runtime::Runtime::new().unwrap();
+        let url = first.block_on(async {
+            let server = ensure_running(&mut slot, localhost).await.unwrap();
+            server.serve(&path).await.unwrap()
+        });
+        drop(first);
+
+        let second = tokio::runtime::Runtime::new().unwrap();
+        second.block_on(async {
+            let server = ensure_running(&mut slot, localhost).await.unwrap();
+            // The file keeps its id
+            let restarted = server.serve(&path).await.unwrap();
+            assert_eq!(restarted.path(), url.path());
+
+            let mut stream = TcpStream::connect(server.local_addr()).await.unwrap();
+            let request = format!("GET {} HTTP/1.1\r\n\r\n", url.path());
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            assert!(response.ends_with("\r\n\r\nRIFF"));
+        });
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{zone_group_topology, Error, Result, SonosDevice, UpdateType, ZoneGroupTopology};
+use instant_xml::FromXml;
+
+const UPDATE_NS: &str = "urn:schemas-rinconnetworks-com:update-1-0";
+
+/// The result of checking for a firmware update
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftwareUpdate {
+    /// The version that is currently installed, eg: `79.1-56030`
+    pub current_version: String,
+    /// The version that is available to install, if different
+    /// from the current version
+    pub available_version: Option<String>,
+    /// The URL from which the player would download the update
+    pub update_url: Option<String>,
+}
+
+impl SoftwareUpdate {
+    pub fn update_available(&self) -> bool {
+        self.available_version.is_some()
+    }
+}
+
+impl SonosDevice {
+    /// Returns the version of the firmware installed on the device,
+    /// eg: `79.1-56030`
+    pub async fn software_version(&self) -> Result<String> {
+        let version = self.zone_info().await?.software_version;
+        if version.is_empty() {
+            return Err(Error::MissingValue("SoftwareVersion"));
+        }
+        Ok(version)
+    }
+
+    /// Asks the device whether a firmware update is available
+    pub async fn check_for_update(&self) -> Result<SoftwareUpdate> {
+        let current_version = self.software_version().await?;
+        let response = <Self as ZoneGroupTopology>::check_for_update(
+            self,
+            zone_group_topology::CheckForUpdateRequest {
+                update_type: UpdateType::Software,
+                cached_only: false,
+                version: current_version.clone(),
+            },
+        )
+        .await?;
+
+        let mut result = SoftwareUpdate {
+            current_version,
+            ..Default::default()
+        };
+        if let Some(xml) = response.update_item.filter(|xml| !xml.trim().is_empty()) {
+            let item = parse_update_item(&xml)?;
+            if !item.version.is_empty() && item.version != result.current_version {
+                result.available_version = Some(item.version);
+                result.update_url = item.update_url.filter(|url| !url.is_empty());
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn parse_update_item(xml: &str) -> Result<UpdateItem> {
+    instant_xml::from_str(xml).map_err(|error| Error::XmlParse {
+        error,
+        text: xml.to_string(),
+    })
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(UPDATE_NS))]
+struct UpdateItem {
+    #[xml(rename = "Version", attribute)]
+    version: String,
+    #[xml(rename = "UpdateURL", attribute)]
+    update_url: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_update_item() {
+        let item = parse_update_item(r#"<UpdateItem xmlns="urn:schemas-rinconnetworks-com:update-1-0" Type="Software" Version="80.1-55180" UpdateURL="http://update.example.com/firmware" DownloadSize="0" ManifestURL="" ManifestHash="" Swgen="2"/>"#).unwrap();
+        assert_eq!(item.version, "80.1-55180");
+        assert_eq!(
+            item.update_url.as_deref(),
+            Some("http://update.example.com/firmware")
+        );
+    }
+}
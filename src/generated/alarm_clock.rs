@@ -0,0 +1,1318 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `AlarmClock` service.
+use super::*;
+/// URN for the `AlarmClock` service.
+/// `urn:schemas-upnp-org:service:AlarmClock:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AlarmClock:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateAlarm", ns(SERVICE_TYPE))]
+pub struct CreateAlarmRequest {
+    /// The start time as `hh:mm:ss`
+    #[xml(rename = "StartLocalTime", ns(""))]
+    pub start_local_time: String,
+    /// The duration as `hh:mm:ss`
+    #[xml(rename = "Duration", ns(""))]
+    pub duration: String,
+    /// Repeat this alarm on
+    #[xml(rename = "Recurrence", ns(""))]
+    pub recurrence: super::Recurrence,
+    /// Alarm enabled after creation
+    #[xml(rename = "Enabled", ns(""))]
+    pub enabled: bool,
+    /// The UUID of the speaker you want this alarm for
+    #[xml(rename = "RoomUUID", ns(""))]
+    pub room_uuid: String,
+    /// The sound uri
+    #[xml(rename = "ProgramURI", ns(""))]
+    pub program_uri: String,
+    /// The sound metadata, can be empty string
+    #[xml(rename = "ProgramMetaData", ns(""))]
+    pub program_meta_data: String,
+    /// Alarm play mode
+    #[xml(rename = "PlayMode", ns(""))]
+    pub play_mode: super::AlarmPlayMode,
+    /// Volume between 0 and 100
+    #[xml(rename = "Volume", ns(""))]
+    pub volume: u16,
+    /// Should grouped players also play the alarm?
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: bool,
+}
+impl CreateAlarmRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> CreateAlarmRequestBuilder {
+        CreateAlarmRequestBuilder::default()
+    }
+}
+/// Builds a `CreateAlarmRequest`; see `CreateAlarmRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CreateAlarmRequestBuilder {
+    request: CreateAlarmRequest,
+}
+impl CreateAlarmRequestBuilder {
+    /// The start time as `hh:mm:ss`
+    pub fn start_local_time(mut self, start_local_time: impl Into<String>) -> Self {
+        self.request.start_local_time = start_local_time.into();
+        self
+    }
+    /// The duration as `hh:mm:ss`
+    pub fn duration(mut self, duration: impl Into<String>) -> Self {
+        self.request.duration = duration.into();
+        self
+    }
+    /// Repeat this alarm on
+    pub fn recurrence(mut self, recurrence: super::Recurrence) -> Self {
+        self.request.recurrence = recurrence;
+        self
+    }
+    /// Alarm enabled after creation
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.request.enabled = enabled;
+        self
+    }
+    /// The UUID of the speaker you want this alarm for
+    pub fn room_uuid(mut self, room_uuid: impl Into<String>) -> Self {
+        self.request.room_uuid = room_uuid.into();
+        self
+    }
+    /// The sound uri
+    pub fn program_uri(mut self, program_uri: impl Into<String>) -> Self {
+        self.request.program_uri = program_uri.into();
+        self
+    }
+    /// The sound metadata, can be empty string
+    pub fn program_meta_data(mut self, program_meta_data: impl Into<String>) -> Self {
+        self.request.program_meta_data = program_meta_data.into();
+        self
+    }
+    /// Alarm play mode
+    pub fn play_mode(mut self, play_mode: super::AlarmPlayMode) -> Self {
+        self.request.play_mode = play_mode;
+        self
+    }
+    /// Volume between 0 and 100
+    pub fn volume(mut self, volume: u16) -> Self {
+        self.request.volume = volume;
+        self
+    }
+    /// Should grouped players also play the alarm?
+    pub fn include_linked_zones(mut self, include_linked_zones: bool) -> Self {
+        self.request.include_linked_zones = include_linked_zones;
+        self
+    }
+    pub fn build(self) -> CreateAlarmRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateAlarmResponse", ns(SERVICE_TYPE))]
+pub struct CreateAlarmResponse {
+    #[xml(rename = "AssignedID", ns(""))]
+    pub assigned_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for CreateAlarmResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "DestroyAlarm", ns(SERVICE_TYPE))]
+pub struct DestroyAlarmRequest {
+    /// The Alarm ID from ListAlarms
+    #[xml(rename = "ID", ns(""))]
+    pub id: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetDailyIndexRefreshTimeResponse", ns(SERVICE_TYPE))]
+pub struct GetDailyIndexRefreshTimeResponse {
+    #[xml(rename = "CurrentDailyIndexRefreshTime", ns(""))]
+    pub current_daily_index_refresh_time: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetDailyIndexRefreshTimeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetFormatResponse", ns(SERVICE_TYPE))]
+pub struct GetFormatResponse {
+    #[xml(rename = "CurrentTimeFormat", ns(""))]
+    pub current_time_format: Option<String>,
+    #[xml(rename = "CurrentDateFormat", ns(""))]
+    pub current_date_format: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetFormatResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHouseholdTimeAtStamp", ns(SERVICE_TYPE))]
+pub struct GetHouseholdTimeAtStampRequest {
+    #[xml(rename = "TimeStamp", ns(""))]
+    pub time_stamp: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHouseholdTimeAtStampResponse", ns(SERVICE_TYPE))]
+pub struct GetHouseholdTimeAtStampResponse {
+    #[xml(rename = "HouseholdUTCTime", ns(""))]
+    pub household_utc_time: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetHouseholdTimeAtStampResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeNowResponse", ns(SERVICE_TYPE))]
+pub struct GetTimeNowResponse {
+    #[xml(rename = "CurrentUTCTime", ns(""))]
+    pub current_utc_time: Option<String>,
+    #[xml(rename = "CurrentLocalTime", ns(""))]
+    pub current_local_time: Option<String>,
+    #[xml(rename = "CurrentTimeZone", ns(""))]
+    pub current_time_zone: Option<String>,
+    #[xml(rename = "CurrentTimeGeneration", ns(""))]
+    pub current_time_generation: Option<u32>,
+}
+impl crate::DecodeSoapResponse for GetTimeNowResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeServerResponse", ns(SERVICE_TYPE))]
+pub struct GetTimeServerResponse {
+    #[xml(rename = "CurrentTimeServer", ns(""))]
+    pub current_time_server: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetTimeServerResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeZoneResponse", ns(SERVICE_TYPE))]
+pub struct GetTimeZoneResponse {
+    #[xml(rename = "Index", ns(""))]
+    pub index: Option<i32>,
+    #[xml(rename = "AutoAdjustDst", ns(""))]
+    pub auto_adjust_dst: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetTimeZoneResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeZoneAndRuleResponse", ns(SERVICE_TYPE))]
+pub struct GetTimeZoneAndRuleResponse {
+    #[xml(rename = "Index", ns(""))]
+    pub index: Option<i32>,
+    #[xml(rename = "AutoAdjustDst", ns(""))]
+    pub auto_adjust_dst: Option<bool>,
+    #[xml(rename = "CurrentTimeZone", ns(""))]
+    pub current_time_zone: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetTimeZoneAndRuleResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeZoneRule", ns(SERVICE_TYPE))]
+pub struct GetTimeZoneRuleRequest {
+    #[xml(rename = "Index", ns(""))]
+    pub index: i32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTimeZoneRuleResponse", ns(SERVICE_TYPE))]
+pub struct GetTimeZoneRuleResponse {
+    #[xml(rename = "TimeZone", ns(""))]
+    pub time_zone: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetTimeZoneRuleResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ListAlarmsResponse", ns(SERVICE_TYPE))]
+pub struct ListAlarmsResponse {
+    #[xml(rename = "CurrentAlarmList", ns(""))]
+    pub current_alarm_list: Option<String>,
+    #[xml(rename = "CurrentAlarmListVersion", ns(""))]
+    pub current_alarm_list_version: Option<String>,
+}
+impl crate::DecodeSoapResponse for ListAlarmsResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetDailyIndexRefreshTime", ns(SERVICE_TYPE))]
+pub struct SetDailyIndexRefreshTimeRequest {
+    #[xml(rename = "DesiredDailyIndexRefreshTime", ns(""))]
+    pub desired_daily_index_refresh_time: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetFormat", ns(SERVICE_TYPE))]
+pub struct SetFormatRequest {
+    #[xml(rename = "DesiredTimeFormat", ns(""))]
+    pub desired_time_format: String,
+    #[xml(rename = "DesiredDateFormat", ns(""))]
+    pub desired_date_format: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetTimeNow", ns(SERVICE_TYPE))]
+pub struct SetTimeNowRequest {
+    #[xml(rename = "DesiredTime", ns(""))]
+    pub desired_time: String,
+    #[xml(rename = "TimeZoneForDesiredTime", ns(""))]
+    pub time_zone_for_desired_time: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetTimeServer", ns(SERVICE_TYPE))]
+pub struct SetTimeServerRequest {
+    #[xml(rename = "DesiredTimeServer", ns(""))]
+    pub desired_time_server: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetTimeZone", ns(SERVICE_TYPE))]
+pub struct SetTimeZoneRequest {
+    #[xml(rename = "Index", ns(""))]
+    pub index: i32,
+    #[xml(rename = "AutoAdjustDst", ns(""))]
+    pub auto_adjust_dst: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "UpdateAlarm", ns(SERVICE_TYPE))]
+pub struct UpdateAlarmRequest {
+    /// The ID of the alarm see ListAlarms
+    #[xml(rename = "ID", ns(""))]
+    pub id: u32,
+    /// The start time as `hh:mm:ss`
+    #[xml(rename = "StartLocalTime", ns(""))]
+    pub start_local_time: String,
+    /// The duration as `hh:mm:ss`
+    #[xml(rename = "Duration", ns(""))]
+    pub duration: String,
+    /// Repeat this alarm on
+    #[xml(rename = "Recurrence", ns(""))]
+    pub recurrence: super::Recurrence,
+    /// Alarm enabled after creation
+    #[xml(rename = "Enabled", ns(""))]
+    pub enabled: bool,
+    /// The UUID of the speaker you want this alarm for
+    #[xml(rename = "RoomUUID", ns(""))]
+    pub room_uuid: String,
+    /// The sound uri
+    #[xml(rename = "ProgramURI", ns(""))]
+    pub program_uri: String,
+    /// The sound metadata, can be empty string
+    #[xml(rename = "ProgramMetaData", ns(""))]
+    pub program_meta_data: String,
+    /// Alarm play mode
+    #[xml(rename = "PlayMode", ns(""))]
+    pub play_mode: super::AlarmPlayMode,
+    /// Volume between 0 and 100
+    #[xml(rename = "Volume", ns(""))]
+    pub volume: u16,
+    /// Should grouped players also play the alarm?
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: bool,
+}
+impl UpdateAlarmRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> UpdateAlarmRequestBuilder {
+        UpdateAlarmRequestBuilder::default()
+    }
+}
+/// Builds a `UpdateAlarmRequest`; see `UpdateAlarmRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UpdateAlarmRequestBuilder {
+    request: UpdateAlarmRequest,
+}
+impl UpdateAlarmRequestBuilder {
+    /// The ID of the alarm see ListAlarms
+    pub fn id(mut self, id: u32) -> Self {
+        self.request.id = id;
+        self
+    }
+    /// The start time as `hh:mm:ss`
+    pub fn start_local_time(mut self, start_local_time: impl Into<String>) -> Self {
+        self.request.start_local_time = start_local_time.into();
+        self
+    }
+    /// The duration as `hh:mm:ss`
+    pub fn duration(mut self, duration: impl Into<String>) -> Self {
+        self.request.duration = duration.into();
+        self
+    }
+    /// Repeat this alarm on
+    pub fn recurrence(mut self, recurrence: super::Recurrence) -> Self {
+        self.request.recurrence = recurrence;
+        self
+    }
+    /// Alarm enabled after creation
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.request.enabled = enabled;
+        self
+    }
+    /// The UUID of the speaker you want this alarm for
+    pub fn room_uuid(mut self, room_uuid: impl Into<String>) -> Self {
+        self.request.room_uuid = room_uuid.into();
+        self
+    }
+    /// The sound uri
+    pub fn program_uri(mut self, program_uri: impl Into<String>) -> Self {
+        self.request.program_uri = program_uri.into();
+        self
+    }
+    /// The sound metadata, can be empty string
+    pub fn program_meta_data(mut self, program_meta_data: impl Into<String>) -> Self {
+        self.request.program_meta_data = program_meta_data.into();
+        self
+    }
+    /// Alarm play mode
+    pub fn play_mode(mut self, play_mode: super::AlarmPlayMode) -> Self {
+        self.request.play_mode = play_mode;
+        self
+    }
+    /// Volume between 0 and 100
+    pub fn volume(mut self, volume: u16) -> Self {
+        self.request.volume = volume;
+        self
+    }
+    /// Should grouped players also play the alarm?
+    pub fn include_linked_zones(mut self, include_linked_zones: bool) -> Self {
+        self.request.include_linked_zones = include_linked_zones;
+        self
+    }
+    pub fn build(self) -> UpdateAlarmRequest {
+        self.request
+    }
+}
+/// A parsed event produced by the `AlarmClock` service.
+/// Use `SonosDevice::subscribe_alarm_clock()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmClockEvent {
+    pub alarm_list_version: Option<String>,
+    pub daily_index_refresh_time: Option<String>,
+    pub date_format: Option<String>,
+    pub time_format: Option<String>,
+    pub time_generation: Option<u32>,
+    pub time_server: Option<String>,
+    pub time_zone: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AlarmClockPropertySet {
+    pub properties: Vec<AlarmClockProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AlarmClockProperty {
+    #[xml(rename = "AlarmListVersion", ns(""))]
+    pub alarm_list_version: Option<String>,
+    #[xml(rename = "DailyIndexRefreshTime", ns(""))]
+    pub daily_index_refresh_time: Option<String>,
+    #[xml(rename = "DateFormat", ns(""))]
+    pub date_format: Option<String>,
+    #[xml(rename = "TimeFormat", ns(""))]
+    pub time_format: Option<String>,
+    #[xml(rename = "TimeGeneration", ns(""))]
+    pub time_generation: Option<u32>,
+    #[xml(rename = "TimeServer", ns(""))]
+    pub time_server: Option<String>,
+    #[xml(rename = "TimeZone", ns(""))]
+    pub time_zone: Option<String>,
+}
+impl DecodeXml for AlarmClockEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: AlarmClockPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.alarm_list_version {
+                result.alarm_list_version.replace(v);
+            }
+            if let Some(v) = prop.daily_index_refresh_time {
+                result.daily_index_refresh_time.replace(v);
+            }
+            if let Some(v) = prop.date_format {
+                result.date_format.replace(v);
+            }
+            if let Some(v) = prop.time_format {
+                result.time_format.replace(v);
+            }
+            if let Some(v) = prop.time_generation {
+                result.time_generation.replace(v);
+            }
+            if let Some(v) = prop.time_server {
+                result.time_server.replace(v);
+            }
+            if let Some(v) = prop.time_zone {
+                result.time_zone.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `AlarmClock` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_alarm_clock(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<AlarmClockEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+/// Errors that are documented for the `AlarmClock` service.
+/// Use `Error::from_error` to obtain one from the error returned
+/// by a method of this service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// 801: Duplicate alarm time
+    DuplicateAlarmTime,
+    /// An error code that is not documented for this service
+    Other(u32),
+}
+impl From<u32> for Error {
+    fn from(code: u32) -> Self {
+        match code {
+            801 => Self::DuplicateAlarmTime,
+            code => Self::Other(code),
+        }
+    }
+}
+impl Error {
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateAlarmTime => 801,
+            Self::Other(code) => *code,
+        }
+    }
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            Self::DuplicateAlarmTime => Some("Duplicate alarm time"),
+            Self::Other(_) => None,
+        }
+    }
+    /// Returns the documented error if `err` is a fault
+    /// reported by this service
+    pub fn from_error(err: &crate::Error) -> Option<Self> {
+        match err {
+            crate::Error::Upnp(e) if e.service == SERVICE_TYPE => Some(e.code.into()),
+            _ => None,
+        }
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum AlarmPlayMode {
+    #[default]
+    Normal,
+    RepeatAll,
+    ShuffleNorepeat,
+    Shuffle,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for AlarmPlayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            AlarmPlayMode::Normal => "NORMAL",
+            AlarmPlayMode::RepeatAll => "REPEAT_ALL",
+            AlarmPlayMode::ShuffleNorepeat => "SHUFFLE_NOREPEAT",
+            AlarmPlayMode::Shuffle => "SHUFFLE",
+            AlarmPlayMode::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for AlarmPlayMode {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<AlarmPlayMode> {
+        match s {
+            "NORMAL" => Ok(AlarmPlayMode::Normal),
+            "REPEAT_ALL" => Ok(AlarmPlayMode::RepeatAll),
+            "SHUFFLE_NOREPEAT" => Ok(AlarmPlayMode::ShuffleNorepeat),
+            "SHUFFLE" => Ok(AlarmPlayMode::Shuffle),
+            s => Ok(AlarmPlayMode::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for AlarmPlayMode {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for AlarmPlayMode {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: AlarmPlayMode = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<AlarmPlayMode>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for AlarmPlayMode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AlarmPlayMode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum Recurrence {
+    #[default]
+    Once,
+    Weekdays,
+    Weekends,
+    Daily,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Recurrence::Once => "ONCE",
+            Recurrence::Weekdays => "WEEKDAYS",
+            Recurrence::Weekends => "WEEKENDS",
+            Recurrence::Daily => "DAILY",
+            Recurrence::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for Recurrence {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Recurrence> {
+        match s {
+            "ONCE" => Ok(Recurrence::Once),
+            "WEEKDAYS" => Ok(Recurrence::Weekdays),
+            "WEEKENDS" => Ok(Recurrence::Weekends),
+            "DAILY" => Ok(Recurrence::Daily),
+            s => Ok(Recurrence::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for Recurrence {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for Recurrence {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: Recurrence = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<Recurrence>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for Recurrence {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Recurrence {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Control the sonos alarms and times
+pub trait AlarmClock {
+    /// Create a single alarm, all properties are required
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn create_alarm(
+        &self,
+        request: alarm_clock::CreateAlarmRequest,
+    ) -> Result<alarm_clock::CreateAlarmResponse>;
+    /// Delete an alarm
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn destroy_alarm(
+        &self,
+        request: alarm_clock::DestroyAlarmRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_daily_index_refresh_time(
+        &self,
+    ) -> Result<alarm_clock::GetDailyIndexRefreshTimeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_format(&self) -> Result<alarm_clock::GetFormatResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_household_time_at_stamp(
+        &self,
+        request: alarm_clock::GetHouseholdTimeAtStampRequest,
+    ) -> Result<alarm_clock::GetHouseholdTimeAtStampResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_time_now(&self) -> Result<alarm_clock::GetTimeNowResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_time_server(&self) -> Result<alarm_clock::GetTimeServerResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_time_zone(&self) -> Result<alarm_clock::GetTimeZoneResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_time_zone_and_rule(
+        &self,
+    ) -> Result<alarm_clock::GetTimeZoneAndRuleResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_time_zone_rule(
+        &self,
+        request: alarm_clock::GetTimeZoneRuleRequest,
+    ) -> Result<alarm_clock::GetTimeZoneRuleResponse>;
+    /// Get the AlarmList as XML
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn list_alarms(&self) -> Result<alarm_clock::ListAlarmsResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_daily_index_refresh_time(
+        &self,
+        request: alarm_clock::SetDailyIndexRefreshTimeRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_format(&self, request: alarm_clock::SetFormatRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_time_now(&self, request: alarm_clock::SetTimeNowRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_time_server(
+        &self,
+        request: alarm_clock::SetTimeServerRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_time_zone(
+        &self,
+        request: alarm_clock::SetTimeZoneRequest,
+    ) -> Result<()>;
+    /// Update an alarm, all parameters are required.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn update_alarm(&self, request: alarm_clock::UpdateAlarmRequest) -> Result<()>;
+}
+impl AlarmClock for SonosDevice {
+    async fn create_alarm(
+        &self,
+        request: alarm_clock::CreateAlarmRequest,
+    ) -> Result<alarm_clock::CreateAlarmResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "CreateAlarm", request).await
+    }
+    async fn destroy_alarm(
+        &self,
+        request: alarm_clock::DestroyAlarmRequest,
+    ) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "DestroyAlarm", request).await
+    }
+    async fn get_daily_index_refresh_time(
+        &self,
+    ) -> Result<alarm_clock::GetDailyIndexRefreshTimeResponse> {
+        self.action(
+                &alarm_clock::SERVICE_TYPE,
+                "GetDailyIndexRefreshTime",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_format(&self) -> Result<alarm_clock::GetFormatResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetFormat", crate::soap::Unit {}).await
+    }
+    async fn get_household_time_at_stamp(
+        &self,
+        request: alarm_clock::GetHouseholdTimeAtStampRequest,
+    ) -> Result<alarm_clock::GetHouseholdTimeAtStampResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetHouseholdTimeAtStamp", request).await
+    }
+    async fn get_time_now(&self) -> Result<alarm_clock::GetTimeNowResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetTimeNow", crate::soap::Unit {}).await
+    }
+    async fn get_time_server(&self) -> Result<alarm_clock::GetTimeServerResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetTimeServer", crate::soap::Unit {})
+            .await
+    }
+    async fn get_time_zone(&self) -> Result<alarm_clock::GetTimeZoneResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetTimeZone", crate::soap::Unit {})
+            .await
+    }
+    async fn get_time_zone_and_rule(
+        &self,
+    ) -> Result<alarm_clock::GetTimeZoneAndRuleResponse> {
+        self.action(
+                &alarm_clock::SERVICE_TYPE,
+                "GetTimeZoneAndRule",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_time_zone_rule(
+        &self,
+        request: alarm_clock::GetTimeZoneRuleRequest,
+    ) -> Result<alarm_clock::GetTimeZoneRuleResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "GetTimeZoneRule", request).await
+    }
+    async fn list_alarms(&self) -> Result<alarm_clock::ListAlarmsResponse> {
+        self.action(&alarm_clock::SERVICE_TYPE, "ListAlarms", crate::soap::Unit {}).await
+    }
+    async fn set_daily_index_refresh_time(
+        &self,
+        request: alarm_clock::SetDailyIndexRefreshTimeRequest,
+    ) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "SetDailyIndexRefreshTime", request)
+            .await
+    }
+    async fn set_format(&self, request: alarm_clock::SetFormatRequest) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "SetFormat", request).await
+    }
+    async fn set_time_now(&self, request: alarm_clock::SetTimeNowRequest) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeNow", request).await
+    }
+    async fn set_time_server(
+        &self,
+        request: alarm_clock::SetTimeServerRequest,
+    ) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeServer", request).await
+    }
+    async fn set_time_zone(
+        &self,
+        request: alarm_clock::SetTimeZoneRequest,
+    ) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeZone", request).await
+    }
+    async fn update_alarm(
+        &self,
+        request: alarm_clock::UpdateAlarmRequest,
+    ) -> Result<()> {
+        self.action(&alarm_clock::SERVICE_TYPE, "UpdateAlarm", request).await
+    }
+}
+/// A variant of `AlarmClock` that can be used as a trait object,
+/// eg: `Box<dyn AlarmClockDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait AlarmClockDyn: Send + Sync {
+    /// See `AlarmClock::create_alarm`
+    fn create_alarm(
+        &self,
+        request: alarm_clock::CreateAlarmRequest,
+    ) -> ActionFuture<'_, alarm_clock::CreateAlarmResponse>;
+    /// See `AlarmClock::destroy_alarm`
+    fn destroy_alarm(
+        &self,
+        request: alarm_clock::DestroyAlarmRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::get_daily_index_refresh_time`
+    fn get_daily_index_refresh_time(
+        &self,
+    ) -> ActionFuture<'_, alarm_clock::GetDailyIndexRefreshTimeResponse>;
+    /// See `AlarmClock::get_format`
+    fn get_format(&self) -> ActionFuture<'_, alarm_clock::GetFormatResponse>;
+    /// See `AlarmClock::get_household_time_at_stamp`
+    fn get_household_time_at_stamp(
+        &self,
+        request: alarm_clock::GetHouseholdTimeAtStampRequest,
+    ) -> ActionFuture<'_, alarm_clock::GetHouseholdTimeAtStampResponse>;
+    /// See `AlarmClock::get_time_now`
+    fn get_time_now(&self) -> ActionFuture<'_, alarm_clock::GetTimeNowResponse>;
+    /// See `AlarmClock::get_time_server`
+    fn get_time_server(&self) -> ActionFuture<'_, alarm_clock::GetTimeServerResponse>;
+    /// See `AlarmClock::get_time_zone`
+    fn get_time_zone(&self) -> ActionFuture<'_, alarm_clock::GetTimeZoneResponse>;
+    /// See `AlarmClock::get_time_zone_and_rule`
+    fn get_time_zone_and_rule(
+        &self,
+    ) -> ActionFuture<'_, alarm_clock::GetTimeZoneAndRuleResponse>;
+    /// See `AlarmClock::get_time_zone_rule`
+    fn get_time_zone_rule(
+        &self,
+        request: alarm_clock::GetTimeZoneRuleRequest,
+    ) -> ActionFuture<'_, alarm_clock::GetTimeZoneRuleResponse>;
+    /// See `AlarmClock::list_alarms`
+    fn list_alarms(&self) -> ActionFuture<'_, alarm_clock::ListAlarmsResponse>;
+    /// See `AlarmClock::set_daily_index_refresh_time`
+    fn set_daily_index_refresh_time(
+        &self,
+        request: alarm_clock::SetDailyIndexRefreshTimeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::set_format`
+    fn set_format(&self, request: alarm_clock::SetFormatRequest) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::set_time_now`
+    fn set_time_now(
+        &self,
+        request: alarm_clock::SetTimeNowRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::set_time_server`
+    fn set_time_server(
+        &self,
+        request: alarm_clock::SetTimeServerRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::set_time_zone`
+    fn set_time_zone(
+        &self,
+        request: alarm_clock::SetTimeZoneRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AlarmClock::update_alarm`
+    fn update_alarm(
+        &self,
+        request: alarm_clock::UpdateAlarmRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl AlarmClockDyn for SonosDevice {
+    fn create_alarm(
+        &self,
+        request: alarm_clock::CreateAlarmRequest,
+    ) -> ActionFuture<'_, alarm_clock::CreateAlarmResponse> {
+        Box::pin(<Self as AlarmClock>::create_alarm(self, request))
+    }
+    fn destroy_alarm(
+        &self,
+        request: alarm_clock::DestroyAlarmRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::destroy_alarm(self, request))
+    }
+    fn get_daily_index_refresh_time(
+        &self,
+    ) -> ActionFuture<'_, alarm_clock::GetDailyIndexRefreshTimeResponse> {
+        Box::pin(<Self as AlarmClock>::get_daily_index_refresh_time(self))
+    }
+    fn get_format(&self) -> ActionFuture<'_, alarm_clock::GetFormatResponse> {
+        Box::pin(<Self as AlarmClock>::get_format(self))
+    }
+    fn get_household_time_at_stamp(
+        &self,
+        request: alarm_clock::GetHouseholdTimeAtStampRequest,
+    ) -> ActionFuture<'_, alarm_clock::GetHouseholdTimeAtStampResponse> {
+        Box::pin(<Self as AlarmClock>::get_household_time_at_stamp(self, request))
+    }
+    fn get_time_now(&self) -> ActionFuture<'_, alarm_clock::GetTimeNowResponse> {
+        Box::pin(<Self as AlarmClock>::get_time_now(self))
+    }
+    fn get_time_server(&self) -> ActionFuture<'_, alarm_clock::GetTimeServerResponse> {
+        Box::pin(<Self as AlarmClock>::get_time_server(self))
+    }
+    fn get_time_zone(&self) -> ActionFuture<'_, alarm_clock::GetTimeZoneResponse> {
+        Box::pin(<Self as AlarmClock>::get_time_zone(self))
+    }
+    fn get_time_zone_and_rule(
+        &self,
+    ) -> ActionFuture<'_, alarm_clock::GetTimeZoneAndRuleResponse> {
+        Box::pin(<Self as AlarmClock>::get_time_zone_and_rule(self))
+    }
+    fn get_time_zone_rule(
+        &self,
+        request: alarm_clock::GetTimeZoneRuleRequest,
+    ) -> ActionFuture<'_, alarm_clock::GetTimeZoneRuleResponse> {
+        Box::pin(<Self as AlarmClock>::get_time_zone_rule(self, request))
+    }
+    fn list_alarms(&self) -> ActionFuture<'_, alarm_clock::ListAlarmsResponse> {
+        Box::pin(<Self as AlarmClock>::list_alarms(self))
+    }
+    fn set_daily_index_refresh_time(
+        &self,
+        request: alarm_clock::SetDailyIndexRefreshTimeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::set_daily_index_refresh_time(self, request))
+    }
+    fn set_format(
+        &self,
+        request: alarm_clock::SetFormatRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::set_format(self, request))
+    }
+    fn set_time_now(
+        &self,
+        request: alarm_clock::SetTimeNowRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::set_time_now(self, request))
+    }
+    fn set_time_server(
+        &self,
+        request: alarm_clock::SetTimeServerRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::set_time_server(self, request))
+    }
+    fn set_time_zone(
+        &self,
+        request: alarm_clock::SetTimeZoneRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::set_time_zone(self, request))
+    }
+    fn update_alarm(
+        &self,
+        request: alarm_clock::UpdateAlarmRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AlarmClock>::update_alarm(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_create_alarm_request() {
+        let request = CreateAlarmRequest {
+            start_local_time: "StartLocalTime".to_string(),
+            duration: "Duration".to_string(),
+            recurrence: Recurrence::Daily,
+            enabled: true,
+            room_uuid: "RoomUUID".to_string(),
+            program_uri: "ProgramURI".to_string(),
+            program_meta_data: "ProgramMetaData".to_string(),
+            play_mode: AlarmPlayMode::Shuffle,
+            volume: 1,
+            include_linked_zones: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CreateAlarmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_alarm_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:CreateAlarmResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><AssignedID>1</AssignedID></u:CreateAlarmResponse></s:Body></s:Envelope>"#;
+        let expected = CreateAlarmResponse {
+            assigned_id: Some(1),
+        };
+        let decoded = <CreateAlarmResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_destroy_alarm_request() {
+        let request = DestroyAlarmRequest { id: 1 };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: DestroyAlarmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_daily_index_refresh_time_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetDailyIndexRefreshTimeResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentDailyIndexRefreshTime>CurrentDailyIndexRefreshTime</CurrentDailyIndexRefreshTime></u:GetDailyIndexRefreshTimeResponse></s:Body></s:Envelope>"#;
+        let expected = GetDailyIndexRefreshTimeResponse {
+            current_daily_index_refresh_time: Some(
+                "CurrentDailyIndexRefreshTime".to_string(),
+            ),
+        };
+        let decoded = <GetDailyIndexRefreshTimeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_format_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetFormatResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentTimeFormat>CurrentTimeFormat</CurrentTimeFormat><CurrentDateFormat>CurrentDateFormat</CurrentDateFormat></u:GetFormatResponse></s:Body></s:Envelope>"#;
+        let expected = GetFormatResponse {
+            current_time_format: Some("CurrentTimeFormat".to_string()),
+            current_date_format: Some("CurrentDateFormat".to_string()),
+        };
+        let decoded = <GetFormatResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_household_time_at_stamp_request() {
+        let request = GetHouseholdTimeAtStampRequest {
+            time_stamp: "TimeStamp".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetHouseholdTimeAtStampRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_household_time_at_stamp_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetHouseholdTimeAtStampResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><HouseholdUTCTime>HouseholdUTCTime</HouseholdUTCTime></u:GetHouseholdTimeAtStampResponse></s:Body></s:Envelope>"#;
+        let expected = GetHouseholdTimeAtStampResponse {
+            household_utc_time: Some("HouseholdUTCTime".to_string()),
+        };
+        let decoded = <GetHouseholdTimeAtStampResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_time_now_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeNowResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentUTCTime>CurrentUTCTime</CurrentUTCTime><CurrentLocalTime>CurrentLocalTime</CurrentLocalTime><CurrentTimeZone>CurrentTimeZone</CurrentTimeZone><CurrentTimeGeneration>1</CurrentTimeGeneration></u:GetTimeNowResponse></s:Body></s:Envelope>"#;
+        let expected = GetTimeNowResponse {
+            current_utc_time: Some("CurrentUTCTime".to_string()),
+            current_local_time: Some("CurrentLocalTime".to_string()),
+            current_time_zone: Some("CurrentTimeZone".to_string()),
+            current_time_generation: Some(1),
+        };
+        let decoded = <GetTimeNowResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_time_server_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeServerResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentTimeServer>CurrentTimeServer</CurrentTimeServer></u:GetTimeServerResponse></s:Body></s:Envelope>"#;
+        let expected = GetTimeServerResponse {
+            current_time_server: Some("CurrentTimeServer".to_string()),
+        };
+        let decoded = <GetTimeServerResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_time_zone_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeZoneResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><Index>1</Index><AutoAdjustDst>1</AutoAdjustDst></u:GetTimeZoneResponse></s:Body></s:Envelope>"#;
+        let expected = GetTimeZoneResponse {
+            index: Some(1),
+            auto_adjust_dst: Some(true),
+        };
+        let decoded = <GetTimeZoneResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_time_zone_and_rule_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeZoneAndRuleResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><Index>1</Index><AutoAdjustDst>1</AutoAdjustDst><CurrentTimeZone>CurrentTimeZone</CurrentTimeZone></u:GetTimeZoneAndRuleResponse></s:Body></s:Envelope>"#;
+        let expected = GetTimeZoneAndRuleResponse {
+            index: Some(1),
+            auto_adjust_dst: Some(true),
+            current_time_zone: Some("CurrentTimeZone".to_string()),
+        };
+        let decoded = <GetTimeZoneAndRuleResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_time_zone_rule_request() {
+        let request = GetTimeZoneRuleRequest { index: 1 };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetTimeZoneRuleRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_time_zone_rule_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeZoneRuleResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><TimeZone>TimeZone</TimeZone></u:GetTimeZoneRuleResponse></s:Body></s:Envelope>"#;
+        let expected = GetTimeZoneRuleResponse {
+            time_zone: Some("TimeZone".to_string()),
+        };
+        let decoded = <GetTimeZoneRuleResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_list_alarms_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ListAlarmsResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentAlarmList>CurrentAlarmList</CurrentAlarmList><CurrentAlarmListVersion>CurrentAlarmListVersion</CurrentAlarmListVersion></u:ListAlarmsResponse></s:Body></s:Envelope>"#;
+        let expected = ListAlarmsResponse {
+            current_alarm_list: Some("CurrentAlarmList".to_string()),
+            current_alarm_list_version: Some("CurrentAlarmListVersion".to_string()),
+        };
+        let decoded = <ListAlarmsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_set_daily_index_refresh_time_request() {
+        let request = SetDailyIndexRefreshTimeRequest {
+            desired_daily_index_refresh_time: "DesiredDailyIndexRefreshTime".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetDailyIndexRefreshTimeRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_format_request() {
+        let request = SetFormatRequest {
+            desired_time_format: "DesiredTimeFormat".to_string(),
+            desired_date_format: "DesiredDateFormat".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetFormatRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_time_now_request() {
+        let request = SetTimeNowRequest {
+            desired_time: "DesiredTime".to_string(),
+            time_zone_for_desired_time: "TimeZoneForDesiredTime".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetTimeNowRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_time_server_request() {
+        let request = SetTimeServerRequest {
+            desired_time_server: "DesiredTimeServer".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetTimeServerRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_time_zone_request() {
+        let request = SetTimeZoneRequest {
+            index: 1,
+            auto_adjust_dst: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetTimeZoneRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_update_alarm_request() {
+        let request = UpdateAlarmRequest {
+            id: 1,
+            start_local_time: "StartLocalTime".to_string(),
+            duration: "Duration".to_string(),
+            recurrence: Recurrence::Daily,
+            enabled: true,
+            room_uuid: "RoomUUID".to_string(),
+            program_uri: "ProgramURI".to_string(),
+            program_meta_data: "ProgramMetaData".to_string(),
+            play_mode: AlarmPlayMode::Shuffle,
+            volume: 1,
+            include_linked_zones: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: UpdateAlarmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
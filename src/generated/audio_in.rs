@@ -0,0 +1,404 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `AudioIn` service.
+use super::*;
+/// URN for the `AudioIn` service.
+/// `urn:schemas-upnp-org:service:AudioIn:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AudioIn:1";
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAudioInputAttributesResponse", ns(SERVICE_TYPE))]
+pub struct GetAudioInputAttributesResponse {
+    #[xml(rename = "CurrentName", ns(""))]
+    pub current_name: Option<String>,
+    #[xml(rename = "CurrentIcon", ns(""))]
+    pub current_icon: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetAudioInputAttributesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLineInLevelResponse", ns(SERVICE_TYPE))]
+pub struct GetLineInLevelResponse {
+    #[xml(rename = "CurrentLeftLineInLevel", ns(""))]
+    pub current_left_line_in_level: Option<i32>,
+    #[xml(rename = "CurrentRightLineInLevel", ns(""))]
+    pub current_right_line_in_level: Option<i32>,
+}
+impl crate::DecodeSoapResponse for GetLineInLevelResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SelectAudio", ns(SERVICE_TYPE))]
+pub struct SelectAudioRequest {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAudioInputAttributes", ns(SERVICE_TYPE))]
+pub struct SetAudioInputAttributesRequest {
+    #[xml(rename = "DesiredName", ns(""))]
+    pub desired_name: String,
+    #[xml(rename = "DesiredIcon", ns(""))]
+    pub desired_icon: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetLineInLevel", ns(SERVICE_TYPE))]
+pub struct SetLineInLevelRequest {
+    #[xml(rename = "DesiredLeftLineInLevel", ns(""))]
+    pub desired_left_line_in_level: i32,
+    #[xml(rename = "DesiredRightLineInLevel", ns(""))]
+    pub desired_right_line_in_level: i32,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StartTransmissionToGroup", ns(SERVICE_TYPE))]
+pub struct StartTransmissionToGroupRequest {
+    #[xml(rename = "CoordinatorID", ns(""))]
+    pub coordinator_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StartTransmissionToGroupResponse", ns(SERVICE_TYPE))]
+pub struct StartTransmissionToGroupResponse {
+    #[xml(rename = "CurrentTransportSettings", ns(""))]
+    pub current_transport_settings: Option<String>,
+}
+impl crate::DecodeSoapResponse for StartTransmissionToGroupResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StopTransmissionToGroup", ns(SERVICE_TYPE))]
+pub struct StopTransmissionToGroupRequest {
+    #[xml(rename = "CoordinatorID", ns(""))]
+    pub coordinator_id: String,
+}
+/// A parsed event produced by the `AudioIn` service.
+/// Use `SonosDevice::subscribe_audio_in()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioInEvent {
+    pub audio_input_name: Option<String>,
+    pub icon: Option<String>,
+    pub left_line_in_level: Option<i32>,
+    pub line_in_connected: Option<bool>,
+    pub playing: Option<bool>,
+    pub right_line_in_level: Option<i32>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AudioInPropertySet {
+    pub properties: Vec<AudioInProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AudioInProperty {
+    #[xml(rename = "AudioInputName", ns(""))]
+    pub audio_input_name: Option<String>,
+    #[xml(rename = "Icon", ns(""))]
+    pub icon: Option<String>,
+    #[xml(rename = "LeftLineInLevel", ns(""))]
+    pub left_line_in_level: Option<i32>,
+    #[xml(rename = "LineInConnected", ns(""))]
+    pub line_in_connected: Option<bool>,
+    #[xml(rename = "Playing", ns(""))]
+    pub playing: Option<bool>,
+    #[xml(rename = "RightLineInLevel", ns(""))]
+    pub right_line_in_level: Option<i32>,
+}
+impl DecodeXml for AudioInEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: AudioInPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.audio_input_name {
+                result.audio_input_name.replace(v);
+            }
+            if let Some(v) = prop.icon {
+                result.icon.replace(v);
+            }
+            if let Some(v) = prop.left_line_in_level {
+                result.left_line_in_level.replace(v);
+            }
+            if let Some(v) = prop.line_in_connected {
+                result.line_in_connected.replace(v);
+            }
+            if let Some(v) = prop.playing {
+                result.playing.replace(v);
+            }
+            if let Some(v) = prop.right_line_in_level {
+                result.right_line_in_level.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `AudioIn` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_audio_in(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<AudioInEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Control line in
+pub trait AudioIn {
+    /// Supported by: `S5`, `S6`
+    async fn get_audio_input_attributes(
+        &self,
+    ) -> Result<audio_in::GetAudioInputAttributesResponse>;
+    /// Supported by: `S5`, `S6`
+    async fn get_line_in_level(&self) -> Result<audio_in::GetLineInLevelResponse>;
+    /// Supported by: `S5`, `S6`
+    async fn select_audio(&self, request: audio_in::SelectAudioRequest) -> Result<()>;
+    /// Supported by: `S5`, `S6`
+    async fn set_audio_input_attributes(
+        &self,
+        request: audio_in::SetAudioInputAttributesRequest,
+    ) -> Result<()>;
+    /// Supported by: `S5`, `S6`
+    async fn set_line_in_level(
+        &self,
+        request: audio_in::SetLineInLevelRequest,
+    ) -> Result<()>;
+    /// Supported by: `S5`, `S6`
+    async fn start_transmission_to_group(
+        &self,
+        request: audio_in::StartTransmissionToGroupRequest,
+    ) -> Result<audio_in::StartTransmissionToGroupResponse>;
+    /// Supported by: `S5`, `S6`
+    async fn stop_transmission_to_group(
+        &self,
+        request: audio_in::StopTransmissionToGroupRequest,
+    ) -> Result<()>;
+}
+impl AudioIn for SonosDevice {
+    async fn get_audio_input_attributes(
+        &self,
+    ) -> Result<audio_in::GetAudioInputAttributesResponse> {
+        self.action(
+                &audio_in::SERVICE_TYPE,
+                "GetAudioInputAttributes",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_line_in_level(&self) -> Result<audio_in::GetLineInLevelResponse> {
+        self.action(&audio_in::SERVICE_TYPE, "GetLineInLevel", crate::soap::Unit {})
+            .await
+    }
+    async fn select_audio(&self, request: audio_in::SelectAudioRequest) -> Result<()> {
+        self.action(&audio_in::SERVICE_TYPE, "SelectAudio", request).await
+    }
+    async fn set_audio_input_attributes(
+        &self,
+        request: audio_in::SetAudioInputAttributesRequest,
+    ) -> Result<()> {
+        self.action(&audio_in::SERVICE_TYPE, "SetAudioInputAttributes", request).await
+    }
+    async fn set_line_in_level(
+        &self,
+        request: audio_in::SetLineInLevelRequest,
+    ) -> Result<()> {
+        self.action(&audio_in::SERVICE_TYPE, "SetLineInLevel", request).await
+    }
+    async fn start_transmission_to_group(
+        &self,
+        request: audio_in::StartTransmissionToGroupRequest,
+    ) -> Result<audio_in::StartTransmissionToGroupResponse> {
+        self.action(&audio_in::SERVICE_TYPE, "StartTransmissionToGroup", request).await
+    }
+    async fn stop_transmission_to_group(
+        &self,
+        request: audio_in::StopTransmissionToGroupRequest,
+    ) -> Result<()> {
+        self.action(&audio_in::SERVICE_TYPE, "StopTransmissionToGroup", request).await
+    }
+}
+/// A variant of `AudioIn` that can be used as a trait object,
+/// eg: `Box<dyn AudioInDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait AudioInDyn: Send + Sync {
+    /// See `AudioIn::get_audio_input_attributes`
+    fn get_audio_input_attributes(
+        &self,
+    ) -> ActionFuture<'_, audio_in::GetAudioInputAttributesResponse>;
+    /// See `AudioIn::get_line_in_level`
+    fn get_line_in_level(&self) -> ActionFuture<'_, audio_in::GetLineInLevelResponse>;
+    /// See `AudioIn::select_audio`
+    fn select_audio(
+        &self,
+        request: audio_in::SelectAudioRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AudioIn::set_audio_input_attributes`
+    fn set_audio_input_attributes(
+        &self,
+        request: audio_in::SetAudioInputAttributesRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AudioIn::set_line_in_level`
+    fn set_line_in_level(
+        &self,
+        request: audio_in::SetLineInLevelRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AudioIn::start_transmission_to_group`
+    fn start_transmission_to_group(
+        &self,
+        request: audio_in::StartTransmissionToGroupRequest,
+    ) -> ActionFuture<'_, audio_in::StartTransmissionToGroupResponse>;
+    /// See `AudioIn::stop_transmission_to_group`
+    fn stop_transmission_to_group(
+        &self,
+        request: audio_in::StopTransmissionToGroupRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl AudioInDyn for SonosDevice {
+    fn get_audio_input_attributes(
+        &self,
+    ) -> ActionFuture<'_, audio_in::GetAudioInputAttributesResponse> {
+        Box::pin(<Self as AudioIn>::get_audio_input_attributes(self))
+    }
+    fn get_line_in_level(&self) -> ActionFuture<'_, audio_in::GetLineInLevelResponse> {
+        Box::pin(<Self as AudioIn>::get_line_in_level(self))
+    }
+    fn select_audio(
+        &self,
+        request: audio_in::SelectAudioRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AudioIn>::select_audio(self, request))
+    }
+    fn set_audio_input_attributes(
+        &self,
+        request: audio_in::SetAudioInputAttributesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AudioIn>::set_audio_input_attributes(self, request))
+    }
+    fn set_line_in_level(
+        &self,
+        request: audio_in::SetLineInLevelRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AudioIn>::set_line_in_level(self, request))
+    }
+    fn start_transmission_to_group(
+        &self,
+        request: audio_in::StartTransmissionToGroupRequest,
+    ) -> ActionFuture<'_, audio_in::StartTransmissionToGroupResponse> {
+        Box::pin(<Self as AudioIn>::start_transmission_to_group(self, request))
+    }
+    fn stop_transmission_to_group(
+        &self,
+        request: audio_in::StopTransmissionToGroupRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AudioIn>::stop_transmission_to_group(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_audio_input_attributes_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAudioInputAttributesResponse xmlns:u="urn:schemas-upnp-org:service:AudioIn:1"><CurrentName>CurrentName</CurrentName><CurrentIcon>CurrentIcon</CurrentIcon></u:GetAudioInputAttributesResponse></s:Body></s:Envelope>"#;
+        let expected = GetAudioInputAttributesResponse {
+            current_name: Some("CurrentName".to_string()),
+            current_icon: Some("CurrentIcon".to_string()),
+        };
+        let decoded = <GetAudioInputAttributesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_line_in_level_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetLineInLevelResponse xmlns:u="urn:schemas-upnp-org:service:AudioIn:1"><CurrentLeftLineInLevel>1</CurrentLeftLineInLevel><CurrentRightLineInLevel>1</CurrentRightLineInLevel></u:GetLineInLevelResponse></s:Body></s:Envelope>"#;
+        let expected = GetLineInLevelResponse {
+            current_left_line_in_level: Some(1),
+            current_right_line_in_level: Some(1),
+        };
+        let decoded = <GetLineInLevelResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_select_audio_request() {
+        let request = SelectAudioRequest {
+            object_id: "ObjectID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SelectAudioRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_audio_input_attributes_request() {
+        let request = SetAudioInputAttributesRequest {
+            desired_name: "DesiredName".to_string(),
+            desired_icon: "DesiredIcon".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAudioInputAttributesRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_line_in_level_request() {
+        let request = SetLineInLevelRequest {
+            desired_left_line_in_level: 1,
+            desired_right_line_in_level: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetLineInLevelRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_start_transmission_to_group_request() {
+        let request = StartTransmissionToGroupRequest {
+            coordinator_id: "CoordinatorID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StartTransmissionToGroupRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_start_transmission_to_group_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:StartTransmissionToGroupResponse xmlns:u="urn:schemas-upnp-org:service:AudioIn:1"><CurrentTransportSettings>CurrentTransportSettings</CurrentTransportSettings></u:StartTransmissionToGroupResponse></s:Body></s:Envelope>"#;
+        let expected = StartTransmissionToGroupResponse {
+            current_transport_settings: Some("CurrentTransportSettings".to_string()),
+        };
+        let decoded = <StartTransmissionToGroupResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_stop_transmission_to_group_request() {
+        let request = StopTransmissionToGroupRequest {
+            coordinator_id: "CoordinatorID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StopTransmissionToGroupRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+}
@@ -0,0 +1,4741 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `AVTransport` service.
+use super::*;
+/// URN for the `AVTransport` service.
+/// `urn:schemas-upnp-org:service:AVTransport:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AVTransport:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMultipleURIsToQueue", ns(SERVICE_TYPE))]
+pub struct AddMultipleUrisToQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "NumberOfURIs", ns(""))]
+    pub number_of_uris: u32,
+    #[xml(rename = "EnqueuedURIs", ns(""))]
+    pub enqueued_uris: String,
+    #[xml(rename = "EnqueuedURIsMetaData", ns(""))]
+    pub enqueued_uris_meta_data: String,
+    #[xml(rename = "ContainerURI", ns(""))]
+    pub container_uri: String,
+    #[xml(rename = "ContainerMetaData", ns(""))]
+    pub container_meta_data: String,
+    #[xml(rename = "DesiredFirstTrackNumberEnqueued", ns(""))]
+    pub desired_first_track_number_enqueued: u32,
+    #[xml(rename = "EnqueueAsNext", ns(""))]
+    pub enqueue_as_next: bool,
+}
+impl AddMultipleUrisToQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddMultipleUrisToQueueRequestBuilder {
+        AddMultipleUrisToQueueRequestBuilder::default()
+    }
+}
+/// Builds a `AddMultipleUrisToQueueRequest`; see `AddMultipleUrisToQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddMultipleUrisToQueueRequestBuilder {
+    request: AddMultipleUrisToQueueRequest,
+}
+impl AddMultipleUrisToQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn number_of_uris(mut self, number_of_uris: u32) -> Self {
+        self.request.number_of_uris = number_of_uris;
+        self
+    }
+    pub fn enqueued_uris(mut self, enqueued_uris: impl Into<String>) -> Self {
+        self.request.enqueued_uris = enqueued_uris.into();
+        self
+    }
+    pub fn enqueued_uris_meta_data(
+        mut self,
+        enqueued_uris_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.enqueued_uris_meta_data = enqueued_uris_meta_data.into();
+        self
+    }
+    pub fn container_uri(mut self, container_uri: impl Into<String>) -> Self {
+        self.request.container_uri = container_uri.into();
+        self
+    }
+    pub fn container_meta_data(
+        mut self,
+        container_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.container_meta_data = container_meta_data.into();
+        self
+    }
+    pub fn desired_first_track_number_enqueued(
+        mut self,
+        desired_first_track_number_enqueued: u32,
+    ) -> Self {
+        self.request.desired_first_track_number_enqueued = desired_first_track_number_enqueued;
+        self
+    }
+    pub fn enqueue_as_next(mut self, enqueue_as_next: bool) -> Self {
+        self.request.enqueue_as_next = enqueue_as_next;
+        self
+    }
+    pub fn build(self) -> AddMultipleUrisToQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMultipleURIsToQueueResponse", ns(SERVICE_TYPE))]
+pub struct AddMultipleUrisToQueueResponse {
+    #[xml(rename = "FirstTrackNumberEnqueued", ns(""))]
+    pub first_track_number_enqueued: Option<u32>,
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for AddMultipleUrisToQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURIToQueue", ns(SERVICE_TYPE))]
+pub struct AddUriToQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "EnqueuedURI", ns(""))]
+    pub enqueued_uri: String,
+    #[xml(rename = "EnqueuedURIMetaData", ns(""))]
+    pub enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    /// use `0` to add at the end or `1` to insert at the beginning
+    #[xml(rename = "DesiredFirstTrackNumberEnqueued", ns(""))]
+    pub desired_first_track_number_enqueued: u32,
+    #[xml(rename = "EnqueueAsNext", ns(""))]
+    pub enqueue_as_next: bool,
+}
+impl AddUriToQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddUriToQueueRequestBuilder {
+        AddUriToQueueRequestBuilder::default()
+    }
+}
+/// Builds a `AddUriToQueueRequest`; see `AddUriToQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddUriToQueueRequestBuilder {
+    request: AddUriToQueueRequest,
+}
+impl AddUriToQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn enqueued_uri(mut self, enqueued_uri: impl Into<String>) -> Self {
+        self.request.enqueued_uri = enqueued_uri.into();
+        self
+    }
+    pub fn enqueued_uri_meta_data(
+        mut self,
+        enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.enqueued_uri_meta_data = enqueued_uri_meta_data;
+        self
+    }
+    /// use `0` to add at the end or `1` to insert at the beginning
+    pub fn desired_first_track_number_enqueued(
+        mut self,
+        desired_first_track_number_enqueued: u32,
+    ) -> Self {
+        self.request.desired_first_track_number_enqueued = desired_first_track_number_enqueued;
+        self
+    }
+    pub fn enqueue_as_next(mut self, enqueue_as_next: bool) -> Self {
+        self.request.enqueue_as_next = enqueue_as_next;
+        self
+    }
+    pub fn build(self) -> AddUriToQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURIToQueueResponse", ns(SERVICE_TYPE))]
+pub struct AddUriToQueueResponse {
+    #[xml(rename = "FirstTrackNumberEnqueued", ns(""))]
+    pub first_track_number_enqueued: Option<u32>,
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+}
+impl crate::DecodeSoapResponse for AddUriToQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURIToSavedQueue", ns(SERVICE_TYPE))]
+pub struct AddUriToSavedQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "EnqueuedURI", ns(""))]
+    pub enqueued_uri: String,
+    #[xml(rename = "EnqueuedURIMetaData", ns(""))]
+    pub enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "AddAtIndex", ns(""))]
+    pub add_at_index: u32,
+}
+impl AddUriToSavedQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddUriToSavedQueueRequestBuilder {
+        AddUriToSavedQueueRequestBuilder::default()
+    }
+}
+/// Builds a `AddUriToSavedQueueRequest`; see `AddUriToSavedQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddUriToSavedQueueRequestBuilder {
+    request: AddUriToSavedQueueRequest,
+}
+impl AddUriToSavedQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn object_id(mut self, object_id: impl Into<String>) -> Self {
+        self.request.object_id = object_id.into();
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn enqueued_uri(mut self, enqueued_uri: impl Into<String>) -> Self {
+        self.request.enqueued_uri = enqueued_uri.into();
+        self
+    }
+    pub fn enqueued_uri_meta_data(
+        mut self,
+        enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.enqueued_uri_meta_data = enqueued_uri_meta_data;
+        self
+    }
+    pub fn add_at_index(mut self, add_at_index: u32) -> Self {
+        self.request.add_at_index = add_at_index;
+        self
+    }
+    pub fn build(self) -> AddUriToSavedQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURIToSavedQueueResponse", ns(SERVICE_TYPE))]
+pub struct AddUriToSavedQueueResponse {
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for AddUriToSavedQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BackupQueue", ns(SERVICE_TYPE))]
+pub struct BackupQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BecomeCoordinatorOfStandaloneGroup", ns(SERVICE_TYPE))]
+pub struct BecomeCoordinatorOfStandaloneGroupRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BecomeCoordinatorOfStandaloneGroupResponse", ns(SERVICE_TYPE))]
+pub struct BecomeCoordinatorOfStandaloneGroupResponse {
+    #[xml(rename = "DelegatedGroupCoordinatorID", ns(""))]
+    pub delegated_group_coordinator_id: Option<String>,
+    #[xml(rename = "NewGroupID", ns(""))]
+    pub new_group_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for BecomeCoordinatorOfStandaloneGroupResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BecomeGroupCoordinator", ns(SERVICE_TYPE))]
+pub struct BecomeGroupCoordinatorRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CurrentCoordinator", ns(""))]
+    pub current_coordinator: String,
+    #[xml(rename = "CurrentGroupID", ns(""))]
+    pub current_group_id: String,
+    #[xml(rename = "OtherMembers", ns(""))]
+    pub other_members: String,
+    #[xml(rename = "TransportSettings", ns(""))]
+    pub transport_settings: String,
+    #[xml(rename = "CurrentURI", ns(""))]
+    pub current_uri: String,
+    #[xml(rename = "CurrentURIMetaData", ns(""))]
+    pub current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "SleepTimerState", ns(""))]
+    pub sleep_timer_state: String,
+    #[xml(rename = "AlarmState", ns(""))]
+    pub alarm_state: String,
+    #[xml(rename = "StreamRestartState", ns(""))]
+    pub stream_restart_state: String,
+    #[xml(rename = "CurrentQueueTrackList", ns(""))]
+    pub current_queue_track_list: String,
+    #[xml(rename = "CurrentVLIState", ns(""))]
+    pub current_vli_state: String,
+}
+impl BecomeGroupCoordinatorRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> BecomeGroupCoordinatorRequestBuilder {
+        BecomeGroupCoordinatorRequestBuilder::default()
+    }
+}
+/// Builds a `BecomeGroupCoordinatorRequest`; see `BecomeGroupCoordinatorRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BecomeGroupCoordinatorRequestBuilder {
+    request: BecomeGroupCoordinatorRequest,
+}
+impl BecomeGroupCoordinatorRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn current_coordinator(
+        mut self,
+        current_coordinator: impl Into<String>,
+    ) -> Self {
+        self.request.current_coordinator = current_coordinator.into();
+        self
+    }
+    pub fn current_group_id(mut self, current_group_id: impl Into<String>) -> Self {
+        self.request.current_group_id = current_group_id.into();
+        self
+    }
+    pub fn other_members(mut self, other_members: impl Into<String>) -> Self {
+        self.request.other_members = other_members.into();
+        self
+    }
+    pub fn transport_settings(mut self, transport_settings: impl Into<String>) -> Self {
+        self.request.transport_settings = transport_settings.into();
+        self
+    }
+    pub fn current_uri(mut self, current_uri: impl Into<String>) -> Self {
+        self.request.current_uri = current_uri.into();
+        self
+    }
+    pub fn current_uri_meta_data(
+        mut self,
+        current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.current_uri_meta_data = current_uri_meta_data;
+        self
+    }
+    pub fn sleep_timer_state(mut self, sleep_timer_state: impl Into<String>) -> Self {
+        self.request.sleep_timer_state = sleep_timer_state.into();
+        self
+    }
+    pub fn alarm_state(mut self, alarm_state: impl Into<String>) -> Self {
+        self.request.alarm_state = alarm_state.into();
+        self
+    }
+    pub fn stream_restart_state(
+        mut self,
+        stream_restart_state: impl Into<String>,
+    ) -> Self {
+        self.request.stream_restart_state = stream_restart_state.into();
+        self
+    }
+    pub fn current_queue_track_list(
+        mut self,
+        current_queue_track_list: impl Into<String>,
+    ) -> Self {
+        self.request.current_queue_track_list = current_queue_track_list.into();
+        self
+    }
+    pub fn current_vli_state(mut self, current_vli_state: impl Into<String>) -> Self {
+        self.request.current_vli_state = current_vli_state.into();
+        self
+    }
+    pub fn build(self) -> BecomeGroupCoordinatorRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BecomeGroupCoordinatorAndSource", ns(SERVICE_TYPE))]
+pub struct BecomeGroupCoordinatorAndSourceRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CurrentCoordinator", ns(""))]
+    pub current_coordinator: String,
+    #[xml(rename = "CurrentGroupID", ns(""))]
+    pub current_group_id: String,
+    #[xml(rename = "OtherMembers", ns(""))]
+    pub other_members: String,
+    #[xml(rename = "CurrentURI", ns(""))]
+    pub current_uri: String,
+    #[xml(rename = "CurrentURIMetaData", ns(""))]
+    pub current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "SleepTimerState", ns(""))]
+    pub sleep_timer_state: String,
+    #[xml(rename = "AlarmState", ns(""))]
+    pub alarm_state: String,
+    #[xml(rename = "StreamRestartState", ns(""))]
+    pub stream_restart_state: String,
+    #[xml(rename = "CurrentAVTTrackList", ns(""))]
+    pub current_avt_track_list: String,
+    #[xml(rename = "CurrentQueueTrackList", ns(""))]
+    pub current_queue_track_list: String,
+    #[xml(rename = "CurrentSourceState", ns(""))]
+    pub current_source_state: String,
+    #[xml(rename = "ResumePlayback", ns(""))]
+    pub resume_playback: bool,
+}
+impl BecomeGroupCoordinatorAndSourceRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> BecomeGroupCoordinatorAndSourceRequestBuilder {
+        BecomeGroupCoordinatorAndSourceRequestBuilder::default()
+    }
+}
+/// Builds a `BecomeGroupCoordinatorAndSourceRequest`; see `BecomeGroupCoordinatorAndSourceRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BecomeGroupCoordinatorAndSourceRequestBuilder {
+    request: BecomeGroupCoordinatorAndSourceRequest,
+}
+impl BecomeGroupCoordinatorAndSourceRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn current_coordinator(
+        mut self,
+        current_coordinator: impl Into<String>,
+    ) -> Self {
+        self.request.current_coordinator = current_coordinator.into();
+        self
+    }
+    pub fn current_group_id(mut self, current_group_id: impl Into<String>) -> Self {
+        self.request.current_group_id = current_group_id.into();
+        self
+    }
+    pub fn other_members(mut self, other_members: impl Into<String>) -> Self {
+        self.request.other_members = other_members.into();
+        self
+    }
+    pub fn current_uri(mut self, current_uri: impl Into<String>) -> Self {
+        self.request.current_uri = current_uri.into();
+        self
+    }
+    pub fn current_uri_meta_data(
+        mut self,
+        current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.current_uri_meta_data = current_uri_meta_data;
+        self
+    }
+    pub fn sleep_timer_state(mut self, sleep_timer_state: impl Into<String>) -> Self {
+        self.request.sleep_timer_state = sleep_timer_state.into();
+        self
+    }
+    pub fn alarm_state(mut self, alarm_state: impl Into<String>) -> Self {
+        self.request.alarm_state = alarm_state.into();
+        self
+    }
+    pub fn stream_restart_state(
+        mut self,
+        stream_restart_state: impl Into<String>,
+    ) -> Self {
+        self.request.stream_restart_state = stream_restart_state.into();
+        self
+    }
+    pub fn current_avt_track_list(
+        mut self,
+        current_avt_track_list: impl Into<String>,
+    ) -> Self {
+        self.request.current_avt_track_list = current_avt_track_list.into();
+        self
+    }
+    pub fn current_queue_track_list(
+        mut self,
+        current_queue_track_list: impl Into<String>,
+    ) -> Self {
+        self.request.current_queue_track_list = current_queue_track_list.into();
+        self
+    }
+    pub fn current_source_state(
+        mut self,
+        current_source_state: impl Into<String>,
+    ) -> Self {
+        self.request.current_source_state = current_source_state.into();
+        self
+    }
+    pub fn resume_playback(mut self, resume_playback: bool) -> Self {
+        self.request.resume_playback = resume_playback;
+        self
+    }
+    pub fn build(self) -> BecomeGroupCoordinatorAndSourceRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ChangeCoordinator", ns(SERVICE_TYPE))]
+pub struct ChangeCoordinatorRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CurrentCoordinator", ns(""))]
+    pub current_coordinator: String,
+    #[xml(rename = "NewCoordinator", ns(""))]
+    pub new_coordinator: String,
+    #[xml(rename = "NewTransportSettings", ns(""))]
+    pub new_transport_settings: String,
+    #[xml(rename = "CurrentAVTransportURI", ns(""))]
+    pub current_av_transport_uri: String,
+}
+impl ChangeCoordinatorRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ChangeCoordinatorRequestBuilder {
+        ChangeCoordinatorRequestBuilder::default()
+    }
+}
+/// Builds a `ChangeCoordinatorRequest`; see `ChangeCoordinatorRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChangeCoordinatorRequestBuilder {
+    request: ChangeCoordinatorRequest,
+}
+impl ChangeCoordinatorRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn current_coordinator(
+        mut self,
+        current_coordinator: impl Into<String>,
+    ) -> Self {
+        self.request.current_coordinator = current_coordinator.into();
+        self
+    }
+    pub fn new_coordinator(mut self, new_coordinator: impl Into<String>) -> Self {
+        self.request.new_coordinator = new_coordinator.into();
+        self
+    }
+    pub fn new_transport_settings(
+        mut self,
+        new_transport_settings: impl Into<String>,
+    ) -> Self {
+        self.request.new_transport_settings = new_transport_settings.into();
+        self
+    }
+    pub fn current_av_transport_uri(
+        mut self,
+        current_av_transport_uri: impl Into<String>,
+    ) -> Self {
+        self.request.current_av_transport_uri = current_av_transport_uri.into();
+        self
+    }
+    pub fn build(self) -> ChangeCoordinatorRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ChangeTransportSettings", ns(SERVICE_TYPE))]
+pub struct ChangeTransportSettingsRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "NewTransportSettings", ns(""))]
+    pub new_transport_settings: String,
+    #[xml(rename = "CurrentAVTransportURI", ns(""))]
+    pub current_av_transport_uri: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ConfigureSleepTimer", ns(SERVICE_TYPE))]
+pub struct ConfigureSleepTimerRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Time to stop after, as `hh:mm:ss` or empty string to cancel
+    #[xml(rename = "NewSleepTimerDuration", ns(""))]
+    pub new_sleep_timer_duration: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateSavedQueue", ns(SERVICE_TYPE))]
+pub struct CreateSavedQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Title", ns(""))]
+    pub title: String,
+    #[xml(rename = "EnqueuedURI", ns(""))]
+    pub enqueued_uri: String,
+    #[xml(rename = "EnqueuedURIMetaData", ns(""))]
+    pub enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+}
+impl CreateSavedQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> CreateSavedQueueRequestBuilder {
+        CreateSavedQueueRequestBuilder::default()
+    }
+}
+/// Builds a `CreateSavedQueueRequest`; see `CreateSavedQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CreateSavedQueueRequestBuilder {
+    request: CreateSavedQueueRequest,
+}
+impl CreateSavedQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.request.title = title.into();
+        self
+    }
+    pub fn enqueued_uri(mut self, enqueued_uri: impl Into<String>) -> Self {
+        self.request.enqueued_uri = enqueued_uri.into();
+        self
+    }
+    pub fn enqueued_uri_meta_data(
+        mut self,
+        enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.enqueued_uri_meta_data = enqueued_uri_meta_data;
+        self
+    }
+    pub fn build(self) -> CreateSavedQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateSavedQueueResponse", ns(SERVICE_TYPE))]
+pub struct CreateSavedQueueResponse {
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "AssignedObjectID", ns(""))]
+    pub assigned_object_id: Option<String>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for CreateSavedQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "DelegateGroupCoordinationTo", ns(SERVICE_TYPE))]
+pub struct DelegateGroupCoordinationToRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// uuid of the new coordinator - must be in same group
+    #[xml(rename = "NewCoordinator", ns(""))]
+    pub new_coordinator: String,
+    /// Should former coordinator rejoin the group?
+    #[xml(rename = "RejoinGroup", ns(""))]
+    pub rejoin_group: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EndDirectControlSession", ns(SERVICE_TYPE))]
+pub struct EndDirectControlSessionRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetCrossfadeMode", ns(SERVICE_TYPE))]
+pub struct GetCrossfadeModeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetCrossfadeModeResponse", ns(SERVICE_TYPE))]
+pub struct GetCrossfadeModeResponse {
+    #[xml(rename = "CrossfadeMode", ns(""))]
+    pub crossfade_mode: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetCrossfadeModeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetCurrentTransportActions", ns(SERVICE_TYPE))]
+pub struct GetCurrentTransportActionsRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetCurrentTransportActionsResponse", ns(SERVICE_TYPE))]
+pub struct GetCurrentTransportActionsResponse {
+    #[xml(rename = "Actions", ns(""))]
+    pub actions: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetCurrentTransportActionsResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetDeviceCapabilities", ns(SERVICE_TYPE))]
+pub struct GetDeviceCapabilitiesRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetDeviceCapabilitiesResponse", ns(SERVICE_TYPE))]
+pub struct GetDeviceCapabilitiesResponse {
+    #[xml(rename = "PlayMedia", ns(""))]
+    pub play_media: Option<String>,
+    #[xml(rename = "RecMedia", ns(""))]
+    pub rec_media: Option<String>,
+    #[xml(rename = "RecQualityModes", ns(""))]
+    pub rec_quality_modes: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetDeviceCapabilitiesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetMediaInfo", ns(SERVICE_TYPE))]
+pub struct GetMediaInfoRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetMediaInfoResponse", ns(SERVICE_TYPE))]
+pub struct GetMediaInfoResponse {
+    #[xml(rename = "NrTracks", ns(""))]
+    pub nr_tracks: Option<u32>,
+    #[xml(rename = "MediaDuration", ns(""))]
+    pub media_duration: Option<crate::HmsDuration>,
+    #[xml(rename = "CurrentURI", ns(""))]
+    pub current_uri: Option<String>,
+    #[xml(rename = "CurrentURIMetaData", ns(""))]
+    pub current_uri_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    #[xml(rename = "NextURI", ns(""))]
+    pub next_uri: Option<String>,
+    #[xml(rename = "NextURIMetaData", ns(""))]
+    pub next_uri_meta_data: Option<String>,
+    #[xml(rename = "PlayMedium", ns(""))]
+    pub play_medium: Option<super::PlaybackStorageMedium>,
+    #[xml(rename = "RecordMedium", ns(""))]
+    pub record_medium: Option<String>,
+    #[xml(rename = "WriteStatus", ns(""))]
+    pub write_status: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetMediaInfoResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetPositionInfo", ns(SERVICE_TYPE))]
+pub struct GetPositionInfoRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetPositionInfoResponse", ns(SERVICE_TYPE))]
+pub struct GetPositionInfoResponse {
+    #[xml(rename = "Track", ns(""))]
+    pub track: Option<u32>,
+    #[xml(rename = "TrackDuration", ns(""))]
+    pub track_duration: Option<crate::HmsDuration>,
+    #[xml(rename = "TrackMetaData", ns(""))]
+    pub track_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    #[xml(rename = "TrackURI", ns(""))]
+    pub track_uri: Option<String>,
+    #[xml(rename = "RelTime", ns(""))]
+    pub rel_time: Option<crate::HmsDuration>,
+    #[xml(rename = "AbsTime", ns(""))]
+    pub abs_time: Option<crate::HmsDuration>,
+    #[xml(rename = "RelCount", ns(""))]
+    pub rel_count: Option<i32>,
+    #[xml(rename = "AbsCount", ns(""))]
+    pub abs_count: Option<i32>,
+}
+impl crate::DecodeSoapResponse for GetPositionInfoResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRemainingSleepTimerDuration", ns(SERVICE_TYPE))]
+pub struct GetRemainingSleepTimerDurationRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRemainingSleepTimerDurationResponse", ns(SERVICE_TYPE))]
+pub struct GetRemainingSleepTimerDurationResponse {
+    #[xml(rename = "RemainingSleepTimerDuration", ns(""))]
+    pub remaining_sleep_timer_duration: Option<String>,
+    #[xml(rename = "CurrentSleepTimerGeneration", ns(""))]
+    pub current_sleep_timer_generation: Option<u32>,
+}
+impl crate::DecodeSoapResponse for GetRemainingSleepTimerDurationResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRunningAlarmProperties", ns(SERVICE_TYPE))]
+pub struct GetRunningAlarmPropertiesRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRunningAlarmPropertiesResponse", ns(SERVICE_TYPE))]
+pub struct GetRunningAlarmPropertiesResponse {
+    #[xml(rename = "AlarmID", ns(""))]
+    pub alarm_id: Option<u32>,
+    #[xml(rename = "GroupID", ns(""))]
+    pub group_id: Option<String>,
+    #[xml(rename = "LoggedStartTime", ns(""))]
+    pub logged_start_time: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetRunningAlarmPropertiesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTransportInfo", ns(SERVICE_TYPE))]
+pub struct GetTransportInfoRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTransportInfoResponse", ns(SERVICE_TYPE))]
+pub struct GetTransportInfoResponse {
+    #[xml(rename = "CurrentTransportState", ns(""))]
+    pub current_transport_state: Option<super::TransportState>,
+    #[xml(rename = "CurrentTransportStatus", ns(""))]
+    pub current_transport_status: Option<String>,
+    #[xml(rename = "CurrentSpeed", ns(""))]
+    pub current_speed: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetTransportInfoResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTransportSettings", ns(SERVICE_TYPE))]
+pub struct GetTransportSettingsRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTransportSettingsResponse", ns(SERVICE_TYPE))]
+pub struct GetTransportSettingsResponse {
+    #[xml(rename = "PlayMode", ns(""))]
+    pub play_mode: Option<super::CurrentPlayMode>,
+    #[xml(rename = "RecQualityMode", ns(""))]
+    pub rec_quality_mode: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetTransportSettingsResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Next", ns(SERVICE_TYPE))]
+pub struct NextRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "NotifyDeletedURI", ns(SERVICE_TYPE))]
+pub struct NotifyDeletedUriRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "DeletedURI", ns(""))]
+    pub deleted_uri: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Pause", ns(SERVICE_TYPE))]
+pub struct PauseRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Play", ns(SERVICE_TYPE))]
+pub struct PlayRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Play speed usually 1, can be a fraction of 1
+    #[xml(rename = "Speed", ns(""))]
+    pub speed: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Previous", ns(SERVICE_TYPE))]
+pub struct PreviousRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveAllTracksFromQueue", ns(SERVICE_TYPE))]
+pub struct RemoveAllTracksFromQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveTrackFromQueue", ns(SERVICE_TYPE))]
+pub struct RemoveTrackFromQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveTrackRangeFromQueue", ns(SERVICE_TYPE))]
+pub struct RemoveTrackRangeFromQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Leave blank
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    /// between 1 and queue-length
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "NumberOfTracks", ns(""))]
+    pub number_of_tracks: u32,
+}
+impl RemoveTrackRangeFromQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> RemoveTrackRangeFromQueueRequestBuilder {
+        RemoveTrackRangeFromQueueRequestBuilder::default()
+    }
+}
+/// Builds a `RemoveTrackRangeFromQueueRequest`; see `RemoveTrackRangeFromQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RemoveTrackRangeFromQueueRequestBuilder {
+    request: RemoveTrackRangeFromQueueRequest,
+}
+impl RemoveTrackRangeFromQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    /// Leave blank
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    /// between 1 and queue-length
+    pub fn starting_index(mut self, starting_index: u32) -> Self {
+        self.request.starting_index = starting_index;
+        self
+    }
+    pub fn number_of_tracks(mut self, number_of_tracks: u32) -> Self {
+        self.request.number_of_tracks = number_of_tracks;
+        self
+    }
+    pub fn build(self) -> RemoveTrackRangeFromQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveTrackRangeFromQueueResponse", ns(SERVICE_TYPE))]
+pub struct RemoveTrackRangeFromQueueResponse {
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for RemoveTrackRangeFromQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReorderTracksInQueue", ns(SERVICE_TYPE))]
+pub struct ReorderTracksInQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "NumberOfTracks", ns(""))]
+    pub number_of_tracks: u32,
+    #[xml(rename = "InsertBefore", ns(""))]
+    pub insert_before: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+}
+impl ReorderTracksInQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ReorderTracksInQueueRequestBuilder {
+        ReorderTracksInQueueRequestBuilder::default()
+    }
+}
+/// Builds a `ReorderTracksInQueueRequest`; see `ReorderTracksInQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReorderTracksInQueueRequestBuilder {
+    request: ReorderTracksInQueueRequest,
+}
+impl ReorderTracksInQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn starting_index(mut self, starting_index: u32) -> Self {
+        self.request.starting_index = starting_index;
+        self
+    }
+    pub fn number_of_tracks(mut self, number_of_tracks: u32) -> Self {
+        self.request.number_of_tracks = number_of_tracks;
+        self
+    }
+    pub fn insert_before(mut self, insert_before: u32) -> Self {
+        self.request.insert_before = insert_before;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn build(self) -> ReorderTracksInQueueRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReorderTracksInSavedQueue", ns(SERVICE_TYPE))]
+pub struct ReorderTracksInSavedQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "TrackList", ns(""))]
+    pub track_list: String,
+    #[xml(rename = "NewPositionList", ns(""))]
+    pub new_position_list: String,
+}
+impl ReorderTracksInSavedQueueRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ReorderTracksInSavedQueueRequestBuilder {
+        ReorderTracksInSavedQueueRequestBuilder::default()
+    }
+}
+/// Builds a `ReorderTracksInSavedQueueRequest`; see `ReorderTracksInSavedQueueRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReorderTracksInSavedQueueRequestBuilder {
+    request: ReorderTracksInSavedQueueRequest,
+}
+impl ReorderTracksInSavedQueueRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn object_id(mut self, object_id: impl Into<String>) -> Self {
+        self.request.object_id = object_id.into();
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn track_list(mut self, track_list: impl Into<String>) -> Self {
+        self.request.track_list = track_list.into();
+        self
+    }
+    pub fn new_position_list(mut self, new_position_list: impl Into<String>) -> Self {
+        self.request.new_position_list = new_position_list.into();
+        self
+    }
+    pub fn build(self) -> ReorderTracksInSavedQueueRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReorderTracksInSavedQueueResponse", ns(SERVICE_TYPE))]
+pub struct ReorderTracksInSavedQueueResponse {
+    #[xml(rename = "QueueLengthChange", ns(""))]
+    pub queue_length_change: Option<i32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for ReorderTracksInSavedQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RunAlarm", ns(SERVICE_TYPE))]
+pub struct RunAlarmRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "AlarmID", ns(""))]
+    pub alarm_id: u32,
+    #[xml(rename = "LoggedStartTime", ns(""))]
+    pub logged_start_time: String,
+    #[xml(rename = "Duration", ns(""))]
+    pub duration: String,
+    #[xml(rename = "ProgramURI", ns(""))]
+    pub program_uri: String,
+    #[xml(rename = "ProgramMetaData", ns(""))]
+    pub program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "PlayMode", ns(""))]
+    pub play_mode: super::CurrentPlayMode,
+    #[xml(rename = "Volume", ns(""))]
+    pub volume: u16,
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: bool,
+}
+impl RunAlarmRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> RunAlarmRequestBuilder {
+        RunAlarmRequestBuilder::default()
+    }
+}
+/// Builds a `RunAlarmRequest`; see `RunAlarmRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RunAlarmRequestBuilder {
+    request: RunAlarmRequest,
+}
+impl RunAlarmRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn alarm_id(mut self, alarm_id: u32) -> Self {
+        self.request.alarm_id = alarm_id;
+        self
+    }
+    pub fn logged_start_time(mut self, logged_start_time: impl Into<String>) -> Self {
+        self.request.logged_start_time = logged_start_time.into();
+        self
+    }
+    pub fn duration(mut self, duration: impl Into<String>) -> Self {
+        self.request.duration = duration.into();
+        self
+    }
+    pub fn program_uri(mut self, program_uri: impl Into<String>) -> Self {
+        self.request.program_uri = program_uri.into();
+        self
+    }
+    pub fn program_meta_data(
+        mut self,
+        program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.program_meta_data = program_meta_data;
+        self
+    }
+    pub fn play_mode(mut self, play_mode: super::CurrentPlayMode) -> Self {
+        self.request.play_mode = play_mode;
+        self
+    }
+    pub fn volume(mut self, volume: u16) -> Self {
+        self.request.volume = volume;
+        self
+    }
+    pub fn include_linked_zones(mut self, include_linked_zones: bool) -> Self {
+        self.request.include_linked_zones = include_linked_zones;
+        self
+    }
+    pub fn build(self) -> RunAlarmRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SaveQueue", ns(SERVICE_TYPE))]
+pub struct SaveQueueRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// SONOS playlist title
+    #[xml(rename = "Title", ns(""))]
+    pub title: String,
+    /// Leave blank
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SaveQueueResponse", ns(SERVICE_TYPE))]
+pub struct SaveQueueResponse {
+    #[xml(rename = "AssignedObjectID", ns(""))]
+    pub assigned_object_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for SaveQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Seek", ns(SERVICE_TYPE))]
+pub struct SeekRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// What to seek
+    #[xml(rename = "Unit", ns(""))]
+    pub unit: super::SeekMode,
+    /// Position of track in queue (start at 1) or `hh:mm:ss` for `REL_TIME` or `+/-hh:mm:ss` for `TIME_DELTA`
+    #[xml(rename = "Target", ns(""))]
+    pub target: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAVTransportURI", ns(SERVICE_TYPE))]
+pub struct SetAvTransportUriRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// The new TransportURI - its a special SONOS format
+    #[xml(rename = "CurrentURI", ns(""))]
+    pub current_uri: String,
+    /// Track Metadata, see MetadataHelper.GuessTrack to guess based on track uri
+    #[xml(rename = "CurrentURIMetaData", ns(""))]
+    pub current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetCrossfadeMode", ns(SERVICE_TYPE))]
+pub struct SetCrossfadeModeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CrossfadeMode", ns(""))]
+    pub crossfade_mode: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetNextAVTransportURI", ns(SERVICE_TYPE))]
+pub struct SetNextAvTransportUriRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "NextURI", ns(""))]
+    pub next_uri: String,
+    #[xml(rename = "NextURIMetaData", ns(""))]
+    pub next_uri_meta_data: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetPlayMode", ns(SERVICE_TYPE))]
+pub struct SetPlayModeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// New playmode
+    #[xml(rename = "NewPlayMode", ns(""))]
+    pub new_play_mode: super::CurrentPlayMode,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SnoozeAlarm", ns(SERVICE_TYPE))]
+pub struct SnoozeAlarmRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Snooze time as `hh:mm:ss`, 10 minutes = 00:10:00
+    #[xml(rename = "Duration", ns(""))]
+    pub duration: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StartAutoplay", ns(SERVICE_TYPE))]
+pub struct StartAutoplayRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "ProgramURI", ns(""))]
+    pub program_uri: String,
+    #[xml(rename = "ProgramMetaData", ns(""))]
+    pub program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "Volume", ns(""))]
+    pub volume: u16,
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: bool,
+    #[xml(rename = "ResetVolumeAfter", ns(""))]
+    pub reset_volume_after: bool,
+}
+impl StartAutoplayRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> StartAutoplayRequestBuilder {
+        StartAutoplayRequestBuilder::default()
+    }
+}
+/// Builds a `StartAutoplayRequest`; see `StartAutoplayRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StartAutoplayRequestBuilder {
+    request: StartAutoplayRequest,
+}
+impl StartAutoplayRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn program_uri(mut self, program_uri: impl Into<String>) -> Self {
+        self.request.program_uri = program_uri.into();
+        self
+    }
+    pub fn program_meta_data(
+        mut self,
+        program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.program_meta_data = program_meta_data;
+        self
+    }
+    pub fn volume(mut self, volume: u16) -> Self {
+        self.request.volume = volume;
+        self
+    }
+    pub fn include_linked_zones(mut self, include_linked_zones: bool) -> Self {
+        self.request.include_linked_zones = include_linked_zones;
+        self
+    }
+    pub fn reset_volume_after(mut self, reset_volume_after: bool) -> Self {
+        self.request.reset_volume_after = reset_volume_after;
+        self
+    }
+    pub fn build(self) -> StartAutoplayRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Stop", ns(SERVICE_TYPE))]
+pub struct StopRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+/// A parsed event produced by the `AVTransport` service.
+/// Use `SonosDevice::subscribe_av_transport()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AVTransportEvent {
+    pub last_change: Option<DecodeXmlString<AVTransportLastChangeMap>>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AVTransportPropertySet {
+    pub properties: Vec<AVTransportProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct AVTransportProperty {
+    #[xml(rename = "LastChange", ns(""))]
+    pub last_change: Option<DecodeXmlString<AVTransportLastChangeMap>>,
+}
+impl DecodeXml for AVTransportEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: AVTransportPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.last_change {
+                result.last_change.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `AVTransport` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_av_transport(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<AVTransportEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AVTransportLastChange {
+    pub av_transport_uri: Option<String>,
+    pub av_transport_uri_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    pub alarm_include_linked_zones: Option<bool>,
+    pub alarm_state: Option<String>,
+    pub alarm_volume: Option<u16>,
+    pub current_av_transport_uri: Option<String>,
+    pub enqueue_as_next: Option<bool>,
+    pub group_id: Option<String>,
+    pub iso8601_time: Option<String>,
+    pub instance_id: Option<crate::InstanceId>,
+    pub list_uri: Option<String>,
+    pub list_uri_meta_data: Option<String>,
+    pub member_id: Option<String>,
+    pub member_list: Option<String>,
+    pub num_tracks: Option<u32>,
+    pub num_tracks_change: Option<i32>,
+    pub object_id: Option<String>,
+    pub player_id: Option<String>,
+    pub queue: Option<String>,
+    pub rejoin_group: Option<bool>,
+    pub reset_volume_after: Option<bool>,
+    pub resume_playback: Option<bool>,
+    pub saved_queue_title: Option<String>,
+    pub seek_mode: Option<super::SeekMode>,
+    pub seek_target: Option<String>,
+    pub sleep_timer_state: Option<String>,
+    pub source_state: Option<String>,
+    pub stream_restart_state: Option<String>,
+    pub track_list: Option<String>,
+    pub track_number: Option<u32>,
+    pub transport_settings: Option<String>,
+    pub uri: Option<String>,
+    pub uri_meta_data: Option<String>,
+    pub vli_state: Option<String>,
+    pub absolute_counter_position: Option<i32>,
+    pub absolute_time_position: Option<crate::HmsDuration>,
+    pub alarm_id_running: Option<u32>,
+    pub alarm_logged_start_time: Option<String>,
+    pub alarm_running: Option<bool>,
+    pub current_crossfade_mode: Option<bool>,
+    pub current_media_duration: Option<crate::HmsDuration>,
+    pub current_play_mode: Option<super::CurrentPlayMode>,
+    pub current_record_quality_mode: Option<String>,
+    pub current_section: Option<u32>,
+    pub current_track: Option<u32>,
+    pub current_track_duration: Option<crate::HmsDuration>,
+    pub current_track_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    pub current_track_uri: Option<String>,
+    pub current_transport_actions: Option<String>,
+    pub current_valid_play_modes: Option<String>,
+    pub direct_control_account_id: Option<String>,
+    pub direct_control_client_id: Option<String>,
+    pub direct_control_is_suspended: Option<bool>,
+    pub enqueued_transport_uri: Option<String>,
+    pub enqueued_transport_uri_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    pub muse_sessions: Option<String>,
+    pub next_av_transport_uri: Option<String>,
+    pub next_av_transport_uri_meta_data: Option<String>,
+    pub next_track_meta_data: Option<String>,
+    pub next_track_uri: Option<String>,
+    pub number_of_tracks: Option<u32>,
+    pub playback_storage_medium: Option<super::PlaybackStorageMedium>,
+    pub possible_playback_storage_media: Option<String>,
+    pub possible_record_quality_modes: Option<String>,
+    pub possible_record_storage_media: Option<String>,
+    pub queue_update_id: Option<u32>,
+    pub record_medium_write_status: Option<String>,
+    pub record_storage_medium: Option<String>,
+    pub relative_counter_position: Option<i32>,
+    pub relative_time_position: Option<crate::HmsDuration>,
+    pub restart_pending: Option<bool>,
+    pub sleep_timer_generation: Option<u32>,
+    pub snooze_running: Option<bool>,
+    pub transport_error_description: Option<String>,
+    pub transport_error_http_code: Option<String>,
+    pub transport_error_http_headers: Option<String>,
+    pub transport_error_uri: Option<String>,
+    pub transport_play_speed: Option<String>,
+    pub transport_state: Option<super::TransportState>,
+    pub transport_status: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AVTransportURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAVTransportURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AVTransportURIMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAVTransportURIMetaData {
+    #[xml(attribute)]
+    val: Option<DecodeXmlString<crate::TrackMetaData>>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmIncludeLinkedZones", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmIncludeLinkedZones {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmState {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmVolume", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmVolume {
+    #[xml(attribute)]
+    val: Option<u16>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentAVTransportURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentAVTransportURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "EnqueueAsNext", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeEnqueueAsNext {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "GroupID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeGroupID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ISO8601Time", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeISO8601Time {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "InstanceID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeInstanceID {
+    #[xml(attribute)]
+    val: Option<crate::InstanceId>,
+}
+#[derive(FromXml)]
+#[xml(rename = "LIST_URI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeLIST_URI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "LIST_URIMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeLIST_URIMetaData {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "MemberID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeMemberID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "MemberList", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeMemberList {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NumTracks", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNumTracks {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NumTracksChange", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNumTracksChange {
+    #[xml(attribute)]
+    val: Option<i32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ObjectID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeObjectID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PlayerID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangePlayerID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Queue", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeQueue {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RejoinGroup", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRejoinGroup {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ResetVolumeAfter", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeResetVolumeAfter {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ResumePlayback", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeResumePlayback {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SavedQueueTitle", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSavedQueueTitle {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SeekMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSeekMode {
+    #[xml(attribute)]
+    val: Option<super::SeekMode>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SeekTarget", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSeekTarget {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SleepTimerState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSleepTimerState {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SourceState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSourceState {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "StreamRestartState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeStreamRestartState {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TrackList", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTrackList {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TrackNumber", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTrackNumber {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportSettings", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportSettings {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "URI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "URIMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeURIMetaData {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "VLIState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeVLIState {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AbsoluteCounterPosition", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAbsoluteCounterPosition {
+    #[xml(attribute)]
+    val: Option<i32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AbsoluteTimePosition", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAbsoluteTimePosition {
+    #[xml(attribute)]
+    val: Option<crate::HmsDuration>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmIDRunning", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmIDRunning {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmLoggedStartTime", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmLoggedStartTime {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AlarmRunning", ns("urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeAlarmRunning {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentCrossfadeMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentCrossfadeMode {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentMediaDuration", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentMediaDuration {
+    #[xml(attribute)]
+    val: Option<crate::HmsDuration>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentPlayMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentPlayMode {
+    #[xml(attribute)]
+    val: Option<super::CurrentPlayMode>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentRecordQualityMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentRecordQualityMode {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentSection", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentSection {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentTrack", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentTrack {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentTrackDuration", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentTrackDuration {
+    #[xml(attribute)]
+    val: Option<crate::HmsDuration>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentTrackMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentTrackMetaData {
+    #[xml(attribute)]
+    val: Option<DecodeXmlString<crate::TrackMetaData>>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentTrackURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentTrackURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "CurrentTransportActions", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentTransportActions {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "CurrentValidPlayModes",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeCurrentValidPlayModes {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "DirectControlAccountID",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeDirectControlAccountID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "DirectControlClientID",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeDirectControlClientID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "DirectControlIsSuspended",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeDirectControlIsSuspended {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "EnqueuedTransportURI",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeEnqueuedTransportURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "EnqueuedTransportURIMetaData",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeEnqueuedTransportURIMetaData {
+    #[xml(attribute)]
+    val: Option<DecodeXmlString<crate::TrackMetaData>>,
+}
+#[derive(FromXml)]
+#[xml(rename = "MuseSessions", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeMuseSessions {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NextAVTransportURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNextAVTransportURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NextAVTransportURIMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNextAVTransportURIMetaData {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NextTrackMetaData", ns("urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNextTrackMetaData {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NextTrackURI", ns("urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNextTrackURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NumberOfTracks", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeNumberOfTracks {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PlaybackStorageMedium", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangePlaybackStorageMedium {
+    #[xml(attribute)]
+    val: Option<super::PlaybackStorageMedium>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PossiblePlaybackStorageMedia", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangePossiblePlaybackStorageMedia {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PossibleRecordQualityModes", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangePossibleRecordQualityModes {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PossibleRecordStorageMedia", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangePossibleRecordStorageMedia {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "QueueUpdateID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeQueueUpdateID {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RecordMediumWriteStatus", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRecordMediumWriteStatus {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RecordStorageMedium", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRecordStorageMedium {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RelativeCounterPosition", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRelativeCounterPosition {
+    #[xml(attribute)]
+    val: Option<i32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RelativeTimePosition", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRelativeTimePosition {
+    #[xml(attribute)]
+    val: Option<crate::HmsDuration>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RestartPending", ns("urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeRestartPending {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(
+    rename = "SleepTimerGeneration",
+    ns("urn:schemas-rinconnetworks-com:metadata-1-0/")
+)]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSleepTimerGeneration {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SnoozeRunning", ns("urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeSnoozeRunning {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportErrorDescription", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportErrorDescription {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportErrorHttpCode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportErrorHttpCode {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportErrorHttpHeaders", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportErrorHttpHeaders {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportErrorURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportErrorURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportPlaySpeed", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportPlaySpeed {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportState", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportState {
+    #[xml(attribute)]
+    val: Option<super::TransportState>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TransportStatus", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct AVTransportLastChangeTransportStatus {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+const LAST_CHANGE_NS: &str = "urn:schemas-upnp-org:metadata-1-0/AVT/";
+#[derive(FromXml)]
+#[xml(rename = "InstanceID", ns(LAST_CHANGE_NS))]
+struct AVTransportLastChangeRootObject {
+    #[xml(rename = "val", attribute)]
+    object_instance_id_: u32,
+    av_transport_uri: Option<AVTransportLastChangeAVTransportURI>,
+    av_transport_uri_meta_data: Option<AVTransportLastChangeAVTransportURIMetaData>,
+    alarm_include_linked_zones: Option<AVTransportLastChangeAlarmIncludeLinkedZones>,
+    alarm_state: Option<AVTransportLastChangeAlarmState>,
+    alarm_volume: Option<AVTransportLastChangeAlarmVolume>,
+    current_av_transport_uri: Option<AVTransportLastChangeCurrentAVTransportURI>,
+    enqueue_as_next: Option<AVTransportLastChangeEnqueueAsNext>,
+    group_id: Option<AVTransportLastChangeGroupID>,
+    iso8601_time: Option<AVTransportLastChangeISO8601Time>,
+    instance_id: Option<AVTransportLastChangeInstanceID>,
+    list_uri: Option<AVTransportLastChangeLIST_URI>,
+    list_uri_meta_data: Option<AVTransportLastChangeLIST_URIMetaData>,
+    member_id: Option<AVTransportLastChangeMemberID>,
+    member_list: Option<AVTransportLastChangeMemberList>,
+    num_tracks: Option<AVTransportLastChangeNumTracks>,
+    num_tracks_change: Option<AVTransportLastChangeNumTracksChange>,
+    object_id: Option<AVTransportLastChangeObjectID>,
+    player_id: Option<AVTransportLastChangePlayerID>,
+    queue: Option<AVTransportLastChangeQueue>,
+    rejoin_group: Option<AVTransportLastChangeRejoinGroup>,
+    reset_volume_after: Option<AVTransportLastChangeResetVolumeAfter>,
+    resume_playback: Option<AVTransportLastChangeResumePlayback>,
+    saved_queue_title: Option<AVTransportLastChangeSavedQueueTitle>,
+    seek_mode: Option<AVTransportLastChangeSeekMode>,
+    seek_target: Option<AVTransportLastChangeSeekTarget>,
+    sleep_timer_state: Option<AVTransportLastChangeSleepTimerState>,
+    source_state: Option<AVTransportLastChangeSourceState>,
+    stream_restart_state: Option<AVTransportLastChangeStreamRestartState>,
+    track_list: Option<AVTransportLastChangeTrackList>,
+    track_number: Option<AVTransportLastChangeTrackNumber>,
+    transport_settings: Option<AVTransportLastChangeTransportSettings>,
+    uri: Option<AVTransportLastChangeURI>,
+    uri_meta_data: Option<AVTransportLastChangeURIMetaData>,
+    vli_state: Option<AVTransportLastChangeVLIState>,
+    absolute_counter_position: Option<AVTransportLastChangeAbsoluteCounterPosition>,
+    absolute_time_position: Option<AVTransportLastChangeAbsoluteTimePosition>,
+    alarm_id_running: Option<AVTransportLastChangeAlarmIDRunning>,
+    alarm_logged_start_time: Option<AVTransportLastChangeAlarmLoggedStartTime>,
+    alarm_running: Option<AVTransportLastChangeAlarmRunning>,
+    current_crossfade_mode: Option<AVTransportLastChangeCurrentCrossfadeMode>,
+    current_media_duration: Option<AVTransportLastChangeCurrentMediaDuration>,
+    current_play_mode: Option<AVTransportLastChangeCurrentPlayMode>,
+    current_record_quality_mode: Option<AVTransportLastChangeCurrentRecordQualityMode>,
+    current_section: Option<AVTransportLastChangeCurrentSection>,
+    current_track: Option<AVTransportLastChangeCurrentTrack>,
+    current_track_duration: Option<AVTransportLastChangeCurrentTrackDuration>,
+    current_track_meta_data: Option<AVTransportLastChangeCurrentTrackMetaData>,
+    current_track_uri: Option<AVTransportLastChangeCurrentTrackURI>,
+    current_transport_actions: Option<AVTransportLastChangeCurrentTransportActions>,
+    current_valid_play_modes: Option<AVTransportLastChangeCurrentValidPlayModes>,
+    direct_control_account_id: Option<AVTransportLastChangeDirectControlAccountID>,
+    direct_control_client_id: Option<AVTransportLastChangeDirectControlClientID>,
+    direct_control_is_suspended: Option<AVTransportLastChangeDirectControlIsSuspended>,
+    enqueued_transport_uri: Option<AVTransportLastChangeEnqueuedTransportURI>,
+    enqueued_transport_uri_meta_data: Option<
+        AVTransportLastChangeEnqueuedTransportURIMetaData,
+    >,
+    muse_sessions: Option<AVTransportLastChangeMuseSessions>,
+    next_av_transport_uri: Option<AVTransportLastChangeNextAVTransportURI>,
+    next_av_transport_uri_meta_data: Option<
+        AVTransportLastChangeNextAVTransportURIMetaData,
+    >,
+    next_track_meta_data: Option<AVTransportLastChangeNextTrackMetaData>,
+    next_track_uri: Option<AVTransportLastChangeNextTrackURI>,
+    number_of_tracks: Option<AVTransportLastChangeNumberOfTracks>,
+    playback_storage_medium: Option<AVTransportLastChangePlaybackStorageMedium>,
+    possible_playback_storage_media: Option<
+        AVTransportLastChangePossiblePlaybackStorageMedia,
+    >,
+    possible_record_quality_modes: Option<
+        AVTransportLastChangePossibleRecordQualityModes,
+    >,
+    possible_record_storage_media: Option<
+        AVTransportLastChangePossibleRecordStorageMedia,
+    >,
+    queue_update_id: Option<AVTransportLastChangeQueueUpdateID>,
+    record_medium_write_status: Option<AVTransportLastChangeRecordMediumWriteStatus>,
+    record_storage_medium: Option<AVTransportLastChangeRecordStorageMedium>,
+    relative_counter_position: Option<AVTransportLastChangeRelativeCounterPosition>,
+    relative_time_position: Option<AVTransportLastChangeRelativeTimePosition>,
+    restart_pending: Option<AVTransportLastChangeRestartPending>,
+    sleep_timer_generation: Option<AVTransportLastChangeSleepTimerGeneration>,
+    snooze_running: Option<AVTransportLastChangeSnoozeRunning>,
+    transport_error_description: Option<AVTransportLastChangeTransportErrorDescription>,
+    transport_error_http_code: Option<AVTransportLastChangeTransportErrorHttpCode>,
+    transport_error_http_headers: Option<AVTransportLastChangeTransportErrorHttpHeaders>,
+    transport_error_uri: Option<AVTransportLastChangeTransportErrorURI>,
+    transport_play_speed: Option<AVTransportLastChangeTransportPlaySpeed>,
+    transport_state: Option<AVTransportLastChangeTransportState>,
+    transport_status: Option<AVTransportLastChangeTransportStatus>,
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AVTransportLastChangeMap {
+    pub map: std::collections::BTreeMap<u32, AVTransportLastChange>,
+}
+impl DecodeXml for AVTransportLastChangeMap {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        #[derive(FromXml)]
+        #[xml(ns(LAST_CHANGE_NS, r = "urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+        struct Event {
+            instance: Vec<AVTransportLastChangeRootObject>,
+        }
+        let last_change: Event = instant_xml::from_str(xml)?;
+        let mut map = std::collections::BTreeMap::new();
+        for item in last_change.instance {
+            let mut result = AVTransportLastChange::default();
+            result.av_transport_uri = item.av_transport_uri.and_then(|v| v.val);
+            result.av_transport_uri_meta_data = item
+                .av_transport_uri_meta_data
+                .and_then(|v| v.val);
+            result.alarm_include_linked_zones = item
+                .alarm_include_linked_zones
+                .and_then(|v| v.val);
+            result.alarm_state = item.alarm_state.and_then(|v| v.val);
+            result.alarm_volume = item.alarm_volume.and_then(|v| v.val);
+            result.current_av_transport_uri = item
+                .current_av_transport_uri
+                .and_then(|v| v.val);
+            result.enqueue_as_next = item.enqueue_as_next.and_then(|v| v.val);
+            result.group_id = item.group_id.and_then(|v| v.val);
+            result.iso8601_time = item.iso8601_time.and_then(|v| v.val);
+            result.instance_id = item.instance_id.and_then(|v| v.val);
+            result.list_uri = item.list_uri.and_then(|v| v.val);
+            result.list_uri_meta_data = item.list_uri_meta_data.and_then(|v| v.val);
+            result.member_id = item.member_id.and_then(|v| v.val);
+            result.member_list = item.member_list.and_then(|v| v.val);
+            result.num_tracks = item.num_tracks.and_then(|v| v.val);
+            result.num_tracks_change = item.num_tracks_change.and_then(|v| v.val);
+            result.object_id = item.object_id.and_then(|v| v.val);
+            result.player_id = item.player_id.and_then(|v| v.val);
+            result.queue = item.queue.and_then(|v| v.val);
+            result.rejoin_group = item.rejoin_group.and_then(|v| v.val);
+            result.reset_volume_after = item.reset_volume_after.and_then(|v| v.val);
+            result.resume_playback = item.resume_playback.and_then(|v| v.val);
+            result.saved_queue_title = item.saved_queue_title.and_then(|v| v.val);
+            result.seek_mode = item.seek_mode.and_then(|v| v.val);
+            result.seek_target = item.seek_target.and_then(|v| v.val);
+            result.sleep_timer_state = item.sleep_timer_state.and_then(|v| v.val);
+            result.source_state = item.source_state.and_then(|v| v.val);
+            result.stream_restart_state = item.stream_restart_state.and_then(|v| v.val);
+            result.track_list = item.track_list.and_then(|v| v.val);
+            result.track_number = item.track_number.and_then(|v| v.val);
+            result.transport_settings = item.transport_settings.and_then(|v| v.val);
+            result.uri = item.uri.and_then(|v| v.val);
+            result.uri_meta_data = item.uri_meta_data.and_then(|v| v.val);
+            result.vli_state = item.vli_state.and_then(|v| v.val);
+            result.absolute_counter_position = item
+                .absolute_counter_position
+                .and_then(|v| v.val);
+            result.absolute_time_position = item
+                .absolute_time_position
+                .and_then(|v| v.val);
+            result.alarm_id_running = item.alarm_id_running.and_then(|v| v.val);
+            result.alarm_logged_start_time = item
+                .alarm_logged_start_time
+                .and_then(|v| v.val);
+            result.alarm_running = item.alarm_running.and_then(|v| v.val);
+            result.current_crossfade_mode = item
+                .current_crossfade_mode
+                .and_then(|v| v.val);
+            result.current_media_duration = item
+                .current_media_duration
+                .and_then(|v| v.val);
+            result.current_play_mode = item.current_play_mode.and_then(|v| v.val);
+            result.current_record_quality_mode = item
+                .current_record_quality_mode
+                .and_then(|v| v.val);
+            result.current_section = item.current_section.and_then(|v| v.val);
+            result.current_track = item.current_track.and_then(|v| v.val);
+            result.current_track_duration = item
+                .current_track_duration
+                .and_then(|v| v.val);
+            result.current_track_meta_data = item
+                .current_track_meta_data
+                .and_then(|v| v.val);
+            result.current_track_uri = item.current_track_uri.and_then(|v| v.val);
+            result.current_transport_actions = item
+                .current_transport_actions
+                .and_then(|v| v.val);
+            result.current_valid_play_modes = item
+                .current_valid_play_modes
+                .and_then(|v| v.val);
+            result.direct_control_account_id = item
+                .direct_control_account_id
+                .and_then(|v| v.val);
+            result.direct_control_client_id = item
+                .direct_control_client_id
+                .and_then(|v| v.val);
+            result.direct_control_is_suspended = item
+                .direct_control_is_suspended
+                .and_then(|v| v.val);
+            result.enqueued_transport_uri = item
+                .enqueued_transport_uri
+                .and_then(|v| v.val);
+            result.enqueued_transport_uri_meta_data = item
+                .enqueued_transport_uri_meta_data
+                .and_then(|v| v.val);
+            result.muse_sessions = item.muse_sessions.and_then(|v| v.val);
+            result.next_av_transport_uri = item
+                .next_av_transport_uri
+                .and_then(|v| v.val);
+            result.next_av_transport_uri_meta_data = item
+                .next_av_transport_uri_meta_data
+                .and_then(|v| v.val);
+            result.next_track_meta_data = item.next_track_meta_data.and_then(|v| v.val);
+            result.next_track_uri = item.next_track_uri.and_then(|v| v.val);
+            result.number_of_tracks = item.number_of_tracks.and_then(|v| v.val);
+            result.playback_storage_medium = item
+                .playback_storage_medium
+                .and_then(|v| v.val);
+            result.possible_playback_storage_media = item
+                .possible_playback_storage_media
+                .and_then(|v| v.val);
+            result.possible_record_quality_modes = item
+                .possible_record_quality_modes
+                .and_then(|v| v.val);
+            result.possible_record_storage_media = item
+                .possible_record_storage_media
+                .and_then(|v| v.val);
+            result.queue_update_id = item.queue_update_id.and_then(|v| v.val);
+            result.record_medium_write_status = item
+                .record_medium_write_status
+                .and_then(|v| v.val);
+            result.record_storage_medium = item
+                .record_storage_medium
+                .and_then(|v| v.val);
+            result.relative_counter_position = item
+                .relative_counter_position
+                .and_then(|v| v.val);
+            result.relative_time_position = item
+                .relative_time_position
+                .and_then(|v| v.val);
+            result.restart_pending = item.restart_pending.and_then(|v| v.val);
+            result.sleep_timer_generation = item
+                .sleep_timer_generation
+                .and_then(|v| v.val);
+            result.snooze_running = item.snooze_running.and_then(|v| v.val);
+            result.transport_error_description = item
+                .transport_error_description
+                .and_then(|v| v.val);
+            result.transport_error_http_code = item
+                .transport_error_http_code
+                .and_then(|v| v.val);
+            result.transport_error_http_headers = item
+                .transport_error_http_headers
+                .and_then(|v| v.val);
+            result.transport_error_uri = item.transport_error_uri.and_then(|v| v.val);
+            result.transport_play_speed = item.transport_play_speed.and_then(|v| v.val);
+            result.transport_state = item.transport_state.and_then(|v| v.val);
+            result.transport_status = item.transport_status.and_then(|v| v.val);
+            map.insert(item.object_instance_id_, result);
+        }
+        Ok(AVTransportLastChangeMap { map })
+    }
+}
+/// Errors that are documented for the `AVTransport` service.
+/// Use `Error::from_error` to obtain one from the error returned
+/// by a method of this service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// 701: Transition not available
+    TransitionNotAvailable,
+    /// 702: No content
+    NoContent,
+    /// 703: Read error
+    ReadError,
+    /// 704: Format not supported for playback
+    FormatNotSupportedForPlayback,
+    /// 705: Transport is locked
+    TransportIsLocked,
+    /// 706: Write error
+    WriteError,
+    /// 707: Media protected or not writeable
+    MediaProtectedOrNotWriteable,
+    /// 708: Format not supported for recording
+    FormatNotSupportedForRecording,
+    /// 709: Media is full
+    MediaIsFull,
+    /// 710: Seek mode not supported
+    SeekModeNotSupported,
+    /// 711: Illegal seek target
+    IllegalSeekTarget,
+    /// 712: Play mode not supported
+    PlayModeNotSupported,
+    /// 713: Record quality not supported
+    RecordQualityNotSupported,
+    /// 714: Illegal MIME-Type
+    IllegalMIMEType,
+    /// 715: Content busy
+    ContentBusy,
+    /// 716: Resource not found
+    ResourceNotFound,
+    /// 717: Play speed not supported
+    PlaySpeedNotSupported,
+    /// 718: Invalid InstanceID
+    InvalidInstanceID,
+    /// 737: No dns configured
+    NoDnsConfigured,
+    /// 738: Bad domain
+    BadDomain,
+    /// 739: Server error
+    ServerError,
+    /// 800: Command not supported or not a coordinator
+    CommandNotSupportedOrNotACoordinator,
+    /// An error code that is not documented for this service
+    Other(u32),
+}
+impl From<u32> for Error {
+    fn from(code: u32) -> Self {
+        match code {
+            701 => Self::TransitionNotAvailable,
+            702 => Self::NoContent,
+            703 => Self::ReadError,
+            704 => Self::FormatNotSupportedForPlayback,
+            705 => Self::TransportIsLocked,
+            706 => Self::WriteError,
+            707 => Self::MediaProtectedOrNotWriteable,
+            708 => Self::FormatNotSupportedForRecording,
+            709 => Self::MediaIsFull,
+            710 => Self::SeekModeNotSupported,
+            711 => Self::IllegalSeekTarget,
+            712 => Self::PlayModeNotSupported,
+            713 => Self::RecordQualityNotSupported,
+            714 => Self::IllegalMIMEType,
+            715 => Self::ContentBusy,
+            716 => Self::ResourceNotFound,
+            717 => Self::PlaySpeedNotSupported,
+            718 => Self::InvalidInstanceID,
+            737 => Self::NoDnsConfigured,
+            738 => Self::BadDomain,
+            739 => Self::ServerError,
+            800 => Self::CommandNotSupportedOrNotACoordinator,
+            code => Self::Other(code),
+        }
+    }
+}
+impl Error {
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::TransitionNotAvailable => 701,
+            Self::NoContent => 702,
+            Self::ReadError => 703,
+            Self::FormatNotSupportedForPlayback => 704,
+            Self::TransportIsLocked => 705,
+            Self::WriteError => 706,
+            Self::MediaProtectedOrNotWriteable => 707,
+            Self::FormatNotSupportedForRecording => 708,
+            Self::MediaIsFull => 709,
+            Self::SeekModeNotSupported => 710,
+            Self::IllegalSeekTarget => 711,
+            Self::PlayModeNotSupported => 712,
+            Self::RecordQualityNotSupported => 713,
+            Self::IllegalMIMEType => 714,
+            Self::ContentBusy => 715,
+            Self::ResourceNotFound => 716,
+            Self::PlaySpeedNotSupported => 717,
+            Self::InvalidInstanceID => 718,
+            Self::NoDnsConfigured => 737,
+            Self::BadDomain => 738,
+            Self::ServerError => 739,
+            Self::CommandNotSupportedOrNotACoordinator => 800,
+            Self::Other(code) => *code,
+        }
+    }
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            Self::TransitionNotAvailable => Some("Transition not available"),
+            Self::NoContent => Some("No content"),
+            Self::ReadError => Some("Read error"),
+            Self::FormatNotSupportedForPlayback => {
+                Some("Format not supported for playback")
+            }
+            Self::TransportIsLocked => Some("Transport is locked"),
+            Self::WriteError => Some("Write error"),
+            Self::MediaProtectedOrNotWriteable => {
+                Some("Media protected or not writeable")
+            }
+            Self::FormatNotSupportedForRecording => {
+                Some("Format not supported for recording")
+            }
+            Self::MediaIsFull => Some("Media is full"),
+            Self::SeekModeNotSupported => Some("Seek mode not supported"),
+            Self::IllegalSeekTarget => Some("Illegal seek target"),
+            Self::PlayModeNotSupported => Some("Play mode not supported"),
+            Self::RecordQualityNotSupported => Some("Record quality not supported"),
+            Self::IllegalMIMEType => Some("Illegal MIME-Type"),
+            Self::ContentBusy => Some("Content busy"),
+            Self::ResourceNotFound => Some("Resource not found"),
+            Self::PlaySpeedNotSupported => Some("Play speed not supported"),
+            Self::InvalidInstanceID => Some("Invalid InstanceID"),
+            Self::NoDnsConfigured => Some("No dns configured"),
+            Self::BadDomain => Some("Bad domain"),
+            Self::ServerError => Some("Server error"),
+            Self::CommandNotSupportedOrNotACoordinator => {
+                Some("Command not supported or not a coordinator")
+            }
+            Self::Other(_) => None,
+        }
+    }
+    /// Returns the documented error if `err` is a fault
+    /// reported by this service
+    pub fn from_error(err: &crate::Error) -> Option<Self> {
+        match err {
+            crate::Error::Upnp(e) if e.service == SERVICE_TYPE => Some(e.code.into()),
+            _ => None,
+        }
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum SeekMode {
+    #[default]
+    TrackNr,
+    RelTime,
+    TimeDelta,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for SeekMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            SeekMode::TrackNr => "TRACK_NR",
+            SeekMode::RelTime => "REL_TIME",
+            SeekMode::TimeDelta => "TIME_DELTA",
+            SeekMode::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for SeekMode {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<SeekMode> {
+        match s {
+            "TRACK_NR" => Ok(SeekMode::TrackNr),
+            "REL_TIME" => Ok(SeekMode::RelTime),
+            "TIME_DELTA" => Ok(SeekMode::TimeDelta),
+            s => Ok(SeekMode::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for SeekMode {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for SeekMode {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: SeekMode = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<SeekMode>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for SeekMode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SeekMode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum CurrentPlayMode {
+    #[default]
+    Normal,
+    RepeatAll,
+    RepeatOne,
+    ShuffleNorepeat,
+    Shuffle,
+    ShuffleRepeatOne,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for CurrentPlayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            CurrentPlayMode::Normal => "NORMAL",
+            CurrentPlayMode::RepeatAll => "REPEAT_ALL",
+            CurrentPlayMode::RepeatOne => "REPEAT_ONE",
+            CurrentPlayMode::ShuffleNorepeat => "SHUFFLE_NOREPEAT",
+            CurrentPlayMode::Shuffle => "SHUFFLE",
+            CurrentPlayMode::ShuffleRepeatOne => "SHUFFLE_REPEAT_ONE",
+            CurrentPlayMode::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for CurrentPlayMode {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<CurrentPlayMode> {
+        match s {
+            "NORMAL" => Ok(CurrentPlayMode::Normal),
+            "REPEAT_ALL" => Ok(CurrentPlayMode::RepeatAll),
+            "REPEAT_ONE" => Ok(CurrentPlayMode::RepeatOne),
+            "SHUFFLE_NOREPEAT" => Ok(CurrentPlayMode::ShuffleNorepeat),
+            "SHUFFLE" => Ok(CurrentPlayMode::Shuffle),
+            "SHUFFLE_REPEAT_ONE" => Ok(CurrentPlayMode::ShuffleRepeatOne),
+            s => Ok(CurrentPlayMode::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for CurrentPlayMode {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for CurrentPlayMode {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: CurrentPlayMode = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<CurrentPlayMode>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for CurrentPlayMode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CurrentPlayMode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum PlaybackStorageMedium {
+    #[default]
+    None,
+    Network,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for PlaybackStorageMedium {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            PlaybackStorageMedium::None => "NONE",
+            PlaybackStorageMedium::Network => "NETWORK",
+            PlaybackStorageMedium::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for PlaybackStorageMedium {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<PlaybackStorageMedium> {
+        match s {
+            "NONE" => Ok(PlaybackStorageMedium::None),
+            "NETWORK" => Ok(PlaybackStorageMedium::Network),
+            s => Ok(PlaybackStorageMedium::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for PlaybackStorageMedium {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for PlaybackStorageMedium {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: PlaybackStorageMedium = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<PlaybackStorageMedium>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for PlaybackStorageMedium {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PlaybackStorageMedium {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum TransportState {
+    #[default]
+    Stopped,
+    Playing,
+    PausedPlayback,
+    Transitioning,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for TransportState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            TransportState::Stopped => "STOPPED",
+            TransportState::Playing => "PLAYING",
+            TransportState::PausedPlayback => "PAUSED_PLAYBACK",
+            TransportState::Transitioning => "TRANSITIONING",
+            TransportState::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for TransportState {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<TransportState> {
+        match s {
+            "STOPPED" => Ok(TransportState::Stopped),
+            "PLAYING" => Ok(TransportState::Playing),
+            "PAUSED_PLAYBACK" => Ok(TransportState::PausedPlayback),
+            "TRANSITIONING" => Ok(TransportState::Transitioning),
+            s => Ok(TransportState::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for TransportState {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for TransportState {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: TransportState = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<TransportState>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for TransportState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TransportState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Service that controls stuff related to transport (play/pause/next/special URLs)
+pub trait AVTransport {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_multiple_uris_to_queue(
+        &self,
+        request: av_transport::AddMultipleUrisToQueueRequest,
+    ) -> Result<av_transport::AddMultipleUrisToQueueResponse>;
+    /// Adds songs to the SONOS queue
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_uri_to_queue(
+        &self,
+        request: av_transport::AddUriToQueueRequest,
+    ) -> Result<av_transport::AddUriToQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_uri_to_saved_queue(
+        &self,
+        request: av_transport::AddUriToSavedQueueRequest,
+    ) -> Result<av_transport::AddUriToSavedQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn backup_queue(
+        &self,
+        request: av_transport::BackupQueueRequest,
+    ) -> Result<()>;
+    /// Leave the current group and revert to a single player.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn become_coordinator_of_standalone_group(
+        &self,
+        request: av_transport::BecomeCoordinatorOfStandaloneGroupRequest,
+    ) -> Result<av_transport::BecomeCoordinatorOfStandaloneGroupResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn become_group_coordinator(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn become_group_coordinator_and_source(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorAndSourceRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn change_coordinator(
+        &self,
+        request: av_transport::ChangeCoordinatorRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn change_transport_settings(
+        &self,
+        request: av_transport::ChangeTransportSettingsRequest,
+    ) -> Result<()>;
+    /// Stop playing after set sleep timer or cancel
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn configure_sleep_timer(
+        &self,
+        request: av_transport::ConfigureSleepTimerRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn create_saved_queue(
+        &self,
+        request: av_transport::CreateSavedQueueRequest,
+    ) -> Result<av_transport::CreateSavedQueueResponse>;
+    /// Delegates the coordinator role to another player in the same group
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn delegate_group_coordination_to(
+        &self,
+        request: av_transport::DelegateGroupCoordinationToRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn end_direct_control_session(
+        &self,
+        request: av_transport::EndDirectControlSessionRequest,
+    ) -> Result<()>;
+    /// Get crossfade mode
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_crossfade_mode(
+        &self,
+        request: av_transport::GetCrossfadeModeRequest,
+    ) -> Result<av_transport::GetCrossfadeModeResponse>;
+    /// Get current transport actions such as Set, Stop, Pause, Play, X_DLNA_SeekTime, Next, X_DLNA_SeekTrackNr
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_current_transport_actions(
+        &self,
+        request: av_transport::GetCurrentTransportActionsRequest,
+    ) -> Result<av_transport::GetCurrentTransportActionsResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_device_capabilities(
+        &self,
+        request: av_transport::GetDeviceCapabilitiesRequest,
+    ) -> Result<av_transport::GetDeviceCapabilitiesResponse>;
+    /// Get information about the current playing media (queue)
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_media_info(
+        &self,
+        request: av_transport::GetMediaInfoRequest,
+    ) -> Result<av_transport::GetMediaInfoResponse>;
+    /// Get information about current position (position in queue and time in current song)
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_position_info(
+        &self,
+        request: av_transport::GetPositionInfoRequest,
+    ) -> Result<av_transport::GetPositionInfoResponse>;
+    /// Get time left on sleeptimer.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_remaining_sleep_timer_duration(
+        &self,
+        request: av_transport::GetRemainingSleepTimerDurationRequest,
+    ) -> Result<av_transport::GetRemainingSleepTimerDurationResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_running_alarm_properties(
+        &self,
+        request: av_transport::GetRunningAlarmPropertiesRequest,
+    ) -> Result<av_transport::GetRunningAlarmPropertiesResponse>;
+    /// Get current transport status, speed and state such as PLAYING, STOPPED, PLAYING, PAUSED_PLAYBACK, TRANSITIONING, NO_MEDIA_PRESENT
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_transport_info(
+        &self,
+        request: av_transport::GetTransportInfoRequest,
+    ) -> Result<av_transport::GetTransportInfoResponse>;
+    /// Get transport settings
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_transport_settings(
+        &self,
+        request: av_transport::GetTransportSettingsRequest,
+    ) -> Result<av_transport::GetTransportSettingsResponse>;
+    /// Go to next song
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn next(&self, request: av_transport::NextRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn notify_deleted_uri(
+        &self,
+        request: av_transport::NotifyDeletedUriRequest,
+    ) -> Result<()>;
+    /// Pause playback
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn pause(&self, request: av_transport::PauseRequest) -> Result<()>;
+    /// Start playing the set TransportURI
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn play(&self, request: av_transport::PlayRequest) -> Result<()>;
+    /// Go to previous song
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn previous(&self, request: av_transport::PreviousRequest) -> Result<()>;
+    /// Flushes the SONOS queue.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_all_tracks_from_queue(
+        &self,
+        request: av_transport::RemoveAllTracksFromQueueRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_track_from_queue(
+        &self,
+        request: av_transport::RemoveTrackFromQueueRequest,
+    ) -> Result<()>;
+    /// Removes the specified range of songs from the SONOS queue.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_track_range_from_queue(
+        &self,
+        request: av_transport::RemoveTrackRangeFromQueueRequest,
+    ) -> Result<av_transport::RemoveTrackRangeFromQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reorder_tracks_in_queue(
+        &self,
+        request: av_transport::ReorderTracksInQueueRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reorder_tracks_in_saved_queue(
+        &self,
+        request: av_transport::ReorderTracksInSavedQueueRequest,
+    ) -> Result<av_transport::ReorderTracksInSavedQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn run_alarm(&self, request: av_transport::RunAlarmRequest) -> Result<()>;
+    /// Saves the current SONOS queue as a SONOS playlist and outputs objectID
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn save_queue(
+        &self,
+        request: av_transport::SaveQueueRequest,
+    ) -> Result<av_transport::SaveQueueResponse>;
+    /// Seek track in queue, time delta or absolute time in song
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn seek(&self, request: av_transport::SeekRequest) -> Result<()>;
+    /// Set the transport URI to a song, a stream, the queue, another player-rincon and a lot more
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_av_transport_uri(
+        &self,
+        request: av_transport::SetAvTransportUriRequest,
+    ) -> Result<()>;
+    /// Set crossfade mode
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_crossfade_mode(
+        &self,
+        request: av_transport::SetCrossfadeModeRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_next_av_transport_uri(
+        &self,
+        request: av_transport::SetNextAvTransportUriRequest,
+    ) -> Result<()>;
+    /// Set the PlayMode
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_play_mode(
+        &self,
+        request: av_transport::SetPlayModeRequest,
+    ) -> Result<()>;
+    /// Snooze the current alarm for some time.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn snooze_alarm(
+        &self,
+        request: av_transport::SnoozeAlarmRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn start_autoplay(
+        &self,
+        request: av_transport::StartAutoplayRequest,
+    ) -> Result<()>;
+    /// Stop playback
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn stop(&self, request: av_transport::StopRequest) -> Result<()>;
+}
+impl AVTransport for SonosDevice {
+    async fn add_multiple_uris_to_queue(
+        &self,
+        request: av_transport::AddMultipleUrisToQueueRequest,
+    ) -> Result<av_transport::AddMultipleUrisToQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "AddMultipleURIsToQueue", request).await
+    }
+    async fn add_uri_to_queue(
+        &self,
+        request: av_transport::AddUriToQueueRequest,
+    ) -> Result<av_transport::AddUriToQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "AddURIToQueue", request).await
+    }
+    async fn add_uri_to_saved_queue(
+        &self,
+        request: av_transport::AddUriToSavedQueueRequest,
+    ) -> Result<av_transport::AddUriToSavedQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "AddURIToSavedQueue", request).await
+    }
+    async fn backup_queue(
+        &self,
+        request: av_transport::BackupQueueRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "BackupQueue", request).await
+    }
+    async fn become_coordinator_of_standalone_group(
+        &self,
+        request: av_transport::BecomeCoordinatorOfStandaloneGroupRequest,
+    ) -> Result<av_transport::BecomeCoordinatorOfStandaloneGroupResponse> {
+        self.action(
+                &av_transport::SERVICE_TYPE,
+                "BecomeCoordinatorOfStandaloneGroup",
+                request,
+            )
+            .await
+    }
+    async fn become_group_coordinator(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "BecomeGroupCoordinator", request).await
+    }
+    async fn become_group_coordinator_and_source(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorAndSourceRequest,
+    ) -> Result<()> {
+        self.action(
+                &av_transport::SERVICE_TYPE,
+                "BecomeGroupCoordinatorAndSource",
+                request,
+            )
+            .await
+    }
+    async fn change_coordinator(
+        &self,
+        request: av_transport::ChangeCoordinatorRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "ChangeCoordinator", request).await
+    }
+    async fn change_transport_settings(
+        &self,
+        request: av_transport::ChangeTransportSettingsRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "ChangeTransportSettings", request)
+            .await
+    }
+    async fn configure_sleep_timer(
+        &self,
+        request: av_transport::ConfigureSleepTimerRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "ConfigureSleepTimer", request).await
+    }
+    async fn create_saved_queue(
+        &self,
+        request: av_transport::CreateSavedQueueRequest,
+    ) -> Result<av_transport::CreateSavedQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "CreateSavedQueue", request).await
+    }
+    async fn delegate_group_coordination_to(
+        &self,
+        request: av_transport::DelegateGroupCoordinationToRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "DelegateGroupCoordinationTo", request)
+            .await
+    }
+    async fn end_direct_control_session(
+        &self,
+        request: av_transport::EndDirectControlSessionRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "EndDirectControlSession", request)
+            .await
+    }
+    async fn get_crossfade_mode(
+        &self,
+        request: av_transport::GetCrossfadeModeRequest,
+    ) -> Result<av_transport::GetCrossfadeModeResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetCrossfadeMode", request).await
+    }
+    async fn get_current_transport_actions(
+        &self,
+        request: av_transport::GetCurrentTransportActionsRequest,
+    ) -> Result<av_transport::GetCurrentTransportActionsResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetCurrentTransportActions", request)
+            .await
+    }
+    async fn get_device_capabilities(
+        &self,
+        request: av_transport::GetDeviceCapabilitiesRequest,
+    ) -> Result<av_transport::GetDeviceCapabilitiesResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetDeviceCapabilities", request).await
+    }
+    async fn get_media_info(
+        &self,
+        request: av_transport::GetMediaInfoRequest,
+    ) -> Result<av_transport::GetMediaInfoResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetMediaInfo", request).await
+    }
+    async fn get_position_info(
+        &self,
+        request: av_transport::GetPositionInfoRequest,
+    ) -> Result<av_transport::GetPositionInfoResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetPositionInfo", request).await
+    }
+    async fn get_remaining_sleep_timer_duration(
+        &self,
+        request: av_transport::GetRemainingSleepTimerDurationRequest,
+    ) -> Result<av_transport::GetRemainingSleepTimerDurationResponse> {
+        self.action(
+                &av_transport::SERVICE_TYPE,
+                "GetRemainingSleepTimerDuration",
+                request,
+            )
+            .await
+    }
+    async fn get_running_alarm_properties(
+        &self,
+        request: av_transport::GetRunningAlarmPropertiesRequest,
+    ) -> Result<av_transport::GetRunningAlarmPropertiesResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetRunningAlarmProperties", request)
+            .await
+    }
+    async fn get_transport_info(
+        &self,
+        request: av_transport::GetTransportInfoRequest,
+    ) -> Result<av_transport::GetTransportInfoResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetTransportInfo", request).await
+    }
+    async fn get_transport_settings(
+        &self,
+        request: av_transport::GetTransportSettingsRequest,
+    ) -> Result<av_transport::GetTransportSettingsResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "GetTransportSettings", request).await
+    }
+    async fn next(&self, request: av_transport::NextRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Next", request).await
+    }
+    async fn notify_deleted_uri(
+        &self,
+        request: av_transport::NotifyDeletedUriRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "NotifyDeletedURI", request).await
+    }
+    async fn pause(&self, request: av_transport::PauseRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Pause", request).await
+    }
+    async fn play(&self, request: av_transport::PlayRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Play", request).await
+    }
+    async fn previous(&self, request: av_transport::PreviousRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Previous", request).await
+    }
+    async fn remove_all_tracks_from_queue(
+        &self,
+        request: av_transport::RemoveAllTracksFromQueueRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "RemoveAllTracksFromQueue", request)
+            .await
+    }
+    async fn remove_track_from_queue(
+        &self,
+        request: av_transport::RemoveTrackFromQueueRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "RemoveTrackFromQueue", request).await
+    }
+    async fn remove_track_range_from_queue(
+        &self,
+        request: av_transport::RemoveTrackRangeFromQueueRequest,
+    ) -> Result<av_transport::RemoveTrackRangeFromQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "RemoveTrackRangeFromQueue", request)
+            .await
+    }
+    async fn reorder_tracks_in_queue(
+        &self,
+        request: av_transport::ReorderTracksInQueueRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "ReorderTracksInQueue", request).await
+    }
+    async fn reorder_tracks_in_saved_queue(
+        &self,
+        request: av_transport::ReorderTracksInSavedQueueRequest,
+    ) -> Result<av_transport::ReorderTracksInSavedQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "ReorderTracksInSavedQueue", request)
+            .await
+    }
+    async fn run_alarm(&self, request: av_transport::RunAlarmRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "RunAlarm", request).await
+    }
+    async fn save_queue(
+        &self,
+        request: av_transport::SaveQueueRequest,
+    ) -> Result<av_transport::SaveQueueResponse> {
+        self.action(&av_transport::SERVICE_TYPE, "SaveQueue", request).await
+    }
+    async fn seek(&self, request: av_transport::SeekRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Seek", request).await
+    }
+    async fn set_av_transport_uri(
+        &self,
+        request: av_transport::SetAvTransportUriRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "SetAVTransportURI", request).await
+    }
+    async fn set_crossfade_mode(
+        &self,
+        request: av_transport::SetCrossfadeModeRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "SetCrossfadeMode", request).await
+    }
+    async fn set_next_av_transport_uri(
+        &self,
+        request: av_transport::SetNextAvTransportUriRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "SetNextAVTransportURI", request).await
+    }
+    async fn set_play_mode(
+        &self,
+        request: av_transport::SetPlayModeRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "SetPlayMode", request).await
+    }
+    async fn snooze_alarm(
+        &self,
+        request: av_transport::SnoozeAlarmRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "SnoozeAlarm", request).await
+    }
+    async fn start_autoplay(
+        &self,
+        request: av_transport::StartAutoplayRequest,
+    ) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "StartAutoplay", request).await
+    }
+    async fn stop(&self, request: av_transport::StopRequest) -> Result<()> {
+        self.action(&av_transport::SERVICE_TYPE, "Stop", request).await
+    }
+}
+/// A variant of `AVTransport` that can be used as a trait object,
+/// eg: `Box<dyn AVTransportDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait AVTransportDyn: Send + Sync {
+    /// See `AVTransport::add_multiple_uris_to_queue`
+    fn add_multiple_uris_to_queue(
+        &self,
+        request: av_transport::AddMultipleUrisToQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddMultipleUrisToQueueResponse>;
+    /// See `AVTransport::add_uri_to_queue`
+    fn add_uri_to_queue(
+        &self,
+        request: av_transport::AddUriToQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddUriToQueueResponse>;
+    /// See `AVTransport::add_uri_to_saved_queue`
+    fn add_uri_to_saved_queue(
+        &self,
+        request: av_transport::AddUriToSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddUriToSavedQueueResponse>;
+    /// See `AVTransport::backup_queue`
+    fn backup_queue(
+        &self,
+        request: av_transport::BackupQueueRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::become_coordinator_of_standalone_group`
+    fn become_coordinator_of_standalone_group(
+        &self,
+        request: av_transport::BecomeCoordinatorOfStandaloneGroupRequest,
+    ) -> ActionFuture<'_, av_transport::BecomeCoordinatorOfStandaloneGroupResponse>;
+    /// See `AVTransport::become_group_coordinator`
+    fn become_group_coordinator(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::become_group_coordinator_and_source`
+    fn become_group_coordinator_and_source(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorAndSourceRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::change_coordinator`
+    fn change_coordinator(
+        &self,
+        request: av_transport::ChangeCoordinatorRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::change_transport_settings`
+    fn change_transport_settings(
+        &self,
+        request: av_transport::ChangeTransportSettingsRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::configure_sleep_timer`
+    fn configure_sleep_timer(
+        &self,
+        request: av_transport::ConfigureSleepTimerRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::create_saved_queue`
+    fn create_saved_queue(
+        &self,
+        request: av_transport::CreateSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::CreateSavedQueueResponse>;
+    /// See `AVTransport::delegate_group_coordination_to`
+    fn delegate_group_coordination_to(
+        &self,
+        request: av_transport::DelegateGroupCoordinationToRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::end_direct_control_session`
+    fn end_direct_control_session(
+        &self,
+        request: av_transport::EndDirectControlSessionRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::get_crossfade_mode`
+    fn get_crossfade_mode(
+        &self,
+        request: av_transport::GetCrossfadeModeRequest,
+    ) -> ActionFuture<'_, av_transport::GetCrossfadeModeResponse>;
+    /// See `AVTransport::get_current_transport_actions`
+    fn get_current_transport_actions(
+        &self,
+        request: av_transport::GetCurrentTransportActionsRequest,
+    ) -> ActionFuture<'_, av_transport::GetCurrentTransportActionsResponse>;
+    /// See `AVTransport::get_device_capabilities`
+    fn get_device_capabilities(
+        &self,
+        request: av_transport::GetDeviceCapabilitiesRequest,
+    ) -> ActionFuture<'_, av_transport::GetDeviceCapabilitiesResponse>;
+    /// See `AVTransport::get_media_info`
+    fn get_media_info(
+        &self,
+        request: av_transport::GetMediaInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetMediaInfoResponse>;
+    /// See `AVTransport::get_position_info`
+    fn get_position_info(
+        &self,
+        request: av_transport::GetPositionInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetPositionInfoResponse>;
+    /// See `AVTransport::get_remaining_sleep_timer_duration`
+    fn get_remaining_sleep_timer_duration(
+        &self,
+        request: av_transport::GetRemainingSleepTimerDurationRequest,
+    ) -> ActionFuture<'_, av_transport::GetRemainingSleepTimerDurationResponse>;
+    /// See `AVTransport::get_running_alarm_properties`
+    fn get_running_alarm_properties(
+        &self,
+        request: av_transport::GetRunningAlarmPropertiesRequest,
+    ) -> ActionFuture<'_, av_transport::GetRunningAlarmPropertiesResponse>;
+    /// See `AVTransport::get_transport_info`
+    fn get_transport_info(
+        &self,
+        request: av_transport::GetTransportInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetTransportInfoResponse>;
+    /// See `AVTransport::get_transport_settings`
+    fn get_transport_settings(
+        &self,
+        request: av_transport::GetTransportSettingsRequest,
+    ) -> ActionFuture<'_, av_transport::GetTransportSettingsResponse>;
+    /// See `AVTransport::next`
+    fn next(&self, request: av_transport::NextRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::notify_deleted_uri`
+    fn notify_deleted_uri(
+        &self,
+        request: av_transport::NotifyDeletedUriRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::pause`
+    fn pause(&self, request: av_transport::PauseRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::play`
+    fn play(&self, request: av_transport::PlayRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::previous`
+    fn previous(&self, request: av_transport::PreviousRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::remove_all_tracks_from_queue`
+    fn remove_all_tracks_from_queue(
+        &self,
+        request: av_transport::RemoveAllTracksFromQueueRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::remove_track_from_queue`
+    fn remove_track_from_queue(
+        &self,
+        request: av_transport::RemoveTrackFromQueueRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::remove_track_range_from_queue`
+    fn remove_track_range_from_queue(
+        &self,
+        request: av_transport::RemoveTrackRangeFromQueueRequest,
+    ) -> ActionFuture<'_, av_transport::RemoveTrackRangeFromQueueResponse>;
+    /// See `AVTransport::reorder_tracks_in_queue`
+    fn reorder_tracks_in_queue(
+        &self,
+        request: av_transport::ReorderTracksInQueueRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::reorder_tracks_in_saved_queue`
+    fn reorder_tracks_in_saved_queue(
+        &self,
+        request: av_transport::ReorderTracksInSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::ReorderTracksInSavedQueueResponse>;
+    /// See `AVTransport::run_alarm`
+    fn run_alarm(&self, request: av_transport::RunAlarmRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::save_queue`
+    fn save_queue(
+        &self,
+        request: av_transport::SaveQueueRequest,
+    ) -> ActionFuture<'_, av_transport::SaveQueueResponse>;
+    /// See `AVTransport::seek`
+    fn seek(&self, request: av_transport::SeekRequest) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::set_av_transport_uri`
+    fn set_av_transport_uri(
+        &self,
+        request: av_transport::SetAvTransportUriRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::set_crossfade_mode`
+    fn set_crossfade_mode(
+        &self,
+        request: av_transport::SetCrossfadeModeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::set_next_av_transport_uri`
+    fn set_next_av_transport_uri(
+        &self,
+        request: av_transport::SetNextAvTransportUriRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::set_play_mode`
+    fn set_play_mode(
+        &self,
+        request: av_transport::SetPlayModeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::snooze_alarm`
+    fn snooze_alarm(
+        &self,
+        request: av_transport::SnoozeAlarmRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::start_autoplay`
+    fn start_autoplay(
+        &self,
+        request: av_transport::StartAutoplayRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `AVTransport::stop`
+    fn stop(&self, request: av_transport::StopRequest) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl AVTransportDyn for SonosDevice {
+    fn add_multiple_uris_to_queue(
+        &self,
+        request: av_transport::AddMultipleUrisToQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddMultipleUrisToQueueResponse> {
+        Box::pin(<Self as AVTransport>::add_multiple_uris_to_queue(self, request))
+    }
+    fn add_uri_to_queue(
+        &self,
+        request: av_transport::AddUriToQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddUriToQueueResponse> {
+        Box::pin(<Self as AVTransport>::add_uri_to_queue(self, request))
+    }
+    fn add_uri_to_saved_queue(
+        &self,
+        request: av_transport::AddUriToSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::AddUriToSavedQueueResponse> {
+        Box::pin(<Self as AVTransport>::add_uri_to_saved_queue(self, request))
+    }
+    fn backup_queue(
+        &self,
+        request: av_transport::BackupQueueRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::backup_queue(self, request))
+    }
+    fn become_coordinator_of_standalone_group(
+        &self,
+        request: av_transport::BecomeCoordinatorOfStandaloneGroupRequest,
+    ) -> ActionFuture<'_, av_transport::BecomeCoordinatorOfStandaloneGroupResponse> {
+        Box::pin(
+            <Self as AVTransport>::become_coordinator_of_standalone_group(self, request),
+        )
+    }
+    fn become_group_coordinator(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::become_group_coordinator(self, request))
+    }
+    fn become_group_coordinator_and_source(
+        &self,
+        request: av_transport::BecomeGroupCoordinatorAndSourceRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(
+            <Self as AVTransport>::become_group_coordinator_and_source(self, request),
+        )
+    }
+    fn change_coordinator(
+        &self,
+        request: av_transport::ChangeCoordinatorRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::change_coordinator(self, request))
+    }
+    fn change_transport_settings(
+        &self,
+        request: av_transport::ChangeTransportSettingsRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::change_transport_settings(self, request))
+    }
+    fn configure_sleep_timer(
+        &self,
+        request: av_transport::ConfigureSleepTimerRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::configure_sleep_timer(self, request))
+    }
+    fn create_saved_queue(
+        &self,
+        request: av_transport::CreateSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::CreateSavedQueueResponse> {
+        Box::pin(<Self as AVTransport>::create_saved_queue(self, request))
+    }
+    fn delegate_group_coordination_to(
+        &self,
+        request: av_transport::DelegateGroupCoordinationToRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::delegate_group_coordination_to(self, request))
+    }
+    fn end_direct_control_session(
+        &self,
+        request: av_transport::EndDirectControlSessionRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::end_direct_control_session(self, request))
+    }
+    fn get_crossfade_mode(
+        &self,
+        request: av_transport::GetCrossfadeModeRequest,
+    ) -> ActionFuture<'_, av_transport::GetCrossfadeModeResponse> {
+        Box::pin(<Self as AVTransport>::get_crossfade_mode(self, request))
+    }
+    fn get_current_transport_actions(
+        &self,
+        request: av_transport::GetCurrentTransportActionsRequest,
+    ) -> ActionFuture<'_, av_transport::GetCurrentTransportActionsResponse> {
+        Box::pin(<Self as AVTransport>::get_current_transport_actions(self, request))
+    }
+    fn get_device_capabilities(
+        &self,
+        request: av_transport::GetDeviceCapabilitiesRequest,
+    ) -> ActionFuture<'_, av_transport::GetDeviceCapabilitiesResponse> {
+        Box::pin(<Self as AVTransport>::get_device_capabilities(self, request))
+    }
+    fn get_media_info(
+        &self,
+        request: av_transport::GetMediaInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetMediaInfoResponse> {
+        Box::pin(<Self as AVTransport>::get_media_info(self, request))
+    }
+    fn get_position_info(
+        &self,
+        request: av_transport::GetPositionInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetPositionInfoResponse> {
+        Box::pin(<Self as AVTransport>::get_position_info(self, request))
+    }
+    fn get_remaining_sleep_timer_duration(
+        &self,
+        request: av_transport::GetRemainingSleepTimerDurationRequest,
+    ) -> ActionFuture<'_, av_transport::GetRemainingSleepTimerDurationResponse> {
+        Box::pin(
+            <Self as AVTransport>::get_remaining_sleep_timer_duration(self, request),
+        )
+    }
+    fn get_running_alarm_properties(
+        &self,
+        request: av_transport::GetRunningAlarmPropertiesRequest,
+    ) -> ActionFuture<'_, av_transport::GetRunningAlarmPropertiesResponse> {
+        Box::pin(<Self as AVTransport>::get_running_alarm_properties(self, request))
+    }
+    fn get_transport_info(
+        &self,
+        request: av_transport::GetTransportInfoRequest,
+    ) -> ActionFuture<'_, av_transport::GetTransportInfoResponse> {
+        Box::pin(<Self as AVTransport>::get_transport_info(self, request))
+    }
+    fn get_transport_settings(
+        &self,
+        request: av_transport::GetTransportSettingsRequest,
+    ) -> ActionFuture<'_, av_transport::GetTransportSettingsResponse> {
+        Box::pin(<Self as AVTransport>::get_transport_settings(self, request))
+    }
+    fn next(&self, request: av_transport::NextRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::next(self, request))
+    }
+    fn notify_deleted_uri(
+        &self,
+        request: av_transport::NotifyDeletedUriRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::notify_deleted_uri(self, request))
+    }
+    fn pause(&self, request: av_transport::PauseRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::pause(self, request))
+    }
+    fn play(&self, request: av_transport::PlayRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::play(self, request))
+    }
+    fn previous(&self, request: av_transport::PreviousRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::previous(self, request))
+    }
+    fn remove_all_tracks_from_queue(
+        &self,
+        request: av_transport::RemoveAllTracksFromQueueRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::remove_all_tracks_from_queue(self, request))
+    }
+    fn remove_track_from_queue(
+        &self,
+        request: av_transport::RemoveTrackFromQueueRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::remove_track_from_queue(self, request))
+    }
+    fn remove_track_range_from_queue(
+        &self,
+        request: av_transport::RemoveTrackRangeFromQueueRequest,
+    ) -> ActionFuture<'_, av_transport::RemoveTrackRangeFromQueueResponse> {
+        Box::pin(<Self as AVTransport>::remove_track_range_from_queue(self, request))
+    }
+    fn reorder_tracks_in_queue(
+        &self,
+        request: av_transport::ReorderTracksInQueueRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::reorder_tracks_in_queue(self, request))
+    }
+    fn reorder_tracks_in_saved_queue(
+        &self,
+        request: av_transport::ReorderTracksInSavedQueueRequest,
+    ) -> ActionFuture<'_, av_transport::ReorderTracksInSavedQueueResponse> {
+        Box::pin(<Self as AVTransport>::reorder_tracks_in_saved_queue(self, request))
+    }
+    fn run_alarm(&self, request: av_transport::RunAlarmRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::run_alarm(self, request))
+    }
+    fn save_queue(
+        &self,
+        request: av_transport::SaveQueueRequest,
+    ) -> ActionFuture<'_, av_transport::SaveQueueResponse> {
+        Box::pin(<Self as AVTransport>::save_queue(self, request))
+    }
+    fn seek(&self, request: av_transport::SeekRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::seek(self, request))
+    }
+    fn set_av_transport_uri(
+        &self,
+        request: av_transport::SetAvTransportUriRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::set_av_transport_uri(self, request))
+    }
+    fn set_crossfade_mode(
+        &self,
+        request: av_transport::SetCrossfadeModeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::set_crossfade_mode(self, request))
+    }
+    fn set_next_av_transport_uri(
+        &self,
+        request: av_transport::SetNextAvTransportUriRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::set_next_av_transport_uri(self, request))
+    }
+    fn set_play_mode(
+        &self,
+        request: av_transport::SetPlayModeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::set_play_mode(self, request))
+    }
+    fn snooze_alarm(
+        &self,
+        request: av_transport::SnoozeAlarmRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::snooze_alarm(self, request))
+    }
+    fn start_autoplay(
+        &self,
+        request: av_transport::StartAutoplayRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::start_autoplay(self, request))
+    }
+    fn stop(&self, request: av_transport::StopRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as AVTransport>::stop(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_add_multiple_uris_to_queue_request() {
+        let request = AddMultipleUrisToQueueRequest {
+            instance_id: crate::InstanceId(1),
+            update_id: 1,
+            number_of_uris: 1,
+            enqueued_uris: "EnqueuedURIs".to_string(),
+            enqueued_uris_meta_data: "EnqueuedURIsMetaData".to_string(),
+            container_uri: "ContainerURI".to_string(),
+            container_meta_data: "ContainerMetaData".to_string(),
+            desired_first_track_number_enqueued: 1,
+            enqueue_as_next: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddMultipleUrisToQueueRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_multiple_uris_to_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddMultipleURIsToQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><FirstTrackNumberEnqueued>1</FirstTrackNumberEnqueued><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:AddMultipleURIsToQueueResponse></s:Body></s:Envelope>"#;
+        let expected = AddMultipleUrisToQueueResponse {
+            first_track_number_enqueued: Some(1),
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <AddMultipleUrisToQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_add_uri_to_queue_request() {
+        let request = AddUriToQueueRequest {
+            instance_id: crate::InstanceId(1),
+            enqueued_uri: "EnqueuedURI".to_string(),
+            enqueued_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            desired_first_track_number_enqueued: 1,
+            enqueue_as_next: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddUriToQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_uri_to_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddURIToQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><FirstTrackNumberEnqueued>1</FirstTrackNumberEnqueued><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength></u:AddURIToQueueResponse></s:Body></s:Envelope>"#;
+        let expected = AddUriToQueueResponse {
+            first_track_number_enqueued: Some(1),
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+        };
+        let decoded = <AddUriToQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_add_uri_to_saved_queue_request() {
+        let request = AddUriToSavedQueueRequest {
+            instance_id: crate::InstanceId(1),
+            object_id: "ObjectID".to_string(),
+            update_id: 1,
+            enqueued_uri: "EnqueuedURI".to_string(),
+            enqueued_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            add_at_index: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddUriToSavedQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_uri_to_saved_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddURIToSavedQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:AddURIToSavedQueueResponse></s:Body></s:Envelope>"#;
+        let expected = AddUriToSavedQueueResponse {
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <AddUriToSavedQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_backup_queue_request() {
+        let request = BackupQueueRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BackupQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_become_coordinator_of_standalone_group_request() {
+        let request = BecomeCoordinatorOfStandaloneGroupRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BecomeCoordinatorOfStandaloneGroupRequest = instant_xml::from_str(
+                &xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_become_coordinator_of_standalone_group_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:BecomeCoordinatorOfStandaloneGroupResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><DelegatedGroupCoordinatorID>DelegatedGroupCoordinatorID</DelegatedGroupCoordinatorID><NewGroupID>NewGroupID</NewGroupID></u:BecomeCoordinatorOfStandaloneGroupResponse></s:Body></s:Envelope>"#;
+        let expected = BecomeCoordinatorOfStandaloneGroupResponse {
+            delegated_group_coordinator_id: Some(
+                "DelegatedGroupCoordinatorID".to_string(),
+            ),
+            new_group_id: Some("NewGroupID".to_string()),
+        };
+        let decoded = <BecomeCoordinatorOfStandaloneGroupResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_become_group_coordinator_request() {
+        let request = BecomeGroupCoordinatorRequest {
+            instance_id: crate::InstanceId(1),
+            current_coordinator: "CurrentCoordinator".to_string(),
+            current_group_id: "CurrentGroupID".to_string(),
+            other_members: "OtherMembers".to_string(),
+            transport_settings: "TransportSettings".to_string(),
+            current_uri: "CurrentURI".to_string(),
+            current_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            sleep_timer_state: "SleepTimerState".to_string(),
+            alarm_state: "AlarmState".to_string(),
+            stream_restart_state: "StreamRestartState".to_string(),
+            current_queue_track_list: "CurrentQueueTrackList".to_string(),
+            current_vli_state: "CurrentVLIState".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BecomeGroupCoordinatorRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_become_group_coordinator_and_source_request() {
+        let request = BecomeGroupCoordinatorAndSourceRequest {
+            instance_id: crate::InstanceId(1),
+            current_coordinator: "CurrentCoordinator".to_string(),
+            current_group_id: "CurrentGroupID".to_string(),
+            other_members: "OtherMembers".to_string(),
+            current_uri: "CurrentURI".to_string(),
+            current_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            sleep_timer_state: "SleepTimerState".to_string(),
+            alarm_state: "AlarmState".to_string(),
+            stream_restart_state: "StreamRestartState".to_string(),
+            current_avt_track_list: "CurrentAVTTrackList".to_string(),
+            current_queue_track_list: "CurrentQueueTrackList".to_string(),
+            current_source_state: "CurrentSourceState".to_string(),
+            resume_playback: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BecomeGroupCoordinatorAndSourceRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_change_coordinator_request() {
+        let request = ChangeCoordinatorRequest {
+            instance_id: crate::InstanceId(1),
+            current_coordinator: "CurrentCoordinator".to_string(),
+            new_coordinator: "NewCoordinator".to_string(),
+            new_transport_settings: "NewTransportSettings".to_string(),
+            current_av_transport_uri: "CurrentAVTransportURI".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ChangeCoordinatorRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_change_transport_settings_request() {
+        let request = ChangeTransportSettingsRequest {
+            instance_id: crate::InstanceId(1),
+            new_transport_settings: "NewTransportSettings".to_string(),
+            current_av_transport_uri: "CurrentAVTransportURI".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ChangeTransportSettingsRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_configure_sleep_timer_request() {
+        let request = ConfigureSleepTimerRequest {
+            instance_id: crate::InstanceId(1),
+            new_sleep_timer_duration: "NewSleepTimerDuration".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ConfigureSleepTimerRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_saved_queue_request() {
+        let request = CreateSavedQueueRequest {
+            instance_id: crate::InstanceId(1),
+            title: "Title".to_string(),
+            enqueued_uri: "EnqueuedURI".to_string(),
+            enqueued_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CreateSavedQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_saved_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:CreateSavedQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength><AssignedObjectID>AssignedObjectID</AssignedObjectID><NewUpdateID>1</NewUpdateID></u:CreateSavedQueueResponse></s:Body></s:Envelope>"#;
+        let expected = CreateSavedQueueResponse {
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+            assigned_object_id: Some("AssignedObjectID".to_string()),
+            new_update_id: Some(1),
+        };
+        let decoded = <CreateSavedQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_delegate_group_coordination_to_request() {
+        let request = DelegateGroupCoordinationToRequest {
+            instance_id: crate::InstanceId(1),
+            new_coordinator: "NewCoordinator".to_string(),
+            rejoin_group: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: DelegateGroupCoordinationToRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_end_direct_control_session_request() {
+        let request = EndDirectControlSessionRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: EndDirectControlSessionRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_crossfade_mode_request() {
+        let request = GetCrossfadeModeRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetCrossfadeModeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_crossfade_mode_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetCrossfadeModeResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><CrossfadeMode>1</CrossfadeMode></u:GetCrossfadeModeResponse></s:Body></s:Envelope>"#;
+        let expected = GetCrossfadeModeResponse {
+            crossfade_mode: Some(true),
+        };
+        let decoded = <GetCrossfadeModeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_current_transport_actions_request() {
+        let request = GetCurrentTransportActionsRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetCurrentTransportActionsRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_current_transport_actions_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetCurrentTransportActionsResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Actions>Actions</Actions></u:GetCurrentTransportActionsResponse></s:Body></s:Envelope>"#;
+        let expected = GetCurrentTransportActionsResponse {
+            actions: Some("Actions".to_string()),
+        };
+        let decoded = <GetCurrentTransportActionsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_device_capabilities_request() {
+        let request = GetDeviceCapabilitiesRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetDeviceCapabilitiesRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_device_capabilities_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetDeviceCapabilitiesResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><PlayMedia>PlayMedia</PlayMedia><RecMedia>RecMedia</RecMedia><RecQualityModes>RecQualityModes</RecQualityModes></u:GetDeviceCapabilitiesResponse></s:Body></s:Envelope>"#;
+        let expected = GetDeviceCapabilitiesResponse {
+            play_media: Some("PlayMedia".to_string()),
+            rec_media: Some("RecMedia".to_string()),
+            rec_quality_modes: Some("RecQualityModes".to_string()),
+        };
+        let decoded = <GetDeviceCapabilitiesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_media_info_request() {
+        let request = GetMediaInfoRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetMediaInfoRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_media_info_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetMediaInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><NrTracks>1</NrTracks><MediaDuration>0:01:12</MediaDuration><CurrentURI>CurrentURI</CurrentURI><NextURI>NextURI</NextURI><NextURIMetaData>NextURIMetaData</NextURIMetaData><PlayMedium>NETWORK</PlayMedium><RecordMedium>RecordMedium</RecordMedium><WriteStatus>WriteStatus</WriteStatus></u:GetMediaInfoResponse></s:Body></s:Envelope>"#;
+        let expected = GetMediaInfoResponse {
+            nr_tracks: Some(1),
+            media_duration: Some(crate::HmsDuration(std::time::Duration::from_secs(72))),
+            current_uri: Some("CurrentURI".to_string()),
+            current_uri_meta_data: None,
+            next_uri: Some("NextURI".to_string()),
+            next_uri_meta_data: Some("NextURIMetaData".to_string()),
+            play_medium: Some(PlaybackStorageMedium::Network),
+            record_medium: Some("RecordMedium".to_string()),
+            write_status: Some("WriteStatus".to_string()),
+        };
+        let decoded = <GetMediaInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/AVTransport/GetMediaInfo.xml`
+    #[test]
+    fn test_get_media_info_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetMediaInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><NrTracks>1</NrTracks><MediaDuration>NOT_IMPLEMENTED</MediaDuration><CurrentURI>x-sonosapi-stream:s24940?sid=254&amp;flags=8224&amp;sn=0</CurrentURI><CurrentURIMetaData>&lt;DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"&gt;&lt;item id="-1" parentID="-1" restricted="true"&gt;&lt;dc:title&gt;BBC Radio 6 Music&lt;/dc:title&gt;&lt;upnp:class&gt;object.item.audioItem.audioBroadcast&lt;/upnp:class&gt;&lt;desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/"&gt;SA_RINCON65031_&lt;/desc&gt;&lt;/item&gt;&lt;/DIDL-Lite&gt;</CurrentURIMetaData><NextURI></NextURI><NextURIMetaData></NextURIMetaData><PlayMedium>NETWORK</PlayMedium><RecordMedium>NOT_IMPLEMENTED</RecordMedium><WriteStatus>NOT_IMPLEMENTED</WriteStatus></u:GetMediaInfoResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetMediaInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded.nr_tracks, Some(1), "NrTracks");
+        assert_eq!(
+            decoded.current_uri, Some("x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0"
+            .to_string()), "CurrentURI"
+        );
+        assert_eq!(
+            decoded.current_uri_meta_data.and_then(| m | m.into_inner()).map(| m | m
+            .title), Some("BBC Radio 6 Music".to_string()), "CurrentURIMetaData"
+        );
+        assert_eq!(
+            decoded.play_medium, Some(PlaybackStorageMedium::Network), "PlayMedium"
+        );
+    }
+    #[test]
+    fn test_get_position_info_request() {
+        let request = GetPositionInfoRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetPositionInfoRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_position_info_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetPositionInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Track>1</Track><TrackDuration>0:01:12</TrackDuration><TrackURI>TrackURI</TrackURI><RelTime>0:01:12</RelTime><AbsTime>0:01:12</AbsTime><RelCount>1</RelCount><AbsCount>1</AbsCount></u:GetPositionInfoResponse></s:Body></s:Envelope>"#;
+        let expected = GetPositionInfoResponse {
+            track: Some(1),
+            track_duration: Some(crate::HmsDuration(std::time::Duration::from_secs(72))),
+            track_meta_data: None,
+            track_uri: Some("TrackURI".to_string()),
+            rel_time: Some(crate::HmsDuration(std::time::Duration::from_secs(72))),
+            abs_time: Some(crate::HmsDuration(std::time::Duration::from_secs(72))),
+            rel_count: Some(1),
+            abs_count: Some(1),
+        };
+        let decoded = <GetPositionInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/AVTransport/GetPositionInfo.xml`
+    #[test]
+    fn test_get_position_info_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetPositionInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Track>3</Track><TrackDuration>0:03:54</TrackDuration><TrackMetaData>&lt;DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"&gt;&lt;item id="-1" parentID="-1" restricted="true"&gt;&lt;res protocolInfo="sonos.com-spotify:*:audio/x-spotify:*" duration="0:03:54"&gt;x-sonos-spotify:spotify%3atrack%3a6rqhFgbbKwnb9MLmUQDhG6?sid=9&amp;amp;flags=8224&amp;amp;sn=1&lt;/res&gt;&lt;r:streamContent&gt;&lt;/r:streamContent&gt;&lt;upnp:albumArtURI&gt;https://i.scdn.co/image/ab67616d0000b273e8b066f70c206551210d902b&lt;/upnp:albumArtURI&gt;&lt;dc:title&gt;Speak to Me&lt;/dc:title&gt;&lt;upnp:class&gt;object.item.audioItem.musicTrack&lt;/upnp:class&gt;&lt;dc:creator&gt;Pink Floyd&lt;/dc:creator&gt;&lt;upnp:album&gt;The Dark Side of the Moon&lt;/upnp:album&gt;&lt;/item&gt;&lt;/DIDL-Lite&gt;</TrackMetaData><TrackURI>x-sonos-spotify:spotify%3atrack%3a6rqhFgbbKwnb9MLmUQDhG6?sid=9&amp;flags=8224&amp;sn=1</TrackURI><RelTime>0:01:12</RelTime><AbsTime>NOT_IMPLEMENTED</AbsTime><RelCount>2147483647</RelCount><AbsCount>2147483647</AbsCount></u:GetPositionInfoResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetPositionInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded.track, Some(3), "Track");
+        assert_eq!(
+            decoded.track_duration, Some(crate
+            ::HmsDuration(std::time::Duration::from_secs(234))), "TrackDuration"
+        );
+        assert_eq!(
+            decoded.track_meta_data.and_then(| m | m.into_inner()).map(| m | m.title),
+            Some("Speak to Me".to_string()), "TrackMetaData"
+        );
+        assert_eq!(
+            decoded.track_uri,
+            Some("x-sonos-spotify:spotify%3atrack%3a6rqhFgbbKwnb9MLmUQDhG6?sid=9&flags=8224&sn=1"
+            .to_string()), "TrackURI"
+        );
+        assert_eq!(
+            decoded.rel_time, Some(crate
+            ::HmsDuration(std::time::Duration::from_secs(72))), "RelTime"
+        );
+        assert_eq!(decoded.rel_count, Some(2147483647), "RelCount");
+        assert_eq!(decoded.abs_count, Some(2147483647), "AbsCount");
+    }
+    #[test]
+    fn test_get_remaining_sleep_timer_duration_request() {
+        let request = GetRemainingSleepTimerDurationRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetRemainingSleepTimerDurationRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_remaining_sleep_timer_duration_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetRemainingSleepTimerDurationResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><RemainingSleepTimerDuration>RemainingSleepTimerDuration</RemainingSleepTimerDuration><CurrentSleepTimerGeneration>1</CurrentSleepTimerGeneration></u:GetRemainingSleepTimerDurationResponse></s:Body></s:Envelope>"#;
+        let expected = GetRemainingSleepTimerDurationResponse {
+            remaining_sleep_timer_duration: Some(
+                "RemainingSleepTimerDuration".to_string(),
+            ),
+            current_sleep_timer_generation: Some(1),
+        };
+        let decoded = <GetRemainingSleepTimerDurationResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_running_alarm_properties_request() {
+        let request = GetRunningAlarmPropertiesRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetRunningAlarmPropertiesRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_running_alarm_properties_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetRunningAlarmPropertiesResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><AlarmID>1</AlarmID><GroupID>GroupID</GroupID><LoggedStartTime>LoggedStartTime</LoggedStartTime></u:GetRunningAlarmPropertiesResponse></s:Body></s:Envelope>"#;
+        let expected = GetRunningAlarmPropertiesResponse {
+            alarm_id: Some(1),
+            group_id: Some("GroupID".to_string()),
+            logged_start_time: Some("LoggedStartTime".to_string()),
+        };
+        let decoded = <GetRunningAlarmPropertiesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_transport_info_request() {
+        let request = GetTransportInfoRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetTransportInfoRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_transport_info_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTransportInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><CurrentTransportState>TRANSITIONING</CurrentTransportState><CurrentTransportStatus>CurrentTransportStatus</CurrentTransportStatus><CurrentSpeed>CurrentSpeed</CurrentSpeed></u:GetTransportInfoResponse></s:Body></s:Envelope>"#;
+        let expected = GetTransportInfoResponse {
+            current_transport_state: Some(TransportState::Transitioning),
+            current_transport_status: Some("CurrentTransportStatus".to_string()),
+            current_speed: Some("CurrentSpeed".to_string()),
+        };
+        let decoded = <GetTransportInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/AVTransport/GetTransportInfo.xml`
+    #[test]
+    fn test_get_transport_info_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTransportInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><CurrentTransportState>PLAYING</CurrentTransportState><CurrentTransportStatus>OK</CurrentTransportStatus><CurrentSpeed>1</CurrentSpeed></u:GetTransportInfoResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetTransportInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(
+            decoded.current_transport_state, Some(TransportState::Playing),
+            "CurrentTransportState"
+        );
+        assert_eq!(
+            decoded.current_transport_status, Some("OK".to_string()),
+            "CurrentTransportStatus"
+        );
+        assert_eq!(decoded.current_speed, Some("1".to_string()), "CurrentSpeed");
+    }
+    #[test]
+    fn test_get_transport_settings_request() {
+        let request = GetTransportSettingsRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetTransportSettingsRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_transport_settings_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTransportSettingsResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><PlayMode>SHUFFLE_REPEAT_ONE</PlayMode><RecQualityMode>RecQualityMode</RecQualityMode></u:GetTransportSettingsResponse></s:Body></s:Envelope>"#;
+        let expected = GetTransportSettingsResponse {
+            play_mode: Some(CurrentPlayMode::ShuffleRepeatOne),
+            rec_quality_mode: Some("RecQualityMode".to_string()),
+        };
+        let decoded = <GetTransportSettingsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/AVTransport/GetTransportSettings.xml`
+    #[test]
+    fn test_get_transport_settings_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTransportSettingsResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><PlayMode>SHUFFLE_NOREPEAT</PlayMode><RecQualityMode>NOT_IMPLEMENTED</RecQualityMode></u:GetTransportSettingsResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetTransportSettingsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(
+            decoded.play_mode, Some(CurrentPlayMode::ShuffleNorepeat), "PlayMode"
+        );
+    }
+    #[test]
+    fn test_next_request() {
+        let request = NextRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: NextRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_notify_deleted_uri_request() {
+        let request = NotifyDeletedUriRequest {
+            instance_id: crate::InstanceId(1),
+            deleted_uri: "DeletedURI".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: NotifyDeletedUriRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_pause_request() {
+        let request = PauseRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PauseRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_play_request() {
+        let request = PlayRequest {
+            instance_id: crate::InstanceId(1),
+            speed: "Speed".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PlayRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_previous_request() {
+        let request = PreviousRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PreviousRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_all_tracks_from_queue_request() {
+        let request = RemoveAllTracksFromQueueRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveAllTracksFromQueueRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_track_from_queue_request() {
+        let request = RemoveTrackFromQueueRequest {
+            instance_id: crate::InstanceId(1),
+            object_id: "ObjectID".to_string(),
+            update_id: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveTrackFromQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_track_range_from_queue_request() {
+        let request = RemoveTrackRangeFromQueueRequest {
+            instance_id: crate::InstanceId(1),
+            update_id: 1,
+            starting_index: 1,
+            number_of_tracks: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveTrackRangeFromQueueRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_track_range_from_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:RemoveTrackRangeFromQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><NewUpdateID>1</NewUpdateID></u:RemoveTrackRangeFromQueueResponse></s:Body></s:Envelope>"#;
+        let expected = RemoveTrackRangeFromQueueResponse {
+            new_update_id: Some(1),
+        };
+        let decoded = <RemoveTrackRangeFromQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_reorder_tracks_in_queue_request() {
+        let request = ReorderTracksInQueueRequest {
+            instance_id: crate::InstanceId(1),
+            starting_index: 1,
+            number_of_tracks: 1,
+            insert_before: 1,
+            update_id: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReorderTracksInQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_reorder_tracks_in_saved_queue_request() {
+        let request = ReorderTracksInSavedQueueRequest {
+            instance_id: crate::InstanceId(1),
+            object_id: "ObjectID".to_string(),
+            update_id: 1,
+            track_list: "TrackList".to_string(),
+            new_position_list: "NewPositionList".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReorderTracksInSavedQueueRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_reorder_tracks_in_saved_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ReorderTracksInSavedQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><QueueLengthChange>1</QueueLengthChange><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:ReorderTracksInSavedQueueResponse></s:Body></s:Envelope>"#;
+        let expected = ReorderTracksInSavedQueueResponse {
+            queue_length_change: Some(1),
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <ReorderTracksInSavedQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_run_alarm_request() {
+        let request = RunAlarmRequest {
+            instance_id: crate::InstanceId(1),
+            alarm_id: 1,
+            logged_start_time: "LoggedStartTime".to_string(),
+            duration: "Duration".to_string(),
+            program_uri: "ProgramURI".to_string(),
+            program_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            play_mode: CurrentPlayMode::ShuffleRepeatOne,
+            volume: 1,
+            include_linked_zones: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RunAlarmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_save_queue_request() {
+        let request = SaveQueueRequest {
+            instance_id: crate::InstanceId(1),
+            title: "Title".to_string(),
+            object_id: "ObjectID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SaveQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_save_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SaveQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><AssignedObjectID>AssignedObjectID</AssignedObjectID></u:SaveQueueResponse></s:Body></s:Envelope>"#;
+        let expected = SaveQueueResponse {
+            assigned_object_id: Some("AssignedObjectID".to_string()),
+        };
+        let decoded = <SaveQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_seek_request() {
+        let request = SeekRequest {
+            instance_id: crate::InstanceId(1),
+            unit: SeekMode::TimeDelta,
+            target: "Target".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SeekRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_av_transport_uri_request() {
+        let request = SetAvTransportUriRequest {
+            instance_id: crate::InstanceId(1),
+            current_uri: "CurrentURI".to_string(),
+            current_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAvTransportUriRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_crossfade_mode_request() {
+        let request = SetCrossfadeModeRequest {
+            instance_id: crate::InstanceId(1),
+            crossfade_mode: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetCrossfadeModeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_next_av_transport_uri_request() {
+        let request = SetNextAvTransportUriRequest {
+            instance_id: crate::InstanceId(1),
+            next_uri: "NextURI".to_string(),
+            next_uri_meta_data: "NextURIMetaData".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetNextAvTransportUriRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_play_mode_request() {
+        let request = SetPlayModeRequest {
+            instance_id: crate::InstanceId(1),
+            new_play_mode: CurrentPlayMode::ShuffleRepeatOne,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetPlayModeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_snooze_alarm_request() {
+        let request = SnoozeAlarmRequest {
+            instance_id: crate::InstanceId(1),
+            duration: "Duration".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SnoozeAlarmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_start_autoplay_request() {
+        let request = StartAutoplayRequest {
+            instance_id: crate::InstanceId(1),
+            program_uri: "ProgramURI".to_string(),
+            program_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            volume: 1,
+            include_linked_zones: true,
+            reset_volume_after: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StartAutoplayRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_stop_request() {
+        let request = StopRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StopRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
@@ -0,0 +1,3899 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Which models support each action, according to the device
+//! descriptions from which this crate was generated, and which
+//! models have features that their device descriptions don't reveal.
+/// The model numbers, eg: `S14`, whose device descriptions are known
+pub const MODELS: &[&str] = &[
+    "S1",
+    "S13",
+    "S14",
+    "S18",
+    "S19",
+    "S21",
+    "S27",
+    "S3",
+    "S33",
+    "S38",
+    "S5",
+    "S6",
+    "S9",
+    "Sub",
+];
+/// Model numbers of players with a built-in battery:
+/// - S17: Move
+/// - S27: Roam
+/// - S35: Roam SL
+/// - S36: Move 2
+pub const BATTERY_MODELS: &[&str] = &["S17", "S27", "S35", "S36"];
+/// Model numbers of players with a line-out whose volume can be fixed:
+/// - S15: Connect (gen 2)
+/// - S23: Port
+/// - ZP80: ZonePlayer 80
+/// - ZP90: Connect
+pub const FIXED_VOLUME_MODELS: &[&str] = &["S15", "S23", "ZP80", "ZP90"];
+/// Model numbers of players that cannot be the target of an AirPlay stream:
+/// - S1: Play:1
+/// - S3: Play:3
+/// - S5: Play:5 (gen 1)
+/// - S9: Playbar
+/// - Sub: Sub
+/// - ZP100: ZonePlayer 100
+/// - ZP120: Connect:Amp
+/// - ZP80: ZonePlayer 80
+/// - ZP90: Connect
+pub const NON_AIRPLAY_MODELS: &[&str] = &[
+    "S1",
+    "S3",
+    "S5",
+    "S9",
+    "Sub",
+    "ZP100",
+    "ZP120",
+    "ZP80",
+    "ZP90",
+];
+/// (service, action, models that support it), sorted by service
+/// and then action
+const ACTIONS: &[(&str, &str, &[&str])] = &[
+    (
+        "AVTransport",
+        "AddMultipleURIsToQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "AddURIToQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "AddURIToSavedQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "BackupQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "BecomeCoordinatorOfStandaloneGroup",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "BecomeGroupCoordinator",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "BecomeGroupCoordinatorAndSource",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "ChangeCoordinator",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "ChangeTransportSettings",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "ConfigureSleepTimer",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "CreateSavedQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "DelegateGroupCoordinationTo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "EndDirectControlSession",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetCrossfadeMode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetCurrentTransportActions",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetDeviceCapabilities",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetMediaInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetPositionInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetRemainingSleepTimerDuration",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetRunningAlarmProperties",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetTransportInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "GetTransportSettings",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Next",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "NotifyDeletedURI",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Pause",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Play",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Previous",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "RemoveAllTracksFromQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "RemoveTrackFromQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "RemoveTrackRangeFromQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "ReorderTracksInQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "ReorderTracksInSavedQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "RunAlarm",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SaveQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Seek",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SetAVTransportURI",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SetCrossfadeMode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SetNextAVTransportURI",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SetPlayMode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "SnoozeAlarm",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "StartAutoplay",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AVTransport",
+        "Stop",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "CreateAlarm",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "DestroyAlarm",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetDailyIndexRefreshTime",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetFormat",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetHouseholdTimeAtStamp",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetTimeNow",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetTimeServer",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetTimeZone",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetTimeZoneAndRule",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "GetTimeZoneRule",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "ListAlarms",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "SetDailyIndexRefreshTime",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "SetFormat",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "SetTimeNow",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "SetTimeServer",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "SetTimeZone",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "AlarmClock",
+        "UpdateAlarm",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    ("AudioIn", "GetAudioInputAttributes", &["S5", "S6"]),
+    ("AudioIn", "GetLineInLevel", &["S5", "S6"]),
+    ("AudioIn", "SelectAudio", &["S5", "S6"]),
+    ("AudioIn", "SetAudioInputAttributes", &["S5", "S6"]),
+    ("AudioIn", "SetLineInLevel", &["S5", "S6"]),
+    ("AudioIn", "StartTransmissionToGroup", &["S5", "S6"]),
+    ("AudioIn", "StopTransmissionToGroup", &["S5", "S6"]),
+    (
+        "ConnectionManager",
+        "GetCurrentConnectionIDs",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ConnectionManager",
+        "GetCurrentConnectionInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ConnectionManager",
+        "GetProtocolInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "Browse",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "CreateObject",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "DestroyObject",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "FindPrefix",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetAlbumArtistDisplayOption",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetAllPrefixLocations",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetBrowseable",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetLastIndexChange",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetSearchCapabilities",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetShareIndexInProgress",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetSortCapabilities",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "GetSystemUpdateID",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "RefreshShareIndex",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "RequestResort",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "SetBrowseable",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ContentDirectory",
+        "UpdateObject",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "AddBondedZones",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "AddHTSatellite",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "CreateStereoPair",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "EnterConfigMode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "ExitConfigMode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetAutoplayLinkedZones",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetAutoplayRoomUUID",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetAutoplayVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetButtonLockState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetButtonState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    ("DeviceProperties", "GetHTForwardState", &["S19", "S38"]),
+    (
+        "DeviceProperties",
+        "GetHouseholdID",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetLEDState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetUseAutoplayVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetZoneAttributes",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "GetZoneInfo",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "RemoveBondedZones",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "RemoveHTSatellite",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "RoomDetectionStartChirping",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "RoomDetectionStopChirping",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SeparateStereoPair",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetAutoplayLinkedZones",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetAutoplayRoomUUID",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetAutoplayVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetButtonLockState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetLEDState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetUseAutoplayVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "DeviceProperties",
+        "SetZoneAttributes",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupManagement",
+        "AddMember",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupManagement",
+        "RemoveMember",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupManagement",
+        "ReportTrackBufferingResult",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupManagement",
+        "SetSourceAreaIds",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "GetGroupMute",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "GetGroupVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "SetGroupMute",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "SetGroupVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "SetRelativeGroupVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "GroupRenderingControl",
+        "SnapshotGroupVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    ("HTControl", "CommitLearnedIRCodes", &["S14", "S19", "S9"]),
+    ("HTControl", "GetIRRepeaterState", &["S14", "S19", "S9"]),
+    ("HTControl", "GetLEDFeedbackState", &["S14", "S19", "S9"]),
+    ("HTControl", "IdentifyIRRemote", &["S14", "S19", "S9"]),
+    ("HTControl", "IsRemoteConfigured", &["S14", "S19", "S9"]),
+    ("HTControl", "LearnIRCode", &["S14", "S19", "S9"]),
+    ("HTControl", "SetIRRepeaterState", &["S14", "S19", "S9"]),
+    ("HTControl", "SetLEDFeedbackState", &["S14", "S19", "S9"]),
+    (
+        "MusicServices",
+        "GetSessionId",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "MusicServices",
+        "ListAvailableServices",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "MusicServices",
+        "UpdateAvailableServices",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "QPlay",
+        "QPlayAuth",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "AddMultipleURIs",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "AddURI",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "AttachQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "Backup",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "Browse",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "CreateQueue",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "RemoveAllTracks",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "RemoveTrackRange",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "ReorderTracks",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "ReplaceAllTracks",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "Queue",
+        "SaveAsSonosPlaylist",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetBass",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetEQ",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetHeadphoneConnected",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetLoudness",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetMute",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetOutputFixed",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetRoomCalibrationStatus",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetSupportsOutputFixed",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetTreble",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetVolumeDB",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "GetVolumeDBRange",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "RampToVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "ResetBasicEQ",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "ResetExtEQ",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "RestoreVolumePriorToRamp",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetBass",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetChannelMap",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetEQ",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetLoudness",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetMute",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetOutputFixed",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetRelativeVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetRoomCalibrationStatus",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetRoomCalibrationX",
+        &["S13", "S14", "S21", "S27", "S3", "S5", "S6", "Sub"],
+    ),
+    (
+        "RenderingControl",
+        "SetTreble",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "RenderingControl",
+        "SetVolumeDB",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "AddAccountX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "AddOAuthAccountX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "DoPostUpdateTasks",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "EditAccountMd",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "EditAccountPasswordX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "EnableRDM",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "GetRDM",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "GetString",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "GetWebCode",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "ProvisionCredentialedTrialAccountX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "RefreshAccountCredentialsX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "Remove",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "RemoveAccount",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "ReplaceAccountX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "ResetThirdPartyCredentials",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "SetAccountNicknameX",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "SystemProperties",
+        "SetString",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "Next",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "Pause",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "Play",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "Previous",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "SetVolume",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "StartTransmission",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "Stop",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "VirtualLineIn",
+        "StopTransmission",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "BeginSoftwareUpdate",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "CheckForUpdate",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "GetZoneGroupAttributes",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "GetZoneGroupState",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "RegisterMobileDevice",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "ReportAlarmStartedRunning",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "ReportUnresponsiveDevice",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+    (
+        "ZoneGroupTopology",
+        "SubmitDiagnostics",
+        &[
+            "S1",
+            "S13",
+            "S14",
+            "S18",
+            "S19",
+            "S21",
+            "S27",
+            "S3",
+            "S33",
+            "S38",
+            "S5",
+            "S6",
+            "S9",
+            "Sub",
+        ],
+    ),
+];
+/// Returns whether the model identified by `model` (eg: `S14`)
+/// supports `action` of `service`, where `service` is the name
+/// of the service trait, eg: `AVTransport`.
+/// Returns `None` if the model, or the action, is not known, in
+/// which case the only way to find out is to try it.
+pub fn is_supported(model: &str, service: &str, action: &str) -> Option<bool> {
+    if !MODELS.contains(&model) {
+        return None;
+    }
+    let idx = ACTIONS
+        .binary_search_by(|(s, a, _)| (*s, *a).cmp(&(service, action)))
+        .ok()?;
+    Some(ACTIONS[idx].2.contains(&model))
+}
+/// Returns the models that support `action` of `service`
+pub fn supported_by(service: &str, action: &str) -> &'static [&'static str] {
+    ACTIONS
+        .binary_search_by(|(s, a, _)| (*s, *a).cmp(&(service, action)))
+        .map(|idx| ACTIONS[idx].2)
+        .unwrap_or(&[])
+}
@@ -0,0 +1,1248 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `ContentDirectory` service.
+use super::*;
+/// URN for the `ContentDirectory` service.
+/// `urn:schemas-upnp-org:service:ContentDirectory:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:ContentDirectory:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Browse", ns(SERVICE_TYPE))]
+pub struct BrowseRequest {
+    /// The search query, (`A:ARTIST` / `A:ALBUMARTIST` / `A:ALBUM` / `A:GENRE` / `A:COMPOSER` / `A:TRACKS` / `A:PLAYLISTS` / `FV:2` / `Q:`/ `R:0/0` / `R:0/1` / `S:` / `SQ:`) with optionally `:search+query` behind it.
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    /// How to browse
+    #[xml(rename = "BrowseFlag", ns(""))]
+    pub browse_flag: super::BrowseFlag,
+    /// Which fields should be returned `*` for all.
+    #[xml(rename = "Filter", ns(""))]
+    pub filter: String,
+    /// Paging, where to start, usually 0
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    /// Paging, number of items, maximum is 1,000. This parameter does NOT restrict the number of items being searched (filter) but only the number being returned. Using 0 is equivalent to 1,000
+    #[xml(rename = "RequestedCount", ns(""))]
+    pub requested_count: u32,
+    /// Sort the results based on metadata fields. `+upnp:artist,+dc:title` for sorting on artist then on title.
+    #[xml(rename = "SortCriteria", ns(""))]
+    pub sort_criteria: String,
+}
+impl BrowseRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> BrowseRequestBuilder {
+        BrowseRequestBuilder::default()
+    }
+}
+/// Builds a `BrowseRequest`; see `BrowseRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BrowseRequestBuilder {
+    request: BrowseRequest,
+}
+impl BrowseRequestBuilder {
+    /// The search query, (`A:ARTIST` / `A:ALBUMARTIST` / `A:ALBUM` / `A:GENRE` / `A:COMPOSER` / `A:TRACKS` / `A:PLAYLISTS` / `FV:2` / `Q:`/ `R:0/0` / `R:0/1` / `S:` / `SQ:`) with optionally `:search+query` behind it.
+    pub fn object_id(mut self, object_id: impl Into<String>) -> Self {
+        self.request.object_id = object_id.into();
+        self
+    }
+    /// How to browse
+    pub fn browse_flag(mut self, browse_flag: super::BrowseFlag) -> Self {
+        self.request.browse_flag = browse_flag;
+        self
+    }
+    /// Which fields should be returned `*` for all.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.request.filter = filter.into();
+        self
+    }
+    /// Paging, where to start, usually 0
+    pub fn starting_index(mut self, starting_index: u32) -> Self {
+        self.request.starting_index = starting_index;
+        self
+    }
+    /// Paging, number of items, maximum is 1,000. This parameter does NOT restrict the number of items being searched (filter) but only the number being returned. Using 0 is equivalent to 1,000
+    pub fn requested_count(mut self, requested_count: u32) -> Self {
+        self.request.requested_count = requested_count;
+        self
+    }
+    /// Sort the results based on metadata fields. `+upnp:artist,+dc:title` for sorting on artist then on title.
+    pub fn sort_criteria(mut self, sort_criteria: impl Into<String>) -> Self {
+        self.request.sort_criteria = sort_criteria.into();
+        self
+    }
+    pub fn build(self) -> BrowseRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BrowseResponse", ns(SERVICE_TYPE))]
+pub struct BrowseResponse {
+    #[xml(rename = "Result", ns(""))]
+    pub result: Option<DecodeXmlString<crate::TrackMetaDataList>>,
+    #[xml(rename = "NumberReturned", ns(""))]
+    pub number_returned: Option<u32>,
+    #[xml(rename = "TotalMatches", ns(""))]
+    pub total_matches: Option<u32>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for BrowseResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateObject", ns(SERVICE_TYPE))]
+pub struct CreateObjectRequest {
+    #[xml(rename = "ContainerID", ns(""))]
+    pub container_id: String,
+    #[xml(rename = "Elements", ns(""))]
+    pub elements: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateObjectResponse", ns(SERVICE_TYPE))]
+pub struct CreateObjectResponse {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: Option<String>,
+    #[xml(rename = "Result", ns(""))]
+    pub result: Option<String>,
+}
+impl crate::DecodeSoapResponse for CreateObjectResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "DestroyObject", ns(SERVICE_TYPE))]
+pub struct DestroyObjectRequest {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "FindPrefix", ns(SERVICE_TYPE))]
+pub struct FindPrefixRequest {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    #[xml(rename = "Prefix", ns(""))]
+    pub prefix: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "FindPrefixResponse", ns(SERVICE_TYPE))]
+pub struct FindPrefixResponse {
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: Option<u32>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for FindPrefixResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAlbumArtistDisplayOptionResponse", ns(SERVICE_TYPE))]
+pub struct GetAlbumArtistDisplayOptionResponse {
+    #[xml(rename = "AlbumArtistDisplayOption", ns(""))]
+    pub album_artist_display_option: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetAlbumArtistDisplayOptionResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAllPrefixLocations", ns(SERVICE_TYPE))]
+pub struct GetAllPrefixLocationsRequest {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAllPrefixLocationsResponse", ns(SERVICE_TYPE))]
+pub struct GetAllPrefixLocationsResponse {
+    #[xml(rename = "TotalPrefixes", ns(""))]
+    pub total_prefixes: Option<u32>,
+    #[xml(rename = "PrefixAndIndexCSV", ns(""))]
+    pub prefix_and_index_csv: Option<String>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for GetAllPrefixLocationsResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetBrowseableResponse", ns(SERVICE_TYPE))]
+pub struct GetBrowseableResponse {
+    #[xml(rename = "IsBrowseable", ns(""))]
+    pub is_browseable: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetBrowseableResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLastIndexChangeResponse", ns(SERVICE_TYPE))]
+pub struct GetLastIndexChangeResponse {
+    #[xml(rename = "LastIndexChange", ns(""))]
+    pub last_index_change: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetLastIndexChangeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSearchCapabilitiesResponse", ns(SERVICE_TYPE))]
+pub struct GetSearchCapabilitiesResponse {
+    #[xml(rename = "SearchCaps", ns(""))]
+    pub search_caps: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetSearchCapabilitiesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetShareIndexInProgressResponse", ns(SERVICE_TYPE))]
+pub struct GetShareIndexInProgressResponse {
+    #[xml(rename = "IsIndexing", ns(""))]
+    pub is_indexing: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetShareIndexInProgressResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSortCapabilitiesResponse", ns(SERVICE_TYPE))]
+pub struct GetSortCapabilitiesResponse {
+    #[xml(rename = "SortCaps", ns(""))]
+    pub sort_caps: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetSortCapabilitiesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSystemUpdateIDResponse", ns(SERVICE_TYPE))]
+pub struct GetSystemUpdateIdResponse {
+    #[xml(rename = "Id", ns(""))]
+    pub id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for GetSystemUpdateIdResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RefreshShareIndex", ns(SERVICE_TYPE))]
+pub struct RefreshShareIndexRequest {
+    /// `WMP`, `ITUNES` or `NONE`
+    #[xml(rename = "AlbumArtistDisplayOption", ns(""))]
+    pub album_artist_display_option: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RequestResort", ns(SERVICE_TYPE))]
+pub struct RequestResortRequest {
+    #[xml(rename = "SortOrder", ns(""))]
+    pub sort_order: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetBrowseable", ns(SERVICE_TYPE))]
+pub struct SetBrowseableRequest {
+    #[xml(rename = "Browseable", ns(""))]
+    pub browseable: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "UpdateObject", ns(SERVICE_TYPE))]
+pub struct UpdateObjectRequest {
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+    #[xml(rename = "CurrentTagValue", ns(""))]
+    pub current_tag_value: String,
+    #[xml(rename = "NewTagValue", ns(""))]
+    pub new_tag_value: String,
+}
+/// A parsed event produced by the `ContentDirectory` service.
+/// Use `SonosDevice::subscribe_content_directory()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentDirectoryEvent {
+    pub browseable: Option<bool>,
+    pub container_update_ids: Option<String>,
+    pub favorite_presets_update_id: Option<String>,
+    pub favorites_update_id: Option<String>,
+    pub radio_favorites_update_id: Option<u32>,
+    pub radio_location_update_id: Option<u32>,
+    pub recently_played_update_id: Option<String>,
+    pub saved_queues_update_id: Option<String>,
+    pub share_index_in_progress: Option<bool>,
+    pub share_index_last_error: Option<String>,
+    pub share_list_update_id: Option<String>,
+    pub system_update_id: Option<u32>,
+    pub user_radio_update_id: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct ContentDirectoryPropertySet {
+    pub properties: Vec<ContentDirectoryProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct ContentDirectoryProperty {
+    #[xml(rename = "Browseable", ns(""))]
+    pub browseable: Option<bool>,
+    #[xml(rename = "ContainerUpdateIDs", ns(""))]
+    pub container_update_ids: Option<String>,
+    #[xml(rename = "FavoritePresetsUpdateID", ns(""))]
+    pub favorite_presets_update_id: Option<String>,
+    #[xml(rename = "FavoritesUpdateID", ns(""))]
+    pub favorites_update_id: Option<String>,
+    #[xml(rename = "RadioFavoritesUpdateID", ns(""))]
+    pub radio_favorites_update_id: Option<u32>,
+    #[xml(rename = "RadioLocationUpdateID", ns(""))]
+    pub radio_location_update_id: Option<u32>,
+    #[xml(rename = "RecentlyPlayedUpdateID", ns(""))]
+    pub recently_played_update_id: Option<String>,
+    #[xml(rename = "SavedQueuesUpdateID", ns(""))]
+    pub saved_queues_update_id: Option<String>,
+    #[xml(rename = "ShareIndexInProgress", ns(""))]
+    pub share_index_in_progress: Option<bool>,
+    #[xml(rename = "ShareIndexLastError", ns(""))]
+    pub share_index_last_error: Option<String>,
+    #[xml(rename = "ShareListUpdateID", ns(""))]
+    pub share_list_update_id: Option<String>,
+    #[xml(rename = "SystemUpdateID", ns(""))]
+    pub system_update_id: Option<u32>,
+    #[xml(rename = "UserRadioUpdateID", ns(""))]
+    pub user_radio_update_id: Option<String>,
+}
+impl DecodeXml for ContentDirectoryEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: ContentDirectoryPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.browseable {
+                result.browseable.replace(v);
+            }
+            if let Some(v) = prop.container_update_ids {
+                result.container_update_ids.replace(v);
+            }
+            if let Some(v) = prop.favorite_presets_update_id {
+                result.favorite_presets_update_id.replace(v);
+            }
+            if let Some(v) = prop.favorites_update_id {
+                result.favorites_update_id.replace(v);
+            }
+            if let Some(v) = prop.radio_favorites_update_id {
+                result.radio_favorites_update_id.replace(v);
+            }
+            if let Some(v) = prop.radio_location_update_id {
+                result.radio_location_update_id.replace(v);
+            }
+            if let Some(v) = prop.recently_played_update_id {
+                result.recently_played_update_id.replace(v);
+            }
+            if let Some(v) = prop.saved_queues_update_id {
+                result.saved_queues_update_id.replace(v);
+            }
+            if let Some(v) = prop.share_index_in_progress {
+                result.share_index_in_progress.replace(v);
+            }
+            if let Some(v) = prop.share_index_last_error {
+                result.share_index_last_error.replace(v);
+            }
+            if let Some(v) = prop.share_list_update_id {
+                result.share_list_update_id.replace(v);
+            }
+            if let Some(v) = prop.system_update_id {
+                result.system_update_id.replace(v);
+            }
+            if let Some(v) = prop.user_radio_update_id {
+                result.user_radio_update_id.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `ContentDirectory` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_content_directory(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<ContentDirectoryEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+/// Errors that are documented for the `ContentDirectory` service.
+/// Use `Error::from_error` to obtain one from the error returned
+/// by a method of this service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// 701: No such object
+    NoSuchObject,
+    /// 702: Invalid CurrentTagValue
+    InvalidCurrentTagValue,
+    /// 703: Invalid NewTagValue
+    InvalidNewTagValue,
+    /// 704: Required tag
+    RequiredTag,
+    /// 705: Read-only tag
+    ReadOnlyTag,
+    /// 706: Parameter mismatch
+    ParameterMismatch,
+    /// 708: Invalid search criteria
+    InvalidSearchCriteria,
+    /// 709: Invalid sort criteria
+    InvalidSortCriteria,
+    /// 710: No such container
+    NoSuchContainer,
+    /// 711: Restricted object
+    RestrictedObject,
+    /// 712: Bad metadata
+    BadMetadata,
+    /// 713: Restricted parent object
+    RestrictedParentObject,
+    /// 714: No such source resource
+    NoSuchSourceResource,
+    /// 715: Resource access denied
+    ResourceAccessDenied,
+    /// 716: Transfer busy
+    TransferBusy,
+    /// 717: No such file transfer
+    NoSuchFileTransfer,
+    /// 718: No such destination resource
+    NoSuchDestinationResource,
+    /// 719: Destination resource access denied
+    DestinationResourceAccessDenied,
+    /// 720: Cannot process the request
+    CannotProcessTheRequest,
+    /// An error code that is not documented for this service
+    Other(u32),
+}
+impl From<u32> for Error {
+    fn from(code: u32) -> Self {
+        match code {
+            701 => Self::NoSuchObject,
+            702 => Self::InvalidCurrentTagValue,
+            703 => Self::InvalidNewTagValue,
+            704 => Self::RequiredTag,
+            705 => Self::ReadOnlyTag,
+            706 => Self::ParameterMismatch,
+            708 => Self::InvalidSearchCriteria,
+            709 => Self::InvalidSortCriteria,
+            710 => Self::NoSuchContainer,
+            711 => Self::RestrictedObject,
+            712 => Self::BadMetadata,
+            713 => Self::RestrictedParentObject,
+            714 => Self::NoSuchSourceResource,
+            715 => Self::ResourceAccessDenied,
+            716 => Self::TransferBusy,
+            717 => Self::NoSuchFileTransfer,
+            718 => Self::NoSuchDestinationResource,
+            719 => Self::DestinationResourceAccessDenied,
+            720 => Self::CannotProcessTheRequest,
+            code => Self::Other(code),
+        }
+    }
+}
+impl Error {
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::NoSuchObject => 701,
+            Self::InvalidCurrentTagValue => 702,
+            Self::InvalidNewTagValue => 703,
+            Self::RequiredTag => 704,
+            Self::ReadOnlyTag => 705,
+            Self::ParameterMismatch => 706,
+            Self::InvalidSearchCriteria => 708,
+            Self::InvalidSortCriteria => 709,
+            Self::NoSuchContainer => 710,
+            Self::RestrictedObject => 711,
+            Self::BadMetadata => 712,
+            Self::RestrictedParentObject => 713,
+            Self::NoSuchSourceResource => 714,
+            Self::ResourceAccessDenied => 715,
+            Self::TransferBusy => 716,
+            Self::NoSuchFileTransfer => 717,
+            Self::NoSuchDestinationResource => 718,
+            Self::DestinationResourceAccessDenied => 719,
+            Self::CannotProcessTheRequest => 720,
+            Self::Other(code) => *code,
+        }
+    }
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            Self::NoSuchObject => Some("No such object"),
+            Self::InvalidCurrentTagValue => Some("Invalid CurrentTagValue"),
+            Self::InvalidNewTagValue => Some("Invalid NewTagValue"),
+            Self::RequiredTag => Some("Required tag"),
+            Self::ReadOnlyTag => Some("Read-only tag"),
+            Self::ParameterMismatch => Some("Parameter mismatch"),
+            Self::InvalidSearchCriteria => Some("Invalid search criteria"),
+            Self::InvalidSortCriteria => Some("Invalid sort criteria"),
+            Self::NoSuchContainer => Some("No such container"),
+            Self::RestrictedObject => Some("Restricted object"),
+            Self::BadMetadata => Some("Bad metadata"),
+            Self::RestrictedParentObject => Some("Restricted parent object"),
+            Self::NoSuchSourceResource => Some("No such source resource"),
+            Self::ResourceAccessDenied => Some("Resource access denied"),
+            Self::TransferBusy => Some("Transfer busy"),
+            Self::NoSuchFileTransfer => Some("No such file transfer"),
+            Self::NoSuchDestinationResource => Some("No such destination resource"),
+            Self::DestinationResourceAccessDenied => {
+                Some("Destination resource access denied")
+            }
+            Self::CannotProcessTheRequest => Some("Cannot process the request"),
+            Self::Other(_) => None,
+        }
+    }
+    /// Returns the documented error if `err` is a fault
+    /// reported by this service
+    pub fn from_error(err: &crate::Error) -> Option<Self> {
+        match err {
+            crate::Error::Upnp(e) if e.service == SERVICE_TYPE => Some(e.code.into()),
+            _ => None,
+        }
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum BrowseFlag {
+    #[default]
+    BrowseMetadata,
+    BrowseDirectChildren,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for BrowseFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            BrowseFlag::BrowseMetadata => "BrowseMetadata",
+            BrowseFlag::BrowseDirectChildren => "BrowseDirectChildren",
+            BrowseFlag::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for BrowseFlag {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<BrowseFlag> {
+        match s {
+            "BrowseMetadata" => Ok(BrowseFlag::BrowseMetadata),
+            "BrowseDirectChildren" => Ok(BrowseFlag::BrowseDirectChildren),
+            s => Ok(BrowseFlag::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for BrowseFlag {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for BrowseFlag {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: BrowseFlag = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<BrowseFlag>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for BrowseFlag {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BrowseFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Browse for local content
+pub trait ContentDirectory {
+    /// Browse for content: Music library (A), share(S:), Sonos playlists(SQ:), Sonos favorites(FV:2), radio stations(R:0/0), radio shows(R:0/1), queue(Q:)). Recommendation: Send one request, check the `TotalMatches` and - if necessary - do additional requests with higher `StartingIndex`. In case of duplicates only the first is returned! Example: albums with same title, even if artists are different
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn browse(
+        &self,
+        request: content_directory::BrowseRequest,
+    ) -> Result<content_directory::BrowseResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn create_object(
+        &self,
+        request: content_directory::CreateObjectRequest,
+    ) -> Result<content_directory::CreateObjectResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn destroy_object(
+        &self,
+        request: content_directory::DestroyObjectRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn find_prefix(
+        &self,
+        request: content_directory::FindPrefixRequest,
+    ) -> Result<content_directory::FindPrefixResponse>;
+    /// Get the current album art display option such as `WMP`, `ITUNES` or `NONE`
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_album_artist_display_option(
+        &self,
+    ) -> Result<content_directory::GetAlbumArtistDisplayOptionResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_all_prefix_locations(
+        &self,
+        request: content_directory::GetAllPrefixLocationsRequest,
+    ) -> Result<content_directory::GetAllPrefixLocationsResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_browseable(&self) -> Result<content_directory::GetBrowseableResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_last_index_change(
+        &self,
+    ) -> Result<content_directory::GetLastIndexChangeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_search_capabilities(
+        &self,
+    ) -> Result<content_directory::GetSearchCapabilitiesResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_share_index_in_progress(
+        &self,
+    ) -> Result<content_directory::GetShareIndexInProgressResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_sort_capabilities(
+        &self,
+    ) -> Result<content_directory::GetSortCapabilitiesResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_system_update_id(
+        &self,
+    ) -> Result<content_directory::GetSystemUpdateIdResponse>;
+    /// Updates the music library (share) index
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn refresh_share_index(
+        &self,
+        request: content_directory::RefreshShareIndexRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn request_resort(
+        &self,
+        request: content_directory::RequestResortRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_browseable(
+        &self,
+        request: content_directory::SetBrowseableRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn update_object(
+        &self,
+        request: content_directory::UpdateObjectRequest,
+    ) -> Result<()>;
+}
+impl ContentDirectory for SonosDevice {
+    async fn browse(
+        &self,
+        request: content_directory::BrowseRequest,
+    ) -> Result<content_directory::BrowseResponse> {
+        self.action(&content_directory::SERVICE_TYPE, "Browse", request).await
+    }
+    async fn create_object(
+        &self,
+        request: content_directory::CreateObjectRequest,
+    ) -> Result<content_directory::CreateObjectResponse> {
+        self.action(&content_directory::SERVICE_TYPE, "CreateObject", request).await
+    }
+    async fn destroy_object(
+        &self,
+        request: content_directory::DestroyObjectRequest,
+    ) -> Result<()> {
+        self.action(&content_directory::SERVICE_TYPE, "DestroyObject", request).await
+    }
+    async fn find_prefix(
+        &self,
+        request: content_directory::FindPrefixRequest,
+    ) -> Result<content_directory::FindPrefixResponse> {
+        self.action(&content_directory::SERVICE_TYPE, "FindPrefix", request).await
+    }
+    async fn get_album_artist_display_option(
+        &self,
+    ) -> Result<content_directory::GetAlbumArtistDisplayOptionResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetAlbumArtistDisplayOption",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_all_prefix_locations(
+        &self,
+        request: content_directory::GetAllPrefixLocationsRequest,
+    ) -> Result<content_directory::GetAllPrefixLocationsResponse> {
+        self.action(&content_directory::SERVICE_TYPE, "GetAllPrefixLocations", request)
+            .await
+    }
+    async fn get_browseable(&self) -> Result<content_directory::GetBrowseableResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetBrowseable",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_last_index_change(
+        &self,
+    ) -> Result<content_directory::GetLastIndexChangeResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetLastIndexChange",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_search_capabilities(
+        &self,
+    ) -> Result<content_directory::GetSearchCapabilitiesResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetSearchCapabilities",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_share_index_in_progress(
+        &self,
+    ) -> Result<content_directory::GetShareIndexInProgressResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetShareIndexInProgress",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_sort_capabilities(
+        &self,
+    ) -> Result<content_directory::GetSortCapabilitiesResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetSortCapabilities",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_system_update_id(
+        &self,
+    ) -> Result<content_directory::GetSystemUpdateIdResponse> {
+        self.action(
+                &content_directory::SERVICE_TYPE,
+                "GetSystemUpdateID",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn refresh_share_index(
+        &self,
+        request: content_directory::RefreshShareIndexRequest,
+    ) -> Result<()> {
+        self.action(&content_directory::SERVICE_TYPE, "RefreshShareIndex", request).await
+    }
+    async fn request_resort(
+        &self,
+        request: content_directory::RequestResortRequest,
+    ) -> Result<()> {
+        self.action(&content_directory::SERVICE_TYPE, "RequestResort", request).await
+    }
+    async fn set_browseable(
+        &self,
+        request: content_directory::SetBrowseableRequest,
+    ) -> Result<()> {
+        self.action(&content_directory::SERVICE_TYPE, "SetBrowseable", request).await
+    }
+    async fn update_object(
+        &self,
+        request: content_directory::UpdateObjectRequest,
+    ) -> Result<()> {
+        self.action(&content_directory::SERVICE_TYPE, "UpdateObject", request).await
+    }
+}
+/// A variant of `ContentDirectory` that can be used as a trait object,
+/// eg: `Box<dyn ContentDirectoryDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait ContentDirectoryDyn: Send + Sync {
+    /// See `ContentDirectory::browse`
+    fn browse(
+        &self,
+        request: content_directory::BrowseRequest,
+    ) -> ActionFuture<'_, content_directory::BrowseResponse>;
+    /// See `ContentDirectory::create_object`
+    fn create_object(
+        &self,
+        request: content_directory::CreateObjectRequest,
+    ) -> ActionFuture<'_, content_directory::CreateObjectResponse>;
+    /// See `ContentDirectory::destroy_object`
+    fn destroy_object(
+        &self,
+        request: content_directory::DestroyObjectRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ContentDirectory::find_prefix`
+    fn find_prefix(
+        &self,
+        request: content_directory::FindPrefixRequest,
+    ) -> ActionFuture<'_, content_directory::FindPrefixResponse>;
+    /// See `ContentDirectory::get_album_artist_display_option`
+    fn get_album_artist_display_option(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetAlbumArtistDisplayOptionResponse>;
+    /// See `ContentDirectory::get_all_prefix_locations`
+    fn get_all_prefix_locations(
+        &self,
+        request: content_directory::GetAllPrefixLocationsRequest,
+    ) -> ActionFuture<'_, content_directory::GetAllPrefixLocationsResponse>;
+    /// See `ContentDirectory::get_browseable`
+    fn get_browseable(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetBrowseableResponse>;
+    /// See `ContentDirectory::get_last_index_change`
+    fn get_last_index_change(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetLastIndexChangeResponse>;
+    /// See `ContentDirectory::get_search_capabilities`
+    fn get_search_capabilities(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSearchCapabilitiesResponse>;
+    /// See `ContentDirectory::get_share_index_in_progress`
+    fn get_share_index_in_progress(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetShareIndexInProgressResponse>;
+    /// See `ContentDirectory::get_sort_capabilities`
+    fn get_sort_capabilities(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSortCapabilitiesResponse>;
+    /// See `ContentDirectory::get_system_update_id`
+    fn get_system_update_id(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSystemUpdateIdResponse>;
+    /// See `ContentDirectory::refresh_share_index`
+    fn refresh_share_index(
+        &self,
+        request: content_directory::RefreshShareIndexRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ContentDirectory::request_resort`
+    fn request_resort(
+        &self,
+        request: content_directory::RequestResortRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ContentDirectory::set_browseable`
+    fn set_browseable(
+        &self,
+        request: content_directory::SetBrowseableRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ContentDirectory::update_object`
+    fn update_object(
+        &self,
+        request: content_directory::UpdateObjectRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl ContentDirectoryDyn for SonosDevice {
+    fn browse(
+        &self,
+        request: content_directory::BrowseRequest,
+    ) -> ActionFuture<'_, content_directory::BrowseResponse> {
+        Box::pin(<Self as ContentDirectory>::browse(self, request))
+    }
+    fn create_object(
+        &self,
+        request: content_directory::CreateObjectRequest,
+    ) -> ActionFuture<'_, content_directory::CreateObjectResponse> {
+        Box::pin(<Self as ContentDirectory>::create_object(self, request))
+    }
+    fn destroy_object(
+        &self,
+        request: content_directory::DestroyObjectRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ContentDirectory>::destroy_object(self, request))
+    }
+    fn find_prefix(
+        &self,
+        request: content_directory::FindPrefixRequest,
+    ) -> ActionFuture<'_, content_directory::FindPrefixResponse> {
+        Box::pin(<Self as ContentDirectory>::find_prefix(self, request))
+    }
+    fn get_album_artist_display_option(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetAlbumArtistDisplayOptionResponse> {
+        Box::pin(<Self as ContentDirectory>::get_album_artist_display_option(self))
+    }
+    fn get_all_prefix_locations(
+        &self,
+        request: content_directory::GetAllPrefixLocationsRequest,
+    ) -> ActionFuture<'_, content_directory::GetAllPrefixLocationsResponse> {
+        Box::pin(<Self as ContentDirectory>::get_all_prefix_locations(self, request))
+    }
+    fn get_browseable(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetBrowseableResponse> {
+        Box::pin(<Self as ContentDirectory>::get_browseable(self))
+    }
+    fn get_last_index_change(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetLastIndexChangeResponse> {
+        Box::pin(<Self as ContentDirectory>::get_last_index_change(self))
+    }
+    fn get_search_capabilities(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSearchCapabilitiesResponse> {
+        Box::pin(<Self as ContentDirectory>::get_search_capabilities(self))
+    }
+    fn get_share_index_in_progress(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetShareIndexInProgressResponse> {
+        Box::pin(<Self as ContentDirectory>::get_share_index_in_progress(self))
+    }
+    fn get_sort_capabilities(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSortCapabilitiesResponse> {
+        Box::pin(<Self as ContentDirectory>::get_sort_capabilities(self))
+    }
+    fn get_system_update_id(
+        &self,
+    ) -> ActionFuture<'_, content_directory::GetSystemUpdateIdResponse> {
+        Box::pin(<Self as ContentDirectory>::get_system_update_id(self))
+    }
+    fn refresh_share_index(
+        &self,
+        request: content_directory::RefreshShareIndexRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ContentDirectory>::refresh_share_index(self, request))
+    }
+    fn request_resort(
+        &self,
+        request: content_directory::RequestResortRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ContentDirectory>::request_resort(self, request))
+    }
+    fn set_browseable(
+        &self,
+        request: content_directory::SetBrowseableRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ContentDirectory>::set_browseable(self, request))
+    }
+    fn update_object(
+        &self,
+        request: content_directory::UpdateObjectRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ContentDirectory>::update_object(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_browse_request() {
+        let request = BrowseRequest {
+            object_id: "ObjectID".to_string(),
+            browse_flag: BrowseFlag::BrowseDirectChildren,
+            filter: "Filter".to_string(),
+            starting_index: 1,
+            requested_count: 1,
+            sort_criteria: "SortCriteria".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BrowseRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_browse_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:BrowseResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><NumberReturned>1</NumberReturned><TotalMatches>1</TotalMatches><UpdateID>1</UpdateID></u:BrowseResponse></s:Body></s:Envelope>"#;
+        let expected = BrowseResponse {
+            result: None,
+            number_returned: Some(1),
+            total_matches: Some(1),
+            update_id: Some(1),
+        };
+        let decoded = <BrowseResponse as crate::DecodeSoapResponse>::decode_soap_xml(xml)
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_create_object_request() {
+        let request = CreateObjectRequest {
+            container_id: "ContainerID".to_string(),
+            elements: "Elements".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CreateObjectRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_object_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:CreateObjectResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><ObjectID>ObjectID</ObjectID><Result>Result</Result></u:CreateObjectResponse></s:Body></s:Envelope>"#;
+        let expected = CreateObjectResponse {
+            object_id: Some("ObjectID".to_string()),
+            result: Some("Result".to_string()),
+        };
+        let decoded = <CreateObjectResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_destroy_object_request() {
+        let request = DestroyObjectRequest {
+            object_id: "ObjectID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: DestroyObjectRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_find_prefix_request() {
+        let request = FindPrefixRequest {
+            object_id: "ObjectID".to_string(),
+            prefix: "Prefix".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: FindPrefixRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_find_prefix_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:FindPrefixResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><StartingIndex>1</StartingIndex><UpdateID>1</UpdateID></u:FindPrefixResponse></s:Body></s:Envelope>"#;
+        let expected = FindPrefixResponse {
+            starting_index: Some(1),
+            update_id: Some(1),
+        };
+        let decoded = <FindPrefixResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_album_artist_display_option_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAlbumArtistDisplayOptionResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><AlbumArtistDisplayOption>AlbumArtistDisplayOption</AlbumArtistDisplayOption></u:GetAlbumArtistDisplayOptionResponse></s:Body></s:Envelope>"#;
+        let expected = GetAlbumArtistDisplayOptionResponse {
+            album_artist_display_option: Some("AlbumArtistDisplayOption".to_string()),
+        };
+        let decoded = <GetAlbumArtistDisplayOptionResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_all_prefix_locations_request() {
+        let request = GetAllPrefixLocationsRequest {
+            object_id: "ObjectID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetAllPrefixLocationsRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_all_prefix_locations_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAllPrefixLocationsResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><TotalPrefixes>1</TotalPrefixes><PrefixAndIndexCSV>PrefixAndIndexCSV</PrefixAndIndexCSV><UpdateID>1</UpdateID></u:GetAllPrefixLocationsResponse></s:Body></s:Envelope>"#;
+        let expected = GetAllPrefixLocationsResponse {
+            total_prefixes: Some(1),
+            prefix_and_index_csv: Some("PrefixAndIndexCSV".to_string()),
+            update_id: Some(1),
+        };
+        let decoded = <GetAllPrefixLocationsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_browseable_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetBrowseableResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><IsBrowseable>1</IsBrowseable></u:GetBrowseableResponse></s:Body></s:Envelope>"#;
+        let expected = GetBrowseableResponse {
+            is_browseable: Some(true),
+        };
+        let decoded = <GetBrowseableResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_last_index_change_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetLastIndexChangeResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><LastIndexChange>LastIndexChange</LastIndexChange></u:GetLastIndexChangeResponse></s:Body></s:Envelope>"#;
+        let expected = GetLastIndexChangeResponse {
+            last_index_change: Some("LastIndexChange".to_string()),
+        };
+        let decoded = <GetLastIndexChangeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_search_capabilities_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetSearchCapabilitiesResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><SearchCaps>SearchCaps</SearchCaps></u:GetSearchCapabilitiesResponse></s:Body></s:Envelope>"#;
+        let expected = GetSearchCapabilitiesResponse {
+            search_caps: Some("SearchCaps".to_string()),
+        };
+        let decoded = <GetSearchCapabilitiesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_share_index_in_progress_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetShareIndexInProgressResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><IsIndexing>1</IsIndexing></u:GetShareIndexInProgressResponse></s:Body></s:Envelope>"#;
+        let expected = GetShareIndexInProgressResponse {
+            is_indexing: Some(true),
+        };
+        let decoded = <GetShareIndexInProgressResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_sort_capabilities_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetSortCapabilitiesResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><SortCaps>SortCaps</SortCaps></u:GetSortCapabilitiesResponse></s:Body></s:Envelope>"#;
+        let expected = GetSortCapabilitiesResponse {
+            sort_caps: Some("SortCaps".to_string()),
+        };
+        let decoded = <GetSortCapabilitiesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_system_update_id_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetSystemUpdateIDResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><Id>1</Id></u:GetSystemUpdateIDResponse></s:Body></s:Envelope>"#;
+        let expected = GetSystemUpdateIdResponse {
+            id: Some(1),
+        };
+        let decoded = <GetSystemUpdateIdResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_refresh_share_index_request() {
+        let request = RefreshShareIndexRequest {
+            album_artist_display_option: "AlbumArtistDisplayOption".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RefreshShareIndexRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_request_resort_request() {
+        let request = RequestResortRequest {
+            sort_order: "SortOrder".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RequestResortRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_browseable_request() {
+        let request = SetBrowseableRequest {
+            browseable: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetBrowseableRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_update_object_request() {
+        let request = UpdateObjectRequest {
+            object_id: "ObjectID".to_string(),
+            current_tag_value: "CurrentTagValue".to_string(),
+            new_tag_value: "NewTagValue".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: UpdateObjectRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
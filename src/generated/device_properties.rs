@@ -0,0 +1,1893 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `DeviceProperties` service.
+use super::*;
+/// URN for the `DeviceProperties` service.
+/// `urn:schemas-upnp-org:service:DeviceProperties:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:DeviceProperties:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddBondedZones", ns(SERVICE_TYPE))]
+pub struct AddBondedZonesRequest {
+    #[xml(rename = "ChannelMapSet", ns(""))]
+    pub channel_map_set: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddHTSatellite", ns(SERVICE_TYPE))]
+pub struct AddHtSatelliteRequest {
+    /// example: `RINCON_000PPP1400:LF,RF;RINCON_000RRR1400:RR;RINCON_000SSS1400:LR;RINCON_000QQQ1400:SW`
+    #[xml(rename = "HTSatChanMapSet", ns(""))]
+    pub ht_sat_chan_map_set: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateStereoPair", ns(SERVICE_TYPE))]
+pub struct CreateStereoPairRequest {
+    /// example: `RINCON_B8E9375831C001400:LF,LF;RINCON_000E58FE3AEA01400:RF,RF`
+    #[xml(rename = "ChannelMapSet", ns(""))]
+    pub channel_map_set: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EnterConfigMode", ns(SERVICE_TYPE))]
+pub struct EnterConfigModeRequest {
+    #[xml(rename = "Mode", ns(""))]
+    pub mode: String,
+    #[xml(rename = "Options", ns(""))]
+    pub options: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EnterConfigModeResponse", ns(SERVICE_TYPE))]
+pub struct EnterConfigModeResponse {
+    #[xml(rename = "State", ns(""))]
+    pub state: Option<String>,
+}
+impl crate::DecodeSoapResponse for EnterConfigModeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ExitConfigMode", ns(SERVICE_TYPE))]
+pub struct ExitConfigModeRequest {
+    #[xml(rename = "Options", ns(""))]
+    pub options: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayLinkedZones", ns(SERVICE_TYPE))]
+pub struct GetAutoplayLinkedZonesRequest {
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayLinkedZonesResponse", ns(SERVICE_TYPE))]
+pub struct GetAutoplayLinkedZonesResponse {
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetAutoplayLinkedZonesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayRoomUUID", ns(SERVICE_TYPE))]
+pub struct GetAutoplayRoomUuidRequest {
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayRoomUUIDResponse", ns(SERVICE_TYPE))]
+pub struct GetAutoplayRoomUuidResponse {
+    #[xml(rename = "RoomUUID", ns(""))]
+    pub room_uuid: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetAutoplayRoomUuidResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayVolume", ns(SERVICE_TYPE))]
+pub struct GetAutoplayVolumeRequest {
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetAutoplayVolumeResponse", ns(SERVICE_TYPE))]
+pub struct GetAutoplayVolumeResponse {
+    #[xml(rename = "CurrentVolume", ns(""))]
+    pub current_volume: Option<u16>,
+}
+impl crate::DecodeSoapResponse for GetAutoplayVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetButtonLockStateResponse", ns(SERVICE_TYPE))]
+pub struct GetButtonLockStateResponse {
+    #[xml(rename = "CurrentButtonLockState", ns(""))]
+    pub current_button_lock_state: Option<super::ButtonLockState>,
+}
+impl crate::DecodeSoapResponse for GetButtonLockStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetButtonStateResponse", ns(SERVICE_TYPE))]
+pub struct GetButtonStateResponse {
+    #[xml(rename = "State", ns(""))]
+    pub state: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetButtonStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHTForwardStateResponse", ns(SERVICE_TYPE))]
+pub struct GetHtForwardStateResponse {
+    #[xml(rename = "IsHTForwardEnabled", ns(""))]
+    pub is_ht_forward_enabled: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetHtForwardStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHouseholdIDResponse", ns(SERVICE_TYPE))]
+pub struct GetHouseholdIdResponse {
+    #[xml(rename = "CurrentHouseholdID", ns(""))]
+    pub current_household_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetHouseholdIdResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLEDStateResponse", ns(SERVICE_TYPE))]
+pub struct GetLedStateResponse {
+    #[xml(rename = "CurrentLEDState", ns(""))]
+    pub current_led_state: Option<super::LEDState>,
+}
+impl crate::DecodeSoapResponse for GetLedStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetUseAutoplayVolume", ns(SERVICE_TYPE))]
+pub struct GetUseAutoplayVolumeRequest {
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetUseAutoplayVolumeResponse", ns(SERVICE_TYPE))]
+pub struct GetUseAutoplayVolumeResponse {
+    #[xml(rename = "UseVolume", ns(""))]
+    pub use_volume: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetUseAutoplayVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetZoneAttributesResponse", ns(SERVICE_TYPE))]
+pub struct GetZoneAttributesResponse {
+    #[xml(rename = "CurrentZoneName", ns(""))]
+    pub current_zone_name: Option<String>,
+    #[xml(rename = "CurrentIcon", ns(""))]
+    pub current_icon: Option<String>,
+    #[xml(rename = "CurrentConfiguration", ns(""))]
+    pub current_configuration: Option<String>,
+    #[xml(rename = "CurrentTargetRoomName", ns(""))]
+    pub current_target_room_name: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetZoneAttributesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetZoneInfoResponse", ns(SERVICE_TYPE))]
+pub struct GetZoneInfoResponse {
+    #[xml(rename = "SerialNumber", ns(""))]
+    pub serial_number: Option<String>,
+    #[xml(rename = "SoftwareVersion", ns(""))]
+    pub software_version: Option<String>,
+    #[xml(rename = "DisplaySoftwareVersion", ns(""))]
+    pub display_software_version: Option<String>,
+    #[xml(rename = "HardwareVersion", ns(""))]
+    pub hardware_version: Option<String>,
+    #[xml(rename = "IPAddress", ns(""))]
+    pub ip_address: Option<String>,
+    #[xml(rename = "MACAddress", ns(""))]
+    pub mac_address: Option<String>,
+    #[xml(rename = "CopyrightInfo", ns(""))]
+    pub copyright_info: Option<String>,
+    #[xml(rename = "ExtraInfo", ns(""))]
+    pub extra_info: Option<String>,
+    #[xml(rename = "HTAudioIn", ns(""))]
+    pub ht_audio_in: Option<u32>,
+    #[xml(rename = "Flags", ns(""))]
+    pub flags: Option<u32>,
+}
+impl crate::DecodeSoapResponse for GetZoneInfoResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveBondedZones", ns(SERVICE_TYPE))]
+pub struct RemoveBondedZonesRequest {
+    #[xml(rename = "ChannelMapSet", ns(""))]
+    pub channel_map_set: String,
+    #[xml(rename = "KeepGrouped", ns(""))]
+    pub keep_grouped: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveHTSatellite", ns(SERVICE_TYPE))]
+pub struct RemoveHtSatelliteRequest {
+    /// example: `RINCON_000RRR1400`
+    #[xml(rename = "SatRoomUUID", ns(""))]
+    pub sat_room_uuid: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RoomDetectionStartChirping", ns(SERVICE_TYPE))]
+pub struct RoomDetectionStartChirpingRequest {
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: u16,
+    #[xml(rename = "DurationMilliseconds", ns(""))]
+    pub duration_milliseconds: u32,
+    #[xml(rename = "ChirpIfPlayingSwappableAudio", ns(""))]
+    pub chirp_if_playing_swappable_audio: Option<bool>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RoomDetectionStartChirpingResponse", ns(SERVICE_TYPE))]
+pub struct RoomDetectionStartChirpingResponse {
+    #[xml(rename = "PlayId", ns(""))]
+    pub play_id: Option<u32>,
+    #[xml(rename = "ChirpIfPlayingSwappableAudio", ns(""))]
+    pub chirp_if_playing_swappable_audio: Option<bool>,
+}
+impl crate::DecodeSoapResponse for RoomDetectionStartChirpingResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RoomDetectionStopChirping", ns(SERVICE_TYPE))]
+pub struct RoomDetectionStopChirpingRequest {
+    #[xml(rename = "PlayId", ns(""))]
+    pub play_id: u32,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SeparateStereoPair", ns(SERVICE_TYPE))]
+pub struct SeparateStereoPairRequest {
+    /// example: `RINCON_B8E9375831C001400:LF,LF;RINCON_000E58FE3AEA01400:RF,RF`
+    #[xml(rename = "ChannelMapSet", ns(""))]
+    pub channel_map_set: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAutoplayLinkedZones", ns(SERVICE_TYPE))]
+pub struct SetAutoplayLinkedZonesRequest {
+    #[xml(rename = "IncludeLinkedZones", ns(""))]
+    pub include_linked_zones: bool,
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAutoplayRoomUUID", ns(SERVICE_TYPE))]
+pub struct SetAutoplayRoomUuidRequest {
+    #[xml(rename = "RoomUUID", ns(""))]
+    pub room_uuid: String,
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAutoplayVolume", ns(SERVICE_TYPE))]
+pub struct SetAutoplayVolumeRequest {
+    #[xml(rename = "Volume", ns(""))]
+    pub volume: u16,
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetButtonLockState", ns(SERVICE_TYPE))]
+pub struct SetButtonLockStateRequest {
+    #[xml(rename = "DesiredButtonLockState", ns(""))]
+    pub desired_button_lock_state: super::ButtonLockState,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetLEDState", ns(SERVICE_TYPE))]
+pub struct SetLedStateRequest {
+    #[xml(rename = "DesiredLEDState", ns(""))]
+    pub desired_led_state: super::LEDState,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetUseAutoplayVolume", ns(SERVICE_TYPE))]
+pub struct SetUseAutoplayVolumeRequest {
+    #[xml(rename = "UseVolume", ns(""))]
+    pub use_volume: bool,
+    #[xml(rename = "Source", ns(""))]
+    pub source: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetZoneAttributes", ns(SERVICE_TYPE))]
+pub struct SetZoneAttributesRequest {
+    #[xml(rename = "DesiredZoneName", ns(""))]
+    pub desired_zone_name: String,
+    #[xml(rename = "DesiredIcon", ns(""))]
+    pub desired_icon: String,
+    #[xml(rename = "DesiredConfiguration", ns(""))]
+    pub desired_configuration: String,
+    #[xml(rename = "DesiredTargetRoomName", ns(""))]
+    pub desired_target_room_name: Option<String>,
+}
+impl SetZoneAttributesRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> SetZoneAttributesRequestBuilder {
+        SetZoneAttributesRequestBuilder::default()
+    }
+}
+/// Builds a `SetZoneAttributesRequest`; see `SetZoneAttributesRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SetZoneAttributesRequestBuilder {
+    request: SetZoneAttributesRequest,
+}
+impl SetZoneAttributesRequestBuilder {
+    pub fn desired_zone_name(mut self, desired_zone_name: impl Into<String>) -> Self {
+        self.request.desired_zone_name = desired_zone_name.into();
+        self
+    }
+    pub fn desired_icon(mut self, desired_icon: impl Into<String>) -> Self {
+        self.request.desired_icon = desired_icon.into();
+        self
+    }
+    pub fn desired_configuration(
+        mut self,
+        desired_configuration: impl Into<String>,
+    ) -> Self {
+        self.request.desired_configuration = desired_configuration.into();
+        self
+    }
+    pub fn desired_target_room_name(
+        mut self,
+        desired_target_room_name: impl Into<String>,
+    ) -> Self {
+        self.request.desired_target_room_name = Some(desired_target_room_name.into());
+        self
+    }
+    pub fn build(self) -> SetZoneAttributesRequest {
+        self.request
+    }
+}
+/// A parsed event produced by the `DeviceProperties` service.
+/// Use `SonosDevice::subscribe_device_properties()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DevicePropertiesEvent {
+    pub air_play_enabled: Option<bool>,
+    pub available_room_calibration: Option<String>,
+    pub behind_wifi_extender: Option<u32>,
+    pub channel_freq: Option<u32>,
+    pub channel_map_set: Option<String>,
+    pub config_mode: Option<String>,
+    pub configuration: Option<String>,
+    pub eth_link: Option<bool>,
+    pub ht_bonded_zone_commit_state: Option<u32>,
+    pub ht_freq: Option<u32>,
+    pub ht_sat_chan_map_set: Option<String>,
+    pub has_configured_ssid: Option<bool>,
+    pub hdmi_cec_available: Option<bool>,
+    pub icon: Option<String>,
+    pub invisible: Option<bool>,
+    pub is_idle: Option<bool>,
+    pub is_zone_bridge: Option<bool>,
+    pub last_changed_play_state: Option<String>,
+    pub mic_enabled: Option<u32>,
+    pub more_info: Option<String>,
+    pub orientation: Option<i32>,
+    pub room_calibration_state: Option<i32>,
+    pub secure_reg_state: Option<u32>,
+    pub settings_replication_state: Option<String>,
+    pub supports_audio_clip: Option<bool>,
+    pub supports_audio_in: Option<bool>,
+    pub tv_configuration_error: Option<bool>,
+    pub voice_config_state: Option<u32>,
+    pub wifi_enabled: Option<bool>,
+    pub wireless_leaf_only: Option<bool>,
+    pub wireless_mode: Option<u32>,
+    pub zone_name: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct DevicePropertiesPropertySet {
+    pub properties: Vec<DevicePropertiesProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct DevicePropertiesProperty {
+    #[xml(rename = "AirPlayEnabled", ns(""))]
+    pub air_play_enabled: Option<bool>,
+    #[xml(rename = "AvailableRoomCalibration", ns(""))]
+    pub available_room_calibration: Option<String>,
+    #[xml(rename = "BehindWifiExtender", ns(""))]
+    pub behind_wifi_extender: Option<u32>,
+    #[xml(rename = "ChannelFreq", ns(""))]
+    pub channel_freq: Option<u32>,
+    #[xml(rename = "ChannelMapSet", ns(""))]
+    pub channel_map_set: Option<String>,
+    #[xml(rename = "ConfigMode", ns(""))]
+    pub config_mode: Option<String>,
+    #[xml(rename = "Configuration", ns(""))]
+    pub configuration: Option<String>,
+    #[xml(rename = "EthLink", ns(""))]
+    pub eth_link: Option<bool>,
+    #[xml(rename = "HTBondedZoneCommitState", ns(""))]
+    pub ht_bonded_zone_commit_state: Option<u32>,
+    #[xml(rename = "HTFreq", ns(""))]
+    pub ht_freq: Option<u32>,
+    #[xml(rename = "HTSatChanMapSet", ns(""))]
+    pub ht_sat_chan_map_set: Option<String>,
+    #[xml(rename = "HasConfiguredSSID", ns(""))]
+    pub has_configured_ssid: Option<bool>,
+    #[xml(rename = "HdmiCecAvailable", ns(""))]
+    pub hdmi_cec_available: Option<bool>,
+    #[xml(rename = "Icon", ns(""))]
+    pub icon: Option<String>,
+    #[xml(rename = "Invisible", ns(""))]
+    pub invisible: Option<bool>,
+    #[xml(rename = "IsIdle", ns(""))]
+    pub is_idle: Option<bool>,
+    #[xml(rename = "IsZoneBridge", ns(""))]
+    pub is_zone_bridge: Option<bool>,
+    #[xml(rename = "LastChangedPlayState", ns(""))]
+    pub last_changed_play_state: Option<String>,
+    #[xml(rename = "MicEnabled", ns(""))]
+    pub mic_enabled: Option<u32>,
+    #[xml(rename = "MoreInfo", ns(""))]
+    pub more_info: Option<String>,
+    #[xml(rename = "Orientation", ns(""))]
+    pub orientation: Option<i32>,
+    #[xml(rename = "RoomCalibrationState", ns(""))]
+    pub room_calibration_state: Option<i32>,
+    #[xml(rename = "SecureRegState", ns(""))]
+    pub secure_reg_state: Option<u32>,
+    #[xml(rename = "SettingsReplicationState", ns(""))]
+    pub settings_replication_state: Option<String>,
+    #[xml(rename = "SupportsAudioClip", ns(""))]
+    pub supports_audio_clip: Option<bool>,
+    #[xml(rename = "SupportsAudioIn", ns(""))]
+    pub supports_audio_in: Option<bool>,
+    #[xml(rename = "TVConfigurationError", ns(""))]
+    pub tv_configuration_error: Option<bool>,
+    #[xml(rename = "VoiceConfigState", ns(""))]
+    pub voice_config_state: Option<u32>,
+    #[xml(rename = "WifiEnabled", ns(""))]
+    pub wifi_enabled: Option<bool>,
+    #[xml(rename = "WirelessLeafOnly", ns(""))]
+    pub wireless_leaf_only: Option<bool>,
+    #[xml(rename = "WirelessMode", ns(""))]
+    pub wireless_mode: Option<u32>,
+    #[xml(rename = "ZoneName", ns(""))]
+    pub zone_name: Option<String>,
+}
+impl DecodeXml for DevicePropertiesEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: DevicePropertiesPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.air_play_enabled {
+                result.air_play_enabled.replace(v);
+            }
+            if let Some(v) = prop.available_room_calibration {
+                result.available_room_calibration.replace(v);
+            }
+            if let Some(v) = prop.behind_wifi_extender {
+                result.behind_wifi_extender.replace(v);
+            }
+            if let Some(v) = prop.channel_freq {
+                result.channel_freq.replace(v);
+            }
+            if let Some(v) = prop.channel_map_set {
+                result.channel_map_set.replace(v);
+            }
+            if let Some(v) = prop.config_mode {
+                result.config_mode.replace(v);
+            }
+            if let Some(v) = prop.configuration {
+                result.configuration.replace(v);
+            }
+            if let Some(v) = prop.eth_link {
+                result.eth_link.replace(v);
+            }
+            if let Some(v) = prop.ht_bonded_zone_commit_state {
+                result.ht_bonded_zone_commit_state.replace(v);
+            }
+            if let Some(v) = prop.ht_freq {
+                result.ht_freq.replace(v);
+            }
+            if let Some(v) = prop.ht_sat_chan_map_set {
+                result.ht_sat_chan_map_set.replace(v);
+            }
+            if let Some(v) = prop.has_configured_ssid {
+                result.has_configured_ssid.replace(v);
+            }
+            if let Some(v) = prop.hdmi_cec_available {
+                result.hdmi_cec_available.replace(v);
+            }
+            if let Some(v) = prop.icon {
+                result.icon.replace(v);
+            }
+            if let Some(v) = prop.invisible {
+                result.invisible.replace(v);
+            }
+            if let Some(v) = prop.is_idle {
+                result.is_idle.replace(v);
+            }
+            if let Some(v) = prop.is_zone_bridge {
+                result.is_zone_bridge.replace(v);
+            }
+            if let Some(v) = prop.last_changed_play_state {
+                result.last_changed_play_state.replace(v);
+            }
+            if let Some(v) = prop.mic_enabled {
+                result.mic_enabled.replace(v);
+            }
+            if let Some(v) = prop.more_info {
+                result.more_info.replace(v);
+            }
+            if let Some(v) = prop.orientation {
+                result.orientation.replace(v);
+            }
+            if let Some(v) = prop.room_calibration_state {
+                result.room_calibration_state.replace(v);
+            }
+            if let Some(v) = prop.secure_reg_state {
+                result.secure_reg_state.replace(v);
+            }
+            if let Some(v) = prop.settings_replication_state {
+                result.settings_replication_state.replace(v);
+            }
+            if let Some(v) = prop.supports_audio_clip {
+                result.supports_audio_clip.replace(v);
+            }
+            if let Some(v) = prop.supports_audio_in {
+                result.supports_audio_in.replace(v);
+            }
+            if let Some(v) = prop.tv_configuration_error {
+                result.tv_configuration_error.replace(v);
+            }
+            if let Some(v) = prop.voice_config_state {
+                result.voice_config_state.replace(v);
+            }
+            if let Some(v) = prop.wifi_enabled {
+                result.wifi_enabled.replace(v);
+            }
+            if let Some(v) = prop.wireless_leaf_only {
+                result.wireless_leaf_only.replace(v);
+            }
+            if let Some(v) = prop.wireless_mode {
+                result.wireless_mode.replace(v);
+            }
+            if let Some(v) = prop.zone_name {
+                result.zone_name.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `DeviceProperties` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_device_properties(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<DevicePropertiesEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum ButtonLockState {
+    #[default]
+    On,
+    Off,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for ButtonLockState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            ButtonLockState::On => "On",
+            ButtonLockState::Off => "Off",
+            ButtonLockState::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for ButtonLockState {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<ButtonLockState> {
+        match s {
+            "On" => Ok(ButtonLockState::On),
+            "Off" => Ok(ButtonLockState::Off),
+            s => Ok(ButtonLockState::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for ButtonLockState {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for ButtonLockState {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: ButtonLockState = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<ButtonLockState>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for ButtonLockState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ButtonLockState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum LEDState {
+    #[default]
+    On,
+    Off,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for LEDState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            LEDState::On => "On",
+            LEDState::Off => "Off",
+            LEDState::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for LEDState {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<LEDState> {
+        match s {
+            "On" => Ok(LEDState::On),
+            "Off" => Ok(LEDState::Off),
+            s => Ok(LEDState::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for LEDState {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for LEDState {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: LEDState = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<LEDState>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for LEDState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LEDState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Modify device properties, like LED status and stereo pairs
+pub trait DeviceProperties {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_bonded_zones(
+        &self,
+        request: device_properties::AddBondedZonesRequest,
+    ) -> Result<()>;
+    /// Adds satellites and/or a sub woofer to a (main) player. The satellites become hidden. The main player RINCON_* is mandatory. RR: right - rear, LF: left - front, SW: subwoofer
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_ht_satellite(
+        &self,
+        request: device_properties::AddHtSatelliteRequest,
+    ) -> Result<()>;
+    /// Create a stereo pair (left, right speakers), right one becomes hidden
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn create_stereo_pair(
+        &self,
+        request: device_properties::CreateStereoPairRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn enter_config_mode(
+        &self,
+        request: device_properties::EnterConfigModeRequest,
+    ) -> Result<device_properties::EnterConfigModeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn exit_config_mode(
+        &self,
+        request: device_properties::ExitConfigModeRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_autoplay_linked_zones(
+        &self,
+        request: device_properties::GetAutoplayLinkedZonesRequest,
+    ) -> Result<device_properties::GetAutoplayLinkedZonesResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_autoplay_room_uuid(
+        &self,
+        request: device_properties::GetAutoplayRoomUuidRequest,
+    ) -> Result<device_properties::GetAutoplayRoomUuidResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_autoplay_volume(
+        &self,
+        request: device_properties::GetAutoplayVolumeRequest,
+    ) -> Result<device_properties::GetAutoplayVolumeResponse>;
+    /// Get the current button lock state
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_button_lock_state(
+        &self,
+    ) -> Result<device_properties::GetButtonLockStateResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_button_state(
+        &self,
+    ) -> Result<device_properties::GetButtonStateResponse>;
+    /// Supported by: `S19`, `S38`
+    async fn get_ht_forward_state(
+        &self,
+    ) -> Result<device_properties::GetHtForwardStateResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_household_id(
+        &self,
+    ) -> Result<device_properties::GetHouseholdIdResponse>;
+    /// Get the current LED state
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_led_state(&self) -> Result<device_properties::GetLedStateResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_use_autoplay_volume(
+        &self,
+        request: device_properties::GetUseAutoplayVolumeRequest,
+    ) -> Result<device_properties::GetUseAutoplayVolumeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_zone_attributes(
+        &self,
+    ) -> Result<device_properties::GetZoneAttributesResponse>;
+    /// Get information about this specific speaker
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_zone_info(&self) -> Result<device_properties::GetZoneInfoResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_bonded_zones(
+        &self,
+        request: device_properties::RemoveBondedZonesRequest,
+    ) -> Result<()>;
+    /// Removes a satellite or a sub woofer from (main) player. The satellite becomes visible.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_ht_satellite(
+        &self,
+        request: device_properties::RemoveHtSatelliteRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S6`, `S9`, `Sub`
+    async fn room_detection_start_chirping(
+        &self,
+        request: device_properties::RoomDetectionStartChirpingRequest,
+    ) -> Result<device_properties::RoomDetectionStartChirpingResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S6`, `S9`, `Sub`
+    async fn room_detection_stop_chirping(
+        &self,
+        request: device_properties::RoomDetectionStopChirpingRequest,
+    ) -> Result<()>;
+    /// Separate a stereo pair
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn separate_stereo_pair(
+        &self,
+        request: device_properties::SeparateStereoPairRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_autoplay_linked_zones(
+        &self,
+        request: device_properties::SetAutoplayLinkedZonesRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_autoplay_room_uuid(
+        &self,
+        request: device_properties::SetAutoplayRoomUuidRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_autoplay_volume(
+        &self,
+        request: device_properties::SetAutoplayVolumeRequest,
+    ) -> Result<()>;
+    /// Set the button lock state
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_button_lock_state(
+        &self,
+        request: device_properties::SetButtonLockStateRequest,
+    ) -> Result<()>;
+    /// Set the LED state
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_led_state(
+        &self,
+        request: device_properties::SetLedStateRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_use_autoplay_volume(
+        &self,
+        request: device_properties::SetUseAutoplayVolumeRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_zone_attributes(
+        &self,
+        request: device_properties::SetZoneAttributesRequest,
+    ) -> Result<()>;
+}
+impl DeviceProperties for SonosDevice {
+    async fn add_bonded_zones(
+        &self,
+        request: device_properties::AddBondedZonesRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "AddBondedZones", request).await
+    }
+    async fn add_ht_satellite(
+        &self,
+        request: device_properties::AddHtSatelliteRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "AddHTSatellite", request).await
+    }
+    async fn create_stereo_pair(
+        &self,
+        request: device_properties::CreateStereoPairRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "CreateStereoPair", request).await
+    }
+    async fn enter_config_mode(
+        &self,
+        request: device_properties::EnterConfigModeRequest,
+    ) -> Result<device_properties::EnterConfigModeResponse> {
+        self.action(&device_properties::SERVICE_TYPE, "EnterConfigMode", request).await
+    }
+    async fn exit_config_mode(
+        &self,
+        request: device_properties::ExitConfigModeRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "ExitConfigMode", request).await
+    }
+    async fn get_autoplay_linked_zones(
+        &self,
+        request: device_properties::GetAutoplayLinkedZonesRequest,
+    ) -> Result<device_properties::GetAutoplayLinkedZonesResponse> {
+        self.action(&device_properties::SERVICE_TYPE, "GetAutoplayLinkedZones", request)
+            .await
+    }
+    async fn get_autoplay_room_uuid(
+        &self,
+        request: device_properties::GetAutoplayRoomUuidRequest,
+    ) -> Result<device_properties::GetAutoplayRoomUuidResponse> {
+        self.action(&device_properties::SERVICE_TYPE, "GetAutoplayRoomUUID", request)
+            .await
+    }
+    async fn get_autoplay_volume(
+        &self,
+        request: device_properties::GetAutoplayVolumeRequest,
+    ) -> Result<device_properties::GetAutoplayVolumeResponse> {
+        self.action(&device_properties::SERVICE_TYPE, "GetAutoplayVolume", request).await
+    }
+    async fn get_button_lock_state(
+        &self,
+    ) -> Result<device_properties::GetButtonLockStateResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetButtonLockState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_button_state(
+        &self,
+    ) -> Result<device_properties::GetButtonStateResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetButtonState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_ht_forward_state(
+        &self,
+    ) -> Result<device_properties::GetHtForwardStateResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetHTForwardState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_household_id(
+        &self,
+    ) -> Result<device_properties::GetHouseholdIdResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetHouseholdID",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_led_state(&self) -> Result<device_properties::GetLedStateResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetLEDState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_use_autoplay_volume(
+        &self,
+        request: device_properties::GetUseAutoplayVolumeRequest,
+    ) -> Result<device_properties::GetUseAutoplayVolumeResponse> {
+        self.action(&device_properties::SERVICE_TYPE, "GetUseAutoplayVolume", request)
+            .await
+    }
+    async fn get_zone_attributes(
+        &self,
+    ) -> Result<device_properties::GetZoneAttributesResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetZoneAttributes",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_zone_info(&self) -> Result<device_properties::GetZoneInfoResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "GetZoneInfo",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn remove_bonded_zones(
+        &self,
+        request: device_properties::RemoveBondedZonesRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "RemoveBondedZones", request).await
+    }
+    async fn remove_ht_satellite(
+        &self,
+        request: device_properties::RemoveHtSatelliteRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "RemoveHTSatellite", request).await
+    }
+    async fn room_detection_start_chirping(
+        &self,
+        request: device_properties::RoomDetectionStartChirpingRequest,
+    ) -> Result<device_properties::RoomDetectionStartChirpingResponse> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "RoomDetectionStartChirping",
+                request,
+            )
+            .await
+    }
+    async fn room_detection_stop_chirping(
+        &self,
+        request: device_properties::RoomDetectionStopChirpingRequest,
+    ) -> Result<()> {
+        self.action(
+                &device_properties::SERVICE_TYPE,
+                "RoomDetectionStopChirping",
+                request,
+            )
+            .await
+    }
+    async fn separate_stereo_pair(
+        &self,
+        request: device_properties::SeparateStereoPairRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SeparateStereoPair", request)
+            .await
+    }
+    async fn set_autoplay_linked_zones(
+        &self,
+        request: device_properties::SetAutoplayLinkedZonesRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetAutoplayLinkedZones", request)
+            .await
+    }
+    async fn set_autoplay_room_uuid(
+        &self,
+        request: device_properties::SetAutoplayRoomUuidRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetAutoplayRoomUUID", request)
+            .await
+    }
+    async fn set_autoplay_volume(
+        &self,
+        request: device_properties::SetAutoplayVolumeRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetAutoplayVolume", request).await
+    }
+    async fn set_button_lock_state(
+        &self,
+        request: device_properties::SetButtonLockStateRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetButtonLockState", request)
+            .await
+    }
+    async fn set_led_state(
+        &self,
+        request: device_properties::SetLedStateRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetLEDState", request).await
+    }
+    async fn set_use_autoplay_volume(
+        &self,
+        request: device_properties::SetUseAutoplayVolumeRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetUseAutoplayVolume", request)
+            .await
+    }
+    async fn set_zone_attributes(
+        &self,
+        request: device_properties::SetZoneAttributesRequest,
+    ) -> Result<()> {
+        self.action(&device_properties::SERVICE_TYPE, "SetZoneAttributes", request).await
+    }
+}
+/// A variant of `DeviceProperties` that can be used as a trait object,
+/// eg: `Box<dyn DevicePropertiesDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait DevicePropertiesDyn: Send + Sync {
+    /// See `DeviceProperties::add_bonded_zones`
+    fn add_bonded_zones(
+        &self,
+        request: device_properties::AddBondedZonesRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::add_ht_satellite`
+    fn add_ht_satellite(
+        &self,
+        request: device_properties::AddHtSatelliteRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::create_stereo_pair`
+    fn create_stereo_pair(
+        &self,
+        request: device_properties::CreateStereoPairRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::enter_config_mode`
+    fn enter_config_mode(
+        &self,
+        request: device_properties::EnterConfigModeRequest,
+    ) -> ActionFuture<'_, device_properties::EnterConfigModeResponse>;
+    /// See `DeviceProperties::exit_config_mode`
+    fn exit_config_mode(
+        &self,
+        request: device_properties::ExitConfigModeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::get_autoplay_linked_zones`
+    fn get_autoplay_linked_zones(
+        &self,
+        request: device_properties::GetAutoplayLinkedZonesRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayLinkedZonesResponse>;
+    /// See `DeviceProperties::get_autoplay_room_uuid`
+    fn get_autoplay_room_uuid(
+        &self,
+        request: device_properties::GetAutoplayRoomUuidRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayRoomUuidResponse>;
+    /// See `DeviceProperties::get_autoplay_volume`
+    fn get_autoplay_volume(
+        &self,
+        request: device_properties::GetAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayVolumeResponse>;
+    /// See `DeviceProperties::get_button_lock_state`
+    fn get_button_lock_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetButtonLockStateResponse>;
+    /// See `DeviceProperties::get_button_state`
+    fn get_button_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetButtonStateResponse>;
+    /// See `DeviceProperties::get_ht_forward_state`
+    fn get_ht_forward_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetHtForwardStateResponse>;
+    /// See `DeviceProperties::get_household_id`
+    fn get_household_id(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetHouseholdIdResponse>;
+    /// See `DeviceProperties::get_led_state`
+    fn get_led_state(&self) -> ActionFuture<'_, device_properties::GetLedStateResponse>;
+    /// See `DeviceProperties::get_use_autoplay_volume`
+    fn get_use_autoplay_volume(
+        &self,
+        request: device_properties::GetUseAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, device_properties::GetUseAutoplayVolumeResponse>;
+    /// See `DeviceProperties::get_zone_attributes`
+    fn get_zone_attributes(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetZoneAttributesResponse>;
+    /// See `DeviceProperties::get_zone_info`
+    fn get_zone_info(&self) -> ActionFuture<'_, device_properties::GetZoneInfoResponse>;
+    /// See `DeviceProperties::remove_bonded_zones`
+    fn remove_bonded_zones(
+        &self,
+        request: device_properties::RemoveBondedZonesRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::remove_ht_satellite`
+    fn remove_ht_satellite(
+        &self,
+        request: device_properties::RemoveHtSatelliteRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::room_detection_start_chirping`
+    fn room_detection_start_chirping(
+        &self,
+        request: device_properties::RoomDetectionStartChirpingRequest,
+    ) -> ActionFuture<'_, device_properties::RoomDetectionStartChirpingResponse>;
+    /// See `DeviceProperties::room_detection_stop_chirping`
+    fn room_detection_stop_chirping(
+        &self,
+        request: device_properties::RoomDetectionStopChirpingRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::separate_stereo_pair`
+    fn separate_stereo_pair(
+        &self,
+        request: device_properties::SeparateStereoPairRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_autoplay_linked_zones`
+    fn set_autoplay_linked_zones(
+        &self,
+        request: device_properties::SetAutoplayLinkedZonesRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_autoplay_room_uuid`
+    fn set_autoplay_room_uuid(
+        &self,
+        request: device_properties::SetAutoplayRoomUuidRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_autoplay_volume`
+    fn set_autoplay_volume(
+        &self,
+        request: device_properties::SetAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_button_lock_state`
+    fn set_button_lock_state(
+        &self,
+        request: device_properties::SetButtonLockStateRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_led_state`
+    fn set_led_state(
+        &self,
+        request: device_properties::SetLedStateRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_use_autoplay_volume`
+    fn set_use_autoplay_volume(
+        &self,
+        request: device_properties::SetUseAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `DeviceProperties::set_zone_attributes`
+    fn set_zone_attributes(
+        &self,
+        request: device_properties::SetZoneAttributesRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl DevicePropertiesDyn for SonosDevice {
+    fn add_bonded_zones(
+        &self,
+        request: device_properties::AddBondedZonesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::add_bonded_zones(self, request))
+    }
+    fn add_ht_satellite(
+        &self,
+        request: device_properties::AddHtSatelliteRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::add_ht_satellite(self, request))
+    }
+    fn create_stereo_pair(
+        &self,
+        request: device_properties::CreateStereoPairRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::create_stereo_pair(self, request))
+    }
+    fn enter_config_mode(
+        &self,
+        request: device_properties::EnterConfigModeRequest,
+    ) -> ActionFuture<'_, device_properties::EnterConfigModeResponse> {
+        Box::pin(<Self as DeviceProperties>::enter_config_mode(self, request))
+    }
+    fn exit_config_mode(
+        &self,
+        request: device_properties::ExitConfigModeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::exit_config_mode(self, request))
+    }
+    fn get_autoplay_linked_zones(
+        &self,
+        request: device_properties::GetAutoplayLinkedZonesRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayLinkedZonesResponse> {
+        Box::pin(<Self as DeviceProperties>::get_autoplay_linked_zones(self, request))
+    }
+    fn get_autoplay_room_uuid(
+        &self,
+        request: device_properties::GetAutoplayRoomUuidRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayRoomUuidResponse> {
+        Box::pin(<Self as DeviceProperties>::get_autoplay_room_uuid(self, request))
+    }
+    fn get_autoplay_volume(
+        &self,
+        request: device_properties::GetAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, device_properties::GetAutoplayVolumeResponse> {
+        Box::pin(<Self as DeviceProperties>::get_autoplay_volume(self, request))
+    }
+    fn get_button_lock_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetButtonLockStateResponse> {
+        Box::pin(<Self as DeviceProperties>::get_button_lock_state(self))
+    }
+    fn get_button_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetButtonStateResponse> {
+        Box::pin(<Self as DeviceProperties>::get_button_state(self))
+    }
+    fn get_ht_forward_state(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetHtForwardStateResponse> {
+        Box::pin(<Self as DeviceProperties>::get_ht_forward_state(self))
+    }
+    fn get_household_id(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetHouseholdIdResponse> {
+        Box::pin(<Self as DeviceProperties>::get_household_id(self))
+    }
+    fn get_led_state(&self) -> ActionFuture<'_, device_properties::GetLedStateResponse> {
+        Box::pin(<Self as DeviceProperties>::get_led_state(self))
+    }
+    fn get_use_autoplay_volume(
+        &self,
+        request: device_properties::GetUseAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, device_properties::GetUseAutoplayVolumeResponse> {
+        Box::pin(<Self as DeviceProperties>::get_use_autoplay_volume(self, request))
+    }
+    fn get_zone_attributes(
+        &self,
+    ) -> ActionFuture<'_, device_properties::GetZoneAttributesResponse> {
+        Box::pin(<Self as DeviceProperties>::get_zone_attributes(self))
+    }
+    fn get_zone_info(&self) -> ActionFuture<'_, device_properties::GetZoneInfoResponse> {
+        Box::pin(<Self as DeviceProperties>::get_zone_info(self))
+    }
+    fn remove_bonded_zones(
+        &self,
+        request: device_properties::RemoveBondedZonesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::remove_bonded_zones(self, request))
+    }
+    fn remove_ht_satellite(
+        &self,
+        request: device_properties::RemoveHtSatelliteRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::remove_ht_satellite(self, request))
+    }
+    fn room_detection_start_chirping(
+        &self,
+        request: device_properties::RoomDetectionStartChirpingRequest,
+    ) -> ActionFuture<'_, device_properties::RoomDetectionStartChirpingResponse> {
+        Box::pin(
+            <Self as DeviceProperties>::room_detection_start_chirping(self, request),
+        )
+    }
+    fn room_detection_stop_chirping(
+        &self,
+        request: device_properties::RoomDetectionStopChirpingRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::room_detection_stop_chirping(self, request))
+    }
+    fn separate_stereo_pair(
+        &self,
+        request: device_properties::SeparateStereoPairRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::separate_stereo_pair(self, request))
+    }
+    fn set_autoplay_linked_zones(
+        &self,
+        request: device_properties::SetAutoplayLinkedZonesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_autoplay_linked_zones(self, request))
+    }
+    fn set_autoplay_room_uuid(
+        &self,
+        request: device_properties::SetAutoplayRoomUuidRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_autoplay_room_uuid(self, request))
+    }
+    fn set_autoplay_volume(
+        &self,
+        request: device_properties::SetAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_autoplay_volume(self, request))
+    }
+    fn set_button_lock_state(
+        &self,
+        request: device_properties::SetButtonLockStateRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_button_lock_state(self, request))
+    }
+    fn set_led_state(
+        &self,
+        request: device_properties::SetLedStateRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_led_state(self, request))
+    }
+    fn set_use_autoplay_volume(
+        &self,
+        request: device_properties::SetUseAutoplayVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_use_autoplay_volume(self, request))
+    }
+    fn set_zone_attributes(
+        &self,
+        request: device_properties::SetZoneAttributesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as DeviceProperties>::set_zone_attributes(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_add_bonded_zones_request() {
+        let request = AddBondedZonesRequest {
+            channel_map_set: "ChannelMapSet".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddBondedZonesRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_ht_satellite_request() {
+        let request = AddHtSatelliteRequest {
+            ht_sat_chan_map_set: "HTSatChanMapSet".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddHtSatelliteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_stereo_pair_request() {
+        let request = CreateStereoPairRequest {
+            channel_map_set: "ChannelMapSet".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CreateStereoPairRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_enter_config_mode_request() {
+        let request = EnterConfigModeRequest {
+            mode: "Mode".to_string(),
+            options: "Options".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: EnterConfigModeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_enter_config_mode_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:EnterConfigModeResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><State>State</State></u:EnterConfigModeResponse></s:Body></s:Envelope>"#;
+        let expected = EnterConfigModeResponse {
+            state: Some("State".to_string()),
+        };
+        let decoded = <EnterConfigModeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_exit_config_mode_request() {
+        let request = ExitConfigModeRequest {
+            options: "Options".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ExitConfigModeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_autoplay_linked_zones_request() {
+        let request = GetAutoplayLinkedZonesRequest {
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetAutoplayLinkedZonesRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_autoplay_linked_zones_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAutoplayLinkedZonesResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><IncludeLinkedZones>1</IncludeLinkedZones></u:GetAutoplayLinkedZonesResponse></s:Body></s:Envelope>"#;
+        let expected = GetAutoplayLinkedZonesResponse {
+            include_linked_zones: Some(true),
+        };
+        let decoded = <GetAutoplayLinkedZonesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_autoplay_room_uuid_request() {
+        let request = GetAutoplayRoomUuidRequest {
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetAutoplayRoomUuidRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_autoplay_room_uuid_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAutoplayRoomUUIDResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><RoomUUID>RoomUUID</RoomUUID></u:GetAutoplayRoomUUIDResponse></s:Body></s:Envelope>"#;
+        let expected = GetAutoplayRoomUuidResponse {
+            room_uuid: Some("RoomUUID".to_string()),
+        };
+        let decoded = <GetAutoplayRoomUuidResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_autoplay_volume_request() {
+        let request = GetAutoplayVolumeRequest {
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetAutoplayVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_autoplay_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetAutoplayVolumeResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentVolume>1</CurrentVolume></u:GetAutoplayVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = GetAutoplayVolumeResponse {
+            current_volume: Some(1),
+        };
+        let decoded = <GetAutoplayVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_button_lock_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetButtonLockStateResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentButtonLockState>Off</CurrentButtonLockState></u:GetButtonLockStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetButtonLockStateResponse {
+            current_button_lock_state: Some(ButtonLockState::Off),
+        };
+        let decoded = <GetButtonLockStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_button_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetButtonStateResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><State>State</State></u:GetButtonStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetButtonStateResponse {
+            state: Some("State".to_string()),
+        };
+        let decoded = <GetButtonStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_ht_forward_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetHTForwardStateResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><IsHTForwardEnabled>1</IsHTForwardEnabled></u:GetHTForwardStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetHtForwardStateResponse {
+            is_ht_forward_enabled: Some(true),
+        };
+        let decoded = <GetHtForwardStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_household_id_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetHouseholdIDResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentHouseholdID>CurrentHouseholdID</CurrentHouseholdID></u:GetHouseholdIDResponse></s:Body></s:Envelope>"#;
+        let expected = GetHouseholdIdResponse {
+            current_household_id: Some("CurrentHouseholdID".to_string()),
+        };
+        let decoded = <GetHouseholdIdResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_led_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetLEDStateResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentLEDState>Off</CurrentLEDState></u:GetLEDStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetLedStateResponse {
+            current_led_state: Some(LEDState::Off),
+        };
+        let decoded = <GetLedStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_use_autoplay_volume_request() {
+        let request = GetUseAutoplayVolumeRequest {
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetUseAutoplayVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_use_autoplay_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetUseAutoplayVolumeResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><UseVolume>1</UseVolume></u:GetUseAutoplayVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = GetUseAutoplayVolumeResponse {
+            use_volume: Some(true),
+        };
+        let decoded = <GetUseAutoplayVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_zone_attributes_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneAttributesResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentZoneName>CurrentZoneName</CurrentZoneName><CurrentIcon>CurrentIcon</CurrentIcon><CurrentConfiguration>CurrentConfiguration</CurrentConfiguration><CurrentTargetRoomName>CurrentTargetRoomName</CurrentTargetRoomName></u:GetZoneAttributesResponse></s:Body></s:Envelope>"#;
+        let expected = GetZoneAttributesResponse {
+            current_zone_name: Some("CurrentZoneName".to_string()),
+            current_icon: Some("CurrentIcon".to_string()),
+            current_configuration: Some("CurrentConfiguration".to_string()),
+            current_target_room_name: Some("CurrentTargetRoomName".to_string()),
+        };
+        let decoded = <GetZoneAttributesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/DeviceProperties/GetZoneAttributes.xml`
+    #[test]
+    fn test_get_zone_attributes_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneAttributesResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><CurrentZoneName>Living Room</CurrentZoneName><CurrentIcon>x-rincon-roomicon:living</CurrentIcon><CurrentConfiguration>1</CurrentConfiguration><CurrentTargetRoomName>Living Room</CurrentTargetRoomName></u:GetZoneAttributesResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetZoneAttributesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(
+            decoded.current_zone_name, Some("Living Room".to_string()), "CurrentZoneName"
+        );
+        assert_eq!(
+            decoded.current_icon, Some("x-rincon-roomicon:living".to_string()),
+            "CurrentIcon"
+        );
+        assert_eq!(
+            decoded.current_configuration, Some("1".to_string()), "CurrentConfiguration"
+        );
+        assert_eq!(
+            decoded.current_target_room_name, Some("Living Room".to_string()),
+            "CurrentTargetRoomName"
+        );
+    }
+    #[test]
+    fn test_get_zone_info_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneInfoResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><SerialNumber>SerialNumber</SerialNumber><SoftwareVersion>SoftwareVersion</SoftwareVersion><DisplaySoftwareVersion>DisplaySoftwareVersion</DisplaySoftwareVersion><HardwareVersion>HardwareVersion</HardwareVersion><IPAddress>IPAddress</IPAddress><MACAddress>MACAddress</MACAddress><CopyrightInfo>CopyrightInfo</CopyrightInfo><ExtraInfo>ExtraInfo</ExtraInfo><HTAudioIn>1</HTAudioIn><Flags>1</Flags></u:GetZoneInfoResponse></s:Body></s:Envelope>"#;
+        let expected = GetZoneInfoResponse {
+            serial_number: Some("SerialNumber".to_string()),
+            software_version: Some("SoftwareVersion".to_string()),
+            display_software_version: Some("DisplaySoftwareVersion".to_string()),
+            hardware_version: Some("HardwareVersion".to_string()),
+            ip_address: Some("IPAddress".to_string()),
+            mac_address: Some("MACAddress".to_string()),
+            copyright_info: Some("CopyrightInfo".to_string()),
+            extra_info: Some("ExtraInfo".to_string()),
+            ht_audio_in: Some(1),
+            flags: Some(1),
+        };
+        let decoded = <GetZoneInfoResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_remove_bonded_zones_request() {
+        let request = RemoveBondedZonesRequest {
+            channel_map_set: "ChannelMapSet".to_string(),
+            keep_grouped: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveBondedZonesRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_ht_satellite_request() {
+        let request = RemoveHtSatelliteRequest {
+            sat_room_uuid: "SatRoomUUID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveHtSatelliteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_room_detection_start_chirping_request() {
+        let request = RoomDetectionStartChirpingRequest {
+            channel: 1,
+            duration_milliseconds: 1,
+            chirp_if_playing_swappable_audio: Some(true),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RoomDetectionStartChirpingRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_room_detection_start_chirping_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:RoomDetectionStartChirpingResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><PlayId>1</PlayId><ChirpIfPlayingSwappableAudio>1</ChirpIfPlayingSwappableAudio></u:RoomDetectionStartChirpingResponse></s:Body></s:Envelope>"#;
+        let expected = RoomDetectionStartChirpingResponse {
+            play_id: Some(1),
+            chirp_if_playing_swappable_audio: Some(true),
+        };
+        let decoded = <RoomDetectionStartChirpingResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_room_detection_stop_chirping_request() {
+        let request = RoomDetectionStopChirpingRequest {
+            play_id: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RoomDetectionStopChirpingRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_separate_stereo_pair_request() {
+        let request = SeparateStereoPairRequest {
+            channel_map_set: "ChannelMapSet".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SeparateStereoPairRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_autoplay_linked_zones_request() {
+        let request = SetAutoplayLinkedZonesRequest {
+            include_linked_zones: true,
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAutoplayLinkedZonesRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_autoplay_room_uuid_request() {
+        let request = SetAutoplayRoomUuidRequest {
+            room_uuid: "RoomUUID".to_string(),
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAutoplayRoomUuidRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_autoplay_volume_request() {
+        let request = SetAutoplayVolumeRequest {
+            volume: 1,
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAutoplayVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_button_lock_state_request() {
+        let request = SetButtonLockStateRequest {
+            desired_button_lock_state: ButtonLockState::Off,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetButtonLockStateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_led_state_request() {
+        let request = SetLedStateRequest {
+            desired_led_state: LEDState::Off,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetLedStateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_use_autoplay_volume_request() {
+        let request = SetUseAutoplayVolumeRequest {
+            use_volume: true,
+            source: "Source".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetUseAutoplayVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_zone_attributes_request() {
+        let request = SetZoneAttributesRequest {
+            desired_zone_name: "DesiredZoneName".to_string(),
+            desired_icon: "DesiredIcon".to_string(),
+            desired_configuration: "DesiredConfiguration".to_string(),
+            desired_target_room_name: Some("DesiredTargetRoomName".to_string()),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetZoneAttributesRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
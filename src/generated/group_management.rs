@@ -0,0 +1,291 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `GroupManagement` service.
+use super::*;
+/// URN for the `GroupManagement` service.
+/// `urn:schemas-upnp-org:service:GroupManagement:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:GroupManagement:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMember", ns(SERVICE_TYPE))]
+pub struct AddMemberRequest {
+    #[xml(rename = "MemberID", ns(""))]
+    pub member_id: String,
+    #[xml(rename = "BootSeq", ns(""))]
+    pub boot_seq: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMemberResponse", ns(SERVICE_TYPE))]
+pub struct AddMemberResponse {
+    #[xml(rename = "CurrentTransportSettings", ns(""))]
+    pub current_transport_settings: Option<String>,
+    #[xml(rename = "CurrentURI", ns(""))]
+    pub current_uri: Option<String>,
+    #[xml(rename = "GroupUUIDJoined", ns(""))]
+    pub group_uuid_joined: Option<String>,
+    #[xml(rename = "ResetVolumeAfter", ns(""))]
+    pub reset_volume_after: Option<bool>,
+    #[xml(rename = "VolumeAVTransportURI", ns(""))]
+    pub volume_av_transport_uri: Option<String>,
+}
+impl crate::DecodeSoapResponse for AddMemberResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveMember", ns(SERVICE_TYPE))]
+pub struct RemoveMemberRequest {
+    #[xml(rename = "MemberID", ns(""))]
+    pub member_id: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReportTrackBufferingResult", ns(SERVICE_TYPE))]
+pub struct ReportTrackBufferingResultRequest {
+    #[xml(rename = "MemberID", ns(""))]
+    pub member_id: String,
+    #[xml(rename = "ResultCode", ns(""))]
+    pub result_code: i32,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetSourceAreaIds", ns(SERVICE_TYPE))]
+pub struct SetSourceAreaIdsRequest {
+    #[xml(rename = "DesiredSourceAreaIds", ns(""))]
+    pub desired_source_area_ids: String,
+}
+/// A parsed event produced by the `GroupManagement` service.
+/// Use `SonosDevice::subscribe_group_management()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupManagementEvent {
+    pub group_coordinator_is_local: Option<bool>,
+    pub local_group_uuid: Option<String>,
+    pub reset_volume_after: Option<bool>,
+    pub virtual_line_in_group_id: Option<String>,
+    pub volume_av_transport_uri: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct GroupManagementPropertySet {
+    pub properties: Vec<GroupManagementProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct GroupManagementProperty {
+    #[xml(rename = "GroupCoordinatorIsLocal", ns(""))]
+    pub group_coordinator_is_local: Option<bool>,
+    #[xml(rename = "LocalGroupUUID", ns(""))]
+    pub local_group_uuid: Option<String>,
+    #[xml(rename = "ResetVolumeAfter", ns(""))]
+    pub reset_volume_after: Option<bool>,
+    #[xml(rename = "VirtualLineInGroupID", ns(""))]
+    pub virtual_line_in_group_id: Option<String>,
+    #[xml(rename = "VolumeAVTransportURI", ns(""))]
+    pub volume_av_transport_uri: Option<String>,
+}
+impl DecodeXml for GroupManagementEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: GroupManagementPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.group_coordinator_is_local {
+                result.group_coordinator_is_local.replace(v);
+            }
+            if let Some(v) = prop.local_group_uuid {
+                result.local_group_uuid.replace(v);
+            }
+            if let Some(v) = prop.reset_volume_after {
+                result.reset_volume_after.replace(v);
+            }
+            if let Some(v) = prop.virtual_line_in_group_id {
+                result.virtual_line_in_group_id.replace(v);
+            }
+            if let Some(v) = prop.volume_av_transport_uri {
+                result.volume_av_transport_uri.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `GroupManagement` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_group_management(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<GroupManagementEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Services related to groups
+pub trait GroupManagement {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_member(
+        &self,
+        request: group_management::AddMemberRequest,
+    ) -> Result<group_management::AddMemberResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_member(
+        &self,
+        request: group_management::RemoveMemberRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn report_track_buffering_result(
+        &self,
+        request: group_management::ReportTrackBufferingResultRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_source_area_ids(
+        &self,
+        request: group_management::SetSourceAreaIdsRequest,
+    ) -> Result<()>;
+}
+impl GroupManagement for SonosDevice {
+    async fn add_member(
+        &self,
+        request: group_management::AddMemberRequest,
+    ) -> Result<group_management::AddMemberResponse> {
+        self.action(&group_management::SERVICE_TYPE, "AddMember", request).await
+    }
+    async fn remove_member(
+        &self,
+        request: group_management::RemoveMemberRequest,
+    ) -> Result<()> {
+        self.action(&group_management::SERVICE_TYPE, "RemoveMember", request).await
+    }
+    async fn report_track_buffering_result(
+        &self,
+        request: group_management::ReportTrackBufferingResultRequest,
+    ) -> Result<()> {
+        self.action(
+                &group_management::SERVICE_TYPE,
+                "ReportTrackBufferingResult",
+                request,
+            )
+            .await
+    }
+    async fn set_source_area_ids(
+        &self,
+        request: group_management::SetSourceAreaIdsRequest,
+    ) -> Result<()> {
+        self.action(&group_management::SERVICE_TYPE, "SetSourceAreaIds", request).await
+    }
+}
+/// A variant of `GroupManagement` that can be used as a trait object,
+/// eg: `Box<dyn GroupManagementDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait GroupManagementDyn: Send + Sync {
+    /// See `GroupManagement::add_member`
+    fn add_member(
+        &self,
+        request: group_management::AddMemberRequest,
+    ) -> ActionFuture<'_, group_management::AddMemberResponse>;
+    /// See `GroupManagement::remove_member`
+    fn remove_member(
+        &self,
+        request: group_management::RemoveMemberRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `GroupManagement::report_track_buffering_result`
+    fn report_track_buffering_result(
+        &self,
+        request: group_management::ReportTrackBufferingResultRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `GroupManagement::set_source_area_ids`
+    fn set_source_area_ids(
+        &self,
+        request: group_management::SetSourceAreaIdsRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl GroupManagementDyn for SonosDevice {
+    fn add_member(
+        &self,
+        request: group_management::AddMemberRequest,
+    ) -> ActionFuture<'_, group_management::AddMemberResponse> {
+        Box::pin(<Self as GroupManagement>::add_member(self, request))
+    }
+    fn remove_member(
+        &self,
+        request: group_management::RemoveMemberRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupManagement>::remove_member(self, request))
+    }
+    fn report_track_buffering_result(
+        &self,
+        request: group_management::ReportTrackBufferingResultRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupManagement>::report_track_buffering_result(self, request))
+    }
+    fn set_source_area_ids(
+        &self,
+        request: group_management::SetSourceAreaIdsRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupManagement>::set_source_area_ids(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_add_member_request() {
+        let request = AddMemberRequest {
+            member_id: "MemberID".to_string(),
+            boot_seq: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddMemberRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_member_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddMemberResponse xmlns:u="urn:schemas-upnp-org:service:GroupManagement:1"><CurrentTransportSettings>CurrentTransportSettings</CurrentTransportSettings><CurrentURI>CurrentURI</CurrentURI><GroupUUIDJoined>GroupUUIDJoined</GroupUUIDJoined><ResetVolumeAfter>1</ResetVolumeAfter><VolumeAVTransportURI>VolumeAVTransportURI</VolumeAVTransportURI></u:AddMemberResponse></s:Body></s:Envelope>"#;
+        let expected = AddMemberResponse {
+            current_transport_settings: Some("CurrentTransportSettings".to_string()),
+            current_uri: Some("CurrentURI".to_string()),
+            group_uuid_joined: Some("GroupUUIDJoined".to_string()),
+            reset_volume_after: Some(true),
+            volume_av_transport_uri: Some("VolumeAVTransportURI".to_string()),
+        };
+        let decoded = <AddMemberResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_remove_member_request() {
+        let request = RemoveMemberRequest {
+            member_id: "MemberID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveMemberRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_report_track_buffering_result_request() {
+        let request = ReportTrackBufferingResultRequest {
+            member_id: "MemberID".to_string(),
+            result_code: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReportTrackBufferingResultRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_source_area_ids_request() {
+        let request = SetSourceAreaIdsRequest {
+            desired_source_area_ids: "DesiredSourceAreaIds".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetSourceAreaIdsRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
@@ -0,0 +1,572 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `GroupRenderingControl` service.
+use super::*;
+/// URN for the `GroupRenderingControl` service.
+/// `urn:schemas-upnp-org:service:GroupRenderingControl:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:GroupRenderingControl:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetGroupMute", ns(SERVICE_TYPE))]
+pub struct GetGroupMuteRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetGroupMuteResponse", ns(SERVICE_TYPE))]
+pub struct GetGroupMuteResponse {
+    #[xml(rename = "CurrentMute", ns(""))]
+    pub current_mute: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetGroupMuteResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetGroupVolume", ns(SERVICE_TYPE))]
+pub struct GetGroupVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetGroupVolumeResponse", ns(SERVICE_TYPE))]
+pub struct GetGroupVolumeResponse {
+    #[xml(rename = "CurrentVolume", ns(""))]
+    pub current_volume: Option<super::GroupVolume>,
+}
+impl crate::DecodeSoapResponse for GetGroupVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetGroupMute", ns(SERVICE_TYPE))]
+pub struct SetGroupMuteRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "DesiredMute", ns(""))]
+    pub desired_mute: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetGroupVolume", ns(SERVICE_TYPE))]
+pub struct SetGroupVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// New volume between 0 and 100
+    #[xml(rename = "DesiredVolume", ns(""))]
+    pub desired_volume: super::GroupVolume,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRelativeGroupVolume", ns(SERVICE_TYPE))]
+pub struct SetRelativeGroupVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Number between -100 and +100
+    #[xml(rename = "Adjustment", ns(""))]
+    pub adjustment: i32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRelativeGroupVolumeResponse", ns(SERVICE_TYPE))]
+pub struct SetRelativeGroupVolumeResponse {
+    #[xml(rename = "NewVolume", ns(""))]
+    pub new_volume: Option<super::GroupVolume>,
+}
+impl crate::DecodeSoapResponse for SetRelativeGroupVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SnapshotGroupVolume", ns(SERVICE_TYPE))]
+pub struct SnapshotGroupVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+/// A parsed event produced by the `GroupRenderingControl` service.
+/// Use `SonosDevice::subscribe_group_rendering_control()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupRenderingControlEvent {
+    pub group_mute: Option<bool>,
+    pub group_volume: Option<super::GroupVolume>,
+    pub group_volume_changeable: Option<bool>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct GroupRenderingControlPropertySet {
+    pub properties: Vec<GroupRenderingControlProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct GroupRenderingControlProperty {
+    #[xml(rename = "GroupMute", ns(""))]
+    pub group_mute: Option<bool>,
+    #[xml(rename = "GroupVolume", ns(""))]
+    pub group_volume: Option<super::GroupVolume>,
+    #[xml(rename = "GroupVolumeChangeable", ns(""))]
+    pub group_volume_changeable: Option<bool>,
+}
+impl DecodeXml for GroupRenderingControlEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: GroupRenderingControlPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.group_mute {
+                result.group_mute.replace(v);
+            }
+            if let Some(v) = prop.group_volume {
+                result.group_volume.replace(v);
+            }
+            if let Some(v) = prop.group_volume_changeable {
+                result.group_volume_changeable.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `GroupRenderingControl` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_group_rendering_control(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<GroupRenderingControlEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+/// Errors that are documented for the `GroupRenderingControl` service.
+/// Use `Error::from_error` to obtain one from the error returned
+/// by a method of this service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// 701: Player isn't the coordinator
+    PlayerIsntTheCoordinator,
+    /// An error code that is not documented for this service
+    Other(u32),
+}
+impl From<u32> for Error {
+    fn from(code: u32) -> Self {
+        match code {
+            701 => Self::PlayerIsntTheCoordinator,
+            code => Self::Other(code),
+        }
+    }
+}
+impl Error {
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::PlayerIsntTheCoordinator => 701,
+            Self::Other(code) => *code,
+        }
+    }
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            Self::PlayerIsntTheCoordinator => Some("Player isn't the coordinator"),
+            Self::Other(_) => None,
+        }
+    }
+    /// Returns the documented error if `err` is a fault
+    /// reported by this service
+    pub fn from_error(err: &crate::Error) -> Option<Self> {
+        match err {
+            crate::Error::Upnp(e) if e.service == SERVICE_TYPE => Some(e.code.into()),
+            _ => None,
+        }
+    }
+}
+/// A `GroupVolume` value, in the range `0..=100`.
+/// Values are checked when they are constructed via `new` or
+/// `try_from`, so that an out of range value is reported before
+/// any request is made. Values reported by the device, or read
+/// back via serde, are accepted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct GroupVolume(u16);
+impl GroupVolume {
+    pub const MIN: u16 = 0;
+    pub const MAX: u16 = 100;
+    /// Returns `Error::OutOfRange` if `value` is outside
+    /// of the range `MIN..=MAX`
+    pub fn new(value: u16) -> Result<Self> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(crate::Error::OutOfRange {
+                name: "GroupVolume",
+                value: value.into(),
+                min: Self::MIN.into(),
+                max: Self::MAX.into(),
+            })
+        }
+    }
+    /// Returns the nearest value that is in range
+    pub fn saturating(value: u16) -> Self {
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+impl TryFrom<u16> for GroupVolume {
+    type Error = crate::Error;
+    fn try_from(value: u16) -> Result<Self> {
+        Self::new(value)
+    }
+}
+impl From<GroupVolume> for u16 {
+    fn from(value: GroupVolume) -> u16 {
+        value.0
+    }
+}
+impl std::fmt::Display for GroupVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl FromStr for GroupVolume {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<GroupVolume> {
+        let value: u16 = s
+            .parse()
+            .map_err(|_| crate::Error::InvalidNumber {
+                name: "GroupVolume",
+                value: s.to_string(),
+            })?;
+        Self::new(value)
+    }
+}
+impl instant_xml::ToXml for GroupVolume {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for GroupVolume {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: u16 = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(GroupVolume(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<GroupVolume>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+#[allow(async_fn_in_trait)]
+/// Volume related controls for groups
+pub trait GroupRenderingControl {
+    /// Get the group mute state.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_group_mute(
+        &self,
+        request: group_rendering_control::GetGroupMuteRequest,
+    ) -> Result<group_rendering_control::GetGroupMuteResponse>;
+    /// Get the group volume.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_group_volume(
+        &self,
+        request: group_rendering_control::GetGroupVolumeRequest,
+    ) -> Result<group_rendering_control::GetGroupVolumeResponse>;
+    /// (Un-/)Mute the entire group
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_group_mute(
+        &self,
+        request: group_rendering_control::SetGroupMuteRequest,
+    ) -> Result<()>;
+    /// Change group volume. Players volume will be changed proportionally based on last snapshot
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_group_volume(
+        &self,
+        request: group_rendering_control::SetGroupVolumeRequest,
+    ) -> Result<()>;
+    /// Relatively change group volume - returns final group volume. Players volume will be changed proportionally based on last snapshot
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_relative_group_volume(
+        &self,
+        request: group_rendering_control::SetRelativeGroupVolumeRequest,
+    ) -> Result<group_rendering_control::SetRelativeGroupVolumeResponse>;
+    /// Creates a new group volume snapshot,  the volume ratio between all players. It is used by SetGroupVolume and SetRelativeGroupVolume
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn snapshot_group_volume(
+        &self,
+        request: group_rendering_control::SnapshotGroupVolumeRequest,
+    ) -> Result<()>;
+}
+impl GroupRenderingControl for SonosDevice {
+    async fn get_group_mute(
+        &self,
+        request: group_rendering_control::GetGroupMuteRequest,
+    ) -> Result<group_rendering_control::GetGroupMuteResponse> {
+        self.action(&group_rendering_control::SERVICE_TYPE, "GetGroupMute", request)
+            .await
+    }
+    async fn get_group_volume(
+        &self,
+        request: group_rendering_control::GetGroupVolumeRequest,
+    ) -> Result<group_rendering_control::GetGroupVolumeResponse> {
+        self.action(&group_rendering_control::SERVICE_TYPE, "GetGroupVolume", request)
+            .await
+    }
+    async fn set_group_mute(
+        &self,
+        request: group_rendering_control::SetGroupMuteRequest,
+    ) -> Result<()> {
+        self.action(&group_rendering_control::SERVICE_TYPE, "SetGroupMute", request)
+            .await
+    }
+    async fn set_group_volume(
+        &self,
+        request: group_rendering_control::SetGroupVolumeRequest,
+    ) -> Result<()> {
+        self.action(&group_rendering_control::SERVICE_TYPE, "SetGroupVolume", request)
+            .await
+    }
+    async fn set_relative_group_volume(
+        &self,
+        request: group_rendering_control::SetRelativeGroupVolumeRequest,
+    ) -> Result<group_rendering_control::SetRelativeGroupVolumeResponse> {
+        self.action(
+                &group_rendering_control::SERVICE_TYPE,
+                "SetRelativeGroupVolume",
+                request,
+            )
+            .await
+    }
+    async fn snapshot_group_volume(
+        &self,
+        request: group_rendering_control::SnapshotGroupVolumeRequest,
+    ) -> Result<()> {
+        self.action(
+                &group_rendering_control::SERVICE_TYPE,
+                "SnapshotGroupVolume",
+                request,
+            )
+            .await
+    }
+}
+/// A variant of `GroupRenderingControl` that can be used as a trait object,
+/// eg: `Box<dyn GroupRenderingControlDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait GroupRenderingControlDyn: Send + Sync {
+    /// See `GroupRenderingControl::get_group_mute`
+    fn get_group_mute(
+        &self,
+        request: group_rendering_control::GetGroupMuteRequest,
+    ) -> ActionFuture<'_, group_rendering_control::GetGroupMuteResponse>;
+    /// See `GroupRenderingControl::get_group_volume`
+    fn get_group_volume(
+        &self,
+        request: group_rendering_control::GetGroupVolumeRequest,
+    ) -> ActionFuture<'_, group_rendering_control::GetGroupVolumeResponse>;
+    /// See `GroupRenderingControl::set_group_mute`
+    fn set_group_mute(
+        &self,
+        request: group_rendering_control::SetGroupMuteRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `GroupRenderingControl::set_group_volume`
+    fn set_group_volume(
+        &self,
+        request: group_rendering_control::SetGroupVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `GroupRenderingControl::set_relative_group_volume`
+    fn set_relative_group_volume(
+        &self,
+        request: group_rendering_control::SetRelativeGroupVolumeRequest,
+    ) -> ActionFuture<'_, group_rendering_control::SetRelativeGroupVolumeResponse>;
+    /// See `GroupRenderingControl::snapshot_group_volume`
+    fn snapshot_group_volume(
+        &self,
+        request: group_rendering_control::SnapshotGroupVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl GroupRenderingControlDyn for SonosDevice {
+    fn get_group_mute(
+        &self,
+        request: group_rendering_control::GetGroupMuteRequest,
+    ) -> ActionFuture<'_, group_rendering_control::GetGroupMuteResponse> {
+        Box::pin(<Self as GroupRenderingControl>::get_group_mute(self, request))
+    }
+    fn get_group_volume(
+        &self,
+        request: group_rendering_control::GetGroupVolumeRequest,
+    ) -> ActionFuture<'_, group_rendering_control::GetGroupVolumeResponse> {
+        Box::pin(<Self as GroupRenderingControl>::get_group_volume(self, request))
+    }
+    fn set_group_mute(
+        &self,
+        request: group_rendering_control::SetGroupMuteRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupRenderingControl>::set_group_mute(self, request))
+    }
+    fn set_group_volume(
+        &self,
+        request: group_rendering_control::SetGroupVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupRenderingControl>::set_group_volume(self, request))
+    }
+    fn set_relative_group_volume(
+        &self,
+        request: group_rendering_control::SetRelativeGroupVolumeRequest,
+    ) -> ActionFuture<'_, group_rendering_control::SetRelativeGroupVolumeResponse> {
+        Box::pin(
+            <Self as GroupRenderingControl>::set_relative_group_volume(self, request),
+        )
+    }
+    fn snapshot_group_volume(
+        &self,
+        request: group_rendering_control::SnapshotGroupVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as GroupRenderingControl>::snapshot_group_volume(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_group_mute_request() {
+        let request = GetGroupMuteRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetGroupMuteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_group_mute_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetGroupMuteResponse xmlns:u="urn:schemas-upnp-org:service:GroupRenderingControl:1"><CurrentMute>1</CurrentMute></u:GetGroupMuteResponse></s:Body></s:Envelope>"#;
+        let expected = GetGroupMuteResponse {
+            current_mute: Some(true),
+        };
+        let decoded = <GetGroupMuteResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_group_volume_request() {
+        let request = GetGroupVolumeRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetGroupVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_group_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetGroupVolumeResponse xmlns:u="urn:schemas-upnp-org:service:GroupRenderingControl:1"><CurrentVolume>1</CurrentVolume></u:GetGroupVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = GetGroupVolumeResponse {
+            current_volume: Some(GroupVolume::new(1).unwrap()),
+        };
+        let decoded = <GetGroupVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_set_group_mute_request() {
+        let request = SetGroupMuteRequest {
+            instance_id: crate::InstanceId(1),
+            desired_mute: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetGroupMuteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_group_volume_request() {
+        let request = SetGroupVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            desired_volume: GroupVolume::new(1).unwrap(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetGroupVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_relative_group_volume_request() {
+        let request = SetRelativeGroupVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            adjustment: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetRelativeGroupVolumeRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_relative_group_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SetRelativeGroupVolumeResponse xmlns:u="urn:schemas-upnp-org:service:GroupRenderingControl:1"><NewVolume>1</NewVolume></u:SetRelativeGroupVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = SetRelativeGroupVolumeResponse {
+            new_volume: Some(GroupVolume::new(1).unwrap()),
+        };
+        let decoded = <SetRelativeGroupVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_snapshot_group_volume_request() {
+        let request = SnapshotGroupVolumeRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SnapshotGroupVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
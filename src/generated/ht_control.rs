@@ -0,0 +1,601 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `HTControl` service.
+use super::*;
+/// URN for the `HTControl` service.
+/// `urn:schemas-upnp-org:service:HTControl:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:HTControl:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CommitLearnedIRCodes", ns(SERVICE_TYPE))]
+pub struct CommitLearnedIrCodesRequest {
+    #[xml(rename = "Name", ns(""))]
+    pub name: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetIRRepeaterStateResponse", ns(SERVICE_TYPE))]
+pub struct GetIrRepeaterStateResponse {
+    #[xml(rename = "CurrentIRRepeaterState", ns(""))]
+    pub current_ir_repeater_state: Option<super::IRRepeaterState>,
+}
+impl crate::DecodeSoapResponse for GetIrRepeaterStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLEDFeedbackStateResponse", ns(SERVICE_TYPE))]
+pub struct GetLedFeedbackStateResponse {
+    #[xml(rename = "LEDFeedbackState", ns(""))]
+    pub led_feedback_state: Option<super::LEDFeedbackState>,
+}
+impl crate::DecodeSoapResponse for GetLedFeedbackStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "IdentifyIRRemote", ns(SERVICE_TYPE))]
+pub struct IdentifyIrRemoteRequest {
+    #[xml(rename = "Timeout", ns(""))]
+    pub timeout: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "IsRemoteConfiguredResponse", ns(SERVICE_TYPE))]
+pub struct IsRemoteConfiguredResponse {
+    #[xml(rename = "RemoteConfigured", ns(""))]
+    pub remote_configured: Option<bool>,
+}
+impl crate::DecodeSoapResponse for IsRemoteConfiguredResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "LearnIRCode", ns(SERVICE_TYPE))]
+pub struct LearnIrCodeRequest {
+    #[xml(rename = "IRCode", ns(""))]
+    pub ir_code: String,
+    #[xml(rename = "Timeout", ns(""))]
+    pub timeout: u32,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetIRRepeaterState", ns(SERVICE_TYPE))]
+pub struct SetIrRepeaterStateRequest {
+    #[xml(rename = "DesiredIRRepeaterState", ns(""))]
+    pub desired_ir_repeater_state: super::IRRepeaterState,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetLEDFeedbackState", ns(SERVICE_TYPE))]
+pub struct SetLedFeedbackStateRequest {
+    #[xml(rename = "LEDFeedbackState", ns(""))]
+    pub led_feedback_state: super::LEDFeedbackState,
+}
+/// A parsed event produced by the `HTControl` service.
+/// Use `SonosDevice::subscribe_ht_control()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HTControlEvent {
+    pub ir_repeater_state: Option<super::IRRepeaterState>,
+    pub tos_link_connected: Option<bool>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct HTControlPropertySet {
+    pub properties: Vec<HTControlProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct HTControlProperty {
+    #[xml(rename = "IRRepeaterState", ns(""))]
+    pub ir_repeater_state: Option<super::IRRepeaterState>,
+    #[xml(rename = "TOSLinkConnected", ns(""))]
+    pub tos_link_connected: Option<bool>,
+}
+impl DecodeXml for HTControlEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: HTControlPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.ir_repeater_state {
+                result.ir_repeater_state.replace(v);
+            }
+            if let Some(v) = prop.tos_link_connected {
+                result.tos_link_connected.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `HTControl` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_ht_control(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<HTControlEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum IRRepeaterState {
+    #[default]
+    On,
+    Off,
+    Disabled,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for IRRepeaterState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            IRRepeaterState::On => "On",
+            IRRepeaterState::Off => "Off",
+            IRRepeaterState::Disabled => "Disabled",
+            IRRepeaterState::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for IRRepeaterState {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<IRRepeaterState> {
+        match s {
+            "On" => Ok(IRRepeaterState::On),
+            "Off" => Ok(IRRepeaterState::Off),
+            "Disabled" => Ok(IRRepeaterState::Disabled),
+            s => Ok(IRRepeaterState::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for IRRepeaterState {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for IRRepeaterState {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: IRRepeaterState = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<IRRepeaterState>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for IRRepeaterState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IRRepeaterState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum LEDFeedbackState {
+    #[default]
+    On,
+    Off,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for LEDFeedbackState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            LEDFeedbackState::On => "On",
+            LEDFeedbackState::Off => "Off",
+            LEDFeedbackState::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for LEDFeedbackState {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<LEDFeedbackState> {
+        match s {
+            "On" => Ok(LEDFeedbackState::On),
+            "Off" => Ok(LEDFeedbackState::Off),
+            s => Ok(LEDFeedbackState::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for LEDFeedbackState {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for LEDFeedbackState {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: LEDFeedbackState = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<LEDFeedbackState>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for LEDFeedbackState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LEDFeedbackState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Service related to the TV remote control
+pub trait HTControl {
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn commit_learned_ir_codes(
+        &self,
+        request: ht_control::CommitLearnedIrCodesRequest,
+    ) -> Result<()>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn get_ir_repeater_state(
+        &self,
+    ) -> Result<ht_control::GetIrRepeaterStateResponse>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn get_led_feedback_state(
+        &self,
+    ) -> Result<ht_control::GetLedFeedbackStateResponse>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn identify_ir_remote(
+        &self,
+        request: ht_control::IdentifyIrRemoteRequest,
+    ) -> Result<()>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn is_remote_configured(
+        &self,
+    ) -> Result<ht_control::IsRemoteConfiguredResponse>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn learn_ir_code(&self, request: ht_control::LearnIrCodeRequest) -> Result<()>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn set_ir_repeater_state(
+        &self,
+        request: ht_control::SetIrRepeaterStateRequest,
+    ) -> Result<()>;
+    /// Supported by: `S14`, `S19`, `S9`
+    async fn set_led_feedback_state(
+        &self,
+        request: ht_control::SetLedFeedbackStateRequest,
+    ) -> Result<()>;
+}
+impl HTControl for SonosDevice {
+    async fn commit_learned_ir_codes(
+        &self,
+        request: ht_control::CommitLearnedIrCodesRequest,
+    ) -> Result<()> {
+        self.action(&ht_control::SERVICE_TYPE, "CommitLearnedIRCodes", request).await
+    }
+    async fn get_ir_repeater_state(
+        &self,
+    ) -> Result<ht_control::GetIrRepeaterStateResponse> {
+        self.action(
+                &ht_control::SERVICE_TYPE,
+                "GetIRRepeaterState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_led_feedback_state(
+        &self,
+    ) -> Result<ht_control::GetLedFeedbackStateResponse> {
+        self.action(
+                &ht_control::SERVICE_TYPE,
+                "GetLEDFeedbackState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn identify_ir_remote(
+        &self,
+        request: ht_control::IdentifyIrRemoteRequest,
+    ) -> Result<()> {
+        self.action(&ht_control::SERVICE_TYPE, "IdentifyIRRemote", request).await
+    }
+    async fn is_remote_configured(
+        &self,
+    ) -> Result<ht_control::IsRemoteConfiguredResponse> {
+        self.action(
+                &ht_control::SERVICE_TYPE,
+                "IsRemoteConfigured",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn learn_ir_code(
+        &self,
+        request: ht_control::LearnIrCodeRequest,
+    ) -> Result<()> {
+        self.action(&ht_control::SERVICE_TYPE, "LearnIRCode", request).await
+    }
+    async fn set_ir_repeater_state(
+        &self,
+        request: ht_control::SetIrRepeaterStateRequest,
+    ) -> Result<()> {
+        self.action(&ht_control::SERVICE_TYPE, "SetIRRepeaterState", request).await
+    }
+    async fn set_led_feedback_state(
+        &self,
+        request: ht_control::SetLedFeedbackStateRequest,
+    ) -> Result<()> {
+        self.action(&ht_control::SERVICE_TYPE, "SetLEDFeedbackState", request).await
+    }
+}
+/// A variant of `HTControl` that can be used as a trait object,
+/// eg: `Box<dyn HTControlDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait HTControlDyn: Send + Sync {
+    /// See `HTControl::commit_learned_ir_codes`
+    fn commit_learned_ir_codes(
+        &self,
+        request: ht_control::CommitLearnedIrCodesRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `HTControl::get_ir_repeater_state`
+    fn get_ir_repeater_state(
+        &self,
+    ) -> ActionFuture<'_, ht_control::GetIrRepeaterStateResponse>;
+    /// See `HTControl::get_led_feedback_state`
+    fn get_led_feedback_state(
+        &self,
+    ) -> ActionFuture<'_, ht_control::GetLedFeedbackStateResponse>;
+    /// See `HTControl::identify_ir_remote`
+    fn identify_ir_remote(
+        &self,
+        request: ht_control::IdentifyIrRemoteRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `HTControl::is_remote_configured`
+    fn is_remote_configured(
+        &self,
+    ) -> ActionFuture<'_, ht_control::IsRemoteConfiguredResponse>;
+    /// See `HTControl::learn_ir_code`
+    fn learn_ir_code(
+        &self,
+        request: ht_control::LearnIrCodeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `HTControl::set_ir_repeater_state`
+    fn set_ir_repeater_state(
+        &self,
+        request: ht_control::SetIrRepeaterStateRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `HTControl::set_led_feedback_state`
+    fn set_led_feedback_state(
+        &self,
+        request: ht_control::SetLedFeedbackStateRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl HTControlDyn for SonosDevice {
+    fn commit_learned_ir_codes(
+        &self,
+        request: ht_control::CommitLearnedIrCodesRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as HTControl>::commit_learned_ir_codes(self, request))
+    }
+    fn get_ir_repeater_state(
+        &self,
+    ) -> ActionFuture<'_, ht_control::GetIrRepeaterStateResponse> {
+        Box::pin(<Self as HTControl>::get_ir_repeater_state(self))
+    }
+    fn get_led_feedback_state(
+        &self,
+    ) -> ActionFuture<'_, ht_control::GetLedFeedbackStateResponse> {
+        Box::pin(<Self as HTControl>::get_led_feedback_state(self))
+    }
+    fn identify_ir_remote(
+        &self,
+        request: ht_control::IdentifyIrRemoteRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as HTControl>::identify_ir_remote(self, request))
+    }
+    fn is_remote_configured(
+        &self,
+    ) -> ActionFuture<'_, ht_control::IsRemoteConfiguredResponse> {
+        Box::pin(<Self as HTControl>::is_remote_configured(self))
+    }
+    fn learn_ir_code(
+        &self,
+        request: ht_control::LearnIrCodeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as HTControl>::learn_ir_code(self, request))
+    }
+    fn set_ir_repeater_state(
+        &self,
+        request: ht_control::SetIrRepeaterStateRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as HTControl>::set_ir_repeater_state(self, request))
+    }
+    fn set_led_feedback_state(
+        &self,
+        request: ht_control::SetLedFeedbackStateRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as HTControl>::set_led_feedback_state(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_commit_learned_ir_codes_request() {
+        let request = CommitLearnedIrCodesRequest {
+            name: "Name".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CommitLearnedIrCodesRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_ir_repeater_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetIRRepeaterStateResponse xmlns:u="urn:schemas-upnp-org:service:HTControl:1"><CurrentIRRepeaterState>Disabled</CurrentIRRepeaterState></u:GetIRRepeaterStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetIrRepeaterStateResponse {
+            current_ir_repeater_state: Some(IRRepeaterState::Disabled),
+        };
+        let decoded = <GetIrRepeaterStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_led_feedback_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetLEDFeedbackStateResponse xmlns:u="urn:schemas-upnp-org:service:HTControl:1"><LEDFeedbackState>Off</LEDFeedbackState></u:GetLEDFeedbackStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetLedFeedbackStateResponse {
+            led_feedback_state: Some(LEDFeedbackState::Off),
+        };
+        let decoded = <GetLedFeedbackStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_identify_ir_remote_request() {
+        let request = IdentifyIrRemoteRequest {
+            timeout: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: IdentifyIrRemoteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_is_remote_configured_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:IsRemoteConfiguredResponse xmlns:u="urn:schemas-upnp-org:service:HTControl:1"><RemoteConfigured>1</RemoteConfigured></u:IsRemoteConfiguredResponse></s:Body></s:Envelope>"#;
+        let expected = IsRemoteConfiguredResponse {
+            remote_configured: Some(true),
+        };
+        let decoded = <IsRemoteConfiguredResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_learn_ir_code_request() {
+        let request = LearnIrCodeRequest {
+            ir_code: "IRCode".to_string(),
+            timeout: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: LearnIrCodeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_ir_repeater_state_request() {
+        let request = SetIrRepeaterStateRequest {
+            desired_ir_repeater_state: IRRepeaterState::Disabled,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetIrRepeaterStateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_led_feedback_state_request() {
+        let request = SetLedFeedbackStateRequest {
+            led_feedback_state: LEDFeedbackState::Off,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetLedFeedbackStateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
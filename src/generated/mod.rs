@@ -0,0 +1,336 @@
+// This file was auto-generated by codegen! Do not edit!
+// Which of the imports are used depends on the enabled services
+
+#![cfg_attr(not(feature = "all"), allow(unused_imports))]
+use std::str::FromStr;
+use crate::SonosDevice;
+use crate::Result;
+use instant_xml::{FromXml, ToXml};
+use crate::upnp::DecodeXml;
+use crate::xmlutil::DecodeXmlString;
+/// Identifies the virtual instance of a service that a request
+/// applies to. Players only have a single instance of each service,
+/// which is `InstanceId::DEFAULT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct InstanceId(pub u32);
+impl InstanceId {
+    pub const DEFAULT: InstanceId = InstanceId(0);
+}
+impl From<u32> for InstanceId {
+    fn from(value: u32) -> InstanceId {
+        InstanceId(value)
+    }
+}
+impl From<InstanceId> for u32 {
+    fn from(value: InstanceId) -> u32 {
+        value.0
+    }
+}
+impl std::fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl instant_xml::ToXml for InstanceId {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for InstanceId {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: u32 = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(InstanceId(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<InstanceId>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// The future returned by the methods of the `dyn`-compatible
+/// service traits
+#[cfg(feature = "dyn-traits")]
+pub type ActionFuture<'a, T> = std::pin::Pin<
+    Box<dyn std::future::Future<Output = Result<T>> + Send + 'a>,
+>;
+#[cfg(feature = "svc-av-transport")]
+pub mod av_transport;
+#[cfg(feature = "svc-av-transport")]
+pub use av_transport::{
+    AVTransport, SeekMode, CurrentPlayMode, PlaybackStorageMedium, TransportState,
+};
+#[cfg(all(feature = "svc-av-transport", feature = "dyn-traits"))]
+pub use av_transport::AVTransportDyn;
+#[cfg(feature = "svc-alarm-clock")]
+pub mod alarm_clock;
+#[cfg(feature = "svc-alarm-clock")]
+pub use alarm_clock::{AlarmClock, AlarmPlayMode, Recurrence};
+#[cfg(all(feature = "svc-alarm-clock", feature = "dyn-traits"))]
+pub use alarm_clock::AlarmClockDyn;
+#[cfg(feature = "svc-audio-in")]
+pub mod audio_in;
+#[cfg(feature = "svc-audio-in")]
+pub use audio_in::AudioIn;
+#[cfg(all(feature = "svc-audio-in", feature = "dyn-traits"))]
+pub use audio_in::AudioInDyn;
+#[cfg(feature = "svc-connection-manager")]
+pub mod connection_manager;
+#[cfg(feature = "svc-connection-manager")]
+pub use connection_manager::{ConnectionManager, ConnectionStatus, Direction};
+#[cfg(all(feature = "svc-connection-manager", feature = "dyn-traits"))]
+pub use connection_manager::ConnectionManagerDyn;
+#[cfg(feature = "svc-content-directory")]
+pub mod content_directory;
+#[cfg(feature = "svc-content-directory")]
+pub use content_directory::{ContentDirectory, BrowseFlag};
+#[cfg(all(feature = "svc-content-directory", feature = "dyn-traits"))]
+pub use content_directory::ContentDirectoryDyn;
+#[cfg(feature = "svc-device-properties")]
+pub mod device_properties;
+#[cfg(feature = "svc-device-properties")]
+pub use device_properties::{DeviceProperties, ButtonLockState, LEDState};
+#[cfg(all(feature = "svc-device-properties", feature = "dyn-traits"))]
+pub use device_properties::DevicePropertiesDyn;
+#[cfg(feature = "svc-group-management")]
+pub mod group_management;
+#[cfg(feature = "svc-group-management")]
+pub use group_management::GroupManagement;
+#[cfg(all(feature = "svc-group-management", feature = "dyn-traits"))]
+pub use group_management::GroupManagementDyn;
+#[cfg(feature = "svc-group-rendering-control")]
+pub mod group_rendering_control;
+#[cfg(feature = "svc-group-rendering-control")]
+pub use group_rendering_control::{GroupRenderingControl, GroupVolume};
+#[cfg(all(feature = "svc-group-rendering-control", feature = "dyn-traits"))]
+pub use group_rendering_control::GroupRenderingControlDyn;
+#[cfg(feature = "svc-ht-control")]
+pub mod ht_control;
+#[cfg(feature = "svc-ht-control")]
+pub use ht_control::{HTControl, IRRepeaterState, LEDFeedbackState};
+#[cfg(all(feature = "svc-ht-control", feature = "dyn-traits"))]
+pub use ht_control::HTControlDyn;
+#[cfg(feature = "svc-music-services")]
+pub mod music_services;
+#[cfg(feature = "svc-music-services")]
+pub use music_services::MusicServices;
+#[cfg(all(feature = "svc-music-services", feature = "dyn-traits"))]
+pub use music_services::MusicServicesDyn;
+#[cfg(feature = "svc-q-play")]
+pub mod q_play;
+#[cfg(feature = "svc-q-play")]
+pub use q_play::QPlay;
+#[cfg(all(feature = "svc-q-play", feature = "dyn-traits"))]
+pub use q_play::QPlayDyn;
+#[cfg(feature = "svc-queue")]
+pub mod queue;
+#[cfg(feature = "svc-queue")]
+pub use queue::Queue;
+#[cfg(all(feature = "svc-queue", feature = "dyn-traits"))]
+pub use queue::QueueDyn;
+#[cfg(feature = "svc-rendering-control")]
+pub mod rendering_control;
+#[cfg(feature = "svc-rendering-control")]
+pub use rendering_control::{
+    RenderingControl, Channel, MuteChannel, RampType, Bass, Treble, Volume,
+};
+#[cfg(all(feature = "svc-rendering-control", feature = "dyn-traits"))]
+pub use rendering_control::RenderingControlDyn;
+#[cfg(feature = "svc-system-properties")]
+pub mod system_properties;
+#[cfg(feature = "svc-system-properties")]
+pub use system_properties::SystemProperties;
+#[cfg(all(feature = "svc-system-properties", feature = "dyn-traits"))]
+pub use system_properties::SystemPropertiesDyn;
+#[cfg(feature = "svc-virtual-line-in")]
+pub mod virtual_line_in;
+#[cfg(feature = "svc-virtual-line-in")]
+pub use virtual_line_in::VirtualLineIn;
+#[cfg(all(feature = "svc-virtual-line-in", feature = "dyn-traits"))]
+pub use virtual_line_in::VirtualLineInDyn;
+#[cfg(feature = "svc-zone-group-topology")]
+pub mod zone_group_topology;
+#[cfg(feature = "svc-zone-group-topology")]
+pub use zone_group_topology::{
+    ZoneGroupTopology, UnresponsiveDeviceActionType, UpdateType,
+};
+#[cfg(all(feature = "svc-zone-group-topology", feature = "dyn-traits"))]
+pub use zone_group_topology::ZoneGroupTopologyDyn;
+pub mod capabilities;
+/// The prelude makes it convenient to use the methods of `SonosDevice`.
+/// Intended usage is `use sonos::prelude::*;` and then you don't have
+/// to worry about importing the individual service traits.
+/// Applications that use only a few services can instead import
+/// from the per-service modules, such as `prelude::services::av_transport`.
+pub mod prelude {
+    #[cfg(feature = "svc-av-transport")]
+    pub use super::AVTransport;
+    #[cfg(feature = "svc-alarm-clock")]
+    pub use super::AlarmClock;
+    #[cfg(feature = "svc-audio-in")]
+    pub use super::AudioIn;
+    #[cfg(feature = "svc-connection-manager")]
+    pub use super::ConnectionManager;
+    #[cfg(feature = "svc-content-directory")]
+    pub use super::ContentDirectory;
+    #[cfg(feature = "svc-device-properties")]
+    pub use super::DeviceProperties;
+    #[cfg(feature = "svc-group-management")]
+    pub use super::GroupManagement;
+    #[cfg(feature = "svc-group-rendering-control")]
+    pub use super::GroupRenderingControl;
+    #[cfg(feature = "svc-ht-control")]
+    pub use super::HTControl;
+    #[cfg(feature = "svc-music-services")]
+    pub use super::MusicServices;
+    #[cfg(feature = "svc-q-play")]
+    pub use super::QPlay;
+    #[cfg(feature = "svc-queue")]
+    pub use super::Queue;
+    #[cfg(feature = "svc-rendering-control")]
+    pub use super::RenderingControl;
+    #[cfg(feature = "svc-system-properties")]
+    pub use super::SystemProperties;
+    #[cfg(feature = "svc-virtual-line-in")]
+    pub use super::VirtualLineIn;
+    #[cfg(feature = "svc-zone-group-topology")]
+    pub use super::ZoneGroupTopology;
+    /// A module per service, so that applications that use only a few
+    /// services can import their traits without importing the rest.
+    /// These are kept out of the prelude itself so that `use sonos::prelude::*`
+    /// doesn't bring names such as `av_transport` into scope, where they
+    /// would be ambiguous with the request and response modules of the
+    /// same name that `use sonos::*` brings into scope.
+    pub mod services {
+        /// Brings only the `AVTransport` trait into scope:
+        /// `use sonos::prelude::services::av_transport::*;`
+        #[cfg(feature = "svc-av-transport")]
+        pub mod av_transport {
+            pub use crate::AVTransport;
+        }
+        /// Brings only the `AlarmClock` trait into scope:
+        /// `use sonos::prelude::services::alarm_clock::*;`
+        #[cfg(feature = "svc-alarm-clock")]
+        pub mod alarm_clock {
+            pub use crate::AlarmClock;
+        }
+        /// Brings only the `AudioIn` trait into scope:
+        /// `use sonos::prelude::services::audio_in::*;`
+        #[cfg(feature = "svc-audio-in")]
+        pub mod audio_in {
+            pub use crate::AudioIn;
+        }
+        /// Brings only the `ConnectionManager` trait into scope:
+        /// `use sonos::prelude::services::connection_manager::*;`
+        #[cfg(feature = "svc-connection-manager")]
+        pub mod connection_manager {
+            pub use crate::ConnectionManager;
+        }
+        /// Brings only the `ContentDirectory` trait into scope:
+        /// `use sonos::prelude::services::content_directory::*;`
+        #[cfg(feature = "svc-content-directory")]
+        pub mod content_directory {
+            pub use crate::ContentDirectory;
+        }
+        /// Brings only the `DeviceProperties` trait into scope:
+        /// `use sonos::prelude::services::device_properties::*;`
+        #[cfg(feature = "svc-device-properties")]
+        pub mod device_properties {
+            pub use crate::DeviceProperties;
+        }
+        /// Brings only the `GroupManagement` trait into scope:
+        /// `use sonos::prelude::services::group_management::*;`
+        #[cfg(feature = "svc-group-management")]
+        pub mod group_management {
+            pub use crate::GroupManagement;
+        }
+        /// Brings only the `GroupRenderingControl` trait into scope:
+        /// `use sonos::prelude::services::group_rendering_control::*;`
+        #[cfg(feature = "svc-group-rendering-control")]
+        pub mod group_rendering_control {
+            pub use crate::GroupRenderingControl;
+        }
+        /// Brings only the `HTControl` trait into scope:
+        /// `use sonos::prelude::services::ht_control::*;`
+        #[cfg(feature = "svc-ht-control")]
+        pub mod ht_control {
+            pub use crate::HTControl;
+        }
+        /// Brings only the `MusicServices` trait into scope:
+        /// `use sonos::prelude::services::music_services::*;`
+        #[cfg(feature = "svc-music-services")]
+        pub mod music_services {
+            pub use crate::MusicServices;
+        }
+        /// Brings only the `QPlay` trait into scope:
+        /// `use sonos::prelude::services::q_play::*;`
+        #[cfg(feature = "svc-q-play")]
+        pub mod q_play {
+            pub use crate::QPlay;
+        }
+        /// Brings only the `Queue` trait into scope:
+        /// `use sonos::prelude::services::queue::*;`
+        #[cfg(feature = "svc-queue")]
+        pub mod queue {
+            pub use crate::Queue;
+        }
+        /// Brings only the `RenderingControl` trait into scope:
+        /// `use sonos::prelude::services::rendering_control::*;`
+        #[cfg(feature = "svc-rendering-control")]
+        pub mod rendering_control {
+            pub use crate::RenderingControl;
+        }
+        /// Brings only the `SystemProperties` trait into scope:
+        /// `use sonos::prelude::services::system_properties::*;`
+        #[cfg(feature = "svc-system-properties")]
+        pub mod system_properties {
+            pub use crate::SystemProperties;
+        }
+        /// Brings only the `VirtualLineIn` trait into scope:
+        /// `use sonos::prelude::services::virtual_line_in::*;`
+        #[cfg(feature = "svc-virtual-line-in")]
+        pub mod virtual_line_in {
+            pub use crate::VirtualLineIn;
+        }
+        /// Brings only the `ZoneGroupTopology` trait into scope:
+        /// `use sonos::prelude::services::zone_group_topology::*;`
+        #[cfg(feature = "svc-zone-group-topology")]
+        pub mod zone_group_topology {
+            pub use crate::ZoneGroupTopology;
+        }
+    }
+}
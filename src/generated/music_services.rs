@@ -0,0 +1,207 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `MusicServices` service.
+use super::*;
+/// URN for the `MusicServices` service.
+/// `urn:schemas-upnp-org:service:MusicServices:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:MusicServices:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSessionId", ns(SERVICE_TYPE))]
+pub struct GetSessionIdRequest {
+    #[xml(rename = "ServiceId", ns(""))]
+    pub service_id: u32,
+    #[xml(rename = "Username", ns(""))]
+    pub username: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSessionIdResponse", ns(SERVICE_TYPE))]
+pub struct GetSessionIdResponse {
+    #[xml(rename = "SessionId", ns(""))]
+    pub session_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetSessionIdResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ListAvailableServicesResponse", ns(SERVICE_TYPE))]
+pub struct ListAvailableServicesResponse {
+    #[xml(rename = "AvailableServiceDescriptorList", ns(""))]
+    pub available_service_descriptor_list: Option<
+        DecodeXmlString<crate::MusicServiceList>,
+    >,
+    #[xml(rename = "AvailableServiceTypeList", ns(""))]
+    pub available_service_type_list: Option<String>,
+    #[xml(rename = "AvailableServiceListVersion", ns(""))]
+    pub available_service_list_version: Option<String>,
+}
+impl crate::DecodeSoapResponse for ListAvailableServicesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+/// A parsed event produced by the `MusicServices` service.
+/// Use `SonosDevice::subscribe_music_services()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicServicesEvent {
+    pub service_list_version: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct MusicServicesPropertySet {
+    pub properties: Vec<MusicServicesProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct MusicServicesProperty {
+    #[xml(rename = "ServiceListVersion", ns(""))]
+    pub service_list_version: Option<String>,
+}
+impl DecodeXml for MusicServicesEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: MusicServicesPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.service_list_version {
+                result.service_list_version.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `MusicServices` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_music_services(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<MusicServicesEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Access to external music services, like Spotify or Youtube Music
+pub trait MusicServices {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_session_id(
+        &self,
+        request: music_services::GetSessionIdRequest,
+    ) -> Result<music_services::GetSessionIdResponse>;
+    /// Load music service list as xml
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn list_available_services(
+        &self,
+    ) -> Result<music_services::ListAvailableServicesResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn update_available_services(&self) -> Result<()>;
+}
+impl MusicServices for SonosDevice {
+    async fn get_session_id(
+        &self,
+        request: music_services::GetSessionIdRequest,
+    ) -> Result<music_services::GetSessionIdResponse> {
+        self.action(&music_services::SERVICE_TYPE, "GetSessionId", request).await
+    }
+    async fn list_available_services(
+        &self,
+    ) -> Result<music_services::ListAvailableServicesResponse> {
+        self.action(
+                &music_services::SERVICE_TYPE,
+                "ListAvailableServices",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn update_available_services(&self) -> Result<()> {
+        self.action(
+                &music_services::SERVICE_TYPE,
+                "UpdateAvailableServices",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+}
+/// A variant of `MusicServices` that can be used as a trait object,
+/// eg: `Box<dyn MusicServicesDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait MusicServicesDyn: Send + Sync {
+    /// See `MusicServices::get_session_id`
+    fn get_session_id(
+        &self,
+        request: music_services::GetSessionIdRequest,
+    ) -> ActionFuture<'_, music_services::GetSessionIdResponse>;
+    /// See `MusicServices::list_available_services`
+    fn list_available_services(
+        &self,
+    ) -> ActionFuture<'_, music_services::ListAvailableServicesResponse>;
+    /// See `MusicServices::update_available_services`
+    fn update_available_services(&self) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl MusicServicesDyn for SonosDevice {
+    fn get_session_id(
+        &self,
+        request: music_services::GetSessionIdRequest,
+    ) -> ActionFuture<'_, music_services::GetSessionIdResponse> {
+        Box::pin(<Self as MusicServices>::get_session_id(self, request))
+    }
+    fn list_available_services(
+        &self,
+    ) -> ActionFuture<'_, music_services::ListAvailableServicesResponse> {
+        Box::pin(<Self as MusicServices>::list_available_services(self))
+    }
+    fn update_available_services(&self) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as MusicServices>::update_available_services(self))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_session_id_request() {
+        let request = GetSessionIdRequest {
+            service_id: 1,
+            username: "Username".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetSessionIdRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_session_id_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetSessionIdResponse xmlns:u="urn:schemas-upnp-org:service:MusicServices:1"><SessionId>SessionId</SessionId></u:GetSessionIdResponse></s:Body></s:Envelope>"#;
+        let expected = GetSessionIdResponse {
+            session_id: Some("SessionId".to_string()),
+        };
+        let decoded = <GetSessionIdResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_list_available_services_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ListAvailableServicesResponse xmlns:u="urn:schemas-upnp-org:service:MusicServices:1"><AvailableServiceTypeList>AvailableServiceTypeList</AvailableServiceTypeList><AvailableServiceListVersion>AvailableServiceListVersion</AvailableServiceListVersion></u:ListAvailableServicesResponse></s:Body></s:Envelope>"#;
+        let expected = ListAvailableServicesResponse {
+            available_service_descriptor_list: None,
+            available_service_type_list: Some("AvailableServiceTypeList".to_string()),
+            available_service_list_version: Some(
+                "AvailableServiceListVersion".to_string(),
+            ),
+        };
+        let decoded = <ListAvailableServicesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+}
@@ -0,0 +1,95 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `QPlay` service.
+use super::*;
+/// URN for the `QPlay` service.
+/// `urn:schemas-tencent-com:service:QPlay:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-tencent-com:service:QPlay:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "QPlayAuth", ns(SERVICE_TYPE))]
+pub struct QPlayAuthRequest {
+    #[xml(rename = "Seed", ns(""))]
+    pub seed: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "QPlayAuthResponse", ns(SERVICE_TYPE))]
+pub struct QPlayAuthResponse {
+    #[xml(rename = "Code", ns(""))]
+    pub code: Option<String>,
+    #[xml(rename = "MID", ns(""))]
+    pub mid: Option<String>,
+    #[xml(rename = "DID", ns(""))]
+    pub did: Option<String>,
+}
+impl crate::DecodeSoapResponse for QPlayAuthResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Services related to Chinese Tencent Qplay service
+pub trait QPlay {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn q_play_auth(
+        &self,
+        request: q_play::QPlayAuthRequest,
+    ) -> Result<q_play::QPlayAuthResponse>;
+}
+impl QPlay for SonosDevice {
+    async fn q_play_auth(
+        &self,
+        request: q_play::QPlayAuthRequest,
+    ) -> Result<q_play::QPlayAuthResponse> {
+        self.action(&q_play::SERVICE_TYPE, "QPlayAuth", request).await
+    }
+}
+/// A variant of `QPlay` that can be used as a trait object,
+/// eg: `Box<dyn QPlayDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait QPlayDyn: Send + Sync {
+    /// See `QPlay::q_play_auth`
+    fn q_play_auth(
+        &self,
+        request: q_play::QPlayAuthRequest,
+    ) -> ActionFuture<'_, q_play::QPlayAuthResponse>;
+}
+#[cfg(feature = "dyn-traits")]
+impl QPlayDyn for SonosDevice {
+    fn q_play_auth(
+        &self,
+        request: q_play::QPlayAuthRequest,
+    ) -> ActionFuture<'_, q_play::QPlayAuthResponse> {
+        Box::pin(<Self as QPlay>::q_play_auth(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_q_play_auth_request() {
+        let request = QPlayAuthRequest {
+            seed: "Seed".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: QPlayAuthRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_q_play_auth_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:QPlayAuthResponse xmlns:u="urn:schemas-tencent-com:service:QPlay:1"><Code>Code</Code><MID>MID</MID><DID>DID</DID></u:QPlayAuthResponse></s:Body></s:Envelope>"#;
+        let expected = QPlayAuthResponse {
+            code: Some("Code".to_string()),
+            mid: Some("MID".to_string()),
+            did: Some("DID".to_string()),
+        };
+        let decoded = <QPlayAuthResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+}
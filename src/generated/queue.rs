@@ -0,0 +1,1304 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `Queue` service.
+use super::*;
+/// URN for the `Queue` service.
+/// `urn:schemas-sonos-com:service:Queue:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-sonos-com:service:Queue:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMultipleURIs", ns(SERVICE_TYPE))]
+pub struct AddMultipleUrisRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "ContainerURI", ns(""))]
+    pub container_uri: String,
+    #[xml(rename = "ContainerMetaData", ns(""))]
+    pub container_meta_data: String,
+    #[xml(rename = "DesiredFirstTrackNumberEnqueued", ns(""))]
+    pub desired_first_track_number_enqueued: u32,
+    #[xml(rename = "EnqueueAsNext", ns(""))]
+    pub enqueue_as_next: bool,
+    #[xml(rename = "NumberOfURIs", ns(""))]
+    pub number_of_uris: u32,
+    #[xml(rename = "EnqueuedURIsAndMetaData", ns(""))]
+    pub enqueued_uris_and_meta_data: String,
+}
+impl AddMultipleUrisRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddMultipleUrisRequestBuilder {
+        AddMultipleUrisRequestBuilder::default()
+    }
+}
+/// Builds a `AddMultipleUrisRequest`; see `AddMultipleUrisRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddMultipleUrisRequestBuilder {
+    request: AddMultipleUrisRequest,
+}
+impl AddMultipleUrisRequestBuilder {
+    pub fn queue_id(mut self, queue_id: u32) -> Self {
+        self.request.queue_id = queue_id;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn container_uri(mut self, container_uri: impl Into<String>) -> Self {
+        self.request.container_uri = container_uri.into();
+        self
+    }
+    pub fn container_meta_data(
+        mut self,
+        container_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.container_meta_data = container_meta_data.into();
+        self
+    }
+    pub fn desired_first_track_number_enqueued(
+        mut self,
+        desired_first_track_number_enqueued: u32,
+    ) -> Self {
+        self.request.desired_first_track_number_enqueued = desired_first_track_number_enqueued;
+        self
+    }
+    pub fn enqueue_as_next(mut self, enqueue_as_next: bool) -> Self {
+        self.request.enqueue_as_next = enqueue_as_next;
+        self
+    }
+    pub fn number_of_uris(mut self, number_of_uris: u32) -> Self {
+        self.request.number_of_uris = number_of_uris;
+        self
+    }
+    pub fn enqueued_uris_and_meta_data(
+        mut self,
+        enqueued_uris_and_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.enqueued_uris_and_meta_data = enqueued_uris_and_meta_data.into();
+        self
+    }
+    pub fn build(self) -> AddMultipleUrisRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddMultipleURIsResponse", ns(SERVICE_TYPE))]
+pub struct AddMultipleUrisResponse {
+    #[xml(rename = "FirstTrackNumberEnqueued", ns(""))]
+    pub first_track_number_enqueued: Option<u32>,
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for AddMultipleUrisResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURI", ns(SERVICE_TYPE))]
+pub struct AddUriRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "EnqueuedURI", ns(""))]
+    pub enqueued_uri: String,
+    #[xml(rename = "EnqueuedURIMetaData", ns(""))]
+    pub enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    #[xml(rename = "DesiredFirstTrackNumberEnqueued", ns(""))]
+    pub desired_first_track_number_enqueued: u32,
+    #[xml(rename = "EnqueueAsNext", ns(""))]
+    pub enqueue_as_next: bool,
+}
+impl AddUriRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddUriRequestBuilder {
+        AddUriRequestBuilder::default()
+    }
+}
+/// Builds a `AddUriRequest`; see `AddUriRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddUriRequestBuilder {
+    request: AddUriRequest,
+}
+impl AddUriRequestBuilder {
+    pub fn queue_id(mut self, queue_id: u32) -> Self {
+        self.request.queue_id = queue_id;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn enqueued_uri(mut self, enqueued_uri: impl Into<String>) -> Self {
+        self.request.enqueued_uri = enqueued_uri.into();
+        self
+    }
+    pub fn enqueued_uri_meta_data(
+        mut self,
+        enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+    ) -> Self {
+        self.request.enqueued_uri_meta_data = enqueued_uri_meta_data;
+        self
+    }
+    pub fn desired_first_track_number_enqueued(
+        mut self,
+        desired_first_track_number_enqueued: u32,
+    ) -> Self {
+        self.request.desired_first_track_number_enqueued = desired_first_track_number_enqueued;
+        self
+    }
+    pub fn enqueue_as_next(mut self, enqueue_as_next: bool) -> Self {
+        self.request.enqueue_as_next = enqueue_as_next;
+        self
+    }
+    pub fn build(self) -> AddUriRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddURIResponse", ns(SERVICE_TYPE))]
+pub struct AddUriResponse {
+    #[xml(rename = "FirstTrackNumberEnqueued", ns(""))]
+    pub first_track_number_enqueued: Option<u32>,
+    #[xml(rename = "NumTracksAdded", ns(""))]
+    pub num_tracks_added: Option<u32>,
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for AddUriResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AttachQueue", ns(SERVICE_TYPE))]
+pub struct AttachQueueRequest {
+    #[xml(rename = "QueueOwnerID", ns(""))]
+    pub queue_owner_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AttachQueueResponse", ns(SERVICE_TYPE))]
+pub struct AttachQueueResponse {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: Option<u32>,
+    #[xml(rename = "QueueOwnerContext", ns(""))]
+    pub queue_owner_context: Option<String>,
+}
+impl crate::DecodeSoapResponse for AttachQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Browse", ns(SERVICE_TYPE))]
+pub struct BrowseRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "RequestedCount", ns(""))]
+    pub requested_count: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BrowseResponse", ns(SERVICE_TYPE))]
+pub struct BrowseResponse {
+    #[xml(rename = "Result", ns(""))]
+    pub result: Option<DecodeXmlString<crate::TrackMetaDataList>>,
+    #[xml(rename = "NumberReturned", ns(""))]
+    pub number_returned: Option<u32>,
+    #[xml(rename = "TotalMatches", ns(""))]
+    pub total_matches: Option<u32>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for BrowseResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateQueue", ns(SERVICE_TYPE))]
+pub struct CreateQueueRequest {
+    #[xml(rename = "QueueOwnerID", ns(""))]
+    pub queue_owner_id: String,
+    #[xml(rename = "QueueOwnerContext", ns(""))]
+    pub queue_owner_context: String,
+    #[xml(rename = "QueuePolicy", ns(""))]
+    pub queue_policy: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CreateQueueResponse", ns(SERVICE_TYPE))]
+pub struct CreateQueueResponse {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for CreateQueueResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveAllTracks", ns(SERVICE_TYPE))]
+pub struct RemoveAllTracksRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveAllTracksResponse", ns(SERVICE_TYPE))]
+pub struct RemoveAllTracksResponse {
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for RemoveAllTracksResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveTrackRange", ns(SERVICE_TYPE))]
+pub struct RemoveTrackRangeRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "NumberOfTracks", ns(""))]
+    pub number_of_tracks: u32,
+}
+impl RemoveTrackRangeRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> RemoveTrackRangeRequestBuilder {
+        RemoveTrackRangeRequestBuilder::default()
+    }
+}
+/// Builds a `RemoveTrackRangeRequest`; see `RemoveTrackRangeRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RemoveTrackRangeRequestBuilder {
+    request: RemoveTrackRangeRequest,
+}
+impl RemoveTrackRangeRequestBuilder {
+    pub fn queue_id(mut self, queue_id: u32) -> Self {
+        self.request.queue_id = queue_id;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn starting_index(mut self, starting_index: u32) -> Self {
+        self.request.starting_index = starting_index;
+        self
+    }
+    pub fn number_of_tracks(mut self, number_of_tracks: u32) -> Self {
+        self.request.number_of_tracks = number_of_tracks;
+        self
+    }
+    pub fn build(self) -> RemoveTrackRangeRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveTrackRangeResponse", ns(SERVICE_TYPE))]
+pub struct RemoveTrackRangeResponse {
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for RemoveTrackRangeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReorderTracks", ns(SERVICE_TYPE))]
+pub struct ReorderTracksRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "NumberOfTracks", ns(""))]
+    pub number_of_tracks: u32,
+    #[xml(rename = "InsertBefore", ns(""))]
+    pub insert_before: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+}
+impl ReorderTracksRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ReorderTracksRequestBuilder {
+        ReorderTracksRequestBuilder::default()
+    }
+}
+/// Builds a `ReorderTracksRequest`; see `ReorderTracksRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReorderTracksRequestBuilder {
+    request: ReorderTracksRequest,
+}
+impl ReorderTracksRequestBuilder {
+    pub fn queue_id(mut self, queue_id: u32) -> Self {
+        self.request.queue_id = queue_id;
+        self
+    }
+    pub fn starting_index(mut self, starting_index: u32) -> Self {
+        self.request.starting_index = starting_index;
+        self
+    }
+    pub fn number_of_tracks(mut self, number_of_tracks: u32) -> Self {
+        self.request.number_of_tracks = number_of_tracks;
+        self
+    }
+    pub fn insert_before(mut self, insert_before: u32) -> Self {
+        self.request.insert_before = insert_before;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn build(self) -> ReorderTracksRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReorderTracksResponse", ns(SERVICE_TYPE))]
+pub struct ReorderTracksResponse {
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for ReorderTracksResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReplaceAllTracks", ns(SERVICE_TYPE))]
+pub struct ReplaceAllTracksRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: u32,
+    #[xml(rename = "ContainerURI", ns(""))]
+    pub container_uri: String,
+    #[xml(rename = "ContainerMetaData", ns(""))]
+    pub container_meta_data: String,
+    #[xml(rename = "CurrentTrackIndex", ns(""))]
+    pub current_track_index: u32,
+    #[xml(rename = "NewCurrentTrackIndices", ns(""))]
+    pub new_current_track_indices: String,
+    #[xml(rename = "NumberOfURIs", ns(""))]
+    pub number_of_uris: u32,
+    #[xml(rename = "EnqueuedURIsAndMetaData", ns(""))]
+    pub enqueued_uris_and_meta_data: String,
+}
+impl ReplaceAllTracksRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ReplaceAllTracksRequestBuilder {
+        ReplaceAllTracksRequestBuilder::default()
+    }
+}
+/// Builds a `ReplaceAllTracksRequest`; see `ReplaceAllTracksRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReplaceAllTracksRequestBuilder {
+    request: ReplaceAllTracksRequest,
+}
+impl ReplaceAllTracksRequestBuilder {
+    pub fn queue_id(mut self, queue_id: u32) -> Self {
+        self.request.queue_id = queue_id;
+        self
+    }
+    pub fn update_id(mut self, update_id: u32) -> Self {
+        self.request.update_id = update_id;
+        self
+    }
+    pub fn container_uri(mut self, container_uri: impl Into<String>) -> Self {
+        self.request.container_uri = container_uri.into();
+        self
+    }
+    pub fn container_meta_data(
+        mut self,
+        container_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.container_meta_data = container_meta_data.into();
+        self
+    }
+    pub fn current_track_index(mut self, current_track_index: u32) -> Self {
+        self.request.current_track_index = current_track_index;
+        self
+    }
+    pub fn new_current_track_indices(
+        mut self,
+        new_current_track_indices: impl Into<String>,
+    ) -> Self {
+        self.request.new_current_track_indices = new_current_track_indices.into();
+        self
+    }
+    pub fn number_of_uris(mut self, number_of_uris: u32) -> Self {
+        self.request.number_of_uris = number_of_uris;
+        self
+    }
+    pub fn enqueued_uris_and_meta_data(
+        mut self,
+        enqueued_uris_and_meta_data: impl Into<String>,
+    ) -> Self {
+        self.request.enqueued_uris_and_meta_data = enqueued_uris_and_meta_data.into();
+        self
+    }
+    pub fn build(self) -> ReplaceAllTracksRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReplaceAllTracksResponse", ns(SERVICE_TYPE))]
+pub struct ReplaceAllTracksResponse {
+    #[xml(rename = "NewQueueLength", ns(""))]
+    pub new_queue_length: Option<u32>,
+    #[xml(rename = "NewUpdateID", ns(""))]
+    pub new_update_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for ReplaceAllTracksResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SaveAsSonosPlaylist", ns(SERVICE_TYPE))]
+pub struct SaveAsSonosPlaylistRequest {
+    #[xml(rename = "QueueID", ns(""))]
+    pub queue_id: u32,
+    #[xml(rename = "Title", ns(""))]
+    pub title: String,
+    #[xml(rename = "ObjectID", ns(""))]
+    pub object_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SaveAsSonosPlaylistResponse", ns(SERVICE_TYPE))]
+pub struct SaveAsSonosPlaylistResponse {
+    #[xml(rename = "AssignedObjectID", ns(""))]
+    pub assigned_object_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for SaveAsSonosPlaylistResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+/// A parsed event produced by the `Queue` service.
+/// Use `SonosDevice::subscribe_queue()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueueEvent {
+    pub last_change: Option<DecodeXmlString<QueueLastChangeMap>>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct QueuePropertySet {
+    pub properties: Vec<QueueProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct QueueProperty {
+    #[xml(rename = "LastChange", ns(""))]
+    pub last_change: Option<DecodeXmlString<QueueLastChangeMap>>,
+}
+impl DecodeXml for QueueEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: QueuePropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.last_change {
+                result.last_change.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `Queue` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_queue(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<QueueEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueueLastChange {
+    pub count: Option<u32>,
+    pub enqueue_as_next: Option<bool>,
+    pub index: Option<u32>,
+    pub list_uri: Option<String>,
+    pub list_uri_and_metadata: Option<String>,
+    pub num_tracks: Option<u32>,
+    pub object_id: Option<String>,
+    pub queue_id: Option<u32>,
+    pub queue_owner_context: Option<String>,
+    pub queue_owner_id: Option<String>,
+    pub queue_policy: Option<String>,
+    pub result: Option<String>,
+    pub saved_queue_title: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_numbers_csv: Option<String>,
+    pub uri: Option<String>,
+    pub uri_meta_data: Option<String>,
+    pub update_id: Option<u32>,
+    pub curated: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Count", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeCount {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "EnqueueAsNext", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeEnqueueAsNext {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Index", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeIndex {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "LIST_URI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeLIST_URI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "LIST_URI_AND_METADATA", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeLIST_URI_AND_METADATA {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NumTracks", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeNumTracks {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ObjectID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeObjectID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "QueueID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeQueueID {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "QueueOwnerContext", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeQueueOwnerContext {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "QueueOwnerID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeQueueOwnerID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "QueuePolicy", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeQueuePolicy {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Result", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeResult {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SavedQueueTitle", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeSavedQueueTitle {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TrackNumber", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeTrackNumber {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "TrackNumbersCSV", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeTrackNumbersCSV {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "URI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "URIMetaData", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeURIMetaData {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "UpdateID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeUpdateID {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Curated", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct QueueLastChangeCurated {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+const LAST_CHANGE_NS: &str = "urn:schemas-sonos-com:metadata-1-0/Queue/";
+#[derive(FromXml)]
+#[xml(rename = "QueueID", ns(LAST_CHANGE_NS))]
+struct QueueLastChangeRootObject {
+    #[xml(rename = "val", attribute)]
+    object_instance_id_: u32,
+    count: Option<QueueLastChangeCount>,
+    enqueue_as_next: Option<QueueLastChangeEnqueueAsNext>,
+    index: Option<QueueLastChangeIndex>,
+    list_uri: Option<QueueLastChangeLIST_URI>,
+    list_uri_and_metadata: Option<QueueLastChangeLIST_URI_AND_METADATA>,
+    num_tracks: Option<QueueLastChangeNumTracks>,
+    object_id: Option<QueueLastChangeObjectID>,
+    queue_id: Option<QueueLastChangeQueueID>,
+    queue_owner_context: Option<QueueLastChangeQueueOwnerContext>,
+    queue_owner_id: Option<QueueLastChangeQueueOwnerID>,
+    queue_policy: Option<QueueLastChangeQueuePolicy>,
+    result: Option<QueueLastChangeResult>,
+    saved_queue_title: Option<QueueLastChangeSavedQueueTitle>,
+    track_number: Option<QueueLastChangeTrackNumber>,
+    track_numbers_csv: Option<QueueLastChangeTrackNumbersCSV>,
+    uri: Option<QueueLastChangeURI>,
+    uri_meta_data: Option<QueueLastChangeURIMetaData>,
+    update_id: Option<QueueLastChangeUpdateID>,
+    curated: Option<QueueLastChangeCurated>,
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueueLastChangeMap {
+    pub map: std::collections::BTreeMap<u32, QueueLastChange>,
+}
+impl DecodeXml for QueueLastChangeMap {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        #[derive(FromXml)]
+        #[xml(ns(LAST_CHANGE_NS, r = "urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+        struct Event {
+            instance: Vec<QueueLastChangeRootObject>,
+        }
+        let last_change: Event = instant_xml::from_str(xml)?;
+        let mut map = std::collections::BTreeMap::new();
+        for item in last_change.instance {
+            let mut result = QueueLastChange::default();
+            result.count = item.count.and_then(|v| v.val);
+            result.enqueue_as_next = item.enqueue_as_next.and_then(|v| v.val);
+            result.index = item.index.and_then(|v| v.val);
+            result.list_uri = item.list_uri.and_then(|v| v.val);
+            result.list_uri_and_metadata = item
+                .list_uri_and_metadata
+                .and_then(|v| v.val);
+            result.num_tracks = item.num_tracks.and_then(|v| v.val);
+            result.object_id = item.object_id.and_then(|v| v.val);
+            result.queue_id = item.queue_id.and_then(|v| v.val);
+            result.queue_owner_context = item.queue_owner_context.and_then(|v| v.val);
+            result.queue_owner_id = item.queue_owner_id.and_then(|v| v.val);
+            result.queue_policy = item.queue_policy.and_then(|v| v.val);
+            result.result = item.result.and_then(|v| v.val);
+            result.saved_queue_title = item.saved_queue_title.and_then(|v| v.val);
+            result.track_number = item.track_number.and_then(|v| v.val);
+            result.track_numbers_csv = item.track_numbers_csv.and_then(|v| v.val);
+            result.uri = item.uri.and_then(|v| v.val);
+            result.uri_meta_data = item.uri_meta_data.and_then(|v| v.val);
+            result.update_id = item.update_id.and_then(|v| v.val);
+            result.curated = item.curated.and_then(|v| v.val);
+            map.insert(item.object_instance_id_, result);
+        }
+        Ok(QueueLastChangeMap { map })
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Modify and browse queues
+pub trait Queue {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_multiple_uris(
+        &self,
+        request: queue::AddMultipleUrisRequest,
+    ) -> Result<queue::AddMultipleUrisResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_uri(
+        &self,
+        request: queue::AddUriRequest,
+    ) -> Result<queue::AddUriResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn attach_queue(
+        &self,
+        request: queue::AttachQueueRequest,
+    ) -> Result<queue::AttachQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn backup(&self) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn browse(
+        &self,
+        request: queue::BrowseRequest,
+    ) -> Result<queue::BrowseResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn create_queue(
+        &self,
+        request: queue::CreateQueueRequest,
+    ) -> Result<queue::CreateQueueResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_all_tracks(
+        &self,
+        request: queue::RemoveAllTracksRequest,
+    ) -> Result<queue::RemoveAllTracksResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_track_range(
+        &self,
+        request: queue::RemoveTrackRangeRequest,
+    ) -> Result<queue::RemoveTrackRangeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reorder_tracks(
+        &self,
+        request: queue::ReorderTracksRequest,
+    ) -> Result<queue::ReorderTracksResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn replace_all_tracks(
+        &self,
+        request: queue::ReplaceAllTracksRequest,
+    ) -> Result<queue::ReplaceAllTracksResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn save_as_sonos_playlist(
+        &self,
+        request: queue::SaveAsSonosPlaylistRequest,
+    ) -> Result<queue::SaveAsSonosPlaylistResponse>;
+}
+impl Queue for SonosDevice {
+    async fn add_multiple_uris(
+        &self,
+        request: queue::AddMultipleUrisRequest,
+    ) -> Result<queue::AddMultipleUrisResponse> {
+        self.action(&queue::SERVICE_TYPE, "AddMultipleURIs", request).await
+    }
+    async fn add_uri(
+        &self,
+        request: queue::AddUriRequest,
+    ) -> Result<queue::AddUriResponse> {
+        self.action(&queue::SERVICE_TYPE, "AddURI", request).await
+    }
+    async fn attach_queue(
+        &self,
+        request: queue::AttachQueueRequest,
+    ) -> Result<queue::AttachQueueResponse> {
+        self.action(&queue::SERVICE_TYPE, "AttachQueue", request).await
+    }
+    async fn backup(&self) -> Result<()> {
+        self.action(&queue::SERVICE_TYPE, "Backup", crate::soap::Unit {}).await
+    }
+    async fn browse(
+        &self,
+        request: queue::BrowseRequest,
+    ) -> Result<queue::BrowseResponse> {
+        self.action(&queue::SERVICE_TYPE, "Browse", request).await
+    }
+    async fn create_queue(
+        &self,
+        request: queue::CreateQueueRequest,
+    ) -> Result<queue::CreateQueueResponse> {
+        self.action(&queue::SERVICE_TYPE, "CreateQueue", request).await
+    }
+    async fn remove_all_tracks(
+        &self,
+        request: queue::RemoveAllTracksRequest,
+    ) -> Result<queue::RemoveAllTracksResponse> {
+        self.action(&queue::SERVICE_TYPE, "RemoveAllTracks", request).await
+    }
+    async fn remove_track_range(
+        &self,
+        request: queue::RemoveTrackRangeRequest,
+    ) -> Result<queue::RemoveTrackRangeResponse> {
+        self.action(&queue::SERVICE_TYPE, "RemoveTrackRange", request).await
+    }
+    async fn reorder_tracks(
+        &self,
+        request: queue::ReorderTracksRequest,
+    ) -> Result<queue::ReorderTracksResponse> {
+        self.action(&queue::SERVICE_TYPE, "ReorderTracks", request).await
+    }
+    async fn replace_all_tracks(
+        &self,
+        request: queue::ReplaceAllTracksRequest,
+    ) -> Result<queue::ReplaceAllTracksResponse> {
+        self.action(&queue::SERVICE_TYPE, "ReplaceAllTracks", request).await
+    }
+    async fn save_as_sonos_playlist(
+        &self,
+        request: queue::SaveAsSonosPlaylistRequest,
+    ) -> Result<queue::SaveAsSonosPlaylistResponse> {
+        self.action(&queue::SERVICE_TYPE, "SaveAsSonosPlaylist", request).await
+    }
+}
+/// A variant of `Queue` that can be used as a trait object,
+/// eg: `Box<dyn QueueDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait QueueDyn: Send + Sync {
+    /// See `Queue::add_multiple_uris`
+    fn add_multiple_uris(
+        &self,
+        request: queue::AddMultipleUrisRequest,
+    ) -> ActionFuture<'_, queue::AddMultipleUrisResponse>;
+    /// See `Queue::add_uri`
+    fn add_uri(
+        &self,
+        request: queue::AddUriRequest,
+    ) -> ActionFuture<'_, queue::AddUriResponse>;
+    /// See `Queue::attach_queue`
+    fn attach_queue(
+        &self,
+        request: queue::AttachQueueRequest,
+    ) -> ActionFuture<'_, queue::AttachQueueResponse>;
+    /// See `Queue::backup`
+    fn backup(&self) -> ActionFuture<'_, ()>;
+    /// See `Queue::browse`
+    fn browse(
+        &self,
+        request: queue::BrowseRequest,
+    ) -> ActionFuture<'_, queue::BrowseResponse>;
+    /// See `Queue::create_queue`
+    fn create_queue(
+        &self,
+        request: queue::CreateQueueRequest,
+    ) -> ActionFuture<'_, queue::CreateQueueResponse>;
+    /// See `Queue::remove_all_tracks`
+    fn remove_all_tracks(
+        &self,
+        request: queue::RemoveAllTracksRequest,
+    ) -> ActionFuture<'_, queue::RemoveAllTracksResponse>;
+    /// See `Queue::remove_track_range`
+    fn remove_track_range(
+        &self,
+        request: queue::RemoveTrackRangeRequest,
+    ) -> ActionFuture<'_, queue::RemoveTrackRangeResponse>;
+    /// See `Queue::reorder_tracks`
+    fn reorder_tracks(
+        &self,
+        request: queue::ReorderTracksRequest,
+    ) -> ActionFuture<'_, queue::ReorderTracksResponse>;
+    /// See `Queue::replace_all_tracks`
+    fn replace_all_tracks(
+        &self,
+        request: queue::ReplaceAllTracksRequest,
+    ) -> ActionFuture<'_, queue::ReplaceAllTracksResponse>;
+    /// See `Queue::save_as_sonos_playlist`
+    fn save_as_sonos_playlist(
+        &self,
+        request: queue::SaveAsSonosPlaylistRequest,
+    ) -> ActionFuture<'_, queue::SaveAsSonosPlaylistResponse>;
+}
+#[cfg(feature = "dyn-traits")]
+impl QueueDyn for SonosDevice {
+    fn add_multiple_uris(
+        &self,
+        request: queue::AddMultipleUrisRequest,
+    ) -> ActionFuture<'_, queue::AddMultipleUrisResponse> {
+        Box::pin(<Self as Queue>::add_multiple_uris(self, request))
+    }
+    fn add_uri(
+        &self,
+        request: queue::AddUriRequest,
+    ) -> ActionFuture<'_, queue::AddUriResponse> {
+        Box::pin(<Self as Queue>::add_uri(self, request))
+    }
+    fn attach_queue(
+        &self,
+        request: queue::AttachQueueRequest,
+    ) -> ActionFuture<'_, queue::AttachQueueResponse> {
+        Box::pin(<Self as Queue>::attach_queue(self, request))
+    }
+    fn backup(&self) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as Queue>::backup(self))
+    }
+    fn browse(
+        &self,
+        request: queue::BrowseRequest,
+    ) -> ActionFuture<'_, queue::BrowseResponse> {
+        Box::pin(<Self as Queue>::browse(self, request))
+    }
+    fn create_queue(
+        &self,
+        request: queue::CreateQueueRequest,
+    ) -> ActionFuture<'_, queue::CreateQueueResponse> {
+        Box::pin(<Self as Queue>::create_queue(self, request))
+    }
+    fn remove_all_tracks(
+        &self,
+        request: queue::RemoveAllTracksRequest,
+    ) -> ActionFuture<'_, queue::RemoveAllTracksResponse> {
+        Box::pin(<Self as Queue>::remove_all_tracks(self, request))
+    }
+    fn remove_track_range(
+        &self,
+        request: queue::RemoveTrackRangeRequest,
+    ) -> ActionFuture<'_, queue::RemoveTrackRangeResponse> {
+        Box::pin(<Self as Queue>::remove_track_range(self, request))
+    }
+    fn reorder_tracks(
+        &self,
+        request: queue::ReorderTracksRequest,
+    ) -> ActionFuture<'_, queue::ReorderTracksResponse> {
+        Box::pin(<Self as Queue>::reorder_tracks(self, request))
+    }
+    fn replace_all_tracks(
+        &self,
+        request: queue::ReplaceAllTracksRequest,
+    ) -> ActionFuture<'_, queue::ReplaceAllTracksResponse> {
+        Box::pin(<Self as Queue>::replace_all_tracks(self, request))
+    }
+    fn save_as_sonos_playlist(
+        &self,
+        request: queue::SaveAsSonosPlaylistRequest,
+    ) -> ActionFuture<'_, queue::SaveAsSonosPlaylistResponse> {
+        Box::pin(<Self as Queue>::save_as_sonos_playlist(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_add_multiple_uris_request() {
+        let request = AddMultipleUrisRequest {
+            queue_id: 1,
+            update_id: 1,
+            container_uri: "ContainerURI".to_string(),
+            container_meta_data: "ContainerMetaData".to_string(),
+            desired_first_track_number_enqueued: 1,
+            enqueue_as_next: true,
+            number_of_uris: 1,
+            enqueued_uris_and_meta_data: "EnqueuedURIsAndMetaData".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddMultipleUrisRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_multiple_uris_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddMultipleURIsResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><FirstTrackNumberEnqueued>1</FirstTrackNumberEnqueued><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:AddMultipleURIsResponse></s:Body></s:Envelope>"#;
+        let expected = AddMultipleUrisResponse {
+            first_track_number_enqueued: Some(1),
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <AddMultipleUrisResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_add_uri_request() {
+        let request = AddUriRequest {
+            queue_id: 1,
+            update_id: 1,
+            enqueued_uri: "EnqueuedURI".to_string(),
+            enqueued_uri_meta_data: DecodeXmlString(
+                Some(crate::TrackMetaData {
+                    id: Some("-1".to_string()),
+                    title: "Title".to_string(),
+                    url: "http://example.com/track.mp3".to_string(),
+                    mime_type: Some("audio/mpeg".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            desired_first_track_number_enqueued: 1,
+            enqueue_as_next: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddUriRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_uri_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddURIResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><FirstTrackNumberEnqueued>1</FirstTrackNumberEnqueued><NumTracksAdded>1</NumTracksAdded><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:AddURIResponse></s:Body></s:Envelope>"#;
+        let expected = AddUriResponse {
+            first_track_number_enqueued: Some(1),
+            num_tracks_added: Some(1),
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <AddUriResponse as crate::DecodeSoapResponse>::decode_soap_xml(xml)
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_attach_queue_request() {
+        let request = AttachQueueRequest {
+            queue_owner_id: "QueueOwnerID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AttachQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_attach_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AttachQueueResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><QueueID>1</QueueID><QueueOwnerContext>QueueOwnerContext</QueueOwnerContext></u:AttachQueueResponse></s:Body></s:Envelope>"#;
+        let expected = AttachQueueResponse {
+            queue_id: Some(1),
+            queue_owner_context: Some("QueueOwnerContext".to_string()),
+        };
+        let decoded = <AttachQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_browse_request() {
+        let request = BrowseRequest {
+            queue_id: 1,
+            starting_index: 1,
+            requested_count: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BrowseRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_browse_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:BrowseResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><NumberReturned>1</NumberReturned><TotalMatches>1</TotalMatches><UpdateID>1</UpdateID></u:BrowseResponse></s:Body></s:Envelope>"#;
+        let expected = BrowseResponse {
+            result: None,
+            number_returned: Some(1),
+            total_matches: Some(1),
+            update_id: Some(1),
+        };
+        let decoded = <BrowseResponse as crate::DecodeSoapResponse>::decode_soap_xml(xml)
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_create_queue_request() {
+        let request = CreateQueueRequest {
+            queue_owner_id: "QueueOwnerID".to_string(),
+            queue_owner_context: "QueueOwnerContext".to_string(),
+            queue_policy: "QueuePolicy".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CreateQueueRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_create_queue_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:CreateQueueResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><QueueID>1</QueueID></u:CreateQueueResponse></s:Body></s:Envelope>"#;
+        let expected = CreateQueueResponse {
+            queue_id: Some(1),
+        };
+        let decoded = <CreateQueueResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_remove_all_tracks_request() {
+        let request = RemoveAllTracksRequest {
+            queue_id: 1,
+            update_id: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveAllTracksRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_all_tracks_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:RemoveAllTracksResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><NewUpdateID>1</NewUpdateID></u:RemoveAllTracksResponse></s:Body></s:Envelope>"#;
+        let expected = RemoveAllTracksResponse {
+            new_update_id: Some(1),
+        };
+        let decoded = <RemoveAllTracksResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_remove_track_range_request() {
+        let request = RemoveTrackRangeRequest {
+            queue_id: 1,
+            update_id: 1,
+            starting_index: 1,
+            number_of_tracks: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveTrackRangeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_track_range_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:RemoveTrackRangeResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><NewUpdateID>1</NewUpdateID></u:RemoveTrackRangeResponse></s:Body></s:Envelope>"#;
+        let expected = RemoveTrackRangeResponse {
+            new_update_id: Some(1),
+        };
+        let decoded = <RemoveTrackRangeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_reorder_tracks_request() {
+        let request = ReorderTracksRequest {
+            queue_id: 1,
+            starting_index: 1,
+            number_of_tracks: 1,
+            insert_before: 1,
+            update_id: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReorderTracksRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_reorder_tracks_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ReorderTracksResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><NewUpdateID>1</NewUpdateID></u:ReorderTracksResponse></s:Body></s:Envelope>"#;
+        let expected = ReorderTracksResponse {
+            new_update_id: Some(1),
+        };
+        let decoded = <ReorderTracksResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_replace_all_tracks_request() {
+        let request = ReplaceAllTracksRequest {
+            queue_id: 1,
+            update_id: 1,
+            container_uri: "ContainerURI".to_string(),
+            container_meta_data: "ContainerMetaData".to_string(),
+            current_track_index: 1,
+            new_current_track_indices: "NewCurrentTrackIndices".to_string(),
+            number_of_uris: 1,
+            enqueued_uris_and_meta_data: "EnqueuedURIsAndMetaData".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReplaceAllTracksRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_replace_all_tracks_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ReplaceAllTracksResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><NewQueueLength>1</NewQueueLength><NewUpdateID>1</NewUpdateID></u:ReplaceAllTracksResponse></s:Body></s:Envelope>"#;
+        let expected = ReplaceAllTracksResponse {
+            new_queue_length: Some(1),
+            new_update_id: Some(1),
+        };
+        let decoded = <ReplaceAllTracksResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_save_as_sonos_playlist_request() {
+        let request = SaveAsSonosPlaylistRequest {
+            queue_id: 1,
+            title: "Title".to_string(),
+            object_id: "ObjectID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SaveAsSonosPlaylistRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_save_as_sonos_playlist_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SaveAsSonosPlaylistResponse xmlns:u="urn:schemas-sonos-com:service:Queue:1"><AssignedObjectID>AssignedObjectID</AssignedObjectID></u:SaveAsSonosPlaylistResponse></s:Body></s:Envelope>"#;
+        let expected = SaveAsSonosPlaylistResponse {
+            assigned_object_id: Some("AssignedObjectID".to_string()),
+        };
+        let decoded = <SaveAsSonosPlaylistResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+}
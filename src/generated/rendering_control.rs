@@ -0,0 +1,2845 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `RenderingControl` service.
+use super::*;
+/// URN for the `RenderingControl` service.
+/// `urn:schemas-upnp-org:service:RenderingControl:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetBass", ns(SERVICE_TYPE))]
+pub struct GetBassRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetBassResponse", ns(SERVICE_TYPE))]
+pub struct GetBassResponse {
+    #[xml(rename = "CurrentBass", ns(""))]
+    pub current_bass: Option<super::Bass>,
+}
+impl crate::DecodeSoapResponse for GetBassResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetEQ", ns(SERVICE_TYPE))]
+pub struct GetEqRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Allowed values `DialogLevel` (bool) / `MusicSurroundLevel` (-15/+15) /  `NightMode` (bool) / `SubGain` (-10/+10) / `SurroundEnable` (bool) / `SurroundLevel` (-15/+15) / `SurroundMode` (0 = ambient, 1 = full) / `HeightChannelLevel` (-10/+10)
+    #[xml(rename = "EQType", ns(""))]
+    pub eq_type: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetEQResponse", ns(SERVICE_TYPE))]
+pub struct GetEqResponse {
+    #[xml(rename = "CurrentValue", ns(""))]
+    pub current_value: Option<i16>,
+}
+impl crate::DecodeSoapResponse for GetEqResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHeadphoneConnected", ns(SERVICE_TYPE))]
+pub struct GetHeadphoneConnectedRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetHeadphoneConnectedResponse", ns(SERVICE_TYPE))]
+pub struct GetHeadphoneConnectedResponse {
+    #[xml(rename = "CurrentHeadphoneConnected", ns(""))]
+    pub current_headphone_connected: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetHeadphoneConnectedResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLoudness", ns(SERVICE_TYPE))]
+pub struct GetLoudnessRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetLoudnessResponse", ns(SERVICE_TYPE))]
+pub struct GetLoudnessResponse {
+    #[xml(rename = "CurrentLoudness", ns(""))]
+    pub current_loudness: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetLoudnessResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetMute", ns(SERVICE_TYPE))]
+pub struct GetMuteRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::MuteChannel,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetMuteResponse", ns(SERVICE_TYPE))]
+pub struct GetMuteResponse {
+    #[xml(rename = "CurrentMute", ns(""))]
+    pub current_mute: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetMuteResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetOutputFixed", ns(SERVICE_TYPE))]
+pub struct GetOutputFixedRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetOutputFixedResponse", ns(SERVICE_TYPE))]
+pub struct GetOutputFixedResponse {
+    #[xml(rename = "CurrentFixed", ns(""))]
+    pub current_fixed: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetOutputFixedResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRoomCalibrationStatus", ns(SERVICE_TYPE))]
+pub struct GetRoomCalibrationStatusRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRoomCalibrationStatusResponse", ns(SERVICE_TYPE))]
+pub struct GetRoomCalibrationStatusResponse {
+    #[xml(rename = "RoomCalibrationEnabled", ns(""))]
+    pub room_calibration_enabled: Option<bool>,
+    #[xml(rename = "RoomCalibrationAvailable", ns(""))]
+    pub room_calibration_available: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetRoomCalibrationStatusResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSupportsOutputFixed", ns(SERVICE_TYPE))]
+pub struct GetSupportsOutputFixedRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetSupportsOutputFixedResponse", ns(SERVICE_TYPE))]
+pub struct GetSupportsOutputFixedResponse {
+    #[xml(rename = "CurrentSupportsFixed", ns(""))]
+    pub current_supports_fixed: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetSupportsOutputFixedResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTreble", ns(SERVICE_TYPE))]
+pub struct GetTrebleRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetTrebleResponse", ns(SERVICE_TYPE))]
+pub struct GetTrebleResponse {
+    #[xml(rename = "CurrentTreble", ns(""))]
+    pub current_treble: Option<super::Treble>,
+}
+impl crate::DecodeSoapResponse for GetTrebleResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolume", ns(SERVICE_TYPE))]
+pub struct GetVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolumeResponse", ns(SERVICE_TYPE))]
+pub struct GetVolumeResponse {
+    #[xml(rename = "CurrentVolume", ns(""))]
+    pub current_volume: Option<super::Volume>,
+}
+impl crate::DecodeSoapResponse for GetVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolumeDB", ns(SERVICE_TYPE))]
+pub struct GetVolumeDbRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolumeDBResponse", ns(SERVICE_TYPE))]
+pub struct GetVolumeDbResponse {
+    #[xml(rename = "CurrentVolume", ns(""))]
+    pub current_volume: Option<i16>,
+}
+impl crate::DecodeSoapResponse for GetVolumeDbResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolumeDBRange", ns(SERVICE_TYPE))]
+pub struct GetVolumeDbRangeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetVolumeDBRangeResponse", ns(SERVICE_TYPE))]
+pub struct GetVolumeDbRangeResponse {
+    #[xml(rename = "MinValue", ns(""))]
+    pub min_value: Option<i16>,
+    #[xml(rename = "MaxValue", ns(""))]
+    pub max_value: Option<i16>,
+}
+impl crate::DecodeSoapResponse for GetVolumeDbRangeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RampToVolume", ns(SERVICE_TYPE))]
+pub struct RampToVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+    #[xml(rename = "RampType", ns(""))]
+    pub ramp_type: super::RampType,
+    #[xml(rename = "DesiredVolume", ns(""))]
+    pub desired_volume: super::Volume,
+    #[xml(rename = "ResetVolumeAfter", ns(""))]
+    pub reset_volume_after: bool,
+    #[xml(rename = "ProgramURI", ns(""))]
+    pub program_uri: String,
+}
+impl RampToVolumeRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> RampToVolumeRequestBuilder {
+        RampToVolumeRequestBuilder::default()
+    }
+}
+/// Builds a `RampToVolumeRequest`; see `RampToVolumeRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RampToVolumeRequestBuilder {
+    request: RampToVolumeRequest,
+}
+impl RampToVolumeRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn channel(mut self, channel: super::Channel) -> Self {
+        self.request.channel = channel;
+        self
+    }
+    pub fn ramp_type(mut self, ramp_type: super::RampType) -> Self {
+        self.request.ramp_type = ramp_type;
+        self
+    }
+    pub fn desired_volume(mut self, desired_volume: super::Volume) -> Self {
+        self.request.desired_volume = desired_volume;
+        self
+    }
+    pub fn reset_volume_after(mut self, reset_volume_after: bool) -> Self {
+        self.request.reset_volume_after = reset_volume_after;
+        self
+    }
+    pub fn program_uri(mut self, program_uri: impl Into<String>) -> Self {
+        self.request.program_uri = program_uri.into();
+        self
+    }
+    pub fn build(self) -> RampToVolumeRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RampToVolumeResponse", ns(SERVICE_TYPE))]
+pub struct RampToVolumeResponse {
+    #[xml(rename = "RampTime", ns(""))]
+    pub ramp_time: Option<u32>,
+}
+impl crate::DecodeSoapResponse for RampToVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ResetBasicEQ", ns(SERVICE_TYPE))]
+pub struct ResetBasicEqRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ResetBasicEQResponse", ns(SERVICE_TYPE))]
+pub struct ResetBasicEqResponse {
+    #[xml(rename = "Bass", ns(""))]
+    pub bass: Option<super::Bass>,
+    #[xml(rename = "Treble", ns(""))]
+    pub treble: Option<super::Treble>,
+    #[xml(rename = "Loudness", ns(""))]
+    pub loudness: Option<bool>,
+    #[xml(rename = "LeftVolume", ns(""))]
+    pub left_volume: Option<u16>,
+    #[xml(rename = "RightVolume", ns(""))]
+    pub right_volume: Option<u16>,
+}
+impl crate::DecodeSoapResponse for ResetBasicEqResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ResetExtEQ", ns(SERVICE_TYPE))]
+pub struct ResetExtEqRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "EQType", ns(""))]
+    pub eq_type: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RestoreVolumePriorToRamp", ns(SERVICE_TYPE))]
+pub struct RestoreVolumePriorToRampRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetBass", ns(SERVICE_TYPE))]
+pub struct SetBassRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "DesiredBass", ns(""))]
+    pub desired_bass: super::Bass,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetChannelMap", ns(SERVICE_TYPE))]
+pub struct SetChannelMapRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "ChannelMap", ns(""))]
+    pub channel_map: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetEQ", ns(SERVICE_TYPE))]
+pub struct SetEqRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// Allowed values `DialogLevel` (bool) / `MusicSurroundLevel` (-15/+15) /  `NightMode` (bool) / `SubGain` (-10/+10) / `SurroundEnable` (bool) / `SurroundLevel` (-15/+15) / `SurroundMode` (0 = ambient, 1 = full) / `HeightChannelLevel` (-10/+10)
+    #[xml(rename = "EQType", ns(""))]
+    pub eq_type: String,
+    /// Booleans required `1` for true or `0` for false, rest number as specified
+    #[xml(rename = "DesiredValue", ns(""))]
+    pub desired_value: i16,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetLoudness", ns(SERVICE_TYPE))]
+pub struct SetLoudnessRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+    #[xml(rename = "DesiredLoudness", ns(""))]
+    pub desired_loudness: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetMute", ns(SERVICE_TYPE))]
+pub struct SetMuteRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::MuteChannel,
+    #[xml(rename = "DesiredMute", ns(""))]
+    pub desired_mute: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetOutputFixed", ns(SERVICE_TYPE))]
+pub struct SetOutputFixedRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "DesiredFixed", ns(""))]
+    pub desired_fixed: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRelativeVolume", ns(SERVICE_TYPE))]
+pub struct SetRelativeVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+    #[xml(rename = "Adjustment", ns(""))]
+    pub adjustment: i32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRelativeVolumeResponse", ns(SERVICE_TYPE))]
+pub struct SetRelativeVolumeResponse {
+    #[xml(rename = "NewVolume", ns(""))]
+    pub new_volume: Option<super::Volume>,
+}
+impl crate::DecodeSoapResponse for SetRelativeVolumeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRoomCalibrationStatus", ns(SERVICE_TYPE))]
+pub struct SetRoomCalibrationStatusRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "RoomCalibrationEnabled", ns(""))]
+    pub room_calibration_enabled: bool,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetRoomCalibrationX", ns(SERVICE_TYPE))]
+pub struct SetRoomCalibrationXRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CalibrationID", ns(""))]
+    pub calibration_id: String,
+    #[xml(rename = "Coefficients", ns(""))]
+    pub coefficients: String,
+    #[xml(rename = "CalibrationMode", ns(""))]
+    pub calibration_mode: String,
+}
+impl SetRoomCalibrationXRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> SetRoomCalibrationXRequestBuilder {
+        SetRoomCalibrationXRequestBuilder::default()
+    }
+}
+/// Builds a `SetRoomCalibrationXRequest`; see `SetRoomCalibrationXRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SetRoomCalibrationXRequestBuilder {
+    request: SetRoomCalibrationXRequest,
+}
+impl SetRoomCalibrationXRequestBuilder {
+    pub fn instance_id(mut self, instance_id: crate::InstanceId) -> Self {
+        self.request.instance_id = instance_id;
+        self
+    }
+    pub fn calibration_id(mut self, calibration_id: impl Into<String>) -> Self {
+        self.request.calibration_id = calibration_id.into();
+        self
+    }
+    pub fn coefficients(mut self, coefficients: impl Into<String>) -> Self {
+        self.request.coefficients = coefficients.into();
+        self
+    }
+    pub fn calibration_mode(mut self, calibration_mode: impl Into<String>) -> Self {
+        self.request.calibration_mode = calibration_mode.into();
+        self
+    }
+    pub fn build(self) -> SetRoomCalibrationXRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetTreble", ns(SERVICE_TYPE))]
+pub struct SetTrebleRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    /// between -10 and 10
+    #[xml(rename = "DesiredTreble", ns(""))]
+    pub desired_treble: super::Treble,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetVolume", ns(SERVICE_TYPE))]
+pub struct SetVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+    #[xml(rename = "DesiredVolume", ns(""))]
+    pub desired_volume: super::Volume,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetVolumeDB", ns(SERVICE_TYPE))]
+pub struct SetVolumeDbRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Channel", ns(""))]
+    pub channel: super::Channel,
+    #[xml(rename = "DesiredVolume", ns(""))]
+    pub desired_volume: i16,
+}
+/// A parsed event produced by the `RenderingControl` service.
+/// Use `SonosDevice::subscribe_rendering_control()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderingControlEvent {
+    pub last_change: Option<DecodeXmlString<RenderingControlLastChangeMap>>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct RenderingControlPropertySet {
+    pub properties: Vec<RenderingControlProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct RenderingControlProperty {
+    #[xml(rename = "LastChange", ns(""))]
+    pub last_change: Option<DecodeXmlString<RenderingControlLastChangeMap>>,
+}
+impl DecodeXml for RenderingControlEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: RenderingControlPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.last_change {
+                result.last_change.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `RenderingControl` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_rendering_control(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<RenderingControlEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderingControlLastChange {
+    pub channel: Option<super::Channel>,
+    pub channel_map: Option<String>,
+    pub eq_type: Option<String>,
+    pub instance_id: Option<crate::InstanceId>,
+    pub left_volume: Option<u16>,
+    pub mute_channel: Option<super::MuteChannel>,
+    pub program_uri: Option<String>,
+    pub ramp_time_seconds: Option<u32>,
+    pub ramp_type: Option<super::RampType>,
+    pub reset_volume_after: Option<bool>,
+    pub right_volume: Option<u16>,
+    pub volume_adjustment: Option<i32>,
+    pub audio_delay: Option<String>,
+    pub audio_delay_left_rear: Option<String>,
+    pub audio_delay_right_rear: Option<String>,
+    pub bass: Option<super::Bass>,
+    pub dialog_level: Option<crate::NumericBool>,
+    pub eq_value: Option<i16>,
+    pub headphone_connected: Option<bool>,
+    pub loudness: Option<bool>,
+    pub music_surround_level: Option<String>,
+    pub mute: Option<bool>,
+    pub night_mode: Option<bool>,
+    pub output_fixed: Option<bool>,
+    pub preset_name_list: Option<String>,
+    pub room_calibration_available: Option<bool>,
+    pub room_calibration_calibration_mode: Option<String>,
+    pub room_calibration_coefficients: Option<String>,
+    pub room_calibration_enabled: Option<bool>,
+    pub room_calibration_id: Option<String>,
+    pub speaker_size: Option<u32>,
+    pub sub_crossover: Option<String>,
+    pub sub_enabled: Option<bool>,
+    pub sub_gain: Option<String>,
+    pub sub_polarity: Option<crate::NumericBool>,
+    pub supports_output_fixed: Option<bool>,
+    pub surround_enabled: Option<bool>,
+    pub surround_level: Option<String>,
+    pub surround_mode: Option<String>,
+    pub treble: Option<super::Treble>,
+    pub volume: Option<super::Volume>,
+    pub volume_db: Option<i16>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Channel", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeChannel {
+    #[xml(attribute)]
+    val: Option<super::Channel>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ChannelMap", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeChannelMap {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "EQType", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeEQType {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "InstanceID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeInstanceID {
+    #[xml(attribute)]
+    val: Option<crate::InstanceId>,
+}
+#[derive(FromXml)]
+#[xml(rename = "LeftVolume", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeLeftVolume {
+    #[xml(attribute)]
+    val: Option<u16>,
+}
+#[derive(FromXml)]
+#[xml(rename = "MuteChannel", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeMuteChannel {
+    #[xml(attribute)]
+    val: Option<super::MuteChannel>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ProgramURI", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeProgramURI {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RampTimeSeconds", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRampTimeSeconds {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RampType", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRampType {
+    #[xml(attribute)]
+    val: Option<super::RampType>,
+}
+#[derive(FromXml)]
+#[xml(rename = "ResetVolumeAfter", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeResetVolumeAfter {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RightVolume", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRightVolume {
+    #[xml(attribute)]
+    val: Option<u16>,
+}
+#[derive(FromXml)]
+#[xml(rename = "VolumeAdjustment", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeVolumeAdjustment {
+    #[xml(attribute)]
+    val: Option<i32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AudioDelay", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeAudioDelay {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AudioDelayLeftRear", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeAudioDelayLeftRear {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "AudioDelayRightRear", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeAudioDelayRightRear {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Bass", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeBass {
+    #[xml(attribute)]
+    val: Option<super::Bass>,
+}
+#[derive(FromXml)]
+#[xml(rename = "DialogLevel", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeDialogLevel {
+    #[xml(attribute)]
+    val: Option<crate::NumericBool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "EQValue", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeEQValue {
+    #[xml(attribute)]
+    val: Option<i16>,
+}
+#[derive(FromXml)]
+#[xml(rename = "HeadphoneConnected", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeHeadphoneConnected {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Loudness", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeLoudness {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "MusicSurroundLevel", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeMusicSurroundLevel {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Mute", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeMute {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "NightMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeNightMode {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "OutputFixed", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeOutputFixed {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "PresetNameList", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangePresetNameList {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RoomCalibrationAvailable", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRoomCalibrationAvailable {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RoomCalibrationCalibrationMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRoomCalibrationCalibrationMode {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RoomCalibrationCoefficients", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRoomCalibrationCoefficients {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RoomCalibrationEnabled", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRoomCalibrationEnabled {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "RoomCalibrationID", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeRoomCalibrationID {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SpeakerSize", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSpeakerSize {
+    #[xml(attribute)]
+    val: Option<u32>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SubCrossover", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSubCrossover {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SubEnabled", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSubEnabled {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SubGain", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSubGain {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SubPolarity", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSubPolarity {
+    #[xml(attribute)]
+    val: Option<crate::NumericBool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SupportsOutputFixed", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSupportsOutputFixed {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SurroundEnabled", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSurroundEnabled {
+    #[xml(attribute)]
+    val: Option<bool>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SurroundLevel", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSurroundLevel {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "SurroundMode", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeSurroundMode {
+    #[xml(attribute)]
+    val: Option<String>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Treble", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeTreble {
+    #[xml(attribute)]
+    val: Option<super::Treble>,
+}
+#[derive(FromXml)]
+#[xml(rename = "Volume", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeVolume {
+    #[xml(attribute)]
+    val: Option<super::Volume>,
+}
+#[derive(FromXml)]
+#[xml(rename = "VolumeDB", ns(LAST_CHANGE_NS))]
+#[allow(non_camel_case_types)]
+struct RenderingControlLastChangeVolumeDB {
+    #[xml(attribute)]
+    val: Option<i16>,
+}
+const LAST_CHANGE_NS: &str = "urn:schemas-upnp-org:metadata-1-0/RCS/";
+#[derive(FromXml)]
+#[xml(rename = "InstanceID", ns(LAST_CHANGE_NS))]
+struct RenderingControlLastChangeRootObject {
+    #[xml(rename = "val", attribute)]
+    object_instance_id_: u32,
+    channel: Option<RenderingControlLastChangeChannel>,
+    channel_map: Option<RenderingControlLastChangeChannelMap>,
+    eq_type: Option<RenderingControlLastChangeEQType>,
+    instance_id: Option<RenderingControlLastChangeInstanceID>,
+    left_volume: Option<RenderingControlLastChangeLeftVolume>,
+    mute_channel: Option<RenderingControlLastChangeMuteChannel>,
+    program_uri: Option<RenderingControlLastChangeProgramURI>,
+    ramp_time_seconds: Option<RenderingControlLastChangeRampTimeSeconds>,
+    ramp_type: Option<RenderingControlLastChangeRampType>,
+    reset_volume_after: Option<RenderingControlLastChangeResetVolumeAfter>,
+    right_volume: Option<RenderingControlLastChangeRightVolume>,
+    volume_adjustment: Option<RenderingControlLastChangeVolumeAdjustment>,
+    audio_delay: Option<RenderingControlLastChangeAudioDelay>,
+    audio_delay_left_rear: Option<RenderingControlLastChangeAudioDelayLeftRear>,
+    audio_delay_right_rear: Option<RenderingControlLastChangeAudioDelayRightRear>,
+    bass: Option<RenderingControlLastChangeBass>,
+    dialog_level: Option<RenderingControlLastChangeDialogLevel>,
+    eq_value: Option<RenderingControlLastChangeEQValue>,
+    headphone_connected: Option<RenderingControlLastChangeHeadphoneConnected>,
+    loudness: Option<RenderingControlLastChangeLoudness>,
+    music_surround_level: Option<RenderingControlLastChangeMusicSurroundLevel>,
+    mute: Option<RenderingControlLastChangeMute>,
+    night_mode: Option<RenderingControlLastChangeNightMode>,
+    output_fixed: Option<RenderingControlLastChangeOutputFixed>,
+    preset_name_list: Option<RenderingControlLastChangePresetNameList>,
+    room_calibration_available: Option<
+        RenderingControlLastChangeRoomCalibrationAvailable,
+    >,
+    room_calibration_calibration_mode: Option<
+        RenderingControlLastChangeRoomCalibrationCalibrationMode,
+    >,
+    room_calibration_coefficients: Option<
+        RenderingControlLastChangeRoomCalibrationCoefficients,
+    >,
+    room_calibration_enabled: Option<RenderingControlLastChangeRoomCalibrationEnabled>,
+    room_calibration_id: Option<RenderingControlLastChangeRoomCalibrationID>,
+    speaker_size: Option<RenderingControlLastChangeSpeakerSize>,
+    sub_crossover: Option<RenderingControlLastChangeSubCrossover>,
+    sub_enabled: Option<RenderingControlLastChangeSubEnabled>,
+    sub_gain: Option<RenderingControlLastChangeSubGain>,
+    sub_polarity: Option<RenderingControlLastChangeSubPolarity>,
+    supports_output_fixed: Option<RenderingControlLastChangeSupportsOutputFixed>,
+    surround_enabled: Option<RenderingControlLastChangeSurroundEnabled>,
+    surround_level: Option<RenderingControlLastChangeSurroundLevel>,
+    surround_mode: Option<RenderingControlLastChangeSurroundMode>,
+    treble: Option<RenderingControlLastChangeTreble>,
+    volume: Option<RenderingControlLastChangeVolume>,
+    volume_db: Option<RenderingControlLastChangeVolumeDB>,
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderingControlLastChangeMap {
+    pub map: std::collections::BTreeMap<u32, RenderingControlLastChange>,
+}
+impl DecodeXml for RenderingControlLastChangeMap {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        #[derive(FromXml)]
+        #[xml(ns(LAST_CHANGE_NS, r = "urn:schemas-rinconnetworks-com:metadata-1-0/"))]
+        struct Event {
+            instance: Vec<RenderingControlLastChangeRootObject>,
+        }
+        let last_change: Event = instant_xml::from_str(xml)?;
+        let mut map = std::collections::BTreeMap::new();
+        for item in last_change.instance {
+            let mut result = RenderingControlLastChange::default();
+            result.channel = item.channel.and_then(|v| v.val);
+            result.channel_map = item.channel_map.and_then(|v| v.val);
+            result.eq_type = item.eq_type.and_then(|v| v.val);
+            result.instance_id = item.instance_id.and_then(|v| v.val);
+            result.left_volume = item.left_volume.and_then(|v| v.val);
+            result.mute_channel = item.mute_channel.and_then(|v| v.val);
+            result.program_uri = item.program_uri.and_then(|v| v.val);
+            result.ramp_time_seconds = item.ramp_time_seconds.and_then(|v| v.val);
+            result.ramp_type = item.ramp_type.and_then(|v| v.val);
+            result.reset_volume_after = item.reset_volume_after.and_then(|v| v.val);
+            result.right_volume = item.right_volume.and_then(|v| v.val);
+            result.volume_adjustment = item.volume_adjustment.and_then(|v| v.val);
+            result.audio_delay = item.audio_delay.and_then(|v| v.val);
+            result.audio_delay_left_rear = item
+                .audio_delay_left_rear
+                .and_then(|v| v.val);
+            result.audio_delay_right_rear = item
+                .audio_delay_right_rear
+                .and_then(|v| v.val);
+            result.bass = item.bass.and_then(|v| v.val);
+            result.dialog_level = item.dialog_level.and_then(|v| v.val);
+            result.eq_value = item.eq_value.and_then(|v| v.val);
+            result.headphone_connected = item.headphone_connected.and_then(|v| v.val);
+            result.loudness = item.loudness.and_then(|v| v.val);
+            result.music_surround_level = item.music_surround_level.and_then(|v| v.val);
+            result.mute = item.mute.and_then(|v| v.val);
+            result.night_mode = item.night_mode.and_then(|v| v.val);
+            result.output_fixed = item.output_fixed.and_then(|v| v.val);
+            result.preset_name_list = item.preset_name_list.and_then(|v| v.val);
+            result.room_calibration_available = item
+                .room_calibration_available
+                .and_then(|v| v.val);
+            result.room_calibration_calibration_mode = item
+                .room_calibration_calibration_mode
+                .and_then(|v| v.val);
+            result.room_calibration_coefficients = item
+                .room_calibration_coefficients
+                .and_then(|v| v.val);
+            result.room_calibration_enabled = item
+                .room_calibration_enabled
+                .and_then(|v| v.val);
+            result.room_calibration_id = item.room_calibration_id.and_then(|v| v.val);
+            result.speaker_size = item.speaker_size.and_then(|v| v.val);
+            result.sub_crossover = item.sub_crossover.and_then(|v| v.val);
+            result.sub_enabled = item.sub_enabled.and_then(|v| v.val);
+            result.sub_gain = item.sub_gain.and_then(|v| v.val);
+            result.sub_polarity = item.sub_polarity.and_then(|v| v.val);
+            result.supports_output_fixed = item
+                .supports_output_fixed
+                .and_then(|v| v.val);
+            result.surround_enabled = item.surround_enabled.and_then(|v| v.val);
+            result.surround_level = item.surround_level.and_then(|v| v.val);
+            result.surround_mode = item.surround_mode.and_then(|v| v.val);
+            result.treble = item.treble.and_then(|v| v.val);
+            result.volume = item.volume.and_then(|v| v.val);
+            result.volume_db = item.volume_db.and_then(|v| v.val);
+            map.insert(item.object_instance_id_, result);
+        }
+        Ok(RenderingControlLastChangeMap {
+            map,
+        })
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum Channel {
+    #[default]
+    Master,
+    Lf,
+    Rf,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Channel::Master => "Master",
+            Channel::Lf => "LF",
+            Channel::Rf => "RF",
+            Channel::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for Channel {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Channel> {
+        match s {
+            "Master" => Ok(Channel::Master),
+            "LF" => Ok(Channel::Lf),
+            "RF" => Ok(Channel::Rf),
+            s => Ok(Channel::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for Channel {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for Channel {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: Channel = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<Channel>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for Channel {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Channel {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum MuteChannel {
+    #[default]
+    Master,
+    Lf,
+    Rf,
+    SpeakerOnly,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for MuteChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            MuteChannel::Master => "Master",
+            MuteChannel::Lf => "LF",
+            MuteChannel::Rf => "RF",
+            MuteChannel::SpeakerOnly => "SpeakerOnly",
+            MuteChannel::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for MuteChannel {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<MuteChannel> {
+        match s {
+            "Master" => Ok(MuteChannel::Master),
+            "LF" => Ok(MuteChannel::Lf),
+            "RF" => Ok(MuteChannel::Rf),
+            "SpeakerOnly" => Ok(MuteChannel::SpeakerOnly),
+            s => Ok(MuteChannel::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for MuteChannel {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for MuteChannel {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: MuteChannel = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<MuteChannel>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for MuteChannel {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MuteChannel {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum RampType {
+    #[default]
+    SleepTimerRampType,
+    AlarmRampType,
+    AutoplayRampType,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for RampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            RampType::SleepTimerRampType => "SLEEP_TIMER_RAMP_TYPE",
+            RampType::AlarmRampType => "ALARM_RAMP_TYPE",
+            RampType::AutoplayRampType => "AUTOPLAY_RAMP_TYPE",
+            RampType::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for RampType {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<RampType> {
+        match s {
+            "SLEEP_TIMER_RAMP_TYPE" => Ok(RampType::SleepTimerRampType),
+            "ALARM_RAMP_TYPE" => Ok(RampType::AlarmRampType),
+            "AUTOPLAY_RAMP_TYPE" => Ok(RampType::AutoplayRampType),
+            s => Ok(RampType::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for RampType {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for RampType {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: RampType = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<RampType>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for RampType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RampType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// A `Bass` value, in the range `-10..=10`.
+/// Values are checked when they are constructed via `new` or
+/// `try_from`, so that an out of range value is reported before
+/// any request is made. Values reported by the device, or read
+/// back via serde, are accepted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Bass(i16);
+impl Bass {
+    pub const MIN: i16 = -10;
+    pub const MAX: i16 = 10;
+    /// Returns `Error::OutOfRange` if `value` is outside
+    /// of the range `MIN..=MAX`
+    pub fn new(value: i16) -> Result<Self> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(crate::Error::OutOfRange {
+                name: "Bass",
+                value: value.into(),
+                min: Self::MIN.into(),
+                max: Self::MAX.into(),
+            })
+        }
+    }
+    /// Returns the nearest value that is in range
+    pub fn saturating(value: i16) -> Self {
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }
+    pub fn get(self) -> i16 {
+        self.0
+    }
+}
+impl TryFrom<i16> for Bass {
+    type Error = crate::Error;
+    fn try_from(value: i16) -> Result<Self> {
+        Self::new(value)
+    }
+}
+impl From<Bass> for i16 {
+    fn from(value: Bass) -> i16 {
+        value.0
+    }
+}
+impl std::fmt::Display for Bass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl FromStr for Bass {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Bass> {
+        let value: i16 = s
+            .parse()
+            .map_err(|_| crate::Error::InvalidNumber {
+                name: "Bass",
+                value: s.to_string(),
+            })?;
+        Self::new(value)
+    }
+}
+impl instant_xml::ToXml for Bass {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for Bass {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: i16 = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(Bass(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<Bass>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// A `Treble` value, in the range `-10..=10`.
+/// Values are checked when they are constructed via `new` or
+/// `try_from`, so that an out of range value is reported before
+/// any request is made. Values reported by the device, or read
+/// back via serde, are accepted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Treble(i16);
+impl Treble {
+    pub const MIN: i16 = -10;
+    pub const MAX: i16 = 10;
+    /// Returns `Error::OutOfRange` if `value` is outside
+    /// of the range `MIN..=MAX`
+    pub fn new(value: i16) -> Result<Self> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(crate::Error::OutOfRange {
+                name: "Treble",
+                value: value.into(),
+                min: Self::MIN.into(),
+                max: Self::MAX.into(),
+            })
+        }
+    }
+    /// Returns the nearest value that is in range
+    pub fn saturating(value: i16) -> Self {
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }
+    pub fn get(self) -> i16 {
+        self.0
+    }
+}
+impl TryFrom<i16> for Treble {
+    type Error = crate::Error;
+    fn try_from(value: i16) -> Result<Self> {
+        Self::new(value)
+    }
+}
+impl From<Treble> for i16 {
+    fn from(value: Treble) -> i16 {
+        value.0
+    }
+}
+impl std::fmt::Display for Treble {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl FromStr for Treble {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Treble> {
+        let value: i16 = s
+            .parse()
+            .map_err(|_| crate::Error::InvalidNumber {
+                name: "Treble",
+                value: s.to_string(),
+            })?;
+        Self::new(value)
+    }
+}
+impl instant_xml::ToXml for Treble {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for Treble {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: i16 = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(Treble(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<Treble>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// A `Volume` value, in the range `0..=100`.
+/// Values are checked when they are constructed via `new` or
+/// `try_from`, so that an out of range value is reported before
+/// any request is made. Values reported by the device, or read
+/// back via serde, are accepted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Volume(u16);
+impl Volume {
+    pub const MIN: u16 = 0;
+    pub const MAX: u16 = 100;
+    /// Returns `Error::OutOfRange` if `value` is outside
+    /// of the range `MIN..=MAX`
+    pub fn new(value: u16) -> Result<Self> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(crate::Error::OutOfRange {
+                name: "Volume",
+                value: value.into(),
+                min: Self::MIN.into(),
+                max: Self::MAX.into(),
+            })
+        }
+    }
+    /// Returns the nearest value that is in range
+    pub fn saturating(value: u16) -> Self {
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+impl TryFrom<u16> for Volume {
+    type Error = crate::Error;
+    fn try_from(value: u16) -> Result<Self> {
+        Self::new(value)
+    }
+}
+impl From<Volume> for u16 {
+    fn from(value: Volume) -> u16 {
+        value.0
+    }
+}
+impl std::fmt::Display for Volume {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl FromStr for Volume {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Volume> {
+        let value: u16 = s
+            .parse()
+            .map_err(|_| crate::Error::InvalidNumber {
+                name: "Volume",
+                value: s.to_string(),
+            })?;
+        Self::new(value)
+    }
+}
+impl instant_xml::ToXml for Volume {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.0.serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for Volume {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: u16 = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(Volume(parsed));
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<Volume>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+#[allow(async_fn_in_trait)]
+/// Volume related controls
+pub trait RenderingControl {
+    /// Get bass level between -10 and 10
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_bass(
+        &self,
+        request: rendering_control::GetBassRequest,
+    ) -> Result<rendering_control::GetBassResponse>;
+    /// Get equalizer value
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_eq(
+        &self,
+        request: rendering_control::GetEqRequest,
+    ) -> Result<rendering_control::GetEqResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_headphone_connected(
+        &self,
+        request: rendering_control::GetHeadphoneConnectedRequest,
+    ) -> Result<rendering_control::GetHeadphoneConnectedResponse>;
+    /// Whether or not Loudness is on
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_loudness(
+        &self,
+        request: rendering_control::GetLoudnessRequest,
+    ) -> Result<rendering_control::GetLoudnessResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_mute(
+        &self,
+        request: rendering_control::GetMuteRequest,
+    ) -> Result<rendering_control::GetMuteResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_output_fixed(
+        &self,
+        request: rendering_control::GetOutputFixedRequest,
+    ) -> Result<rendering_control::GetOutputFixedResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_room_calibration_status(
+        &self,
+        request: rendering_control::GetRoomCalibrationStatusRequest,
+    ) -> Result<rendering_control::GetRoomCalibrationStatusResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_supports_output_fixed(
+        &self,
+        request: rendering_control::GetSupportsOutputFixedRequest,
+    ) -> Result<rendering_control::GetSupportsOutputFixedResponse>;
+    /// Get treble
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_treble(
+        &self,
+        request: rendering_control::GetTrebleRequest,
+    ) -> Result<rendering_control::GetTrebleResponse>;
+    /// Get volume
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_volume(
+        &self,
+        request: rendering_control::GetVolumeRequest,
+    ) -> Result<rendering_control::GetVolumeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_volume_db(
+        &self,
+        request: rendering_control::GetVolumeDbRequest,
+    ) -> Result<rendering_control::GetVolumeDbResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_volume_db_range(
+        &self,
+        request: rendering_control::GetVolumeDbRangeRequest,
+    ) -> Result<rendering_control::GetVolumeDbRangeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn ramp_to_volume(
+        &self,
+        request: rendering_control::RampToVolumeRequest,
+    ) -> Result<rendering_control::RampToVolumeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reset_basic_eq(
+        &self,
+        request: rendering_control::ResetBasicEqRequest,
+    ) -> Result<rendering_control::ResetBasicEqResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reset_ext_eq(
+        &self,
+        request: rendering_control::ResetExtEqRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn restore_volume_prior_to_ramp(
+        &self,
+        request: rendering_control::RestoreVolumePriorToRampRequest,
+    ) -> Result<()>;
+    /// Set bass level, between -10 and 10
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_bass(&self, request: rendering_control::SetBassRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_channel_map(
+        &self,
+        request: rendering_control::SetChannelMapRequest,
+    ) -> Result<()>;
+    /// Set equalizer value for different types
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_eq(&self, request: rendering_control::SetEqRequest) -> Result<()>;
+    /// Set loudness on / off
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_loudness(
+        &self,
+        request: rendering_control::SetLoudnessRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_mute(&self, request: rendering_control::SetMuteRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_output_fixed(
+        &self,
+        request: rendering_control::SetOutputFixedRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_relative_volume(
+        &self,
+        request: rendering_control::SetRelativeVolumeRequest,
+    ) -> Result<rendering_control::SetRelativeVolumeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_room_calibration_status(
+        &self,
+        request: rendering_control::SetRoomCalibrationStatusRequest,
+    ) -> Result<()>;
+    /// Supported by: `S13`, `S14`, `S21`, `S27`, `S3`, `S5`, `S6`, `Sub`
+    async fn set_room_calibration_x(
+        &self,
+        request: rendering_control::SetRoomCalibrationXRequest,
+    ) -> Result<()>;
+    /// Set treble level
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_treble(
+        &self,
+        request: rendering_control::SetTrebleRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_volume(
+        &self,
+        request: rendering_control::SetVolumeRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_volume_db(
+        &self,
+        request: rendering_control::SetVolumeDbRequest,
+    ) -> Result<()>;
+}
+impl RenderingControl for SonosDevice {
+    async fn get_bass(
+        &self,
+        request: rendering_control::GetBassRequest,
+    ) -> Result<rendering_control::GetBassResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetBass", request).await
+    }
+    async fn get_eq(
+        &self,
+        request: rendering_control::GetEqRequest,
+    ) -> Result<rendering_control::GetEqResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetEQ", request).await
+    }
+    async fn get_headphone_connected(
+        &self,
+        request: rendering_control::GetHeadphoneConnectedRequest,
+    ) -> Result<rendering_control::GetHeadphoneConnectedResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetHeadphoneConnected", request)
+            .await
+    }
+    async fn get_loudness(
+        &self,
+        request: rendering_control::GetLoudnessRequest,
+    ) -> Result<rendering_control::GetLoudnessResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetLoudness", request).await
+    }
+    async fn get_mute(
+        &self,
+        request: rendering_control::GetMuteRequest,
+    ) -> Result<rendering_control::GetMuteResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetMute", request).await
+    }
+    async fn get_output_fixed(
+        &self,
+        request: rendering_control::GetOutputFixedRequest,
+    ) -> Result<rendering_control::GetOutputFixedResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetOutputFixed", request).await
+    }
+    async fn get_room_calibration_status(
+        &self,
+        request: rendering_control::GetRoomCalibrationStatusRequest,
+    ) -> Result<rendering_control::GetRoomCalibrationStatusResponse> {
+        self.action(
+                &rendering_control::SERVICE_TYPE,
+                "GetRoomCalibrationStatus",
+                request,
+            )
+            .await
+    }
+    async fn get_supports_output_fixed(
+        &self,
+        request: rendering_control::GetSupportsOutputFixedRequest,
+    ) -> Result<rendering_control::GetSupportsOutputFixedResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetSupportsOutputFixed", request)
+            .await
+    }
+    async fn get_treble(
+        &self,
+        request: rendering_control::GetTrebleRequest,
+    ) -> Result<rendering_control::GetTrebleResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetTreble", request).await
+    }
+    async fn get_volume(
+        &self,
+        request: rendering_control::GetVolumeRequest,
+    ) -> Result<rendering_control::GetVolumeResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetVolume", request).await
+    }
+    async fn get_volume_db(
+        &self,
+        request: rendering_control::GetVolumeDbRequest,
+    ) -> Result<rendering_control::GetVolumeDbResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetVolumeDB", request).await
+    }
+    async fn get_volume_db_range(
+        &self,
+        request: rendering_control::GetVolumeDbRangeRequest,
+    ) -> Result<rendering_control::GetVolumeDbRangeResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "GetVolumeDBRange", request).await
+    }
+    async fn ramp_to_volume(
+        &self,
+        request: rendering_control::RampToVolumeRequest,
+    ) -> Result<rendering_control::RampToVolumeResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "RampToVolume", request).await
+    }
+    async fn reset_basic_eq(
+        &self,
+        request: rendering_control::ResetBasicEqRequest,
+    ) -> Result<rendering_control::ResetBasicEqResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "ResetBasicEQ", request).await
+    }
+    async fn reset_ext_eq(
+        &self,
+        request: rendering_control::ResetExtEqRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "ResetExtEQ", request).await
+    }
+    async fn restore_volume_prior_to_ramp(
+        &self,
+        request: rendering_control::RestoreVolumePriorToRampRequest,
+    ) -> Result<()> {
+        self.action(
+                &rendering_control::SERVICE_TYPE,
+                "RestoreVolumePriorToRamp",
+                request,
+            )
+            .await
+    }
+    async fn set_bass(&self, request: rendering_control::SetBassRequest) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetBass", request).await
+    }
+    async fn set_channel_map(
+        &self,
+        request: rendering_control::SetChannelMapRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetChannelMap", request).await
+    }
+    async fn set_eq(&self, request: rendering_control::SetEqRequest) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetEQ", request).await
+    }
+    async fn set_loudness(
+        &self,
+        request: rendering_control::SetLoudnessRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetLoudness", request).await
+    }
+    async fn set_mute(&self, request: rendering_control::SetMuteRequest) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetMute", request).await
+    }
+    async fn set_output_fixed(
+        &self,
+        request: rendering_control::SetOutputFixedRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetOutputFixed", request).await
+    }
+    async fn set_relative_volume(
+        &self,
+        request: rendering_control::SetRelativeVolumeRequest,
+    ) -> Result<rendering_control::SetRelativeVolumeResponse> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetRelativeVolume", request).await
+    }
+    async fn set_room_calibration_status(
+        &self,
+        request: rendering_control::SetRoomCalibrationStatusRequest,
+    ) -> Result<()> {
+        self.action(
+                &rendering_control::SERVICE_TYPE,
+                "SetRoomCalibrationStatus",
+                request,
+            )
+            .await
+    }
+    async fn set_room_calibration_x(
+        &self,
+        request: rendering_control::SetRoomCalibrationXRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetRoomCalibrationX", request)
+            .await
+    }
+    async fn set_treble(
+        &self,
+        request: rendering_control::SetTrebleRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetTreble", request).await
+    }
+    async fn set_volume(
+        &self,
+        request: rendering_control::SetVolumeRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetVolume", request).await
+    }
+    async fn set_volume_db(
+        &self,
+        request: rendering_control::SetVolumeDbRequest,
+    ) -> Result<()> {
+        self.action(&rendering_control::SERVICE_TYPE, "SetVolumeDB", request).await
+    }
+}
+/// A variant of `RenderingControl` that can be used as a trait object,
+/// eg: `Box<dyn RenderingControlDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait RenderingControlDyn: Send + Sync {
+    /// See `RenderingControl::get_bass`
+    fn get_bass(
+        &self,
+        request: rendering_control::GetBassRequest,
+    ) -> ActionFuture<'_, rendering_control::GetBassResponse>;
+    /// See `RenderingControl::get_eq`
+    fn get_eq(
+        &self,
+        request: rendering_control::GetEqRequest,
+    ) -> ActionFuture<'_, rendering_control::GetEqResponse>;
+    /// See `RenderingControl::get_headphone_connected`
+    fn get_headphone_connected(
+        &self,
+        request: rendering_control::GetHeadphoneConnectedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetHeadphoneConnectedResponse>;
+    /// See `RenderingControl::get_loudness`
+    fn get_loudness(
+        &self,
+        request: rendering_control::GetLoudnessRequest,
+    ) -> ActionFuture<'_, rendering_control::GetLoudnessResponse>;
+    /// See `RenderingControl::get_mute`
+    fn get_mute(
+        &self,
+        request: rendering_control::GetMuteRequest,
+    ) -> ActionFuture<'_, rendering_control::GetMuteResponse>;
+    /// See `RenderingControl::get_output_fixed`
+    fn get_output_fixed(
+        &self,
+        request: rendering_control::GetOutputFixedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetOutputFixedResponse>;
+    /// See `RenderingControl::get_room_calibration_status`
+    fn get_room_calibration_status(
+        &self,
+        request: rendering_control::GetRoomCalibrationStatusRequest,
+    ) -> ActionFuture<'_, rendering_control::GetRoomCalibrationStatusResponse>;
+    /// See `RenderingControl::get_supports_output_fixed`
+    fn get_supports_output_fixed(
+        &self,
+        request: rendering_control::GetSupportsOutputFixedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetSupportsOutputFixedResponse>;
+    /// See `RenderingControl::get_treble`
+    fn get_treble(
+        &self,
+        request: rendering_control::GetTrebleRequest,
+    ) -> ActionFuture<'_, rendering_control::GetTrebleResponse>;
+    /// See `RenderingControl::get_volume`
+    fn get_volume(
+        &self,
+        request: rendering_control::GetVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeResponse>;
+    /// See `RenderingControl::get_volume_db`
+    fn get_volume_db(
+        &self,
+        request: rendering_control::GetVolumeDbRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeDbResponse>;
+    /// See `RenderingControl::get_volume_db_range`
+    fn get_volume_db_range(
+        &self,
+        request: rendering_control::GetVolumeDbRangeRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeDbRangeResponse>;
+    /// See `RenderingControl::ramp_to_volume`
+    fn ramp_to_volume(
+        &self,
+        request: rendering_control::RampToVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::RampToVolumeResponse>;
+    /// See `RenderingControl::reset_basic_eq`
+    fn reset_basic_eq(
+        &self,
+        request: rendering_control::ResetBasicEqRequest,
+    ) -> ActionFuture<'_, rendering_control::ResetBasicEqResponse>;
+    /// See `RenderingControl::reset_ext_eq`
+    fn reset_ext_eq(
+        &self,
+        request: rendering_control::ResetExtEqRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::restore_volume_prior_to_ramp`
+    fn restore_volume_prior_to_ramp(
+        &self,
+        request: rendering_control::RestoreVolumePriorToRampRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_bass`
+    fn set_bass(
+        &self,
+        request: rendering_control::SetBassRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_channel_map`
+    fn set_channel_map(
+        &self,
+        request: rendering_control::SetChannelMapRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_eq`
+    fn set_eq(&self, request: rendering_control::SetEqRequest) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_loudness`
+    fn set_loudness(
+        &self,
+        request: rendering_control::SetLoudnessRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_mute`
+    fn set_mute(
+        &self,
+        request: rendering_control::SetMuteRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_output_fixed`
+    fn set_output_fixed(
+        &self,
+        request: rendering_control::SetOutputFixedRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_relative_volume`
+    fn set_relative_volume(
+        &self,
+        request: rendering_control::SetRelativeVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::SetRelativeVolumeResponse>;
+    /// See `RenderingControl::set_room_calibration_status`
+    fn set_room_calibration_status(
+        &self,
+        request: rendering_control::SetRoomCalibrationStatusRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_room_calibration_x`
+    fn set_room_calibration_x(
+        &self,
+        request: rendering_control::SetRoomCalibrationXRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_treble`
+    fn set_treble(
+        &self,
+        request: rendering_control::SetTrebleRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_volume`
+    fn set_volume(
+        &self,
+        request: rendering_control::SetVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `RenderingControl::set_volume_db`
+    fn set_volume_db(
+        &self,
+        request: rendering_control::SetVolumeDbRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl RenderingControlDyn for SonosDevice {
+    fn get_bass(
+        &self,
+        request: rendering_control::GetBassRequest,
+    ) -> ActionFuture<'_, rendering_control::GetBassResponse> {
+        Box::pin(<Self as RenderingControl>::get_bass(self, request))
+    }
+    fn get_eq(
+        &self,
+        request: rendering_control::GetEqRequest,
+    ) -> ActionFuture<'_, rendering_control::GetEqResponse> {
+        Box::pin(<Self as RenderingControl>::get_eq(self, request))
+    }
+    fn get_headphone_connected(
+        &self,
+        request: rendering_control::GetHeadphoneConnectedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetHeadphoneConnectedResponse> {
+        Box::pin(<Self as RenderingControl>::get_headphone_connected(self, request))
+    }
+    fn get_loudness(
+        &self,
+        request: rendering_control::GetLoudnessRequest,
+    ) -> ActionFuture<'_, rendering_control::GetLoudnessResponse> {
+        Box::pin(<Self as RenderingControl>::get_loudness(self, request))
+    }
+    fn get_mute(
+        &self,
+        request: rendering_control::GetMuteRequest,
+    ) -> ActionFuture<'_, rendering_control::GetMuteResponse> {
+        Box::pin(<Self as RenderingControl>::get_mute(self, request))
+    }
+    fn get_output_fixed(
+        &self,
+        request: rendering_control::GetOutputFixedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetOutputFixedResponse> {
+        Box::pin(<Self as RenderingControl>::get_output_fixed(self, request))
+    }
+    fn get_room_calibration_status(
+        &self,
+        request: rendering_control::GetRoomCalibrationStatusRequest,
+    ) -> ActionFuture<'_, rendering_control::GetRoomCalibrationStatusResponse> {
+        Box::pin(<Self as RenderingControl>::get_room_calibration_status(self, request))
+    }
+    fn get_supports_output_fixed(
+        &self,
+        request: rendering_control::GetSupportsOutputFixedRequest,
+    ) -> ActionFuture<'_, rendering_control::GetSupportsOutputFixedResponse> {
+        Box::pin(<Self as RenderingControl>::get_supports_output_fixed(self, request))
+    }
+    fn get_treble(
+        &self,
+        request: rendering_control::GetTrebleRequest,
+    ) -> ActionFuture<'_, rendering_control::GetTrebleResponse> {
+        Box::pin(<Self as RenderingControl>::get_treble(self, request))
+    }
+    fn get_volume(
+        &self,
+        request: rendering_control::GetVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeResponse> {
+        Box::pin(<Self as RenderingControl>::get_volume(self, request))
+    }
+    fn get_volume_db(
+        &self,
+        request: rendering_control::GetVolumeDbRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeDbResponse> {
+        Box::pin(<Self as RenderingControl>::get_volume_db(self, request))
+    }
+    fn get_volume_db_range(
+        &self,
+        request: rendering_control::GetVolumeDbRangeRequest,
+    ) -> ActionFuture<'_, rendering_control::GetVolumeDbRangeResponse> {
+        Box::pin(<Self as RenderingControl>::get_volume_db_range(self, request))
+    }
+    fn ramp_to_volume(
+        &self,
+        request: rendering_control::RampToVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::RampToVolumeResponse> {
+        Box::pin(<Self as RenderingControl>::ramp_to_volume(self, request))
+    }
+    fn reset_basic_eq(
+        &self,
+        request: rendering_control::ResetBasicEqRequest,
+    ) -> ActionFuture<'_, rendering_control::ResetBasicEqResponse> {
+        Box::pin(<Self as RenderingControl>::reset_basic_eq(self, request))
+    }
+    fn reset_ext_eq(
+        &self,
+        request: rendering_control::ResetExtEqRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::reset_ext_eq(self, request))
+    }
+    fn restore_volume_prior_to_ramp(
+        &self,
+        request: rendering_control::RestoreVolumePriorToRampRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::restore_volume_prior_to_ramp(self, request))
+    }
+    fn set_bass(
+        &self,
+        request: rendering_control::SetBassRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_bass(self, request))
+    }
+    fn set_channel_map(
+        &self,
+        request: rendering_control::SetChannelMapRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_channel_map(self, request))
+    }
+    fn set_eq(&self, request: rendering_control::SetEqRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_eq(self, request))
+    }
+    fn set_loudness(
+        &self,
+        request: rendering_control::SetLoudnessRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_loudness(self, request))
+    }
+    fn set_mute(
+        &self,
+        request: rendering_control::SetMuteRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_mute(self, request))
+    }
+    fn set_output_fixed(
+        &self,
+        request: rendering_control::SetOutputFixedRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_output_fixed(self, request))
+    }
+    fn set_relative_volume(
+        &self,
+        request: rendering_control::SetRelativeVolumeRequest,
+    ) -> ActionFuture<'_, rendering_control::SetRelativeVolumeResponse> {
+        Box::pin(<Self as RenderingControl>::set_relative_volume(self, request))
+    }
+    fn set_room_calibration_status(
+        &self,
+        request: rendering_control::SetRoomCalibrationStatusRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_room_calibration_status(self, request))
+    }
+    fn set_room_calibration_x(
+        &self,
+        request: rendering_control::SetRoomCalibrationXRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_room_calibration_x(self, request))
+    }
+    fn set_treble(
+        &self,
+        request: rendering_control::SetTrebleRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_treble(self, request))
+    }
+    fn set_volume(
+        &self,
+        request: rendering_control::SetVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_volume(self, request))
+    }
+    fn set_volume_db(
+        &self,
+        request: rendering_control::SetVolumeDbRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as RenderingControl>::set_volume_db(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_bass_request() {
+        let request = GetBassRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetBassRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_bass_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetBassResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentBass>1</CurrentBass></u:GetBassResponse></s:Body></s:Envelope>"#;
+        let expected = GetBassResponse {
+            current_bass: Some(Bass::new(1).unwrap()),
+        };
+        let decoded = <GetBassResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_eq_request() {
+        let request = GetEqRequest {
+            instance_id: crate::InstanceId(1),
+            eq_type: "EQType".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetEqRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_eq_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetEQResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentValue>1</CurrentValue></u:GetEQResponse></s:Body></s:Envelope>"#;
+        let expected = GetEqResponse {
+            current_value: Some(1),
+        };
+        let decoded = <GetEqResponse as crate::DecodeSoapResponse>::decode_soap_xml(xml)
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_headphone_connected_request() {
+        let request = GetHeadphoneConnectedRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetHeadphoneConnectedRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_headphone_connected_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetHeadphoneConnectedResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentHeadphoneConnected>1</CurrentHeadphoneConnected></u:GetHeadphoneConnectedResponse></s:Body></s:Envelope>"#;
+        let expected = GetHeadphoneConnectedResponse {
+            current_headphone_connected: Some(true),
+        };
+        let decoded = <GetHeadphoneConnectedResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_loudness_request() {
+        let request = GetLoudnessRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetLoudnessRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_loudness_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetLoudnessResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentLoudness>1</CurrentLoudness></u:GetLoudnessResponse></s:Body></s:Envelope>"#;
+        let expected = GetLoudnessResponse {
+            current_loudness: Some(true),
+        };
+        let decoded = <GetLoudnessResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_mute_request() {
+        let request = GetMuteRequest {
+            instance_id: crate::InstanceId(1),
+            channel: MuteChannel::SpeakerOnly,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetMuteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_mute_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetMuteResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentMute>1</CurrentMute></u:GetMuteResponse></s:Body></s:Envelope>"#;
+        let expected = GetMuteResponse {
+            current_mute: Some(true),
+        };
+        let decoded = <GetMuteResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/RenderingControl/GetMute.xml`
+    #[test]
+    fn test_get_mute_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetMuteResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentMute>0</CurrentMute></u:GetMuteResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetMuteResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded.current_mute, Some(false), "CurrentMute");
+    }
+    #[test]
+    fn test_get_output_fixed_request() {
+        let request = GetOutputFixedRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetOutputFixedRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_output_fixed_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetOutputFixedResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentFixed>1</CurrentFixed></u:GetOutputFixedResponse></s:Body></s:Envelope>"#;
+        let expected = GetOutputFixedResponse {
+            current_fixed: Some(true),
+        };
+        let decoded = <GetOutputFixedResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_room_calibration_status_request() {
+        let request = GetRoomCalibrationStatusRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetRoomCalibrationStatusRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_room_calibration_status_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetRoomCalibrationStatusResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><RoomCalibrationEnabled>1</RoomCalibrationEnabled><RoomCalibrationAvailable>1</RoomCalibrationAvailable></u:GetRoomCalibrationStatusResponse></s:Body></s:Envelope>"#;
+        let expected = GetRoomCalibrationStatusResponse {
+            room_calibration_enabled: Some(true),
+            room_calibration_available: Some(true),
+        };
+        let decoded = <GetRoomCalibrationStatusResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_supports_output_fixed_request() {
+        let request = GetSupportsOutputFixedRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetSupportsOutputFixedRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_supports_output_fixed_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetSupportsOutputFixedResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentSupportsFixed>1</CurrentSupportsFixed></u:GetSupportsOutputFixedResponse></s:Body></s:Envelope>"#;
+        let expected = GetSupportsOutputFixedResponse {
+            current_supports_fixed: Some(true),
+        };
+        let decoded = <GetSupportsOutputFixedResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_treble_request() {
+        let request = GetTrebleRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetTrebleRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_treble_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTrebleResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentTreble>1</CurrentTreble></u:GetTrebleResponse></s:Body></s:Envelope>"#;
+        let expected = GetTrebleResponse {
+            current_treble: Some(Treble::new(1).unwrap()),
+        };
+        let decoded = <GetTrebleResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_volume_request() {
+        let request = GetVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentVolume>1</CurrentVolume></u:GetVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = GetVolumeResponse {
+            current_volume: Some(Volume::new(1).unwrap()),
+        };
+        let decoded = <GetVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/RenderingControl/GetVolume.xml`
+    #[test]
+    fn test_get_volume_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentVolume>23</CurrentVolume></u:GetVolumeResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(
+            decoded.current_volume, Some(Volume::new(23).unwrap()), "CurrentVolume"
+        );
+    }
+    #[test]
+    fn test_get_volume_db_request() {
+        let request = GetVolumeDbRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetVolumeDbRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_volume_db_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetVolumeDBResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentVolume>1</CurrentVolume></u:GetVolumeDBResponse></s:Body></s:Envelope>"#;
+        let expected = GetVolumeDbResponse {
+            current_volume: Some(1),
+        };
+        let decoded = <GetVolumeDbResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_volume_db_range_request() {
+        let request = GetVolumeDbRangeRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetVolumeDbRangeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_volume_db_range_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetVolumeDBRangeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><MinValue>1</MinValue><MaxValue>1</MaxValue></u:GetVolumeDBRangeResponse></s:Body></s:Envelope>"#;
+        let expected = GetVolumeDbRangeResponse {
+            min_value: Some(1),
+            max_value: Some(1),
+        };
+        let decoded = <GetVolumeDbRangeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_ramp_to_volume_request() {
+        let request = RampToVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+            ramp_type: RampType::AutoplayRampType,
+            desired_volume: Volume::new(1).unwrap(),
+            reset_volume_after: true,
+            program_uri: "ProgramURI".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RampToVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_ramp_to_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:RampToVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><RampTime>1</RampTime></u:RampToVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = RampToVolumeResponse {
+            ramp_time: Some(1),
+        };
+        let decoded = <RampToVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_reset_basic_eq_request() {
+        let request = ResetBasicEqRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ResetBasicEqRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_reset_basic_eq_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ResetBasicEQResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><Bass>1</Bass><Treble>1</Treble><Loudness>1</Loudness><LeftVolume>1</LeftVolume><RightVolume>1</RightVolume></u:ResetBasicEQResponse></s:Body></s:Envelope>"#;
+        let expected = ResetBasicEqResponse {
+            bass: Some(Bass::new(1).unwrap()),
+            treble: Some(Treble::new(1).unwrap()),
+            loudness: Some(true),
+            left_volume: Some(1),
+            right_volume: Some(1),
+        };
+        let decoded = <ResetBasicEqResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_reset_ext_eq_request() {
+        let request = ResetExtEqRequest {
+            instance_id: crate::InstanceId(1),
+            eq_type: "EQType".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ResetExtEqRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_restore_volume_prior_to_ramp_request() {
+        let request = RestoreVolumePriorToRampRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RestoreVolumePriorToRampRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_bass_request() {
+        let request = SetBassRequest {
+            instance_id: crate::InstanceId(1),
+            desired_bass: Bass::new(1).unwrap(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetBassRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_channel_map_request() {
+        let request = SetChannelMapRequest {
+            instance_id: crate::InstanceId(1),
+            channel_map: "ChannelMap".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetChannelMapRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_eq_request() {
+        let request = SetEqRequest {
+            instance_id: crate::InstanceId(1),
+            eq_type: "EQType".to_string(),
+            desired_value: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetEqRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_loudness_request() {
+        let request = SetLoudnessRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+            desired_loudness: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetLoudnessRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_mute_request() {
+        let request = SetMuteRequest {
+            instance_id: crate::InstanceId(1),
+            channel: MuteChannel::SpeakerOnly,
+            desired_mute: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetMuteRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_output_fixed_request() {
+        let request = SetOutputFixedRequest {
+            instance_id: crate::InstanceId(1),
+            desired_fixed: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetOutputFixedRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_relative_volume_request() {
+        let request = SetRelativeVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+            adjustment: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetRelativeVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_relative_volume_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SetRelativeVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><NewVolume>1</NewVolume></u:SetRelativeVolumeResponse></s:Body></s:Envelope>"#;
+        let expected = SetRelativeVolumeResponse {
+            new_volume: Some(Volume::new(1).unwrap()),
+        };
+        let decoded = <SetRelativeVolumeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_set_room_calibration_status_request() {
+        let request = SetRoomCalibrationStatusRequest {
+            instance_id: crate::InstanceId(1),
+            room_calibration_enabled: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetRoomCalibrationStatusRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_room_calibration_x_request() {
+        let request = SetRoomCalibrationXRequest {
+            instance_id: crate::InstanceId(1),
+            calibration_id: "CalibrationID".to_string(),
+            coefficients: "Coefficients".to_string(),
+            calibration_mode: "CalibrationMode".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetRoomCalibrationXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_treble_request() {
+        let request = SetTrebleRequest {
+            instance_id: crate::InstanceId(1),
+            desired_treble: Treble::new(1).unwrap(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetTrebleRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_volume_request() {
+        let request = SetVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+            desired_volume: Volume::new(1).unwrap(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_volume_db_request() {
+        let request = SetVolumeDbRequest {
+            instance_id: crate::InstanceId(1),
+            channel: Channel::Rf,
+            desired_volume: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetVolumeDbRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
@@ -0,0 +1,1072 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `SystemProperties` service.
+use super::*;
+/// URN for the `SystemProperties` service.
+/// `urn:schemas-upnp-org:service:SystemProperties:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:SystemProperties:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddAccountX", ns(SERVICE_TYPE))]
+pub struct AddAccountXRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountID", ns(""))]
+    pub account_id: String,
+    #[xml(rename = "AccountPassword", ns(""))]
+    pub account_password: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddAccountXResponse", ns(SERVICE_TYPE))]
+pub struct AddAccountXResponse {
+    #[xml(rename = "AccountUDN", ns(""))]
+    pub account_udn: Option<String>,
+}
+impl crate::DecodeSoapResponse for AddAccountXResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddOAuthAccountX", ns(SERVICE_TYPE))]
+pub struct AddOAuthAccountXRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountToken", ns(""))]
+    pub account_token: String,
+    #[xml(rename = "AccountKey", ns(""))]
+    pub account_key: String,
+    #[xml(rename = "OAuthDeviceID", ns(""))]
+    pub o_auth_device_id: String,
+    #[xml(rename = "AuthorizationCode", ns(""))]
+    pub authorization_code: String,
+    #[xml(rename = "RedirectURI", ns(""))]
+    pub redirect_uri: String,
+    #[xml(rename = "UserIdHashCode", ns(""))]
+    pub user_id_hash_code: String,
+    #[xml(rename = "AccountTier", ns(""))]
+    pub account_tier: u32,
+}
+impl AddOAuthAccountXRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> AddOAuthAccountXRequestBuilder {
+        AddOAuthAccountXRequestBuilder::default()
+    }
+}
+/// Builds a `AddOAuthAccountXRequest`; see `AddOAuthAccountXRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AddOAuthAccountXRequestBuilder {
+    request: AddOAuthAccountXRequest,
+}
+impl AddOAuthAccountXRequestBuilder {
+    pub fn account_type(mut self, account_type: u32) -> Self {
+        self.request.account_type = account_type;
+        self
+    }
+    pub fn account_token(mut self, account_token: impl Into<String>) -> Self {
+        self.request.account_token = account_token.into();
+        self
+    }
+    pub fn account_key(mut self, account_key: impl Into<String>) -> Self {
+        self.request.account_key = account_key.into();
+        self
+    }
+    pub fn o_auth_device_id(mut self, o_auth_device_id: impl Into<String>) -> Self {
+        self.request.o_auth_device_id = o_auth_device_id.into();
+        self
+    }
+    pub fn authorization_code(mut self, authorization_code: impl Into<String>) -> Self {
+        self.request.authorization_code = authorization_code.into();
+        self
+    }
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.request.redirect_uri = redirect_uri.into();
+        self
+    }
+    pub fn user_id_hash_code(mut self, user_id_hash_code: impl Into<String>) -> Self {
+        self.request.user_id_hash_code = user_id_hash_code.into();
+        self
+    }
+    pub fn account_tier(mut self, account_tier: u32) -> Self {
+        self.request.account_tier = account_tier;
+        self
+    }
+    pub fn build(self) -> AddOAuthAccountXRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "AddOAuthAccountXResponse", ns(SERVICE_TYPE))]
+pub struct AddOAuthAccountXResponse {
+    #[xml(rename = "AccountUDN", ns(""))]
+    pub account_udn: Option<String>,
+    #[xml(rename = "AccountNickname", ns(""))]
+    pub account_nickname: Option<String>,
+}
+impl crate::DecodeSoapResponse for AddOAuthAccountXResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EditAccountMd", ns(SERVICE_TYPE))]
+pub struct EditAccountMdRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountID", ns(""))]
+    pub account_id: String,
+    #[xml(rename = "NewAccountMd", ns(""))]
+    pub new_account_md: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EditAccountPasswordX", ns(SERVICE_TYPE))]
+pub struct EditAccountPasswordXRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountID", ns(""))]
+    pub account_id: String,
+    #[xml(rename = "NewAccountPassword", ns(""))]
+    pub new_account_password: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "EnableRDM", ns(SERVICE_TYPE))]
+pub struct EnableRdmRequest {
+    #[xml(rename = "RDMValue", ns(""))]
+    pub rdm_value: bool,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetRDMResponse", ns(SERVICE_TYPE))]
+pub struct GetRdmResponse {
+    #[xml(rename = "RDMValue", ns(""))]
+    pub rdm_value: Option<bool>,
+}
+impl crate::DecodeSoapResponse for GetRdmResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetString", ns(SERVICE_TYPE))]
+pub struct GetStringRequest {
+    /// The key for this variable
+    #[xml(rename = "VariableName", ns(""))]
+    pub variable_name: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetStringResponse", ns(SERVICE_TYPE))]
+pub struct GetStringResponse {
+    #[xml(rename = "StringValue", ns(""))]
+    pub string_value: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetStringResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetWebCode", ns(SERVICE_TYPE))]
+pub struct GetWebCodeRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetWebCodeResponse", ns(SERVICE_TYPE))]
+pub struct GetWebCodeResponse {
+    #[xml(rename = "WebCode", ns(""))]
+    pub web_code: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetWebCodeResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ProvisionCredentialedTrialAccountX", ns(SERVICE_TYPE))]
+pub struct ProvisionCredentialedTrialAccountXRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountID", ns(""))]
+    pub account_id: String,
+    #[xml(rename = "AccountPassword", ns(""))]
+    pub account_password: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ProvisionCredentialedTrialAccountXResponse", ns(SERVICE_TYPE))]
+pub struct ProvisionCredentialedTrialAccountXResponse {
+    #[xml(rename = "IsExpired", ns(""))]
+    pub is_expired: Option<bool>,
+    #[xml(rename = "AccountUDN", ns(""))]
+    pub account_udn: Option<String>,
+}
+impl crate::DecodeSoapResponse for ProvisionCredentialedTrialAccountXResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RefreshAccountCredentialsX", ns(SERVICE_TYPE))]
+pub struct RefreshAccountCredentialsXRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountUID", ns(""))]
+    pub account_uid: u32,
+    #[xml(rename = "AccountToken", ns(""))]
+    pub account_token: String,
+    #[xml(rename = "AccountKey", ns(""))]
+    pub account_key: String,
+}
+impl RefreshAccountCredentialsXRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> RefreshAccountCredentialsXRequestBuilder {
+        RefreshAccountCredentialsXRequestBuilder::default()
+    }
+}
+/// Builds a `RefreshAccountCredentialsXRequest`; see `RefreshAccountCredentialsXRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RefreshAccountCredentialsXRequestBuilder {
+    request: RefreshAccountCredentialsXRequest,
+}
+impl RefreshAccountCredentialsXRequestBuilder {
+    pub fn account_type(mut self, account_type: u32) -> Self {
+        self.request.account_type = account_type;
+        self
+    }
+    pub fn account_uid(mut self, account_uid: u32) -> Self {
+        self.request.account_uid = account_uid;
+        self
+    }
+    pub fn account_token(mut self, account_token: impl Into<String>) -> Self {
+        self.request.account_token = account_token.into();
+        self
+    }
+    pub fn account_key(mut self, account_key: impl Into<String>) -> Self {
+        self.request.account_key = account_key.into();
+        self
+    }
+    pub fn build(self) -> RefreshAccountCredentialsXRequest {
+        self.request
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Remove", ns(SERVICE_TYPE))]
+pub struct RemoveRequest {
+    /// The key for this variable
+    #[xml(rename = "VariableName", ns(""))]
+    pub variable_name: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RemoveAccount", ns(SERVICE_TYPE))]
+pub struct RemoveAccountRequest {
+    #[xml(rename = "AccountType", ns(""))]
+    pub account_type: u32,
+    #[xml(rename = "AccountID", ns(""))]
+    pub account_id: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReplaceAccountX", ns(SERVICE_TYPE))]
+pub struct ReplaceAccountXRequest {
+    #[xml(rename = "AccountUDN", ns(""))]
+    pub account_udn: String,
+    #[xml(rename = "NewAccountID", ns(""))]
+    pub new_account_id: String,
+    #[xml(rename = "NewAccountPassword", ns(""))]
+    pub new_account_password: String,
+    #[xml(rename = "AccountToken", ns(""))]
+    pub account_token: String,
+    #[xml(rename = "AccountKey", ns(""))]
+    pub account_key: String,
+    #[xml(rename = "OAuthDeviceID", ns(""))]
+    pub o_auth_device_id: String,
+}
+impl ReplaceAccountXRequest {
+    /// Returns a builder for this request. Fields that are not set
+    /// keep their default value: numbers are `0`, strings are empty
+    /// and enums use their first allowed value, eg: `Channel::Master`.
+    pub fn builder() -> ReplaceAccountXRequestBuilder {
+        ReplaceAccountXRequestBuilder::default()
+    }
+}
+/// Builds a `ReplaceAccountXRequest`; see `ReplaceAccountXRequest::builder`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReplaceAccountXRequestBuilder {
+    request: ReplaceAccountXRequest,
+}
+impl ReplaceAccountXRequestBuilder {
+    pub fn account_udn(mut self, account_udn: impl Into<String>) -> Self {
+        self.request.account_udn = account_udn.into();
+        self
+    }
+    pub fn new_account_id(mut self, new_account_id: impl Into<String>) -> Self {
+        self.request.new_account_id = new_account_id.into();
+        self
+    }
+    pub fn new_account_password(
+        mut self,
+        new_account_password: impl Into<String>,
+    ) -> Self {
+        self.request.new_account_password = new_account_password.into();
+        self
+    }
+    pub fn account_token(mut self, account_token: impl Into<String>) -> Self {
+        self.request.account_token = account_token.into();
+        self
+    }
+    pub fn account_key(mut self, account_key: impl Into<String>) -> Self {
+        self.request.account_key = account_key.into();
+        self
+    }
+    pub fn o_auth_device_id(mut self, o_auth_device_id: impl Into<String>) -> Self {
+        self.request.o_auth_device_id = o_auth_device_id.into();
+        self
+    }
+    pub fn build(self) -> ReplaceAccountXRequest {
+        self.request
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReplaceAccountXResponse", ns(SERVICE_TYPE))]
+pub struct ReplaceAccountXResponse {
+    #[xml(rename = "NewAccountUDN", ns(""))]
+    pub new_account_udn: Option<String>,
+}
+impl crate::DecodeSoapResponse for ReplaceAccountXResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetAccountNicknameX", ns(SERVICE_TYPE))]
+pub struct SetAccountNicknameXRequest {
+    #[xml(rename = "AccountUDN", ns(""))]
+    pub account_udn: String,
+    #[xml(rename = "AccountNickname", ns(""))]
+    pub account_nickname: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetString", ns(SERVICE_TYPE))]
+pub struct SetStringRequest {
+    /// The key for this variable, use something unique
+    #[xml(rename = "VariableName", ns(""))]
+    pub variable_name: String,
+    #[xml(rename = "StringValue", ns(""))]
+    pub string_value: String,
+}
+/// A parsed event produced by the `SystemProperties` service.
+/// Use `SonosDevice::subscribe_system_properties()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemPropertiesEvent {
+    pub customer_id: Option<String>,
+    pub third_party_hash: Option<String>,
+    pub update_id: Option<u32>,
+    pub update_idx: Option<u32>,
+    pub voice_update_id: Option<u32>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct SystemPropertiesPropertySet {
+    pub properties: Vec<SystemPropertiesProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct SystemPropertiesProperty {
+    #[xml(rename = "CustomerID", ns(""))]
+    pub customer_id: Option<String>,
+    #[xml(rename = "ThirdPartyHash", ns(""))]
+    pub third_party_hash: Option<String>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+    #[xml(rename = "UpdateIDX", ns(""))]
+    pub update_idx: Option<u32>,
+    #[xml(rename = "VoiceUpdateID", ns(""))]
+    pub voice_update_id: Option<u32>,
+}
+impl DecodeXml for SystemPropertiesEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: SystemPropertiesPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.customer_id {
+                result.customer_id.replace(v);
+            }
+            if let Some(v) = prop.third_party_hash {
+                result.third_party_hash.replace(v);
+            }
+            if let Some(v) = prop.update_id {
+                result.update_id.replace(v);
+            }
+            if let Some(v) = prop.update_idx {
+                result.update_idx.replace(v);
+            }
+            if let Some(v) = prop.voice_update_id {
+                result.voice_update_id.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `SystemProperties` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_system_properties(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<SystemPropertiesEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Manage system-wide settings, mainly account stuff
+pub trait SystemProperties {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_account_x(
+        &self,
+        request: system_properties::AddAccountXRequest,
+    ) -> Result<system_properties::AddAccountXResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn add_o_auth_account_x(
+        &self,
+        request: system_properties::AddOAuthAccountXRequest,
+    ) -> Result<system_properties::AddOAuthAccountXResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn do_post_update_tasks(&self) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn edit_account_md(
+        &self,
+        request: system_properties::EditAccountMdRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn edit_account_password_x(
+        &self,
+        request: system_properties::EditAccountPasswordXRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn enable_rdm(
+        &self,
+        request: system_properties::EnableRdmRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_rdm(&self) -> Result<system_properties::GetRdmResponse>;
+    /// Get a saved string.
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_string(
+        &self,
+        request: system_properties::GetStringRequest,
+    ) -> Result<system_properties::GetStringResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_web_code(
+        &self,
+        request: system_properties::GetWebCodeRequest,
+    ) -> Result<system_properties::GetWebCodeResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn provision_credentialed_trial_account_x(
+        &self,
+        request: system_properties::ProvisionCredentialedTrialAccountXRequest,
+    ) -> Result<system_properties::ProvisionCredentialedTrialAccountXResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn refresh_account_credentials_x(
+        &self,
+        request: system_properties::RefreshAccountCredentialsXRequest,
+    ) -> Result<()>;
+    /// Remove a saved string
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove(&self, request: system_properties::RemoveRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn remove_account(
+        &self,
+        request: system_properties::RemoveAccountRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn replace_account_x(
+        &self,
+        request: system_properties::ReplaceAccountXRequest,
+    ) -> Result<system_properties::ReplaceAccountXResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn reset_third_party_credentials(&self) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_account_nickname_x(
+        &self,
+        request: system_properties::SetAccountNicknameXRequest,
+    ) -> Result<()>;
+    /// Save a string in the system
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_string(
+        &self,
+        request: system_properties::SetStringRequest,
+    ) -> Result<()>;
+}
+impl SystemProperties for SonosDevice {
+    async fn add_account_x(
+        &self,
+        request: system_properties::AddAccountXRequest,
+    ) -> Result<system_properties::AddAccountXResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "AddAccountX", request).await
+    }
+    async fn add_o_auth_account_x(
+        &self,
+        request: system_properties::AddOAuthAccountXRequest,
+    ) -> Result<system_properties::AddOAuthAccountXResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "AddOAuthAccountX", request).await
+    }
+    async fn do_post_update_tasks(&self) -> Result<()> {
+        self.action(
+                &system_properties::SERVICE_TYPE,
+                "DoPostUpdateTasks",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn edit_account_md(
+        &self,
+        request: system_properties::EditAccountMdRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "EditAccountMd", request).await
+    }
+    async fn edit_account_password_x(
+        &self,
+        request: system_properties::EditAccountPasswordXRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "EditAccountPasswordX", request)
+            .await
+    }
+    async fn enable_rdm(
+        &self,
+        request: system_properties::EnableRdmRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "EnableRDM", request).await
+    }
+    async fn get_rdm(&self) -> Result<system_properties::GetRdmResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "GetRDM", crate::soap::Unit {})
+            .await
+    }
+    async fn get_string(
+        &self,
+        request: system_properties::GetStringRequest,
+    ) -> Result<system_properties::GetStringResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "GetString", request).await
+    }
+    async fn get_web_code(
+        &self,
+        request: system_properties::GetWebCodeRequest,
+    ) -> Result<system_properties::GetWebCodeResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "GetWebCode", request).await
+    }
+    async fn provision_credentialed_trial_account_x(
+        &self,
+        request: system_properties::ProvisionCredentialedTrialAccountXRequest,
+    ) -> Result<system_properties::ProvisionCredentialedTrialAccountXResponse> {
+        self.action(
+                &system_properties::SERVICE_TYPE,
+                "ProvisionCredentialedTrialAccountX",
+                request,
+            )
+            .await
+    }
+    async fn refresh_account_credentials_x(
+        &self,
+        request: system_properties::RefreshAccountCredentialsXRequest,
+    ) -> Result<()> {
+        self.action(
+                &system_properties::SERVICE_TYPE,
+                "RefreshAccountCredentialsX",
+                request,
+            )
+            .await
+    }
+    async fn remove(&self, request: system_properties::RemoveRequest) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "Remove", request).await
+    }
+    async fn remove_account(
+        &self,
+        request: system_properties::RemoveAccountRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "RemoveAccount", request).await
+    }
+    async fn replace_account_x(
+        &self,
+        request: system_properties::ReplaceAccountXRequest,
+    ) -> Result<system_properties::ReplaceAccountXResponse> {
+        self.action(&system_properties::SERVICE_TYPE, "ReplaceAccountX", request).await
+    }
+    async fn reset_third_party_credentials(&self) -> Result<()> {
+        self.action(
+                &system_properties::SERVICE_TYPE,
+                "ResetThirdPartyCredentials",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn set_account_nickname_x(
+        &self,
+        request: system_properties::SetAccountNicknameXRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "SetAccountNicknameX", request)
+            .await
+    }
+    async fn set_string(
+        &self,
+        request: system_properties::SetStringRequest,
+    ) -> Result<()> {
+        self.action(&system_properties::SERVICE_TYPE, "SetString", request).await
+    }
+}
+/// A variant of `SystemProperties` that can be used as a trait object,
+/// eg: `Box<dyn SystemPropertiesDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait SystemPropertiesDyn: Send + Sync {
+    /// See `SystemProperties::add_account_x`
+    fn add_account_x(
+        &self,
+        request: system_properties::AddAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::AddAccountXResponse>;
+    /// See `SystemProperties::add_o_auth_account_x`
+    fn add_o_auth_account_x(
+        &self,
+        request: system_properties::AddOAuthAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::AddOAuthAccountXResponse>;
+    /// See `SystemProperties::do_post_update_tasks`
+    fn do_post_update_tasks(&self) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::edit_account_md`
+    fn edit_account_md(
+        &self,
+        request: system_properties::EditAccountMdRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::edit_account_password_x`
+    fn edit_account_password_x(
+        &self,
+        request: system_properties::EditAccountPasswordXRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::enable_rdm`
+    fn enable_rdm(
+        &self,
+        request: system_properties::EnableRdmRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::get_rdm`
+    fn get_rdm(&self) -> ActionFuture<'_, system_properties::GetRdmResponse>;
+    /// See `SystemProperties::get_string`
+    fn get_string(
+        &self,
+        request: system_properties::GetStringRequest,
+    ) -> ActionFuture<'_, system_properties::GetStringResponse>;
+    /// See `SystemProperties::get_web_code`
+    fn get_web_code(
+        &self,
+        request: system_properties::GetWebCodeRequest,
+    ) -> ActionFuture<'_, system_properties::GetWebCodeResponse>;
+    /// See `SystemProperties::provision_credentialed_trial_account_x`
+    fn provision_credentialed_trial_account_x(
+        &self,
+        request: system_properties::ProvisionCredentialedTrialAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::ProvisionCredentialedTrialAccountXResponse>;
+    /// See `SystemProperties::refresh_account_credentials_x`
+    fn refresh_account_credentials_x(
+        &self,
+        request: system_properties::RefreshAccountCredentialsXRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::remove`
+    fn remove(&self, request: system_properties::RemoveRequest) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::remove_account`
+    fn remove_account(
+        &self,
+        request: system_properties::RemoveAccountRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::replace_account_x`
+    fn replace_account_x(
+        &self,
+        request: system_properties::ReplaceAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::ReplaceAccountXResponse>;
+    /// See `SystemProperties::reset_third_party_credentials`
+    fn reset_third_party_credentials(&self) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::set_account_nickname_x`
+    fn set_account_nickname_x(
+        &self,
+        request: system_properties::SetAccountNicknameXRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `SystemProperties::set_string`
+    fn set_string(
+        &self,
+        request: system_properties::SetStringRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl SystemPropertiesDyn for SonosDevice {
+    fn add_account_x(
+        &self,
+        request: system_properties::AddAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::AddAccountXResponse> {
+        Box::pin(<Self as SystemProperties>::add_account_x(self, request))
+    }
+    fn add_o_auth_account_x(
+        &self,
+        request: system_properties::AddOAuthAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::AddOAuthAccountXResponse> {
+        Box::pin(<Self as SystemProperties>::add_o_auth_account_x(self, request))
+    }
+    fn do_post_update_tasks(&self) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::do_post_update_tasks(self))
+    }
+    fn edit_account_md(
+        &self,
+        request: system_properties::EditAccountMdRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::edit_account_md(self, request))
+    }
+    fn edit_account_password_x(
+        &self,
+        request: system_properties::EditAccountPasswordXRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::edit_account_password_x(self, request))
+    }
+    fn enable_rdm(
+        &self,
+        request: system_properties::EnableRdmRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::enable_rdm(self, request))
+    }
+    fn get_rdm(&self) -> ActionFuture<'_, system_properties::GetRdmResponse> {
+        Box::pin(<Self as SystemProperties>::get_rdm(self))
+    }
+    fn get_string(
+        &self,
+        request: system_properties::GetStringRequest,
+    ) -> ActionFuture<'_, system_properties::GetStringResponse> {
+        Box::pin(<Self as SystemProperties>::get_string(self, request))
+    }
+    fn get_web_code(
+        &self,
+        request: system_properties::GetWebCodeRequest,
+    ) -> ActionFuture<'_, system_properties::GetWebCodeResponse> {
+        Box::pin(<Self as SystemProperties>::get_web_code(self, request))
+    }
+    fn provision_credentialed_trial_account_x(
+        &self,
+        request: system_properties::ProvisionCredentialedTrialAccountXRequest,
+    ) -> ActionFuture<
+        '_,
+        system_properties::ProvisionCredentialedTrialAccountXResponse,
+    > {
+        Box::pin(
+            <Self as SystemProperties>::provision_credentialed_trial_account_x(
+                self,
+                request,
+            ),
+        )
+    }
+    fn refresh_account_credentials_x(
+        &self,
+        request: system_properties::RefreshAccountCredentialsXRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(
+            <Self as SystemProperties>::refresh_account_credentials_x(self, request),
+        )
+    }
+    fn remove(&self, request: system_properties::RemoveRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::remove(self, request))
+    }
+    fn remove_account(
+        &self,
+        request: system_properties::RemoveAccountRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::remove_account(self, request))
+    }
+    fn replace_account_x(
+        &self,
+        request: system_properties::ReplaceAccountXRequest,
+    ) -> ActionFuture<'_, system_properties::ReplaceAccountXResponse> {
+        Box::pin(<Self as SystemProperties>::replace_account_x(self, request))
+    }
+    fn reset_third_party_credentials(&self) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::reset_third_party_credentials(self))
+    }
+    fn set_account_nickname_x(
+        &self,
+        request: system_properties::SetAccountNicknameXRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::set_account_nickname_x(self, request))
+    }
+    fn set_string(
+        &self,
+        request: system_properties::SetStringRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as SystemProperties>::set_string(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_add_account_x_request() {
+        let request = AddAccountXRequest {
+            account_type: 1,
+            account_id: "AccountID".to_string(),
+            account_password: "AccountPassword".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddAccountXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_account_x_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddAccountXResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><AccountUDN>AccountUDN</AccountUDN></u:AddAccountXResponse></s:Body></s:Envelope>"#;
+        let expected = AddAccountXResponse {
+            account_udn: Some("AccountUDN".to_string()),
+        };
+        let decoded = <AddAccountXResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_add_o_auth_account_x_request() {
+        let request = AddOAuthAccountXRequest {
+            account_type: 1,
+            account_token: "AccountToken".to_string(),
+            account_key: "AccountKey".to_string(),
+            o_auth_device_id: "OAuthDeviceID".to_string(),
+            authorization_code: "AuthorizationCode".to_string(),
+            redirect_uri: "RedirectURI".to_string(),
+            user_id_hash_code: "UserIdHashCode".to_string(),
+            account_tier: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: AddOAuthAccountXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_add_o_auth_account_x_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddOAuthAccountXResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><AccountUDN>AccountUDN</AccountUDN><AccountNickname>AccountNickname</AccountNickname></u:AddOAuthAccountXResponse></s:Body></s:Envelope>"#;
+        let expected = AddOAuthAccountXResponse {
+            account_udn: Some("AccountUDN".to_string()),
+            account_nickname: Some("AccountNickname".to_string()),
+        };
+        let decoded = <AddOAuthAccountXResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_edit_account_md_request() {
+        let request = EditAccountMdRequest {
+            account_type: 1,
+            account_id: "AccountID".to_string(),
+            new_account_md: "NewAccountMd".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: EditAccountMdRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_edit_account_password_x_request() {
+        let request = EditAccountPasswordXRequest {
+            account_type: 1,
+            account_id: "AccountID".to_string(),
+            new_account_password: "NewAccountPassword".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: EditAccountPasswordXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_enable_rdm_request() {
+        let request = EnableRdmRequest {
+            rdm_value: true,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: EnableRdmRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_rdm_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetRDMResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><RDMValue>1</RDMValue></u:GetRDMResponse></s:Body></s:Envelope>"#;
+        let expected = GetRdmResponse {
+            rdm_value: Some(true),
+        };
+        let decoded = <GetRdmResponse as crate::DecodeSoapResponse>::decode_soap_xml(xml)
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_string_request() {
+        let request = GetStringRequest {
+            variable_name: "VariableName".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetStringRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_string_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetStringResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><StringValue>StringValue</StringValue></u:GetStringResponse></s:Body></s:Envelope>"#;
+        let expected = GetStringResponse {
+            string_value: Some("StringValue".to_string()),
+        };
+        let decoded = <GetStringResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_web_code_request() {
+        let request = GetWebCodeRequest {
+            account_type: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: GetWebCodeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_get_web_code_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetWebCodeResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><WebCode>WebCode</WebCode></u:GetWebCodeResponse></s:Body></s:Envelope>"#;
+        let expected = GetWebCodeResponse {
+            web_code: Some("WebCode".to_string()),
+        };
+        let decoded = <GetWebCodeResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_provision_credentialed_trial_account_x_request() {
+        let request = ProvisionCredentialedTrialAccountXRequest {
+            account_type: 1,
+            account_id: "AccountID".to_string(),
+            account_password: "AccountPassword".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ProvisionCredentialedTrialAccountXRequest = instant_xml::from_str(
+                &xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_provision_credentialed_trial_account_x_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ProvisionCredentialedTrialAccountXResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><IsExpired>1</IsExpired><AccountUDN>AccountUDN</AccountUDN></u:ProvisionCredentialedTrialAccountXResponse></s:Body></s:Envelope>"#;
+        let expected = ProvisionCredentialedTrialAccountXResponse {
+            is_expired: Some(true),
+            account_udn: Some("AccountUDN".to_string()),
+        };
+        let decoded = <ProvisionCredentialedTrialAccountXResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_refresh_account_credentials_x_request() {
+        let request = RefreshAccountCredentialsXRequest {
+            account_type: 1,
+            account_uid: 1,
+            account_token: "AccountToken".to_string(),
+            account_key: "AccountKey".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RefreshAccountCredentialsXRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_request() {
+        let request = RemoveRequest {
+            variable_name: "VariableName".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_remove_account_request() {
+        let request = RemoveAccountRequest {
+            account_type: 1,
+            account_id: "AccountID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RemoveAccountRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_replace_account_x_request() {
+        let request = ReplaceAccountXRequest {
+            account_udn: "AccountUDN".to_string(),
+            new_account_id: "NewAccountID".to_string(),
+            new_account_password: "NewAccountPassword".to_string(),
+            account_token: "AccountToken".to_string(),
+            account_key: "AccountKey".to_string(),
+            o_auth_device_id: "OAuthDeviceID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReplaceAccountXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_replace_account_x_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:ReplaceAccountXResponse xmlns:u="urn:schemas-upnp-org:service:SystemProperties:1"><NewAccountUDN>NewAccountUDN</NewAccountUDN></u:ReplaceAccountXResponse></s:Body></s:Envelope>"#;
+        let expected = ReplaceAccountXResponse {
+            new_account_udn: Some("NewAccountUDN".to_string()),
+        };
+        let decoded = <ReplaceAccountXResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_set_account_nickname_x_request() {
+        let request = SetAccountNicknameXRequest {
+            account_udn: "AccountUDN".to_string(),
+            account_nickname: "AccountNickname".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetAccountNicknameXRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_string_request() {
+        let request = SetStringRequest {
+            variable_name: "VariableName".to_string(),
+            string_value: "StringValue".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetStringRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
@@ -0,0 +1,355 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `VirtualLineIn` service.
+use super::*;
+/// URN for the `VirtualLineIn` service.
+/// `urn:schemas-upnp-org:service:VirtualLineIn:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:VirtualLineIn:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Next", ns(SERVICE_TYPE))]
+pub struct NextRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Pause", ns(SERVICE_TYPE))]
+pub struct PauseRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Play", ns(SERVICE_TYPE))]
+pub struct PlayRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "Speed", ns(""))]
+    pub speed: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Previous", ns(SERVICE_TYPE))]
+pub struct PreviousRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SetVolume", ns(SERVICE_TYPE))]
+pub struct SetVolumeRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "DesiredVolume", ns(""))]
+    pub desired_volume: u16,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StartTransmission", ns(SERVICE_TYPE))]
+pub struct StartTransmissionRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CoordinatorID", ns(""))]
+    pub coordinator_id: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StartTransmissionResponse", ns(SERVICE_TYPE))]
+pub struct StartTransmissionResponse {
+    #[xml(rename = "CurrentTransportSettings", ns(""))]
+    pub current_transport_settings: Option<String>,
+}
+impl crate::DecodeSoapResponse for StartTransmissionResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "Stop", ns(SERVICE_TYPE))]
+pub struct StopRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "StopTransmission", ns(SERVICE_TYPE))]
+pub struct StopTransmissionRequest {
+    #[xml(rename = "InstanceID", ns(""))]
+    pub instance_id: crate::InstanceId,
+    #[xml(rename = "CoordinatorID", ns(""))]
+    pub coordinator_id: String,
+}
+/// A parsed event produced by the `VirtualLineIn` service.
+/// Use `SonosDevice::subscribe_virtual_line_in()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VirtualLineInEvent {
+    pub current_track_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    pub last_change: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct VirtualLineInPropertySet {
+    pub properties: Vec<VirtualLineInProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct VirtualLineInProperty {
+    #[xml(rename = "CurrentTrackMetaData", ns(""))]
+    pub current_track_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
+    #[xml(rename = "LastChange", ns(""))]
+    pub last_change: Option<String>,
+}
+impl DecodeXml for VirtualLineInEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: VirtualLineInPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.current_track_meta_data {
+                result.current_track_meta_data.replace(v);
+            }
+            if let Some(v) = prop.last_change {
+                result.last_change.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `VirtualLineIn` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_virtual_line_in(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<VirtualLineInEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[allow(async_fn_in_trait)]
+pub trait VirtualLineIn {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn next(&self, request: virtual_line_in::NextRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn pause(&self, request: virtual_line_in::PauseRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn play(&self, request: virtual_line_in::PlayRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn previous(&self, request: virtual_line_in::PreviousRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn set_volume(&self, request: virtual_line_in::SetVolumeRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn start_transmission(
+        &self,
+        request: virtual_line_in::StartTransmissionRequest,
+    ) -> Result<virtual_line_in::StartTransmissionResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn stop(&self, request: virtual_line_in::StopRequest) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn stop_transmission(
+        &self,
+        request: virtual_line_in::StopTransmissionRequest,
+    ) -> Result<()>;
+}
+impl VirtualLineIn for SonosDevice {
+    async fn next(&self, request: virtual_line_in::NextRequest) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "Next", request).await
+    }
+    async fn pause(&self, request: virtual_line_in::PauseRequest) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "Pause", request).await
+    }
+    async fn play(&self, request: virtual_line_in::PlayRequest) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "Play", request).await
+    }
+    async fn previous(&self, request: virtual_line_in::PreviousRequest) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "Previous", request).await
+    }
+    async fn set_volume(
+        &self,
+        request: virtual_line_in::SetVolumeRequest,
+    ) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "SetVolume", request).await
+    }
+    async fn start_transmission(
+        &self,
+        request: virtual_line_in::StartTransmissionRequest,
+    ) -> Result<virtual_line_in::StartTransmissionResponse> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "StartTransmission", request).await
+    }
+    async fn stop(&self, request: virtual_line_in::StopRequest) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "Stop", request).await
+    }
+    async fn stop_transmission(
+        &self,
+        request: virtual_line_in::StopTransmissionRequest,
+    ) -> Result<()> {
+        self.action(&virtual_line_in::SERVICE_TYPE, "StopTransmission", request).await
+    }
+}
+/// A variant of `VirtualLineIn` that can be used as a trait object,
+/// eg: `Box<dyn VirtualLineInDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait VirtualLineInDyn: Send + Sync {
+    /// See `VirtualLineIn::next`
+    fn next(&self, request: virtual_line_in::NextRequest) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::pause`
+    fn pause(&self, request: virtual_line_in::PauseRequest) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::play`
+    fn play(&self, request: virtual_line_in::PlayRequest) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::previous`
+    fn previous(
+        &self,
+        request: virtual_line_in::PreviousRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::set_volume`
+    fn set_volume(
+        &self,
+        request: virtual_line_in::SetVolumeRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::start_transmission`
+    fn start_transmission(
+        &self,
+        request: virtual_line_in::StartTransmissionRequest,
+    ) -> ActionFuture<'_, virtual_line_in::StartTransmissionResponse>;
+    /// See `VirtualLineIn::stop`
+    fn stop(&self, request: virtual_line_in::StopRequest) -> ActionFuture<'_, ()>;
+    /// See `VirtualLineIn::stop_transmission`
+    fn stop_transmission(
+        &self,
+        request: virtual_line_in::StopTransmissionRequest,
+    ) -> ActionFuture<'_, ()>;
+}
+#[cfg(feature = "dyn-traits")]
+impl VirtualLineInDyn for SonosDevice {
+    fn next(&self, request: virtual_line_in::NextRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::next(self, request))
+    }
+    fn pause(&self, request: virtual_line_in::PauseRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::pause(self, request))
+    }
+    fn play(&self, request: virtual_line_in::PlayRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::play(self, request))
+    }
+    fn previous(
+        &self,
+        request: virtual_line_in::PreviousRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::previous(self, request))
+    }
+    fn set_volume(
+        &self,
+        request: virtual_line_in::SetVolumeRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::set_volume(self, request))
+    }
+    fn start_transmission(
+        &self,
+        request: virtual_line_in::StartTransmissionRequest,
+    ) -> ActionFuture<'_, virtual_line_in::StartTransmissionResponse> {
+        Box::pin(<Self as VirtualLineIn>::start_transmission(self, request))
+    }
+    fn stop(&self, request: virtual_line_in::StopRequest) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::stop(self, request))
+    }
+    fn stop_transmission(
+        &self,
+        request: virtual_line_in::StopTransmissionRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as VirtualLineIn>::stop_transmission(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_next_request() {
+        let request = NextRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: NextRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_pause_request() {
+        let request = PauseRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PauseRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_play_request() {
+        let request = PlayRequest {
+            instance_id: crate::InstanceId(1),
+            speed: "Speed".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PlayRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_previous_request() {
+        let request = PreviousRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: PreviousRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_set_volume_request() {
+        let request = SetVolumeRequest {
+            instance_id: crate::InstanceId(1),
+            desired_volume: 1,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SetVolumeRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_start_transmission_request() {
+        let request = StartTransmissionRequest {
+            instance_id: crate::InstanceId(1),
+            coordinator_id: "CoordinatorID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StartTransmissionRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_start_transmission_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:StartTransmissionResponse xmlns:u="urn:schemas-upnp-org:service:VirtualLineIn:1"><CurrentTransportSettings>CurrentTransportSettings</CurrentTransportSettings></u:StartTransmissionResponse></s:Body></s:Envelope>"#;
+        let expected = StartTransmissionResponse {
+            current_transport_settings: Some("CurrentTransportSettings".to_string()),
+        };
+        let decoded = <StartTransmissionResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_stop_request() {
+        let request = StopRequest {
+            instance_id: crate::InstanceId(1),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StopRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_stop_transmission_request() {
+        let request = StopTransmissionRequest {
+            instance_id: crate::InstanceId(1),
+            coordinator_id: "CoordinatorID".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: StopTransmissionRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
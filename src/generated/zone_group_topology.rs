@@ -0,0 +1,777 @@
+// This file was auto-generated by codegen! Do not edit!
+
+//! Request and Response types for the `ZoneGroupTopology` service.
+use super::*;
+/// URN for the `ZoneGroupTopology` service.
+/// `urn:schemas-upnp-org:service:ZoneGroupTopology:1`
+pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:ZoneGroupTopology:1";
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "BeginSoftwareUpdate", ns(SERVICE_TYPE))]
+pub struct BeginSoftwareUpdateRequest {
+    #[xml(rename = "UpdateURL", ns(""))]
+    pub update_url: String,
+    #[xml(rename = "Flags", ns(""))]
+    pub flags: u32,
+    #[xml(rename = "ExtraOptions", ns(""))]
+    pub extra_options: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CheckForUpdate", ns(SERVICE_TYPE))]
+pub struct CheckForUpdateRequest {
+    #[xml(rename = "UpdateType", ns(""))]
+    pub update_type: super::UpdateType,
+    #[xml(rename = "CachedOnly", ns(""))]
+    pub cached_only: bool,
+    #[xml(rename = "Version", ns(""))]
+    pub version: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "CheckForUpdateResponse", ns(SERVICE_TYPE))]
+pub struct CheckForUpdateResponse {
+    #[xml(rename = "UpdateItem", ns(""))]
+    pub update_item: Option<String>,
+}
+impl crate::DecodeSoapResponse for CheckForUpdateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetZoneGroupAttributesResponse", ns(SERVICE_TYPE))]
+pub struct GetZoneGroupAttributesResponse {
+    #[xml(rename = "CurrentZoneGroupName", ns(""))]
+    pub current_zone_group_name: Option<String>,
+    #[xml(rename = "CurrentZoneGroupID", ns(""))]
+    pub current_zone_group_id: Option<String>,
+    #[xml(rename = "CurrentZonePlayerUUIDsInGroup", ns(""))]
+    pub current_zone_player_uuids_in_group: Option<String>,
+    #[xml(rename = "CurrentMuseHouseholdId", ns(""))]
+    pub current_muse_household_id: Option<String>,
+}
+impl crate::DecodeSoapResponse for GetZoneGroupAttributesResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "GetZoneGroupStateResponse", ns(SERVICE_TYPE))]
+pub struct GetZoneGroupStateResponse {
+    #[xml(rename = "ZoneGroupState", ns(""))]
+    pub zone_group_state: Option<DecodeXmlString<crate::ZoneGroupState>>,
+}
+impl crate::DecodeSoapResponse for GetZoneGroupStateResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "RegisterMobileDevice", ns(SERVICE_TYPE))]
+pub struct RegisterMobileDeviceRequest {
+    #[xml(rename = "MobileDeviceName", ns(""))]
+    pub mobile_device_name: String,
+    #[xml(rename = "MobileDeviceUDN", ns(""))]
+    pub mobile_device_udn: String,
+    #[xml(rename = "MobileIPAndPort", ns(""))]
+    pub mobile_ip_and_port: String,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "ReportUnresponsiveDevice", ns(SERVICE_TYPE))]
+pub struct ReportUnresponsiveDeviceRequest {
+    #[xml(rename = "DeviceUUID", ns(""))]
+    pub device_uuid: String,
+    #[xml(rename = "DesiredAction", ns(""))]
+    pub desired_action: super::UnresponsiveDeviceActionType,
+}
+#[derive(ToXml, FromXml, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SubmitDiagnostics", ns(SERVICE_TYPE))]
+pub struct SubmitDiagnosticsRequest {
+    #[xml(rename = "IncludeControllers", ns(""))]
+    pub include_controllers: bool,
+    #[xml(rename = "Type", ns(""))]
+    pub type_: String,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(rename = "SubmitDiagnosticsResponse", ns(SERVICE_TYPE))]
+pub struct SubmitDiagnosticsResponse {
+    #[xml(rename = "DiagnosticID", ns(""))]
+    pub diagnostic_id: Option<u32>,
+}
+impl crate::DecodeSoapResponse for SubmitDiagnosticsResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        Ok(envelope.body.payload)
+    }
+}
+/// A parsed event produced by the `ZoneGroupTopology` service.
+/// Use `SonosDevice::subscribe_zone_group_topology()` to obtain an event
+/// stream that produces these.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoneGroupTopologyEvent {
+    pub alarm_run_sequence: Option<String>,
+    pub areas_update_id: Option<String>,
+    pub available_software_update: Option<String>,
+    pub muse_household_id: Option<String>,
+    pub netsettings_update_id: Option<String>,
+    pub source_areas_update_id: Option<String>,
+    pub third_party_media_servers_x: Option<String>,
+    pub zone_group_id: Option<String>,
+    pub zone_group_name: Option<String>,
+    pub zone_group_state: Option<DecodeXmlString<crate::ZoneGroupState>>,
+    pub zone_player_uuids_in_group: Option<String>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "propertyset", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct ZoneGroupTopologyPropertySet {
+    pub properties: Vec<ZoneGroupTopologyProperty>,
+}
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "property", ns(crate::upnp::UPNP_EVENT, e = crate::upnp::UPNP_EVENT))]
+struct ZoneGroupTopologyProperty {
+    #[xml(rename = "AlarmRunSequence", ns(""))]
+    pub alarm_run_sequence: Option<String>,
+    #[xml(rename = "AreasUpdateID", ns(""))]
+    pub areas_update_id: Option<String>,
+    #[xml(rename = "AvailableSoftwareUpdate", ns(""))]
+    pub available_software_update: Option<String>,
+    #[xml(rename = "MuseHouseholdId", ns(""))]
+    pub muse_household_id: Option<String>,
+    #[xml(rename = "NetsettingsUpdateID", ns(""))]
+    pub netsettings_update_id: Option<String>,
+    #[xml(rename = "SourceAreasUpdateID", ns(""))]
+    pub source_areas_update_id: Option<String>,
+    #[xml(rename = "ThirdPartyMediaServersX", ns(""))]
+    pub third_party_media_servers_x: Option<String>,
+    #[xml(rename = "ZoneGroupID", ns(""))]
+    pub zone_group_id: Option<String>,
+    #[xml(rename = "ZoneGroupName", ns(""))]
+    pub zone_group_name: Option<String>,
+    #[xml(rename = "ZoneGroupState", ns(""))]
+    pub zone_group_state: Option<DecodeXmlString<crate::ZoneGroupState>>,
+    #[xml(rename = "ZonePlayerUUIDsInGroup", ns(""))]
+    pub zone_player_uuids_in_group: Option<String>,
+}
+impl DecodeXml for ZoneGroupTopologyEvent {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let mut result = Self::default();
+        let set: ZoneGroupTopologyPropertySet = instant_xml::from_str(xml)?;
+        for prop in set.properties {
+            if let Some(v) = prop.alarm_run_sequence {
+                result.alarm_run_sequence.replace(v);
+            }
+            if let Some(v) = prop.areas_update_id {
+                result.areas_update_id.replace(v);
+            }
+            if let Some(v) = prop.available_software_update {
+                result.available_software_update.replace(v);
+            }
+            if let Some(v) = prop.muse_household_id {
+                result.muse_household_id.replace(v);
+            }
+            if let Some(v) = prop.netsettings_update_id {
+                result.netsettings_update_id.replace(v);
+            }
+            if let Some(v) = prop.source_areas_update_id {
+                result.source_areas_update_id.replace(v);
+            }
+            if let Some(v) = prop.third_party_media_servers_x {
+                result.third_party_media_servers_x.replace(v);
+            }
+            if let Some(v) = prop.zone_group_id {
+                result.zone_group_id.replace(v);
+            }
+            if let Some(v) = prop.zone_group_name {
+                result.zone_group_name.replace(v);
+            }
+            if let Some(v) = prop.zone_group_state {
+                result.zone_group_state.replace(v);
+            }
+            if let Some(v) = prop.zone_player_uuids_in_group {
+                result.zone_player_uuids_in_group.replace(v);
+            }
+        }
+        Ok(result)
+    }
+}
+impl crate::SonosDevice {
+    /// Subscribe to events from the `ZoneGroupTopology` service on this device
+    #[cfg(feature = "events")]
+    pub async fn subscribe_zone_group_topology(
+        &self,
+    ) -> crate::Result<crate::upnp::EventStream<ZoneGroupTopologyEvent>> {
+        self.subscribe_helper(&SERVICE_TYPE).await
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum UnresponsiveDeviceActionType {
+    #[default]
+    Remove,
+    TopologyMonitorProbe,
+    VerifyThenRemoveSystemwide,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for UnresponsiveDeviceActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            UnresponsiveDeviceActionType::Remove => "Remove",
+            UnresponsiveDeviceActionType::TopologyMonitorProbe => "TopologyMonitorProbe",
+            UnresponsiveDeviceActionType::VerifyThenRemoveSystemwide => {
+                "VerifyThenRemoveSystemwide"
+            }
+            UnresponsiveDeviceActionType::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for UnresponsiveDeviceActionType {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<UnresponsiveDeviceActionType> {
+        match s {
+            "Remove" => Ok(UnresponsiveDeviceActionType::Remove),
+            "TopologyMonitorProbe" => {
+                Ok(UnresponsiveDeviceActionType::TopologyMonitorProbe)
+            }
+            "VerifyThenRemoveSystemwide" => {
+                Ok(UnresponsiveDeviceActionType::VerifyThenRemoveSystemwide)
+            }
+            s => Ok(UnresponsiveDeviceActionType::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for UnresponsiveDeviceActionType {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for UnresponsiveDeviceActionType {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: UnresponsiveDeviceActionType = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<UnresponsiveDeviceActionType>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnresponsiveDeviceActionType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnresponsiveDeviceActionType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[derive(PartialEq, Debug, Clone, Eq, Default)]
+pub enum UpdateType {
+    #[default]
+    All,
+    Software,
+    /// Allows passing a value that was not known at the
+    /// time that this crate was generated from the available
+    /// device descriptions
+    Unspecified(String),
+}
+impl std::fmt::Display for UpdateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            UpdateType::All => "All",
+            UpdateType::Software => "Software",
+            UpdateType::Unspecified(s) => s.as_str(),
+        };
+        f.write_str(s)
+    }
+}
+impl FromStr for UpdateType {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<UpdateType> {
+        match s {
+            "All" => Ok(UpdateType::All),
+            "Software" => Ok(UpdateType::Software),
+            s => Ok(UpdateType::Unspecified(s.to_string())),
+        }
+    }
+}
+impl instant_xml::ToXml for UpdateType {
+    fn serialize<W: std::fmt::Write + ?Sized>(
+        &self,
+        field: Option<instant_xml::Id<'_>>,
+        serializer: &mut instant_xml::Serializer<W>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        self.to_string().serialize(field, serializer)
+    }
+    fn present(&self) -> bool {
+        true
+    }
+}
+impl<'xml> instant_xml::FromXml<'xml> for UpdateType {
+    #[inline]
+    fn matches(id: instant_xml::Id<'_>, field: Option<instant_xml::Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut instant_xml::Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+        match deserializer.take_str()? {
+            Some(value) => {
+                let parsed: UpdateType = value
+                    .parse()
+                    .map_err(|err| {
+                        instant_xml::Error::Other(
+                            format!("invalid value for field {field}: {value}: {err:#}"),
+                        )
+                    })?;
+                *into = Some(parsed);
+                Ok(())
+            }
+            None => Err(instant_xml::Error::MissingValue(field)),
+        }
+    }
+    type Accumulator = Option<UpdateType>;
+    const KIND: instant_xml::Kind = instant_xml::Kind::Scalar;
+}
+/// Uses the same string form as the device, so that
+/// `Unspecified` values are preserved
+#[cfg(feature = "serde")]
+impl serde::Serialize for UpdateType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UpdateType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+#[allow(async_fn_in_trait)]
+/// Zone config stuff, eg getting all the configured sonos zones
+pub trait ZoneGroupTopology {
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn begin_software_update(
+        &self,
+        request: zone_group_topology::BeginSoftwareUpdateRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn check_for_update(
+        &self,
+        request: zone_group_topology::CheckForUpdateRequest,
+    ) -> Result<zone_group_topology::CheckForUpdateResponse>;
+    /// Get information about the current Zone
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_zone_group_attributes(
+        &self,
+    ) -> Result<zone_group_topology::GetZoneGroupAttributesResponse>;
+    /// Get all the Sonos groups, (as XML)
+    ///
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn get_zone_group_state(
+        &self,
+    ) -> Result<zone_group_topology::GetZoneGroupStateResponse>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn register_mobile_device(
+        &self,
+        request: zone_group_topology::RegisterMobileDeviceRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn report_alarm_started_running(&self) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn report_unresponsive_device(
+        &self,
+        request: zone_group_topology::ReportUnresponsiveDeviceRequest,
+    ) -> Result<()>;
+    /// Supported by: `S1`, `S13`, `S14`, `S18`, `S19`, `S21`, `S27`, `S3`, `S33`, `S38`, `S5`, `S6`, `S9`, `Sub`
+    async fn submit_diagnostics(
+        &self,
+        request: zone_group_topology::SubmitDiagnosticsRequest,
+    ) -> Result<zone_group_topology::SubmitDiagnosticsResponse>;
+}
+impl ZoneGroupTopology for SonosDevice {
+    async fn begin_software_update(
+        &self,
+        request: zone_group_topology::BeginSoftwareUpdateRequest,
+    ) -> Result<()> {
+        self.action(&zone_group_topology::SERVICE_TYPE, "BeginSoftwareUpdate", request)
+            .await
+    }
+    async fn check_for_update(
+        &self,
+        request: zone_group_topology::CheckForUpdateRequest,
+    ) -> Result<zone_group_topology::CheckForUpdateResponse> {
+        self.action(&zone_group_topology::SERVICE_TYPE, "CheckForUpdate", request).await
+    }
+    async fn get_zone_group_attributes(
+        &self,
+    ) -> Result<zone_group_topology::GetZoneGroupAttributesResponse> {
+        self.action(
+                &zone_group_topology::SERVICE_TYPE,
+                "GetZoneGroupAttributes",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn get_zone_group_state(
+        &self,
+    ) -> Result<zone_group_topology::GetZoneGroupStateResponse> {
+        self.action(
+                &zone_group_topology::SERVICE_TYPE,
+                "GetZoneGroupState",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn register_mobile_device(
+        &self,
+        request: zone_group_topology::RegisterMobileDeviceRequest,
+    ) -> Result<()> {
+        self.action(&zone_group_topology::SERVICE_TYPE, "RegisterMobileDevice", request)
+            .await
+    }
+    async fn report_alarm_started_running(&self) -> Result<()> {
+        self.action(
+                &zone_group_topology::SERVICE_TYPE,
+                "ReportAlarmStartedRunning",
+                crate::soap::Unit {},
+            )
+            .await
+    }
+    async fn report_unresponsive_device(
+        &self,
+        request: zone_group_topology::ReportUnresponsiveDeviceRequest,
+    ) -> Result<()> {
+        self.action(
+                &zone_group_topology::SERVICE_TYPE,
+                "ReportUnresponsiveDevice",
+                request,
+            )
+            .await
+    }
+    async fn submit_diagnostics(
+        &self,
+        request: zone_group_topology::SubmitDiagnosticsRequest,
+    ) -> Result<zone_group_topology::SubmitDiagnosticsResponse> {
+        self.action(&zone_group_topology::SERVICE_TYPE, "SubmitDiagnostics", request)
+            .await
+    }
+}
+/// A variant of `ZoneGroupTopology` that can be used as a trait object,
+/// eg: `Box<dyn ZoneGroupTopologyDyn>`, because its methods return
+/// boxed futures
+#[cfg(feature = "dyn-traits")]
+pub trait ZoneGroupTopologyDyn: Send + Sync {
+    /// See `ZoneGroupTopology::begin_software_update`
+    fn begin_software_update(
+        &self,
+        request: zone_group_topology::BeginSoftwareUpdateRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ZoneGroupTopology::check_for_update`
+    fn check_for_update(
+        &self,
+        request: zone_group_topology::CheckForUpdateRequest,
+    ) -> ActionFuture<'_, zone_group_topology::CheckForUpdateResponse>;
+    /// See `ZoneGroupTopology::get_zone_group_attributes`
+    fn get_zone_group_attributes(
+        &self,
+    ) -> ActionFuture<'_, zone_group_topology::GetZoneGroupAttributesResponse>;
+    /// See `ZoneGroupTopology::get_zone_group_state`
+    fn get_zone_group_state(
+        &self,
+    ) -> ActionFuture<'_, zone_group_topology::GetZoneGroupStateResponse>;
+    /// See `ZoneGroupTopology::register_mobile_device`
+    fn register_mobile_device(
+        &self,
+        request: zone_group_topology::RegisterMobileDeviceRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ZoneGroupTopology::report_alarm_started_running`
+    fn report_alarm_started_running(&self) -> ActionFuture<'_, ()>;
+    /// See `ZoneGroupTopology::report_unresponsive_device`
+    fn report_unresponsive_device(
+        &self,
+        request: zone_group_topology::ReportUnresponsiveDeviceRequest,
+    ) -> ActionFuture<'_, ()>;
+    /// See `ZoneGroupTopology::submit_diagnostics`
+    fn submit_diagnostics(
+        &self,
+        request: zone_group_topology::SubmitDiagnosticsRequest,
+    ) -> ActionFuture<'_, zone_group_topology::SubmitDiagnosticsResponse>;
+}
+#[cfg(feature = "dyn-traits")]
+impl ZoneGroupTopologyDyn for SonosDevice {
+    fn begin_software_update(
+        &self,
+        request: zone_group_topology::BeginSoftwareUpdateRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ZoneGroupTopology>::begin_software_update(self, request))
+    }
+    fn check_for_update(
+        &self,
+        request: zone_group_topology::CheckForUpdateRequest,
+    ) -> ActionFuture<'_, zone_group_topology::CheckForUpdateResponse> {
+        Box::pin(<Self as ZoneGroupTopology>::check_for_update(self, request))
+    }
+    fn get_zone_group_attributes(
+        &self,
+    ) -> ActionFuture<'_, zone_group_topology::GetZoneGroupAttributesResponse> {
+        Box::pin(<Self as ZoneGroupTopology>::get_zone_group_attributes(self))
+    }
+    fn get_zone_group_state(
+        &self,
+    ) -> ActionFuture<'_, zone_group_topology::GetZoneGroupStateResponse> {
+        Box::pin(<Self as ZoneGroupTopology>::get_zone_group_state(self))
+    }
+    fn register_mobile_device(
+        &self,
+        request: zone_group_topology::RegisterMobileDeviceRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ZoneGroupTopology>::register_mobile_device(self, request))
+    }
+    fn report_alarm_started_running(&self) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ZoneGroupTopology>::report_alarm_started_running(self))
+    }
+    fn report_unresponsive_device(
+        &self,
+        request: zone_group_topology::ReportUnresponsiveDeviceRequest,
+    ) -> ActionFuture<'_, ()> {
+        Box::pin(<Self as ZoneGroupTopology>::report_unresponsive_device(self, request))
+    }
+    fn submit_diagnostics(
+        &self,
+        request: zone_group_topology::SubmitDiagnosticsRequest,
+    ) -> ActionFuture<'_, zone_group_topology::SubmitDiagnosticsResponse> {
+        Box::pin(<Self as ZoneGroupTopology>::submit_diagnostics(self, request))
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_begin_software_update_request() {
+        let request = BeginSoftwareUpdateRequest {
+            update_url: "UpdateURL".to_string(),
+            flags: 1,
+            extra_options: "ExtraOptions".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: BeginSoftwareUpdateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_check_for_update_request() {
+        let request = CheckForUpdateRequest {
+            update_type: UpdateType::Software,
+            cached_only: true,
+            version: "Version".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: CheckForUpdateRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_check_for_update_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:CheckForUpdateResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"><UpdateItem>UpdateItem</UpdateItem></u:CheckForUpdateResponse></s:Body></s:Envelope>"#;
+        let expected = CheckForUpdateResponse {
+            update_item: Some("UpdateItem".to_string()),
+        };
+        let decoded = <CheckForUpdateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_zone_group_attributes_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneGroupAttributesResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"><CurrentZoneGroupName>CurrentZoneGroupName</CurrentZoneGroupName><CurrentZoneGroupID>CurrentZoneGroupID</CurrentZoneGroupID><CurrentZonePlayerUUIDsInGroup>CurrentZonePlayerUUIDsInGroup</CurrentZonePlayerUUIDsInGroup><CurrentMuseHouseholdId>CurrentMuseHouseholdId</CurrentMuseHouseholdId></u:GetZoneGroupAttributesResponse></s:Body></s:Envelope>"#;
+        let expected = GetZoneGroupAttributesResponse {
+            current_zone_group_name: Some("CurrentZoneGroupName".to_string()),
+            current_zone_group_id: Some("CurrentZoneGroupID".to_string()),
+            current_zone_player_uuids_in_group: Some(
+                "CurrentZonePlayerUUIDsInGroup".to_string(),
+            ),
+            current_muse_household_id: Some("CurrentMuseHouseholdId".to_string()),
+        };
+        let decoded = <GetZoneGroupAttributesResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    #[test]
+    fn test_get_zone_group_state_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneGroupStateResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"></u:GetZoneGroupStateResponse></s:Body></s:Envelope>"#;
+        let expected = GetZoneGroupStateResponse {
+            zone_group_state: None,
+        };
+        let decoded = <GetZoneGroupStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+    /// Decodes `data/responses/ZoneGroupTopology/GetZoneGroupState.xml`
+    #[test]
+    fn test_get_zone_group_state_response_fixture() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneGroupStateResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"><ZoneGroupState>&lt;ZoneGroupState&gt;
+  &lt;ZoneGroups&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:3435548679"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.161:1400/xml/device_description.xml" ZoneName="Primary Bath" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="145" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="1" WirelessLeafOnly="0" ChannelFreq="5220" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="4" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="RawBattPct:99,BattPct:100,BattChg:CHARGING,BattTmp:33" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:3326086195"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.196:1400/xml/device_description.xml" ZoneName="Some Room" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:LR;RINCON_XXX:RR" ActiveZoneID="add8bfbb-2894-4744-9f7d-50dda5cc9917" BootSeq="123" TVConfigurationError="0" HdmiCecAvailable="1" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="1" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"&gt;
+        &lt;Satellite UUID="RINCON_XXX" Location="http://10.10.10.131:1400/xml/device_description.xml" ZoneName="Some Room" Icon="" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:LR" ActiveZoneID="add8bfbb-2894-4744-9f7d-50dda5cc9917" BootSeq="237" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+        &lt;Satellite UUID="RINCON_XXX" Location="http://10.10.10.226:1400/xml/device_description.xml" ZoneName="Some Room" Icon="" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:RR" ActiveZoneID="add8bfbb-2894-4744-9f7d-50dda5cc9917" BootSeq="274" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+      &lt;/ZoneGroupMember&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:2302873263"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.166:1400/xml/device_description.xml" ZoneName="Study" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="73" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="1" Orientation="0" RoomCalibrationState="4" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="0" MoreInfo="TargetRoomName:Study" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:4111376911"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.138:1400/xml/device_description.xml" ZoneName="Beam" Icon="x-rincon-roomicon:masterbedroom" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="158" TVConfigurationError="0" HdmiCecAvailable="1" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="3" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:2134456247"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.165:1400/xml/device_description.xml" ZoneName="Kitchen (Move)" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="112" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="1" WirelessLeafOnly="0" ChannelFreq="5785" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="4" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="RawBattPct:100,BattPct:100,BattChg:CHARGING,BattTmp:27" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:2884078592"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.231:1400/xml/device_description.xml" ZoneName="Primary Bedroom" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:SW;RINCON_XXX:LR;RINCON_XXX:RR" ActiveZoneID="9d7f5b8f-04fe-4dc0-9e81-0a14b77f05f9" BootSeq="91" TVConfigurationError="0" HdmiCecAvailable="1" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="1" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"&gt;
+        &lt;Satellite UUID="RINCON_XXX" Location="http://10.10.10.190:1400/xml/device_description.xml" ZoneName="Primary Bedroom" Icon="x-rincon-roomicon:masterbedroom" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:RR" ActiveZoneID="9d7f5b8f-04fe-4dc0-9e81-0a14b77f05f9" BootSeq="286" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+        &lt;Satellite UUID="RINCON_XXX" Location="http://10.10.10.198:1400/xml/device_description.xml" ZoneName="Primary Bedroom" Icon="x-rincon-roomicon:masterbedroom" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:LR" ActiveZoneID="9d7f5b8f-04fe-4dc0-9e81-0a14b77f05f9" BootSeq="278" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+        &lt;Satellite UUID="RINCON_XXX" Location="http://10.10.10.116:1400/xml/device_description.xml" ZoneName="Sub" Icon="" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" HTSatChanMapSet="RINCON_XXX:LF,RF;RINCON_XXX:SW" ActiveZoneID="9d7f5b8f-04fe-4dc0-9e81-0a14b77f05f9" BootSeq="90" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+      &lt;/ZoneGroupMember&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:1940091512"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.157:1400/xml/device_description.xml" ZoneName="Great Room" Icon="" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="89" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="1" Orientation="0" RoomCalibrationState="4" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:2667033389"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.120:1400/xml/device_description.xml" ZoneName="Other Room" Icon="x-rincon-roomicon:living" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" ChannelMapSet="RINCON_XXX:LF,LF;RINCON_XXX:RF,RF;RINCON_XXX:SW,SW" ActiveZoneID="f0c3257b-77f6-482c-83a4-5f3be1032a53" BootSeq="320" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="0" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.158:1400/xml/device_description.xml" ZoneName="Other Room" Icon="x-rincon-roomicon:living" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" ChannelMapSet="RINCON_XXX:LF,LF;RINCON_XXX:RF,RF;RINCON_XXX:SW,SW" ActiveZoneID="f0c3257b-77f6-482c-83a4-5f3be1032a53" BootSeq="273" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="4" RoomCalibrationState="3" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="1" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.217:1400/xml/device_description.xml" ZoneName="Other Room" Icon="x-rincon-roomicon:living" Configuration="1" Invisible="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" ChannelMapSet="RINCON_XXX:LF,LF;RINCON_XXX:RF,RF;RINCON_XXX:SW,SW" ActiveZoneID="f0c3257b-77f6-482c-83a4-5f3be1032a53" BootSeq="253" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="3" RoomCalibrationState="5" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+    &lt;ZoneGroup Coordinator="RINCON_XXX" ID="RINCON_XXX:97"&gt;
+      &lt;ZoneGroupMember UUID="RINCON_XXX" Location="http://10.10.10.236:1400/xml/device_description.xml" ZoneName="Kitchen" Icon="x-rincon-roomicon:masterbedroom" Configuration="1" SoftwareVersion="78.1-52020" SWGen="2" MinCompatibleVersion="77.0-00000" LegacyCompatibleVersion="58.0-00000" BootSeq="367" TVConfigurationError="0" HdmiCecAvailable="0" WirelessMode="0" WirelessLeafOnly="0" ChannelFreq="2437" BehindWifiExtender="0" WifiEnabled="1" EthLink="0" Orientation="3" RoomCalibrationState="4" SecureRegState="3" VoiceConfigState="0" MicEnabled="0" AirPlayEnabled="0" IdleState="1" MoreInfo="" SSLPort="1443" HHSSLPort="1843"/&gt;
+    &lt;/ZoneGroup&gt;
+  &lt;/ZoneGroups&gt;
+  &lt;VanishedDevices&gt;
+&lt;/VanishedDevices&gt;
+&lt;/ZoneGroupState&gt;</ZoneGroupState></u:GetZoneGroupStateResponse></s:Body></s:Envelope>"#;
+        let decoded = <GetZoneGroupStateResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert!(decoded.zone_group_state.is_some(), "ZoneGroupState was not decoded");
+    }
+    #[test]
+    fn test_register_mobile_device_request() {
+        let request = RegisterMobileDeviceRequest {
+            mobile_device_name: "MobileDeviceName".to_string(),
+            mobile_device_udn: "MobileDeviceUDN".to_string(),
+            mobile_ip_and_port: "MobileIPAndPort".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: RegisterMobileDeviceRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_report_unresponsive_device_request() {
+        let request = ReportUnresponsiveDeviceRequest {
+            device_uuid: "DeviceUUID".to_string(),
+            desired_action: UnresponsiveDeviceActionType::VerifyThenRemoveSystemwide,
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: ReportUnresponsiveDeviceRequest = instant_xml::from_str(&xml)
+            .unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_submit_diagnostics_request() {
+        let request = SubmitDiagnosticsRequest {
+            include_controllers: true,
+            type_: "Type".to_string(),
+        };
+        let xml = instant_xml::to_string(&request).unwrap();
+        let decoded: SubmitDiagnosticsRequest = instant_xml::from_str(&xml).unwrap();
+        assert_eq!(decoded, request);
+    }
+    #[test]
+    fn test_submit_diagnostics_response() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SubmitDiagnosticsResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"><DiagnosticID>1</DiagnosticID></u:SubmitDiagnosticsResponse></s:Body></s:Envelope>"#;
+        let expected = SubmitDiagnosticsResponse {
+            diagnostic_id: Some(1),
+        };
+        let decoded = <SubmitDiagnosticsResponse as crate::DecodeSoapResponse>::decode_soap_xml(
+                xml,
+            )
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+}
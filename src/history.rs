@@ -0,0 +1,108 @@
+use crate::{Result, SonosDevice, TrackMetaData};
+
+/// The ContentDirectory containers that hold recently played content.
+/// Not every firmware version exposes all of them; containers that
+/// the device doesn't know about are skipped by `recently_played`.
+pub const RECENTLY_PLAYED_CONTAINERS: &[&str] = &["R:0/0", "R:0/1", "RQ:"];
+
+/// An entry from the listening history of a player
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RecentlyPlayed {
+    /// The ContentDirectory container that this entry was found in
+    pub container: &'static str,
+    pub track: TrackMetaData,
+}
+
+impl SonosDevice {
+    /// Returns the recently played items known to this player,
+    /// gathered from each of the `RECENTLY_PLAYED_CONTAINERS`
+    /// that are available on the device.
+    /// At most `limit` items are returned from each container.
+    pub async fn recently_played(&self, limit: u32) -> Result<Vec<RecentlyPlayed>> {
+        let mut result = vec![];
+
+        for &container in RECENTLY_PLAYED_CONTAINERS {
+            match self.content_browse(container, 0, limit).await {
+                Ok(tracks) => {
+                    result.extend(
+                        tracks
+                            .into_iter()
+                            .map(|track| RecentlyPlayed { container, track }),
+                    );
+                }
+                // The device reports a SOAP fault for containers
+                // that it doesn't support
+                Err(err) if err.is_soap_fault() => {
+                    log::debug!("{container} is not available on {}", self.url());
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(all(test, feature = "vcr"))]
+mod test {
+    use crate::vcr::{replay_device, Exchange};
+    use crate::Error;
+
+    const CONTROL: &str = "/MediaServer/ContentDirectory/Control";
+
+    fn browse(didl: &str, count: u32) -> Exchange {
+        Exchange::upnp(
+            CONTROL,
+            "ContentDirectory",
+            "Browse",
+            &format!(
+                "<Result>{}</Result><NumberReturned>{count}</NumberReturned><TotalMatches>{count}</TotalMatches><UpdateID>1</UpdateID>",
+                didl.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_recently_played() {
+        let (device, client) = replay_device(vec![
+            browse(
+                r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/"><item id="R:0/0/0" parentID="R:0/0" restricted="true"><dc:title>BBC Radio 6 Music</dc:title><upnp:class>object.item.audioItem.audioBroadcast</upnp:class><res protocolInfo="x-rincon-mp3radio:*:*:*">x-sonosapi-stream:s24940?sid=254&amp;flags=8224&amp;sn=0</res></item></DIDL-Lite>"#,
+                1,
+            ),
+            // This firmware doesn't have the second container
+            Exchange::upnp_fault(CONTROL, "ContentDirectory", "Browse", 701),
+            browse(
+                r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"></DIDL-Lite>"#,
+                0,
+            ),
+        ])
+        .await;
+
+        let history = device.recently_played(10).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].container, "R:0/0");
+        assert_eq!(history[0].track.title, "BBC Radio 6 Music");
+        assert_eq!(
+            history[0].track.url,
+            "x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0"
+        );
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_recently_played_error() {
+        let (device, _client) = replay_device(vec![browse(
+            r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"></DIDL-Lite>"#,
+            0,
+        )])
+        .await;
+
+        // Failures other than faults from the device are reported
+        let err = device.recently_played(10).await.unwrap_err();
+        assert!(
+            matches!(err.inner(), Error::NoRecordedExchange(_)),
+            "{err:#}"
+        );
+    }
+}
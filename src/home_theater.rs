@@ -0,0 +1,302 @@
+use crate::eq::check_range;
+use crate::{
+    device_properties, ht_control, rendering_control, Capability, DeviceProperties, Error,
+    HTControl, IRRepeaterState, InstanceId, RenderingControl, Result, SonosDevice,
+};
+use std::ops::RangeInclusive;
+
+/// The source name used by the autoplay actions for the TV input
+const TV_SOURCE: &str = "Spdif";
+
+/// The range of the sub gain and surround level settings
+pub const SUB_GAIN_RANGE: RangeInclusive<i16> = -15..=15;
+pub const SURROUND_LEVEL_RANGE: RangeInclusive<i16> = -15..=15;
+pub const HEIGHT_CHANNEL_LEVEL_RANGE: RangeInclusive<i16> = -10..=10;
+
+/// The range of the audio delay (lip sync) setting. The player
+/// expresses the delay as a level rather than as a duration.
+pub const AUDIO_DELAY_RANGE: RangeInclusive<i16> = 0..=5;
+
+/// How the surround speakers are used when playing music
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SurroundMode {
+    /// Subtle ambient sound
+    #[default]
+    Ambient,
+    /// Louder, full-range sound
+    Full,
+}
+
+/// The home theater audio settings of a soundbar and the sub,
+/// surrounds and height channels bonded to it.
+/// Settings that are not applicable to the current setup, for
+/// example, the sub settings when no sub is bonded, are `None`
+/// when read, and are left unchanged when set to `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HomeTheaterAudio {
+    /// In the range -15..=15
+    pub sub_gain: Option<i16>,
+    pub sub_enabled: Option<bool>,
+    /// In the range -15..=15
+    pub surround_level: Option<i16>,
+    pub surround_mode: Option<SurroundMode>,
+    /// In the range -10..=10
+    pub height_channel_level: Option<i16>,
+}
+
+impl HomeTheaterAudio {
+    /// Returns a copy of the settings with the levels clamped
+    /// to their valid ranges
+    pub fn clamped(self) -> Self {
+        let clamp = |v: Option<i16>, range: RangeInclusive<i16>| {
+            v.map(|v| v.clamp(*range.start(), *range.end()))
+        };
+        Self {
+            sub_gain: clamp(self.sub_gain, SUB_GAIN_RANGE),
+            surround_level: clamp(self.surround_level, SURROUND_LEVEL_RANGE),
+            height_channel_level: clamp(self.height_channel_level, HEIGHT_CHANNEL_LEVEL_RANGE),
+            ..self
+        }
+    }
+}
+
+impl SonosDevice {
+    /// Returns the home theater audio settings
+    pub async fn home_theater_audio(&self) -> Result<HomeTheaterAudio> {
+        Ok(HomeTheaterAudio {
+            sub_gain: self.eq_value("SubGain").await?,
+            sub_enabled: self.eq_value("SubEnable").await?.map(|v| v != 0),
+            surround_level: self.eq_value("SurroundLevel").await?,
+            surround_mode: self.eq_value("SurroundMode").await?.map(|v| {
+                if v == 0 {
+                    SurroundMode::Ambient
+                } else {
+                    SurroundMode::Full
+                }
+            }),
+            height_channel_level: self.eq_value("HeightChannelLevel").await?,
+        })
+    }
+
+    /// Applies the home theater audio settings, clamping the levels
+    /// to their valid ranges. Settings that are `None` are unchanged.
+    pub async fn set_home_theater_audio(&self, settings: HomeTheaterAudio) -> Result<()> {
+        let settings = settings.clamped();
+        if let Some(v) = settings.sub_gain {
+            self.set_eq_value("SubGain", v).await?;
+        }
+        if let Some(v) = settings.sub_enabled {
+            self.set_eq_value("SubEnable", v.into()).await?;
+        }
+        if let Some(v) = settings.surround_level {
+            self.set_eq_value("SurroundLevel", v).await?;
+        }
+        if let Some(v) = settings.surround_mode {
+            self.set_eq_value("SurroundMode", (v == SurroundMode::Full).into())
+                .await?;
+        }
+        if let Some(v) = settings.height_channel_level {
+            self.set_eq_value("HeightChannelLevel", v).await?;
+        }
+        Ok(())
+    }
+
+    /// Sets the sub gain, clamped to -15..=15
+    pub async fn set_sub_gain(&self, gain: i16) -> Result<()> {
+        self.set_home_theater_audio(HomeTheaterAudio {
+            sub_gain: Some(gain),
+            ..Default::default()
+        })
+        .await
+    }
+
+    pub async fn set_sub_enabled(&self, enabled: bool) -> Result<()> {
+        self.set_eq_value("SubEnable", enabled.into()).await
+    }
+
+    /// Sets the surround level, clamped to -15..=15
+    pub async fn set_surround_level(&self, level: i16) -> Result<()> {
+        self.set_home_theater_audio(HomeTheaterAudio {
+            surround_level: Some(level),
+            ..Default::default()
+        })
+        .await
+    }
+
+    pub async fn set_surround_mode(&self, mode: SurroundMode) -> Result<()> {
+        self.set_eq_value("SurroundMode", (mode == SurroundMode::Full).into())
+            .await
+    }
+
+    /// Sets the height channel level, clamped to -10..=10
+    pub async fn set_height_channel_level(&self, level: i16) -> Result<()> {
+        self.set_home_theater_audio(HomeTheaterAudio {
+            height_channel_level: Some(level),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Returns true if the soundbar starts playing the TV when it
+    /// detects that the TV has been turned on
+    pub async fn tv_autoplay(&self) -> Result<bool> {
+        self.ensure_home_theater()?;
+        let room_uuid = <Self as DeviceProperties>::get_autoplay_room_uuid(
+            self,
+            device_properties::GetAutoplayRoomUuidRequest {
+                source: TV_SOURCE.to_string(),
+            },
+        )
+        .await?
+        .room_uuid;
+        Ok(room_uuid.map(|uuid| !uuid.is_empty()).unwrap_or(false))
+    }
+
+    /// Sets whether the soundbar starts playing the TV when it
+    /// detects that the TV has been turned on
+    pub async fn set_tv_autoplay(&self, enabled: bool) -> Result<()> {
+        self.ensure_home_theater()?;
+        let room_uuid = if enabled {
+            self.zone_group_member()
+                .await?
+                .ok_or(Error::MissingValue("ZoneGroupMember"))?
+                .uuid
+                .into()
+        } else {
+            String::new()
+        };
+        <Self as DeviceProperties>::set_autoplay_room_uuid(
+            self,
+            device_properties::SetAutoplayRoomUuidRequest {
+                room_uuid,
+                source: TV_SOURCE.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Returns the state of the IR repeater, which relays the
+    /// signal from the TV remote to devices that are obscured by
+    /// the soundbar
+    pub async fn ir_repeater_state(&self) -> Result<IRRepeaterState> {
+        self.ensure_home_theater()?;
+        <Self as HTControl>::get_ir_repeater_state(self)
+            .await?
+            .current_ir_repeater_state
+            .ok_or(Error::MissingValue("CurrentIRRepeaterState"))
+    }
+
+    pub async fn set_ir_repeater_state(&self, state: IRRepeaterState) -> Result<()> {
+        self.ensure_home_theater()?;
+        <Self as HTControl>::set_ir_repeater_state(
+            self,
+            ht_control::SetIrRepeaterStateRequest {
+                desired_ir_repeater_state: state,
+            },
+        )
+        .await
+    }
+
+    /// Returns true if a TV remote has been configured to control
+    /// the volume of the soundbar
+    pub async fn is_remote_configured(&self) -> Result<bool> {
+        self.ensure_home_theater()?;
+        Ok(<Self as HTControl>::is_remote_configured(self)
+            .await?
+            .remote_configured
+            .unwrap_or(false))
+    }
+
+    /// Returns the audio delay (lip sync) level; see `AUDIO_DELAY_RANGE`
+    pub async fn audio_delay(&self) -> Result<i16> {
+        self.ensure_home_theater()?;
+        self.eq_value("AudioDelay")
+            .await?
+            .ok_or(Error::MissingValue("AudioDelay"))
+    }
+
+    /// Sets the audio delay (lip sync) level, which delays the audio
+    /// so that it lines up with the picture on TVs that are slow to
+    /// display it. Higher levels delay the audio further.
+    /// Returns `Error::OutOfRange` if `level` is outside of
+    /// `AUDIO_DELAY_RANGE`.
+    pub async fn set_audio_delay(&self, level: i16) -> Result<()> {
+        check_range("AudioDelay", level, AUDIO_DELAY_RANGE)?;
+        self.ensure_home_theater()?;
+        self.set_eq_value("AudioDelay", level).await
+    }
+
+    fn ensure_home_theater(&self) -> Result<()> {
+        if self.supports(Capability::HtPlayback) {
+            Ok(())
+        } else {
+            Err(Error::NotSupported("Home theater control"))
+        }
+    }
+
+    /// Reads an extended EQ value, returning `Ok(None)` if the
+    /// device rejects the EQ type as not applicable
+    async fn eq_value(&self, eq_type: &str) -> Result<Option<i16>> {
+        let result = <Self as RenderingControl>::get_eq(
+            self,
+            rendering_control::GetEqRequest {
+                instance_id: InstanceId::DEFAULT,
+                eq_type: eq_type.to_string(),
+            },
+        )
+        .await;
+        match result {
+            Ok(response) => Ok(response.current_value),
+            Err(err) if err.is_soap_fault() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn set_eq_value(&self, eq_type: &str, value: i16) -> Result<()> {
+        <Self as RenderingControl>::set_eq(
+            self,
+            rendering_control::SetEqRequest {
+                instance_id: InstanceId::DEFAULT,
+                eq_type: eq_type.to_string(),
+                desired_value: value,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clamped() {
+        let settings = HomeTheaterAudio {
+            sub_gain: Some(20),
+            surround_level: Some(-20),
+            height_channel_level: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.clamped(),
+            HomeTheaterAudio {
+                sub_gain: Some(15),
+                surround_level: Some(-15),
+                height_channel_level: Some(3),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_audio_delay_range() {
+        let (device, _client) = crate::vcr::replay_device(vec![]).await;
+        assert!(matches!(
+            device.set_audio_delay(6).await,
+            Err(Error::OutOfRange { value: 6, .. })
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{Error, Result};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::{Method, StatusCode};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+/// An HTTP request made to a device
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: String,
+    /// Overrides the timeout configured for the client, if any
+    pub timeout: Option<Duration>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: Url) -> Self {
+        Self {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: String::new(),
+            timeout: None,
+        }
+    }
+
+    pub fn get(url: Url) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    pub fn post(url: Url) -> Self {
+        Self::new(Method::POST, url)
+    }
+
+    /// Adds a header
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self> {
+        self.headers.insert(
+            HeaderName::from_bytes(name.as_bytes())?,
+            HeaderValue::from_str(value)?,
+        );
+        Ok(self)
+    }
+
+    pub fn body(mut self, body: String) -> Self {
+        self.body = body;
+        self
+    }
+}
+
+/// The response to an `HttpRequest`
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Returns `Error::FailedRequest` if the status does not
+    /// indicate success
+    pub fn check(self) -> Result<Self> {
+        if self.status.is_success() {
+            Ok(self)
+        } else {
+            Err(Error::FailedRequest {
+                status: self.status,
+                body: self.body,
+                headers: self.headers,
+            })
+        }
+    }
+}
+
+pub type HttpFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// The HTTP stack used to talk to devices.
+/// Implementations are provided for `reqwest::Client`, with the
+/// `reqwest` feature, and for `HyperClient`, with the `hyper` feature;
+/// embedders can implement it to supply their own stack, such as the
+/// host's fetch API on wasm32-wasi, and pass it to
+/// `SonosDeviceBuilder::http_client`.
+///
+/// Failures in the underlying stack should be reported as
+/// `Error::Http`. The `RetryPolicy` only retries those whose chain
+/// of sources includes a `std::io::Error` of kind
+/// `ConnectionRefused`, as the request can't have reached the
+/// device; wrap the I/O error when failing to connect so that the
+/// request can be retried.
+pub trait HttpClient: Send + Sync {
+    fn send(&self, request: HttpRequest) -> HttpFuture<'_>;
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpClient for reqwest::Client {
+    fn send(&self, request: HttpRequest) -> HttpFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, request.url)
+                .headers(request.headers)
+                .body(request.body);
+            if let Some(timeout) = request.timeout {
+                builder = builder.timeout(timeout);
+            }
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.text().await?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// The `HttpClient` of a device, which is shared by its clones
+#[derive(Clone)]
+pub(crate) struct SharedHttpClient(Arc<dyn HttpClient>);
+
+impl SharedHttpClient {
+    pub fn new(client: Arc<dyn HttpClient>) -> Self {
+        Self(client)
+    }
+}
+
+impl std::ops::Deref for SharedHttpClient {
+    type Target = dyn HttpClient;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl std::fmt::Debug for SharedHttpClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SharedHttpClient")
+    }
+}
+
+#[cfg(feature = "hyper")]
+mod hyper_client {
+    use super::*;
+    use http_body_util::{BodyExt, Full};
+    use hyper::body::Bytes;
+    use hyper_util::client::legacy::connect::HttpConnector;
+    use hyper_util::client::legacy::Client;
+    use hyper_util::rt::TokioExecutor;
+
+    fn http_error(err: impl std::error::Error + Send + Sync + 'static) -> Error {
+        Error::Http(Box::new(err))
+    }
+
+    /// An `HttpClient` built on hyper, for embedders that already use
+    /// hyper and would rather not pull in a second HTTP stack.
+    /// It speaks plain HTTP only.
+    #[derive(Clone)]
+    pub struct HyperClient(Client<HttpConnector, Full<Bytes>>);
+
+    impl HyperClient {
+        pub fn new() -> Self {
+            Self(Client::builder(TokioExecutor::new()).build_http())
+        }
+
+        /// Wraps an existing hyper client, so that its connection
+        /// pool can be reused
+        pub fn with_client(client: Client<HttpConnector, Full<Bytes>>) -> Self {
+            Self(client)
+        }
+    }
+
+    impl Default for HyperClient {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl HttpClient for HyperClient {
+        fn send(&self, request: HttpRequest) -> HttpFuture<'_> {
+            Box::pin(async move {
+                let mut req = hyper::Request::builder()
+                    .method(request.method)
+                    .uri(request.url.as_str())
+                    .body(Full::new(Bytes::from(request.body)))
+                    .map_err(http_error)?;
+                *req.headers_mut() = request.headers;
+
+                let response = self.0.request(req);
+                let response = match request.timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, response)
+                        .await
+                        .map_err(|_| Error::Timeout)?,
+                    None => response.await,
+                }
+                .map_err(http_error)?;
+
+                let (parts, body) = response.into_parts();
+                let body = body.collect().await.map_err(http_error)?.to_bytes();
+                Ok(HttpResponse {
+                    status: parts.status,
+                    headers: parts.headers,
+                    body: String::from_utf8_lossy(&body).to_string(),
+                })
+            })
+        }
+    }
+}
+
+#[cfg(feature = "hyper")]
+pub use hyper_client::HyperClient;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_header() {
+        let request = HttpRequest::get("http://127.0.0.1:1400/".parse().unwrap())
+            .header("SOAPAction", "\"urn:x#Play\"")
+            .unwrap();
+        assert_eq!(request.headers["soapaction"], "\"urn:x#Play\"");
+        assert!(matches!(
+            HttpRequest::get("http://127.0.0.1:1400/".parse().unwrap()).header("bad name", "x"),
+            Err(Error::InvalidHeaderName(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Reading requests for the small HTTP servers in this crate: the
+//! event listener, the file server and the metrics endpoint
+use crate::AsyncTcpStream;
+use std::io;
+
+/// The limit on the size of the request line and headers
+const MAX_HEAD: usize = 16 * 1024;
+
+/// The limit on the size of the body; event bodies describing the
+/// topology of a large household can run to a few hundred KiB
+const MAX_BODY: usize = 4 * 1024 * 1024;
+
+/// A request received by one of the servers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IncomingRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl IncomingRequest {
+    /// Returns the value of the header `name`, compared case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Reads a request from `stream`, including the body if it has a
+/// `Content-Length`. Returns `None` if the peer closes the connection
+/// before sending a complete request.
+pub(crate) async fn read_request(
+    stream: &mut dyn AsyncTcpStream,
+) -> io::Result<Option<IncomingRequest>> {
+    let mut reqbuf = vec![];
+    let mut buf = [0u8; 4096];
+    let (mut request, body_start, content_length) = loop {
+        let len = stream.read(&mut buf).await?;
+        if len == 0 {
+            return Ok(None);
+        }
+        reqbuf.extend_from_slice(&buf[0..len]);
+
+        let mut headers = [httparse::EMPTY_HEADER; 32];
+        let mut req = httparse::Request::new(&mut headers);
+        match req.parse(&reqbuf).map_err(invalid_data)? {
+            httparse::Status::Complete(body_start) => {
+                let request = IncomingRequest {
+                    method: req.method.unwrap_or_default().to_string(),
+                    path: req.path.unwrap_or("/").to_string(),
+                    headers: req
+                        .headers
+                        .iter()
+                        .map(|h| {
+                            (
+                                h.name.to_string(),
+                                String::from_utf8_lossy(h.value).to_string(),
+                            )
+                        })
+                        .collect(),
+                    body: vec![],
+                };
+                let content_length = match request.header("Content-Length") {
+                    Some(value) => value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| invalid_data(format!("Invalid Content-Length {value:?}")))?,
+                    None => 0,
+                };
+                break (request, body_start, content_length);
+            }
+            httparse::Status::Partial if reqbuf.len() < MAX_HEAD => continue,
+            httparse::Status::Partial => {
+                return Err(invalid_data("request head is too large"));
+            }
+        }
+    };
+
+    if content_length > MAX_BODY {
+        return Err(invalid_data(format!(
+            "Content-Length {content_length} is too large"
+        )));
+    }
+    while reqbuf.len() - body_start < content_length {
+        let len = stream.read(&mut buf).await?;
+        if len == 0 {
+            return Ok(None);
+        }
+        reqbuf.extend_from_slice(&buf[0..len]);
+    }
+    reqbuf.truncate(body_start + content_length);
+    request.body = reqbuf.split_off(body_start);
+    Ok(Some(request))
+}
+
+fn invalid_data(err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod test {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpListener, TcpStream};
+
+    async fn roundtrip(chunks: &[&[u8]]) -> io::Result<Option<IncomingRequest>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let mut client = TcpStream::connect(listener.local_addr()?).await?;
+        let (mut server, _) = listener.accept().await?;
+        for chunk in chunks {
+            AsyncWriteExt::write_all(&mut client, chunk).await?;
+        }
+        drop(client);
+        read_request(&mut server).await
+    }
+
+    #[tokio::test]
+    async fn test_read_request() {
+        let request = roundtrip(&[
+            b"NOTIFY /event HTTP/1.1\r\nSID: uuid:1\r\nContent-Len",
+            b"gth: 5\r\n\r\nhel",
+            b"lo",
+        ])
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(request.method, "NOTIFY");
+        assert_eq!(request.path, "/event");
+        assert_eq!(request.header("sid"), Some("uuid:1"));
+        assert_eq!(request.body, b"hello");
+
+        let request = roundtrip(&[b"GET /metrics HTTP/1.1\r\n\r\n"])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(request.path, "/metrics");
+        assert!(request.body.is_empty());
+
+        assert_eq!(
+            roundtrip(&[b"GET /metrics HTTP/1.1\r\n"]).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            roundtrip(&[b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort"])
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(roundtrip(&[b"\x00\x01 nonsense\r\n\r\n"]).await.is_err());
+    }
+}
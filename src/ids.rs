@@ -0,0 +1,270 @@
+use crate::{Error, Result};
+use instant_xml::{Deserializer, FromXml, Id, Kind, ToXml};
+use std::fmt;
+use std::str::FromStr;
+
+/// Generates the conversions shared by the string valued identifiers.
+/// Values are validated by `new`, `FromStr` and `TryFrom<String>`.
+/// Values reported by the device, or read back via serde, are
+/// accepted as-is, so that an unexpected format from a newer
+/// firmware doesn't prevent the rest of a response from decoding.
+macro_rules! string_id {
+    ($ty:ident, $kind:literal, $valid:expr) => {
+        impl $ty {
+            /// Returns `Error::InvalidIdentifier` if `value` is not
+            /// in the expected format
+            pub fn new(value: impl Into<String>) -> Result<Self> {
+                let value = value.into();
+                let valid: fn(&str) -> bool = $valid;
+                if valid(&value) {
+                    Ok(Self(value))
+                } else {
+                    Err(Error::InvalidIdentifier { kind: $kind, value })
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        /// Formats as the bare string, so that identifiers read
+        /// the same in `Debug` output as they do in the protocol
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = Error;
+
+            fn from_str(value: &str) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<String> for $ty {
+            type Error = Error;
+
+            fn try_from(value: String) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$ty> for String {
+            fn from(value: $ty) -> String {
+                value.0
+            }
+        }
+
+        impl AsRef<str> for $ty {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $ty {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $ty {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<'xml> FromXml<'xml> for $ty {
+            #[inline]
+            fn matches(id: Id<'_>, field: Option<Id<'_>>) -> bool {
+                match field {
+                    Some(field) => id == field,
+                    None => false,
+                }
+            }
+
+            fn deserialize<'cx>(
+                into: &mut Self::Accumulator,
+                field: &'static str,
+                deserializer: &mut Deserializer<'cx, 'xml>,
+            ) -> std::result::Result<(), instant_xml::Error> {
+                if into.is_some() {
+                    return Err(instant_xml::Error::DuplicateValue(field));
+                }
+                if let Some(value) = deserializer.take_str()? {
+                    *into = Some(Self(value.to_string()));
+                }
+                Ok(())
+            }
+
+            type Accumulator = Option<Self>;
+            const KIND: Kind = Kind::Scalar;
+        }
+
+        impl ToXml for $ty {
+            fn serialize<W>(
+                &self,
+                id: Option<Id<'_>>,
+                serializer: &mut instant_xml::Serializer<'_, W>,
+            ) -> std::result::Result<(), instant_xml::Error>
+            where
+                W: fmt::Write + ?Sized,
+            {
+                self.0.serialize(id, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                Ok(Self(<String as serde::Deserialize>::deserialize(
+                    deserializer,
+                )?))
+            }
+        }
+    };
+}
+
+/// The UUID of a player, eg: `RINCON_000E58000000001400`
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlayerUuid(String);
+
+string_id!(PlayerUuid, "PlayerUuid", |value| {
+    value
+        .strip_prefix("RINCON_")
+        .is_some_and(|rest| !rest.is_empty() && rest.bytes().all(|b| b.is_ascii_alphanumeric()))
+});
+
+/// The id of a zone group, which is usually the UUID of the player
+/// that coordinated the group when it was formed followed by a
+/// number, eg: `RINCON_000E58000000001400:3581233811`.
+/// Ids of other forms, such as plain UUIDs, are also in use, so
+/// the only requirement is that it is not empty and has no spaces.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GroupId(String);
+
+string_id!(GroupId, "GroupId", |value| {
+    !value.is_empty() && !value.contains(char::is_whitespace)
+});
+
+impl GroupId {
+    /// Returns the UUID of the player whose id this group's id was
+    /// derived from. Note that coordination may since have moved
+    /// to another player; see `ZoneGroup::coordinator`.
+    pub fn player_uuid(&self) -> Option<PlayerUuid> {
+        let (uuid, _) = self.0.split_once(':')?;
+        uuid.parse().ok()
+    }
+}
+
+/// Identifies an item in the queue by its ContentDirectory object id,
+/// eg: `Q:0/5` for the fifth track.
+/// Not to be confused with `QueueItemId`, the `dc:queueItemId`
+/// element of DIDL-Lite metadata.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueueObjectId(String);
+
+const QUEUE_ITEM_PREFIX: &str = "Q:0/";
+
+string_id!(QueueObjectId, "QueueObjectId", |value| {
+    value
+        .strip_prefix(QUEUE_ITEM_PREFIX)
+        .and_then(|n| n.parse::<u32>().ok())
+        .is_some_and(|n| n > 0)
+});
+
+impl QueueObjectId {
+    /// Returns the id of the item at `track_number`, which
+    /// starts at 1
+    pub fn from_track_number(track_number: u32) -> Self {
+        Self(format!("{QUEUE_ITEM_PREFIX}{track_number}"))
+    }
+
+    /// Returns the position of the item in the queue, starting at 1
+    pub fn track_number(&self) -> Option<u32> {
+        self.0.strip_prefix(QUEUE_ITEM_PREFIX)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_player_uuid() {
+        let uuid: PlayerUuid = "RINCON_000E58000000001400".parse().unwrap();
+        assert_eq!(uuid.to_string(), "RINCON_000E58000000001400");
+        assert_eq!(format!("{uuid:?}"), "\"RINCON_000E58000000001400\"");
+        assert!(matches!(
+            "000E58000000001400".parse::<PlayerUuid>(),
+            Err(Error::InvalidIdentifier {
+                kind: "PlayerUuid",
+                ..
+            })
+        ));
+        assert!("RINCON_".parse::<PlayerUuid>().is_err());
+    }
+
+    #[test]
+    fn test_group_id() {
+        let id: GroupId = "RINCON_000E58000000001400:3581233811".parse().unwrap();
+        assert_eq!(
+            id.player_uuid().unwrap().as_str(),
+            "RINCON_000E58000000001400"
+        );
+
+        let id: GroupId = "9d7f5b8f-04fe-4dc0-9e81-0a14b77f05f9".parse().unwrap();
+        assert_eq!(id.player_uuid(), None);
+        assert!("".parse::<GroupId>().is_err());
+    }
+
+    #[test]
+    fn test_queue_item_id() {
+        let id = QueueObjectId::from_track_number(5);
+        assert_eq!(id, "Q:0/5");
+        assert_eq!(id.track_number(), Some(5));
+        assert_eq!(
+            "Q:0/12".parse::<QueueObjectId>().unwrap().track_number(),
+            Some(12)
+        );
+        assert!("Q:0/0".parse::<QueueObjectId>().is_err());
+        assert!("FV:2/5".parse::<QueueObjectId>().is_err());
+    }
+
+    #[test]
+    fn test_xml() {
+        #[derive(Debug, PartialEq, FromXml, ToXml)]
+        struct Group {
+            #[xml(attribute)]
+            coordinator: PlayerUuid,
+            #[xml(attribute)]
+            id: GroupId,
+        }
+
+        let xml = r#"<Group coordinator="RINCON_XXX" id="RINCON_XXX:97"></Group>"#;
+        let group: Group = instant_xml::from_str(xml).unwrap();
+        assert_eq!(group.coordinator, "RINCON_XXX");
+        assert_eq!(group.id.player_uuid(), Some(group.coordinator.clone()));
+        assert_eq!(instant_xml::to_string(&group).unwrap(), xml);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::metrics::{short_service_name, MetricLabels};
+use crate::{ActionCall, Error, Result, SonosDevice};
+use std::future::Future;
+use std::time::Instant;
+
+/// Summarizes the outcome of an action: `ok` on success, otherwise
+/// the UPnP error code reported by the device, the HTTP status code,
+/// or `error` for other kinds of failure
+pub(crate) fn result_code<T>(result: &Result<T>) -> String {
+    match result {
+        Ok(_) => "ok".to_string(),
+        Err(Error::Upnp(error)) => error.code.to_string(),
+        Err(Error::FailedRequest { status, .. }) => status.as_u16().to_string(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+/// Runs `fut`, which performs `call`, reporting its latency and
+/// outcome to the metrics sink of the device and, when the `tracing`
+/// feature is enabled, inside a span that carries the device,
+/// service, action, duration and result code
+pub(crate) async fn observe<T>(
+    device: &SonosDevice,
+    call: &ActionCall<'_>,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let labels = MetricLabels {
+        device: device.shared.url.host_str().unwrap_or_default(),
+        service: short_service_name(call.service),
+        action: call.action,
+    };
+    let sink = device.options.metrics.sink();
+    if let Some(sink) = sink {
+        sink.call_started(&labels);
+    }
+    let started = Instant::now();
+
+    #[cfg(feature = "tracing")]
+    let (result, span) = {
+        use tracing::Instrument;
+
+        let room = device.shared.room_name.lock().unwrap().clone();
+        let span = tracing::info_span!(
+            "sonos_action",
+            device = labels.device,
+            room = room.as_deref().unwrap_or_default(),
+            service = call.service,
+            action = call.action,
+            duration_ms = tracing::field::Empty,
+            result = tracing::field::Empty,
+        );
+        (fut.instrument(span.clone()).await, span)
+    };
+    #[cfg(not(feature = "tracing"))]
+    let result = fut.await;
+
+    let elapsed = started.elapsed();
+    let code = result_code(&result);
+    #[cfg(feature = "tracing")]
+    {
+        span.record("duration_ms", elapsed.as_millis() as u64);
+        span.record("result", code.as_str());
+    }
+
+    if let Some(sink) = sink {
+        if result.is_ok() {
+            sink.call_finished(&labels, elapsed);
+        } else {
+            sink.call_failed(&labels, elapsed, &code);
+        }
+    }
+    result
+}
@@ -0,0 +1,154 @@
+use crate::{Error, Result};
+use http::header::HeaderMap;
+use std::sync::Arc;
+use url::Url;
+
+/// Identifies a SOAP action that is being performed
+#[derive(Debug, Clone, Copy)]
+pub struct ActionCall<'a> {
+    /// The service type, eg: `urn:schemas-upnp-org:service:AVTransport:1`
+    pub service: &'a str,
+    /// The name of the action, eg: `Play`
+    pub action: &'a str,
+    /// The control URL to which the action is posted
+    pub url: &'a Url,
+}
+
+/// A SOAP request that is about to be sent
+#[derive(Debug, Clone)]
+pub struct SoapRequest {
+    /// Additional headers to send with the request
+    pub headers: HeaderMap,
+    /// The SOAP envelope
+    pub body: String,
+}
+
+/// Hooks into the SOAP actions performed by a `SonosDevice`, allowing
+/// integrators to add logging or metrics, to modify requests and
+/// responses, or to inject faults for testing.
+/// Interceptors are called in the order in which they were added.
+pub trait Interceptor: Send + Sync {
+    /// Called before each attempt to send a request.
+    /// Returning an error aborts the action with that error.
+    fn on_request(&self, _call: &ActionCall, _request: &mut SoapRequest) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the body of a successful response, before it
+    /// is decoded. Returning an error fails the action with that error.
+    fn on_response(&self, _call: &ActionCall, _body: &mut String) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called when an action fails
+    fn on_error(&self, _call: &ActionCall, _error: &Error) {}
+}
+
+/// The interceptors that have been added to a device
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl Interceptors {
+    pub fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub fn on_request(&self, call: &ActionCall, request: &mut SoapRequest) -> Result<()> {
+        for interceptor in &self.0 {
+            interceptor.on_request(call, request)?;
+        }
+        Ok(())
+    }
+
+    pub fn on_response(&self, call: &ActionCall, body: &mut String) -> Result<()> {
+        for interceptor in &self.0 {
+            interceptor.on_response(call, body)?;
+        }
+        Ok(())
+    }
+
+    pub fn on_error(&self, call: &ActionCall, error: &Error) {
+        for interceptor in &self.0 {
+            interceptor.on_error(call, error);
+        }
+    }
+}
+
+impl std::fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Interceptors({})", self.0.len())
+    }
+}
+
+#[cfg(all(test, feature = "tokio-runtime", feature = "svc-rendering-control"))]
+mod test {
+    use super::*;
+    use crate::{HttpClient, HttpFuture, HttpRequest, HttpResponse, SonosDevice};
+    use http::{HeaderValue, Method, StatusCode};
+    use std::sync::Mutex;
+
+    /// Serves the device description and answers every action
+    /// with a response that cannot be decoded
+    #[derive(Default)]
+    struct FakeDevice {
+        posted: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl HttpClient for FakeDevice {
+        fn send(&self, request: HttpRequest) -> HttpFuture<'_> {
+            Box::pin(async move {
+                let body = if request.method == Method::GET {
+                    include_str!("../data/device_spec.xml").to_string()
+                } else {
+                    self.posted.lock().unwrap().push(request);
+                    "<s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\"><s:Body><u:GetVolumeResponse xmlns:u=\"urn:schemas-upnp-org:service:RenderingControl:1\"><CurrentVolume>loud</CurrentVolume></u:GetVolumeResponse></s:Body></s:Envelope>".to_string()
+                };
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body,
+                })
+            })
+        }
+    }
+
+    struct Rewrite;
+
+    impl Interceptor for Rewrite {
+        fn on_request(&self, _call: &ActionCall, request: &mut SoapRequest) -> Result<()> {
+            request
+                .headers
+                .insert("x-rewritten", HeaderValue::from_static("yes"));
+            request.body = request.body.replace(">Master</Channel>", ">LF</Channel>");
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_raw_soap_records_rewritten_request() {
+        let client = Arc::new(FakeDevice::default());
+        let device = SonosDevice::builder()
+            .http_client(client.clone())
+            .from_url(
+                "http://192.168.1.2:1400/xml/device_description.xml"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .with_interceptor(Arc::new(Rewrite));
+
+        let err = device.get_volume().await.unwrap_err();
+
+        let posted = client.posted.lock().unwrap().pop().unwrap();
+        assert_eq!(
+            posted.headers.get("x-rewritten"),
+            Some(&HeaderValue::from_static("yes"))
+        );
+        assert!(posted.body.contains(">LF</Channel>"));
+
+        let raw = err.raw().expect("decode errors record the exchange");
+        assert_eq!(raw.request, posted.body);
+        assert!(!raw.request.contains("Master"));
+    }
+}
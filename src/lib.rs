@@ -1,21 +1,276 @@
+use crate::redact::{redact_xml, SENSITIVE_ARGUMENTS};
+use http::StatusCode;
 use instant_xml::{FromXmlOwned, ToXml};
-use reqwest::{StatusCode, Url};
 use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
+use url::Url;
 
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("sonos");
+
+#[cfg(feature = "svc-system-properties")]
+mod accounts;
+#[cfg(all(
+    feature = "svc-alarm-clock",
+    feature = "svc-av-transport",
+    feature = "svc-rendering-control"
+))]
+mod alarm;
+#[cfg(all(
+    feature = "serde",
+    feature = "svc-alarm-clock",
+    feature = "svc-av-transport",
+    feature = "svc-rendering-control"
+))]
+mod alarm_json;
+#[cfg(feature = "svc-rendering-control")]
+mod balance;
+mod battery;
+#[cfg(feature = "uniffi")]
+pub mod bindings;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(any(
+    feature = "mqtt",
+    feature = "rest",
+    feature = "uniffi",
+    feature = "webhooks"
+))]
+pub mod bridge;
+mod builder;
+mod cancel;
+mod capability;
+#[cfg_attr(not(feature = "svc-rendering-control"), allow(dead_code))]
+mod coalesce;
+mod device_error;
 mod didl;
+#[cfg(feature = "discovery")]
 mod discovery;
+#[cfg(feature = "svc-rendering-control")]
+mod eq;
+mod fault;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "file-server")]
+mod file_server;
+#[cfg(all(feature = "svc-device-properties", feature = "svc-zone-group-topology"))]
+mod firmware;
+// The generated code is formatted by codegen
+#[rustfmt::skip]
 mod generated;
+#[cfg(feature = "svc-content-directory")]
+mod history;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-ht-control",
+    feature = "svc-rendering-control",
+    feature = "svc-zone-group-topology"
+))]
+mod home_theater;
+mod http_client;
+#[cfg(any(feature = "events", feature = "file-server"))]
+mod http_server;
+mod ids;
+mod instrument;
+mod interceptor;
+#[cfg(all(
+    feature = "svc-audio-in",
+    feature = "svc-device-properties",
+    feature = "svc-rendering-control"
+))]
+mod line_in;
+#[cfg(feature = "svc-rendering-control")]
+mod line_out;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-queue"))]
+mod m3u;
+mod metrics;
+#[cfg(all(feature = "mpris", target_os = "linux"))]
+mod mpris;
+#[cfg(feature = "svc-music-services")]
+mod music_service;
+#[cfg(feature = "svc-zone-group-topology")]
+mod network;
+#[cfg(all(
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+mod podcast;
+#[cfg(feature = "svc-music-services")]
+mod presentation;
+#[cfg(feature = "prometheus")]
+mod prometheus;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-queue"))]
+mod queue_sync;
+#[cfg(all(
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+mod radio;
+mod reboot;
+mod redact;
+mod retry;
+#[cfg(feature = "svc-device-properties")]
+mod room;
+mod runtime;
+#[cfg(all(
+    feature = "scheduler",
+    feature = "svc-alarm-clock",
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-rendering-control",
+    feature = "svc-system-properties"
+))]
+mod scheduler;
+#[cfg(feature = "serde")]
+pub mod schema;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-music-services"))]
+mod service_uri;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+mod smapi;
+mod sonos_bool;
+mod sonos_uri;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+mod system;
+#[cfg(feature = "svc-alarm-clock")]
+mod time_zone;
+#[cfg(feature = "https")]
+mod tls;
+#[cfg(feature = "svc-rendering-control")]
+mod trueplay;
 mod upnp;
+#[cfg(feature = "vcr")]
+mod vcr;
+#[cfg(feature = "svc-rendering-control")]
+mod volume;
 mod xmlutil;
 mod zone;
+#[cfg(feature = "svc-device-properties")]
+mod zone_info;
 
+#[cfg(feature = "svc-system-properties")]
+pub use accounts::*;
+#[cfg(all(
+    feature = "svc-alarm-clock",
+    feature = "svc-av-transport",
+    feature = "svc-rendering-control"
+))]
+pub use alarm::*;
+pub use battery::*;
+pub use builder::*;
+pub use capability::*;
+pub use device_error::*;
 pub use didl::*;
+#[cfg(feature = "discovery")]
 pub use discovery::*;
+#[cfg(feature = "svc-rendering-control")]
+pub use eq::*;
+pub use fault::*;
+#[cfg(feature = "file-server")]
+pub use file_server::*;
+#[cfg(all(feature = "svc-device-properties", feature = "svc-zone-group-topology"))]
+pub use firmware::*;
 pub use generated::*;
+#[cfg(feature = "svc-content-directory")]
+pub use history::*;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-ht-control",
+    feature = "svc-rendering-control",
+    feature = "svc-zone-group-topology"
+))]
+pub use home_theater::*;
+#[cfg(feature = "hyper")]
+pub use http_client::HyperClient;
+pub use http_client::{HttpClient, HttpFuture, HttpRequest, HttpResponse};
+pub use ids::*;
+pub use interceptor::{ActionCall, Interceptor, SoapRequest};
+#[cfg(all(
+    feature = "svc-audio-in",
+    feature = "svc-device-properties",
+    feature = "svc-rendering-control"
+))]
+pub use line_in::*;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-queue"))]
+pub use m3u::*;
+pub use metrics::{MetricLabels, MetricsSink};
+#[cfg(all(feature = "mpris", target_os = "linux"))]
+pub use mpris::MprisServer;
+#[cfg(feature = "svc-music-services")]
+pub use music_service::*;
+#[cfg(feature = "svc-zone-group-topology")]
+pub use network::*;
+#[cfg(feature = "svc-music-services")]
+pub use presentation::*;
+#[cfg(feature = "prometheus")]
+pub use prometheus::*;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-queue"))]
+pub use queue_sync::*;
+#[cfg(all(
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+pub use radio::*;
+pub use retry::*;
+#[cfg(feature = "svc-device-properties")]
+pub use room::*;
+pub use runtime::*;
+#[cfg(all(
+    feature = "scheduler",
+    feature = "svc-alarm-clock",
+    feature = "svc-av-transport",
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-rendering-control",
+    feature = "svc-system-properties"
+))]
+pub use scheduler::*;
+#[cfg(all(feature = "svc-av-transport", feature = "svc-music-services"))]
+pub use service_uri::*;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+pub use smapi::*;
+pub use sonos_bool::*;
+pub use sonos_uri::*;
+#[cfg(all(
+    feature = "svc-device-properties",
+    feature = "svc-music-services",
+    feature = "svc-system-properties"
+))]
+pub use system::*;
+#[cfg(feature = "svc-alarm-clock")]
+pub use time_zone::*;
+#[cfg(feature = "https")]
+pub use tls::CertificateFingerprint;
+#[cfg(feature = "svc-rendering-control")]
+pub use trueplay::*;
 pub use upnp::*;
-pub use xmlutil::DecodeXmlString;
+#[cfg(feature = "vcr")]
+pub use vcr::*;
+#[cfg(feature = "svc-rendering-control")]
+pub use volume::VolumeChange;
+pub use xmlutil::{DecodeXmlString, HmsDuration, NumericBool};
 pub use zone::*;
+#[cfg(feature = "svc-device-properties")]
+pub use zone_info::*;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -32,13 +287,35 @@ pub enum Error {
     UnsupportedService(String),
     #[error("Invalid URI: {0:#?}")]
     InvalidUri(#[from] url::ParseError),
+    #[cfg(feature = "reqwest")]
     #[error("Reqwest Error: {0:#?}")]
     Reqwest(#[from] reqwest::Error),
+    #[error("HTTP Error: {0:#}")]
+    Http(Box<dyn std::error::Error + Send + Sync>),
+    #[error("Invalid header value: {0:#}")]
+    InvalidHeaderValue(#[from] http::header::InvalidHeaderValue),
+    #[error("Invalid header name: {0:#}")]
+    InvalidHeaderName(#[from] http::header::InvalidHeaderName),
     #[error("Failed Request: {status:?} {body}")]
     FailedRequest {
         status: StatusCode,
         body: String,
-        headers: reqwest::header::HeaderMap,
+        headers: http::HeaderMap,
+    },
+    #[error("{0}")]
+    Upnp(UpnpError),
+    /// Wraps the errors returned by the methods of `SonosDevice`
+    /// to identify the device that failed; see `Error::inner`
+    #[error("{device}: {error:#}")]
+    Device {
+        device: Box<DeviceIdentity>,
+        error: Box<Error>,
+    },
+    #[error("Failed to decode response to {action}: {error:#}")]
+    DecodeResponse {
+        action: String,
+        error: Box<Error>,
+        raw: RawSoap,
     },
     #[error("Device has no name!?")]
     NoName,
@@ -60,8 +337,105 @@ pub enum Error {
     LastChangeFormatUnexpected(String),
     #[error("Device reports None for volume")]
     VolumeNone,
+    #[error("Device did not assign an ObjectID when creating {0}")]
+    NoAssignedObjectId(String),
+    #[error("Device did not report a value for {0}")]
+    MissingValue(&'static str),
+    #[error("{0:?} is not a boolean value")]
+    InvalidBool(String),
+    #[error("Invalid {kind} {value:?}")]
+    InvalidIdentifier { kind: &'static str, value: String },
+    #[error("Invalid MAC address {0}")]
+    InvalidMacAddress(String),
+    #[cfg(feature = "rustls")]
+    #[error("TLS Error: {0}")]
+    Tls(#[from] rustls::Error),
+    #[cfg(feature = "https")]
+    #[error("Invalid certificate fingerprint {0}")]
+    InvalidFingerprint(String),
+    #[cfg(feature = "vcr")]
+    #[error("No recorded exchange matches {0}")]
+    NoRecordedExchange(String),
+    #[error("The operation was cancelled")]
+    Cancelled,
+    #[error("Timed out waiting for the device")]
+    Timeout,
+    #[error("{0} is not supported by this device")]
+    NotSupported(&'static str),
+    #[error("Invalid {name} {value:?}")]
+    InvalidNumber { name: &'static str, value: String },
+    #[error("{name} value {value} is outside of the range {min}..={max}")]
+    OutOfRange {
+        name: &'static str,
+        value: i32,
+        min: i32,
+        max: i32,
+    },
+    #[error("Music service {0} is not available to this household")]
+    MusicServiceNotFound(u32),
+    #[error("Music service named {0} is not available to this household")]
+    MusicServiceNameNotFound(String),
+    #[error("The system has no devices")]
+    NoDevices,
+    #[cfg(feature = "svc-music-services")]
+    #[error("Music service authentication type {0:?} is not supported")]
+    UnsupportedAuthType(AuthType),
+    #[error("No token has been stored for music service {0}")]
+    NoServiceToken(u32),
+    #[cfg(all(
+        feature = "svc-device-properties",
+        feature = "svc-music-services",
+        feature = "svc-system-properties"
+    ))]
+    #[error("SMAPI Fault: {code} {message}")]
+    SmapiFault {
+        code: String,
+        message: String,
+        /// A replacement token, provided by the service when
+        /// the token used for the request has expired
+        refreshed_token: Option<SmapiToken>,
+    },
+    #[error("Alarm {0} not found")]
+    AlarmNotFound(u32),
+    #[error("Invalid time {0}")]
+    InvalidTime(String),
+    #[error("Time zone {0} is not known")]
+    UnknownTimeZone(String),
+    #[error("Favorite {0} not found")]
+    FavoriteNotFound(String),
+    #[error("No address was given to SonosDeviceBuilder")]
+    NoAddress,
+    #[error("No async runtime is available; enable the tokio-runtime feature or supply one")]
+    NoRuntime,
+    #[error("No HTTP client is available; enable the reqwest feature or supply one")]
+    NoHttpClient,
+    #[cfg(feature = "serde")]
+    #[error("JSON Error: {0:#}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(all(feature = "mpris", target_os = "linux"))]
+    #[error("D-Bus Error: {0:#}")]
+    DBus(#[from] zbus::Error),
+    #[cfg(feature = "mqtt")]
+    #[error("MQTT Error: {0:#}")]
+    Mqtt(Box<dyn std::error::Error + Send + Sync>),
+    #[cfg(any(
+        feature = "mqtt",
+        feature = "rest",
+        feature = "uniffi",
+        feature = "webhooks"
+    ))]
+    #[error("Unknown bridge command {0}")]
+    UnknownBridgeCommand(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[cfg(feature = "blocking")]
+    #[error(
+        "sonos::blocking can't be used from within an async runtime; use the async API instead"
+    )]
+    BlockingInAsyncContext,
 }
 
+#[cfg(feature = "reqwest")]
 impl Error {
     pub async fn with_failed_http_response(response: reqwest::Response) -> Error {
         let status = response.status();
@@ -88,20 +462,72 @@ impl Error {
     }
 }
 
+/// A handle to a player.
+///
+/// Handles are cheap to clone: the clones share the connection to
+/// the device, the cached room name and the limit on concurrent
+/// actions, so a handle can be cloned into each task that uses the
+/// device, or stored in a map, rather than constructing another.
+/// `SonosDevice` is `Send + Sync`, and its methods take `&self`,
+/// so a handle can also be shared by reference across tasks.
+///
+/// The `with_*` methods return a copy of the handle whose options
+/// differ; the shared state is unaffected, so other handles to the
+/// same device continue to use their own options.
 #[derive(Debug, Clone)]
 pub struct SonosDevice {
+    shared: Arc<SharedDevice>,
+    options: Arc<HandleOptions>,
+}
+
+/// The state that is shared by the clones of a `SonosDevice`
+#[derive(Debug)]
+struct SharedDevice {
     url: Url,
     device: DeviceSpec,
+    /// The last known room name, used to label errors and metrics.
+    /// Refreshed by `name()` and `set_room_name()`.
+    room_name: Mutex<Option<String>>,
+    client: http_client::SharedHttpClient,
+    runtime: runtime::SharedRuntime,
+    /// Limits the number of concurrent SOAP actions, as players
+    /// misbehave when too many are in flight at once.
+    action_permits: tokio::sync::Semaphore,
+    /// Set when rapid `set_volume` calls are coalesced; see
+    /// `SonosDeviceBuilder::coalesce_volume`
+    #[cfg_attr(not(feature = "svc-rendering-control"), allow(dead_code))]
+    volume_coalescer: Option<coalesce::Coalescer>,
+    /// The music services available to the household, loaded on
+    /// first use; see `SonosDevice::music_service`
+    #[cfg(feature = "svc-music-services")]
+    services: Arc<ServiceRegistry>,
+}
+
+/// The options of a `SonosDevice` handle, which the `with_*`
+/// methods override on a copy of the handle
+#[derive(Debug, Clone)]
+struct HandleOptions {
+    retry_policy: RetryPolicy,
+    /// Overrides the timeout of the client; see `with_timeout()`
+    timeout: Option<std::time::Duration>,
+    interceptors: interceptor::Interceptors,
+    metrics: metrics::Metrics,
 }
 
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SonosDevice>();
+};
+
 impl SonosDevice {
     /// Constructs a SonosDevice from the supplied IP Address.
     /// Validates that the device is actually a Sonos device
     /// before returning successfully.
     pub async fn from_ip(addr: Ipv4Addr) -> Result<Self> {
-        Self::from_url(format!("http://{addr}:1400/xml/device_description.xml").parse()?).await
+        Self::builder().from_ip(addr).await
     }
 
+    #[cfg(all(feature = "discovery", feature = "svc-device-properties"))]
     /// Resolves the SonosDevice whose name is equal to the provided
     /// name.  If no matching device is found within a reasonably
     /// short, unspecified, implementation-defined timeout, then
@@ -124,21 +550,22 @@ impl SonosDevice {
     /// Validates that the device is actually a Sonos device
     /// before returning successfully.
     pub async fn from_url(url: Url) -> Result<Self> {
-        let response = reqwest::get(url.clone()).await?;
-
-        let response = Error::check_response(response).await?;
-        let body = response.text().await?;
-        let device = DeviceSpec::parse_xml(&body)?;
-
-        Ok(Self { url, device })
+        Self::builder().from_url(url).await
     }
 
-    /// Returns the room/zone name of the device
+    #[cfg(feature = "svc-device-properties")]
+    /// Returns the room/zone name of the device.
+    /// The name is always read from the device, as the room may have
+    /// been renamed by another controller, and is remembered so that
+    /// errors and metrics can be labelled with it.
     pub async fn name(&self) -> Result<String> {
-        let attr = self.get_zone_attributes().await?;
-        attr.current_zone_name.ok_or(Error::NoName)
+        let attr = <Self as DeviceProperties>::get_zone_attributes(self).await?;
+        let name = attr.current_zone_name.ok_or(Error::NoName)?;
+        self.shared.room_name.lock().unwrap().replace(name.clone());
+        Ok(name)
     }
 
+    #[cfg(feature = "svc-zone-group-topology")]
     /// Returns information about the zone to which this device belongs
     pub async fn get_zone_group_state(&self) -> Result<Vec<ZoneGroup>> {
         let state = <Self as ZoneGroupTopology>::get_zone_group_state(self).await?;
@@ -151,12 +578,29 @@ impl SonosDevice {
         })
     }
 
+    #[cfg(feature = "svc-zone-group-topology")]
+    /// Returns the topology entry for this device, if it is a
+    /// visible member of a zone group
+    pub async fn zone_group_member(&self) -> Result<Option<ZoneGroupMember>> {
+        let host = self.shared.url.host_str();
+        for group in self.get_zone_group_state().await? {
+            for member in group.members {
+                let location: Option<Url> = member.location.parse().ok();
+                if location.as_ref().and_then(|url| url.host_str()) == host {
+                    return Ok(Some(member));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    #[cfg(feature = "svc-rendering-control")]
     /// Sets the mute state for the master sound channel
     pub async fn set_mute(&self, mute: bool) -> Result<()> {
         <Self as RenderingControl>::set_mute(
             self,
             rendering_control::SetMuteRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 channel: MuteChannel::Master,
                 desired_mute: mute,
             },
@@ -164,12 +608,13 @@ impl SonosDevice {
         .await
     }
 
+    #[cfg(feature = "svc-rendering-control")]
     /// Returns the mute state for the master sound channel
     pub async fn get_mute(&self) -> Result<bool> {
         <Self as RenderingControl>::get_mute(
             self,
             rendering_control::GetMuteRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 channel: MuteChannel::Master,
             },
         )
@@ -178,13 +623,24 @@ impl SonosDevice {
         .ok_or(Error::VolumeNone)
     }
 
+    #[cfg(feature = "svc-rendering-control")]
     /// Sets the volume of master sound channel.
-    /// volume is in the range 0-100
-    pub async fn set_volume(&self, volume: u16) -> Result<()> {
+    /// volume is in the range 0-100.
+    ///
+    /// If volume coalescing is enabled, the call waits for the
+    /// coalescing window and only the last of a burst of calls is
+    /// sent to the device; the others return `Ok(())` without
+    /// doing anything.
+    pub async fn set_volume(&self, volume: Volume) -> Result<()> {
+        if let Some(coalescer) = &self.shared.volume_coalescer {
+            if !coalescer.is_latest().await {
+                return Ok(());
+            }
+        }
         <Self as RenderingControl>::set_volume(
             self,
             rendering_control::SetVolumeRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 channel: Channel::Master,
                 desired_volume: volume,
             },
@@ -192,13 +648,14 @@ impl SonosDevice {
         .await
     }
 
+    #[cfg(feature = "svc-rendering-control")]
     /// Gets the volume of the master sound channel.
     /// Returned volume is in the range 0-100
-    pub async fn get_volume(&self) -> Result<u16> {
+    pub async fn get_volume(&self) -> Result<Volume> {
         <Self as RenderingControl>::get_volume(
             self,
             rendering_control::GetVolumeRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 channel: Channel::Master,
             },
         )
@@ -207,55 +664,144 @@ impl SonosDevice {
         .ok_or(Error::VolumeNone)
     }
 
+    #[cfg(feature = "svc-rendering-control")]
+    /// Adjusts the volume of the master sound channel by `delta`,
+    /// which may be negative, and returns the new volume.
+    /// The device clamps the result to the range 0-100.
+    pub async fn adjust_volume(&self, delta: i16) -> Result<Volume> {
+        <Self as RenderingControl>::set_relative_volume(
+            self,
+            rendering_control::SetRelativeVolumeRequest {
+                instance_id: InstanceId::DEFAULT,
+                channel: Channel::Master,
+                adjustment: delta.into(),
+            },
+        )
+        .await?
+        .new_volume
+        .ok_or(Error::VolumeNone)
+    }
+
+    #[cfg(feature = "svc-device-properties")]
+    /// Turns the status light on the front of the device on or off
+    pub async fn set_status_light(&self, on: bool) -> Result<()> {
+        <Self as DeviceProperties>::set_led_state(
+            self,
+            device_properties::SetLedStateRequest {
+                desired_led_state: LEDState::from_bool(on),
+            },
+        )
+        .await
+    }
+
+    #[cfg(feature = "svc-device-properties")]
+    /// Returns true if the status light on the front of the device is on
+    pub async fn status_light(&self) -> Result<bool> {
+        let state = <Self as DeviceProperties>::get_led_state(self)
+            .await?
+            .current_led_state
+            .ok_or(Error::MissingValue("CurrentLEDState"))?;
+        state.try_to_bool()
+    }
+
+    #[cfg(feature = "svc-device-properties")]
+    /// Locks or unlocks the physical buttons and touch controls on
+    /// the device
+    pub async fn set_buttons_locked(&self, locked: bool) -> Result<()> {
+        <Self as DeviceProperties>::set_button_lock_state(
+            self,
+            device_properties::SetButtonLockStateRequest {
+                desired_button_lock_state: ButtonLockState::from_bool(locked),
+            },
+        )
+        .await
+    }
+
+    #[cfg(feature = "svc-device-properties")]
+    /// Returns true if the physical buttons and touch controls on
+    /// the device are locked
+    pub async fn buttons_locked(&self) -> Result<bool> {
+        let state = <Self as DeviceProperties>::get_button_lock_state(self)
+            .await?
+            .current_button_lock_state
+            .ok_or(Error::MissingValue("CurrentButtonLockState"))?;
+        state.try_to_bool()
+    }
+
+    #[cfg(feature = "svc-av-transport")]
     /// Stops playback
     pub async fn stop(&self) -> Result<()> {
         <Self as AVTransport>::stop(self, Default::default()).await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     /// Begin playback
     pub async fn play(&self) -> Result<()> {
         <Self as AVTransport>::play(
             self,
             av_transport::PlayRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 speed: "1".to_string(),
             },
         )
         .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     /// pause playback
     pub async fn pause(&self) -> Result<()> {
-        <Self as AVTransport>::pause(self, av_transport::PauseRequest { instance_id: 0 }).await
+        <Self as AVTransport>::pause(
+            self,
+            av_transport::PauseRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     /// Skip to the next track
     pub async fn next(&self) -> Result<()> {
-        <Self as AVTransport>::next(self, av_transport::NextRequest { instance_id: 0 }).await
+        <Self as AVTransport>::next(
+            self,
+            av_transport::NextRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     /// Skip to the previous track
     pub async fn previous(&self) -> Result<()> {
-        <Self as AVTransport>::previous(self, av_transport::PreviousRequest { instance_id: 0 })
-            .await
+        <Self as AVTransport>::previous(
+            self,
+            av_transport::PreviousRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     /// Clears the queue
     pub async fn queue_clear(&self) -> Result<()> {
         <Self as AVTransport>::remove_all_tracks_from_queue(self, Default::default()).await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     pub async fn set_play_mode(&self, new_play_mode: CurrentPlayMode) -> Result<()> {
         <Self as AVTransport>::set_play_mode(
             self,
             av_transport::SetPlayModeRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 new_play_mode: new_play_mode,
             },
         )
         .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     pub async fn set_av_transport_uri(
         &self,
         uri: &str,
@@ -264,7 +810,7 @@ impl SonosDevice {
         <Self as AVTransport>::set_av_transport_uri(
             self,
             av_transport::SetAvTransportUriRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 current_uri: uri.to_string(),
                 current_uri_meta_data: metadata.into(),
             },
@@ -272,6 +818,7 @@ impl SonosDevice {
         .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     pub async fn queue_prepend(
         &self,
         uri: &str,
@@ -280,7 +827,7 @@ impl SonosDevice {
         <Self as AVTransport>::add_uri_to_queue(
             self,
             av_transport::AddUriToQueueRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 enqueued_uri: uri.to_string(),
                 enqueued_uri_meta_data: metadata.into(),
                 desired_first_track_number_enqueued: 0,
@@ -290,6 +837,7 @@ impl SonosDevice {
         .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
     pub async fn queue_append(
         &self,
         uri: &str,
@@ -298,7 +846,7 @@ impl SonosDevice {
         <Self as AVTransport>::add_uri_to_queue(
             self,
             av_transport::AddUriToQueueRequest {
-                instance_id: 0,
+                instance_id: InstanceId::DEFAULT,
                 enqueued_uri: uri.to_string(),
                 enqueued_uri_meta_data: metadata.into(),
                 desired_first_track_number_enqueued: 0,
@@ -308,6 +856,21 @@ impl SonosDevice {
         .await
     }
 
+    #[cfg(feature = "svc-av-transport")]
+    /// Removes `item` from the queue; see `TrackMetaData::queue_item_id`
+    pub async fn queue_remove(&self, item: &QueueObjectId) -> Result<()> {
+        <Self as AVTransport>::remove_track_from_queue(
+            self,
+            av_transport::RemoveTrackFromQueueRequest {
+                instance_id: InstanceId::DEFAULT,
+                object_id: item.to_string(),
+                update_id: 0,
+            },
+        )
+        .await
+    }
+
+    #[cfg(feature = "svc-queue")]
     pub async fn queue_browse(
         &self,
         starting_index: u32,
@@ -329,8 +892,37 @@ impl SonosDevice {
         }
     }
 
+    #[cfg(feature = "svc-content-directory")]
+    /// Browses the direct children of a ContentDirectory container,
+    /// such as `FV:2` (favorites), `SQ:` (saved playlists) or `R:0/0`
+    /// (radio stations).
+    /// A `requested_count` of 0 is treated by the device as 1000.
+    pub async fn content_browse(
+        &self,
+        object_id: &str,
+        starting_index: u32,
+        requested_count: u32,
+    ) -> Result<Vec<TrackMetaData>> {
+        let result = <Self as ContentDirectory>::browse(
+            self,
+            content_directory::BrowseRequest::builder()
+                .object_id(object_id)
+                .browse_flag(BrowseFlag::BrowseDirectChildren)
+                .filter("*")
+                .starting_index(starting_index)
+                .requested_count(requested_count)
+                .build(),
+        )
+        .await?;
+
+        match result.result {
+            Some(list) => Ok(list.into_inner().map(|i| i.tracks).unwrap_or_else(Vec::new)),
+            None => Ok(vec![]),
+        }
+    }
+
     pub fn url(&self) -> &Url {
-        &self.url
+        &self.shared.url
     }
 }
 
@@ -342,6 +934,7 @@ mod soap {
     use instant_xml::ToXml;
 
     #[derive(Debug, Eq, PartialEq, ToXml)]
+    #[cfg_attr(not(feature = "svc-zone-group-topology"), allow(dead_code))]
     pub struct Unit;
 
     #[derive(Debug, Eq, PartialEq, ToXml)]
@@ -397,7 +990,9 @@ mod soap_empty_resp {
     pub struct Body {}
 }
 
-/// This trait decodes a SOAP response envelope into Self
+/// This trait decodes a SOAP response envelope into Self.
+/// Implementations for response types that derive `FromXml` can
+/// simply delegate to `decode_soap_payload`.
 pub trait DecodeSoapResponse {
     /// xml is a complete Soap `<Envelope>` element.
     /// This method decodes and returns Self from that Envelope.
@@ -406,6 +1001,13 @@ pub trait DecodeSoapResponse {
         Self: Sized;
 }
 
+/// Decodes the payload of the `<Body>` of the SOAP `<Envelope>`
+/// in `xml` as `T`
+pub fn decode_soap_payload<T: FromXmlOwned>(xml: &str) -> Result<T> {
+    let envelope: soap_resp::Envelope<T> = instant_xml::from_str(xml)?;
+    Ok(envelope.body.payload)
+}
+
 impl DecodeSoapResponse for () {
     fn decode_soap_xml(xml: &str) -> Result<()> {
         // Verify that it parses, but discard because it has no
@@ -417,23 +1019,114 @@ impl DecodeSoapResponse for () {
 
 impl SonosDevice {
     pub fn device_spec(&self) -> &DeviceSpec {
-        &self.device
+        &self.shared.device
+    }
+
+    /// Returns a copy of this handle that applies `policy` to the
+    /// SOAP actions that it performs.
+    /// By default, actions are not retried.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        Arc::make_mut(&mut self.options).retry_policy = policy;
+        self
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.options.retry_policy
+    }
+
+    /// Returns a copy of this handle that passes the SOAP actions
+    /// that it performs through `interceptor`, in addition to any
+    /// interceptors that were previously added
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn Interceptor>) -> Self {
+        Arc::make_mut(&mut self.options)
+            .interceptors
+            .push(interceptor);
+        self
     }
 
+    /// Returns a copy of this handle that reports the latency and
+    /// outcome of the SOAP actions that it performs to `sink`
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        Arc::make_mut(&mut self.options).metrics = metrics::Metrics::new(sink);
+        self
+    }
+
+    #[cfg(feature = "events")]
     pub async fn subscribe_helper<T: DecodeXml + 'static>(
         &self,
         service: &str,
     ) -> Result<EventStream<T>> {
         let service = self
+            .shared
             .device
             .get_service(service)
-            .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
-        service.subscribe(&self.url).await
+            .ok_or_else(|| Error::UnsupportedService(service.to_string()).with_device(self))?;
+        service
+            .subscribe_with_client(&self.shared.client, &self.shared.runtime, &self.shared.url)
+            .await
+            .map_err(|err| err.with_device(self))
     }
 
     /// This is a low level helper function for performing a SOAP Action
     /// request. You most likely want to use one of the methods
-    /// implemented by the various service traits instead of this.
+    /// implemented by the various service traits instead of this,
+    /// but it can be used to call actions that are not yet modeled
+    /// by this crate.
+    ///
+    /// `service` is the service type, eg:
+    /// `urn:schemas-upnp-org:service:AVTransport:1`, and `action` is
+    /// the name of the action.
+    ///
+    /// `payload` must serialize to the action element, named for the
+    /// action and in the namespace of the service type, whose children
+    /// are the arguments in the empty namespace.
+    ///
+    /// `RESP` must decode the `<{action}Response>` element in the same
+    /// way; use `()` for actions that return no values.
+    ///
+    /// Dropping the returned future abandons the request, but the
+    /// device may already have received and carried out the action.
+    ///
+    /// ```no_run
+    /// # async fn example(device: sonos::SonosDevice) -> sonos::Result<()> {
+    /// use instant_xml::{FromXml, ToXml};
+    /// use sonos::InstanceId;
+    ///
+    /// const SERVICE: &str = "urn:schemas-upnp-org:service:AVTransport:1";
+    ///
+    /// #[derive(ToXml)]
+    /// #[xml(rename = "GetTransportSettings", ns(SERVICE))]
+    /// struct Request {
+    ///     #[xml(rename = "InstanceID", ns(""))]
+    ///     instance_id: InstanceId,
+    /// }
+    ///
+    /// #[derive(FromXml, Debug)]
+    /// #[xml(rename = "GetTransportSettingsResponse", ns(SERVICE))]
+    /// struct Response {
+    ///     #[xml(rename = "PlayMode", ns(""))]
+    ///     play_mode: Option<String>,
+    /// }
+    ///
+    /// impl sonos::DecodeSoapResponse for Response {
+    ///     fn decode_soap_xml(xml: &str) -> sonos::Result<Self> {
+    ///         sonos::decode_soap_payload(xml)
+    ///     }
+    /// }
+    ///
+    /// let response: Response = device
+    ///     .action(
+    ///         SERVICE,
+    ///         "GetTransportSettings",
+    ///         Request {
+    ///             instance_id: InstanceId::DEFAULT,
+    ///         },
+    ///     )
+    ///     .await?;
+    /// println!("{:?}", response.play_mode);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn action<REQ: ToXml, RESP>(
         &self,
         service: &str,
@@ -444,6 +1137,7 @@ impl SonosDevice {
         RESP: FromXmlOwned + std::fmt::Debug + DecodeSoapResponse,
     {
         let service = self
+            .shared
             .device
             .get_service(service)
             .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
@@ -454,25 +1148,139 @@ impl SonosDevice {
         };
 
         let body = instant_xml::to_string(&envelope)?;
-        log::trace!("Sending: {body}");
 
-        let soap_action = format!("\"{}#{action}\"", service.service_type);
-        let url = service.control_url(&self.url);
+        let url = service.control_url(&self.shared.url);
+        let call = ActionCall {
+            service: &service.service_type,
+            action,
+            url: &url,
+        };
+
+        let result = instrument::observe(self, &call, async {
+            let (sent_body, response_body) = self.perform_action(&call, &body).await?;
+            RESP::decode_soap_xml(&response_body).map_err(|error| Error::DecodeResponse {
+                action: action.to_string(),
+                error: Box::new(error),
+                raw: RawSoap::new(&sent_body, &response_body),
+            })
+        })
+        .await;
+        if let Err(err) = &result {
+            self.options.interceptors.on_error(&call, err);
+        }
+        result.map_err(|err| err.with_device(self))
+    }
+
+    /// Performs a SOAP Action without encoding the request or decoding
+    /// the response, which is useful for experimenting with undocumented
+    /// actions or for debugging devices that return malformed XML.
+    ///
+    /// `body` is the XML of the action element, which is wrapped in a
+    /// SOAP envelope before it is sent, eg:
+    /// `<u:GetVolume xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><InstanceID>0</InstanceID><Channel>Master</Channel></u:GetVolume>`.
+    /// The complete response envelope is returned.
+    pub async fn action_raw(&self, service: &str, action: &str, body: &str) -> Result<String> {
+        let service = self
+            .shared
+            .device
+            .get_service(service)
+            .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
+
+        let body = format!(
+            "<s:Envelope xmlns:s=\"{SOAP_ENVELOPE}\" s:encodingStyle=\"{SOAP_ENCODING}\">\
+             <s:Body>{body}</s:Body></s:Envelope>"
+        );
+
+        let url = service.control_url(&self.shared.url);
+        let call = ActionCall {
+            service: &service.service_type,
+            action,
+            url: &url,
+        };
+
+        let result = instrument::observe(self, &call, async {
+            let (_sent_body, response_body) = self.perform_action(&call, &body).await?;
+            Ok(response_body)
+        })
+        .await;
+        if let Err(err) = &result {
+            self.options.interceptors.on_error(&call, err);
+        }
+        result.map_err(|err| err.with_device(self))
+    }
+
+    /// Posts the action, retrying according to the retry policy.
+    /// Returns the body that was sent, as rewritten by the interceptors,
+    /// and the body of the response.
+    async fn perform_action(&self, call: &ActionCall<'_>, body: &str) -> Result<(String, String)> {
+        let mut attempt = 0;
+        let (sent_body, mut response_body) = loop {
+            let soap_request = self.intercept_request(call, body)?;
+            let sent_body = soap_request.body.clone();
+            match self.post_action(call, soap_request).await {
+                Ok(response_body) => break (sent_body, response_body),
+                Err(err)
+                    if attempt + 1 < self.options.retry_policy.max_attempts
+                        && self.options.retry_policy.is_retryable(&err) =>
+                {
+                    attempt += 1;
+                    let backoff = self.options.retry_policy.backoff(attempt);
+                    log::debug!("{} failed: {err:#}, retrying in {backoff:?}", call.action);
+                    self.shared.runtime.sleep(backoff).await;
+                }
+                Err(err) => return Err(err.with_upnp_fault(call.service, call.action, &sent_body)),
+            }
+        };
+        log::trace!(
+            "Got response: {}",
+            redact_xml(&response_body, SENSITIVE_ARGUMENTS)
+        );
 
-        let response = reqwest::Client::new()
-            .post(url)
-            .header("CONTENT-TYPE", "text/xml; charset=\"utf-8\"")
-            .header("SOAPAction", soap_action)
-            .body::<String>(body.into())
-            .send()
-            .await?;
+        self.options
+            .interceptors
+            .on_response(call, &mut response_body)?;
+        Ok((sent_body, response_body))
+    }
 
-        let response = Error::check_response(response).await?;
+    /// Gives the interceptors the chance to rewrite the request
+    /// before an attempt to send it
+    fn intercept_request(&self, call: &ActionCall<'_>, body: &str) -> Result<SoapRequest> {
+        let mut soap_request = SoapRequest {
+            headers: http::HeaderMap::new(),
+            body: body.to_string(),
+        };
+        self.options
+            .interceptors
+            .on_request(call, &mut soap_request)?;
+        Ok(soap_request)
+    }
 
-        let body = response.text().await?;
-        log::trace!("Got response: {body}");
+    async fn post_action(
+        &self,
+        call: &ActionCall<'_>,
+        soap_request: SoapRequest,
+    ) -> Result<String> {
+        log::trace!(
+            "Sending: {}",
+            redact_xml(&soap_request.body, SENSITIVE_ARGUMENTS)
+        );
+        let mut request = HttpRequest::post(call.url.clone())
+            .header("content-type", "text/xml; charset=\"utf-8\"")?
+            .header(
+                "soapaction",
+                &format!("\"{}#{}\"", call.service, call.action),
+            )?
+            .body(soap_request.body);
+        request.headers.extend(soap_request.headers);
+        request.timeout = self.options.timeout;
 
-        RESP::decode_soap_xml(&body)
+        let _permit = self
+            .shared
+            .action_permits
+            .acquire()
+            .await
+            .expect("action_permits is never closed");
+        Ok(self.shared.client.send(request).await?.check()?.body)
     }
 }
 
@@ -481,23 +1289,146 @@ mod test {
     use super::*;
 
     #[test]
+    #[cfg(feature = "svc-av-transport")]
     fn test_xml() {
         use crate::av_transport::StopRequest;
-        let stop = StopRequest { instance_id: 32 };
+        let stop = StopRequest {
+            instance_id: InstanceId(32),
+        };
         k9::snapshot!(
             instant_xml::to_string(&stop).unwrap(),
             r#"<Stop xmlns="urn:schemas-upnp-org:service:AVTransport:1"><InstanceID xmlns="">32</InstanceID></Stop>"#
         );
     }
 
+    /// Applications commonly glob-import both the crate and the prelude,
+    /// which must not make the names of the service modules ambiguous
+    #[cfg(feature = "svc-av-transport")]
+    mod prelude_glob {
+        use crate::prelude::*;
+        use crate::*;
+
+        #[test]
+        fn test_service_module() {
+            let stop = av_transport::StopRequest {
+                instance_id: InstanceId::DEFAULT,
+            };
+            assert_eq!(stop.instance_id, InstanceId(0));
+            fn assert_trait<T: services::av_transport::AVTransport>() {}
+            assert_trait::<SonosDevice>();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "svc-content-directory")]
+    fn test_request_builder() {
+        let request = content_directory::BrowseRequest::builder()
+            .object_id("Q:0")
+            .requested_count(10)
+            .build();
+        assert_eq!(
+            request,
+            content_directory::BrowseRequest {
+                object_id: "Q:0".to_string(),
+                browse_flag: BrowseFlag::BrowseMetadata,
+                filter: String::new(),
+                starting_index: 0,
+                requested_count: 10,
+                sort_criteria: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "svc-rendering-control"))]
+    fn test_serde() {
+        let request = rendering_control::SetVolumeRequest {
+            instance_id: InstanceId::DEFAULT,
+            channel: Channel::Unspecified("Surround".to_string()),
+            desired_volume: Volume::saturating(30),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(
+            json,
+            r#"{"instance_id":0,"channel":"Surround","desired_volume":30}"#
+        );
+        let parsed: rendering_control::SetVolumeRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, request);
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vcr", feature = "svc-device-properties"))]
+    async fn test_status_light() {
+        use crate::vcr::{replay_device, Exchange};
+        const CONTROL: &str = "/DeviceProperties/Control";
+
+        let (device, client) = replay_device(vec![
+            Exchange::upnp(CONTROL, "DeviceProperties", "SetLEDState", "").request(
+                device_properties::SetLedStateRequest {
+                    desired_led_state: LEDState::Off,
+                },
+            ),
+            Exchange::upnp(
+                CONTROL,
+                "DeviceProperties",
+                "GetLEDState",
+                "<CurrentLEDState>Off</CurrentLEDState>",
+            ),
+            Exchange::upnp(
+                CONTROL,
+                "DeviceProperties",
+                "GetLEDState",
+                "<CurrentLEDState>Dim</CurrentLEDState>",
+            ),
+        ])
+        .await;
+
+        device.set_status_light(false).await.unwrap();
+        assert!(!device.status_light().await.unwrap());
+        assert!(matches!(
+            device.status_light().await.map_err(Error::into_inner),
+            Err(Error::InvalidBool(value)) if value == "Dim"
+        ));
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vcr", feature = "svc-device-properties"))]
+    async fn test_buttons_locked() {
+        use crate::vcr::{replay_device, Exchange};
+        const CONTROL: &str = "/DeviceProperties/Control";
+
+        let (device, client) = replay_device(vec![
+            Exchange::upnp(CONTROL, "DeviceProperties", "SetButtonLockState", "").request(
+                device_properties::SetButtonLockStateRequest {
+                    desired_button_lock_state: ButtonLockState::On,
+                },
+            ),
+            Exchange::upnp(
+                CONTROL,
+                "DeviceProperties",
+                "GetButtonLockState",
+                "<CurrentButtonLockState>On</CurrentButtonLockState>",
+            ),
+        ])
+        .await;
+
+        device.set_buttons_locked(true).await.unwrap();
+        assert!(device.buttons_locked().await.unwrap());
+        assert_eq!(client.unused(), vec![]);
+    }
+
     #[test]
+    #[cfg(feature = "svc-av-transport")]
     fn test_soap_envelope() {
         use crate::av_transport::StopRequest;
 
         let action = soap::Envelope {
             encoding_style: crate::SOAP_ENCODING,
             body: soap::Body {
-                payload: StopRequest { instance_id: 0 },
+                payload: StopRequest {
+                    instance_id: InstanceId::DEFAULT,
+                },
             },
         };
 
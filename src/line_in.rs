@@ -0,0 +1,343 @@
+use crate::eq::check_range;
+use crate::{audio_in, device_properties, AudioIn, DeviceProperties, Result, SonosDevice, Volume};
+use std::ops::RangeInclusive;
+
+/// The source name used by the autoplay actions for the line-in
+const LINE_IN_SOURCE: &str = "LineIn";
+
+/// The range of the line-in level
+pub const LINE_IN_LEVEL_RANGE: RangeInclusive<i16> = 0..=10;
+
+/// Configures how a player responds to its line-in, for example,
+/// when a turntable is connected to it
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineInConfig {
+    /// The UUID (eg: `RINCON_XXX`) of the room that should start
+    /// playing the line-in when a signal is detected, or `None` to
+    /// disable autoplay
+    pub autoplay_room_uuid: Option<String>,
+    /// Whether the rooms that are grouped with the autoplay room
+    /// should also play the line-in
+    pub include_linked_zones: bool,
+    /// The volume to set when autoplay starts, or `None` to leave
+    /// the volume unchanged
+    pub autoplay_volume: Option<Volume>,
+    /// The input level of the line-in, in the range 0..=10
+    pub level: i16,
+}
+
+/// The name and icon that the Sonos app shows for the line-in
+/// source of a player
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineInAttributes {
+    /// eg: `Turntable`
+    pub name: String,
+    /// eg: `AudioComponent:Turntable`
+    pub icon: String,
+}
+
+/// A stream of changes to the line-in connection state of a player,
+/// as returned from `SonosDevice::subscribe_line_in_connection`
+#[cfg(feature = "events")]
+pub struct LineInConnectionStream {
+    events: crate::EventStream<audio_in::AudioInEvent>,
+}
+
+#[cfg(feature = "events")]
+impl LineInConnectionStream {
+    /// Waits for the next report of the connection state, returning
+    /// true if a source is connected to the line-in.
+    /// The first report reflects the state at the time of subscribing.
+    /// This method is cancel safe.
+    pub async fn recv(&mut self) -> Option<bool> {
+        loop {
+            let event = self.events.recv().await?;
+            if let Some(connected) = event.line_in_connected {
+                return Some(connected);
+            }
+        }
+    }
+
+    /// Like `recv`, but returns `Error::Cancelled` as soon as `token`
+    /// is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn recv_cancellable(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<Option<bool>> {
+        crate::cancel::until_cancelled(self.recv(), token.cancelled())
+            .await
+            .ok_or(crate::Error::Cancelled)
+    }
+
+    /// Explicitly cancel the subscription
+    pub async fn unsubscribe(self) {
+        self.events.unsubscribe().await
+    }
+}
+
+impl SonosDevice {
+    /// Returns the name and icon of the line-in source
+    pub async fn line_in_attributes(&self) -> Result<LineInAttributes> {
+        let response = <Self as AudioIn>::get_audio_input_attributes(self).await?;
+        Ok(LineInAttributes {
+            name: response.current_name.unwrap_or_default(),
+            icon: response.current_icon.unwrap_or_default(),
+        })
+    }
+
+    /// Sets the name and icon of the line-in source
+    pub async fn set_line_in_attributes(&self, attributes: &LineInAttributes) -> Result<()> {
+        <Self as AudioIn>::set_audio_input_attributes(
+            self,
+            audio_in::SetAudioInputAttributesRequest {
+                desired_name: attributes.name.to_string(),
+                desired_icon: attributes.icon.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Returns true if a source is connected to the line-in.
+    /// The AudioIn service has no action to query this, so this
+    /// briefly subscribes to its events to obtain the current state.
+    /// If the future is dropped before the state is reported, the
+    /// subscription is left to expire rather than being cancelled.
+    #[cfg(feature = "events")]
+    pub async fn line_in_connected(&self) -> Result<bool> {
+        let mut stream = self.subscribe_line_in_connection().await?;
+        let connected = stream.recv().await;
+        stream.unsubscribe().await;
+        connected.ok_or(crate::Error::MissingValue("LineInConnected"))
+    }
+
+    /// Subscribes to changes in the line-in connection state, so that,
+    /// for example, an automation can react when a turntable starts
+    #[cfg(feature = "events")]
+    pub async fn subscribe_line_in_connection(&self) -> Result<LineInConnectionStream> {
+        Ok(LineInConnectionStream {
+            events: self.subscribe_audio_in().await?,
+        })
+    }
+
+    /// Returns the line-in configuration of the player
+    pub async fn line_in_config(&self) -> Result<LineInConfig> {
+        let room_uuid = <Self as DeviceProperties>::get_autoplay_room_uuid(
+            self,
+            device_properties::GetAutoplayRoomUuidRequest {
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?
+        .room_uuid
+        .filter(|uuid| !uuid.is_empty());
+
+        let include_linked_zones = <Self as DeviceProperties>::get_autoplay_linked_zones(
+            self,
+            device_properties::GetAutoplayLinkedZonesRequest {
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?
+        .include_linked_zones
+        .unwrap_or(false);
+
+        let use_volume = <Self as DeviceProperties>::get_use_autoplay_volume(
+            self,
+            device_properties::GetUseAutoplayVolumeRequest {
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?
+        .use_volume
+        .unwrap_or(false);
+
+        let autoplay_volume = if use_volume {
+            <Self as DeviceProperties>::get_autoplay_volume(
+                self,
+                device_properties::GetAutoplayVolumeRequest {
+                    source: LINE_IN_SOURCE.to_string(),
+                },
+            )
+            .await?
+            .current_volume
+            .map(Volume::saturating)
+        } else {
+            None
+        };
+
+        let level = <Self as AudioIn>::get_line_in_level(self)
+            .await?
+            .current_left_line_in_level
+            .unwrap_or(0) as i16;
+
+        Ok(LineInConfig {
+            autoplay_room_uuid: room_uuid,
+            include_linked_zones,
+            autoplay_volume,
+            level,
+        })
+    }
+
+    /// Applies the line-in configuration to the player.
+    /// The configuration is validated before any of it is applied,
+    /// but it is applied one setting at a time, so if the future is
+    /// dropped part way through, only some settings will have changed.
+    pub async fn set_line_in_config(&self, config: &LineInConfig) -> Result<()> {
+        check_range("LineInLevel", config.level, LINE_IN_LEVEL_RANGE)?;
+
+        <Self as DeviceProperties>::set_autoplay_room_uuid(
+            self,
+            device_properties::SetAutoplayRoomUuidRequest {
+                room_uuid: config.autoplay_room_uuid.clone().unwrap_or_default(),
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?;
+
+        <Self as DeviceProperties>::set_autoplay_linked_zones(
+            self,
+            device_properties::SetAutoplayLinkedZonesRequest {
+                include_linked_zones: config.include_linked_zones,
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?;
+
+        <Self as DeviceProperties>::set_use_autoplay_volume(
+            self,
+            device_properties::SetUseAutoplayVolumeRequest {
+                use_volume: config.autoplay_volume.is_some(),
+                source: LINE_IN_SOURCE.to_string(),
+            },
+        )
+        .await?;
+
+        if let Some(volume) = config.autoplay_volume {
+            <Self as DeviceProperties>::set_autoplay_volume(
+                self,
+                device_properties::SetAutoplayVolumeRequest {
+                    volume: volume.get(),
+                    source: LINE_IN_SOURCE.to_string(),
+                },
+            )
+            .await?;
+        }
+
+        <Self as AudioIn>::set_line_in_level(
+            self,
+            audio_in::SetLineInLevelRequest {
+                desired_left_line_in_level: config.level.into(),
+                desired_right_line_in_level: config.level.into(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "vcr"))]
+mod test {
+    use super::*;
+    use crate::vcr::{replay_device, Exchange};
+    use crate::Error;
+
+    const DEVICE_PROPERTIES: &str = "/DeviceProperties/Control";
+    const AUDIO_IN: &str = "/AudioIn/Control";
+
+    fn device_properties(action: &str, args: &str) -> Exchange {
+        Exchange::upnp(DEVICE_PROPERTIES, "DeviceProperties", action, args)
+    }
+
+    #[tokio::test]
+    async fn test_line_in_config() {
+        let (device, client) = replay_device(vec![
+            device_properties(
+                "GetAutoplayRoomUUID",
+                "<RoomUUID>RINCON_000E58000001</RoomUUID>",
+            ),
+            device_properties(
+                "GetAutoplayLinkedZones",
+                "<IncludeLinkedZones>1</IncludeLinkedZones>",
+            ),
+            device_properties("GetUseAutoplayVolume", "<UseVolume>1</UseVolume>"),
+            device_properties("GetAutoplayVolume", "<CurrentVolume>30</CurrentVolume>"),
+            Exchange::upnp(
+                AUDIO_IN,
+                "AudioIn",
+                "GetLineInLevel",
+                "<CurrentLeftLineInLevel>4</CurrentLeftLineInLevel><CurrentRightLineInLevel>4</CurrentRightLineInLevel>",
+            ),
+        ])
+        .await;
+
+        assert_eq!(
+            device.line_in_config().await.unwrap(),
+            LineInConfig {
+                autoplay_room_uuid: Some("RINCON_000E58000001".to_string()),
+                include_linked_zones: true,
+                autoplay_volume: Some(Volume::saturating(30)),
+                level: 4,
+            }
+        );
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_set_line_in_config() {
+        let source = || LINE_IN_SOURCE.to_string();
+        let (device, client) = replay_device(vec![
+            device_properties("SetAutoplayRoomUUID", "").request(
+                device_properties::SetAutoplayRoomUuidRequest {
+                    room_uuid: String::new(),
+                    source: source(),
+                },
+            ),
+            device_properties("SetAutoplayLinkedZones", "").request(
+                device_properties::SetAutoplayLinkedZonesRequest {
+                    include_linked_zones: false,
+                    source: source(),
+                },
+            ),
+            device_properties("SetUseAutoplayVolume", "").request(
+                device_properties::SetUseAutoplayVolumeRequest {
+                    use_volume: false,
+                    source: source(),
+                },
+            ),
+            Exchange::upnp(AUDIO_IN, "AudioIn", "SetLineInLevel", "").request(
+                audio_in::SetLineInLevelRequest {
+                    desired_left_line_in_level: 7,
+                    desired_right_line_in_level: 7,
+                },
+            ),
+        ])
+        .await;
+
+        // Autoplay disabled, so the autoplay volume is not sent
+        device
+            .set_line_in_config(&LineInConfig {
+                level: 7,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(client.unused(), vec![]);
+
+        // An invalid configuration is rejected before anything is applied
+        assert!(matches!(
+            device
+                .set_line_in_config(&LineInConfig {
+                    level: 11,
+                    ..Default::default()
+                })
+                .await,
+            Err(Error::OutOfRange {
+                name: "LineInLevel",
+                value: 11,
+                ..
+            })
+        ));
+    }
+}
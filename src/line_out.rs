@@ -0,0 +1,117 @@
+use crate::{rendering_control, Error, InstanceId, RenderingControl, Result, SonosDevice};
+
+impl SonosDevice {
+    /// Returns true if the device has a line-out whose volume can be
+    /// fixed, such as a Port or Connect
+    pub async fn supports_fixed_volume(&self) -> Result<bool> {
+        Ok(<Self as RenderingControl>::get_supports_output_fixed(
+            self,
+            rendering_control::GetSupportsOutputFixedRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?
+        .current_supports_fixed
+        .unwrap_or(false))
+    }
+
+    /// Sets whether the line-out is fixed at full volume, for use with
+    /// an external amplifier that controls the volume.
+    /// Returns `Error::NotSupported` for devices without a line-out.
+    pub async fn set_fixed_volume(&self, fixed: bool) -> Result<()> {
+        self.ensure_fixed_volume_supported().await?;
+        <Self as RenderingControl>::set_output_fixed(
+            self,
+            rendering_control::SetOutputFixedRequest {
+                instance_id: InstanceId::DEFAULT,
+                desired_fixed: fixed,
+            },
+        )
+        .await
+    }
+
+    /// Returns true if the line-out is fixed at full volume.
+    /// Returns `Error::NotSupported` for devices without a line-out.
+    pub async fn is_fixed_volume(&self) -> Result<bool> {
+        self.ensure_fixed_volume_supported().await?;
+        <Self as RenderingControl>::get_output_fixed(
+            self,
+            rendering_control::GetOutputFixedRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?
+        .current_fixed
+        .ok_or(Error::MissingValue("CurrentFixed"))
+    }
+
+    async fn ensure_fixed_volume_supported(&self) -> Result<()> {
+        if self.supports_fixed_volume().await? {
+            Ok(())
+        } else {
+            Err(Error::NotSupported("Fixed line-out volume"))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "vcr"))]
+mod test {
+    use super::*;
+    use crate::vcr::{replay_device, Exchange};
+
+    const CONTROL: &str = "/MediaRenderer/RenderingControl/Control";
+
+    fn supports_fixed(supported: bool) -> Exchange {
+        Exchange::upnp(
+            CONTROL,
+            "RenderingControl",
+            "GetSupportsOutputFixed",
+            &format!(
+                "<CurrentSupportsFixed>{}</CurrentSupportsFixed>",
+                supported as u8
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fixed_volume() {
+        let (device, client) = replay_device(vec![
+            supports_fixed(true),
+            Exchange::upnp(CONTROL, "RenderingControl", "SetOutputFixed", "").request(
+                rendering_control::SetOutputFixedRequest {
+                    instance_id: InstanceId::DEFAULT,
+                    desired_fixed: true,
+                },
+            ),
+            supports_fixed(true),
+            Exchange::upnp(
+                CONTROL,
+                "RenderingControl",
+                "GetOutputFixed",
+                "<CurrentFixed>1</CurrentFixed>",
+            ),
+        ])
+        .await;
+
+        device.set_fixed_volume(true).await.unwrap();
+        assert!(device.is_fixed_volume().await.unwrap());
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_fixed_volume_unsupported() {
+        let (device, client) =
+            replay_device(vec![supports_fixed(false), supports_fixed(false)]).await;
+
+        assert!(matches!(
+            device.set_fixed_volume(true).await,
+            Err(Error::NotSupported(_))
+        ));
+        assert!(matches!(
+            device.is_fixed_volume().await,
+            Err(Error::NotSupported(_))
+        ));
+        // Nothing is sent to a player without a line-out
+        assert_eq!(client.unused(), vec![]);
+    }
+}
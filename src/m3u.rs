@@ -0,0 +1,292 @@
+use crate::{
+    av_transport, AVTransport, DecodeXmlString, Error, InstanceId, Result, SonosDevice,
+    TrackMetaData,
+};
+use std::time::Duration;
+
+const EXTM3U: &str = "#EXTM3U";
+const EXTINF: &str = "#EXTINF:";
+
+/// The number of items requested per page when walking a
+/// queue or playlist for export
+const PAGE_SIZE: u32 = 100;
+
+/// Renders the supplied tracks as an extended M3U playlist.
+/// The track URLs are emitted verbatim, so tracks from the
+/// music library will have their `x-file-cifs:` style library
+/// URIs in the output.
+pub fn to_m3u(tracks: &[TrackMetaData]) -> String {
+    let mut result = format!("{EXTM3U}\n");
+
+    for track in tracks {
+        let seconds = track.duration.map(|d| d.as_secs() as i64).unwrap_or(-1);
+        let label = match &track.creator {
+            Some(creator) => format!("{creator} - {}", track.title),
+            None => track.title.to_string(),
+        };
+        result.push_str(&format!("{EXTINF}{seconds},{label}\n{}\n", track.url));
+    }
+
+    result
+}
+
+/// Parses an M3U or M3U8 playlist.
+/// Extended `#EXTINF` information is used to populate the duration,
+/// creator and title of the returned tracks, where present.
+pub fn parse_m3u(m3u: &str) -> Vec<TrackMetaData> {
+    let mut result = vec![];
+    let mut pending: Option<TrackMetaData> = None;
+
+    for line in m3u.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(info) = line.strip_prefix(EXTINF) {
+            let (seconds, label) = info.split_once(',').unwrap_or((info, ""));
+            let (creator, title) = match label.split_once(" - ") {
+                Some((creator, title)) => (Some(creator.trim().to_string()), title.trim()),
+                None => (None, label.trim()),
+            };
+            pending.replace(TrackMetaData {
+                title: title.to_string(),
+                creator,
+                duration: seconds.trim().parse::<u64>().ok().map(Duration::from_secs),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        if line.starts_with('#') {
+            // Some other directive or comment
+            continue;
+        }
+
+        let mut track = pending.take().unwrap_or_default();
+        track.url = line.to_string();
+        result.push(track);
+    }
+
+    result
+}
+
+/// The outcome of importing an M3U playlist
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct M3uImport {
+    /// The ObjectID (eg: `SQ:12`) of the Sonos playlist that was
+    /// created, if the import was to a new playlist
+    pub playlist_id: Option<String>,
+    /// The tracks that were added
+    pub added: Vec<TrackMetaData>,
+    /// The playlist entries that could not be matched to a
+    /// playable item
+    pub unmatched: Vec<TrackMetaData>,
+}
+
+impl SonosDevice {
+    /// Returns the contents of the queue as an extended M3U playlist
+    pub async fn export_queue_m3u(&self) -> Result<String> {
+        let mut tracks = vec![];
+        loop {
+            let page = self.queue_browse(tracks.len() as u32, PAGE_SIZE).await?;
+            let done = page.len() < PAGE_SIZE as usize;
+            tracks.extend(page);
+            if done {
+                break;
+            }
+        }
+        Ok(to_m3u(&tracks))
+    }
+
+    /// Returns the contents of the Sonos playlist identified by
+    /// `playlist_id` (eg: `SQ:12`) as an extended M3U playlist
+    pub async fn export_playlist_m3u(&self, playlist_id: &str) -> Result<String> {
+        let mut tracks = vec![];
+        loop {
+            let page = self
+                .content_browse(playlist_id, tracks.len() as u32, PAGE_SIZE)
+                .await?;
+            let done = page.len() < PAGE_SIZE as usize;
+            tracks.extend(page);
+            if done {
+                break;
+            }
+        }
+        Ok(to_m3u(&tracks))
+    }
+
+    /// Resolves an entry from an M3U playlist into something that can
+    /// be enqueued.
+    /// Entries whose location is a URI are used as-is. Otherwise, the
+    /// music library is searched for a track with a matching title,
+    /// preferring one with a matching creator.
+    pub async fn resolve_m3u_entry(&self, entry: &TrackMetaData) -> Result<Option<TrackMetaData>> {
+        // A single letter scheme is really a windows drive letter
+        if matches!(url::Url::parse(&entry.url), Ok(url) if url.scheme().len() > 1) {
+            return Ok(Some(entry.clone()));
+        }
+
+        let title = if entry.title.is_empty() {
+            // Fall back to the file name, sans extension
+            let name = entry.url.rsplit(['/', '\\']).next().unwrap_or(&entry.url);
+            name.rsplit_once('.')
+                .map(|(stem, _ext)| stem)
+                .unwrap_or(name)
+        } else {
+            entry.title.as_str()
+        };
+        if title.is_empty() {
+            return Ok(None);
+        }
+
+        let query: String = url::form_urlencoded::byte_serialize(title.as_bytes()).collect();
+        let candidates = self
+            .content_browse(&format!("A:TRACKS:{query}"), 0, PAGE_SIZE)
+            .await?;
+
+        let same = |a: &str, b: &str| a.trim().eq_ignore_ascii_case(b.trim());
+        let mut title_match = None;
+        for candidate in candidates {
+            if !same(&candidate.title, title) {
+                continue;
+            }
+            match (&entry.creator, &candidate.creator) {
+                (Some(wanted), Some(actual)) if same(wanted, actual) => {
+                    return Ok(Some(candidate));
+                }
+                _ => {
+                    if title_match.is_none() {
+                        title_match.replace(candidate);
+                    }
+                }
+            }
+        }
+
+        Ok(title_match)
+    }
+
+    /// Appends the entries of an M3U playlist to the queue.
+    /// If the future is dropped part way through, the entries
+    /// preceding that point will have been appended.
+    pub async fn import_m3u_to_queue(&self, m3u: &str) -> Result<M3uImport> {
+        let mut result = M3uImport::default();
+
+        for entry in parse_m3u(m3u) {
+            match self.resolve_m3u_entry(&entry).await? {
+                Some(track) => {
+                    self.queue_append(&track.url, Some(track.clone())).await?;
+                    result.added.push(track);
+                }
+                None => result.unmatched.push(entry),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a new Sonos playlist named `title` from the entries
+    /// of an M3U playlist
+    pub async fn import_m3u_as_playlist(&self, title: &str, m3u: &str) -> Result<M3uImport> {
+        let created = <Self as AVTransport>::create_saved_queue(
+            self,
+            av_transport::CreateSavedQueueRequest {
+                instance_id: InstanceId::DEFAULT,
+                title: title.to_string(),
+                enqueued_uri: String::new(),
+                enqueued_uri_meta_data: DecodeXmlString(None),
+            },
+        )
+        .await?;
+
+        let playlist_id = created
+            .assigned_object_id
+            .ok_or_else(|| Error::NoAssignedObjectId(title.to_string()))?;
+        let mut update_id = created.new_update_id.unwrap_or(0);
+
+        let mut result = M3uImport {
+            playlist_id: Some(playlist_id.clone()),
+            ..Default::default()
+        };
+
+        for entry in parse_m3u(m3u) {
+            match self.resolve_m3u_entry(&entry).await? {
+                Some(track) => {
+                    let added = <Self as AVTransport>::add_uri_to_saved_queue(
+                        self,
+                        av_transport::AddUriToSavedQueueRequest {
+                            instance_id: InstanceId::DEFAULT,
+                            object_id: playlist_id.clone(),
+                            update_id,
+                            enqueued_uri: track.url.to_string(),
+                            enqueued_uri_meta_data: track.clone().into(),
+                            // Append to the end
+                            add_at_index: u32::MAX,
+                        },
+                    )
+                    .await?;
+                    if let Some(id) = added.new_update_id {
+                        update_id = id;
+                    }
+                    result.added.push(track);
+                }
+                None => result.unmatched.push(entry),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_m3u_round_trip() {
+        let tracks = vec![
+            TrackMetaData {
+                title: "Track Title".to_string(),
+                creator: Some("Some Guy".to_string()),
+                duration: Some(Duration::from_secs(151)),
+                url: "x-file-cifs://nas/music/track.flac".to_string(),
+                ..Default::default()
+            },
+            TrackMetaData {
+                title: "Stream".to_string(),
+                url: "http://example.com/stream.mp3".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let m3u = to_m3u(&tracks);
+        assert_eq!(
+            m3u,
+            "#EXTM3U\n\
+            #EXTINF:151,Some Guy - Track Title\n\
+            x-file-cifs://nas/music/track.flac\n\
+            #EXTINF:-1,Stream\n\
+            http://example.com/stream.mp3\n"
+        );
+        assert_eq!(parse_m3u(&m3u), tracks);
+    }
+
+    #[test]
+    fn test_parse_plain_m3u() {
+        let parsed = parse_m3u("\u{feff}# comment\r\nmusic/a.mp3\r\n\r\nmusic/b.mp3\r\n");
+        assert_eq!(
+            parsed,
+            vec![
+                TrackMetaData {
+                    url: "music/a.mp3".to_string(),
+                    ..Default::default()
+                },
+                TrackMetaData {
+                    url: "music/b.mp3".to_string(),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+}
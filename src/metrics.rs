@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Identifies the action that a metric refers to.
+/// The values have low cardinality, so they are suitable for use
+/// as labels in systems such as Prometheus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetricLabels<'a> {
+    /// The host portion of the device URL, usually its IP address
+    pub device: &'a str,
+    /// The short name of the service, eg: `AVTransport`
+    pub service: &'a str,
+    /// The name of the action, eg: `Play`
+    pub action: &'a str,
+}
+
+/// Receives the latency and outcome of each SOAP action, so that they
+/// can be forwarded to a metrics backend such as Prometheus or statsd.
+/// Implementations should return promptly, as they are called inline.
+pub trait MetricsSink: Send + Sync {
+    /// Called before the action is sent to the device
+    fn call_started(&self, _labels: &MetricLabels) {}
+
+    /// Called when the action completes successfully
+    fn call_finished(&self, _labels: &MetricLabels, _elapsed: Duration) {}
+
+    /// Called when the action fails. `result_code` is the UPnP error
+    /// code reported by the device, the HTTP status code, or `error`
+    /// for other kinds of failure.
+    fn call_failed(&self, _labels: &MetricLabels, _elapsed: Duration, _result_code: &str) {}
+}
+
+/// The metrics sink configured for a device, if any
+#[derive(Clone, Default)]
+pub(crate) struct Metrics(Option<Arc<dyn MetricsSink>>);
+
+impl Metrics {
+    pub fn new(sink: Arc<dyn MetricsSink>) -> Self {
+        Self(Some(sink))
+    }
+
+    pub fn sink(&self) -> Option<&dyn MetricsSink> {
+        self.0.as_deref()
+    }
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.0 {
+            Some(_) => write!(f, "Metrics(Some)"),
+            None => write!(f, "Metrics(None)"),
+        }
+    }
+}
+
+/// Extracts the short name from a service type, eg:
+/// `urn:schemas-upnp-org:service:AVTransport:1` becomes `AVTransport`
+pub(crate) fn short_service_name(service_type: &str) -> &str {
+    service_type.rsplit(':').nth(1).unwrap_or(service_type)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_short_service_name() {
+        assert_eq!(
+            short_service_name("urn:schemas-upnp-org:service:AVTransport:1"),
+            "AVTransport"
+        );
+        assert_eq!(short_service_name("AVTransport"), "AVTransport");
+    }
+}
@@ -0,0 +1,580 @@
+//! Exposes each group as an MPRIS player on the D-Bus session bus,
+//! so that desktop media keys and applets can control it.
+//! See <https://specifications.freedesktop.org/mpris-spec/latest/>
+use crate::av_transport::AVTransportEvent;
+use crate::group_rendering_control::{self, GroupRenderingControlEvent};
+use crate::zone_group_topology::ZoneGroupTopologyEvent;
+use crate::{
+    Error, GroupRenderingControl, GroupVolume, InstanceId, PlayerUuid, Result, SonosDevice,
+    SonosSystem, TrackMetaData, TransportState, ZoneGroup,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::task::JoinHandle;
+use zbus::fdo;
+use zbus::object_server::SignalContext;
+use zbus::zvariant::{ObjectPath, OwnedValue, Value};
+
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const BUS_NAME_PREFIX: &str = "org.mpris.MediaPlayer2.sonos";
+const NO_TRACK: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+
+enum Update {
+    Transport(PlayerUuid, AVTransportEvent),
+    GroupRendering(PlayerUuid, GroupRenderingControlEvent),
+    Topology(Box<ZoneGroupTopologyEvent>),
+}
+
+/// Publishes an MPRIS `MediaPlayer2` player for each group in the
+/// household, named `org.mpris.MediaPlayer2.sonos.<room>` after the
+/// room that coordinates the group. Players are added and removed
+/// as groups change. Playback, metadata and volume are driven by
+/// events from the group coordinators.
+///
+/// ```no_run
+/// # async fn example() -> sonos::Result<()> {
+/// use sonos::{MprisServer, SonosSystem};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let system = Arc::new(SonosSystem::discover(Duration::from_secs(5)).await?);
+/// MprisServer::new(system).run().await
+/// # }
+/// ```
+pub struct MprisServer {
+    system: Arc<SonosSystem>,
+}
+
+/// The D-Bus connection that owns the bus name of a group, and
+/// the tasks that forward the events of its coordinator
+struct GroupPlayer {
+    connection: zbus::Connection,
+    identity: String,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl Drop for GroupPlayer {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+impl MprisServer {
+    pub fn new(system: Arc<SonosSystem>) -> Self {
+        Self { system }
+    }
+
+    /// Publishes the players and keeps them up to date until an
+    /// error occurs subscribing to events or talking to the bus
+    pub async fn run(&self) -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let device = self.system.devices().first().ok_or(Error::NoDevices)?;
+        let _topology = device
+            .subscribe_zone_group_topology()
+            .await?
+            .forward(&tx, |event| Update::Topology(Box::new(event)));
+
+        let mut players: BTreeMap<PlayerUuid, GroupPlayer> = BTreeMap::new();
+        while let Some(update) = rx.recv().await {
+            match update {
+                Update::Topology(event) => {
+                    if let Some(state) = event.zone_group_state.and_then(|s| s.into_inner()) {
+                        self.sync_groups(&state.groups, &tx, &mut players).await?;
+                    }
+                }
+                Update::Transport(uuid, event) => {
+                    if let Some(player) = players.get(&uuid) {
+                        player.apply_transport(event).await?;
+                    }
+                }
+                Update::GroupRendering(uuid, event) => {
+                    if let Some(player) = players.get(&uuid) {
+                        player.apply_group_rendering(event).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `run`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn run_until_cancelled(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.run(), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+
+    /// Starts a player for each group that doesn't have one, and
+    /// stops the players of groups that no longer exist
+    async fn sync_groups(
+        &self,
+        groups: &[ZoneGroup],
+        tx: &UnboundedSender<Update>,
+        players: &mut BTreeMap<PlayerUuid, GroupPlayer>,
+    ) -> Result<()> {
+        let mut wanted = BTreeMap::new();
+        for group in groups {
+            let Some(coordinator) = group
+                .members
+                .iter()
+                .find(|member| member.uuid == group.coordinator)
+            else {
+                continue;
+            };
+            let Some(device) = self
+                .system
+                .devices()
+                .iter()
+                .find(|d| d.url().as_str() == coordinator.location)
+            else {
+                continue;
+            };
+            let identity = match group.members.len() {
+                1 => coordinator.zone_name.clone(),
+                n => format!("{} + {}", coordinator.zone_name, n - 1),
+            };
+            wanted.insert(
+                group.coordinator.clone(),
+                (device.clone(), coordinator.zone_name.clone(), identity),
+            );
+        }
+
+        players.retain(|uuid, player| {
+            wanted
+                .get(uuid)
+                .is_some_and(|(_, _, identity)| *identity == player.identity)
+        });
+        for (uuid, (device, room, identity)) in wanted {
+            if players.contains_key(&uuid) {
+                continue;
+            }
+            log::debug!("starting MPRIS player for {identity}");
+            let player = GroupPlayer::start(uuid.clone(), device, &room, identity, tx).await?;
+            players.insert(uuid, player);
+        }
+        Ok(())
+    }
+}
+
+impl GroupPlayer {
+    async fn start(
+        uuid: PlayerUuid,
+        device: SonosDevice,
+        room: &str,
+        identity: String,
+        tx: &UnboundedSender<Update>,
+    ) -> Result<Self> {
+        let transport = device.subscribe_av_transport().await?;
+        let rendering = device.subscribe_group_rendering_control().await?;
+
+        let connection = zbus::connection::Builder::session()?
+            .name(format!("{BUS_NAME_PREFIX}.{}", bus_name_element(room)))?
+            .serve_at(
+                OBJECT_PATH,
+                RootInterface {
+                    identity: identity.clone(),
+                },
+            )?
+            .serve_at(
+                OBJECT_PATH,
+                PlayerInterface {
+                    device,
+                    status: PlaybackStatus::Stopped,
+                    track: None,
+                    track_number: None,
+                    volume: None,
+                },
+            )?
+            .build()
+            .await?;
+
+        let transport_uuid = uuid.clone();
+        let tasks = vec![
+            transport.forward(tx, move |event| {
+                Update::Transport(transport_uuid.clone(), event)
+            }),
+            rendering.forward(tx, move |event| Update::GroupRendering(uuid.clone(), event)),
+        ];
+
+        Ok(Self {
+            connection,
+            identity,
+            tasks,
+        })
+    }
+
+    async fn apply_transport(&self, event: AVTransportEvent) -> Result<()> {
+        let Some(change) = event.last_change.and_then(|c| c.into_inner()) else {
+            return Ok(());
+        };
+        let iface_ref = self
+            .connection
+            .object_server()
+            .interface::<_, PlayerInterface>(OBJECT_PATH)
+            .await?;
+        let mut iface = iface_ref.get_mut().await;
+        let ctxt = iface_ref.signal_context();
+        for change in change.map.into_values() {
+            if let Some(state) = change.transport_state {
+                let status = PlaybackStatus::from(&state);
+                if status != iface.status {
+                    iface.status = status;
+                    iface.playback_status_changed(ctxt).await?;
+                }
+            }
+            if let Some(track) = change.current_track_meta_data {
+                iface.track = track.into_inner();
+                iface.track_number = change.current_track;
+                iface.metadata_changed(ctxt).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_group_rendering(&self, event: GroupRenderingControlEvent) -> Result<()> {
+        let Some(volume) = event.group_volume else {
+            return Ok(());
+        };
+        let iface_ref = self
+            .connection
+            .object_server()
+            .interface::<_, PlayerInterface>(OBJECT_PATH)
+            .await?;
+        let mut iface = iface_ref.get_mut().await;
+        iface.volume.replace(volume);
+        iface.volume_changed(iface_ref.signal_context()).await?;
+        Ok(())
+    }
+}
+
+/// Returns `room_name` in a form that is valid as an element of a
+/// bus name, which may only contain `[A-Za-z0-9_]` and may not start
+/// with a digit
+fn bus_name_element(room_name: &str) -> String {
+    let mut element: String = room_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if element.is_empty() || element.starts_with(|c: char| c.is_ascii_digit()) {
+        element.insert(0, '_');
+    }
+    element
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlaybackStatus {
+    Playing,
+    Paused,
+    Stopped,
+}
+
+impl From<&TransportState> for PlaybackStatus {
+    fn from(state: &TransportState) -> Self {
+        match state {
+            TransportState::Playing | TransportState::Transitioning => Self::Playing,
+            TransportState::PausedPlayback => Self::Paused,
+            _ => Self::Stopped,
+        }
+    }
+}
+
+/// Implements `org.mpris.MediaPlayer2`
+struct RootInterface {
+    identity: String,
+}
+
+#[zbus::interface(name = "org.mpris.MediaPlayer2")]
+impl RootInterface {
+    fn raise(&self) {}
+
+    fn quit(&self) {}
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> String {
+        self.identity.clone()
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        vec!["http".to_string(), "https".to_string()]
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Implements `org.mpris.MediaPlayer2.Player` for the coordinator
+/// of a group
+struct PlayerInterface {
+    device: SonosDevice,
+    status: PlaybackStatus,
+    track: Option<TrackMetaData>,
+    track_number: Option<u32>,
+    volume: Option<GroupVolume>,
+}
+
+impl PlayerInterface {
+    fn track_id(&self) -> ObjectPath<'static> {
+        match self.track_number {
+            Some(n) if self.track.is_some() => {
+                ObjectPath::try_from(format!("{OBJECT_PATH}/Track/{n}")).expect("valid path")
+            }
+            _ => ObjectPath::from_static_str_unchecked(NO_TRACK),
+        }
+    }
+
+    async fn seek_to(&self, position: Duration) -> fdo::Result<()> {
+        self.device.seek_to(position).await.map_err(fdo_error)
+    }
+}
+
+#[zbus::interface(name = "org.mpris.MediaPlayer2.Player")]
+impl PlayerInterface {
+    async fn next(&self) -> fdo::Result<()> {
+        self.device.next().await.map_err(fdo_error)
+    }
+
+    async fn previous(&self) -> fdo::Result<()> {
+        self.device.previous().await.map_err(fdo_error)
+    }
+
+    async fn pause(&self) -> fdo::Result<()> {
+        self.device.pause().await.map_err(fdo_error)
+    }
+
+    async fn play_pause(&self) -> fdo::Result<()> {
+        if self.status == PlaybackStatus::Playing {
+            self.device.pause().await
+        } else {
+            self.device.play().await
+        }
+        .map_err(fdo_error)
+    }
+
+    async fn stop(&self) -> fdo::Result<()> {
+        self.device.stop().await.map_err(fdo_error)
+    }
+
+    async fn play(&self) -> fdo::Result<()> {
+        self.device.play().await.map_err(fdo_error)
+    }
+
+    /// Seeks by `offset` microseconds, which may be negative.
+    /// Seeking past the end of the track skips to the next track.
+    async fn seek(
+        &self,
+        offset: i64,
+        #[zbus(signal_context)] ctxt: SignalContext<'_>,
+    ) -> fdo::Result<()> {
+        let current = self.device.playback_position().await.map_err(fdo_error)?;
+        let target = (micros(current) + offset).max(0);
+        let duration = self.track.as_ref().and_then(|t| t.duration);
+        if duration.is_some_and(|d| target > micros(d)) {
+            return self.next().await;
+        }
+        self.seek_to(Duration::from_micros(target as u64)).await?;
+        Self::seeked(&ctxt, target).await?;
+        Ok(())
+    }
+
+    /// Seeks to `position` microseconds, if `track_id` is
+    /// still the current track
+    async fn set_position(
+        &self,
+        track_id: ObjectPath<'_>,
+        position: i64,
+        #[zbus(signal_context)] ctxt: SignalContext<'_>,
+    ) -> fdo::Result<()> {
+        if track_id != self.track_id() || position < 0 {
+            return Ok(());
+        }
+        self.seek_to(Duration::from_micros(position as u64)).await?;
+        Self::seeked(&ctxt, position).await?;
+        Ok(())
+    }
+
+    async fn open_uri(&self, uri: &str) -> fdo::Result<()> {
+        self.device
+            .set_av_transport_uri(uri, None)
+            .await
+            .map_err(fdo_error)?;
+        self.device.play().await.map_err(fdo_error)
+    }
+
+    #[zbus(signal)]
+    async fn seeked(ctxt: &SignalContext<'_>, position: i64) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> String {
+        format!("{:?}", self.status)
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn minimum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn maximum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        let mut metadata = HashMap::new();
+        let mut insert = |key: &str, value: Value| {
+            if let Ok(value) = OwnedValue::try_from(value) {
+                metadata.insert(key.to_string(), value);
+            }
+        };
+        insert("mpris:trackid", self.track_id().into());
+        if let Some(track) = &self.track {
+            insert("xesam:title", track.title.clone().into());
+            if let Some(creator) = &track.creator {
+                insert("xesam:artist", vec![creator.clone()].into());
+            }
+            if let Some(album) = &track.album {
+                insert("xesam:album", album.clone().into());
+            }
+            if let Some(art_url) = &track.art_url {
+                insert("mpris:artUrl", art_url.clone().into());
+            }
+            if let Some(duration) = track.duration {
+                insert("mpris:length", micros(duration).into());
+            }
+            insert("xesam:url", track.url.clone().into());
+        }
+        metadata
+    }
+
+    /// The group volume, from 0.0 to 1.0
+    #[zbus(property)]
+    fn volume(&self) -> f64 {
+        self.volume
+            .map(|v| f64::from(v.get()) / f64::from(GroupVolume::MAX))
+            .unwrap_or(0.0)
+    }
+
+    #[zbus(property)]
+    async fn set_volume(&mut self, value: f64) -> fdo::Result<()> {
+        let desired = (value.clamp(0.0, 1.0) * f64::from(GroupVolume::MAX)).round() as u16;
+        let desired = GroupVolume::new(desired).map_err(fdo_error)?;
+        <SonosDevice as GroupRenderingControl>::set_group_volume(
+            &self.device,
+            group_rendering_control::SetGroupVolumeRequest {
+                instance_id: InstanceId::DEFAULT,
+                desired_volume: desired,
+            },
+        )
+        .await
+        .map_err(fdo_error)
+    }
+
+    /// The position in microseconds, which is read from the player
+    /// whenever it is requested, as MPRIS doesn't signal changes to it
+    #[zbus(property(emits_changed_signal = "false"))]
+    async fn position(&self) -> i64 {
+        self.device
+            .playback_position()
+            .await
+            .map(micros)
+            .unwrap_or(0)
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_seek(&self) -> bool {
+        self.track.as_ref().is_some_and(|t| t.duration.is_some())
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+fn micros(duration: Duration) -> i64 {
+    i64::try_from(duration.as_micros()).unwrap_or(i64::MAX)
+}
+
+fn fdo_error(err: Error) -> fdo::Error {
+    fdo::Error::Failed(format!("{err:#}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bus_name_element() {
+        assert_eq!(bus_name_element("Living Room"), "Living_Room");
+        assert_eq!(bus_name_element("2nd Bedroom"), "_2nd_Bedroom");
+        assert_eq!(bus_name_element("Büro"), "B_ro");
+        assert_eq!(bus_name_element(""), "_");
+    }
+
+    #[test]
+    fn test_playback_status() {
+        assert_eq!(
+            PlaybackStatus::from(&TransportState::Transitioning),
+            PlaybackStatus::Playing
+        );
+        assert_eq!(
+            PlaybackStatus::from(&TransportState::PausedPlayback),
+            PlaybackStatus::Paused
+        );
+        assert_eq!(
+            PlaybackStatus::from(&TransportState::Unspecified("NO_MEDIA_PRESENT".to_string())),
+            PlaybackStatus::Stopped
+        );
+    }
+}
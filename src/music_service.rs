@@ -0,0 +1,340 @@
+use crate::upnp::DecodeXml;
+use crate::{Error, MusicServices, Result, SonosDevice};
+use instant_xml::FromXml;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// The list of music services that are available to a household,
+/// as reported by `MusicServices::ListAvailableServices`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicServiceList {
+    pub services: Vec<MusicService>,
+}
+
+impl DecodeXml for MusicServiceList {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let parsed: Services = instant_xml::from_str(xml)?;
+        Ok(Self {
+            services: parsed.services.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+/// Describes a music service that can be used with Sonos
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicService {
+    pub id: u32,
+    pub name: String,
+    pub version: Option<String>,
+    /// The SMAPI endpoint for the service
+    pub uri: String,
+    /// The SMAPI endpoint for the service, via https
+    pub secure_uri: Option<String>,
+    pub container_type: Option<String>,
+    /// A bitmask of the capabilities of the service
+    pub capabilities: u32,
+    pub auth_type: AuthType,
+    /// How frequently, in seconds, the service should be polled
+    /// for updates
+    pub poll_interval: Option<u32>,
+    pub presentation: Option<MusicServicePresentation>,
+}
+
+impl MusicService {
+    /// The service type number, which is used to build the `desc`
+    /// element of DIDL-Lite metadata for items from this service.
+    pub fn service_type(&self) -> u32 {
+        self.id * 256 + 7
+    }
+
+    /// Returns the `desc` value that identifies this service as the
+    /// provider of an item in DIDL-Lite metadata, eg: `SA_RINCON65031_`
+    /// for TuneIn. Services that require an account also name the
+    /// token of the account.
+    pub fn desc(&self) -> String {
+        let service_type = self.service_type();
+        match self.auth_type {
+            AuthType::Anonymous => format!("SA_RINCON{service_type}_"),
+            _ => format!(
+                "SA_RINCON{service_type}_X_#{}",
+                self.account_variable(0, "Token")
+            ),
+        }
+    }
+
+    /// Returns the name of the SystemProperties string variable that
+    /// holds `suffix` (`Token` or `Key`) for account `serial_number`
+    /// of this service, eg: `Svc519-0-Token`
+    pub fn account_variable(&self, serial_number: u32, suffix: &str) -> String {
+        format!("Svc{}-{serial_number}-{suffix}", self.service_type())
+    }
+}
+
+/// Locations of the presentation resources for a music service
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicServicePresentation {
+    pub strings_uri: Option<String>,
+    pub strings_version: Option<String>,
+    pub presentation_map_uri: Option<String>,
+    pub presentation_map_version: Option<String>,
+}
+
+/// The authentication mechanism used by a music service
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AuthType {
+    #[default]
+    Anonymous,
+    UserId,
+    DeviceLink,
+    AppLink,
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+impl FromStr for AuthType {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<AuthType> {
+        match s {
+            "Anonymous" => Ok(AuthType::Anonymous),
+            "UserId" => Ok(AuthType::UserId),
+            "DeviceLink" => Ok(AuthType::DeviceLink),
+            "AppLink" => Ok(AuthType::AppLink),
+            s => Ok(AuthType::Unspecified(s.to_string())),
+        }
+    }
+}
+
+impl SonosDevice {
+    /// Returns the list of music services that are available
+    /// to the household
+    pub async fn list_music_services(&self) -> Result<Vec<MusicService>> {
+        let response = self.list_available_services().await?;
+        Ok(response
+            .available_service_descriptor_list
+            .and_then(|list| list.into_inner())
+            .map(|list| list.services)
+            .unwrap_or_else(Vec::new))
+    }
+
+    /// Returns the cache of music service descriptors that is used
+    /// by `music_service`, and shared by the handles of this device
+    pub fn services(&self) -> &Arc<ServiceRegistry> {
+        &self.shared.services
+    }
+
+    /// Returns the music service identified by `service_id`.
+    /// The list of services is loaded on first use and then cached;
+    /// use `services().refresh()` to reload it.
+    pub async fn music_service(&self, service_id: u32) -> Result<MusicService> {
+        let services = self.services();
+        if !services.is_loaded() {
+            services.refresh(self).await?;
+        }
+        services
+            .by_id(service_id)
+            .ok_or(Error::MusicServiceNotFound(service_id))
+    }
+}
+
+/// A cache of the music services that are available to a household.
+/// The list of services rarely changes, so it is loaded once and
+/// then only reloaded when explicitly refreshed.
+#[derive(Debug, Default)]
+pub struct ServiceRegistry {
+    services: Mutex<Option<Vec<MusicService>>>,
+}
+
+impl ServiceRegistry {
+    /// (Re)loads the list of services from `device`
+    pub async fn refresh(&self, device: &SonosDevice) -> Result<()> {
+        let services = device.list_music_services().await?;
+        self.services.lock().unwrap().replace(services);
+        Ok(())
+    }
+
+    /// Returns true if the list of services has been loaded
+    pub fn is_loaded(&self) -> bool {
+        self.services.lock().unwrap().is_some()
+    }
+
+    /// Returns the cached list of services
+    pub fn services(&self) -> Vec<MusicService> {
+        self.services.lock().unwrap().clone().unwrap_or_default()
+    }
+
+    pub fn by_id(&self, service_id: u32) -> Option<MusicService> {
+        self.services
+            .lock()
+            .unwrap()
+            .as_ref()?
+            .iter()
+            .find(|s| s.id == service_id)
+            .cloned()
+    }
+
+    /// Looks up a service by name, ignoring case
+    pub fn by_name(&self, name: &str) -> Option<MusicService> {
+        self.services
+            .lock()
+            .unwrap()
+            .as_ref()?
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+            .cloned()
+    }
+}
+
+#[derive(Debug, FromXml)]
+struct Services {
+    services: Vec<Service>,
+}
+
+#[derive(Debug, FromXml)]
+struct Service {
+    #[xml(rename = "Id", attribute)]
+    id: u32,
+    #[xml(rename = "Name", attribute)]
+    name: String,
+    #[xml(rename = "Version", attribute)]
+    version: Option<String>,
+    #[xml(rename = "Uri", attribute)]
+    uri: String,
+    #[xml(rename = "SecureUri", attribute)]
+    secure_uri: Option<String>,
+    #[xml(rename = "ContainerType", attribute)]
+    container_type: Option<String>,
+    #[xml(rename = "Capabilities", attribute)]
+    capabilities: Option<u32>,
+
+    policy: Option<Policy>,
+    presentation: Option<Presentation>,
+}
+
+#[derive(Debug, FromXml)]
+struct Policy {
+    #[xml(rename = "Auth", attribute)]
+    auth: Option<String>,
+    #[xml(rename = "PollInterval", attribute)]
+    poll_interval: Option<u32>,
+}
+
+#[derive(Debug, FromXml)]
+struct Presentation {
+    strings: Option<Strings>,
+    presentation_map: Option<PresentationMap>,
+}
+
+#[derive(Debug, FromXml)]
+struct Strings {
+    #[xml(rename = "Uri", attribute)]
+    uri: Option<String>,
+    #[xml(rename = "Version", attribute)]
+    version: Option<String>,
+}
+
+#[derive(Debug, FromXml)]
+struct PresentationMap {
+    #[xml(rename = "Uri", attribute)]
+    uri: Option<String>,
+    #[xml(rename = "Version", attribute)]
+    version: Option<String>,
+}
+
+impl From<Service> for MusicService {
+    fn from(service: Service) -> MusicService {
+        let (auth, poll_interval) = match service.policy {
+            Some(policy) => (policy.auth, policy.poll_interval),
+            None => (None, None),
+        };
+        MusicService {
+            id: service.id,
+            name: service.name,
+            version: service.version,
+            uri: service.uri,
+            secure_uri: service.secure_uri,
+            container_type: service.container_type,
+            capabilities: service.capabilities.unwrap_or(0),
+            auth_type: auth.and_then(|auth| auth.parse().ok()).unwrap_or_default(),
+            poll_interval,
+            presentation: service.presentation.map(|p| {
+                let (strings_uri, strings_version) = match p.strings {
+                    Some(s) => (s.uri, s.version),
+                    None => (None, None),
+                };
+                let (presentation_map_uri, presentation_map_version) = match p.presentation_map {
+                    Some(m) => (m.uri, m.version),
+                    None => (None, None),
+                };
+                MusicServicePresentation {
+                    strings_uri,
+                    strings_version,
+                    presentation_map_uri,
+                    presentation_map_version,
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_services() {
+        let xml = r#"<Services SchemaVersion="1"><Service Capabilities="513" Id="0" MaxMessagingChars="0" Name="Napster" SecureUri="https://api.napster.com/v2.1/sonos" Uri="http://api.napster.com/v2.1/sonos" Version="1.1" ContainerType="MService"><Policy Auth="UserId" PollInterval="30"/><Presentation><Strings Version="5" Uri="http://example.com/strings.xml"/><PresentationMap Version="2" Uri="http://example.com/pm.xml"/></Presentation></Service><Service Capabilities="0" Id="254" Name="TuneIn" Uri="http://legato.radiotime.com/Radio.asmx" Version="1.1"><Policy Auth="Anonymous"/></Service></Services>"#;
+
+        let list = MusicServiceList::decode_xml(xml).unwrap();
+        assert_eq!(
+            list.services,
+            vec![
+                MusicService {
+                    id: 0,
+                    name: "Napster".to_string(),
+                    version: Some("1.1".to_string()),
+                    uri: "http://api.napster.com/v2.1/sonos".to_string(),
+                    secure_uri: Some("https://api.napster.com/v2.1/sonos".to_string()),
+                    container_type: Some("MService".to_string()),
+                    capabilities: 513,
+                    auth_type: AuthType::UserId,
+                    poll_interval: Some(30),
+                    presentation: Some(MusicServicePresentation {
+                        strings_uri: Some("http://example.com/strings.xml".to_string()),
+                        strings_version: Some("5".to_string()),
+                        presentation_map_uri: Some("http://example.com/pm.xml".to_string()),
+                        presentation_map_version: Some("2".to_string()),
+                    }),
+                },
+                MusicService {
+                    id: 254,
+                    name: "TuneIn".to_string(),
+                    version: Some("1.1".to_string()),
+                    uri: "http://legato.radiotime.com/Radio.asmx".to_string(),
+                    secure_uri: None,
+                    container_type: None,
+                    capabilities: 0,
+                    auth_type: AuthType::Anonymous,
+                    poll_interval: None,
+                    presentation: None,
+                },
+            ]
+        );
+        assert_eq!(list.services[1].service_type(), 65031);
+        assert_eq!(list.services[1].desc(), "SA_RINCON65031_");
+        assert_eq!(list.services[0].desc(), "SA_RINCON7_X_#Svc7-0-Token");
+
+        let registry = ServiceRegistry::default();
+        assert_eq!(registry.by_id(254), None);
+        registry.services.lock().unwrap().replace(list.services);
+        assert_eq!(
+            registry.by_id(254).map(|s| s.name),
+            Some("TuneIn".to_string())
+        );
+        assert_eq!(registry.by_name("napster").map(|s| s.id), Some(0));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{HttpRequest, Result, SonosDevice};
+
+/// How a player is connected to the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionMode {
+    /// Connected via ethernet
+    Wired,
+    /// Connected to the household wifi network
+    Wireless,
+    /// Connected to the SonosNet mesh network
+    SonosNet,
+    #[default]
+    Unknown,
+}
+
+/// Network diagnostics for a player
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkStatus {
+    pub connection: ConnectionMode,
+    /// The frequency, in MHz, of the wireless channel
+    pub channel_freq: Option<u32>,
+    pub behind_wifi_extender: bool,
+    /// The signal strength, in dBm, where available
+    pub signal_strength: Option<i32>,
+    /// The noise floor, in dBm, of the wireless radio,
+    /// where available
+    pub noise_floor: Option<i32>,
+}
+
+impl SonosDevice {
+    /// Returns network diagnostics for the player.
+    /// The connection mode is taken from the zone group topology,
+    /// while the signal strength and noise floor are scraped from
+    /// the diagnostic pages served by the player, which are not
+    /// available on all models and firmware versions.
+    pub async fn network_status(&self) -> Result<NetworkStatus> {
+        self.identify_errors(async {
+            let mut status = NetworkStatus::default();
+
+            if let Some(member) = self.zone_group_member().await? {
+                status.connection = if member.eth_link != 0 {
+                    ConnectionMode::Wired
+                } else if member.wireless_mode == 0 {
+                    ConnectionMode::SonosNet
+                } else {
+                    ConnectionMode::Wireless
+                };
+                status.channel_freq = Some(member.channel_freq).filter(|&f| f != 0);
+                status.behind_wifi_extender = member.behind_wifi_extender != 0;
+            }
+
+            if status.connection != ConnectionMode::Wired {
+                match self.diagnostic_page("/status/proc/ath_rincon/status").await {
+                    Ok(page) => {
+                        status.noise_floor = find_dbm(&page, "noise");
+                        status.signal_strength = find_dbm(&page, "rssi");
+                    }
+                    Err(err) => log::debug!("network_status: {err:#}"),
+                }
+            }
+
+            Ok(status)
+        })
+        .await
+    }
+
+    async fn diagnostic_page(&self, path: &str) -> Result<String> {
+        let url = self.shared.url.join(path)?;
+        Ok(self
+            .shared
+            .client
+            .send(HttpRequest::get(url))
+            .await?
+            .check()?
+            .body)
+    }
+}
+
+/// Returns the first signed integer on the first line that contains
+/// `label` (ignoring case)
+fn find_dbm(page: &str, label: &str) -> Option<i32> {
+    let line = page
+        .lines()
+        .find(|line| line.to_ascii_lowercase().contains(label))?;
+    let (_, value) = line.split_once([':', '='])?;
+    let value: String = value
+        .trim()
+        .chars()
+        .take_while(|c| *c == '-' || c.is_ascii_digit())
+        .collect();
+    value.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_dbm() {
+        let page = "Channel: 11\nNoise Floor: -95 dBm\nRSSI = -48\n";
+        assert_eq!(find_dbm(page, "noise"), Some(-95));
+        assert_eq!(find_dbm(page, "rssi"), Some(-48));
+        assert_eq!(find_dbm(page, "phy"), None);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    av_transport, duration_to_hms, AVTransport, InstanceId, Result, SeekMode, SonosDevice,
+};
+use std::time::Duration;
+
+impl SonosDevice {
+    /// Returns the position within the current track
+    pub async fn playback_position(&self) -> Result<Duration> {
+        let info = <Self as AVTransport>::get_position_info(
+            self,
+            av_transport::GetPositionInfoRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?;
+        Ok(info.rel_time.map(Duration::from).unwrap_or_default())
+    }
+
+    /// Seeks to `position` within the current track, for example,
+    /// to resume a podcast episode where the listener left off
+    pub async fn seek_to(&self, position: Duration) -> Result<()> {
+        <Self as AVTransport>::seek(
+            self,
+            av_transport::SeekRequest {
+                instance_id: InstanceId::DEFAULT,
+                unit: SeekMode::RelTime,
+                target: duration_to_hms(position),
+            },
+        )
+        .await
+    }
+
+    /// Records the current playback position as the resume position
+    /// of `item_id` with the music service identified by `service_id`,
+    /// returning the position that was saved.
+    pub async fn save_resume_position(&self, service_id: u32, item_id: &str) -> Result<Duration> {
+        let position = self.playback_position().await?;
+        let service = self.music_service(service_id).await?;
+        let client = self.smapi_client(&service).await?;
+        client.set_played_seconds(item_id, position).await?;
+        Ok(position)
+    }
+
+    /// Returns the resume position that the music service identified
+    /// by `service_id` has saved for `item_id`, or `None` if the item
+    /// has not been partially played
+    pub async fn resume_position(
+        &self,
+        service_id: u32,
+        item_id: &str,
+    ) -> Result<Option<Duration>> {
+        let service = self.music_service(service_id).await?;
+        let client = self.smapi_client(&service).await?;
+        client.resume_position(item_id).await
+    }
+}
@@ -0,0 +1,365 @@
+use crate::{Error, HttpClient, HttpRequest, MusicService, Result};
+use instant_xml::FromXml;
+use std::collections::BTreeMap;
+
+const SONOS_API_NS: &str = "http://sonos.com/sonosapi";
+
+/// The language used when a label is not available in the
+/// requested language
+const FALLBACK_LANGUAGE: &str = "en-US";
+
+/// The presentation resources of a music service, which describe
+/// how the service should be rendered in a user interface
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServicePresentation {
+    /// Localized labels, keyed by language (eg: `en-US`) and then
+    /// by string id
+    pub strings: BTreeMap<String, BTreeMap<String, String>>,
+    /// The categories that can be searched
+    pub search_categories: Vec<SearchCategory>,
+    /// The sizes at which the service can provide album art,
+    /// and the substitution that produces an image of that size
+    pub image_sizes: Vec<ImageSize>,
+    /// The logos of the service, for use in a service picker
+    pub logos: Vec<ServiceLogo>,
+}
+
+/// A searchable category, as used with `SmapiClient::search`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchCategory {
+    /// The well-known category id (eg: `artists`), or, for
+    /// custom categories, the string id of its label
+    pub id: String,
+    /// The id to pass to `SmapiClient::search`
+    pub mapped_id: String,
+    /// true if `id` is a string id rather than a well-known category
+    pub custom: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageSize {
+    pub size: u32,
+    pub substitution: String,
+}
+
+/// A logo of a music service, as listed in its presentation map
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceLogo {
+    /// The kind of logo, eg: `square` or `wide`
+    pub kind: String,
+    /// The absolute URL of the image
+    pub url: String,
+}
+
+impl ServicePresentation {
+    /// Returns the label for `string_id` in `language`, falling back
+    /// to the `en-US` label
+    pub fn label(&self, language: &str, string_id: &str) -> Option<&str> {
+        [language, FALLBACK_LANGUAGE]
+            .iter()
+            .find_map(|lang| self.strings.get(*lang)?.get(string_id))
+            .map(|s| s.as_str())
+    }
+
+    /// Returns the URL of the logo of the given `kind`, eg: `square`
+    pub fn logo(&self, kind: &str) -> Option<&str> {
+        self.logos
+            .iter()
+            .find(|logo| logo.kind == kind)
+            .map(|logo| logo.url.as_str())
+    }
+
+    /// Returns the languages for which labels are available
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.strings.keys().map(|s| s.as_str())
+    }
+}
+
+impl MusicService {
+    /// Fetches and parses the presentation map and strings files
+    /// of the service. Services that don't provide these resources
+    /// yield an empty `ServicePresentation`.
+    #[cfg(feature = "reqwest")]
+    pub async fn fetch_presentation(&self) -> Result<ServicePresentation> {
+        self.fetch_presentation_with(&reqwest::Client::new()).await
+    }
+
+    /// Like `fetch_presentation`, but fetches the resources via `client`
+    pub async fn fetch_presentation_with(
+        &self,
+        client: &dyn HttpClient,
+    ) -> Result<ServicePresentation> {
+        let mut result = ServicePresentation::default();
+        let Some(presentation) = &self.presentation else {
+            return Ok(result);
+        };
+
+        if let Some(uri) = &presentation.strings_uri {
+            result.strings = parse_strings(&fetch(client, uri).await?)?;
+        }
+        if let Some(uri) = &presentation.presentation_map_uri {
+            let map = parse_presentation_map(&fetch(client, uri).await?, &uri.parse()?)?;
+            result.search_categories = map.search_categories;
+            result.image_sizes = map.image_sizes;
+            result.logos = map.logos;
+        }
+
+        Ok(result)
+    }
+}
+
+async fn fetch(client: &dyn HttpClient, uri: &str) -> Result<String> {
+    let response = client.send(HttpRequest::get(uri.parse()?)).await?;
+    Ok(response.check()?.body)
+}
+
+fn parse_strings(xml: &str) -> Result<BTreeMap<String, BTreeMap<String, String>>> {
+    // The language is carried in an `xml:lang` attribute; strip the
+    // prefix so that it can be matched without namespace resolution
+    let xml = xml.replace(" xml:lang=", " lang=");
+    let tables: StringTables = instant_xml::from_str(&xml).map_err(|error| Error::XmlParse {
+        error,
+        text: xml.to_string(),
+    })?;
+
+    let mut result = BTreeMap::new();
+    for table in tables.tables {
+        let strings: &mut BTreeMap<String, String> = result.entry(table.lang).or_default();
+        for s in table.strings {
+            strings.insert(s.id, s.value);
+        }
+    }
+    Ok(result)
+}
+
+/// Parses the presentation map, returning a `ServicePresentation`
+/// without any strings. Relative logo URLs are resolved against
+/// `base`, the URL that the map was fetched from.
+fn parse_presentation_map(xml: &str, base: &url::Url) -> Result<ServicePresentation> {
+    let presentation: Presentation =
+        instant_xml::from_str(xml).map_err(|error| Error::XmlParse {
+            error,
+            text: xml.to_string(),
+        })?;
+
+    let mut result = ServicePresentation::default();
+
+    for map in presentation.maps {
+        for m in map.matches {
+            if let Some(categories) = m.search_categories {
+                result
+                    .search_categories
+                    .extend(categories.categories.into_iter().map(|c| SearchCategory {
+                        id: c.id,
+                        mapped_id: c.mapped_id,
+                        custom: false,
+                    }));
+                result
+                    .search_categories
+                    .extend(categories.custom.into_iter().map(|c| SearchCategory {
+                        id: c.string_id,
+                        mapped_id: c.mapped_id,
+                        custom: true,
+                    }));
+            }
+            if map.map_type == "ArtWorkSizeMap" {
+                if let Some(sizes) = m.image_size_map {
+                    result
+                        .image_sizes
+                        .extend(sizes.entries.into_iter().map(|e| ImageSize {
+                            size: e.size,
+                            substitution: e.substitution,
+                        }));
+                }
+            }
+            if map.map_type == "Logos" {
+                for logo in m.logos.into_iter().flat_map(|logos| logos.logos) {
+                    result.logos.push(ServiceLogo {
+                        kind: logo.kind,
+                        url: base.join(&logo.uri)?.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "stringtables", ns(SONOS_API_NS))]
+struct StringTables {
+    tables: Vec<StringTable>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "stringtable", ns(SONOS_API_NS))]
+struct StringTable {
+    #[xml(attribute)]
+    lang: String,
+    strings: Vec<StringEntry>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "string", ns(SONOS_API_NS))]
+struct StringEntry {
+    #[xml(rename = "stringId", attribute)]
+    id: String,
+    #[xml(direct)]
+    value: String,
+}
+
+#[derive(Debug, FromXml)]
+struct Presentation {
+    maps: Vec<PresentationMap>,
+}
+
+#[derive(Debug, FromXml)]
+struct PresentationMap {
+    #[xml(rename = "type", attribute)]
+    map_type: String,
+    matches: Vec<Match>,
+}
+
+#[derive(Debug, FromXml)]
+struct Match {
+    #[xml(rename = "imageSizeMap")]
+    image_size_map: Option<ImageSizeMap>,
+    search_categories: Option<SearchCategories>,
+    logos: Option<Logos>,
+}
+
+#[derive(Debug, FromXml)]
+struct Logos {
+    logos: Vec<Logo>,
+}
+
+#[derive(Debug, FromXml)]
+struct Logo {
+    #[xml(rename = "type", attribute)]
+    kind: String,
+    #[xml(attribute)]
+    uri: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "imageSizeMap")]
+struct ImageSizeMap {
+    entries: Vec<SizeEntry>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "sizeEntry")]
+struct SizeEntry {
+    #[xml(attribute)]
+    size: u32,
+    #[xml(attribute)]
+    substitution: String,
+}
+
+#[derive(Debug, FromXml)]
+struct SearchCategories {
+    categories: Vec<Category>,
+    custom: Vec<CustomCategory>,
+}
+
+#[derive(Debug, FromXml)]
+struct Category {
+    #[xml(attribute)]
+    id: String,
+    #[xml(rename = "mappedId", attribute)]
+    mapped_id: String,
+}
+
+#[derive(Debug, FromXml)]
+struct CustomCategory {
+    #[xml(rename = "stringId", attribute)]
+    string_id: String,
+    #[xml(rename = "mappedId", attribute)]
+    mapped_id: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_strings() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?><stringtables xmlns="http://sonos.com/sonosapi"><stringtable rev="1" xml:lang="en-US"><string stringId="Playlists">Playlists</string></stringtable><stringtable rev="1" xml:lang="de-DE"><string stringId="Playlists">Wiedergabelisten</string></stringtable></stringtables>"#;
+        let presentation = ServicePresentation {
+            strings: parse_strings(xml).unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            presentation.label("de-DE", "Playlists"),
+            Some("Wiedergabelisten")
+        );
+        assert_eq!(presentation.label("fr-FR", "Playlists"), Some("Playlists"));
+        assert_eq!(presentation.label("en-US", "Missing"), None);
+    }
+
+    #[test]
+    fn test_parse_presentation_map() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?><Presentation><PresentationMap type="ArtWorkSizeMap"><Match><imageSizeMap><sizeEntry size="60" substitution="60x60"/><sizeEntry size="300" substitution="300x300"/></imageSizeMap></Match></PresentationMap><PresentationMap type="Search"><Match><SearchCategories><Category id="artists" mappedId="search:artist"/><CustomCategory stringId="Playlists" mappedId="search:playlist"/></SearchCategories></Match></PresentationMap></Presentation>"#;
+        let base = "http://example.com/sonos/pm.xml".parse().unwrap();
+        let map = parse_presentation_map(xml, &base).unwrap();
+        assert_eq!(
+            map.search_categories,
+            vec![
+                SearchCategory {
+                    id: "artists".to_string(),
+                    mapped_id: "search:artist".to_string(),
+                    custom: false,
+                },
+                SearchCategory {
+                    id: "Playlists".to_string(),
+                    mapped_id: "search:playlist".to_string(),
+                    custom: true,
+                },
+            ]
+        );
+        assert_eq!(
+            map.image_sizes,
+            vec![
+                ImageSize {
+                    size: 60,
+                    substitution: "60x60".to_string()
+                },
+                ImageSize {
+                    size: 300,
+                    substitution: "300x300".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_logos() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?><Presentation><PresentationMap type="Logos"><Match><Logos><Logo type="square" uri="logos/square.png"/><Logo type="wide" uri="https://cdn.example.com/wide.svg"/></Logos></Match></PresentationMap></Presentation>"#;
+        let base = "http://example.com/sonos/pm.xml".parse().unwrap();
+        let presentation = parse_presentation_map(xml, &base).unwrap();
+        assert_eq!(
+            presentation.logos,
+            vec![
+                ServiceLogo {
+                    kind: "square".to_string(),
+                    url: "http://example.com/sonos/logos/square.png".to_string(),
+                },
+                ServiceLogo {
+                    kind: "wide".to_string(),
+                    url: "https://cdn.example.com/wide.svg".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            presentation.logo("wide"),
+            Some("https://cdn.example.com/wide.svg")
+        );
+        assert_eq!(presentation.logo("round"), None);
+        assert!(presentation.search_categories.is_empty());
+    }
+}
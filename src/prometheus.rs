@@ -0,0 +1,491 @@
+use crate::av_transport::AVTransportEvent;
+use crate::http_server::read_request;
+use crate::rendering_control::RenderingControlEvent;
+use crate::zone_group_topology::ZoneGroupTopologyEvent;
+use crate::{
+    Error, MetricLabels, MetricsSink, Result, SonosDevice, SonosSystem, TransportState, ZoneGroup,
+};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::unbounded_channel;
+
+/// How often battery and wifi gauges are refreshed by default;
+/// the players don't send events for them
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long a scrape may take to send its request and read the metrics
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The gauges of a player, keyed by the host of its URL
+#[derive(Debug, Clone, Default, PartialEq)]
+struct PlayerGauges {
+    room: String,
+    volume: Option<u16>,
+    muted: Option<bool>,
+    playing: Option<bool>,
+    group_size: Option<usize>,
+    battery_level: Option<u8>,
+    battery_charging: Option<bool>,
+    wifi_signal: Option<i32>,
+}
+
+/// The name and help text of a gauge, and how to read its value
+type Gauge = (&'static str, &'static str, fn(&PlayerGauges) -> Option<f64>);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct CallKey {
+    device: String,
+    service: String,
+    action: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CallStats {
+    /// The number of calls by result: `ok`, or the result code
+    /// passed to `MetricsSink::call_failed`
+    results: BTreeMap<String, u64>,
+    seconds: f64,
+    count: u64,
+}
+
+enum Update {
+    Transport(String, AVTransportEvent),
+    Rendering(String, RenderingControlEvent),
+    Topology(Box<ZoneGroupTopologyEvent>),
+}
+
+/// Exposes the state of the players of a household, and the outcome
+/// of the SOAP actions performed on them, in the Prometheus text
+/// format.
+///
+/// Register the exporter as the `MetricsSink` of the system to count
+/// SOAP actions, run `watch` to keep the player gauges up to date,
+/// and `serve` to answer scrapes:
+///
+/// ```no_run
+/// # async fn example() -> sonos::Result<()> {
+/// use sonos::{PrometheusExporter, SonosSystem};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let exporter = Arc::new(PrometheusExporter::new());
+/// let system = SonosSystem::discover(Duration::from_secs(5))
+///     .await?
+///     .with_metrics(exporter.clone());
+/// tokio::try_join!(
+///     exporter.watch(&system),
+///     exporter.serve("0.0.0.0:9798".parse().unwrap()),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PrometheusExporter {
+    players: Mutex<BTreeMap<String, PlayerGauges>>,
+    calls: Mutex<BTreeMap<CallKey, CallStats>>,
+    poll_interval: Duration,
+}
+
+impl Default for PrometheusExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrometheusExporter {
+    pub fn new() -> Self {
+        Self {
+            players: Mutex::new(BTreeMap::new()),
+            calls: Mutex::new(BTreeMap::new()),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Sets how often the battery and wifi gauges are refreshed
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Keeps the player gauges up to date until an error occurs
+    /// subscribing to the players. Volume, playback and grouping are
+    /// updated from events, while battery and wifi are polled.
+    pub async fn watch(&self, system: &SonosSystem) -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        for device in system.devices() {
+            let host = device_host(device);
+            let room = device.name().await?;
+            self.players.lock().unwrap().insert(
+                host.clone(),
+                PlayerGauges {
+                    room,
+                    ..PlayerGauges::default()
+                },
+            );
+
+            let key = host.clone();
+            device
+                .subscribe_av_transport()
+                .await?
+                .forward(&tx, move |event| Update::Transport(key.clone(), event));
+            device
+                .subscribe_rendering_control()
+                .await?
+                .forward(&tx, move |event| Update::Rendering(host.clone(), event));
+        }
+        if let Some(device) = system.devices().first() {
+            device
+                .subscribe_zone_group_topology()
+                .await?
+                .forward(&tx, |event| Update::Topology(Box::new(event)));
+        }
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    for device in system.devices() {
+                        self.poll(device).await;
+                    }
+                }
+                Some(update) = rx.recv() => self.apply(update),
+            }
+        }
+    }
+
+    /// Like `watch`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn watch_until_cancelled(
+        &self,
+        system: &SonosSystem,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.watch(system), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+
+    /// Answers HTTP requests for `/metrics` on `addr` with the output
+    /// of `render`, until accepting a connection fails.
+    /// Each connection is answered by its own task, with the metrics
+    /// as they were when the connection was accepted, so that a slow
+    /// or stalled client can't hold up other scrapes.
+    pub async fn serve(&self, addr: SocketAddr) -> Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let metrics = self.render();
+            tokio::spawn(async move {
+                match tokio::time::timeout(REQUEST_TIMEOUT, respond(stream, metrics)).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => log::debug!("metrics request from {peer} failed: {err:#}"),
+                    Err(_) => log::debug!("metrics request from {peer} timed out"),
+                }
+            });
+        }
+    }
+
+    async fn poll(&self, device: &SonosDevice) {
+        let battery = match device.battery_status().await {
+            Ok(status) => Some(status),
+            Err(err) if matches!(err.inner(), Error::NotSupported(_)) => None,
+            Err(err) => {
+                log::debug!("battery status: {err:#}");
+                None
+            }
+        };
+        let wifi_signal = match device.network_status().await {
+            Ok(status) => status.signal_strength,
+            Err(err) => {
+                log::debug!("network status: {err:#}");
+                None
+            }
+        };
+
+        let mut players = self.players.lock().unwrap();
+        if let Some(player) = players.get_mut(&device_host(device)) {
+            player.battery_level = battery.as_ref().map(|b| b.level);
+            player.battery_charging = battery.as_ref().map(|b| b.charging);
+            player.wifi_signal = wifi_signal;
+        }
+    }
+
+    fn apply(&self, update: Update) {
+        let mut players = self.players.lock().unwrap();
+        match update {
+            Update::Transport(host, event) => {
+                let (Some(player), Some(change)) = (
+                    players.get_mut(&host),
+                    event.last_change.and_then(|c| c.into_inner()),
+                ) else {
+                    return;
+                };
+                for change in change.map.into_values() {
+                    if let Some(state) = change.transport_state {
+                        player.playing.replace(state == TransportState::Playing);
+                    }
+                }
+            }
+            Update::Rendering(host, event) => {
+                let (Some(player), Some(change)) = (
+                    players.get_mut(&host),
+                    event.last_change.and_then(|c| c.into_inner()),
+                ) else {
+                    return;
+                };
+                for change in change.map.into_values() {
+                    if let Some(volume) = change.volume {
+                        player.volume.replace(volume.into());
+                    }
+                    if let Some(muted) = change.mute {
+                        player.muted.replace(muted);
+                    }
+                }
+            }
+            Update::Topology(event) => {
+                if let Some(state) = event.zone_group_state.and_then(|s| s.into_inner()) {
+                    apply_group_sizes(&mut players, &state.groups);
+                }
+            }
+        }
+    }
+
+    /// Returns the current values of the metrics in the Prometheus
+    /// text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let players = self.players.lock().unwrap();
+
+        let gauges: [Gauge; 7] = [
+            (
+                "sonos_player_volume",
+                "The volume of the master channel, from 0 to 100",
+                |p| p.volume.map(f64::from),
+            ),
+            (
+                "sonos_player_muted",
+                "1 if the master channel is muted",
+                |p| p.muted.map(bool_value),
+            ),
+            ("sonos_player_playing", "1 if the player is playing", |p| {
+                p.playing.map(bool_value)
+            }),
+            (
+                "sonos_player_group_size",
+                "The number of players in the group that the player belongs to",
+                |p| p.group_size.map(|n| n as f64),
+            ),
+            (
+                "sonos_player_battery_level_percent",
+                "The battery level of a portable player",
+                |p| p.battery_level.map(f64::from),
+            ),
+            (
+                "sonos_player_battery_charging",
+                "1 if a portable player is charging",
+                |p| p.battery_charging.map(bool_value),
+            ),
+            (
+                "sonos_player_wifi_signal_dbm",
+                "The wifi signal strength, in dBm",
+                |p| p.wifi_signal.map(f64::from),
+            ),
+        ];
+        for (name, help, value) in gauges {
+            writeln!(out, "# HELP {name} {help}").ok();
+            writeln!(out, "# TYPE {name} gauge").ok();
+            for (host, player) in players.iter() {
+                if let Some(value) = value(player) {
+                    writeln!(
+                        out,
+                        "{name}{{device=\"{}\",room=\"{}\"}} {value}",
+                        escape_label(host),
+                        escape_label(&player.room)
+                    )
+                    .ok();
+                }
+            }
+        }
+        drop(players);
+
+        let calls = self.calls.lock().unwrap();
+        writeln!(
+            out,
+            "# HELP sonos_soap_calls_total SOAP actions performed, by result"
+        )
+        .ok();
+        writeln!(out, "# TYPE sonos_soap_calls_total counter").ok();
+        for (key, stats) in calls.iter() {
+            for (result, count) in &stats.results {
+                writeln!(
+                    out,
+                    "sonos_soap_calls_total{{{},result=\"{}\"}} {count}",
+                    key.labels(),
+                    escape_label(result)
+                )
+                .ok();
+            }
+        }
+        writeln!(
+            out,
+            "# HELP sonos_soap_call_duration_seconds The time taken by SOAP actions"
+        )
+        .ok();
+        writeln!(out, "# TYPE sonos_soap_call_duration_seconds summary").ok();
+        for (key, stats) in calls.iter() {
+            let labels = key.labels();
+            writeln!(
+                out,
+                "sonos_soap_call_duration_seconds_sum{{{labels}}} {}",
+                stats.seconds
+            )
+            .ok();
+            writeln!(
+                out,
+                "sonos_soap_call_duration_seconds_count{{{labels}}} {}",
+                stats.count
+            )
+            .ok();
+        }
+
+        out
+    }
+
+    fn record(&self, labels: &MetricLabels, elapsed: Duration, result: &str) {
+        let key = CallKey {
+            device: labels.device.to_string(),
+            service: labels.service.to_string(),
+            action: labels.action.to_string(),
+        };
+        let mut calls = self.calls.lock().unwrap();
+        let stats = calls.entry(key).or_default();
+        *stats.results.entry(result.to_string()).or_default() += 1;
+        stats.seconds += elapsed.as_secs_f64();
+        stats.count += 1;
+    }
+}
+
+impl MetricsSink for PrometheusExporter {
+    fn call_finished(&self, labels: &MetricLabels, elapsed: Duration) {
+        self.record(labels, elapsed, "ok");
+    }
+
+    fn call_failed(&self, labels: &MetricLabels, elapsed: Duration, result_code: &str) {
+        self.record(labels, elapsed, result_code);
+    }
+}
+
+impl CallKey {
+    fn labels(&self) -> String {
+        format!(
+            "device=\"{}\",service=\"{}\",action=\"{}\"",
+            escape_label(&self.device),
+            escape_label(&self.service),
+            escape_label(&self.action)
+        )
+    }
+}
+
+/// Returns the host portion of the URL of `device`, which matches
+/// `MetricLabels::device`
+fn device_host(device: &SonosDevice) -> String {
+    device.url().host_str().unwrap_or_default().to_string()
+}
+
+fn apply_group_sizes(players: &mut BTreeMap<String, PlayerGauges>, groups: &[ZoneGroup]) {
+    for group in groups {
+        for member in &group.members {
+            let host = url::Url::parse(&member.location)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string));
+            if let Some(player) = host.and_then(|host| players.get_mut(&host)) {
+                player.group_size.replace(group.members.len());
+            }
+        }
+    }
+}
+
+fn bool_value(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Answers a request on `stream` with `metrics` if it is for `/metrics`
+async fn respond(mut stream: TcpStream, metrics: String) -> Result<()> {
+    let Some(request) = read_request(&mut stream).await? else {
+        return Ok(());
+    };
+
+    let (status, body) = if request.path.split('?').next() == Some("/metrics") {
+        ("200 OK", metrics)
+    } else {
+        ("404 Not Found", "Not Found\n".to_string())
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: text/plain; version=0.0.4\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n\
+         {body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let exporter = PrometheusExporter::new();
+        exporter.players.lock().unwrap().insert(
+            "192.168.1.10".to_string(),
+            PlayerGauges {
+                room: "Kid's \"Den\"".to_string(),
+                volume: Some(20),
+                playing: Some(true),
+                group_size: Some(2),
+                ..PlayerGauges::default()
+            },
+        );
+        let labels = MetricLabels {
+            device: "192.168.1.10",
+            service: "AVTransport",
+            action: "Play",
+        };
+        exporter.call_finished(&labels, Duration::from_millis(250));
+        exporter.call_failed(&labels, Duration::from_millis(250), "701");
+
+        let text = exporter.render();
+        let samples: Vec<&str> = text.lines().filter(|l| !l.starts_with('#')).collect();
+        assert_eq!(
+            samples,
+            vec![
+                r#"sonos_player_volume{device="192.168.1.10",room="Kid's \"Den\""} 20"#,
+                r#"sonos_player_playing{device="192.168.1.10",room="Kid's \"Den\""} 1"#,
+                r#"sonos_player_group_size{device="192.168.1.10",room="Kid's \"Den\""} 2"#,
+                r#"sonos_soap_calls_total{device="192.168.1.10",service="AVTransport",action="Play",result="701"} 1"#,
+                r#"sonos_soap_calls_total{device="192.168.1.10",service="AVTransport",action="Play",result="ok"} 1"#,
+                r#"sonos_soap_call_duration_seconds_sum{device="192.168.1.10",service="AVTransport",action="Play"} 0.5"#,
+                r#"sonos_soap_call_duration_seconds_count{device="192.168.1.10",service="AVTransport",action="Play"} 2"#,
+            ]
+        );
+        assert!(text.contains("# TYPE sonos_player_wifi_signal_dbm gauge\n"));
+    }
+}
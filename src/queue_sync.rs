@@ -0,0 +1,336 @@
+use crate::{
+    av_transport, queue, AVTransport, InstanceId, Queue, Result, SonosDevice, TrackMetaData,
+};
+
+/// The number of items requested per page when reading the queue
+const PAGE_SIZE: u32 = 100;
+
+/// A single change to the queue, as planned by `QueueSync`.
+/// Indices are 1-based, as used by the AVTransport service, and are
+/// relative to the state of the queue after all of the preceding
+/// operations have been applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QueueOp {
+    /// Remove `number_of_tracks` tracks starting at `starting_index`
+    Remove {
+        starting_index: u32,
+        number_of_tracks: u32,
+    },
+    /// Move the track at `from` so that it is positioned before
+    /// the track that is currently at `insert_before`
+    Move { from: u32, insert_before: u32 },
+    /// Insert `track` so that it becomes the track at `position`
+    Add {
+        track: Box<TrackMetaData>,
+        position: u32,
+    },
+}
+
+/// Brings the queue into line with a desired list of tracks by
+/// issuing a minimal set of remove, move and add operations, rather
+/// than clearing the queue and rebuilding it.
+/// Tracks are identified by their URL. Because tracks that are
+/// present in both lists are never removed, the currently playing
+/// track and its position are preserved whenever it is part of the
+/// desired list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueueSync {
+    desired: Vec<TrackMetaData>,
+}
+
+impl QueueSync {
+    pub fn new(desired: Vec<TrackMetaData>) -> Self {
+        Self { desired }
+    }
+
+    /// Computes the operations required to turn `current` into
+    /// the desired list of tracks
+    pub fn plan(&self, current: &[TrackMetaData]) -> Vec<QueueOp> {
+        let mut ops = vec![];
+
+        // Pair up each current track with an unused desired slot
+        let mut matched = vec![false; self.desired.len()];
+        let mut targets = vec![];
+        for track in current {
+            let slot = self
+                .desired
+                .iter()
+                .enumerate()
+                .position(|(idx, d)| !matched[idx] && d.url == track.url);
+            if let Some(slot) = slot {
+                matched[slot] = true;
+            }
+            targets.push(slot);
+        }
+
+        // Remove the unwanted tracks, working backwards so that the
+        // indices of the earlier runs are unaffected
+        let mut idx = targets.len();
+        while idx > 0 {
+            idx -= 1;
+            if targets[idx].is_some() {
+                continue;
+            }
+            let end = idx;
+            while idx > 0 && targets[idx - 1].is_none() {
+                idx -= 1;
+            }
+            ops.push(QueueOp::Remove {
+                starting_index: idx as u32 + 1,
+                number_of_tracks: (end - idx) as u32 + 1,
+            });
+        }
+
+        // The retained tracks, expressed as their desired slot
+        let mut working: Vec<usize> = targets.into_iter().flatten().collect();
+
+        // Tracks in the longest increasing run are already in the correct
+        // relative order; everything else needs to move
+        let keep = longest_increasing_subsequence(&working);
+        let mut placed = vec![false; self.desired.len()];
+        let mut movers = vec![];
+        for (&slot, &kept) in working.iter().zip(keep.iter()) {
+            if kept {
+                placed[slot] = true;
+            } else {
+                movers.push(slot);
+            }
+        }
+        movers.sort();
+
+        for slot in movers {
+            let from = working
+                .iter()
+                .position(|&s| s == slot)
+                .expect("mover to be in the queue");
+            // Insert ahead of the next placed track, or at the end
+            let insert_before = working
+                .iter()
+                .position(|&s| s > slot && placed[s])
+                .unwrap_or(working.len());
+
+            if from + 1 != insert_before {
+                ops.push(QueueOp::Move {
+                    from: from as u32 + 1,
+                    insert_before: insert_before as u32 + 1,
+                });
+                working.remove(from);
+                let dest = if from < insert_before {
+                    insert_before - 1
+                } else {
+                    insert_before
+                };
+                working.insert(dest, slot);
+            }
+            placed[slot] = true;
+        }
+
+        // Everything present is now in the correct order, so the
+        // missing tracks can be added directly into position
+        for (slot, track) in self.desired.iter().enumerate() {
+            if !matched[slot] {
+                ops.push(QueueOp::Add {
+                    track: Box::new(track.clone()),
+                    position: slot as u32 + 1,
+                });
+            }
+        }
+
+        ops
+    }
+
+    /// Synchronizes the queue of `device` with the desired list of
+    /// tracks, returning the operations that were applied.
+    /// The operations are applied one at a time, so if the future is
+    /// dropped part way through, the queue will be partially updated;
+    /// calling `apply` again completes the synchronization.
+    pub async fn apply(&self, device: &SonosDevice) -> Result<Vec<QueueOp>> {
+        let (current, mut update_id) = read_queue(device).await?;
+        let ops = self.plan(&current);
+
+        for op in &ops {
+            match op {
+                QueueOp::Remove {
+                    starting_index,
+                    number_of_tracks,
+                } => {
+                    let response = <SonosDevice as AVTransport>::remove_track_range_from_queue(
+                        device,
+                        av_transport::RemoveTrackRangeFromQueueRequest {
+                            instance_id: InstanceId::DEFAULT,
+                            update_id,
+                            starting_index: *starting_index,
+                            number_of_tracks: *number_of_tracks,
+                        },
+                    )
+                    .await?;
+                    if let Some(id) = response.new_update_id {
+                        update_id = id;
+                    }
+                }
+                QueueOp::Move {
+                    from,
+                    insert_before,
+                } => {
+                    <SonosDevice as AVTransport>::reorder_tracks_in_queue(
+                        device,
+                        av_transport::ReorderTracksInQueueRequest {
+                            instance_id: InstanceId::DEFAULT,
+                            starting_index: *from,
+                            number_of_tracks: 1,
+                            insert_before: *insert_before,
+                            update_id,
+                        },
+                    )
+                    .await?;
+                    // The response doesn't include the new UpdateID
+                    update_id = read_update_id(device).await?;
+                }
+                QueueOp::Add { track, position } => {
+                    <SonosDevice as AVTransport>::add_uri_to_queue(
+                        device,
+                        av_transport::AddUriToQueueRequest {
+                            instance_id: InstanceId::DEFAULT,
+                            enqueued_uri: track.url.to_string(),
+                            enqueued_uri_meta_data: (**track).clone().into(),
+                            desired_first_track_number_enqueued: *position,
+                            enqueue_as_next: false,
+                        },
+                    )
+                    .await?;
+                    update_id = read_update_id(device).await?;
+                }
+            }
+        }
+
+        Ok(ops)
+    }
+}
+
+/// Returns the full contents of the queue together with its UpdateID
+async fn read_queue(device: &SonosDevice) -> Result<(Vec<TrackMetaData>, u32)> {
+    let mut tracks = vec![];
+    let mut update_id = 0;
+
+    loop {
+        let response = <SonosDevice as Queue>::browse(
+            device,
+            queue::BrowseRequest {
+                queue_id: 0,
+                starting_index: tracks.len() as u32,
+                requested_count: PAGE_SIZE,
+            },
+        )
+        .await?;
+        if let Some(id) = response.update_id {
+            update_id = id;
+        }
+        let page = response
+            .result
+            .and_then(|list| list.into_inner())
+            .map(|list| list.tracks)
+            .unwrap_or_else(Vec::new);
+        let done = page.len() < PAGE_SIZE as usize;
+        tracks.extend(page);
+        if done {
+            return Ok((tracks, update_id));
+        }
+    }
+}
+
+async fn read_update_id(device: &SonosDevice) -> Result<u32> {
+    let response = <SonosDevice as Queue>::browse(
+        device,
+        queue::BrowseRequest {
+            queue_id: 0,
+            starting_index: 0,
+            requested_count: 1,
+        },
+    )
+    .await?;
+    Ok(response.update_id.unwrap_or(0))
+}
+
+/// Returns a mask identifying the members of a longest strictly
+/// increasing subsequence of `values`
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // tails[n] is the index of the smallest tail value of
+    // any increasing run of length n+1
+    let mut tails: Vec<usize> = vec![];
+    let mut prev = vec![None; values.len()];
+
+    for (idx, &value) in values.iter().enumerate() {
+        let pos = tails.partition_point(|&t| values[t] < value);
+        if pos > 0 {
+            prev[idx] = Some(tails[pos - 1]);
+        }
+        if pos == tails.len() {
+            tails.push(idx);
+        } else {
+            tails[pos] = idx;
+        }
+    }
+
+    let mut keep = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(idx) = next {
+        keep[idx] = true;
+        next = prev[idx];
+    }
+    keep
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tracks(urls: &[&str]) -> Vec<TrackMetaData> {
+        urls.iter()
+            .map(|url| TrackMetaData {
+                url: url.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_queue_sync_move() {
+        let sync = QueueSync::new(tracks(&["b", "c", "d", "a"]));
+        assert_eq!(
+            sync.plan(&tracks(&["a", "b", "c", "d"])),
+            vec![QueueOp::Move {
+                from: 1,
+                insert_before: 5
+            }]
+        );
+    }
+
+    #[test]
+    fn test_queue_sync_remove_and_add() {
+        let sync = QueueSync::new(tracks(&["a", "y", "b"]));
+        assert_eq!(
+            sync.plan(&tracks(&["a", "x", "x", "b", "z"])),
+            vec![
+                QueueOp::Remove {
+                    starting_index: 5,
+                    number_of_tracks: 1
+                },
+                QueueOp::Remove {
+                    starting_index: 2,
+                    number_of_tracks: 2
+                },
+                QueueOp::Add {
+                    track: Box::new(tracks(&["y"]).remove(0)),
+                    position: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_queue_sync_unchanged() {
+        let sync = QueueSync::new(tracks(&["a", "b", "a"]));
+        assert_eq!(sync.plan(&tracks(&["a", "b", "a"])), vec![]);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    MusicService, ObjectClass, Result, ServiceParams, SonosDevice, SonosSystem, SonosUri,
+    TrackMetaData, STREAM_FLAGS,
+};
+
+/// The music service ID of TuneIn
+pub const TUNEIN_SERVICE_ID: u32 = 254;
+
+/// The number of stations requested when searching
+const SEARCH_COUNT: u32 = 50;
+
+/// A radio station available via TuneIn
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadioStation {
+    /// The TuneIn station id, eg: `s24940`
+    pub id: String,
+    pub title: String,
+    pub art_url: Option<String>,
+    /// The id of the music service that provides the station
+    pub service_id: u32,
+    /// The `desc` of the music service that provides the station;
+    /// see `MusicService::desc`
+    pub desc: String,
+}
+
+impl RadioStation {
+    /// Describes the station `id` provided by `service`, which is
+    /// usually the TuneIn entry from the service registry
+    pub fn new(service: &MusicService, id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            art_url: None,
+            service_id: service.id,
+            desc: service.desc(),
+        }
+    }
+
+    /// Returns the `x-sonosapi-stream` URI that plays this station
+    pub fn uri(&self) -> String {
+        SonosUri::SonosApi {
+            kind: "stream".to_string(),
+            item_id: self.id.to_string(),
+            params: ServiceParams::new(self.service_id, STREAM_FLAGS, 0),
+        }
+        .to_string()
+    }
+
+    /// Returns the metadata to use alongside `uri()` when setting
+    /// the transport URI, or when enqueuing or saving the station
+    /// as a favorite
+    pub fn to_track_metadata(&self) -> TrackMetaData {
+        TrackMetaData {
+            title: self.title.to_string(),
+            url: self.uri(),
+            art_url: self.art_url.clone(),
+            class: ObjectClass::AudioBroadcast,
+            desc: Some(self.desc.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl SonosSystem {
+    /// Returns the TuneIn station identified by `station_id`, eg: `s24940`,
+    /// described using the cached TuneIn service descriptor
+    pub async fn radio_station(&self, station_id: &str) -> Result<RadioStation> {
+        let service = self.music_service(TUNEIN_SERVICE_ID).await?;
+        Ok(RadioStation::new(&service, station_id, station_id))
+    }
+}
+
+impl SonosDevice {
+    /// Searches TuneIn for radio stations matching `query`
+    pub async fn search_stations(&self, query: &str) -> Result<Vec<RadioStation>> {
+        let service = self.music_service(TUNEIN_SERVICE_ID).await?;
+        let client = self.smapi_client(&service).await?;
+
+        let results = client.search("stations", query, 0, SEARCH_COUNT).await?;
+        Ok(results
+            .items
+            .into_iter()
+            .filter(|item| !item.is_container && item.item_type == "stream")
+            .map(|item| RadioStation {
+                art_url: item.art_url,
+                ..RadioStation::new(&service, &item.id, &item.title)
+            })
+            .collect())
+    }
+
+    /// Plays the TuneIn station identified by `station_id`, eg: `s24940`
+    pub async fn play_station(&self, station_id: &str) -> Result<()> {
+        let service = self.music_service(TUNEIN_SERVICE_ID).await?;
+        let station = RadioStation::new(&service, station_id, station_id);
+        self.play_radio_station(&station).await
+    }
+
+    /// Plays `station`, as returned from `search_stations`
+    pub async fn play_radio_station(&self, station: &RadioStation) -> Result<()> {
+        self.set_av_transport_uri(&station.uri(), Some(station.to_track_metadata()))
+            .await?;
+        self.play().await
+    }
+
+    /// Searches TuneIn for `query` and plays the best match,
+    /// returning the station that was chosen.
+    /// A station whose title matches `query` exactly (ignoring case)
+    /// is preferred, otherwise the first result is played.
+    pub async fn play_station_named(&self, query: &str) -> Result<Option<RadioStation>> {
+        let stations = self.search_stations(query).await?;
+        let station = stations
+            .iter()
+            .find(|s| s.title.trim().eq_ignore_ascii_case(query.trim()))
+            .or_else(|| stations.first())
+            .cloned();
+
+        if let Some(station) = &station {
+            self.play_radio_station(station).await?;
+        }
+        Ok(station)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_station_uri() {
+        let tunein = MusicService {
+            id: TUNEIN_SERVICE_ID,
+            name: "TuneIn".to_string(),
+            version: None,
+            uri: "http://legato.radiotime.com/Radio.asmx".to_string(),
+            secure_uri: None,
+            container_type: None,
+            capabilities: 0,
+            auth_type: crate::AuthType::Anonymous,
+            poll_interval: None,
+            presentation: None,
+        };
+        let station = RadioStation::new(&tunein, "s24940", "BBC Radio 6 Music");
+        assert_eq!(
+            station.uri(),
+            "x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0"
+        );
+        assert_eq!(
+            station.to_track_metadata().to_didl_string(),
+            r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/"><item id="-1" parentID="-1" restricted="true"><res protocolInfo="http-get:*:audio/mpeg">x-sonosapi-stream:s24940?sid=254&amp;flags=8224&amp;sn=0</res><dc:title>BBC Radio 6 Music</dc:title><upnp:class>object.item.audioItem.audioBroadcast</upnp:class><desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON65031_</desc></item></DIDL-Lite>"#
+        );
+    }
+}
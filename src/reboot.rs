@@ -0,0 +1,243 @@
+use crate::{Error, HttpRequest, Result, SonosDevice};
+use std::time::{Duration, Instant};
+
+/// How frequently the device is polled while waiting
+/// for it to go down or come back online
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long to wait for each poll to respond
+const POLL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How long the device is given to go down after it has
+/// accepted the reboot request
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+impl SonosDevice {
+    /// Reboots the device, returning once it has stopped responding.
+    /// The device will be unavailable for a minute or so afterwards;
+    /// use `wait_until_back_online` to wait for it to return.
+    /// Returns `Error::Timeout` if the device is still responding
+    /// 30 seconds after accepting the request.
+    /// Dropping the future once the request has been sent doesn't
+    /// stop the device from rebooting.
+    pub async fn reboot(&self) -> Result<()> {
+        self.identify_errors(async {
+            let url = self.shared.url.join("/reboot")?;
+            let client = &self.shared.client;
+
+            let response = client.send(HttpRequest::get(url.clone())).await?.check()?;
+
+            // Newer firmware serves a confirmation form carrying a token
+            // that must be posted back; older firmware reboots upon the GET
+            if let Some(token) = find_form_token(&response.body, "csrfToken") {
+                let form = format!(
+                    "csrfToken={}",
+                    url::form_urlencoded::byte_serialize(token.as_bytes()).collect::<String>()
+                );
+                let request = HttpRequest::post(url)
+                    .header("content-type", "application/x-www-form-urlencoded")?
+                    .body(form);
+                match client.send(request).await {
+                    Ok(response) => {
+                        response.check()?;
+                    }
+                    // The device may drop the connection as it goes down
+                    Err(err) if is_connection_closed(&err) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+
+            // The device keeps answering for a few seconds after accepting
+            // the request; wait for it to go down, so that a subsequent
+            // `wait_until_back_online` doesn't return immediately
+            self.wait_until_online(false, SHUTDOWN_TIMEOUT).await
+        })
+        .await
+    }
+
+    /// Waits until the device responds to requests for its device
+    /// description, for example, after calling `reboot`.
+    /// Returns `Error::Timeout` if it is not back within `timeout`.
+    pub async fn wait_until_back_online(&self, timeout: Duration) -> Result<()> {
+        self.wait_until_online(true, timeout)
+            .await
+            .map_err(|err| err.with_device(self))
+    }
+
+    /// Polls the device description until the device is responding,
+    /// or has stopped responding, as indicated by `online`
+    async fn wait_until_online(&self, online: bool, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        let mut request = HttpRequest::get(self.shared.url.clone());
+        request.timeout = Some(POLL_TIMEOUT);
+
+        loop {
+            let responding = match self.shared.client.send(request.clone()).await {
+                Ok(response) => response.status.is_success(),
+                Err(_) => false,
+            };
+            if responding == online {
+                return Ok(());
+            }
+            if Instant::now() + POLL_INTERVAL > deadline {
+                return Err(Error::Timeout);
+            }
+            self.shared.runtime.sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Like `wait_until_back_online`, but returns `Error::Cancelled`
+    /// as soon as `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn wait_until_back_online_cancellable(
+        &self,
+        timeout: Duration,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.wait_until_back_online(timeout), token.cancelled())
+            .await
+            .unwrap_or(Err(Error::Cancelled))
+    }
+}
+
+/// Extracts the value of the hidden form input named `name`
+fn find_form_token(html: &str, name: &str) -> Option<String> {
+    let name_attr = format!("name=\"{name}\"");
+    let start = html.find(&name_attr)?;
+    let tag_start = html[..start].rfind('<')?;
+    let tag_end = start + html[start..].find('>')?;
+    let tag = &html[tag_start..tag_end];
+    let (_, value) = tag.split_once("value=\"")?;
+    let (value, _) = value.split_once('"')?;
+    Some(value.to_string())
+}
+
+/// Returns true if `err` reports that the device reset or closed the
+/// connection before responding, as it may do when it goes down for
+/// the reboot. Other failures, such as being unable to connect, are
+/// not expected.
+fn is_connection_closed(err: &Error) -> bool {
+    let err: &(dyn std::error::Error + 'static) = match err {
+        #[cfg(feature = "reqwest")]
+        Error::Reqwest(err) => err,
+        Error::Http(err) => err.as_ref(),
+        _ => return false,
+    };
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            );
+        }
+        #[cfg(any(feature = "reqwest", feature = "hyper"))]
+        if let Some(err) = err.downcast_ref::<hyper::Error>() {
+            if err.is_incomplete_message() {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_form_token() {
+        let html = r#"<form method="post"><input type="hidden" value="abc123" name="csrfToken"><input type="submit"></form>"#;
+        assert_eq!(
+            find_form_token(html, "csrfToken"),
+            Some("abc123".to_string())
+        );
+        assert_eq!(find_form_token("<html></html>", "csrfToken"), None);
+    }
+
+    #[test]
+    fn test_is_connection_closed() {
+        let io = |kind| Error::Http(Box::new(std::io::Error::from(kind)));
+        assert!(is_connection_closed(&io(
+            std::io::ErrorKind::ConnectionReset
+        )));
+        assert!(is_connection_closed(&io(std::io::ErrorKind::UnexpectedEof)));
+        assert!(!is_connection_closed(&io(
+            std::io::ErrorKind::ConnectionRefused
+        )));
+        assert!(!is_connection_closed(&io(std::io::ErrorKind::TimedOut)));
+        assert!(!is_connection_closed(&Error::Timeout));
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(all(feature = "vcr", feature = "tokio-runtime"))]
+    async fn test_reboot() {
+        use crate::vcr::{replay_device, Exchange};
+
+        let description = |status| Exchange {
+            method: "GET".to_string(),
+            path: "/xml/device_description.xml".to_string(),
+            status,
+            response_body: include_str!("../data/device_spec.xml").to_string(),
+            ..Default::default()
+        };
+        let (device, client) = replay_device(vec![
+            Exchange {
+                method: "GET".to_string(),
+                path: "/reboot".to_string(),
+                status: 200,
+                response_body: r#"<form method="post"><input type="hidden" value="abc123" name="csrfToken"></form>"#.to_string(),
+                ..Default::default()
+            },
+            Exchange {
+                method: "POST".to_string(),
+                path: "/reboot".to_string(),
+                request_body: "csrfToken=abc123".to_string(),
+                status: 200,
+                ..Default::default()
+            },
+            // Still up for a moment after accepting the request
+            description(200),
+            description(503),
+            // Still down when we start waiting for it to return
+            description(503),
+            description(200),
+        ])
+        .await;
+
+        device.reboot().await.unwrap();
+        device
+            .wait_until_back_online(Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "reqwest", feature = "tokio-runtime"))]
+    async fn test_closed_by_device() {
+        use crate::HttpClient;
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url: url::Url = format!("http://{}/reboot", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+        });
+
+        let client = reqwest::Client::new();
+        let err = client
+            .send(HttpRequest::post(url).body("csrfToken=abc".to_string()))
+            .await
+            .unwrap_err();
+        assert!(is_connection_closed(&err), "{err:?}");
+    }
+}
@@ -0,0 +1,116 @@
+//! Helpers that keep credentials and identifiers out of `Debug`
+//! output and trace logs, so that logs are safe to share in bug reports.
+use std::borrow::Cow;
+use std::fmt;
+
+/// Formats as `<redacted>`, for use in place of a secret
+/// in a `Debug` implementation
+#[cfg_attr(not(feature = "svc-system-properties"), allow(dead_code))]
+pub(crate) struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/// Formats an identifier with all but its last few characters
+/// masked, which is enough to tell identifiers apart in a log
+/// without revealing them
+#[cfg_attr(not(feature = "svc-system-properties"), allow(dead_code))]
+pub(crate) struct Masked<'a>(pub &'a str);
+
+impl fmt::Debug for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const VISIBLE: usize = 4;
+        let len = self.0.chars().count();
+        let tail: String = self.0.chars().skip(len.saturating_sub(VISIBLE)).collect();
+        if len <= VISIBLE {
+            write!(f, "\"{}\"", "*".repeat(len))
+        } else {
+            write!(f, "\"***{tail}\"")
+        }
+    }
+}
+
+/// The SOAP action arguments whose values are removed from trace logs
+/// and from the `RawSoap` retained with errors.
+/// `StringValue` is included because `SetString` and `GetString` are
+/// used to persist music service tokens.
+pub(crate) const SENSITIVE_ARGUMENTS: &[&str] = &[
+    "AccountKey",
+    "AccountPassword",
+    "AccountToken",
+    "NewAccountPassword",
+    "StringValue",
+];
+
+/// Replaces the text content of the elements named in `elements`,
+/// with or without a namespace prefix, with `REDACTED`
+pub(crate) fn redact_xml<'a>(xml: &'a str, elements: &[&str]) -> Cow<'a, str> {
+    let mut result = String::new();
+    let mut copied = 0;
+    let mut pos = 0;
+    while let Some(open) = xml[pos..].find('<').map(|i| i + pos) {
+        let Some(close) = xml[open..].find('>').map(|i| i + open) else {
+            break;
+        };
+        pos = close + 1;
+
+        let tag = &xml[open + 1..close];
+        if tag.ends_with('/') || tag.starts_with(['/', '?', '!']) {
+            continue;
+        }
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        let local_name = name.rsplit(':').next().unwrap_or(name);
+        if !elements.contains(&local_name) {
+            continue;
+        }
+        let end = xml[pos..].find('<').map_or(xml.len(), |i| i + pos);
+        if end > pos {
+            result.push_str(&xml[copied..pos]);
+            result.push_str("REDACTED");
+            copied = end;
+        }
+        pos = end;
+    }
+
+    if copied == 0 {
+        Cow::Borrowed(xml)
+    } else {
+        result.push_str(&xml[copied..]);
+        Cow::Owned(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_masked() {
+        assert_eq!(
+            format!("{:?}", Masked("Sonos_abcdefghijkl1234")),
+            "\"***1234\""
+        );
+        assert_eq!(format!("{:?}", Masked("abc")), "\"***\"");
+        assert_eq!(format!("{:?}", Some(Redacted)), "Some(<redacted>)");
+    }
+
+    #[test]
+    fn test_redact_xml() {
+        let xml = "<credentials><deviceId>00-0E-58</deviceId>\
+            <loginToken><token>secret</token><key/><s:householdId>Sonos_x</s:householdId>\
+            </loginToken></credentials>";
+        assert_eq!(
+            redact_xml(xml, &["deviceId", "token", "key", "householdId"]),
+            "<credentials><deviceId>REDACTED</deviceId>\
+            <loginToken><token>REDACTED</token><key/><s:householdId>REDACTED</s:householdId>\
+            </loginToken></credentials>"
+        );
+        assert!(matches!(
+            redact_xml("<a><b>1</b></a>", &["token"]),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::Error;
+use http::StatusCode;
+use std::time::Duration;
+
+/// Controls how SOAP actions are retried when they fail with
+/// an error that is likely to be transient, such as a connection
+/// being refused or the device reporting that it is busy.
+/// SOAP faults are never retried, as they indicate that the device
+/// processed and rejected the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The total number of attempts, including the first.
+    /// A value of 1 disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry; the delay doubles
+    /// for each subsequent retry
+    pub initial_backoff: Duration,
+    /// The upper bound on the delay between retries
+    pub max_backoff: Duration,
+    /// HTTP status codes that are considered to be transient
+    pub retry_statuses: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+            retry_statuses: vec![StatusCode::SERVICE_UNAVAILABLE],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that makes a single attempt
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    pub fn retry_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.retry_statuses = statuses;
+        self
+    }
+
+    /// Returns the delay to wait before making attempt number
+    /// `attempt`, where the first retry is attempt 1
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Returns true if `err` is likely to be transient and retrying
+    /// cannot cause the action to be performed twice.
+    /// That is the case when the connection could not be established,
+    /// or when the device responded with one of `retry_statuses`.
+    /// Timeouts and errors after the request was sent are not retried,
+    /// as the device may already have acted on a non-idempotent action
+    /// such as `AddURIToQueue`.
+    pub fn is_retryable(&self, err: &Error) -> bool {
+        match err {
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(err) => err.is_connect(),
+            Error::Http(err) => is_connection_refused(err.as_ref()),
+            Error::FailedRequest { status, .. } => self.retry_statuses.contains(status),
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if the chain of `err` includes a refused connection,
+/// which is how a custom `HttpClient` reports that it failed to connect
+fn is_connection_refused(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return io.kind() == std::io::ErrorKind::ConnectionRefused;
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1600));
+        assert_eq!(policy.backoff(6), Duration::from_secs(2));
+        assert_eq!(policy.backoff(64), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let policy = RetryPolicy::default();
+        let failed = |status| Error::FailedRequest {
+            status,
+            body: String::new(),
+            headers: Default::default(),
+        };
+        assert!(policy.is_retryable(&failed(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(!policy.is_retryable(&failed(StatusCode::INTERNAL_SERVER_ERROR)));
+
+        let io = |kind| Error::Http(Box::new(std::io::Error::from(kind)));
+        assert!(policy.is_retryable(&io(std::io::ErrorKind::ConnectionRefused)));
+        assert!(!policy.is_retryable(&io(std::io::ErrorKind::TimedOut)));
+        assert!(!policy.is_retryable(&io(std::io::ErrorKind::ConnectionReset)));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{device_properties, DeviceProperties, Result, SonosDevice};
+use std::fmt;
+use std::str::FromStr;
+
+/// The room icons that are known to the Sonos app
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerIcon {
+    Balcony,
+    Bathroom,
+    Bedroom,
+    BonusRoom,
+    Den,
+    Dining,
+    Family,
+    Foyer,
+    Garage,
+    Garden,
+    GuestRoom,
+    Hallway,
+    KidsRoom,
+    Kitchen,
+    Library,
+    #[default]
+    Living,
+    MasterBedroom,
+    Media,
+    Office,
+    Patio,
+    Pool,
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+const ICON_PREFIX: &str = "x-rincon-roomicon:";
+
+const ICONS: &[(&str, PlayerIcon)] = &[
+    ("balcony", PlayerIcon::Balcony),
+    ("bathroom", PlayerIcon::Bathroom),
+    ("bedroom", PlayerIcon::Bedroom),
+    ("bonusroom", PlayerIcon::BonusRoom),
+    ("den", PlayerIcon::Den),
+    ("dining", PlayerIcon::Dining),
+    ("family", PlayerIcon::Family),
+    ("foyer", PlayerIcon::Foyer),
+    ("garage", PlayerIcon::Garage),
+    ("garden", PlayerIcon::Garden),
+    ("guestroom", PlayerIcon::GuestRoom),
+    ("hallway", PlayerIcon::Hallway),
+    ("kidsroom", PlayerIcon::KidsRoom),
+    ("kitchen", PlayerIcon::Kitchen),
+    ("library", PlayerIcon::Library),
+    ("living", PlayerIcon::Living),
+    ("masterbedroom", PlayerIcon::MasterBedroom),
+    ("media", PlayerIcon::Media),
+    ("office", PlayerIcon::Office),
+    ("patio", PlayerIcon::Patio),
+    ("pool", PlayerIcon::Pool),
+];
+
+impl fmt::Display for PlayerIcon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let PlayerIcon::Unspecified(s) = self {
+            return f.write_str(s);
+        }
+        let name = ICONS
+            .iter()
+            .find(|(_, icon)| icon == self)
+            .map(|(name, _)| *name)
+            .expect("all known icons are in ICONS");
+        write!(f, "{ICON_PREFIX}{name}")
+    }
+}
+
+impl FromStr for PlayerIcon {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<PlayerIcon> {
+        let name = s.strip_prefix(ICON_PREFIX).unwrap_or(s);
+        Ok(ICONS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, icon)| icon.clone())
+            .unwrap_or_else(|| PlayerIcon::Unspecified(s.to_string())))
+    }
+}
+
+impl SonosDevice {
+    /// Renames the room to which this device belongs
+    pub async fn set_room_name(&self, name: &str) -> Result<()> {
+        self.update_zone_attributes(|attr| attr.desired_zone_name = name.to_string())
+            .await?;
+        self.shared
+            .room_name
+            .lock()
+            .unwrap()
+            .replace(name.to_string());
+        Ok(())
+    }
+
+    /// Returns the icon of the room to which this device belongs
+    pub async fn icon(&self) -> Result<PlayerIcon> {
+        let attr = self.get_zone_attributes().await?;
+        attr.current_icon.unwrap_or_default().parse()
+    }
+
+    /// Sets the icon of the room to which this device belongs
+    pub async fn set_icon(&self, icon: PlayerIcon) -> Result<()> {
+        self.update_zone_attributes(|attr| attr.desired_icon = icon.to_string())
+            .await
+    }
+
+    /// SetZoneAttributes replaces all of the attributes at once, so
+    /// this reads the current values, applies `apply` and writes
+    /// them back
+    async fn update_zone_attributes(
+        &self,
+        apply: impl FnOnce(&mut device_properties::SetZoneAttributesRequest),
+    ) -> Result<()> {
+        let current = self.get_zone_attributes().await?;
+        let mut request = device_properties::SetZoneAttributesRequest {
+            desired_zone_name: current.current_zone_name.unwrap_or_default(),
+            desired_icon: current.current_icon.unwrap_or_default(),
+            desired_configuration: current.current_configuration.unwrap_or_default(),
+            desired_target_room_name: current.current_target_room_name,
+        };
+        apply(&mut request);
+        <Self as DeviceProperties>::set_zone_attributes(self, request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_player_icon() {
+        assert_eq!(
+            "x-rincon-roomicon:kitchen".parse::<PlayerIcon>().unwrap(),
+            PlayerIcon::Kitchen
+        );
+        assert_eq!(
+            PlayerIcon::MasterBedroom.to_string(),
+            "x-rincon-roomicon:masterbedroom"
+        );
+        assert_eq!(
+            "x-rincon-roomicon:attic".parse::<PlayerIcon>().unwrap(),
+            PlayerIcon::Unspecified("x-rincon-roomicon:attic".to_string())
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_name_follows_rename() {
+        use crate::vcr::{replay_device, Exchange};
+
+        let zone_attributes = |name: &str| {
+            Exchange::upnp(
+                "/DeviceProperties/Control",
+                "DeviceProperties",
+                "GetZoneAttributes",
+                &format!("<CurrentZoneName>{name}</CurrentZoneName><CurrentIcon>x-rincon-roomicon:living</CurrentIcon><CurrentConfiguration>1</CurrentConfiguration><CurrentTargetRoomName>{name}</CurrentTargetRoomName>"),
+            )
+        };
+        let (device, client) = replay_device(vec![
+            zone_attributes("Living Room"),
+            // Renamed by another controller
+            zone_attributes("Den"),
+        ])
+        .await;
+
+        assert_eq!(device.name().await.unwrap(), "Living Room");
+        assert_eq!(device.name().await.unwrap(), "Den");
+        assert_eq!(client.unused(), vec![]);
+    }
+}
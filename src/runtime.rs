@@ -0,0 +1,229 @@
+use crate::cancel::until_cancelled;
+use crate::Result;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The services of an async runtime that are used for discovery,
+/// event subscriptions and retries.
+/// With the `tokio-runtime` feature, which is enabled by default,
+/// `TokioRuntime` is used unless another is supplied. Embedders that
+/// use another executor, such as smol or async-std, can implement
+/// this and pass it to `SonosDeviceBuilder::runtime` and
+/// `discover_with_runtime`.
+/// The HTTP stack is configured separately; see `HttpClient`.
+pub trait Runtime: Send + Sync {
+    /// Runs `future` in the background
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Returns a future that completes after `duration`
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    fn bind_udp(&self, addr: SocketAddr)
+        -> BoxFuture<'static, io::Result<Box<dyn AsyncUdpSocket>>>;
+
+    fn bind_tcp(
+        &self,
+        addr: SocketAddr,
+    ) -> BoxFuture<'static, io::Result<Box<dyn AsyncTcpListener>>>;
+
+    fn connect_tcp(
+        &self,
+        addr: SocketAddr,
+    ) -> BoxFuture<'static, io::Result<Box<dyn AsyncTcpStream>>>;
+}
+
+/// A UDP socket, as used by `discover`
+pub trait AsyncUdpSocket: Send + Sync {
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> io::Result<()>;
+
+    fn send_to<'a>(&'a self, buf: &'a [u8], addr: SocketAddr) -> BoxFuture<'a, io::Result<usize>>;
+
+    fn recv_from<'a>(&'a self, buf: &'a mut [u8])
+        -> BoxFuture<'a, io::Result<(usize, SocketAddr)>>;
+}
+
+/// A TCP listener, as used to receive events from a device
+pub trait AsyncTcpListener: Send + Sync {
+    fn local_addr(&self) -> io::Result<SocketAddr>;
+
+    fn accept(&self) -> BoxFuture<'_, io::Result<Box<dyn AsyncTcpStream>>>;
+}
+
+pub trait AsyncTcpStream: Send {
+    fn local_addr(&self) -> io::Result<SocketAddr>;
+
+    /// Reads into `buf`, returning the number of bytes read, which
+    /// is 0 at the end of the stream
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> BoxFuture<'a, io::Result<usize>>;
+
+    /// Writes all of `buf`, such as the response to an event
+    fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> BoxFuture<'a, io::Result<()>>;
+}
+
+/// The `Runtime` of a device, which is shared by its clones
+#[derive(Clone)]
+pub(crate) struct SharedRuntime(Arc<dyn Runtime>);
+
+impl SharedRuntime {
+    pub fn new(runtime: Arc<dyn Runtime>) -> Self {
+        Self(runtime)
+    }
+
+    /// Returns `TokioRuntime` when the `tokio-runtime` feature is
+    /// enabled, otherwise `Error::NoRuntime`
+    pub fn default_runtime() -> Result<Self> {
+        #[cfg(feature = "tokio-runtime")]
+        return Ok(Self::new(Arc::new(TokioRuntime)));
+        #[cfg(not(feature = "tokio-runtime"))]
+        return Err(crate::Error::NoRuntime);
+    }
+
+    /// Runs `fut` until it completes, returning its output, or until
+    /// `timeout` elapses, in which case `None` is returned
+    #[cfg_attr(not(any(feature = "discovery", feature = "events")), allow(dead_code))]
+    pub async fn timeout<F: Future>(&self, timeout: Duration, fut: F) -> Option<F::Output> {
+        until_cancelled(fut, self.sleep(timeout)).await
+    }
+}
+
+impl std::ops::Deref for SharedRuntime {
+    type Target = dyn Runtime;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl std::fmt::Debug for SharedRuntime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SharedRuntime")
+    }
+}
+
+#[cfg(feature = "tokio-runtime")]
+mod tokio_runtime {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream, UdpSocket};
+
+    /// A `Runtime` that uses the tokio runtime in which it is called
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct TokioRuntime;
+
+    impl Runtime for TokioRuntime {
+        fn spawn(&self, future: BoxFuture<'static, ()>) {
+            tokio::spawn(future);
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            Box::pin(tokio::time::sleep(duration))
+        }
+
+        fn bind_udp(
+            &self,
+            addr: SocketAddr,
+        ) -> BoxFuture<'static, io::Result<Box<dyn AsyncUdpSocket>>> {
+            Box::pin(async move {
+                let socket = UdpSocket::bind(addr).await?;
+                Ok(Box::new(socket) as Box<dyn AsyncUdpSocket>)
+            })
+        }
+
+        fn bind_tcp(
+            &self,
+            addr: SocketAddr,
+        ) -> BoxFuture<'static, io::Result<Box<dyn AsyncTcpListener>>> {
+            Box::pin(async move {
+                let listener = TcpListener::bind(addr).await?;
+                Ok(Box::new(listener) as Box<dyn AsyncTcpListener>)
+            })
+        }
+
+        fn connect_tcp(
+            &self,
+            addr: SocketAddr,
+        ) -> BoxFuture<'static, io::Result<Box<dyn AsyncTcpStream>>> {
+            Box::pin(async move {
+                let stream = TcpStream::connect(addr).await?;
+                Ok(Box::new(stream) as Box<dyn AsyncTcpStream>)
+            })
+        }
+    }
+
+    impl AsyncUdpSocket for UdpSocket {
+        fn set_multicast_ttl_v4(&self, ttl: u32) -> io::Result<()> {
+            UdpSocket::set_multicast_ttl_v4(self, ttl)
+        }
+
+        fn send_to<'a>(
+            &'a self,
+            buf: &'a [u8],
+            addr: SocketAddr,
+        ) -> BoxFuture<'a, io::Result<usize>> {
+            Box::pin(UdpSocket::send_to(self, buf, addr))
+        }
+
+        fn recv_from<'a>(
+            &'a self,
+            buf: &'a mut [u8],
+        ) -> BoxFuture<'a, io::Result<(usize, SocketAddr)>> {
+            Box::pin(UdpSocket::recv_from(self, buf))
+        }
+    }
+
+    impl AsyncTcpListener for TcpListener {
+        fn local_addr(&self) -> io::Result<SocketAddr> {
+            TcpListener::local_addr(self)
+        }
+
+        fn accept(&self) -> BoxFuture<'_, io::Result<Box<dyn AsyncTcpStream>>> {
+            Box::pin(async move {
+                let (stream, _addr) = TcpListener::accept(self).await?;
+                Ok(Box::new(stream) as Box<dyn AsyncTcpStream>)
+            })
+        }
+    }
+
+    impl AsyncTcpStream for TcpStream {
+        fn local_addr(&self) -> io::Result<SocketAddr> {
+            TcpStream::local_addr(self)
+        }
+
+        fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> BoxFuture<'a, io::Result<usize>> {
+            Box::pin(AsyncReadExt::read(self, buf))
+        }
+
+        fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> BoxFuture<'a, io::Result<()>> {
+            Box::pin(AsyncWriteExt::write_all(self, buf))
+        }
+    }
+}
+
+#[cfg(feature = "tokio-runtime")]
+pub use tokio_runtime::TokioRuntime;
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let runtime = SharedRuntime::default_runtime().unwrap();
+        assert_eq!(
+            runtime.timeout(Duration::from_secs(5), async { 42 }).await,
+            Some(42)
+        );
+        assert_eq!(
+            runtime
+                .timeout(Duration::from_millis(5), std::future::pending::<u32>())
+                .await,
+            None
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    AlarmClock, AlarmRecurrence, Error, LocalTime, Result, SonosDevice, SonosSystem, Volume,
+    Weekday,
+};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// How often the household clock is checked for due jobs
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Identifies a job registered with a `Scheduler`
+pub type JobId = u64;
+
+/// Something to do at a scheduled time
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JobAction {
+    /// Plays the favorite whose title is `favorite` in `room`
+    PlayFavorite {
+        room: String,
+        favorite: String,
+    },
+    /// Stops playback in `room`
+    Stop {
+        room: String,
+    },
+    /// Stops playback in every group in the household
+    StopAll,
+    SetVolume {
+        room: String,
+        volume: Volume,
+    },
+}
+
+/// A job that runs `action` at `time` on the days included
+/// in `recurrence`, in the local time of the household
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Job {
+    pub name: String,
+    pub time: LocalTime,
+    /// A job with `AlarmRecurrence::Once` is removed after it has run
+    pub recurrence: AlarmRecurrence,
+    pub action: JobAction,
+}
+
+/// Persistence hook for the jobs registered with a `Scheduler`.
+/// `save` is called whenever the set of jobs changes.
+pub trait JobStore: Send + Sync {
+    fn load(&self) -> Result<Vec<Job>>;
+    fn save(&self, jobs: &[Job]) -> Result<()>;
+}
+
+/// Runs jobs against the players of a `SonosSystem`.
+/// This is useful for schedules that are richer than the alarms that
+/// the players support themselves. Jobs are only run while `run`
+/// is being awaited.
+pub struct Scheduler {
+    system: Arc<SonosSystem>,
+    jobs: Mutex<BTreeMap<JobId, Job>>,
+    next_id: Mutex<JobId>,
+    store: Option<Box<dyn JobStore>>,
+    poll_interval: Duration,
+}
+
+impl Scheduler {
+    pub fn new(system: Arc<SonosSystem>) -> Self {
+        Self {
+            system,
+            jobs: Mutex::new(BTreeMap::new()),
+            next_id: Mutex::new(1),
+            store: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Creates a scheduler whose jobs are loaded from, and
+    /// saved to, `store`
+    pub fn with_store(system: Arc<SonosSystem>, store: Box<dyn JobStore>) -> Result<Self> {
+        let jobs = store.load()?;
+        let mut scheduler = Self::new(system);
+        scheduler.store.replace(store);
+        {
+            let mut map = scheduler.jobs.lock().unwrap();
+            let mut next_id = scheduler.next_id.lock().unwrap();
+            for job in jobs {
+                map.insert(*next_id, job);
+                *next_id += 1;
+            }
+        }
+        Ok(scheduler)
+    }
+
+    /// Sets how often the household clock is checked for due jobs
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn add_job(&self, job: Job) -> Result<JobId> {
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            let id = *next_id;
+            *next_id += 1;
+            id
+        };
+        self.jobs.lock().unwrap().insert(id, job);
+        self.persist()?;
+        Ok(id)
+    }
+
+    pub fn remove_job(&self, id: JobId) -> Result<Option<Job>> {
+        let job = self.jobs.lock().unwrap().remove(&id);
+        if job.is_some() {
+            self.persist()?;
+        }
+        Ok(job)
+    }
+
+    pub fn jobs(&self) -> Vec<(JobId, Job)> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, job)| (*id, job.clone()))
+            .collect()
+    }
+
+    fn persist(&self) -> Result<()> {
+        if let Some(store) = &self.store {
+            let jobs: Vec<Job> = self.jobs.lock().unwrap().values().cloned().collect();
+            store.save(&jobs)?;
+        }
+        Ok(())
+    }
+
+    /// Runs due jobs until an error occurs saving the jobs.
+    /// Errors reading the household clock are logged and the clock is
+    /// read again on the next tick; errors from the jobs themselves are
+    /// logged and do not stop the scheduler.
+    /// Dropping the future stops the scheduler; a job that is running
+    /// at the time may have been partially carried out.
+    pub async fn run(&self) -> Result<()> {
+        let mut previous = None;
+        loop {
+            self.tick(&mut previous).await?;
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Reads the household clock and runs the jobs that became due
+    /// since `previous`, which is then advanced to the current time
+    async fn tick(&self, previous: &mut Option<HouseholdTime>) -> Result<()> {
+        let now = match self.household_time().await {
+            Ok(now) => now,
+            Err(err) => {
+                log::warn!("failed to read the household clock: {err:#}");
+                return Ok(());
+            }
+        };
+        let Some(previous) = previous.replace(now) else {
+            return Ok(());
+        };
+
+        for (id, job) in self.jobs() {
+            if !is_due(&job, &previous, &now) {
+                continue;
+            }
+            log::debug!("running scheduled job {}", job.name);
+            if let Err(err) = self.run_action(&job.action).await {
+                log::error!("scheduled job {} failed: {err:#}", job.name);
+            }
+            if job.recurrence == AlarmRecurrence::Once {
+                self.remove_job(id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `run`, but returns `Ok(())` once `token` is cancelled
+    #[cfg(feature = "cancellation")]
+    pub async fn run_until_cancelled(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<()> {
+        crate::cancel::until_cancelled(self.run(), token.cancelled())
+            .await
+            .unwrap_or(Ok(()))
+    }
+
+    async fn household_time(&self) -> Result<HouseholdTime> {
+        let device = self.system.devices().first().ok_or(Error::NoDevices)?;
+        let response = <SonosDevice as AlarmClock>::get_time_now(device).await?;
+        let local = response
+            .current_local_time
+            .ok_or(Error::MissingValue("CurrentLocalTime"))?;
+        HouseholdTime::parse(&local).ok_or(Error::InvalidTime(local))
+    }
+
+    async fn run_action(&self, action: &JobAction) -> Result<()> {
+        match action {
+            JobAction::PlayFavorite { room, favorite } => {
+                let device = self.system.device_for_room(room).await?;
+                let favorites = device.content_browse("FV:2", 0, 0).await?;
+                let item = favorites
+                    .into_iter()
+                    .find(|item| item.title == *favorite)
+                    .ok_or_else(|| Error::FavoriteNotFound(favorite.to_string()))?;
+                let uri = item.url.clone();
+                device.set_av_transport_uri(&uri, Some(item)).await?;
+                device.play().await
+            }
+            JobAction::Stop { room } => self.system.device_for_room(room).await?.stop().await,
+            JobAction::StopAll => {
+                let device = self.system.devices().first().ok_or(Error::NoDevices)?;
+                for group in device.get_zone_group_state().await? {
+                    let Some(coordinator) = group
+                        .members
+                        .iter()
+                        .find(|member| member.uuid == group.coordinator)
+                    else {
+                        continue;
+                    };
+                    if let Some(device) = self
+                        .system
+                        .devices()
+                        .iter()
+                        .find(|d| d.url().as_str() == coordinator.location)
+                    {
+                        device.stop().await?;
+                    }
+                }
+                Ok(())
+            }
+            JobAction::SetVolume { room, volume } => {
+                self.system
+                    .device_for_room(room)
+                    .await?
+                    .set_volume(*volume)
+                    .await
+            }
+        }
+    }
+}
+
+/// A point in time as measured by the household clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HouseholdTime {
+    /// Days since 1970-01-01
+    day: i64,
+    /// Seconds since midnight
+    seconds: u32,
+}
+
+impl HouseholdTime {
+    /// Parses the `YYYY-MM-DD HH:MM:SS` form used by GetTimeNow
+    fn parse(s: &str) -> Option<Self> {
+        let (date, time) = s.trim().split_once(' ')?;
+        let mut date = date.split('-').map(|f| f.parse::<i64>().ok());
+        let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+        let time: LocalTime = time.parse().ok()?;
+        Some(Self {
+            day: days_from_civil(year, month, day),
+            seconds: u32::from(time.hour) * 3600
+                + u32::from(time.minute) * 60
+                + u32::from(time.second),
+        })
+    }
+
+    fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.day + 4).rem_euclid(7) as usize]
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of the specified
+/// date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns true if `job` was scheduled to run after `previous`
+/// and at or before `now`
+fn is_due(job: &Job, previous: &HouseholdTime, now: &HouseholdTime) -> bool {
+    let t = u32::from(job.time.hour) * 3600
+        + u32::from(job.time.minute) * 60
+        + u32::from(job.time.second);
+    let due_on = |time: &HouseholdTime| job.recurrence.includes(time.weekday());
+
+    if now.day == previous.day {
+        t > previous.seconds && t <= now.seconds && due_on(now)
+    } else if now.day == previous.day + 1 {
+        (t > previous.seconds && due_on(previous)) || (t <= now.seconds && due_on(now))
+    } else {
+        // The clock jumped; don't try to catch up on missed jobs
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_household_time() {
+        let time = HouseholdTime::parse("2024-05-06 07:30:15").unwrap();
+        assert_eq!(time.day, 19849);
+        assert_eq!(time.seconds, 27015);
+        assert_eq!(time.weekday(), Weekday::Monday);
+    }
+
+    #[test]
+    fn test_is_due() {
+        let job = Job {
+            name: "wake up".to_string(),
+            time: LocalTime::new(7, 0, 0).unwrap(),
+            recurrence: AlarmRecurrence::Weekdays,
+            action: JobAction::StopAll,
+        };
+        let monday = |s| HouseholdTime::parse(s).unwrap();
+        assert!(is_due(
+            &job,
+            &monday("2024-05-06 06:59:50"),
+            &monday("2024-05-06 07:00:05")
+        ));
+        assert!(!is_due(
+            &job,
+            &monday("2024-05-06 07:00:05"),
+            &monday("2024-05-06 07:00:20")
+        ));
+        // Sunday
+        assert!(!is_due(
+            &job,
+            &monday("2024-05-05 06:59:50"),
+            &monday("2024-05-05 07:00:05")
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_tick_survives_clock_errors() {
+        use crate::{Exchange, ReplayClient};
+
+        let time_now = |local: &str| Exchange {
+            method: "POST".to_string(),
+            path: "/AlarmClock/Control".to_string(),
+            soap_action: Some(
+                "\"urn:schemas-upnp-org:service:AlarmClock:1#GetTimeNow\"".to_string(),
+            ),
+            status: 200,
+            response_body: format!(
+                r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeNowResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentUTCTime></CurrentUTCTime><CurrentLocalTime>{local}</CurrentLocalTime><CurrentTimeZone>0000</CurrentTimeZone><CurrentTimeGeneration>1</CurrentTimeGeneration></u:GetTimeNowResponse></s:Body></s:Envelope>"#
+            ),
+            ..Default::default()
+        };
+        let client = Arc::new(ReplayClient::new(vec![
+            Exchange {
+                method: "GET".to_string(),
+                path: "/xml/device_description.xml".to_string(),
+                status: 200,
+                response_body: include_str!("../data/device_spec.xml").to_string(),
+                ..Default::default()
+            },
+            time_now("2024-05-06 06:59:50"),
+            time_now("not a time"),
+            time_now("2024-05-06 07:00:05"),
+        ]));
+        let device = SonosDevice::builder()
+            .http_client(client.clone())
+            .from_url(
+                "http://192.168.1.2:1400/xml/device_description.xml"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let scheduler = Scheduler::new(Arc::new(SonosSystem::new(vec![device])));
+        scheduler
+            .add_job(Job {
+                name: "once".to_string(),
+                time: LocalTime::new(7, 0, 0).unwrap(),
+                recurrence: AlarmRecurrence::Once,
+                action: JobAction::Stop {
+                    room: "Nowhere".to_string(),
+                },
+            })
+            .unwrap();
+
+        let mut previous = None;
+        scheduler.tick(&mut previous).await.unwrap();
+        assert_eq!(previous, HouseholdTime::parse("2024-05-06 06:59:50"));
+
+        // The bad reading is skipped rather than stopping the scheduler
+        scheduler.tick(&mut previous).await.unwrap();
+        assert_eq!(previous, HouseholdTime::parse("2024-05-06 06:59:50"));
+
+        // The job still runs once the clock can be read again; it
+        // fails as there is no such room, but is removed regardless
+        scheduler.tick(&mut previous).await.unwrap();
+        assert_eq!(previous, HouseholdTime::parse("2024-05-06 07:00:05"));
+        assert_eq!(scheduler.jobs(), vec![]);
+        assert_eq!(client.unused(), vec![]);
+    }
+}
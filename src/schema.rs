@@ -0,0 +1,305 @@
+//! The JSON documents produced by the bridges in `sonos::bridge`.
+//! Unlike the `serde` representations of the UPnP types, which follow
+//! whatever the devices report, these shapes are part of the public
+//! API: fields are only added within a version, and renaming or
+//! removing one increments `SCHEMA_VERSION`.
+//!
+//! Every document is wrapped in `Versioned`, which adds a `version`
+//! field alongside the fields of the document, eg:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "room": "Kitchen",
+//!   "state": "PLAYING",
+//!   "track": {
+//!     "title": "Song 2",
+//!     "artist": "Blur",
+//!     "album": "Blur",
+//!     "art_url": null
+//!   },
+//!   "volume": 20,
+//!   "muted": false,
+//!   "coordinator": "Kitchen",
+//!   "group": ["Kitchen", "Den"]
+//! }
+//! ```
+//!
+//! Events are tagged with their `BridgeEventKind` in the `type` field:
+//!
+//! ```json
+//! {"version": 1, "type": "volume_change", "room": "Kitchen", "volume": 20, "muted": false}
+//! {"version": 1, "type": "track_change", "room": "Kitchen", "track": null}
+//! {"version": 1, "type": "topology_change", "groups": [{"coordinator": "Den", "rooms": ["Den", "Kitchen"]}]}
+//! ```
+//!
+//! Fields whose value is unknown, such as the volume of a room that
+//! hasn't reported it yet, are `null` rather than omitted.
+use crate::TrackMetaData;
+
+/// The version of the documents defined by this module
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A document, tagged with the `SCHEMA_VERSION` that it conforms to
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    #[serde(flatten)]
+    pub document: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(document: T) -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            document,
+        }
+    }
+}
+
+/// The track that is playing in a room
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct TrackInfo {
+    pub title: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub art_url: Option<String>,
+}
+
+impl From<&TrackMetaData> for TrackInfo {
+    fn from(track: &TrackMetaData) -> Self {
+        Self {
+            title: track.title.clone(),
+            artist: track.creator.clone(),
+            album: track.album.clone(),
+            art_url: track.art_url.clone(),
+        }
+    }
+}
+
+/// The state of a room
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RoomState {
+    pub room: String,
+    /// The transport state, eg: `PLAYING` or `PAUSED_PLAYBACK`
+    pub state: Option<String>,
+    /// `None` when nothing is playing
+    pub track: Option<TrackInfo>,
+    pub volume: Option<u16>,
+    pub muted: Option<bool>,
+    /// The room that coordinates the group that this room belongs to
+    pub coordinator: Option<String>,
+    /// The rooms in the same group as this one, including itself,
+    /// with the coordinator first
+    pub group: Vec<String>,
+}
+
+/// The state of every room
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoomList {
+    pub rooms: Vec<RoomState>,
+}
+
+/// A group of rooms that play in sync
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Group {
+    pub coordinator: String,
+    /// The rooms in the group, with the coordinator first
+    pub rooms: Vec<String>,
+}
+
+/// How the rooms are grouped
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Topology {
+    /// Sorted by the name of the coordinator
+    pub groups: Vec<Group>,
+}
+
+impl Topology {
+    /// Collects the groups described by the `coordinator` and `group`
+    /// of each room; rooms whose group isn't known yet are skipped
+    pub fn from_rooms(rooms: &[RoomState]) -> Self {
+        let mut groups: Vec<Group> = rooms
+            .iter()
+            .filter(|room| room.coordinator.as_ref() == Some(&room.room))
+            .map(|room| Group {
+                coordinator: room.room.clone(),
+                rooms: room.group.clone(),
+            })
+            .collect();
+        groups.sort();
+        Self { groups }
+    }
+}
+
+/// The kinds of `BridgeEvent`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum BridgeEventKind {
+    TrackChange,
+    VolumeChange,
+    TopologyChange,
+}
+
+impl BridgeEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TrackChange => "track_change",
+            Self::VolumeChange => "volume_change",
+            Self::TopologyChange => "topology_change",
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A change to the state of the household
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum BridgeEvent {
+    /// The track playing in a room changed; `track` is `None`
+    /// when playback stopped
+    TrackChange {
+        room: String,
+        track: Option<TrackInfo>,
+    },
+    /// The volume or mute state of a room changed
+    VolumeChange {
+        room: String,
+        volume: Option<u16>,
+        muted: Option<bool>,
+    },
+    /// Rooms were grouped or ungrouped
+    TopologyChange { groups: Vec<Group> },
+}
+
+impl BridgeEvent {
+    pub fn kind(&self) -> BridgeEventKind {
+        match self {
+            Self::TrackChange { .. } => BridgeEventKind::TrackChange,
+            Self::VolumeChange { .. } => BridgeEventKind::VolumeChange,
+            Self::TopologyChange { .. } => BridgeEventKind::TopologyChange,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that `document` serializes to exactly `json`, and that
+    /// `json` deserializes back to `document`
+    fn assert_schema<T>(document: T, json: &str)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        let document = Versioned::new(document);
+        assert_eq!(serde_json::to_string(&document).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Versioned<T>>(json).unwrap(),
+            document
+        );
+    }
+
+    fn kitchen() -> RoomState {
+        RoomState {
+            room: "Kitchen".to_string(),
+            state: Some("PLAYING".to_string()),
+            track: Some(TrackInfo {
+                title: "Song 2".to_string(),
+                artist: Some("Blur".to_string()),
+                album: Some("Blur".to_string()),
+                art_url: None,
+            }),
+            volume: Some(20),
+            muted: Some(false),
+            coordinator: Some("Kitchen".to_string()),
+            group: vec!["Kitchen".to_string(), "Den".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_room_state() {
+        assert_schema(
+            kitchen(),
+            r#"{"version":1,"room":"Kitchen","state":"PLAYING","track":{"title":"Song 2","artist":"Blur","album":"Blur","art_url":null},"volume":20,"muted":false,"coordinator":"Kitchen","group":["Kitchen","Den"]}"#,
+        );
+        assert_schema(
+            RoomState {
+                room: "Den".to_string(),
+                ..RoomState::default()
+            },
+            r#"{"version":1,"room":"Den","state":null,"track":null,"volume":null,"muted":null,"coordinator":null,"group":[]}"#,
+        );
+    }
+
+    #[test]
+    fn test_room_list() {
+        assert_schema(RoomList { rooms: vec![] }, r#"{"version":1,"rooms":[]}"#);
+    }
+
+    #[test]
+    fn test_events() {
+        assert_schema(
+            BridgeEvent::TrackChange {
+                room: "Kitchen".to_string(),
+                track: None,
+            },
+            r#"{"version":1,"type":"track_change","room":"Kitchen","track":null}"#,
+        );
+        assert_schema(
+            BridgeEvent::VolumeChange {
+                room: "Kitchen".to_string(),
+                volume: Some(20),
+                muted: Some(false),
+            },
+            r#"{"version":1,"type":"volume_change","room":"Kitchen","volume":20,"muted":false}"#,
+        );
+        assert_schema(
+            BridgeEvent::TopologyChange {
+                groups: vec![Group {
+                    coordinator: "Den".to_string(),
+                    rooms: vec!["Den".to_string(), "Kitchen".to_string()],
+                }],
+            },
+            r#"{"version":1,"type":"topology_change","groups":[{"coordinator":"Den","rooms":["Den","Kitchen"]}]}"#,
+        );
+    }
+
+    #[test]
+    fn test_topology_from_rooms() {
+        let room = |name: &str, coordinator: &str, group: &[&str]| RoomState {
+            room: name.to_string(),
+            coordinator: Some(coordinator.to_string()),
+            group: group.iter().map(|s| s.to_string()).collect(),
+            ..RoomState::default()
+        };
+        let rooms = [
+            room("Office", "Office", &["Office"]),
+            room("Kitchen", "Den", &["Den", "Kitchen"]),
+            room("Den", "Den", &["Den", "Kitchen"]),
+        ];
+        assert_eq!(
+            Topology::from_rooms(&rooms).groups,
+            vec![
+                Group {
+                    coordinator: "Den".to_string(),
+                    rooms: vec!["Den".to_string(), "Kitchen".to_string()],
+                },
+                Group {
+                    coordinator: "Office".to_string(),
+                    rooms: vec!["Office".to_string()],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{
+    MusicService, ObjectClass, Result, ServiceParams, SonosDevice, SonosUri, TrackMetaData,
+};
+
+/// The flags used for `x-rincon-cpcontainer` URIs
+const CONTAINER_FLAGS: u32 = 8300;
+
+/// The flags used for `x-sonosapi-radio` URIs
+const RADIO_FLAGS: u32 = 8300;
+
+/// The flags used for `x-sonosapi-stream` URIs
+#[cfg_attr(
+    not(all(
+        feature = "svc-device-properties",
+        feature = "svc-music-services",
+        feature = "svc-system-properties"
+    )),
+    allow(dead_code)
+)]
+pub(crate) const STREAM_FLAGS: u32 = 8224;
+
+/// The music service ID of Sonos Radio
+pub const SONOS_RADIO_SERVICE_ID: u32 = 303;
+
+/// The kinds of music service container that can be played
+/// or enqueued via an `x-rincon-cpcontainer` URI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServiceContainerKind {
+    Album,
+    Playlist,
+}
+
+impl ServiceContainerKind {
+    /// The prefix that Sonos uses to identify the kind of container
+    /// in both the URI and the DIDL-Lite item id
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::Album => "1004206c",
+            Self::Playlist => "1006206c",
+        }
+    }
+
+    pub fn class(&self) -> ObjectClass {
+        match self {
+            Self::Album => ObjectClass::MusicAlbum,
+            Self::Playlist => ObjectClass::PlayList,
+        }
+    }
+}
+
+/// Percent-encodes a music service item id in the form used
+/// within Sonos URIs, eg: `spotify:album:123` becomes
+/// `spotify%3aalbum%3a123`
+pub fn encode_item_id(item_id: &str) -> String {
+    let mut result = String::new();
+    for b in item_id.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+            result.push(b as char);
+        } else {
+            result.push_str(&format!("%{b:02x}"));
+        }
+    }
+    result
+}
+
+/// Builds the `x-rincon-cpcontainer` URI and the matching DIDL-Lite
+/// metadata for a music service album or playlist, so that it can be
+/// passed to `set_av_transport_uri` or `queue_append`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceContainerUri {
+    service_id: u32,
+    desc: String,
+    kind: ServiceContainerKind,
+    item_id: String,
+    serial_number: u32,
+    title: String,
+}
+
+impl ServiceContainerUri {
+    /// `item_id` is the id of the item as reported by `service`,
+    /// eg: `spotify:playlist:37i9dQZF1DXcBWIGoYBM5M`
+    pub fn new(service: &MusicService, kind: ServiceContainerKind, item_id: &str) -> Self {
+        Self {
+            service_id: service.id,
+            desc: service.desc(),
+            kind,
+            item_id: item_id.to_string(),
+            serial_number: 0,
+            title: String::new(),
+        }
+    }
+
+    /// Sets the serial number of the account that should be used
+    /// to access the item. This is the `sn=` value in the URI.
+    pub fn serial_number(mut self, serial_number: u32) -> Self {
+        self.serial_number = serial_number;
+        self
+    }
+
+    /// Sets the title to include in the metadata
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Returns the DIDL-Lite item id
+    pub fn object_id(&self) -> String {
+        self.sonos_uri().object_id().unwrap_or_default()
+    }
+
+    pub fn uri(&self) -> String {
+        self.sonos_uri().to_string()
+    }
+
+    fn sonos_uri(&self) -> SonosUri {
+        SonosUri::Container {
+            object_id: [self.kind.prefix(), &encode_item_id(&self.item_id)].concat(),
+            params: ServiceParams::new(self.service_id, CONTAINER_FLAGS, self.serial_number),
+        }
+    }
+
+    pub fn to_track_metadata(&self) -> TrackMetaData {
+        TrackMetaData {
+            id: Some(self.object_id()),
+            title: self.title.to_string(),
+            url: self.uri(),
+            class: self.kind.class(),
+            desc: Some(self.desc.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Builds the `x-sonosapi-radio` URI and the matching DIDL-Lite
+/// metadata for a music service radio station, such as a Sonos Radio
+/// or Pandora station.
+/// The player rejects these URIs with UPnP error 714 unless the
+/// metadata carries the broadcast class and the `desc` of the service,
+/// so always pass `to_track_metadata()` alongside `uri()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceRadioUri {
+    service_id: u32,
+    desc: String,
+    station_id: String,
+    serial_number: u32,
+    title: String,
+}
+
+impl ServiceRadioUri {
+    /// `station_id` is the id of the station as reported by `service`
+    pub fn new(service: &MusicService, station_id: &str) -> Self {
+        Self {
+            service_id: service.id,
+            desc: service.desc(),
+            station_id: station_id.to_string(),
+            serial_number: 0,
+            title: String::new(),
+        }
+    }
+
+    /// Sets the serial number of the account that should be used
+    /// to access the station. This is the `sn=` value in the URI.
+    pub fn serial_number(mut self, serial_number: u32) -> Self {
+        self.serial_number = serial_number;
+        self
+    }
+
+    /// Sets the title to include in the metadata
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Returns the DIDL-Lite item id
+    pub fn object_id(&self) -> String {
+        self.sonos_uri().object_id().unwrap_or_default()
+    }
+
+    pub fn uri(&self) -> String {
+        self.sonos_uri().to_string()
+    }
+
+    fn sonos_uri(&self) -> SonosUri {
+        SonosUri::SonosApi {
+            kind: "radio".to_string(),
+            item_id: encode_item_id(&self.station_id),
+            params: ServiceParams::new(self.service_id, RADIO_FLAGS, self.serial_number),
+        }
+    }
+
+    pub fn to_track_metadata(&self) -> TrackMetaData {
+        TrackMetaData {
+            id: Some(self.object_id()),
+            title: self.title.to_string(),
+            url: self.uri(),
+            class: ObjectClass::AudioBroadcast,
+            desc: Some(self.desc.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl SonosDevice {
+    /// Starts playing a music service radio station
+    pub async fn play_service_radio(&self, station: &ServiceRadioUri) -> Result<()> {
+        self.set_av_transport_uri(&station.uri(), Some(station.to_track_metadata()))
+            .await?;
+        self.play().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AuthType;
+
+    fn service(id: u32, auth_type: AuthType) -> MusicService {
+        MusicService {
+            id,
+            name: String::new(),
+            version: None,
+            uri: String::new(),
+            secure_uri: None,
+            container_type: None,
+            capabilities: 0,
+            auth_type,
+            poll_interval: None,
+            presentation: None,
+        }
+    }
+
+    #[test]
+    fn test_container_uri() {
+        let container = ServiceContainerUri::new(
+            &service(9, AuthType::DeviceLink),
+            ServiceContainerKind::Playlist,
+            "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M",
+        )
+        .serial_number(3)
+        .title("Today's Top Hits");
+
+        assert_eq!(
+            container.uri(),
+            "x-rincon-cpcontainer:1006206cspotify%3aplaylist%3a37i9dQZF1DXcBWIGoYBM5M?sid=9&flags=8300&sn=3"
+        );
+
+        let metadata = container.to_track_metadata();
+        assert_eq!(
+            metadata.id.as_deref(),
+            Some("1006206cspotify%3aplaylist%3a37i9dQZF1DXcBWIGoYBM5M")
+        );
+        assert_eq!(
+            metadata.desc.as_deref(),
+            Some("SA_RINCON2311_X_#Svc2311-0-Token")
+        );
+        assert_eq!(metadata.class, ObjectClass::PlayList);
+    }
+
+    #[test]
+    fn test_radio_uri() {
+        let station = ServiceRadioUri::new(
+            &service(SONOS_RADIO_SERVICE_ID, AuthType::UserId),
+            "sonos:hits",
+        )
+        .serial_number(5);
+        assert_eq!(
+            station.uri(),
+            "x-sonosapi-radio:sonos%3ahits?sid=303&flags=8300&sn=5"
+        );
+        let metadata = station.to_track_metadata();
+        assert_eq!(metadata.id.as_deref(), Some("100c206csonos%3ahits"));
+        assert_eq!(
+            metadata.desc.as_deref(),
+            Some("SA_RINCON77575_X_#Svc77575-0-Token")
+        );
+        assert_eq!(metadata.class, ObjectClass::AudioBroadcast);
+
+        let station = ServiceRadioUri::new(&service(254, AuthType::Anonymous), "s12345");
+        assert_eq!(
+            station.to_track_metadata().desc.as_deref(),
+            Some("SA_RINCON65031_")
+        );
+    }
+}
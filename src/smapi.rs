@@ -0,0 +1,1106 @@
+//! A client for the Sonos Music API (SMAPI), which is the SOAP API
+//! implemented by music services and used by Sonos players to browse
+//! and play their content.
+use crate::redact::{redact_xml, Masked, Redacted};
+use crate::{
+    AuthType, DeviceProperties, Error, HttpClient, HttpRequest, MusicService, MusicServiceAccount,
+    Result, SonosDevice, SOAP_ENVELOPE,
+};
+use http::StatusCode;
+use instant_xml::{FromXml, FromXmlOwned, ToXml};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+const SMAPI_NS: &str = "http://www.sonos.com/Services/1.1";
+
+/// The elements of SMAPI requests and responses whose content
+/// is removed from trace logs
+const SENSITIVE_ELEMENTS: &[&str] = &[
+    "authToken",
+    "deviceId",
+    "householdId",
+    "key",
+    "privateKey",
+    "token",
+];
+
+/// The SystemProperties variable that holds the serial number
+/// that identifies this player to music services
+const DEVICE_ID_VARIABLE: &str = "R_TrialZPSerial";
+
+/// The credentials produced by linking a household to a music service.
+/// These are redacted from the `Debug` representation.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmapiToken {
+    pub token: String,
+    pub key: String,
+}
+
+impl MusicServiceAccount {
+    /// Returns the token of a linked account, if any
+    pub(crate) fn token(&self) -> Option<SmapiToken> {
+        match (self.oa_device_id.as_deref(), self.key.as_deref()) {
+            (Some(token), Some(key)) if !token.is_empty() && !key.is_empty() => Some(SmapiToken {
+                token: token.to_string(),
+                key: key.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SmapiToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SmapiToken")
+            .field("token", &Redacted)
+            .field("key", &Redacted)
+            .finish()
+    }
+}
+
+/// The information required to have the user link their music
+/// service account to the household
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceLinkCode {
+    /// The URL that the user should visit to link their account
+    pub reg_url: String,
+    pub link_code: String,
+    /// Whether the `link_code` should be shown to the user
+    pub show_link_code: bool,
+    pub link_device_id: Option<String>,
+}
+
+/// An item returned from an SMAPI browse or search request
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmapiMediaItem {
+    pub id: String,
+    /// eg: `stream`, `track`, `album`, `container`
+    pub item_type: String,
+    pub title: String,
+    pub mime_type: Option<String>,
+    pub art_url: Option<String>,
+    /// true if this item is a `mediaCollection` that can be browsed
+    /// into, rather than a playable `mediaMetadata` item
+    pub is_container: bool,
+}
+
+/// A page of results from an SMAPI browse or search request
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmapiMediaList {
+    pub index: u32,
+    pub total: u32,
+    pub items: Vec<SmapiMediaItem>,
+}
+
+/// Tokens that change whenever the corresponding content on the
+/// service changes, as reported by `getLastUpdate`.
+/// Poll this and compare with a previously saved value to determine
+/// whether cached catalog, favorites or play positions need to be
+/// refreshed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmapiLastUpdate {
+    pub catalog: Option<String>,
+    pub favorites: Option<String>,
+    /// How frequently, in seconds, the service would like
+    /// to be polled
+    pub poll_interval: Option<u32>,
+}
+
+/// A client for the SMAPI endpoint of a specific music service
+pub struct SmapiClient {
+    endpoint: Url,
+    device_id: String,
+    household_id: String,
+    token: Mutex<Option<SmapiToken>>,
+    client: Option<Arc<dyn HttpClient>>,
+}
+
+impl SmapiClient {
+    /// Creates a client for `service`.
+    /// `device_id` is the serial number of the player and
+    /// `household_id` is the household that the player belongs to.
+    pub fn new(service: &MusicService, device_id: &str, household_id: &str) -> Result<Self> {
+        let endpoint = service.secure_uri.as_deref().unwrap_or(&service.uri);
+        Ok(Self {
+            endpoint: endpoint.parse()?,
+            device_id: device_id.to_string(),
+            household_id: household_id.to_string(),
+            token: Mutex::new(None),
+            #[cfg(feature = "reqwest")]
+            client: Some(Arc::new(reqwest::Client::new())),
+            #[cfg(not(feature = "reqwest"))]
+            client: None,
+        })
+    }
+
+    /// Sends requests via `client`. This is required when the
+    /// `reqwest` feature is disabled.
+    pub fn http_client(mut self, client: Arc<dyn HttpClient>) -> Self {
+        self.client.replace(client);
+        self
+    }
+
+    /// Sets the token used to authenticate subsequent requests
+    pub fn set_token(&self, token: Option<SmapiToken>) {
+        *self.token.lock().unwrap() = token;
+    }
+
+    /// Returns the token used to authenticate requests.
+    /// This may change over time, as the service can issue a
+    /// replacement when the token is refreshed.
+    pub fn token(&self) -> Option<SmapiToken> {
+        self.token.lock().unwrap().clone()
+    }
+
+    pub fn household_id(&self) -> &str {
+        &self.household_id
+    }
+
+    /// Performs an SMAPI request.
+    /// If the service indicates that the token needs to be refreshed,
+    /// and provides a replacement, the replacement is adopted and the
+    /// request is retried.
+    pub async fn call<REQ: ToXml, RESP: FromXmlOwned>(
+        &self,
+        action: &str,
+        payload: REQ,
+    ) -> Result<RESP> {
+        let payload = instant_xml::to_string(&payload)?;
+
+        match self.call_once(action, &payload).await {
+            Err(Error::SmapiFault {
+                refreshed_token: Some(token),
+                ..
+            }) => {
+                log::debug!("{action}: adopting refreshed token");
+                self.set_token(Some(token));
+                self.call_once(action, &payload).await
+            }
+            result => result,
+        }
+    }
+
+    async fn call_once<RESP: FromXmlOwned>(&self, action: &str, payload: &str) -> Result<RESP> {
+        let credentials = Credentials {
+            device_id: self.device_id.to_string(),
+            device_provider: "Sonos".to_string(),
+            login_token: self.token().map(|token| LoginToken {
+                token: token.token,
+                key: token.key,
+                household_id: self.household_id.to_string(),
+            }),
+        };
+        let header = instant_xml::to_string(&credentials)?;
+        let body = format!(
+            "<s:Envelope xmlns:s=\"{SOAP_ENVELOPE}\">\
+            <s:Header>{header}</s:Header>\
+            <s:Body>{payload}</s:Body>\
+            </s:Envelope>"
+        );
+        log::trace!("Sending: {}", redact_xml(&body, SENSITIVE_ELEMENTS));
+
+        let request = HttpRequest::post(self.endpoint.clone())
+            .header("content-type", "text/xml; charset=\"utf-8\"")?
+            .header("soapaction", &format!("\"{SMAPI_NS}#{action}\""))?
+            .body(body);
+        let client = self.client.as_deref().ok_or(Error::NoHttpClient)?;
+        let response = client.send(request).await?;
+
+        if response.status == StatusCode::INTERNAL_SERVER_ERROR {
+            let body = response.body;
+            log::trace!("Got fault: {}", redact_xml(&body, SENSITIVE_ELEMENTS));
+            let envelope: Envelope<Fault> =
+                instant_xml::from_str(&body).map_err(|error| Error::XmlParse {
+                    error,
+                    text: body.to_string(),
+                })?;
+            return Err(envelope.body.payload.into());
+        }
+
+        let body = response.check()?.body;
+        log::trace!("Got response: {}", redact_xml(&body, SENSITIVE_ELEMENTS));
+
+        let envelope: Envelope<RESP> =
+            instant_xml::from_str(&body).map_err(|error| Error::XmlParse {
+                error,
+                text: body.to_string(),
+            })?;
+        Ok(envelope.body.payload)
+    }
+
+    /// Begins the device link flow, returning the code and URL
+    /// that the user needs to link their account
+    pub async fn get_device_link_code(&self) -> Result<DeviceLinkCode> {
+        let response: GetDeviceLinkCodeResponse = self
+            .call(
+                "getDeviceLinkCode",
+                GetDeviceLinkCode {
+                    household_id: self.household_id.to_string(),
+                },
+            )
+            .await?;
+        Ok(response.result.into())
+    }
+
+    /// Begins the app link flow, returning the code and URL
+    /// that the user needs to link their account
+    pub async fn get_app_link(&self) -> Result<DeviceLinkCode> {
+        let response: GetAppLinkResponse = self
+            .call(
+                "getAppLink",
+                GetAppLink {
+                    household_id: self.household_id.to_string(),
+                },
+            )
+            .await?;
+        Ok(response.result.authorize_account.device_link.into())
+    }
+
+    /// Checks whether the user has completed linking their account.
+    /// Returns `Ok(None)` if the user has yet to do so.
+    /// On success, the token is also adopted by this client.
+    pub async fn get_device_auth_token(&self, link: &DeviceLinkCode) -> Result<Option<SmapiToken>> {
+        let result: Result<GetDeviceAuthTokenResponse> = self
+            .call(
+                "getDeviceAuthToken",
+                GetDeviceAuthToken {
+                    household_id: self.household_id.to_string(),
+                    link_code: link.link_code.to_string(),
+                    link_device_id: link.link_device_id.clone(),
+                },
+            )
+            .await;
+        match result {
+            Ok(response) => {
+                let token = SmapiToken {
+                    token: response.result.auth_token,
+                    key: response.result.private_key,
+                };
+                self.set_token(Some(token.clone()));
+                Ok(Some(token))
+            }
+            Err(Error::SmapiFault { code, .. }) if code.ends_with("NOT_LINKED_RETRY") => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Explicitly requests a replacement for the current token.
+    /// The replacement is adopted by this client.
+    pub async fn refresh_auth_token(&self) -> Result<SmapiToken> {
+        let response: RefreshAuthTokenResponse =
+            self.call("refreshAuthToken", RefreshAuthToken {}).await?;
+        let token = SmapiToken {
+            token: response.result.auth_token,
+            key: response.result.private_key,
+        };
+        self.set_token(Some(token.clone()));
+        Ok(token)
+    }
+
+    /// Searches the `category` (as advertised by the service, eg:
+    /// `stations`) for items matching `term`
+    pub async fn search(
+        &self,
+        category: &str,
+        term: &str,
+        index: u32,
+        count: u32,
+    ) -> Result<SmapiMediaList> {
+        let response: SearchResponse = self
+            .call(
+                "search",
+                Search {
+                    id: category.to_string(),
+                    term: term.to_string(),
+                    index,
+                    count,
+                },
+            )
+            .await?;
+        Ok(response.result.into())
+    }
+
+    /// Reports that `item_id` has been playing for `position`.
+    /// This is used by podcast and audiobook services to track the
+    /// resume position. Returns the interval, in seconds, after which
+    /// the service would like the position to be reported again, if
+    /// the service wants further reports.
+    pub async fn report_play_seconds(
+        &self,
+        item_id: &str,
+        position: Duration,
+    ) -> Result<Option<u32>> {
+        let response: ReportPlaySecondsResponse = self
+            .call(
+                "reportPlaySeconds",
+                ReportPlaySeconds {
+                    id: item_id.to_string(),
+                    seconds: position.as_secs() as u32,
+                    offset_millis: position.as_millis() as u64,
+                },
+            )
+            .await?;
+        Ok(response.result.and_then(|r| r.interval).filter(|&i| i > 0))
+    }
+
+    /// Explicitly sets the resume position of `item_id` to `position`,
+    /// for example, when the user marks an episode as partially played
+    pub async fn set_played_seconds(&self, item_id: &str, position: Duration) -> Result<()> {
+        let _: SetPlayedSecondsResponse = self
+            .call(
+                "setPlayedSeconds",
+                SetPlayedSeconds {
+                    id: item_id.to_string(),
+                    seconds: position.as_secs() as u32,
+                    offset_millis: position.as_millis() as u64,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the resume position that the service has saved for
+    /// `item_id`, as reported in the `positionInformation` of its
+    /// media metadata, or `None` if the item has not been partially
+    /// played
+    pub async fn resume_position(&self, item_id: &str) -> Result<Option<Duration>> {
+        let response: GetMediaMetadataResponse = self
+            .call(
+                "getMediaMetadata",
+                GetMediaMetadata {
+                    id: item_id.to_string(),
+                },
+            )
+            .await?;
+        Ok(response
+            .result
+            .position_information
+            .map(|p| Duration::from_millis(p.offset_millis)))
+    }
+
+    /// Returns the tokens that indicate whether content, including
+    /// play positions, has changed on the service
+    pub async fn get_last_update(&self) -> Result<SmapiLastUpdate> {
+        let response: GetLastUpdateResponse = self.call("getLastUpdate", GetLastUpdate {}).await?;
+        Ok(SmapiLastUpdate {
+            catalog: response.result.catalog,
+            favorites: response.result.favorites,
+            poll_interval: response.result.poll_interval,
+        })
+    }
+}
+
+/// An in-progress authorization of a music service
+pub struct ServiceAuthorization {
+    service_id: u32,
+    client: SmapiClient,
+    link: DeviceLinkCode,
+}
+
+impl ServiceAuthorization {
+    /// The code and URL that should be presented to the user
+    pub fn link(&self) -> &DeviceLinkCode {
+        &self.link
+    }
+
+    /// Checks whether the user has completed linking their account.
+    /// Returns `Ok(None)` if the user has yet to do so, in which case
+    /// you should wait a few seconds before polling again.
+    /// On success, the token is persisted to `device`.
+    pub async fn poll(&self, device: &SonosDevice) -> Result<Option<SmapiToken>> {
+        let token = self.client.get_device_auth_token(&self.link).await?;
+        if let Some(token) = &token {
+            device.set_service_token(self.service_id, token).await?;
+        }
+        Ok(token)
+    }
+}
+
+impl SonosDevice {
+    /// Returns an SMAPI client for `service` that will identify itself
+    /// as this player, using any token that has been stored for the
+    /// service.
+    pub async fn smapi_client(&self, service: &MusicService) -> Result<SmapiClient> {
+        let device_id = self.system_string(DEVICE_ID_VARIABLE).await?;
+        let household_id = self
+            .get_household_id()
+            .await?
+            .current_household_id
+            .unwrap_or_default();
+
+        let client = SmapiClient::new(service, &device_id.unwrap_or_default(), &household_id)?;
+        client.set_token(self.service_token(service.id).await?);
+        Ok(client)
+    }
+
+    /// Begins linking the household to the music service identified by
+    /// `service_id`, using either the device link or app link flow as
+    /// appropriate for the service.
+    pub async fn authorize_service(&self, service_id: u32) -> Result<ServiceAuthorization> {
+        let service = self.music_service(service_id).await?;
+
+        let client = self.smapi_client(&service).await?;
+        client.set_token(None);
+
+        let link = match &service.auth_type {
+            AuthType::DeviceLink => client.get_device_link_code().await?,
+            AuthType::AppLink => client.get_app_link().await?,
+            auth_type => return Err(Error::UnsupportedAuthType(auth_type.clone())),
+        };
+
+        Ok(ServiceAuthorization {
+            service_id,
+            client,
+            link,
+        })
+    }
+
+    /// Returns the token stored for the music service identified
+    /// by `service_id`, if any.
+    /// The token and key are kept in the SystemProperties string
+    /// variables named by `MusicService::account_variable`; tokens
+    /// linked by the Sonos app before then are read from the account
+    /// record instead.
+    pub async fn service_token(&self, service_id: u32) -> Result<Option<SmapiToken>> {
+        let service = self.music_service(service_id).await?;
+        let account = self.account_for_service(service_id).await?;
+        let serial_number = account.as_ref().map_or(0, |a| a.serial_number);
+
+        let token = self
+            .system_string(&service.account_variable(serial_number, "Token"))
+            .await?;
+        let key = self
+            .system_string(&service.account_variable(serial_number, "Key"))
+            .await?;
+        match (token, key) {
+            (Some(token), Some(key)) if !token.is_empty() && !key.is_empty() => {
+                Ok(Some(SmapiToken { token, key }))
+            }
+            _ => Ok(account.and_then(|account| account.token())),
+        }
+    }
+
+    /// Persists the token for the music service identified by
+    /// `service_id` in the SystemProperties string variables that
+    /// `service_token` reads it from.
+    /// The token and key are written separately; if the future is
+    /// dropped in between, the stored token is incomplete until this
+    /// is called again.
+    pub async fn set_service_token(&self, service_id: u32, token: &SmapiToken) -> Result<()> {
+        let service = self.music_service(service_id).await?;
+        let serial_number = self
+            .account_for_service(service_id)
+            .await?
+            .map_or(0, |account| account.serial_number);
+
+        self.set_system_string(
+            &service.account_variable(serial_number, "Token"),
+            &token.token,
+        )
+        .await?;
+        self.set_system_string(&service.account_variable(serial_number, "Key"), &token.key)
+            .await
+    }
+
+    /// Asks the music service identified by `service_id` for a
+    /// replacement token, and persists it
+    pub async fn refresh_service_token(&self, service_id: u32) -> Result<SmapiToken> {
+        let service = self.music_service(service_id).await?;
+        let client = self.smapi_client(&service).await?;
+        if client.token().is_none() {
+            return Err(Error::NoServiceToken(service_id));
+        }
+        let token = client.refresh_auth_token().await?;
+        self.set_service_token(service_id, &token).await?;
+        Ok(token)
+    }
+}
+
+#[derive(ToXml)]
+#[xml(rename = "credentials", ns(SMAPI_NS))]
+struct Credentials {
+    #[xml(rename = "deviceId")]
+    device_id: String,
+    #[xml(rename = "deviceProvider")]
+    device_provider: String,
+    login_token: Option<LoginToken>,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("device_id", &Masked(&self.device_id))
+            .field("device_provider", &self.device_provider)
+            .field("login_token", &self.login_token)
+            .finish()
+    }
+}
+
+#[derive(ToXml)]
+#[xml(rename = "loginToken", ns(SMAPI_NS))]
+struct LoginToken {
+    token: String,
+    key: String,
+    #[xml(rename = "householdId")]
+    household_id: String,
+}
+
+impl std::fmt::Debug for LoginToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LoginToken")
+            .field("token", &Redacted)
+            .field("key", &Redacted)
+            .field("household_id", &Masked(&self.household_id))
+            .finish()
+    }
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Envelope<T> {
+    body: Body<T>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Body<T> {
+    payload: T,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(ns(SOAP_ENVELOPE))]
+struct Fault {
+    #[xml(rename = "faultcode", ns(""))]
+    fault_code: String,
+    #[xml(rename = "faultstring", ns(""))]
+    fault_string: Option<String>,
+    detail: Option<FaultDetail>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "detail", ns(""))]
+struct FaultDetail {
+    refresh: Option<RefreshAuthTokenResult>,
+}
+
+impl From<Fault> for Error {
+    fn from(fault: Fault) -> Error {
+        Error::SmapiFault {
+            code: fault.fault_code,
+            message: fault.fault_string.unwrap_or_default(),
+            refreshed_token: fault.detail.and_then(|d| d.refresh).map(|r| SmapiToken {
+                token: r.auth_token,
+                key: r.private_key,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "getDeviceLinkCode", ns(SMAPI_NS))]
+struct GetDeviceLinkCode {
+    #[xml(rename = "householdId")]
+    household_id: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getDeviceLinkCodeResponse", ns(SMAPI_NS))]
+struct GetDeviceLinkCodeResponse {
+    result: GetDeviceLinkCodeResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getDeviceLinkCodeResult", ns(SMAPI_NS))]
+struct GetDeviceLinkCodeResult {
+    #[xml(rename = "regUrl")]
+    reg_url: String,
+    #[xml(rename = "linkCode")]
+    link_code: String,
+    #[xml(rename = "showLinkCode")]
+    show_link_code: Option<bool>,
+    #[xml(rename = "linkDeviceId")]
+    link_device_id: Option<String>,
+}
+
+impl From<GetDeviceLinkCodeResult> for DeviceLinkCode {
+    fn from(r: GetDeviceLinkCodeResult) -> DeviceLinkCode {
+        DeviceLinkCode {
+            reg_url: r.reg_url,
+            link_code: r.link_code,
+            show_link_code: r.show_link_code.unwrap_or(false),
+            link_device_id: r.link_device_id,
+        }
+    }
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "getAppLink", ns(SMAPI_NS))]
+struct GetAppLink {
+    #[xml(rename = "householdId")]
+    household_id: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getAppLinkResponse", ns(SMAPI_NS))]
+struct GetAppLinkResponse {
+    result: GetAppLinkResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getAppLinkResult", ns(SMAPI_NS))]
+struct GetAppLinkResult {
+    authorize_account: AuthorizeAccount,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "authorizeAccount", ns(SMAPI_NS))]
+struct AuthorizeAccount {
+    device_link: DeviceLink,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "deviceLink", ns(SMAPI_NS))]
+struct DeviceLink {
+    #[xml(rename = "regUrl")]
+    reg_url: String,
+    #[xml(rename = "linkCode")]
+    link_code: String,
+    #[xml(rename = "showLinkCode")]
+    show_link_code: Option<bool>,
+    #[xml(rename = "linkDeviceId")]
+    link_device_id: Option<String>,
+}
+
+impl From<DeviceLink> for DeviceLinkCode {
+    fn from(r: DeviceLink) -> DeviceLinkCode {
+        DeviceLinkCode {
+            reg_url: r.reg_url,
+            link_code: r.link_code,
+            show_link_code: r.show_link_code.unwrap_or(false),
+            link_device_id: r.link_device_id,
+        }
+    }
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "getDeviceAuthToken", ns(SMAPI_NS))]
+struct GetDeviceAuthToken {
+    #[xml(rename = "householdId")]
+    household_id: String,
+    #[xml(rename = "linkCode")]
+    link_code: String,
+    #[xml(rename = "linkDeviceId")]
+    link_device_id: Option<String>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getDeviceAuthTokenResponse", ns(SMAPI_NS))]
+struct GetDeviceAuthTokenResponse {
+    result: GetDeviceAuthTokenResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getDeviceAuthTokenResult", ns(SMAPI_NS))]
+struct GetDeviceAuthTokenResult {
+    #[xml(rename = "authToken")]
+    auth_token: String,
+    #[xml(rename = "privateKey")]
+    private_key: String,
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "refreshAuthToken", ns(SMAPI_NS))]
+struct RefreshAuthToken {}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "refreshAuthTokenResponse", ns(SMAPI_NS))]
+struct RefreshAuthTokenResponse {
+    result: RefreshAuthTokenResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "refreshAuthTokenResult", ns(SMAPI_NS))]
+struct RefreshAuthTokenResult {
+    #[xml(rename = "authToken")]
+    auth_token: String,
+    #[xml(rename = "privateKey")]
+    private_key: String,
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "reportPlaySeconds", ns(SMAPI_NS))]
+struct ReportPlaySeconds {
+    id: String,
+    seconds: u32,
+    #[xml(rename = "offsetMillis")]
+    offset_millis: u64,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "reportPlaySecondsResponse", ns(SMAPI_NS))]
+struct ReportPlaySecondsResponse {
+    result: Option<ReportPlaySecondsResult>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "reportPlaySecondsResult", ns(SMAPI_NS))]
+struct ReportPlaySecondsResult {
+    interval: Option<u32>,
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "setPlayedSeconds", ns(SMAPI_NS))]
+struct SetPlayedSeconds {
+    id: String,
+    seconds: u32,
+    #[xml(rename = "offsetMillis")]
+    offset_millis: u64,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "setPlayedSecondsResponse", ns(SMAPI_NS))]
+struct SetPlayedSecondsResponse {}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "getMediaMetadata", ns(SMAPI_NS))]
+struct GetMediaMetadata {
+    id: String,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getMediaMetadataResponse", ns(SMAPI_NS))]
+struct GetMediaMetadataResponse {
+    result: GetMediaMetadataResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getMediaMetadataResult", ns(SMAPI_NS))]
+struct GetMediaMetadataResult {
+    position_information: Option<PositionInformation>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "positionInformation", ns(SMAPI_NS))]
+struct PositionInformation {
+    #[xml(rename = "offsetMillis")]
+    offset_millis: u64,
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "getLastUpdate", ns(SMAPI_NS))]
+struct GetLastUpdate {}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getLastUpdateResponse", ns(SMAPI_NS))]
+struct GetLastUpdateResponse {
+    result: GetLastUpdateResult,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "getLastUpdateResult", ns(SMAPI_NS))]
+struct GetLastUpdateResult {
+    catalog: Option<String>,
+    favorites: Option<String>,
+    #[xml(rename = "pollInterval")]
+    poll_interval: Option<u32>,
+}
+
+#[derive(Debug, ToXml)]
+#[xml(rename = "search", ns(SMAPI_NS))]
+struct Search {
+    id: String,
+    term: String,
+    index: u32,
+    count: u32,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "searchResponse", ns(SMAPI_NS))]
+struct SearchResponse {
+    result: MediaList,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "searchResult", ns(SMAPI_NS))]
+struct MediaList {
+    index: u32,
+    total: u32,
+    collections: Vec<MediaCollection>,
+    metadata: Vec<MediaMetadata>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "mediaCollection", ns(SMAPI_NS))]
+struct MediaCollection {
+    id: String,
+    #[xml(rename = "itemType")]
+    item_type: String,
+    title: String,
+    #[xml(rename = "albumArtURI")]
+    album_art_uri: Option<String>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "mediaMetadata", ns(SMAPI_NS))]
+struct MediaMetadata {
+    id: String,
+    #[xml(rename = "itemType")]
+    item_type: String,
+    title: String,
+    #[xml(rename = "mimeType")]
+    mime_type: Option<String>,
+    stream_metadata: Option<StreamMetadata>,
+}
+
+#[derive(Debug, FromXml)]
+#[xml(rename = "streamMetadata", ns(SMAPI_NS))]
+struct StreamMetadata {
+    logo: Option<String>,
+}
+
+impl From<MediaList> for SmapiMediaList {
+    fn from(list: MediaList) -> SmapiMediaList {
+        let collections = list.collections.into_iter().map(|c| SmapiMediaItem {
+            id: c.id,
+            item_type: c.item_type,
+            title: c.title,
+            mime_type: None,
+            art_url: c.album_art_uri,
+            is_container: true,
+        });
+        let metadata = list.metadata.into_iter().map(|m| SmapiMediaItem {
+            id: m.id,
+            item_type: m.item_type,
+            title: m.title,
+            mime_type: m.mime_type,
+            art_url: m.stream_metadata.and_then(|s| s.logo),
+            is_container: false,
+        });
+        SmapiMediaList {
+            index: list.index,
+            total: list.total,
+            items: collections.chain(metadata).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_credentials() {
+        let credentials = Credentials {
+            device_id: "00-11-22-33-44-55:A".to_string(),
+            device_provider: "Sonos".to_string(),
+            login_token: Some(LoginToken {
+                token: "TOKEN".to_string(),
+                key: "KEY".to_string(),
+                household_id: "Sonos_XXX".to_string(),
+            }),
+        };
+        assert_eq!(
+            instant_xml::to_string(&credentials).unwrap(),
+            r#"<credentials xmlns="http://www.sonos.com/Services/1.1"><deviceId>00-11-22-33-44-55:A</deviceId><deviceProvider>Sonos</deviceProvider><loginToken><token>TOKEN</token><key>KEY</key><householdId>Sonos_XXX</householdId></loginToken></credentials>"#
+        );
+    }
+
+    #[test]
+    fn test_account_token() {
+        let mut account = MusicServiceAccount {
+            service_type: 2311,
+            serial_number: 1,
+            oa_device_id: Some("TOKEN".to_string()),
+            key: Some("KEY".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            account.token(),
+            Some(SmapiToken {
+                token: "TOKEN".to_string(),
+                key: "KEY".to_string(),
+            })
+        );
+        assert!(!format!("{account:?}").contains("TOKEN"));
+
+        account.key = Some(String::new());
+        assert_eq!(account.token(), None);
+    }
+
+    #[test]
+    fn test_device_link_code() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><getDeviceLinkCodeResponse xmlns="http://www.sonos.com/Services/1.1"><getDeviceLinkCodeResult><regUrl>https://example.com/link</regUrl><linkCode>ABCD</linkCode><showLinkCode>true</showLinkCode></getDeviceLinkCodeResult></getDeviceLinkCodeResponse></s:Body></s:Envelope>"#;
+        let envelope: Envelope<GetDeviceLinkCodeResponse> = instant_xml::from_str(xml).unwrap();
+        assert_eq!(
+            DeviceLinkCode::from(envelope.body.payload.result),
+            DeviceLinkCode {
+                reg_url: "https://example.com/link".to_string(),
+                link_code: "ABCD".to_string(),
+                show_link_code: true,
+                link_device_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_last_update() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><getLastUpdateResponse xmlns="http://www.sonos.com/Services/1.1"><getLastUpdateResult><catalog>1700000000</catalog><favorites>42</favorites><pollInterval>60</pollInterval></getLastUpdateResult></getLastUpdateResponse></s:Body></s:Envelope>"#;
+        let envelope: Envelope<GetLastUpdateResponse> = instant_xml::from_str(xml).unwrap();
+        let result = envelope.body.payload.result;
+        assert_eq!(result.catalog.as_deref(), Some("1700000000"));
+        assert_eq!(result.favorites.as_deref(), Some("42"));
+        assert_eq!(result.poll_interval, Some(60));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_resume_position() {
+        use crate::vcr::{Exchange, ReplayClient};
+
+        let metadata = |position: &str| Exchange {
+            method: "POST".to_string(),
+            path: "/smapi".to_string(),
+            soap_action: Some(format!("\"{SMAPI_NS}#getMediaMetadata\"")),
+            status: 200,
+            response_body: format!(
+                r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><getMediaMetadataResponse xmlns="http://www.sonos.com/Services/1.1"><getMediaMetadataResult><id>episode:1</id><itemType>track</itemType><title>Episode 1</title><mimeType>audio/mpeg</mimeType>{position}</getMediaMetadataResult></getMediaMetadataResponse></s:Body></s:Envelope>"#
+            ),
+            ..Default::default()
+        };
+        let client = ReplayClient::new(vec![
+            metadata("<positionInformation><id>episode:1</id><index>0</index><offsetMillis>754250</offsetMillis></positionInformation>"),
+            metadata(""),
+        ]);
+        let service = MusicService {
+            id: 1,
+            name: "Podcasts".to_string(),
+            version: None,
+            uri: "http://podcasts.example.com/smapi".to_string(),
+            secure_uri: None,
+            container_type: None,
+            capabilities: 0,
+            auth_type: AuthType::Anonymous,
+            poll_interval: None,
+            presentation: None,
+        };
+        let smapi = SmapiClient::new(&service, "RINCON_000E58000000", "Sonos_XXX")
+            .unwrap()
+            .http_client(Arc::new(client));
+
+        assert_eq!(
+            smapi.resume_position("episode:1").await.unwrap(),
+            Some(Duration::from_millis(754250))
+        );
+        assert_eq!(smapi.resume_position("episode:1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vcr")]
+    async fn test_service_token() {
+        use crate::system_properties::{GetStringRequest, SetStringRequest};
+        use crate::vcr::{replay_device, Exchange};
+
+        let services = Exchange::upnp(
+            "/MusicServices/Control",
+            "MusicServices",
+            "ListAvailableServices",
+            &format!(
+                "<AvailableServiceDescriptorList>{}</AvailableServiceDescriptorList>",
+                r#"<Services SchemaVersion="1"><Service Capabilities="0" Id="2" Name="Deezer" Uri="https://deezer.example.com/smapi" Version="1.1"><Policy Auth="DeviceLink"/></Service></Services>"#
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;")
+                    .replace('"', "&quot;")
+            ),
+        );
+        let accounts = Exchange {
+            method: "GET".to_string(),
+            path: "/status/accounts".to_string(),
+            status: 200,
+            response_body: r#"<ZPSupportInfo type="User"><Accounts Version="8" NextSerialNum="4"><Account Type="519" SerialNum="3"><UN>user</UN><MD>1</MD><NN></NN><OADevID></OADevID><Key></Key></Account></Accounts></ZPSupportInfo>"#.to_string(),
+            ..Default::default()
+        };
+        let get_string = |name: &str| {
+            Exchange::upnp_fault(
+                "/SystemProperties/Control",
+                "SystemProperties",
+                "GetString",
+                800,
+            )
+            .request(GetStringRequest {
+                variable_name: name.to_string(),
+            })
+        };
+        let string_value = |name: &str, value: &str| {
+            Exchange::upnp(
+                "/SystemProperties/Control",
+                "SystemProperties",
+                "GetString",
+                &format!("<StringValue>{value}</StringValue>"),
+            )
+            .request(GetStringRequest {
+                variable_name: name.to_string(),
+            })
+        };
+        let set_string = |name: &str, value: &str| {
+            Exchange::upnp(
+                "/SystemProperties/Control",
+                "SystemProperties",
+                "SetString",
+                "",
+            )
+            .request(SetStringRequest {
+                variable_name: name.to_string(),
+                string_value: value.to_string(),
+            })
+        };
+
+        // The list of services is only requested once
+        let (device, client) = replay_device(vec![
+            services,
+            accounts.clone(),
+            get_string("Svc519-3-Token"),
+            get_string("Svc519-3-Key"),
+            accounts.clone(),
+            set_string("Svc519-3-Token", "token"),
+            set_string("Svc519-3-Key", "key"),
+            accounts,
+            string_value("Svc519-3-Token", "token"),
+            string_value("Svc519-3-Key", "key"),
+        ])
+        .await;
+
+        assert_eq!(device.service_token(2).await.unwrap(), None);
+
+        let token = SmapiToken {
+            token: "token".to_string(),
+            key: "key".to_string(),
+        };
+        device.set_service_token(2, &token).await.unwrap();
+        assert_eq!(device.service_token(2).await.unwrap(), Some(token));
+        assert_eq!(client.unused(), vec![]);
+    }
+
+    #[test]
+    fn test_fault() {
+        let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><s:Fault><faultcode>s:Client.NOT_LINKED_RETRY</faultcode><faultstring>Not linked yet</faultstring></s:Fault></s:Body></s:Envelope>"#;
+        let envelope: Envelope<Fault> = instant_xml::from_str(xml).unwrap();
+        match Error::from(envelope.body.payload) {
+            Error::SmapiFault {
+                code,
+                message,
+                refreshed_token,
+            } => {
+                assert_eq!(code, "s:Client.NOT_LINKED_RETRY");
+                assert_eq!(message, "Not linked yet");
+                assert_eq!(refreshed_token, None);
+            }
+            err => panic!("unexpected {err:#}"),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Error, NumericBool, Result};
+
+/// Parses the various ways that players spell a boolean:
+/// `1`/`0`, `true`/`false`, `On`/`Off` and `yes`/`no`, ignoring case
+/// and surrounding whitespace. Returns `None` for anything else,
+/// including the empty string.
+pub fn parse_sonos_bool(value: &str) -> Option<bool> {
+    let value = value.trim();
+    for (spelling, result) in [
+        ("1", true),
+        ("0", false),
+        ("true", true),
+        ("false", false),
+        ("on", true),
+        ("off", false),
+        ("yes", true),
+        ("no", false),
+    ] {
+        if value.eq_ignore_ascii_case(spelling) {
+            return Some(result);
+        }
+    }
+    None
+}
+
+/// Converts between `bool` and the types that players use to
+/// represent booleans, such as `LEDState` and string valued state
+/// variables, so that callers don't have to compare against the
+/// spelling used by a particular action.
+///
+/// ```
+/// use sonos::SonosBool;
+/// assert_eq!("On".to_string().to_bool(), Some(true));
+/// assert_eq!(String::from_bool(false), "0");
+/// ```
+pub trait SonosBool {
+    /// Returns the boolean value, or `None` if the value isn't
+    /// recognized as a boolean
+    fn to_bool(&self) -> Option<bool>;
+
+    /// Returns the value that players use to represent `value`
+    fn from_bool(value: bool) -> Self
+    where
+        Self: Sized;
+
+    /// Like `to_bool`, but returns `Error::InvalidBool` if the
+    /// value isn't recognized as a boolean
+    fn try_to_bool(&self) -> Result<bool>
+    where
+        Self: std::fmt::Display,
+    {
+        self.to_bool()
+            .ok_or_else(|| Error::InvalidBool(self.to_string()))
+    }
+}
+
+impl SonosBool for String {
+    fn to_bool(&self) -> Option<bool> {
+        parse_sonos_bool(self)
+    }
+
+    fn from_bool(value: bool) -> Self {
+        if value { "1" } else { "0" }.to_string()
+    }
+}
+
+impl SonosBool for NumericBool {
+    fn to_bool(&self) -> Option<bool> {
+        Some(self.0)
+    }
+
+    fn from_bool(value: bool) -> Self {
+        NumericBool(value)
+    }
+}
+
+impl std::fmt::Display for NumericBool {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(if self.0 { "1" } else { "0" })
+    }
+}
+
+/// Implements `SonosBool` for the generated enums whose
+/// values are `On` and `Off`
+macro_rules! on_off_bool {
+    ($($(#[$meta:meta])* $ty:path;)*) => {
+        $(
+            $(#[$meta])*
+            impl SonosBool for $ty {
+                fn to_bool(&self) -> Option<bool> {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        Self::On => Some(true),
+                        Self::Off => Some(false),
+                        Self::Unspecified(value) => parse_sonos_bool(value),
+                        _ => None,
+                    }
+                }
+
+                fn from_bool(value: bool) -> Self {
+                    if value {
+                        Self::On
+                    } else {
+                        Self::Off
+                    }
+                }
+            }
+        )*
+    };
+}
+
+on_off_bool! {
+    #[cfg(feature = "svc-device-properties")]
+    crate::ButtonLockState;
+    #[cfg(feature = "svc-device-properties")]
+    crate::LEDState;
+    #[cfg(feature = "svc-ht-control")]
+    crate::IRRepeaterState;
+    #[cfg(feature = "svc-ht-control")]
+    crate::LEDFeedbackState;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for value in ["1", "true", "On", "ON", "yes", " on "] {
+            assert_eq!(parse_sonos_bool(value), Some(true), "{value}");
+        }
+        for value in ["0", "False", "Off", "no"] {
+            assert_eq!(parse_sonos_bool(value), Some(false), "{value}");
+        }
+        for value in ["", "2", "Disabled"] {
+            assert_eq!(parse_sonos_bool(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(String::from_bool(true), "1");
+        assert_eq!("off".to_string().to_bool(), Some(false));
+        assert!(matches!(
+            "maybe".to_string().try_to_bool(),
+            Err(Error::InvalidBool(value)) if value == "maybe"
+        ));
+        assert_eq!(NumericBool::from_bool(true).to_bool(), Some(true));
+    }
+
+    #[test]
+    #[cfg(feature = "svc-device-properties")]
+    fn test_on_off() {
+        use crate::LEDState;
+        assert_eq!(LEDState::from_bool(true), LEDState::On);
+        assert_eq!(LEDState::Off.to_bool(), Some(false));
+        assert_eq!(LEDState::Unspecified("1".to_string()).to_bool(), Some(true));
+        assert_eq!(LEDState::Unspecified("dim".to_string()).to_bool(), None);
+    }
+}
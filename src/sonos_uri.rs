@@ -0,0 +1,368 @@
+use crate::PlayerUuid;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The prefix that Sonos uses for the DIDL-Lite item id of
+/// `x-sonosapi-radio` stations
+const RADIO_PREFIX: &str = "100c206c";
+
+/// The query parameters of a music service URI, which identify the
+/// service and the account that provides the item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceParams {
+    /// The music service id
+    pub sid: Option<u32>,
+    pub flags: Option<u32>,
+    /// The serial number of the account; see
+    /// `MusicServiceAccount::serial_number`
+    pub sn: Option<u32>,
+}
+
+impl ServiceParams {
+    pub fn new(sid: u32, flags: u32, sn: u32) -> Self {
+        Self {
+            sid: Some(sid),
+            flags: Some(flags),
+            sn: Some(sn),
+        }
+    }
+
+    /// Parses `sid=9&flags=8224&sn=1`, returning `None` if there
+    /// are parameters other than these
+    fn parse(query: &str) -> Option<Self> {
+        let mut result = Self::default();
+        for pair in query.split('&') {
+            let (name, value) = pair.split_once('=')?;
+            let value = Some(value.parse().ok()?);
+            match name {
+                "sid" => result.sid = value,
+                "flags" => result.flags = value,
+                "sn" => result.sn = value,
+                _ => return None,
+            }
+        }
+        Some(result)
+    }
+}
+
+impl fmt::Display for ServiceParams {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let params = [("sid", self.sid), ("flags", self.flags), ("sn", self.sn)];
+        let mut sep = "?";
+        for (name, value) in params {
+            if let Some(value) = value {
+                write!(f, "{sep}{name}={value}")?;
+                sep = "&";
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A transport URI, as passed to `set_av_transport_uri` and reported
+/// by `get_media_info`, parsed into its components.
+///
+/// Parsing never fails: URIs that aren't recognized, or that can't
+/// be reproduced exactly from their components, are kept as `Other`,
+/// so that `uri.parse::<SonosUri>()?.to_string() == uri` always holds.
+/// Item ids are kept in the percent-encoded form used in the URI;
+/// see `encode_item_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SonosUri {
+    /// `x-rincon:RINCON_XXX`; the player follows the group
+    /// coordinator whose UUID is `uuid`
+    Group { uuid: PlayerUuid },
+    /// `x-rincon-queue:RINCON_XXX#0`; the queue of the player
+    /// whose UUID is `uuid`
+    Queue { uuid: PlayerUuid },
+    /// `x-rincon-stream:RINCON_XXX`; the line-in of the player
+    /// whose UUID is `uuid`
+    LineIn { uuid: PlayerUuid },
+    /// `x-sonos-htastream:RINCON_XXX:spdif`; the TV input of the
+    /// home theater player whose UUID is `uuid`
+    HomeTheater { uuid: PlayerUuid },
+    /// `x-rincon-buzzer:0`; the alarm chime
+    Buzzer,
+    /// `x-rincon-mp3radio:example.com/stream`; an internet radio
+    /// stream, where `url` is the http URL without its scheme
+    Mp3Radio { url: String },
+    /// `x-rincon-cpcontainer:<object id>?sid=..&flags=..&sn=..`;
+    /// a music service album or playlist
+    Container {
+        object_id: String,
+        params: ServiceParams,
+    },
+    /// `x-sonos-spotify:<item id>?sid=..&flags=..&sn=..`
+    Spotify {
+        item_id: String,
+        params: ServiceParams,
+    },
+    /// `x-sonosapi-<kind>:<item id>?sid=..&flags=..&sn=..`, where
+    /// `kind` is eg: `stream`, `radio`, `hls` or `hls-static`
+    SonosApi {
+        kind: String,
+        item_id: String,
+        params: ServiceParams,
+    },
+    /// Any other URI, such as an http URL or an `x-file-cifs:`
+    /// library track
+    Other(String),
+}
+
+impl SonosUri {
+    /// Returns the UUID of the player that the URI refers to,
+    /// for the variants that refer to a player
+    pub fn uuid(&self) -> Option<&PlayerUuid> {
+        match self {
+            Self::Group { uuid }
+            | Self::Queue { uuid }
+            | Self::LineIn { uuid }
+            | Self::HomeTheater { uuid } => Some(uuid),
+            _ => None,
+        }
+    }
+
+    /// Returns the music service parameters, for the variants
+    /// that refer to music service content
+    pub fn service_params(&self) -> Option<&ServiceParams> {
+        match self {
+            Self::Container { params, .. }
+            | Self::Spotify { params, .. }
+            | Self::SonosApi { params, .. } => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Returns the DIDL-Lite item id that accompanies the URI in its
+    /// metadata, for music service containers and radio stations
+    pub fn object_id(&self) -> Option<String> {
+        match self {
+            Self::Container { object_id, .. } => Some(object_id.clone()),
+            Self::SonosApi { kind, item_id, .. } if kind == "radio" => {
+                Some(format!("{RADIO_PREFIX}{item_id}"))
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_known(uri: &str) -> Option<Self> {
+        let (scheme, rest) = uri.split_once(':')?;
+        let with_params = || {
+            let (id, query) = rest.split_once('?').unwrap_or((rest, ""));
+            let params = if query.is_empty() {
+                ServiceParams::default()
+            } else {
+                ServiceParams::parse(query)?
+            };
+            Some((id.to_string(), params))
+        };
+
+        Some(match scheme {
+            "x-rincon" => Self::Group {
+                uuid: rest.parse().ok()?,
+            },
+            "x-rincon-queue" => Self::Queue {
+                uuid: rest.strip_suffix("#0")?.parse().ok()?,
+            },
+            "x-rincon-stream" => Self::LineIn {
+                uuid: rest.parse().ok()?,
+            },
+            "x-sonos-htastream" => Self::HomeTheater {
+                uuid: rest.strip_suffix(":spdif")?.parse().ok()?,
+            },
+            "x-rincon-buzzer" if rest == "0" => Self::Buzzer,
+            "x-rincon-mp3radio" => Self::Mp3Radio {
+                url: rest.to_string(),
+            },
+            "x-rincon-cpcontainer" => {
+                let (object_id, params) = with_params()?;
+                Self::Container { object_id, params }
+            }
+            "x-sonos-spotify" => {
+                let (item_id, params) = with_params()?;
+                Self::Spotify { item_id, params }
+            }
+            _ => {
+                let kind = scheme.strip_prefix("x-sonosapi-")?;
+                let (item_id, params) = with_params()?;
+                Self::SonosApi {
+                    kind: kind.to_string(),
+                    item_id,
+                    params,
+                }
+            }
+        })
+    }
+}
+
+impl FromStr for SonosUri {
+    type Err = Infallible;
+
+    fn from_str(uri: &str) -> std::result::Result<Self, Infallible> {
+        Ok(uri.into())
+    }
+}
+
+impl fmt::Display for SonosUri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Group { uuid } => write!(f, "x-rincon:{uuid}"),
+            Self::Queue { uuid } => write!(f, "x-rincon-queue:{uuid}#0"),
+            Self::LineIn { uuid } => write!(f, "x-rincon-stream:{uuid}"),
+            Self::HomeTheater { uuid } => write!(f, "x-sonos-htastream:{uuid}:spdif"),
+            Self::Buzzer => write!(f, "x-rincon-buzzer:0"),
+            Self::Mp3Radio { url } => write!(f, "x-rincon-mp3radio:{url}"),
+            Self::Container { object_id, params } => {
+                write!(f, "x-rincon-cpcontainer:{object_id}{params}")
+            }
+            Self::Spotify { item_id, params } => write!(f, "x-sonos-spotify:{item_id}{params}"),
+            Self::SonosApi {
+                kind,
+                item_id,
+                params,
+            } => write!(f, "x-sonosapi-{kind}:{item_id}{params}"),
+            Self::Other(uri) => write!(f, "{uri}"),
+        }
+    }
+}
+
+impl From<&str> for SonosUri {
+    fn from(uri: &str) -> Self {
+        match Self::parse_known(uri) {
+            Some(parsed) if parsed.to_string() == uri => parsed,
+            _ => Self::Other(uri.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SonosUri {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SonosUri {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.as_str().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cases = [
+            (
+                "x-rincon:RINCON_000E58000000001400",
+                SonosUri::Group {
+                    uuid: "RINCON_000E58000000001400".parse().unwrap(),
+                },
+            ),
+            (
+                "x-rincon-queue:RINCON_000E58000000001400#0",
+                SonosUri::Queue {
+                    uuid: "RINCON_000E58000000001400".parse().unwrap(),
+                },
+            ),
+            (
+                "x-rincon-stream:RINCON_000E58000000001400",
+                SonosUri::LineIn {
+                    uuid: "RINCON_000E58000000001400".parse().unwrap(),
+                },
+            ),
+            (
+                "x-sonos-htastream:RINCON_000E58000000001400:spdif",
+                SonosUri::HomeTheater {
+                    uuid: "RINCON_000E58000000001400".parse().unwrap(),
+                },
+            ),
+            ("x-rincon-buzzer:0", SonosUri::Buzzer),
+            (
+                "x-rincon-mp3radio:example.com/stream.mp3",
+                SonosUri::Mp3Radio {
+                    url: "example.com/stream.mp3".to_string(),
+                },
+            ),
+            (
+                "x-sonos-spotify:spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC?sid=9&flags=8224&sn=1",
+                SonosUri::Spotify {
+                    item_id: "spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC".to_string(),
+                    params: ServiceParams::new(9, 8224, 1),
+                },
+            ),
+            (
+                "x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0",
+                SonosUri::SonosApi {
+                    kind: "stream".to_string(),
+                    item_id: "s24940".to_string(),
+                    params: ServiceParams::new(254, 8224, 0),
+                },
+            ),
+            (
+                "x-sonosapi-hls-static:ALkSOiE?sid=284&flags=8232",
+                SonosUri::SonosApi {
+                    kind: "hls-static".to_string(),
+                    item_id: "ALkSOiE".to_string(),
+                    params: ServiceParams {
+                        sid: Some(284),
+                        flags: Some(8232),
+                        sn: None,
+                    },
+                },
+            ),
+            (
+                "x-rincon-cpcontainer:1006206cplaylist?sid=9&flags=8300&sn=3",
+                SonosUri::Container {
+                    object_id: "1006206cplaylist".to_string(),
+                    params: ServiceParams::new(9, 8300, 3),
+                },
+            ),
+            (
+                "x-file-cifs://nas/music/track.flac",
+                SonosUri::Other("x-file-cifs://nas/music/track.flac".to_string()),
+            ),
+            // Parameters that we don't model are preserved
+            // by falling back to Other
+            (
+                "x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0&x=1",
+                SonosUri::Other("x-sonosapi-stream:s24940?sid=254&flags=8224&sn=0&x=1".to_string()),
+            ),
+            (
+                "x-sonosapi-stream:s24940?sn=0&sid=254",
+                SonosUri::Other("x-sonosapi-stream:s24940?sn=0&sid=254".to_string()),
+            ),
+        ];
+
+        for (uri, expected) in cases {
+            let parsed: SonosUri = uri.into();
+            assert_eq!(parsed, expected, "{uri}");
+            assert_eq!(parsed.to_string(), uri);
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let uri = SonosUri::from("x-rincon:RINCON_000E58000000001400");
+        assert_eq!(
+            uri.uuid().map(PlayerUuid::as_str),
+            Some("RINCON_000E58000000001400")
+        );
+        assert_eq!(uri.service_params(), None);
+
+        let uri = SonosUri::from("x-sonosapi-radio:sonos%3ahits?sid=303&flags=8300&sn=5");
+        assert_eq!(uri.uuid(), None);
+        assert_eq!(
+            uri.service_params(),
+            Some(&ServiceParams::new(303, 8300, 5))
+        );
+        assert_eq!(uri.object_id().as_deref(), Some("100c206csonos%3ahits"));
+        assert_eq!(SonosUri::Buzzer.object_id(), None);
+    }
+}
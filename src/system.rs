@@ -0,0 +1,129 @@
+use crate::{Error, MetricsSink, MusicService, Result, ServiceRegistry, SmapiClient, SonosDevice};
+use std::sync::Arc;
+#[cfg(feature = "discovery")]
+use std::time::Duration;
+
+/// Represents the set of players that make up a household.
+/// State that is shared across the household, such as the list
+/// of available music services, is cached here.
+#[derive(Debug, Default)]
+pub struct SonosSystem {
+    devices: Vec<SonosDevice>,
+    services: Arc<ServiceRegistry>,
+}
+
+impl SonosSystem {
+    /// Creates a system from `devices`. The music services are
+    /// cached in the registry of the first device, so that they are
+    /// also shared with its `SonosDevice::music_service` lookups.
+    pub fn new(devices: Vec<SonosDevice>) -> Self {
+        let services = devices
+            .first()
+            .map(|device| device.services().clone())
+            .unwrap_or_default();
+        Self { devices, services }
+    }
+
+    /// Discovers the players on the network, waiting up to `timeout`
+    /// for them to respond
+    #[cfg(feature = "discovery")]
+    pub async fn discover(timeout: Duration) -> Result<Self> {
+        let mut rx = crate::discover(timeout).await?;
+        let mut devices: Vec<SonosDevice> = vec![];
+        while let Some(device) = rx.recv().await {
+            if !devices.iter().any(|d| d.url() == device.url()) {
+                devices.push(device);
+            }
+        }
+        Ok(Self::new(devices))
+    }
+
+    /// Reports the latency and outcome of the SOAP actions performed
+    /// by each of the devices in the system to `sink`
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.devices = self
+            .devices
+            .into_iter()
+            .map(|device| device.with_metrics(sink.clone()))
+            .collect();
+        self
+    }
+
+    /// Like `discover`, but returns the players found so far
+    /// as soon as `token` is cancelled
+    #[cfg(all(feature = "cancellation", feature = "discovery"))]
+    pub async fn discover_cancellable(
+        timeout: Duration,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<Self> {
+        let mut rx = crate::discover_cancellable(timeout, token).await?;
+        let mut devices: Vec<SonosDevice> = vec![];
+        while let Some(device) = rx.recv().await {
+            if !devices.iter().any(|d| d.url() == device.url()) {
+                devices.push(device);
+            }
+        }
+        Ok(Self::new(devices))
+    }
+
+    pub fn devices(&self) -> &[SonosDevice] {
+        &self.devices
+    }
+
+    /// Returns the player whose room name is `room_name`
+    pub async fn device_for_room(&self, room_name: &str) -> Result<&SonosDevice> {
+        for device in &self.devices {
+            if let Ok(name) = device.name().await {
+                if name == room_name {
+                    return Ok(device);
+                }
+            }
+        }
+        Err(Error::RoomNotFound(room_name.to_string()))
+    }
+
+    /// Returns the cache of music service descriptors.
+    /// Note that the cache is empty until it is loaded, either
+    /// explicitly via `refresh_music_services` or implicitly via
+    /// one of the lookup methods on `SonosSystem`.
+    pub fn services(&self) -> &ServiceRegistry {
+        &self.services
+    }
+
+    /// Reloads the list of available music services
+    pub async fn refresh_music_services(&self) -> Result<()> {
+        let device = self.devices.first().ok_or(Error::NoDevices)?;
+        self.services.refresh(device).await
+    }
+
+    async fn ensure_services_loaded(&self) -> Result<()> {
+        if !self.services.is_loaded() {
+            self.refresh_music_services().await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the music service identified by `service_id`
+    pub async fn music_service(&self, service_id: u32) -> Result<MusicService> {
+        self.ensure_services_loaded().await?;
+        self.services
+            .by_id(service_id)
+            .ok_or(Error::MusicServiceNotFound(service_id))
+    }
+
+    /// Returns the music service named `name`, ignoring case
+    pub async fn music_service_by_name(&self, name: &str) -> Result<MusicService> {
+        self.ensure_services_loaded().await?;
+        self.services
+            .by_name(name)
+            .ok_or_else(|| Error::MusicServiceNameNotFound(name.to_string()))
+    }
+
+    /// Returns an SMAPI client for the music service identified
+    /// by `service_id`
+    pub async fn smapi_client(&self, service_id: u32) -> Result<SmapiClient> {
+        let service = self.music_service(service_id).await?;
+        let device = self.devices.first().ok_or(Error::NoDevices)?;
+        device.smapi_client(&service).await
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{alarm_clock, AlarmClock, Error, Result, SonosDevice};
+use std::fmt;
+use std::str::FromStr;
+
+/// The players number their time zones from 0; no known firmware
+/// has more than this many
+const MAX_TIME_ZONE_INDEX: i32 = 128;
+
+/// Maps IANA time zone names to the POSIX TZ rule that the players
+/// report for the equivalent entry in their own time zone list.
+/// The players identify time zones by an index into that list, which
+/// is resolved at runtime by `SonosDevice::time_zone_index_for_rule`.
+pub const TIME_ZONE_RULES: &[(&str, &str)] = &[
+    ("Pacific/Honolulu", "HST10"),
+    ("America/Anchorage", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Los_Angeles", "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Phoenix", "MST7"),
+    ("America/Denver", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Chicago", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/New_York", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Halifax", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Sao_Paulo", "<-03>3"),
+    ("Atlantic/Reykjavik", "GMT0"),
+    ("Europe/London", "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Dublin", "IST-1GMT0,M10.5.0,M3.5.0/1"),
+    ("Europe/Lisbon", "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Europe/Paris", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Berlin", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Amsterdam", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Stockholm", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Helsinki", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Athens", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Moscow", "MSK-3"),
+    ("Asia/Dubai", "<+04>-4"),
+    ("Asia/Kolkata", "IST-5:30"),
+    ("Asia/Shanghai", "CST-8"),
+    ("Asia/Singapore", "<+08>-8"),
+    ("Asia/Tokyo", "JST-9"),
+    ("Australia/Perth", "AWST-8"),
+    ("Australia/Adelaide", "ACST-9:30ACDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Brisbane", "AEST-10"),
+    ("Australia/Sydney", "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Pacific/Auckland", "NZST-12NZDT,M9.5.0,M4.1.0/3"),
+];
+
+/// Returns the POSIX TZ rule for the IANA time zone `name`,
+/// if it is present in `TIME_ZONE_RULES`
+pub fn time_zone_rule_for_name(name: &str) -> Option<&'static str> {
+    TIME_ZONE_RULES
+        .iter()
+        .find(|(iana, _)| iana.eq_ignore_ascii_case(name))
+        .map(|(_, rule)| *rule)
+}
+
+/// The time zone configuration of the household
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeZoneSetting {
+    /// The index of the time zone in the list maintained by the player
+    pub index: i32,
+    /// Whether daylight saving time is applied automatically
+    pub auto_adjust_dst: bool,
+    /// The POSIX TZ rule for the time zone, eg: `GMT0BST,M3.5.0/1,M10.5.0`
+    pub rule: Option<String>,
+}
+
+/// How the time of day is displayed by the Sonos apps
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeFormat {
+    #[default]
+    TwelveHour,
+    TwentyFourHour,
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+impl fmt::Display for TimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TwelveHour => write!(f, "12H"),
+            Self::TwentyFourHour => write!(f, "24H"),
+            Self::Unspecified(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for TimeFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "12H" => Ok(Self::TwelveHour),
+            "24H" => Ok(Self::TwentyFourHour),
+            s => Ok(Self::Unspecified(s.to_string())),
+        }
+    }
+}
+
+/// The order in which the day, month and year are displayed
+/// by the Sonos apps
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateFormat {
+    DayMonthYear,
+    #[default]
+    MonthDayYear,
+    YearMonthDay,
+    /// Allows representing a value that was not known at the
+    /// time that this crate was written
+    Unspecified(String),
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DayMonthYear => write!(f, "DMY"),
+            Self::MonthDayYear => write!(f, "MDY"),
+            Self::YearMonthDay => write!(f, "YMD"),
+            Self::Unspecified(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for DateFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "DMY" => Ok(Self::DayMonthYear),
+            "MDY" => Ok(Self::MonthDayYear),
+            "YMD" => Ok(Self::YearMonthDay),
+            s => Ok(Self::Unspecified(s.to_string())),
+        }
+    }
+}
+
+impl SonosDevice {
+    /// Returns the time zone configuration of the household
+    pub async fn time_zone(&self) -> Result<TimeZoneSetting> {
+        let response = <Self as AlarmClock>::get_time_zone_and_rule(self).await?;
+        Ok(TimeZoneSetting {
+            index: response.index.ok_or(Error::MissingValue("Index"))?,
+            auto_adjust_dst: response.auto_adjust_dst.unwrap_or(true),
+            rule: response.current_time_zone.filter(|rule| !rule.is_empty()),
+        })
+    }
+
+    /// Sets the time zone of the household to the entry at `index`
+    /// in the list maintained by the player
+    pub async fn set_time_zone(&self, index: i32, auto_adjust_dst: bool) -> Result<()> {
+        <Self as AlarmClock>::set_time_zone(
+            self,
+            alarm_clock::SetTimeZoneRequest {
+                index,
+                auto_adjust_dst,
+            },
+        )
+        .await
+    }
+
+    /// Returns the POSIX TZ rule of the time zone at `index`, or
+    /// `Ok(None)` if the player has no such time zone
+    pub async fn time_zone_rule(&self, index: i32) -> Result<Option<String>> {
+        let result = <Self as AlarmClock>::get_time_zone_rule(
+            self,
+            alarm_clock::GetTimeZoneRuleRequest { index },
+        )
+        .await;
+        match result {
+            Ok(response) => Ok(response.time_zone.filter(|rule| !rule.is_empty())),
+            // The device reports a SOAP fault for an out of range index
+            Err(err) if err.is_soap_fault() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Searches the time zone list of the player for the first entry
+    /// whose POSIX TZ rule is `rule`
+    pub async fn time_zone_index_for_rule(&self, rule: &str) -> Result<Option<i32>> {
+        for index in 0..MAX_TIME_ZONE_INDEX {
+            match self.time_zone_rule(index).await? {
+                Some(candidate) if candidate == rule => return Ok(Some(index)),
+                Some(_) => {}
+                None => break,
+            }
+        }
+        Ok(None)
+    }
+
+    /// Sets the time zone of the household using its IANA name,
+    /// eg: `Europe/London`, with automatic daylight saving adjustment.
+    /// Returns `Error::UnknownTimeZone` if the name is not present in
+    /// `TIME_ZONE_RULES` or the player has no matching time zone.
+    pub async fn set_time_zone_by_name(&self, name: &str) -> Result<()> {
+        let rule = time_zone_rule_for_name(name)
+            .ok_or_else(|| Error::UnknownTimeZone(name.to_string()))?;
+        let index = self
+            .time_zone_index_for_rule(rule)
+            .await?
+            .ok_or_else(|| Error::UnknownTimeZone(name.to_string()))?;
+        self.set_time_zone(index, true).await
+    }
+
+    /// Returns the time and date display formats of the household
+    pub async fn time_format(&self) -> Result<(TimeFormat, DateFormat)> {
+        let response = <Self as AlarmClock>::get_format(self).await?;
+        Ok((
+            response.current_time_format.unwrap_or_default().parse()?,
+            response.current_date_format.unwrap_or_default().parse()?,
+        ))
+    }
+
+    /// Sets the time and date display formats of the household
+    pub async fn set_time_format(
+        &self,
+        time_format: &TimeFormat,
+        date_format: &DateFormat,
+    ) -> Result<()> {
+        <Self as AlarmClock>::set_format(
+            self,
+            alarm_clock::SetFormatRequest {
+                desired_time_format: time_format.to_string(),
+                desired_date_format: date_format.to_string(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_zone_rule_for_name() {
+        assert_eq!(
+            time_zone_rule_for_name("europe/london"),
+            Some("GMT0BST,M3.5.0/1,M10.5.0")
+        );
+        assert_eq!(time_zone_rule_for_name("Mars/Olympus_Mons"), None);
+    }
+
+    #[test]
+    fn test_formats() {
+        assert_eq!(
+            "24H".parse::<TimeFormat>().unwrap(),
+            TimeFormat::TwentyFourHour
+        );
+        assert_eq!(DateFormat::YearMonthDay.to_string(), "YMD");
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{Error, Result};
+use reqwest::ClientBuilder;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+#[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+compile_error!("the https feature requires either the rustls or the native-tls feature");
+
+/// The port on which the device serves its API over HTTPS
+pub(crate) const SECURE_PORT: u16 = 1443;
+
+/// The SHA-256 fingerprint of a device certificate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CertificateFingerprint(pub [u8; 32]);
+
+impl CertificateFingerprint {
+    pub fn of_certificate(der: &[u8]) -> Self {
+        Self(Sha256::digest(der).into())
+    }
+}
+
+impl std::fmt::Display for CertificateFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (idx, b) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ":")?;
+            }
+            write!(f, "{b:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a hex encoded fingerprint, optionally separated by colons,
+/// as printed by `openssl x509 -fingerprint -sha256`
+impl FromStr for CertificateFingerprint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let digits: Vec<u8> = s.bytes().filter(|&b| b != b':').collect();
+        let invalid = || Error::InvalidFingerprint(s.to_string());
+        if digits.len() != 64 {
+            return Err(invalid());
+        }
+        let mut result = [0u8; 32];
+        for (idx, pair) in digits.chunks(2).enumerate() {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            result[idx] = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(result))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CertificateFingerprint {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CertificateFingerprint {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Configures `builder` to accept the self-signed certificate
+/// presented by the device, subject to any pinned fingerprints
+#[cfg(feature = "rustls")]
+pub(crate) fn configure(
+    builder: ClientBuilder,
+    pinned: &[CertificateFingerprint],
+) -> Result<ClientBuilder> {
+    Ok(builder.use_preconfigured_tls(device_verifier::client_config(pinned)?))
+}
+
+/// Configures `builder` to accept the self-signed certificate
+/// presented by the device. native-tls offers no way to inspect the
+/// certificate during the handshake, so pinning requires rustls.
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+pub(crate) fn configure(
+    builder: ClientBuilder,
+    pinned: &[CertificateFingerprint],
+) -> Result<ClientBuilder> {
+    if !pinned.is_empty() {
+        return Err(Error::NotSupported("Certificate pinning with native-tls"));
+    }
+    Ok(builder.danger_accept_invalid_certs(true))
+}
+
+#[cfg(feature = "rustls")]
+mod device_verifier {
+    use super::CertificateFingerprint;
+    use crate::Result;
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+    use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+    use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+    use std::sync::Arc;
+
+    /// Sonos devices present a self-signed certificate, so it cannot be
+    /// verified against a certificate authority. This verifier accepts
+    /// any certificate, unless fingerprints have been pinned, in which
+    /// case the certificate must match one of them.
+    #[derive(Debug)]
+    struct DeviceCertVerifier {
+        pinned: Vec<CertificateFingerprint>,
+        provider: Arc<CryptoProvider>,
+    }
+
+    impl ServerCertVerifier for DeviceCertVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8],
+            _now: UnixTime,
+        ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+            if self.pinned.is_empty() {
+                return Ok(ServerCertVerified::assertion());
+            }
+            let fingerprint = CertificateFingerprint::of_certificate(end_entity);
+            if self.pinned.contains(&fingerprint) {
+                Ok(ServerCertVerified::assertion())
+            } else {
+                Err(rustls::Error::General(format!(
+                    "certificate fingerprint {fingerprint} is not pinned"
+                )))
+            }
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls12_signature(
+                message,
+                cert,
+                dss,
+                &self.provider.signature_verification_algorithms,
+            )
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls13_signature(
+                message,
+                cert,
+                dss,
+                &self.provider.signature_verification_algorithms,
+            )
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.provider
+                .signature_verification_algorithms
+                .supported_schemes()
+        }
+    }
+
+    /// Builds the TLS configuration used to talk to devices
+    pub fn client_config(pinned: &[CertificateFingerprint]) -> Result<ClientConfig> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        Ok(ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(DeviceCertVerifier {
+                pinned: pinned.to_vec(),
+                provider,
+            }))
+            .with_no_client_auth())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint() {
+        let text = "00:11:22:33:44:55:66:77:88:99:AA:BB:CC:DD:EE:FF:00:11:22:33:44:55:66:77:88:99:AA:BB:CC:DD:EE:FF";
+        let fingerprint: CertificateFingerprint = text.parse().unwrap();
+        assert_eq!(fingerprint.0[10], 0xaa);
+        assert_eq!(fingerprint.to_string(), text);
+        assert_eq!(
+            text.replace(':', "")
+                .to_lowercase()
+                .parse::<CertificateFingerprint>()
+                .unwrap(),
+            fingerprint
+        );
+        assert!("00:11".parse::<CertificateFingerprint>().is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{rendering_control, InstanceId, RenderingControl, Result, SonosDevice};
+
+/// The Trueplay room calibration state of a player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrueplayStatus {
+    /// true if the player has been tuned, so that a
+    /// calibration is available to be applied
+    pub available: bool,
+    /// true if the calibration is currently being applied
+    pub enabled: bool,
+}
+
+impl SonosDevice {
+    /// Returns the Trueplay calibration state of the player
+    pub async fn trueplay_status(&self) -> Result<TrueplayStatus> {
+        let status = <Self as RenderingControl>::get_room_calibration_status(
+            self,
+            rendering_control::GetRoomCalibrationStatusRequest {
+                instance_id: InstanceId::DEFAULT,
+            },
+        )
+        .await?;
+        Ok(TrueplayStatus {
+            available: status.room_calibration_available.unwrap_or(false),
+            enabled: status.room_calibration_enabled.unwrap_or(false),
+        })
+    }
+
+    /// Enables or disables the Trueplay calibration of the player.
+    /// This has no effect unless a calibration is available.
+    pub async fn set_trueplay_enabled(&self, enabled: bool) -> Result<()> {
+        <Self as RenderingControl>::set_room_calibration_status(
+            self,
+            rendering_control::SetRoomCalibrationStatusRequest {
+                instance_id: InstanceId::DEFAULT,
+                room_calibration_enabled: enabled,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "vcr"))]
+mod test {
+    use super::*;
+    use crate::vcr::{replay_device, Exchange};
+
+    const CONTROL: &str = "/MediaRenderer/RenderingControl/Control";
+
+    #[tokio::test]
+    async fn test_trueplay() {
+        let (device, client) = replay_device(vec![
+            Exchange::upnp(
+                CONTROL,
+                "RenderingControl",
+                "GetRoomCalibrationStatus",
+                "<RoomCalibrationEnabled>0</RoomCalibrationEnabled><RoomCalibrationAvailable>1</RoomCalibrationAvailable>",
+            ),
+            Exchange::upnp(CONTROL, "RenderingControl", "SetRoomCalibrationStatus", "").request(
+                rendering_control::SetRoomCalibrationStatusRequest {
+                    instance_id: InstanceId::DEFAULT,
+                    room_calibration_enabled: true,
+                },
+            ),
+            // Players that have never been tuned omit the state
+            Exchange::upnp(CONTROL, "RenderingControl", "GetRoomCalibrationStatus", ""),
+        ])
+        .await;
+
+        assert_eq!(
+            device.trueplay_status().await.unwrap(),
+            TrueplayStatus {
+                available: true,
+                enabled: false,
+            }
+        );
+        device.set_trueplay_enabled(true).await.unwrap();
+        assert_eq!(
+            device.trueplay_status().await.unwrap(),
+            TrueplayStatus::default()
+        );
+        assert_eq!(client.unused(), vec![]);
+    }
+}
@@ -1,11 +1,10 @@
-use crate::Error;
 use instant_xml::FromXml;
-use reqwest::{Method, Response, Url};
-use std::net::IpAddr;
-use tokio::io::AsyncReadExt;
-use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::mpsc::{channel, Receiver, Sender};
-use url::Host;
+use url::Url;
+
+#[cfg(feature = "events")]
+mod events;
+#[cfg(feature = "events")]
+pub use events::*;
 
 const UPNP_DEVICE: &str = "urn:schemas-upnp-org:device-1-0";
 
@@ -121,202 +120,6 @@ impl Service {
     pub fn scpd_url(&self, url: &Url) -> Url {
         self.join_url(url, &self.scpd_url)
     }
-
-    pub async fn subscribe<T: DecodeXml + 'static>(
-        &self,
-        url: &Url,
-    ) -> crate::Result<EventStream<T>> {
-        let sub_url = self.event_sub_url(url);
-
-        // Figure out an appropriate local address to talk to
-        // this device
-        let host = url
-            .host()
-            .ok_or_else(|| Error::NoIpInDeviceUrl(url.clone()))?;
-        let ip: IpAddr = match host {
-            Host::Domain(_s) => return Err(Error::NoIpInDeviceUrl(url.clone())),
-            Host::Ipv4(v4) => v4.into(),
-            Host::Ipv6(v6) => v6.into(),
-        };
-
-        let probe = TcpStream::connect((ip, url.port().unwrap_or(80))).await?;
-        let listener = TcpListener::bind((probe.local_addr()?.ip(), 0)).await?;
-        let local = listener.local_addr()?;
-
-        let response = reqwest::Client::new()
-            .request(
-                Method::from_bytes(b"SUBSCRIBE").expect("SUBSCRIBE to be a valid method"),
-                sub_url.clone(),
-            )
-            .header("CALLBACK", format!("<http://{local}>"))
-            .header("NT", "upnp:event")
-            .header("TIMEOUT", format!("Second-{SUBSCRIPTION_TIMEOUT}"))
-            .send()
-            .await?;
-
-        let response = Error::check_response(response).await?;
-
-        log::trace!("response: {response:?}");
-
-        let sid = response
-            .headers()
-            .get("sid")
-            .ok_or(Error::SubscriptionFailedNoSid)?
-            .to_str()
-            .map_err(|_| Error::SubscriptionFailedNoSid)?
-            .to_string();
-
-        let body = response.text().await?;
-        log::trace!("Got response: {body}");
-
-        let (tx, rx) = channel(16);
-        {
-            let sid = sid.clone();
-            let sub_url = sub_url.clone();
-            tokio::spawn(async move { process_subscription(listener, tx, sid, sub_url).await });
-        }
-
-        Ok(EventStream { sid, rx, sub_url })
-    }
-}
-
-const SUBSCRIPTION_TIMEOUT: u64 = 60;
-
-async fn process_subscription<T: DecodeXml + 'static>(
-    listener: TcpListener,
-    tx: Sender<SubscriptionMessage<T>>,
-    sid: String,
-    sub_url: Url,
-) -> crate::Result<()> {
-    let mut deadline =
-        tokio::time::Instant::now() + tokio::time::Duration::from_secs(SUBSCRIPTION_TIMEOUT - 10);
-    loop {
-        match tokio::time::timeout_at(deadline, listener.accept()).await {
-            Ok(Ok((client, _addr))) => {
-                let tx = tx.clone();
-                tokio::spawn(async move { handle_subscription_request(client, tx).await });
-            }
-            Ok(Err(err)) => {
-                log::error!("accept failed: {err:#}");
-                return Ok(());
-            }
-            Err(_) => {
-                log::debug!("time to renew!");
-                // Time to renew subscription
-                let renew = match tx.try_send(SubscriptionMessage::Ping) {
-                    Ok(_) | Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => true,
-                    Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
-                        // It's dead; don't bother renewing
-                        false
-                    }
-                };
-
-                renew_or_cancel_sub(&sub_url, renew, &sid).await?;
-
-                if renew {
-                    deadline = tokio::time::Instant::now()
-                        + tokio::time::Duration::from_secs(SUBSCRIPTION_TIMEOUT - 10);
-                } else {
-                    return Ok(());
-                }
-            }
-        }
-    }
-}
-
-async fn handle_subscription_request<T: DecodeXml>(
-    mut client: TcpStream,
-    tx: Sender<SubscriptionMessage<T>>,
-) -> crate::Result<()> {
-    let mut reqbuf = vec![];
-    let mut buf = [0u8; 4096];
-
-    while let Ok(len) = client.read(&mut buf).await {
-        reqbuf.extend_from_slice(&buf[0..len]);
-
-        let mut headers = [httparse::EMPTY_HEADER; 16];
-        let mut req = httparse::Request::new(&mut headers);
-
-        match req.parse(&reqbuf) {
-            Err(err) => {
-                log::error!("Error parsing request: {err:#}");
-                break;
-            }
-            Ok(httparse::Status::Partial) => continue,
-            Ok(httparse::Status::Complete(body_start)) => {
-                // It's only *maybe* complete; check the content-length
-                // vs. the data in the buffer
-                if let Some(cl) = req
-                    .headers
-                    .iter()
-                    .find(|h| h.name.eq_ignore_ascii_case("Content-Length"))
-                {
-                    match std::str::from_utf8(cl.value)
-                        .ok()
-                        .and_then(|s| s.parse::<usize>().ok())
-                    {
-                        Some(cl) => {
-                            let avail = reqbuf.len() - body_start;
-                            if avail < cl {
-                                // We need more data
-                                continue;
-                            }
-                        }
-                        None => {
-                            log::error!("Invalid header: {cl:?}");
-                            break;
-                        }
-                    }
-                }
-                let body = String::from_utf8_lossy(&reqbuf[body_start..]).to_string();
-
-                log::trace!("{req:#?}");
-                log::trace!("{body}");
-
-                match T::decode_xml(&body) {
-                    Ok(event) => {
-                        if let Err(err) = tx.send(SubscriptionMessage::Event(event)).await {
-                            log::error!("Channel is dead {err:#}");
-                            return Ok(());
-                        }
-                    }
-                    Err(err) => {
-                        log::error!("Failed to parse PropertySet: {err:#} from {body}");
-                    }
-                }
-
-                break;
-            }
-        }
-    }
-    Ok(())
-}
-
-async fn renew_or_cancel_sub(sub_url: &Url, subscribe: bool, sid: &str) -> crate::Result<Response> {
-    let mut request = reqwest::Client::new()
-        .request(
-            Method::from_bytes(if subscribe {
-                b"SUBSCRIBE"
-            } else {
-                b"UNSUBSCRIBE"
-            })
-            .expect("SUBSCRIBE to be a valid method"),
-            sub_url.clone(),
-        )
-        .header("SID", sid);
-    if subscribe {
-        request = request.header("TIMEOUT", format!("Second-{SUBSCRIPTION_TIMEOUT}"));
-    }
-    let response = request.send().await?;
-
-    let response = Error::check_response(response).await?;
-
-    Ok(response)
-}
-
-enum SubscriptionMessage<T> {
-    Ping,
-    Event(T),
 }
 
 /// A helper trait for parsing a uPNP event stream into
@@ -332,43 +135,7 @@ pub trait EncodeXml {
     fn encode_xml(&self) -> std::result::Result<String, instant_xml::Error>;
 }
 
-/// Manages a live subscription to an event stream for a service.
-/// While this object is live, the event stream will be renewed
-/// every minute.
-/// The stream isn't automatically cancelled on Drop because there
-/// is no async-Drop, but you can call the `unsubscribe` method
-/// to explicitly cancel it.
-/// The stream dispatching machinery has liveness checking that will ping
-/// the internal receiver and will cancel the subscription after about
-/// a minute or so of the EventStream being dropped.
-pub struct EventStream<T: DecodeXml> {
-    rx: Receiver<SubscriptionMessage<T>>,
-    sid: String,
-    sub_url: Url,
-}
-
-impl<T: DecodeXml> EventStream<T> {
-    /// Receives the next event from the stream
-    pub async fn recv(&mut self) -> Option<T> {
-        loop {
-            let msg = self.rx.recv().await?;
-            match msg {
-                SubscriptionMessage::Ping => {}
-                SubscriptionMessage::Event(v) => {
-                    return Some(v);
-                }
-            }
-        }
-    }
-
-    /// Explicitly cancel the subscription
-    pub async fn unsubscribe(self) {
-        renew_or_cancel_sub(&self.sub_url, false, &self.sid)
-            .await
-            .ok();
-    }
-}
-
+#[cfg_attr(not(feature = "svc-zone-group-topology"), allow(dead_code))]
 pub(crate) const UPNP_EVENT: &str = "urn:schemas-upnp-org:event-1-0";
 
 #[cfg(test)]
@@ -0,0 +1,295 @@
+//! Subscriptions to the events of a service, which the device
+//! delivers by making HTTP requests to a listener that we run
+use super::{DecodeXml, Service};
+use crate::http_client::SharedHttpClient;
+use crate::http_server::read_request;
+use crate::runtime::SharedRuntime;
+use crate::{AsyncTcpListener, AsyncTcpStream, Error, HttpRequest, HttpResponse};
+use http::Method;
+use std::net::{IpAddr, SocketAddr};
+#[cfg(feature = "reqwest")]
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use url::{Host, Url};
+
+impl Service {
+    #[cfg(feature = "reqwest")]
+    pub async fn subscribe<T: DecodeXml + 'static>(
+        &self,
+        url: &Url,
+    ) -> crate::Result<EventStream<T>> {
+        self.subscribe_with_client(
+            &SharedHttpClient::new(Arc::new(reqwest::Client::new())),
+            &SharedRuntime::default_runtime()?,
+            url,
+        )
+        .await
+    }
+
+    /// Subscribes to the event stream, using `client` to make the
+    /// subscription requests and `runtime` to receive the events
+    pub(crate) async fn subscribe_with_client<T: DecodeXml + 'static>(
+        &self,
+        client: &SharedHttpClient,
+        runtime: &SharedRuntime,
+        url: &Url,
+    ) -> crate::Result<EventStream<T>> {
+        let sub_url = self.event_sub_url(url);
+
+        // Figure out an appropriate local address to talk to
+        // this device
+        let host = url
+            .host()
+            .ok_or_else(|| Error::NoIpInDeviceUrl(url.clone()))?;
+        let ip: IpAddr = match host {
+            Host::Domain(_s) => return Err(Error::NoIpInDeviceUrl(url.clone())),
+            Host::Ipv4(v4) => v4.into(),
+            Host::Ipv6(v6) => v6.into(),
+        };
+
+        let probe = runtime
+            .connect_tcp(SocketAddr::from((ip, url.port().unwrap_or(80))))
+            .await?;
+        let listener = runtime
+            .bind_tcp(SocketAddr::from((probe.local_addr()?.ip(), 0)))
+            .await?;
+        let local = listener.local_addr()?;
+
+        let request = HttpRequest::new(
+            Method::from_bytes(b"SUBSCRIBE").expect("SUBSCRIBE to be a valid method"),
+            sub_url.clone(),
+        )
+        .header("callback", &format!("<http://{local}>"))?
+        .header("nt", "upnp:event")?
+        .header("timeout", &format!("Second-{SUBSCRIPTION_TIMEOUT}"))?;
+        let response = client.send(request).await?.check()?;
+
+        log::trace!("response: {response:?}");
+
+        let sid = response
+            .headers
+            .get("sid")
+            .ok_or(Error::SubscriptionFailedNoSid)?
+            .to_str()
+            .map_err(|_| Error::SubscriptionFailedNoSid)?
+            .to_string();
+
+        log::trace!("Got response: {}", response.body);
+
+        let (tx, rx) = channel(16);
+        {
+            let sid = sid.clone();
+            let sub_url = sub_url.clone();
+            let client = client.clone();
+            let task_runtime = runtime.clone();
+            runtime.spawn(Box::pin(async move {
+                process_subscription(client, task_runtime, listener, tx, sid, sub_url)
+                    .await
+                    .ok();
+            }));
+        }
+
+        Ok(EventStream {
+            client: client.clone(),
+            sid,
+            rx,
+            sub_url,
+        })
+    }
+}
+
+const SUBSCRIPTION_TIMEOUT: u64 = 60;
+
+/// How long after subscribing that the subscription is renewed
+const RENEWAL_INTERVAL: Duration = Duration::from_secs(SUBSCRIPTION_TIMEOUT - 10);
+
+async fn process_subscription<T: DecodeXml + 'static>(
+    client: SharedHttpClient,
+    runtime: SharedRuntime,
+    listener: Box<dyn AsyncTcpListener>,
+    tx: Sender<SubscriptionMessage<T>>,
+    sid: String,
+    sub_url: Url,
+) -> crate::Result<()> {
+    let mut deadline = Instant::now() + RENEWAL_INTERVAL;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match runtime.timeout(remaining, listener.accept()).await {
+            Some(Ok(client)) => {
+                let tx = tx.clone();
+                runtime.spawn(Box::pin(async move {
+                    handle_subscription_request(client, tx).await.ok();
+                }));
+            }
+            Some(Err(err)) => {
+                log::error!("accept failed: {err:#}");
+                return Ok(());
+            }
+            None => {
+                log::debug!("time to renew!");
+                // Time to renew subscription
+                let renew = match tx.try_send(SubscriptionMessage::Ping) {
+                    Ok(_) | Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => true,
+                    Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                        // It's dead; don't bother renewing
+                        false
+                    }
+                };
+
+                renew_or_cancel_sub(&client, &sub_url, renew, &sid).await?;
+
+                if renew {
+                    deadline = Instant::now() + RENEWAL_INTERVAL;
+                } else {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+async fn handle_subscription_request<T: DecodeXml>(
+    mut client: Box<dyn AsyncTcpStream>,
+    tx: Sender<SubscriptionMessage<T>>,
+) -> crate::Result<()> {
+    let request = match read_request(client.as_mut()).await {
+        Ok(Some(request)) => request,
+        Ok(None) => return Ok(()),
+        Err(err) => {
+            log::error!("Error reading request: {err:#}");
+            return Ok(());
+        }
+    };
+    let body = String::from_utf8_lossy(&request.body).to_string();
+
+    log::trace!("{} {} {:#?}", request.method, request.path, request.headers);
+    log::trace!("{body}");
+
+    client
+        .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+        .await
+        .ok();
+
+    match T::decode_xml(&body) {
+        Ok(event) => {
+            if let Err(err) = tx.send(SubscriptionMessage::Event(event)).await {
+                log::error!("Channel is dead {err:#}");
+            }
+        }
+        Err(err) => {
+            log::error!("Failed to parse PropertySet: {err:#} from {body}");
+        }
+    }
+    Ok(())
+}
+
+async fn renew_or_cancel_sub(
+    client: &SharedHttpClient,
+    sub_url: &Url,
+    subscribe: bool,
+    sid: &str,
+) -> crate::Result<HttpResponse> {
+    let mut request = HttpRequest::new(
+        Method::from_bytes(if subscribe {
+            b"SUBSCRIBE"
+        } else {
+            b"UNSUBSCRIBE"
+        })
+        .expect("SUBSCRIBE to be a valid method"),
+        sub_url.clone(),
+    )
+    .header("sid", sid)?;
+    if subscribe {
+        request = request.header("timeout", &format!("Second-{SUBSCRIPTION_TIMEOUT}"))?;
+    }
+    client.send(request).await?.check()
+}
+
+enum SubscriptionMessage<T> {
+    Ping,
+    Event(T),
+}
+
+/// Manages a live subscription to an event stream for a service.
+/// While this object is live, the event stream will be renewed
+/// every minute.
+/// The stream isn't automatically cancelled on Drop because there
+/// is no async-Drop, but you can call the `unsubscribe` method
+/// to explicitly cancel it.
+/// The stream dispatching machinery has liveness checking that will ping
+/// the internal receiver and will cancel the subscription after about
+/// a minute or so of the EventStream being dropped.
+pub struct EventStream<T: DecodeXml> {
+    client: SharedHttpClient,
+    rx: Receiver<SubscriptionMessage<T>>,
+    sid: String,
+    sub_url: Url,
+}
+
+impl<T: DecodeXml> EventStream<T> {
+    /// Receives the next event from the stream.
+    /// This method is cancel safe: if the future is dropped before
+    /// it completes, no event is lost.
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            let msg = self.rx.recv().await?;
+            match msg {
+                SubscriptionMessage::Ping => {}
+                SubscriptionMessage::Event(v) => {
+                    return Some(v);
+                }
+            }
+        }
+    }
+
+    /// Like `recv`, but returns `Error::Cancelled` as soon as `token`
+    /// is cancelled. As `recv` is cancel safe, no event is lost.
+    #[cfg(feature = "cancellation")]
+    pub async fn recv_cancellable(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> crate::Result<Option<T>> {
+        crate::cancel::until_cancelled(self.recv(), token.cancelled())
+            .await
+            .ok_or(Error::Cancelled)
+    }
+
+    /// Explicitly cancel the subscription
+    pub async fn unsubscribe(self) {
+        renew_or_cancel_sub(&self.client, &self.sub_url, false, &self.sid)
+            .await
+            .ok();
+    }
+}
+
+#[cfg(feature = "tokio-runtime")]
+impl<T: DecodeXml + Send + 'static> EventStream<T> {
+    /// Spawns a task that sends each event, converted by `wrap`, to `tx`
+    /// until either end closes
+    #[cfg_attr(
+        not(any(
+            feature = "mqtt",
+            feature = "rest",
+            feature = "uniffi",
+            feature = "webhooks",
+            feature = "prometheus",
+            all(feature = "mpris", target_os = "linux")
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn forward<U: Send + 'static>(
+        mut self,
+        tx: &tokio::sync::mpsc::UnboundedSender<U>,
+        wrap: impl Fn(T) -> U + Send + 'static,
+    ) -> tokio::task::JoinHandle<()> {
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            while let Some(event) = self.recv().await {
+                if tx.send(wrap(event)).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{Error, HttpClient, HttpFuture, HttpRequest, HttpResponse, Result};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// A recorded HTTP request and its response
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Exchange {
+    pub method: String,
+    /// The path and query of the URL. The host is not recorded,
+    /// so that fixtures can be replayed against any address.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soap_action: Option<String>,
+    /// An empty body matches any request body, which makes
+    /// hand written fixtures easier to maintain
+    #[serde(default)]
+    pub request_body: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_headers: Vec<(String, String)>,
+    pub response_body: String,
+}
+
+impl Exchange {
+    fn matches(&self, request: &HttpRequest) -> bool {
+        self.method == request.method.as_str()
+            && self.path == path_of(request)
+            && self.soap_action.as_deref() == soap_action_of(request)
+            && (self.request_body.is_empty() || self.request_body == request.body)
+    }
+
+    fn to_response(&self) -> Result<HttpResponse> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.response_headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|err| Error::Http(Box::new(err)))?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(self.status).map_err(|err| Error::Http(Box::new(err)))?,
+            headers,
+            body: self.response_body.clone(),
+        })
+    }
+}
+
+fn path_of(request: &HttpRequest) -> String {
+    match request.url.query() {
+        Some(query) => format!("{}?{query}", request.url.path()),
+        None => request.url.path().to_string(),
+    }
+}
+
+fn soap_action_of(request: &HttpRequest) -> Option<&str> {
+    request
+        .headers
+        .get("soapaction")
+        .and_then(|value| value.to_str().ok())
+}
+
+/// An `HttpClient` that passes requests through to another client
+/// and records the exchanges to a fixture file, which can later be
+/// replayed by `ReplayClient`.
+/// The file is rewritten after each exchange.
+pub struct RecordingClient {
+    inner: Arc<dyn HttpClient>,
+    path: PathBuf,
+    exchanges: Mutex<Vec<Exchange>>,
+}
+
+impl RecordingClient {
+    pub fn new(inner: Arc<dyn HttpClient>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            exchanges: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn exchanges(&self) -> Vec<Exchange> {
+        self.exchanges.lock().unwrap().clone()
+    }
+
+    fn record(&self, exchange: Exchange) -> Result<()> {
+        let mut exchanges = self.exchanges.lock().unwrap();
+        exchanges.push(exchange);
+        std::fs::write(&self.path, serde_json::to_string_pretty(&*exchanges)?)?;
+        Ok(())
+    }
+}
+
+impl HttpClient for RecordingClient {
+    fn send(&self, request: HttpRequest) -> HttpFuture<'_> {
+        Box::pin(async move {
+            let method = request.method.to_string();
+            let path = path_of(&request);
+            let soap_action = soap_action_of(&request).map(|s| s.to_string());
+            let request_body = request.body.clone();
+
+            let response = self.inner.send(request).await?;
+            self.record(Exchange {
+                method,
+                path,
+                soap_action,
+                request_body,
+                status: response.status.as_u16(),
+                response_headers: response
+                    .headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect(),
+                response_body: response.body.clone(),
+            })?;
+            Ok(response)
+        })
+    }
+}
+
+/// An `HttpClient` that answers requests from recorded exchanges,
+/// allowing code that talks to a device to be tested without one.
+/// Each request is answered by the first exchange that matches its
+/// method, path, SOAPAction and body and that has not already been
+/// used, so repeated requests replay in the order they were recorded.
+/// Requests for which there is no such exchange fail with
+/// `Error::NoRecordedExchange`.
+pub struct ReplayClient {
+    exchanges: Mutex<Vec<(Exchange, bool)>>,
+}
+
+impl ReplayClient {
+    pub fn new(exchanges: Vec<Exchange>) -> Self {
+        Self {
+            exchanges: Mutex::new(exchanges.into_iter().map(|e| (e, false)).collect()),
+        }
+    }
+
+    /// Loads a fixture file written by `RecordingClient`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(Self::new(serde_json::from_str(&json)?))
+    }
+
+    /// Returns the exchanges that have not been replayed
+    pub fn unused(&self) -> Vec<Exchange> {
+        self.exchanges
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, used)| !used)
+            .map(|(exchange, _)| exchange.clone())
+            .collect()
+    }
+}
+
+impl HttpClient for ReplayClient {
+    fn send(&self, request: HttpRequest) -> HttpFuture<'_> {
+        Box::pin(async move {
+            let mut exchanges = self.exchanges.lock().unwrap();
+            let (exchange, used) = exchanges
+                .iter_mut()
+                .find(|(exchange, used)| !used && exchange.matches(&request))
+                .ok_or_else(|| {
+                    Error::NoRecordedExchange(format!(
+                        "{} {} {}",
+                        request.method,
+                        path_of(&request),
+                        soap_action_of(&request).unwrap_or_default()
+                    ))
+                })?;
+            *used = true;
+            exchange.to_response()
+        })
+    }
+}
+
+#[cfg(test)]
+impl Exchange {
+    /// Returns an exchange that answers `action` of the UPnP `service`
+    /// whose control URL is `path`, with a response carrying `args`
+    pub(crate) fn upnp(path: &str, service: &str, action: &str, args: &str) -> Self {
+        Self {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            soap_action: Some(format!(
+                "\"urn:schemas-upnp-org:service:{service}:1#{action}\""
+            )),
+            status: 200,
+            response_body: format!(
+                r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:{action}Response xmlns:u="urn:schemas-upnp-org:service:{service}:1">{args}</u:{action}Response></s:Body></s:Envelope>"#
+            ),
+            ..Default::default()
+        }
+    }
+
+    /// Only matches a request that carries `payload`
+    pub(crate) fn request<T: instant_xml::ToXml>(mut self, payload: T) -> Self {
+        self.request_body = instant_xml::to_string(&crate::soap::Envelope {
+            encoding_style: crate::SOAP_ENCODING,
+            body: crate::soap::Body { payload },
+        })
+        .unwrap();
+        self
+    }
+
+    /// Returns an exchange that answers `action` of the UPnP `service`
+    /// whose control URL is `path` with a fault carrying `error_code`
+    pub(crate) fn upnp_fault(path: &str, service: &str, action: &str, error_code: u32) -> Self {
+        Self {
+            status: 500,
+            response_body: format!(
+                r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><s:Fault><faultcode>s:Client</faultcode><faultstring>UPnPError</faultstring><detail><UPnPError xmlns="urn:schemas-upnp-org:control-1-0"><errorCode>{error_code}</errorCode></UPnPError></detail></s:Fault></s:Body></s:Envelope>"#
+            ),
+            ..Self::upnp(path, service, action, "")
+        }
+    }
+}
+
+/// Returns the device described by `data/device_spec.xml`, a Port,
+/// whose subsequent requests are answered by `exchanges`
+#[cfg(test)]
+pub(crate) async fn replay_device(
+    exchanges: Vec<Exchange>,
+) -> (crate::SonosDevice, Arc<ReplayClient>) {
+    let description = Exchange {
+        method: "GET".to_string(),
+        path: "/xml/device_description.xml".to_string(),
+        status: 200,
+        response_body: include_str!("../data/device_spec.xml").to_string(),
+        ..Default::default()
+    };
+    let client = Arc::new(ReplayClient::new(
+        std::iter::once(description).chain(exchanges).collect(),
+    ));
+    let device = crate::SonosDevice::builder()
+        .http_client(client.clone())
+        .from_url(
+            "http://192.168.1.2:1400/xml/device_description.xml"
+                .parse()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    (device, client)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SonosDevice;
+
+    #[tokio::test]
+    #[cfg(feature = "svc-rendering-control")]
+    async fn test_replay() {
+        let client = ReplayClient::new(vec![
+            Exchange {
+                method: "GET".to_string(),
+                path: "/xml/device_description.xml".to_string(),
+                status: 200,
+                response_body: include_str!("../data/device_spec.xml").to_string(),
+                ..Default::default()
+            },
+            Exchange {
+                method: "POST".to_string(),
+                path: "/MediaRenderer/RenderingControl/Control".to_string(),
+                soap_action: Some(
+                    "\"urn:schemas-upnp-org:service:RenderingControl:1#GetVolume\"".to_string(),
+                ),
+                status: 200,
+                response_body: r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"><CurrentVolume>17</CurrentVolume></u:GetVolumeResponse></s:Body></s:Envelope>"#.to_string(),
+                ..Default::default()
+            },
+        ]);
+        let client = Arc::new(client);
+
+        let device = SonosDevice::builder()
+            .http_client(client.clone())
+            .from_url(
+                "http://192.168.1.2:1400/xml/device_description.xml"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(device.get_volume().await.unwrap().get(), 17);
+        assert_eq!(client.unused(), vec![]);
+
+        assert!(matches!(
+            device.get_volume().await.map_err(Error::into_inner),
+            Err(Error::NoRecordedExchange(_))
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Error, Volume};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+impl Volume {
+    /// Returns the volume that is `delta` steps louder,
+    /// clamped to `Volume::MAX`
+    pub fn saturating_add(self, delta: u16) -> Self {
+        Self::saturating(self.get().saturating_add(delta))
+    }
+
+    /// Returns the volume that is `delta` steps quieter,
+    /// clamped to `Volume::MIN`
+    pub fn saturating_sub(self, delta: u16) -> Self {
+        Self::saturating(self.get().saturating_sub(delta))
+    }
+
+    /// Returns the volume adjusted by `delta`, which may be negative,
+    /// clamped to the range `Volume::MIN`-`Volume::MAX`
+    pub fn saturating_add_signed(self, delta: i16) -> Self {
+        Self::saturating(self.get().saturating_add_signed(delta))
+    }
+
+    /// Returns the volume as a fraction of `Volume::MAX`,
+    /// in the range 0.0-1.0
+    pub fn fraction(self) -> f32 {
+        f32::from(self.get()) / f32::from(Self::MAX)
+    }
+
+    /// Returns the volume that is `fraction` of `Volume::MAX`,
+    /// rounded to the nearest step. Values outside the range
+    /// 0.0-1.0, and NaN, are clamped.
+    pub fn from_fraction(fraction: f32) -> Self {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        Self::saturating((fraction * f32::from(Self::MAX)).round() as u16)
+    }
+
+    /// Returns `percent`% of this volume, rounded to the nearest
+    /// step and clamped to `Volume::MAX`. This is useful for
+    /// ducking, or for scaling a preset volume.
+    pub fn scale(self, percent: u16) -> Self {
+        let scaled = (u32::from(self.get()) * u32::from(percent) + 50) / 100;
+        Self::saturating(u16::try_from(scaled).unwrap_or(u16::MAX))
+    }
+}
+
+impl Add<u16> for Volume {
+    type Output = Self;
+
+    /// Saturates at `Volume::MAX` rather than overflowing
+    fn add(self, delta: u16) -> Self {
+        self.saturating_add(delta)
+    }
+}
+
+impl AddAssign<u16> for Volume {
+    fn add_assign(&mut self, delta: u16) {
+        *self = *self + delta;
+    }
+}
+
+impl Sub<u16> for Volume {
+    type Output = Self;
+
+    /// Saturates at `Volume::MIN` rather than underflowing
+    fn sub(self, delta: u16) -> Self {
+        self.saturating_sub(delta)
+    }
+}
+
+impl SubAssign<u16> for Volume {
+    fn sub_assign(&mut self, delta: u16) {
+        *self = *self - delta;
+    }
+}
+
+impl From<u8> for Volume {
+    /// Clamps `volume` to `Volume::MAX`
+    fn from(volume: u8) -> Self {
+        Self::saturating(volume.into())
+    }
+}
+
+#[cfg(feature = "svc-group-rendering-control")]
+impl From<Volume> for crate::GroupVolume {
+    fn from(volume: Volume) -> Self {
+        Self::saturating(volume.get())
+    }
+}
+
+#[cfg(feature = "svc-group-rendering-control")]
+impl From<crate::GroupVolume> for Volume {
+    fn from(volume: crate::GroupVolume) -> Self {
+        Self::saturating(volume.get())
+    }
+}
+
+/// A change to the volume as a person would type it: `25` sets
+/// the volume, while `+5` and `-5` adjust the current volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeChange {
+    Set(Volume),
+    Adjust(i16),
+}
+
+impl FromStr for VolumeChange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim();
+        if !s.starts_with(['+', '-']) {
+            return s.parse().map(Self::Set);
+        }
+        let delta: i16 = s
+            .parse()
+            .map_err(|_| Error::InvalidArgument(format!("Invalid volume adjustment {s:?}")))?;
+        let max = Volume::MAX as i16;
+        if !(-max..=max).contains(&delta) {
+            return Err(Error::OutOfRange {
+                name: "Volume",
+                value: delta.into(),
+                min: (-max).into(),
+                max: max.into(),
+            });
+        }
+        Ok(Self::Adjust(delta))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let v = Volume::saturating(95);
+        assert_eq!((v + 10).get(), 100);
+        assert_eq!((v - 10).get(), 85);
+        assert_eq!((Volume::saturating(3) - 10).get(), 0);
+        assert_eq!(v.saturating_add_signed(-100).get(), 0);
+        assert_eq!(v.saturating_add_signed(3).get(), 98);
+
+        let mut v = Volume::saturating(50);
+        v += 60;
+        assert_eq!(v.get(), 100);
+        v -= 1;
+        assert_eq!(v.get(), 99);
+
+        assert_eq!(Volume::from(200u8).get(), 100);
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(Volume::saturating(25).fraction(), 0.25);
+        assert_eq!(Volume::from_fraction(0.333).get(), 33);
+        assert_eq!(Volume::from_fraction(1.5).get(), 100);
+        assert_eq!(Volume::from_fraction(-1.0).get(), 0);
+        assert_eq!(Volume::from_fraction(f32::NAN).get(), 0);
+
+        assert_eq!(Volume::saturating(40).scale(50).get(), 20);
+        assert_eq!(Volume::saturating(15).scale(50).get(), 8);
+        assert_eq!(Volume::saturating(80).scale(200).get(), 100);
+    }
+
+    #[test]
+    fn test_parse_change() {
+        assert_eq!(
+            "25".parse::<VolumeChange>().unwrap(),
+            VolumeChange::Set(Volume::saturating(25))
+        );
+        assert_eq!(
+            " +5".parse::<VolumeChange>().unwrap(),
+            VolumeChange::Adjust(5)
+        );
+        assert_eq!(
+            "-100".parse::<VolumeChange>().unwrap(),
+            VolumeChange::Adjust(-100)
+        );
+        assert!(matches!(
+            "+x".parse::<VolumeChange>(),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            "-101".parse::<VolumeChange>(),
+            Err(Error::OutOfRange { value: -101, .. })
+        ));
+    }
+}
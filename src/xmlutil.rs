@@ -1,5 +1,7 @@
 use crate::upnp::{DecodeXml, EncodeXml};
+use crate::{duration_to_hms, hms_to_duration};
 use instant_xml::{Deserializer, FromXml, Id, Kind, ToXml};
+use std::time::Duration;
 
 /// This is a wrapper container that can be used to adapt a
 /// scalar embedded xml string value into a more rich Rust
@@ -103,3 +105,216 @@ impl<T: DecodeXml> From<Option<T>> for DecodeXmlString<T> {
         DecodeXmlString(value)
     }
 }
+
+/// Serializes as the decoded value rather than as the embedded
+/// xml string
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for DecodeXmlString<T>
+where
+    T: DecodeXml + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for DecodeXmlString<T>
+where
+    T: DecodeXml + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(DecodeXmlString(
+            <Option<T> as serde::Deserialize>::deserialize(deserializer)?,
+        ))
+    }
+}
+
+/// A duration that is represented as `H:MM:SS` in xml, such as
+/// the length of a track or the position within it.
+/// Values of `NOT_IMPLEMENTED` are treated as absent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HmsDuration(pub Duration);
+
+impl From<Duration> for HmsDuration {
+    fn from(value: Duration) -> HmsDuration {
+        HmsDuration(value)
+    }
+}
+
+impl From<HmsDuration> for Duration {
+    fn from(value: HmsDuration) -> Duration {
+        value.0
+    }
+}
+
+impl<'xml> FromXml<'xml> for HmsDuration {
+    #[inline]
+    fn matches(id: Id<'_>, field: Option<Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+
+        if let Some(value) = deserializer.take_str()? {
+            let value = value.trim();
+            if !value.is_empty() && value != "NOT_IMPLEMENTED" {
+                *into = Some(HmsDuration(hms_to_duration(value)));
+            }
+        }
+        Ok(())
+    }
+
+    type Accumulator = Option<Self>;
+    const KIND: Kind = Kind::Scalar;
+}
+
+impl ToXml for HmsDuration {
+    fn serialize<W>(
+        &self,
+        id: Option<Id<'_>>,
+        serializer: &mut instant_xml::Serializer<'_, W>,
+    ) -> std::result::Result<(), instant_xml::Error>
+    where
+        W: std::fmt::Write + ?Sized,
+    {
+        duration_to_hms(self.0).serialize(id, serializer)
+    }
+}
+
+/// Serializes using the same `H:MM:SS` form as the device
+#[cfg(feature = "serde")]
+impl serde::Serialize for HmsDuration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&duration_to_hms(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HmsDuration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(HmsDuration(hms_to_duration(&s)))
+    }
+}
+
+/// A flag that is held in a string valued state variable, and
+/// which is represented as `0` or `1` in xml
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct NumericBool(pub bool);
+
+impl From<bool> for NumericBool {
+    fn from(value: bool) -> NumericBool {
+        NumericBool(value)
+    }
+}
+
+impl From<NumericBool> for bool {
+    fn from(value: NumericBool) -> bool {
+        value.0
+    }
+}
+
+impl<'xml> FromXml<'xml> for NumericBool {
+    #[inline]
+    fn matches(id: Id<'_>, field: Option<Id<'_>>) -> bool {
+        match field {
+            Some(field) => id == field,
+            None => false,
+        }
+    }
+
+    fn deserialize<'cx>(
+        into: &mut Self::Accumulator,
+        field: &'static str,
+        deserializer: &mut Deserializer<'cx, 'xml>,
+    ) -> std::result::Result<(), instant_xml::Error> {
+        if into.is_some() {
+            return Err(instant_xml::Error::DuplicateValue(field));
+        }
+
+        if let Some(value) = deserializer.take_str()? {
+            *into = match value.trim() {
+                "" => None,
+                value => match crate::parse_sonos_bool(value) {
+                    Some(value) => Some(NumericBool(value)),
+                    None => {
+                        return Err(instant_xml::Error::Other(format!(
+                            "invalid value for field {field}: {value}"
+                        )))
+                    }
+                },
+            };
+        }
+        Ok(())
+    }
+
+    type Accumulator = Option<Self>;
+    const KIND: Kind = Kind::Scalar;
+}
+
+impl ToXml for NumericBool {
+    fn serialize<W>(
+        &self,
+        id: Option<Id<'_>>,
+        serializer: &mut instant_xml::Serializer<'_, W>,
+    ) -> std::result::Result<(), instant_xml::Error>
+    where
+        W: std::fmt::Write + ?Sized,
+    {
+        let value = if self.0 { "1" } else { "0" };
+        value.serialize(id, serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq, FromXml, ToXml)]
+    struct Position {
+        duration: Option<HmsDuration>,
+        enabled: Option<NumericBool>,
+    }
+
+    #[test]
+    fn test_wrappers() {
+        let position = Position {
+            duration: Some(HmsDuration(Duration::from_secs(234))),
+            enabled: Some(NumericBool(true)),
+        };
+        let xml = instant_xml::to_string(&position).unwrap();
+        assert_eq!(
+            xml,
+            "<Position><duration>00:03:54</duration><enabled>1</enabled></Position>"
+        );
+        assert_eq!(instant_xml::from_str::<Position>(&xml).unwrap(), position);
+
+        let position: Position = instant_xml::from_str(
+            "<Position><duration>NOT_IMPLEMENTED</duration><enabled>0</enabled></Position>",
+        )
+        .unwrap();
+        assert_eq!(
+            position,
+            Position {
+                duration: None,
+                enabled: Some(NumericBool(false)),
+            }
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::upnp::DecodeXml;
+use crate::{GroupId, PlayerUuid};
 use instant_xml::FromXml;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZoneGroupState {
     pub groups: Vec<ZoneGroup>,
 }
@@ -34,11 +36,12 @@ struct ZoneGroups {
 }
 
 #[derive(Debug, FromXml, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZoneGroup {
     #[xml(rename = "Coordinator", attribute)]
-    pub coordinator: String,
+    pub coordinator: PlayerUuid,
     #[xml(rename = "ID", attribute)]
-    pub id: String,
+    pub id: GroupId,
 
     pub members: Vec<ZoneGroupMember>,
 }
@@ -49,11 +52,12 @@ pub struct ZoneGroup {
 macro_rules! machine_info {
     (pub struct $ty:ident { $($inner:tt)* }) => {
 #[derive(Debug, FromXml, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct $ty {
     $($inner)*
 
     #[xml(rename = "UUID", attribute)]
-    pub uuid: String,
+    pub uuid: PlayerUuid,
     /// URL of the device_description.xml
     #[xml(rename = "Location", attribute)]
     pub location: String,
@@ -0,0 +1,135 @@
+use crate::redact::Masked;
+use crate::{DeviceProperties, Error, Result, SonosDevice};
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// A hardware (MAC) address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02X}:{b:02X}:{c:02X}:{d:02X}:{e:02X}:{g:02X}")
+    }
+}
+
+impl FromStr for MacAddress {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<MacAddress> {
+        let mut result = [0u8; 6];
+        let mut fields = s.trim().split([':', '-']);
+        for byte in result.iter_mut() {
+            *byte = fields
+                .next()
+                .and_then(|f| u8::from_str_radix(f, 16).ok())
+                .ok_or_else(|| Error::InvalidMacAddress(s.to_string()))?;
+        }
+        if fields.next().is_some() {
+            return Err(Error::InvalidMacAddress(s.to_string()));
+        }
+        Ok(MacAddress(result))
+    }
+}
+
+/// Serializes using the `Display` form, eg: `00:0E:58:00:00:01`
+#[cfg(feature = "serde")]
+impl serde::Serialize for MacAddress {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MacAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Information about the hardware and software of a device.
+/// The serial number is masked in the `Debug` representation.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoneInfo {
+    /// The serial number, eg: `00-0E-58-00-00-01:A`
+    pub serial_number: String,
+    /// The version of the firmware, eg: `79.1-56030`
+    pub software_version: String,
+    /// The version of the firmware as shown in the Sonos app,
+    /// eg: `16.2`
+    pub display_software_version: Option<String>,
+    pub hardware_version: String,
+    pub ip_address: Option<IpAddr>,
+    pub mac_address: Option<MacAddress>,
+    pub copyright_info: Option<String>,
+    pub extra_info: Option<String>,
+    pub ht_audio_in: Option<u32>,
+    pub flags: Option<u32>,
+}
+
+impl fmt::Debug for ZoneInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ZoneInfo")
+            .field("serial_number", &Masked(&self.serial_number))
+            .field("software_version", &self.software_version)
+            .field("display_software_version", &self.display_software_version)
+            .field("hardware_version", &self.hardware_version)
+            .field("ip_address", &self.ip_address)
+            .field("mac_address", &self.mac_address)
+            .field("copyright_info", &self.copyright_info)
+            .field("extra_info", &self.extra_info)
+            .field("ht_audio_in", &self.ht_audio_in)
+            .field("flags", &self.flags)
+            .finish()
+    }
+}
+
+impl SonosDevice {
+    /// Returns information about the hardware and software of the device
+    pub async fn zone_info(&self) -> Result<ZoneInfo> {
+        let info = <Self as DeviceProperties>::get_zone_info(self).await?;
+        Ok(ZoneInfo {
+            serial_number: info.serial_number.unwrap_or_default(),
+            software_version: info.software_version.unwrap_or_default(),
+            display_software_version: info.display_software_version,
+            hardware_version: info.hardware_version.unwrap_or_default(),
+            ip_address: info.ip_address.and_then(|ip| ip.parse().ok()),
+            mac_address: info.mac_address.and_then(|mac| mac.parse().ok()),
+            copyright_info: info.copyright_info,
+            extra_info: info.extra_info,
+            ht_audio_in: info.ht_audio_in,
+            flags: info.flags,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mac_address() {
+        let mac: MacAddress = "00:0e:58:aa:bb:0C".parse().unwrap();
+        assert_eq!(mac, MacAddress([0x00, 0x0e, 0x58, 0xaa, 0xbb, 0x0c]));
+        assert_eq!(mac.to_string(), "00:0E:58:AA:BB:0C");
+        assert!("00:0E:58:AA:BB".parse::<MacAddress>().is_err());
+        assert!("00:0E:58:AA:BB:CC:DD".parse::<MacAddress>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_mac_address_serde() {
+        let mac = MacAddress([0x00, 0x0e, 0x58, 0xaa, 0xbb, 0x0c]);
+        let json = serde_json::to_string(&mac).unwrap();
+        assert_eq!(json, "\"00:0E:58:AA:BB:0C\"");
+        assert_eq!(serde_json::from_str::<MacAddress>(&json).unwrap(), mac);
+    }
+}